@@ -0,0 +1,16 @@
+use std::{env, io};
+
+use quickfix_msg_gen::*;
+
+const SPEC_FILENAME: &str = "spec/FIX44_CUSTOM.xml";
+const BEGIN_STRING: &str = "FIX.4.4";
+
+fn main() -> io::Result<()> {
+    let out_dir = env::var("OUT_DIR").expect("Missing OUT_DIR");
+
+    // Regenerate code each time dictionary is modified.
+    println!("cargo:rerun-if-changed={SPEC_FILENAME}");
+
+    // Any error in dictionary fails the build, with file / line where it has been detected.
+    generate(SPEC_FILENAME, format!("{out_dir}/code.rs"), BEGIN_STRING)
+}
@@ -0,0 +1,3 @@
+/*! Messages generated from `spec/FIX44_CUSTOM.xml`, a FIX 4.4 dictionary with venue custom tags. */
+
+include!(concat!(env!("OUT_DIR"), "/code.rs"));
@@ -0,0 +1,26 @@
+use quickfix::{FieldMap, Message, QuickFixError};
+use quickfix_example_custom_dictionary::{field_id, field_types::Urgency, News};
+
+#[test]
+fn test_custom_field_round_trip() -> Result<(), QuickFixError> {
+    assert_eq!(field_id::VENUE_RISK_GROUP, 20001);
+
+    let mut news = News::try_new("Hello".to_string())?;
+    news.set_venue_risk_group("DESK-42".to_string())?;
+    news.set_urgency(Urgency::VenuePriority)?;
+
+    let text = news.to_fix_string();
+    assert!(text.contains("\u{1}20001=DESK-42\u{1}"), "{text}");
+    assert!(text.contains("\u{1}61=V\u{1}"), "{text}");
+
+    // Parse it back, and read custom values through typed API.
+    let news = News::try_from(Message::try_from_text(&text)?).unwrap();
+    assert_eq!(news.get_headline(), "Hello");
+    assert_eq!(news.get_venue_risk_group().as_deref(), Some("DESK-42"));
+    assert_eq!(news.get_urgency(), Some(Urgency::VenuePriority));
+
+    // Custom tag is a regular field for untyped API.
+    let msg = Message::from(news);
+    assert_eq!(msg.get_field(20001).as_deref(), Some("DESK-42"));
+    Ok(())
+}
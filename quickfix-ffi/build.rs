@@ -1,4 +1,8 @@
-use std::{env, fs, path::Path, process::Command};
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
 
 use cmake::Config;
 use fs_extra::dir::CopyOptions;
@@ -30,6 +34,21 @@ fn env_var(name: &str) -> Option<String> {
     })
 }
 
+/// Read `name` for build target first (`<name>_<target>`, with dashes or underscores), then `name`.
+fn target_env_var(name: &str) -> Option<String> {
+    let target = env::var("TARGET").expect("Missing TARGET");
+    [
+        format!("{name}_{target}"),
+        format!("{name}_{}", target.replace('-', "_")),
+        name.to_string(),
+    ]
+    .into_iter()
+    .find_map(|key| {
+        println!("cargo:rerun-if-env-changed={key}");
+        env_var(&key)
+    })
+}
+
 fn split_flags(value: &str) -> impl Iterator<Item = String> + '_ {
     value
         .split_whitespace()
@@ -189,34 +208,338 @@ fn declare_rerun_envs() {
     }
 }
 
-fn main() {
-    let out_dir = env::var("OUT_DIR").expect("Missing OUT_DIR");
-    let target_os = TargetOs::from_env();
+/// Pre-built libquickfix, found from environment variables.
+#[derive(Debug)]
+struct SystemLib {
+    include_dir: PathBuf,
+    lib_dir: PathBuf,
+    link_static: bool,
+}
 
-    if have_feature("build-with-io-uring") && target_os != TargetOs::Linux {
-        panic!("Feature `build-with-io-uring` is only supported on Linux targets");
+impl SystemLib {
+    /// Read `QUICKFIX_DIR` (with `include` and `lib` sub directories), `QUICKFIX_INCLUDE_DIR`,
+    /// `QUICKFIX_LIB_DIR` and `QUICKFIX_STATIC`, every one of them being overridable per target.
+    fn from_env() -> Option<Self> {
+        let root_dir = target_env_var("QUICKFIX_DIR").map(PathBuf::from);
+        let include_dir = target_env_var("QUICKFIX_INCLUDE_DIR")
+            .map(PathBuf::from)
+            .or_else(|| Some(root_dir.as_ref()?.join("include")));
+        let lib_dir = target_env_var("QUICKFIX_LIB_DIR")
+            .map(PathBuf::from)
+            .or_else(|| Some(root_dir.as_ref()?.join("lib")));
+        let link_static = target_env_var("QUICKFIX_STATIC").is_some_and(|value| value != "0");
+
+        match (include_dir, lib_dir) {
+            (Some(include_dir), Some(lib_dir)) => Some(Self {
+                include_dir,
+                lib_dir,
+                link_static,
+            }),
+            (None, None) => None,
+            _ => panic!(
+                "Both QUICKFIX_INCLUDE_DIR and QUICKFIX_LIB_DIR (or QUICKFIX_DIR) must be set to use a system quickfix library"
+            ),
+        }
+    }
+}
+
+/// Where libquickfix comes from.
+#[derive(Debug)]
+enum QuickfixSource {
+    /// Build bundled sources as a static library.
+    Vendored,
+    /// Link an already installed library.
+    System(SystemLib),
+}
+
+impl QuickfixSource {
+    /// Feature `vendored` always builds bundled sources. Otherwise, an installed library is used
+    /// when configured, falling back to bundled sources.
+    fn from_env() -> Self {
+        if have_feature("vendored") {
+            return Self::Vendored;
+        }
+        SystemLib::from_env().map_or(Self::Vendored, Self::System)
     }
+}
+
+/// Expose build configuration to this crate as `cfg(quickfix_<name>)` and to dependent build
+/// scripts as `DEP_QUICKFIX_<NAME>` (`1` or `0`).
+fn emit_build_config(source: &QuickfixSource) {
+    for (name, enabled) in [
+        ("vendored", matches!(source, QuickfixSource::Vendored)),
+        ("ssl", have_feature("build-with-ssl")),
+        ("mysql", have_feature("build-with-mysql")),
+        ("postgres", have_feature("build-with-postgres")),
+        ("io_uring", have_feature("build-with-io-uring")),
+    ] {
+        println!("cargo:rustc-check-cfg=cfg(quickfix_{name})");
+        if enabled {
+            println!("cargo:rustc-cfg=quickfix_{name}");
+        }
+        println!("cargo:{name}={}", u8::from(enabled));
+    }
+}
+
+/// Public members added to quickfix classes for the C bind to reach what they keep private:
+/// (header, class, members, SSL only). Members are inline, so neither class layout nor library
+/// ABI change and already built libraries keep working with patched headers.
+const BIND_ACCESSORS: &[(&str, &str, &str, bool)] = &[
+    (
+        "Session.h",
+        "Session",
+        "Responder *&bindResponder() { return m_pResponder; }
+  static void bindRemoveSession(Session &session) { removeSession(session); }",
+        false,
+    ),
+    (
+        "DataDictionary.h",
+        "DataDictionary",
+        "const decltype(m_messageFields) &bindMessageFields() const { return m_messageFields; }",
+        false,
+    ),
+    (
+        "Acceptor.h",
+        "Acceptor",
+        "decltype(m_sessions) &bindSessions() { return m_sessions; }
+  decltype(m_sessionIDs) &bindSessionIDs() { return m_sessionIDs; }",
+        false,
+    ),
+    (
+        "Initiator.h",
+        "Initiator",
+        "decltype(m_mutex) &bindMutex() { return m_mutex; }
+  decltype(m_settings) &bindSettings() { return m_settings; }
+  const decltype(m_settings) &bindSettings() const { return m_settings; }
+  decltype(m_sessions) &bindSessions() { return m_sessions; }
+  decltype(m_sessionIDs) &bindSessionIDs() { return m_sessionIDs; }
+  decltype(m_pending) &bindPending() { return m_pending; }
+  decltype(m_connected) &bindConnected() { return m_connected; }
+  decltype(m_disconnected) &bindDisconnected() { return m_disconnected; }
+  void bindSetDisconnected(const SessionID &sessionID) { setDisconnected(sessionID); }",
+        false,
+    ),
+    (
+        "SocketAcceptor.h",
+        "SocketAcceptor",
+        "SocketServer *bindServer() const { return m_pServer; }
+  decltype(m_portToSessions) &bindPortToSessions() { return m_portToSessions; }
+  void bindOnConfigure(const SessionSettings &s) { SocketAcceptor::onConfigure(s); }
+  void bindOnInitialize(const SessionSettings &s) { SocketAcceptor::onInitialize(s); }",
+        false,
+    ),
+    // Listening sockets created by the bind, e.g. unix domain ones, are registered like `add` does for TCP ports.
+    (
+        "SocketServer.h",
+        "SocketServer",
+        "void bindAddListener(socket_handle socket, int port) {
+    SocketInfo info(socket, port, false, 0, 0);
+    m_socketToInfo[socket] = info;
+    m_portToInfo[port] = info;
+    m_monitor.addRead(socket);
+  }",
+        false,
+    ),
+    (
+        "ThreadedSocketAcceptor.h",
+        "ThreadedSocketAcceptor",
+        "const decltype(m_sockets) &bindSockets() const { return m_sockets; }",
+        false,
+    ),
+    // Initiators are subclassed by the bind to hook their connect path: base implementations are called qualified.
+    (
+        "SocketInitiator.h",
+        "SocketInitiator",
+        "int &bindReconnectInterval() { return m_reconnectInterval; }
+  decltype(m_pendingConnections) &bindPendingConnections() { return m_pendingConnections; }
+  SocketConnector &bindConnector() { return m_connector; }
+  void bindDoConnect(const SessionID &s, const Dictionary &d) { SocketInitiator::doConnect(s, d); }
+  void bindOnConnect(SocketConnector &c, socket_handle s) { SocketInitiator::onConnect(c, s); }",
+        false,
+    ),
+    (
+        "ThreadedSocketInitiator.h",
+        "ThreadedSocketInitiator",
+        "int &bindReconnectInterval() { return m_reconnectInterval; }
+  void bindDoConnect(const SessionID &s, const Dictionary &d) { ThreadedSocketInitiator::doConnect(s, d); }",
+        false,
+    ),
+    // Connections derive privately from `Responder`: downcast a responder whose type is checked.
+    (
+        "SocketConnection.h",
+        "SocketConnection",
+        "static SocketConnection *bindFromResponder(Responder *responder) { return static_cast<SocketConnection *>(responder); }",
+        false,
+    ),
+    (
+        "ThreadedSocketConnection.h",
+        "ThreadedSocketConnection",
+        "static ThreadedSocketConnection *bindFromResponder(Responder *responder) { return static_cast<ThreadedSocketConnection *>(responder); }",
+        false,
+    ),
+    (
+        "SSLSocketAcceptor.h",
+        "SSLSocketAcceptor",
+        "SocketServer *bindServer() const { return m_pServer; }",
+        true,
+    ),
+    (
+        "ThreadedSSLSocketAcceptor.h",
+        "ThreadedSSLSocketAcceptor",
+        "const decltype(m_sockets) &bindSockets() const { return m_sockets; }",
+        true,
+    ),
+    (
+        "SSLSocketInitiator.h",
+        "SSLSocketInitiator",
+        "int &bindReconnectInterval() { return m_reconnectInterval; }
+  decltype(m_pendingConnections) &bindPendingConnections() { return m_pendingConnections; }
+  void bindDoConnect(const SessionID &s, const Dictionary &d) { SSLSocketInitiator::doConnect(s, d); }
+  void bindOnConnect(SocketConnector &c, socket_handle s) { SSLSocketInitiator::onConnect(c, s); }",
+        true,
+    ),
+    (
+        "ThreadedSSLSocketInitiator.h",
+        "ThreadedSSLSocketInitiator",
+        "int &bindReconnectInterval() { return m_reconnectInterval; }
+  void bindDoConnect(const SessionID &s, const Dictionary &d) { ThreadedSSLSocketInitiator::doConnect(s, d); }",
+        true,
+    ),
+    (
+        "SSLSocketConnection.h",
+        "SSLSocketConnection",
+        "static SSLSocketConnection *bindFromResponder(Responder *responder) { return static_cast<SSLSocketConnection *>(responder); }",
+        true,
+    ),
+    (
+        "ThreadedSSLSocketConnection.h",
+        "ThreadedSSLSocketConnection",
+        "static ThreadedSSLSocketConnection *bindFromResponder(Responder *responder) { return static_cast<ThreadedSSLSocketConnection *>(responder); }",
+        true,
+    ),
+];
+
+/// Marker of headers already patched, by a previous build or in quickfix sources themselves.
+const BIND_ACCESSORS_MARKER: &str = "// Accessors used by quickfix-bind.";
+
+/// Skip a comment, string or char literal starting at `pos`, returning where it ends.
+fn skip_cpp_token(source: &[u8], pos: usize) -> Option<usize> {
+    let rest = &source[pos..];
+    if rest.starts_with(b"//") {
+        return Some(
+            rest.iter()
+                .position(|&c| c == b'\n')
+                .map_or(source.len(), |end| pos + end),
+        );
+    }
+    if rest.starts_with(b"/*") {
+        let end = rest.windows(2).position(|w| w == b"*/")?;
+        return Some(pos + end + 2);
+    }
+    let quote = rest[0];
+    if quote == b'"' || quote == b'\'' {
+        let mut i = 1;
+        while i < rest.len() && rest[i] != quote {
+            i += if rest[i] == b'\\' { 2 } else { 1 };
+        }
+        return Some(pos + i + 1);
+    }
+    None
+}
 
+/// Find closing brace of `class_name` definition in `source`.
+fn find_class_end(source: &str, class_name: &str) -> Option<usize> {
+    let bytes = source.as_bytes();
+    let pattern = format!("class {class_name}");
+    let mut in_class = false;
+    let mut depth = 0;
+    let mut pos = 0;
+    while pos < bytes.len() {
+        if let Some(end) = skip_cpp_token(bytes, pos) {
+            pos = end;
+            continue;
+        }
+        if !in_class {
+            if bytes[pos..].starts_with(pattern.as_bytes()) {
+                pos += pattern.len();
+                let after = &bytes[pos..];
+                let after = &after[after.iter().take_while(|c| c.is_ascii_whitespace()).count()..];
+                // Skip forward declarations and longer class names.
+                in_class = after.starts_with(b"{")
+                    || (after.starts_with(b":") && !after.starts_with(b"::"));
+                continue;
+            }
+        } else if bytes[pos] == b'{' {
+            depth += 1;
+        } else if bytes[pos] == b'}' {
+            depth -= 1;
+            if depth == 0 {
+                return Some(pos);
+            }
+        }
+        pos += 1;
+    }
+    None
+}
+
+/// Add `BIND_ACCESSORS` to quickfix headers found in `header_dir`.
+fn patch_headers(header_dir: &Path) {
+    let with_ssl = have_feature("build-with-ssl");
+    for (header, class_name, members, ssl_only) in BIND_ACCESSORS {
+        if *ssl_only && !with_ssl {
+            continue;
+        }
+        let path = header_dir.join(header);
+        let source = fs::read_to_string(&path)
+            .unwrap_or_else(|err| panic!("Fail to read {}: {err}", path.display()));
+        if source.contains(BIND_ACCESSORS_MARKER) {
+            continue;
+        }
+        let end = find_class_end(&source, class_name)
+            .unwrap_or_else(|| panic!("Cannot find class {class_name} in {}", path.display()));
+        let patched = format!(
+            "{}\n{BIND_ACCESSORS_MARKER}\npublic:\n  {members}\n{}",
+            &source[..end],
+            &source[end..]
+        );
+        fs::write(&path, patched)
+            .unwrap_or_else(|err| panic!("Fail to write {}: {err}", path.display()));
+    }
+}
+
+/// Copy headers of an installed library to `OUT_DIR` and patch them, returning the new include
+/// directory.
+fn patch_system_headers(out_dir: &str, include_dir: &Path) -> PathBuf {
+    let patched_include_dir = Path::new(out_dir).join("quickfix-include");
+    let _ = fs::remove_dir_all(&patched_include_dir);
+    fs::create_dir_all(&patched_include_dir).expect("Fail to create patched include directory");
+    fs_extra::copy_items(
+        &[include_dir.join("quickfix")],
+        &patched_include_dir,
+        &CopyOptions::default(),
+    )
+    .expect("Fail to copy quickfix headers");
+
+    patch_headers(&patched_include_dir.join("quickfix"));
+    patched_include_dir
+}
+
+/// Build bundled libquickfix, returning its install directory.
+fn build_vendored(out_dir: &str, perf_flags: &PerfFlags) -> PathBuf {
     // Make sure sub-repositories are correctly init
     update_sub_repositories();
-
-    // Tell Cargo that if the given file changes, to rerun this build script.
-    println!("cargo:rerun-if-changed=./CMakeLists.txt");
     println!("cargo:rerun-if-changed=./libquickfix");
-    println!("cargo:rerun-if-changed=./quickfix-bind");
-    declare_rerun_envs();
 
     // Clone libquickfix to OUT_DIR because it modify itself when building
-    let libquickfix_build_dir = Path::new(&out_dir).join("libquickfix");
+    let libquickfix_build_dir = Path::new(out_dir).join("libquickfix");
 
     let _ = fs::remove_dir_all(&libquickfix_build_dir);
-    fs_extra::copy_items(&["./libquickfix"], &out_dir, &CopyOptions::default())
+    fs_extra::copy_items(&["./libquickfix"], out_dir, &CopyOptions::default())
         .expect("Fail to copy libquickfix");
-
-    let perf_flags = PerfFlags::from_env(&out_dir);
+    patch_headers(&libquickfix_build_dir.join("src").join("C++"));
 
     // Build quickfix as a static library
-    let mut quickfix_cmake_config = Config::new(libquickfix_build_dir);
+    let mut quickfix_cmake_config = Config::new(&libquickfix_build_dir);
     quickfix_cmake_config
         .define("CMAKE_POLICY_VERSION_MINIMUM", "3.10")
         .define("HAVE_SSL", read_cmake_opt("build-with-ssl"))
@@ -239,17 +562,59 @@ fn main() {
             .define("CMAKE_CXX_COMPILER_LAUNCHER", compiler_launcher);
     }
 
-    let quickfix_dst = quickfix_cmake_config.build();
-    let quickfix_include_path = format!("{}/include", quickfix_dst.display());
-    let quickfix_lib_path = format!("{}/lib", quickfix_dst.display());
+    quickfix_cmake_config.build()
+}
+
+fn main() {
+    let out_dir = env::var("OUT_DIR").expect("Missing OUT_DIR");
+    let target_os = TargetOs::from_env();
+
+    if have_feature("build-with-io-uring") && target_os != TargetOs::Linux {
+        panic!("Feature `build-with-io-uring` is only supported on Linux targets");
+    }
+
+    // Tell Cargo that if the given file changes, to rerun this build script.
+    println!("cargo:rerun-if-changed=./CMakeLists.txt");
+    println!("cargo:rerun-if-changed=./quickfix-bind");
+    declare_rerun_envs();
+
+    let source = QuickfixSource::from_env();
+    emit_build_config(&source);
+
+    let perf_flags = PerfFlags::from_env(&out_dir);
+
+    let (quickfix_include_paths, quickfix_lib_path, quickfix_link_kind) = match &source {
+        QuickfixSource::Vendored => {
+            let quickfix_dst = build_vendored(&out_dir, &perf_flags);
+            (
+                vec![quickfix_dst.join("include")],
+                quickfix_dst.join("lib"),
+                "static=",
+            )
+        }
+        QuickfixSource::System(lib) => (
+            // Patched headers take precedence, others (e.g. config) are still found in place.
+            vec![
+                patch_system_headers(&out_dir, &lib.include_dir),
+                lib.include_dir.clone(),
+            ],
+            lib.lib_dir.clone(),
+            if lib.link_static { "static=" } else { "" },
+        ),
+    };
+    let quickfix_lib_path = quickfix_lib_path.display().to_string();
 
     // Build quickfix C bind also as a static library.
-    env::set_var("CMAKE_LIBRARY_PATH", [quickfix_lib_path].join(";"));
+    env::set_var("CMAKE_LIBRARY_PATH", &quickfix_lib_path);
 
     let mut quickfix_bind_cmake_config = Config::new(".");
+    for quickfix_include_path in &quickfix_include_paths {
+        let quickfix_include_path = quickfix_include_path.display();
+        quickfix_bind_cmake_config
+            .cflag(format!("-I{quickfix_include_path}"))
+            .cxxflag(format!("-I{quickfix_include_path}"));
+    }
     quickfix_bind_cmake_config
-        .cflag(format!("-I{quickfix_include_path}"))
-        .cxxflag(format!("-I{quickfix_include_path}"))
         .define("QUICKFIX_BIND_EXAMPLES", "OFF")
         .define("HAVE_SSL", read_cmake_opt("build-with-ssl"))
         .define("HAVE_MYSQL", read_cmake_opt("build-with-mysql"))
@@ -265,17 +630,14 @@ fn main() {
     let quickfix_bind_dst = quickfix_bind_cmake_config.build();
 
     // Configure rustc.
-    println!(
-        "cargo:rustc-link-search=native={}/lib",
-        quickfix_dst.display()
-    );
+    println!("cargo:rustc-link-search=native={quickfix_lib_path}");
     println!(
         "cargo:rustc-link-search=native={}/lib",
         quickfix_bind_dst.display()
     );
 
     // ⚠️ NOTE: libquickfix as a different name on windows with debug profile.
-    println!("cargo:rustc-link-lib=static=quickfix");
+    println!("cargo:rustc-link-lib={quickfix_link_kind}quickfix");
     println!("cargo:rustc-link-lib=static=quickfixbind");
 
     // Lib std C++ is only available on UNIX platform.
@@ -9,12 +9,13 @@ static void customOnCreate(const void *data, const FixSessionID_t *session) {
   printf("customOnCreate: %p %p\n", data, session);
 }
 
-static void customOnLogon(const void *data, const FixSessionID_t *session) {
-  printf("customOnLogon: %p %p\n", data, session);
+static void customOnLogon(const void *data, const FixSessionID_t *session, int8_t sequenceReset) {
+  printf("customOnLogon: %p %p %d\n", data, session, sequenceReset);
 }
 
-static void customOnLogout(const void *data, const FixSessionID_t *session) {
-  printf("customOnLogout: %p %p\n", data, session);
+static void customOnLogout(const void *data, const FixSessionID_t *session, int8_t kind, int8_t initiatedLocally,
+                           const char *reason) {
+  printf("customOnLogout: %p %p %d %d %s\n", data, session, kind, initiatedLocally, reason ? reason : "");
 }
 
 static void customToAdmin(const void *data, FixMessage_t *msg, const FixSessionID_t *session) {
@@ -26,8 +27,8 @@ static int8_t customToApp(const void *data, FixMessage_t *msg, const FixSessionI
   return CALLBACK_OK;
 }
 
-static int8_t customFromAdmin(const void *data, FixMessage_t *msg, const FixSessionID_t *session) {
-  printf("customFromAdmin: %p %p %p\n", data, msg, session);
+static int8_t customFromAdmin(const void *data, FixMessage_t *msg, const FixSessionID_t *session, int8_t kind) {
+  printf("customFromAdmin: %p %p %p %d\n", data, msg, session, kind);
   FixMessage_delete(msg);
   return CALLBACK_OK;
 }
@@ -38,6 +39,14 @@ static int8_t customFromApp(const void *data, FixMessage_t *msg, const FixSessio
   return CALLBACK_OK;
 }
 
+static void customOnResendBegin(const void *data, const FixSessionID_t *session, uint32_t begin, uint32_t end) {
+  printf("customOnResendBegin: %p %p %u %u\n", data, session, begin, end);
+}
+
+static void customOnResendComplete(const void *data, const FixSessionID_t *session) {
+  printf("customOnResendComplete: %p %p\n", data, session);
+}
+
 static const FixApplicationCallbacks_t APP_CALLBACKS = {
     .onCreate = customOnCreate,
     .onLogon = customOnLogon,
@@ -46,18 +55,20 @@ static const FixApplicationCallbacks_t APP_CALLBACKS = {
     .toApp = customToApp,
     .fromAdmin = customFromAdmin,
     .fromApp = customFromApp,
+    .onResendBegin = customOnResendBegin,
+    .onResendComplete = customOnResendComplete,
 };
 
-static void customOnIncoming(const void *data, const FixSessionID_t *sessionId, const char *msg) {
-  printf("customOnIncoming: %p %p: %s\n", data, sessionId, msg);
+static void customOnIncoming(const void *data, const FixSessionID_t *sessionId, const char *msg, uint64_t msg_len) {
+  printf("customOnIncoming: %p %p: %.*s\n", data, sessionId, (int)msg_len, msg);
 }
 
-static void customOnOutgoing(const void *data, const FixSessionID_t *sessionId, const char *msg) {
-  printf("customOnOutgoing: %p %p: %s\n", data, sessionId, msg);
+static void customOnOutgoing(const void *data, const FixSessionID_t *sessionId, const char *msg, uint64_t msg_len) {
+  printf("customOnOutgoing: %p %p: %.*s\n", data, sessionId, (int)msg_len, msg);
 }
 
-static void customOnEvent(const void *data, const FixSessionID_t *sessionId, const char *msg) {
-  printf("customOnEvent: %p %p: %s\n", data, sessionId, msg);
+static void customOnEvent(const void *data, const FixSessionID_t *sessionId, const char *msg, uint64_t msg_len) {
+  printf("customOnEvent: %p %p: %.*s\n", data, sessionId, (int)msg_len, msg);
 }
 
 static const FixLogCallbacks_t LOG_CALLBACKS = {
@@ -11,12 +11,18 @@ extern "C" fn custom_on_create(data: *const ffi::c_void, session: FixSessionID_t
     println!("custom_on_create: {data:?} {session:?}");
 }
 
-extern "C" fn custom_on_logon(data: *const ffi::c_void, session: FixSessionID_t) {
-    println!("custom_on_logon: {data:?} {session:?}");
+extern "C" fn custom_on_logon(data: *const ffi::c_void, session: FixSessionID_t, reset: i8) {
+    println!("custom_on_logon: {data:?} {session:?} {reset}");
 }
 
-extern "C" fn custom_on_logout(data: *const ffi::c_void, session: FixSessionID_t) {
-    println!("custom_on_logout: {data:?} {session:?}");
+extern "C" fn custom_on_logout(
+    data: *const ffi::c_void,
+    session: FixSessionID_t,
+    kind: i8,
+    initiated_locally: i8,
+    reason: *const ffi::c_char,
+) {
+    println!("custom_on_logout: {data:?} {session:?} {kind} {initiated_locally} {reason:?}");
 }
 
 extern "C" fn custom_to_admin(
@@ -40,8 +46,9 @@ extern "C" fn custom_from_admin(
     data: *const ffi::c_void,
     msg: FixMessage_t,
     session: FixSessionID_t,
+    kind: i8,
 ) -> i8 {
-    println!("custom_from_admin: {data:?} {msg:?} {session:?}");
+    println!("custom_from_admin: {data:?} {msg:?} {session:?} {kind}");
     unsafe { FixMessage_delete(msg) };
     CALLBACK_OK
 }
@@ -56,6 +63,59 @@ extern "C" fn custom_from_app(
     CALLBACK_OK
 }
 
+extern "C" fn custom_on_resend_begin(
+    data: *const ffi::c_void,
+    session: FixSessionID_t,
+    begin: u32,
+    end: u32,
+) {
+    println!("custom_on_resend_begin: {data:?} {session:?} {begin} {end}");
+}
+
+extern "C" fn custom_on_resend_complete(data: *const ffi::c_void, session: FixSessionID_t) {
+    println!("custom_on_resend_complete: {data:?} {session:?}");
+}
+
+extern "C" fn custom_on_connect_failed(
+    data: *const ffi::c_void,
+    session: FixSessionID_t,
+    attempt: u32,
+    error_kind: i8,
+    _message: *const ffi::c_char,
+) {
+    println!("custom_on_connect_failed: {data:?} {session:?} {attempt} {error_kind}");
+}
+
+extern "C" fn custom_on_connect(
+    data: *const ffi::c_void,
+    session: FixSessionID_t,
+    _peer_address: *const ffi::c_char,
+) {
+    println!("custom_on_connect: {data:?} {session:?}");
+}
+
+extern "C" fn custom_on_disconnect(data: *const ffi::c_void, session: FixSessionID_t, reason: i8) {
+    println!("custom_on_disconnect: {data:?} {session:?} {reason}");
+}
+
+extern "C" fn custom_on_raw_incoming(
+    data: *const ffi::c_void,
+    session: FixSessionID_t,
+    _raw: *const ffi::c_char,
+    len: u64,
+) {
+    println!("custom_on_raw_incoming: {data:?} {session:?} {len}");
+}
+
+extern "C" fn custom_on_raw_outgoing(
+    data: *const ffi::c_void,
+    session: FixSessionID_t,
+    _raw: *const ffi::c_char,
+    len: u64,
+) {
+    println!("custom_on_raw_outgoing: {data:?} {session:?} {len}");
+}
+
 const APP_CALLBACKS: FixApplicationCallbacks_t = FixApplicationCallbacks_t {
     onCreate: custom_on_create,
     onLogon: custom_on_logon,
@@ -64,30 +124,40 @@ const APP_CALLBACKS: FixApplicationCallbacks_t = FixApplicationCallbacks_t {
     toApp: custom_to_app,
     fromAdmin: custom_from_admin,
     fromApp: custom_from_app,
+    onResendBegin: custom_on_resend_begin,
+    onResendComplete: custom_on_resend_complete,
+    onConnectFailed: custom_on_connect_failed,
+    onConnect: custom_on_connect,
+    onDisconnect: custom_on_disconnect,
+    onRawIncoming: custom_on_raw_incoming,
+    onRawOutgoing: custom_on_raw_outgoing,
 };
 
 extern "C" fn custom_on_incoming(
     data: *const ffi::c_void,
     session_id: Option<FixSessionID_t>,
     msg: *const ffi::c_char,
+    msg_len: u64,
 ) {
-    println!("custom_on_incoming: {data:?} {session_id:?} {msg:?}");
+    println!("custom_on_incoming: {data:?} {session_id:?} {msg:?} ({msg_len} bytes)");
 }
 
 extern "C" fn custom_on_outgoing(
     data: *const ffi::c_void,
     session_id: Option<FixSessionID_t>,
     msg: *const ffi::c_char,
+    msg_len: u64,
 ) {
-    println!("custom_on_outgoing: {data:?} {session_id:?} {msg:?}");
+    println!("custom_on_outgoing: {data:?} {session_id:?} {msg:?} ({msg_len} bytes)");
 }
 
 extern "C" fn custom_on_event(
     data: *const ffi::c_void,
     session_id: Option<FixSessionID_t>,
     msg: *const ffi::c_char,
+    msg_len: u64,
 ) {
-    println!("custom_on_event: {data:?} {session_id:?} {msg:?}");
+    println!("custom_on_event: {data:?} {session_id:?} {msg:?} ({msg_len} bytes)");
 }
 
 const LOG_CALLBACKS: FixLogCallbacks_t = FixLogCallbacks_t {
@@ -41,6 +41,7 @@
 #define ERROR_SOCKET_SEND_FAILED -36
 #define ERROR_SOCKET_RECV_FAILED -37
 #define ERROR_SOCKET_CLOSE_FAILED -38
+#define ERROR_SESSION_NOT_LOGGED_ON -39
 
 #define CALLBACK_OK 0
 #define CALLBACK_RESULT_DO_NOT_SEND -1
@@ -50,6 +51,29 @@
 #define CALLBACK_RESULT_REJECT_LOGON -5
 #define CALLBACK_RESULT_UNSUPPORTED_MESSAGE_TYPE -6
 
+#define CONNECT_ERROR_OTHER 0
+#define CONNECT_ERROR_REFUSED 1
+#define CONNECT_ERROR_TIMED_OUT 2
+#define CONNECT_ERROR_UNREACHABLE 3
+#define CONNECT_ERROR_DNS 4
+
+#define ADMIN_MSG_KIND_OTHER 0
+#define ADMIN_MSG_KIND_HEARTBEAT 1
+#define ADMIN_MSG_KIND_TEST_REQUEST 2
+#define ADMIN_MSG_KIND_RESEND_REQUEST 3
+#define ADMIN_MSG_KIND_REJECT 4
+#define ADMIN_MSG_KIND_SEQUENCE_RESET 5
+#define ADMIN_MSG_KIND_LOGOUT 6
+#define ADMIN_MSG_KIND_LOGON 7
+
+#define LOGOUT_KIND_NORMAL 0
+#define LOGOUT_KIND_DISCONNECT 1
+#define LOGOUT_KIND_LOGON_REJECTED 2
+
+#define DISCONNECT_REASON_LOCAL 0
+#define DISCONNECT_REASON_REMOTE 1
+#define DISCONNECT_REASON_TIMEOUT 2
+
 #ifdef __cplusplus
 extern "C" {
 namespace FIX {
@@ -57,6 +81,7 @@ namespace FIX {
 
 typedef struct SessionSettings FixSessionSettings_t;
 typedef struct Dictionary FixDictionary_t;
+typedef struct DictionaryCursor FixDictionaryCursor_t;
 typedef struct DataDictionary FixDataDictionary_t;
 typedef struct MessageStoreFactory FixMessageStoreFactory_t;
 typedef struct LogFactory FixLogFactory_t;
@@ -72,44 +97,189 @@ typedef struct Group FixGroup_t;
 
 typedef struct ApplicationCallbacks {
   void (*onCreate)(const void *data, const FixSessionID_t *session);
-  void (*onLogon)(const void *data, const FixSessionID_t *session);
-  void (*onLogout)(const void *data, const FixSessionID_t *session);
+  /**
+   * `sequenceReset` is 1 when ResetSeqNumFlag(141) was set on a Logon exchanged for this logon.
+   */
+  void (*onLogon)(const void *data, const FixSessionID_t *session, int8_t sequenceReset);
+  /**
+   * `kind` is one of `LOGOUT_KIND_*`, `initiatedLocally` is 1 when this side sent the first Logout or
+   * dropped the connection. `reason` is NULL when no Logout Text(58) nor disconnect reason is known.
+   */
+  void (*onLogout)(const void *data, const FixSessionID_t *session, int8_t kind, int8_t initiatedLocally,
+                   const char *reason);
   void (*toAdmin)(const void *data, FixMessage_t *msg, const FixSessionID_t *session);
   int8_t (*toApp)(const void *data, FixMessage_t *msg, const FixSessionID_t *session);
   /**
    * Receives ownership of `msg`. Callback implementation must eventually free it
-   * via `FixMessage_delete`.
+   * via `FixMessage_delete`. `kind` is one of `ADMIN_MSG_KIND_*`, from message MsgType(35).
    */
-  int8_t (*fromAdmin)(const void *data, FixMessage_t *msg, const FixSessionID_t *session);
+  int8_t (*fromAdmin)(const void *data, FixMessage_t *msg, const FixSessionID_t *session, int8_t kind);
   /**
    * Receives ownership of `msg`. Callback implementation must eventually free it
    * via `FixMessage_delete`.
    */
   int8_t (*fromApp)(const void *data, FixMessage_t *msg, const FixSessionID_t *session);
+  /**
+   * Called when a ResendRequest is about to be sent. `end` is 0 when all messages
+   * following `begin` are requested.
+   */
+  void (*onResendBegin)(const void *data, const FixSessionID_t *session, uint32_t begin, uint32_t end);
+  /**
+   * Called once all messages requested by the last ResendRequest have been received.
+   */
+  void (*onResendComplete)(const void *data, const FixSessionID_t *session);
+  /**
+   * Called when an initiator session could not connect to its counterparty.
+   * `attempt` counts consecutive failures, `errorKind` is one of `CONNECT_ERROR_*`.
+   */
+  void (*onConnectFailed)(const void *data, const FixSessionID_t *session, uint32_t attempt, int8_t errorKind,
+                          const char *message);
+  /**
+   * Called once a connection is bound to its session, before its first message is handled.
+   * `peerAddress` is formatted as `host:port` (`[host]:port` for IPv6), NULL when unknown
+   * (e.g. unix domain sockets).
+   */
+  void (*onConnect)(const void *data, const FixSessionID_t *session, const char *peerAddress);
+  /**
+   * Called once connection of a session has been closed, before `onLogout`.
+   * `reason` is one of `DISCONNECT_REASON_*`.
+   */
+  void (*onDisconnect)(const void *data, const FixSessionID_t *session, int8_t reason);
+  /**
+   * Called with raw message received from counterparty, before it is parsed.
+   * `raw` is not NUL terminated and only valid during the call.
+   */
+  void (*onRawIncoming)(const void *data, const FixSessionID_t *session, const char *raw, uint64_t len);
+  /**
+   * Called with raw message about to be written to socket, once serialized.
+   * `raw` is not NUL terminated and only valid during the call.
+   */
+  void (*onRawOutgoing)(const void *data, const FixSessionID_t *session, const char *raw, uint64_t len);
 } FixApplicationCallbacks_t;
 
+typedef struct SessionStats {
+  uint64_t sentApp;
+  uint64_t sentAdmin;
+  uint64_t receivedApp;
+  uint64_t receivedAdmin;
+  /** Milliseconds since UNIX epoch, 0 when nothing has been received yet. */
+  int64_t lastReceivedAt;
+  /** Milliseconds since UNIX epoch, 0 when nothing has been sent yet. */
+  int64_t lastSentAt;
+} FixSessionStats_t;
+
+typedef struct TransportStats {
+  uint64_t bytesSent;
+  uint64_t bytesReceived;
+  uint64_t connectCount;
+  /** Milliseconds since UNIX epoch, 0 when session has never been connected. */
+  int64_t lastConnectAt;
+} FixTransportStats_t;
+
+typedef struct StoreMetrics {
+  uint64_t messageCount;
+  /** Size of the files written by a file store, -1 for other stores. */
+  int64_t approxBytes;
+} FixStoreMetrics_t;
+
+typedef struct StoreRecord {
+  uint32_t seqNum;
+  const char *msg;
+  uint64_t len;
+} FixStoreRecord_t;
+
+typedef struct StoreSnapshot {
+  uint32_t nextSender;
+  uint32_t nextTarget;
+  /** Milliseconds since UNIX epoch. */
+  int64_t creationTime;
+  const FixStoreRecord_t *records;
+  uint64_t recordsLen;
+} FixStoreSnapshot_t;
+
+/** Negative values mean "unset" / "leave unchanged". Durations are in seconds. */
+typedef struct SocketOptions {
+  int8_t nodelay;
+  int8_t keepalive;
+  int32_t keepaliveIdle;
+  int32_t keepaliveInterval;
+  int32_t keepaliveCount;
+  int32_t sendBufferSize;
+  int32_t receiveBufferSize;
+} FixSocketOptions_t;
+
 typedef struct LogCallbacks {
-  void (*onIncoming)(const void *data, const FixSessionID_t *sessionId, const char *msg);
-  void (*onOutgoing)(const void *data, const FixSessionID_t *sessionId, const char *msg);
-  void (*onEvent)(const void *data, const FixSessionID_t *sessionId, const char *msg);
+  void (*onIncoming)(const void *data, const FixSessionID_t *sessionId, const char *msg, uint64_t msg_len);
+  void (*onOutgoing)(const void *data, const FixSessionID_t *sessionId, const char *msg, uint64_t msg_len);
+  void (*onEvent)(const void *data, const FixSessionID_t *sessionId, const char *msg, uint64_t msg_len);
 } FixLogCallbacks_t;
 
+typedef struct SessionLogFactoryCallbacks {
+  const void *(*create)(const void *data, const FixSessionID_t *sessionId);
+  void (*destroy)(const void *data, const void *logData);
+} FixSessionLogFactoryCallbacks_t;
+
+/**
+ * Callbacks of a message store implemented by the caller, shared by every session of the factory.
+ * Functions returning `int8_t` return 0 on success and a negative value on failure, functions returning
+ * `int64_t` return a negative value on failure. Failures are reported to quickfix as `IOException`, with the
+ * message given to `FixCustomMessageStore_setError` by the failing callback, if any.
+ */
+typedef struct MessageStoreCallbacks {
+  int8_t (*onCreate)(const void *data, const FixSessionID_t *session);
+  /**
+   * Store message `seqNum`, returns 1 when stored and 0 when not. `msg` is not NUL terminated and only
+   * valid during the call.
+   */
+  int8_t (*set)(const void *data, const FixSessionID_t *session, uint32_t seqNum, const char *msg, uint64_t len);
+  /**
+   * Call `push` with every stored message from `begin` to `end` (inclusive), in sequence order.
+   */
+  int8_t (*get)(const void *data, const FixSessionID_t *session, uint32_t begin, uint32_t end, void *output,
+                void (*push)(void *output, const char *msg, uint64_t len));
+  int64_t (*getNextSenderMsgSeqNum)(const void *data, const FixSessionID_t *session);
+  int64_t (*getNextTargetMsgSeqNum)(const void *data, const FixSessionID_t *session);
+  int8_t (*setNextSenderMsgSeqNum)(const void *data, const FixSessionID_t *session, uint32_t value);
+  int8_t (*setNextTargetMsgSeqNum)(const void *data, const FixSessionID_t *session, uint32_t value);
+  int8_t (*incrNextSenderMsgSeqNum)(const void *data, const FixSessionID_t *session);
+  int8_t (*incrNextTargetMsgSeqNum)(const void *data, const FixSessionID_t *session);
+  /**
+   * Milliseconds since UNIX epoch.
+   */
+  int64_t (*getCreationTime)(const void *data, const FixSessionID_t *session);
+  /**
+   * `now` is in milliseconds since UNIX epoch.
+   */
+  int8_t (*reset)(const void *data, const FixSessionID_t *session, int64_t now);
+  int8_t (*refresh)(const void *data, const FixSessionID_t *session);
+} FixMessageStoreCallbacks_t;
+
 const char *Fix_getLastErrorMessage();
 int8_t Fix_getLastErrorCode();
+int32_t Fix_getLastErrorField();
+const char *Fix_getLastErrorDetail();
+const char *Fix_getLastErrorType();
 void Fix_clearLastErrorMessage();
 
 int8_t FixBenchmark_noop();
 int8_t FixBenchmark_strEqual(const char *lhs, uint64_t lhs_len, const char *rhs, uint64_t rhs_len);
 
 FixSessionSettings_t *FixSessionSettings_new();
+FixSessionSettings_t *FixSessionSettings_copy(const FixSessionSettings_t *src);
 FixSessionSettings_t *FixSessionSettings_fromPath(const char *configPath);
+FixSessionSettings_t *FixSessionSettings_fromString(const char *text);
+int64_t FixSessionSettings_getStringLen(const FixSessionSettings_t *obj);
+int8_t FixSessionSettings_readString(const FixSessionSettings_t *obj, char *buffer, uint64_t buffer_len);
 const FixDictionary_t *FixSessionSettings_getGlobalRef(const FixSessionSettings_t *obj);
 const FixDictionary_t *FixSessionSettings_getSessionRef(const FixSessionSettings_t *obj, const FixSessionID_t *id);
 int8_t FixSessionSettings_setGlobal(FixSessionSettings_t *obj, const FixDictionary_t *value);
 int8_t FixSessionSettings_setSession(FixSessionSettings_t *obj, const FixSessionID_t *id, const FixDictionary_t *value);
+int64_t FixSessionSettings_getSessionCount(const FixSessionSettings_t *obj);
+FixSessionID_t *FixSessionSettings_getSessionIdAt(const FixSessionSettings_t *obj, uint64_t index);
 void FixSessionSettings_delete(const FixSessionSettings_t *obj);
 
 FixDictionary_t *FixDictionary_new(const char *name);
+FixDictionary_t *FixDictionary_copy(const FixDictionary_t *src);
 int8_t FixDictionary_setString(FixDictionary_t *obj, const char *key, const char *value);
 int8_t FixDictionary_setInt(FixDictionary_t *obj, const char *key, int32_t value);
 int8_t FixDictionary_setDouble(FixDictionary_t *obj, const char *key, double value);
@@ -121,16 +291,54 @@ int32_t FixDictionary_getInt(const FixDictionary_t *obj, const char *key);
 double FixDictionary_getDouble(const FixDictionary_t *obj, const char *key);
 int8_t FixDictionary_getBool(const FixDictionary_t *obj, const char *key);
 int32_t FixDictionary_getDay(const FixDictionary_t *obj, const char *key);
+int8_t FixDictionary_readInt(const FixDictionary_t *obj, const char *key, int32_t *value);
+int8_t FixDictionary_readDouble(const FixDictionary_t *obj, const char *key, double *value);
+int8_t FixDictionary_readBool(const FixDictionary_t *obj, const char *key, int8_t *value);
+int8_t FixDictionary_readDay(const FixDictionary_t *obj, const char *key, int32_t *value);
+int8_t FixDictionary_merge(FixDictionary_t *obj, const FixDictionary_t *other);
+FixDictionaryCursor_t *FixDictionary_cursor(const FixDictionary_t *obj);
+int8_t FixDictionaryCursor_next(FixDictionaryCursor_t *obj, const char **key, uint64_t *key_len, const char **value,
+                                uint64_t *value_len);
+void FixDictionaryCursor_delete(const FixDictionaryCursor_t *obj);
 int8_t FixDictionary_hasKey(const FixDictionary_t *obj, const char *key);
 void FixDictionary_delete(const FixDictionary_t *obj);
 
 FixDataDictionary_t *FixDataDictionary_new();
 FixDataDictionary_t *FixDataDictionary_fromPath(const char *configPath);
+int64_t FixDataDictionary_getFieldNameLen(const FixDataDictionary_t *obj, int32_t tag);
+int8_t FixDataDictionary_readFieldName(const FixDataDictionary_t *obj, int32_t tag, char *buffer, uint64_t buffer_len);
+int8_t FixDataDictionary_getFieldTag(const FixDataDictionary_t *obj, const char *name, int32_t *tag);
+const char *FixDataDictionary_getFieldTypeName(const FixDataDictionary_t *obj, int32_t tag);
+int64_t FixDataDictionary_getValueNameLen(const FixDataDictionary_t *obj, int32_t tag, const char *value);
+int8_t FixDataDictionary_readValueName(const FixDataDictionary_t *obj, int32_t tag, const char *value, char *buffer,
+                                       uint64_t buffer_len);
+int64_t FixDataDictionary_getMessageFieldCount(const FixDataDictionary_t *obj, const char *msgType);
+int8_t FixDataDictionary_getMessageFieldAt(const FixDataDictionary_t *obj, const char *msgType, uint64_t index,
+                                           int32_t *tag);
+int8_t FixDataDictionary_isRequiredField(const FixDataDictionary_t *obj, const char *msgType, int32_t tag);
+const FixDataDictionary_t *FixDataDictionary_getGroupRef(const FixDataDictionary_t *obj, const char *msgType,
+                                                         int32_t tag, int32_t *delimiter);
+int8_t FixDataDictionary_validate(const FixDataDictionary_t *obj, const FixMessage_t *msg, int32_t *field);
 void FixDataDictionary_delete(const FixDataDictionary_t *obj);
 
 FixMessageStoreFactory_t *FixFileMessageStoreFactory_new(const FixSessionSettings_t *settings);
+FixMessageStoreFactory_t *FixFileMessageStoreFactory_newWithPath(const char *path);
 FixMessageStoreFactory_t *FixMemoryMessageStoreFactory_new();
 FixMessageStoreFactory_t *FixNullMessageStoreFactory_new();
+FixMessageStoreFactory_t *FixCustomMessageStoreFactory_new(const void *data,
+                                                           const FixMessageStoreCallbacks_t *callbacks);
+/**
+ * Set error message of the custom message store callback failing on this thread.
+ */
+void FixCustomMessageStore_setError(const char *message);
+/**
+ * `onDivergence` may be NULL: secondary store failures are then reported as I/O errors.
+ */
+FixMessageStoreFactory_t *FixCompositeMessageStoreFactory_new(FixMessageStoreFactory_t *primary,
+                                                              FixMessageStoreFactory_t *secondary, const void *data,
+                                                              void (*onDivergence)(const void *data,
+                                                                                   const FixSessionID_t *session,
+                                                                                   const char *reason));
 
 #ifdef HAVE_MYSQL
 FixMessageStoreFactory_t *FixMysqlMessageStoreFactory_new(const FixSessionSettings_t *settings);
@@ -140,24 +348,50 @@ FixMessageStoreFactory_t *FixMysqlMessageStoreFactory_new(const FixSessionSettin
 FixMessageStoreFactory_t *FixPostgresMessageStoreFactory_new(const FixSessionSettings_t *settings);
 #endif // HAVE_POSTGRESQL
 
+int8_t FixMessageStoreFactory_resetStore(FixMessageStoreFactory_t *obj, const FixSessionID_t *sessionId);
+int8_t FixMessageStoreFactory_getSequenceNumbers(FixMessageStoreFactory_t *obj, const FixSessionID_t *sessionId,
+                                                 uint32_t *nextSender, uint32_t *nextTarget);
+int8_t FixMessageStoreFactory_setSequenceNumbers(FixMessageStoreFactory_t *obj, const FixSessionID_t *sessionId,
+                                                 uint32_t nextSender, uint32_t nextTarget);
+int8_t FixMessageStoreFactory_importStore(FixMessageStoreFactory_t *obj, const FixSessionID_t *sessionId,
+                                          const FixStoreSnapshot_t *snapshot);
 void FixMessageStoreFactory_delete(const FixMessageStoreFactory_t *obj);
 
 FixLogFactory_t *FixLogFactory_new(const void *data, const FixLogCallbacks_t *callbacks);
+FixLogFactory_t *FixSessionLogFactory_new(const void *data, const FixSessionLogFactoryCallbacks_t *factoryCallbacks,
+                                          const FixLogCallbacks_t *callbacks);
 void FixLogFactory_delete(const FixLogFactory_t *obj);
 
 FixApplication_t *FixApplication_new(const void *data, const FixApplicationCallbacks_t *callbacks);
 void FixApplication_delete(const FixApplication_t *obj);
+/**
+ * Attach a description to the error code returned by the application callback currently running on this thread,
+ * e.g. logout reason of `CALLBACK_RESULT_REJECT_LOGON`.
+ */
+void FixApplication_setCallbackErrorText(const char *text);
+/**
+ * Attach the offending tag to the error code returned by the application callback currently running on this thread,
+ * reported as RefTagID(371) of the generated reject.
+ */
+void FixApplication_setCallbackErrorField(int32_t field);
 
 FixAcceptor_t *FixAcceptor_new(FixApplication_t *application, FixMessageStoreFactory_t *storeFactory,
                                const FixSessionSettings_t *settings, FixLogFactory_t *logFactory,
                                int8_t isMultiThreaded, int8_t isSslEnabled);
 int8_t FixAcceptor_start(FixAcceptor_t *obj);
 int8_t FixAcceptor_block(FixAcceptor_t *obj);
-int8_t FixAcceptor_poll(FixAcceptor_t *obj);
+int8_t FixAcceptor_poll(FixAcceptor_t *obj, double timeout);
 int8_t FixAcceptor_stop(FixAcceptor_t *obj);
+int8_t FixAcceptor_stopForce(FixAcceptor_t *obj);
+int8_t FixAcceptor_stopWithReason(FixAcceptor_t *obj, const char *reason);
 int8_t FixAcceptor_isLoggedOn(const FixAcceptor_t *obj);
 int8_t FixAcceptor_isStopped(const FixAcceptor_t *obj);
 FixSession_t *FixAcceptor_getSession(const FixAcceptor_t *obj, const FixSessionID_t *sessionId);
+int64_t FixAcceptor_getSessionCount(const FixAcceptor_t *obj);
+FixSessionID_t *FixAcceptor_getSessionIdAt(const FixAcceptor_t *obj, uint64_t index);
+int8_t FixAcceptor_removeSession(FixAcceptor_t *obj, const FixSessionID_t *id, int8_t forceDisconnect);
+int64_t FixAcceptor_getListenEndpointCount(const FixAcceptor_t *obj);
+int8_t FixAcceptor_readListenEndpointAt(const FixAcceptor_t *obj, uint64_t index, char *buffer, uint64_t buffer_len);
 void FixAcceptor_delete(const FixAcceptor_t *obj);
 
 FixInitiator_t *FixInitiator_new(FixApplication_t *application, FixMessageStoreFactory_t *storeFactory,
@@ -165,11 +399,24 @@ FixInitiator_t *FixInitiator_new(FixApplication_t *application, FixMessageStoreF
                                  int8_t isMultiThreaded, int8_t isSslEnabled);
 int8_t FixInitiator_start(FixInitiator_t *obj);
 int8_t FixInitiator_block(FixInitiator_t *obj);
-int8_t FixInitiator_poll(FixInitiator_t *obj);
+int8_t FixInitiator_poll(FixInitiator_t *obj, double timeout);
 int8_t FixInitiator_stop(FixInitiator_t *obj);
+int8_t FixInitiator_stopForce(FixInitiator_t *obj);
+int8_t FixInitiator_stopWithReason(FixInitiator_t *obj, const char *reason);
 int8_t FixInitiator_isLoggedOn(const FixInitiator_t *obj);
 int8_t FixInitiator_isStopped(const FixInitiator_t *obj);
 FixSession_t *FixInitiator_getSession(const FixInitiator_t *obj, const FixSessionID_t *sessionId);
+int64_t FixInitiator_getSessionCount(const FixInitiator_t *obj);
+FixSessionID_t *FixInitiator_getSessionIdAt(const FixInitiator_t *obj, uint64_t index);
+const FixDictionary_t *FixInitiator_getSessionSettingsRef(const FixInitiator_t *obj, const FixSessionID_t *id);
+int8_t FixInitiator_setSessionSettings(FixInitiator_t *obj, const FixSessionID_t *id, const FixDictionary_t *value);
+int8_t FixInitiator_addSession(FixInitiator_t *obj, FixApplication_t *application,
+                               FixMessageStoreFactory_t *storeFactory, FixLogFactory_t *logFactory,
+                               const FixSessionID_t *id, const FixDictionary_t *value);
+int8_t FixInitiator_removeSession(FixInitiator_t *obj, const FixSessionID_t *id, int8_t forceDisconnect);
+int8_t FixInitiator_setReconnectInterval(FixInitiator_t *obj, int32_t seconds);
+int8_t FixInitiator_setReconnectPolicy(FixInitiator_t *obj, const FixSessionID_t *id, int64_t initialMillis,
+                                       int64_t maxMillis, int64_t jitterMillis);
 void FixInitiator_delete(const FixInitiator_t *obj);
 
 FixSessionID_t *FixSessionID_new(const char *beginString, const char *senderCompID, const char *targetCompID,
@@ -185,14 +432,25 @@ void FixSessionID_delete(const FixSessionID_t *session);
 
 FixMessage_t *FixMessage_new();
 FixMessage_t *FixMessage_copy(const FixMessage_t *src);
-FixMessage_t *FixMessage_fromString(const char *text);
-FixMessage_t *FixMessage_fromStringAndDictionary(const char *text, const FixDataDictionary_t *dictionary);
+FixMessage_t *FixMessage_fromString(const char *text, uint64_t text_len);
+FixMessage_t *FixMessage_fromStringAndDictionary(const char *text, uint64_t text_len,
+                                                 const FixDataDictionary_t *dictionary);
 const char *FixMessage_getField(const FixMessage_t *obj, int32_t tag);
 int8_t FixMessage_isFieldEqual(const FixMessage_t *obj, int32_t tag, const char *value, uint64_t value_len);
+const char *FixMessage_getFieldValue(const FixMessage_t *obj, int32_t tag, uint64_t *value_len);
+int64_t FixMessage_getFields(const FixMessage_t *obj, const int32_t *tags, uint64_t tags_len, char *buffer,
+                             uint64_t buffer_len, int64_t *lengths);
 int8_t FixMessage_setField(FixMessage_t *obj, int32_t tag, const char *value);
+int8_t FixMessage_setFieldRaw(FixMessage_t *obj, int32_t tag, const char *value, uint64_t value_len);
 int8_t FixMessage_removeField(FixMessage_t *obj, int32_t tag);
+int8_t FixMessage_clear(FixMessage_t *obj);
+int8_t FixMessage_assign(FixMessage_t *obj, const FixMessage_t *src);
 int8_t FixMessage_addGroup(FixMessage_t *obj, const FixGroup_t *group);
+int8_t FixMessage_addGroups(FixMessage_t *obj, const FixGroup_t *tmpl, const int32_t *tags, const char *const *values,
+                            const uint64_t *value_lens, const uint64_t *row_lens, uint64_t rows_len);
 const char *FixMessage_toString(FixMessage_t *obj);
+const char *FixMessage_toStringRef(FixMessage_t *obj, uint64_t *len);
+int64_t FixMessage_toBuffer(const FixMessage_t *obj, char *buffer, uint64_t buffer_len);
 int64_t FixMessage_getStringLen(const FixMessage_t *obj);
 int8_t FixMessage_readString(const FixMessage_t *obj, char *buffer, uint64_t buffer_len);
 void FixMessage_delete(const FixMessage_t *obj);
@@ -203,9 +461,15 @@ FixHeader_t *FixMessage_copyHeader(const FixMessage_t *obj);
 FixHeader_t *FixMessage_getHeaderRef(FixMessage_t *obj);
 const char *FixHeader_getField(const FixHeader_t *obj, int32_t tag);
 int8_t FixHeader_isFieldEqual(const FixHeader_t *obj, int32_t tag, const char *value, uint64_t value_len);
+const char *FixHeader_getFieldValue(const FixHeader_t *obj, int32_t tag, uint64_t *value_len);
+int64_t FixHeader_getFields(const FixHeader_t *obj, const int32_t *tags, uint64_t tags_len, char *buffer,
+                            uint64_t buffer_len, int64_t *lengths);
 int8_t FixHeader_setField(FixHeader_t *obj, int32_t tag, const char *value);
+int8_t FixHeader_setFieldRaw(FixHeader_t *obj, int32_t tag, const char *value, uint64_t value_len);
 int8_t FixHeader_removeField(FixHeader_t *obj, int32_t tag);
 int8_t FixHeader_addGroup(FixHeader_t *obj, const FixGroup_t *group);
+int8_t FixHeader_addGroups(FixHeader_t *obj, const FixGroup_t *tmpl, const int32_t *tags, const char *const *values,
+                           const uint64_t *value_lens, const uint64_t *row_lens, uint64_t rows_len);
 void FixHeader_delete(const FixHeader_t *obj);
 
 FixTrailer_t *FixTrailer_new();
@@ -214,9 +478,15 @@ FixTrailer_t *FixMessage_copyTrailer(const FixMessage_t *obj);
 FixTrailer_t *FixMessage_getTrailerRef(FixMessage_t *obj);
 const char *FixTrailer_getField(const FixTrailer_t *obj, int32_t tag);
 int8_t FixTrailer_isFieldEqual(const FixTrailer_t *obj, int32_t tag, const char *value, uint64_t value_len);
+const char *FixTrailer_getFieldValue(const FixTrailer_t *obj, int32_t tag, uint64_t *value_len);
+int64_t FixTrailer_getFields(const FixTrailer_t *obj, const int32_t *tags, uint64_t tags_len, char *buffer,
+                             uint64_t buffer_len, int64_t *lengths);
 int8_t FixTrailer_setField(FixTrailer_t *obj, int32_t tag, const char *value);
+int8_t FixTrailer_setFieldRaw(FixTrailer_t *obj, int32_t tag, const char *value, uint64_t value_len);
 int8_t FixTrailer_removeField(FixTrailer_t *obj, int32_t tag);
 int8_t FixTrailer_addGroup(FixTrailer_t *obj, const FixGroup_t *group);
+int8_t FixTrailer_addGroups(FixTrailer_t *obj, const FixGroup_t *tmpl, const int32_t *tags, const char *const *values,
+                            const uint64_t *value_lens, const uint64_t *row_lens, uint64_t rows_len);
 void FixTrailer_delete(const FixTrailer_t *obj);
 
 FixGroup_t *FixGroup_new(int32_t fieldId, int32_t delim, const int32_t order[]);
@@ -230,20 +500,66 @@ int32_t FixGroup_getFieldId(const FixGroup_t *obj);
 int32_t FixGroup_getDelim(const FixGroup_t *obj);
 const char *FixGroup_getField(const FixGroup_t *obj, int32_t tag);
 int8_t FixGroup_isFieldEqual(const FixGroup_t *obj, int32_t tag, const char *value, uint64_t value_len);
+const char *FixGroup_getFieldValue(const FixGroup_t *obj, int32_t tag, uint64_t *value_len);
+int64_t FixGroup_getFields(const FixGroup_t *obj, const int32_t *tags, uint64_t tags_len, char *buffer,
+                           uint64_t buffer_len, int64_t *lengths);
 int8_t FixGroup_setField(FixGroup_t *obj, int32_t tag, const char *value);
+int8_t FixGroup_setFieldRaw(FixGroup_t *obj, int32_t tag, const char *value, uint64_t value_len);
 int8_t FixGroup_removeField(FixGroup_t *obj, int32_t tag);
 int8_t FixGroup_addGroup(FixGroup_t *obj, const FixGroup_t *group);
+int8_t FixGroup_addGroups(FixGroup_t *obj, const FixGroup_t *tmpl, const int32_t *tags, const char *const *values,
+                          const uint64_t *value_lens, const uint64_t *row_lens, uint64_t rows_len);
 void FixGroup_delete(const FixGroup_t *obj);
 
 int8_t FixSession_sendToTarget(FixMessage_t *msg, const FixSessionID_t *session_id);
+int8_t FixSession_sendToTargetWithCompIds(FixMessage_t *msg, const char *beginString, const char *senderCompId,
+                                          const char *targetCompId, const char *qualifier);
+int64_t FixSession_sendAllToTarget(FixMessage_t *const *msgs, uint64_t msgs_len, const FixSessionID_t *session_id);
+int8_t FixSession_trySendToTarget(FixMessage_t *msg, const FixSessionID_t *session_id);
 FixSession_t *FixSession_lookup(const FixSessionID_t *session_id);
 int8_t FixSession_logout(FixSession_t *session);
+int8_t FixSession_logoutWithReason(FixSession_t *session, const char *reason);
 int8_t FixSession_isLoggedOn(FixSession_t *session);
+int8_t FixSession_isEnabled(FixSession_t *session);
 int8_t FixSession_send(FixSession_t *session, FixMessage_t *msg);
+FixSession_t *FixSession_resolve(const FixSessionID_t *session_id, uint64_t *generation);
+int8_t FixSession_sendResolved(FixSession_t *session, uint64_t generation, FixMessage_t *msg);
+int8_t FixSession_reset(FixSession_t *session);
+int8_t FixSession_logon(FixSession_t *session);
+int8_t FixSession_disconnect(FixSession_t *session);
+int8_t FixSession_requestResend(FixSession_t *session, int32_t beginSeqNo, int32_t endSeqNo);
+int8_t FixSession_sendGapFill(FixSession_t *session, int32_t newSeqNo);
+int8_t FixSession_next(FixSession_t *session, const char *msg);
+int8_t FixSession_tick(FixSession_t *session, int64_t nowMillis);
+int8_t FixSession_refresh(FixSession_t *session);
+int8_t FixSession_sendTestRequest(FixSession_t *session, const char *testReqId);
 int8_t FixSession_setNextSenderMsgSeqNum(FixSession_t *session, int32_t num);
 int8_t FixSession_setNextTargetMsgSeqNum(FixSession_t *session, int32_t num);
 int32_t FixSession_getExpectedTargetNum(FixSession_t *session);
 int32_t FixSession_getExpectedSenderNum(FixSession_t *session);
+FixSessionID_t *FixSession_getSessionID(FixSession_t *session);
+int64_t FixSession_getOutboundQueueLen(FixSession_t *session);
+int64_t FixSession_getOutboundQueueLenById(const FixSessionID_t *session_id);
+int8_t FixSession_getStats(FixSession_t *session, FixSessionStats_t *stats);
+int8_t FixSession_getTransportStats(FixSession_t *session, FixTransportStats_t *stats);
+FixDictionary_t *FixSession_getSettings(FixSession_t *session);
+int8_t FixSession_isResendRequested(FixSession_t *session);
+int32_t FixSession_getHeartBtInt(FixSession_t *session);
+int8_t FixSession_setHeartBtInt(FixSession_t *session, int32_t value);
+int8_t FixSession_isSessionTime(FixSession_t *session);
+int8_t FixSession_isSessionTimeAt(FixSession_t *session, int64_t nowMillis);
+int64_t FixSession_getMillisSinceLastReceived(FixSession_t *session);
+int64_t FixSession_getStoreCreationTime(FixSession_t *session);
+int8_t FixSession_getStoreMetrics(FixSession_t *session, FixStoreMetrics_t *metrics);
+int8_t FixSession_importStore(FixSession_t *session, const FixStoreSnapshot_t *snapshot);
+int8_t FixSession_setResetOnLogon(FixSession_t *session, int8_t value);
+int8_t FixSession_setResetOnLogout(FixSession_t *session, int8_t value);
+int8_t FixSession_setResetOnDisconnect(FixSession_t *session, int8_t value);
+int8_t FixSession_readPeerAddress(FixSession_t *session, char *buffer, uint64_t buffer_len);
+int8_t FixSession_setSocketOptions(FixSession_t *session, const FixSocketOptions_t *options);
+int8_t FixSession_readSocketOptions(FixSession_t *session, FixSocketOptions_t *options);
+int8_t FixSession_getStoredMessages(FixSession_t *session, uint32_t begin, uint32_t end, void *output,
+                                    void (*push)(void *output, const char *msg, uint64_t len));
 
 #ifdef __cplusplus
 }
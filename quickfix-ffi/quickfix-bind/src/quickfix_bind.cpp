@@ -1,10 +1,31 @@
 #include "quickfix_bind.h"
 
+#include <algorithm>
+#include <atomic>
+#include <chrono>
+#include <condition_variable>
+#include <cstdlib>
 #include <cstring>
 #include <exception>
+#include <filesystem>
+#include <functional>
 #include <iostream>
 #include <limits>
+#include <map>
+#include <memory>
+#include <mutex>
+#include <random>
+#include <set>
+#include <shared_mutex>
+#include <sstream>
+#include <thread>
+#include <typeinfo>
 #include <utility>
+#include <vector>
+
+#if defined(__GNUG__)
+#include <cxxabi.h>
+#endif
 
 #include <quickfix/Application.h>
 #include <quickfix/DataDictionary.h>
@@ -15,17 +36,30 @@
 #include <quickfix/Message.h>
 #include <quickfix/NullStore.h>
 #include <quickfix/Session.h>
+#include <quickfix/SessionFactory.h>
 #include <quickfix/SessionID.h>
 #include <quickfix/SessionSettings.h>
 #include <quickfix/SocketAcceptor.h>
+#include <quickfix/SocketConnection.h>
 #include <quickfix/SocketInitiator.h>
 #include <quickfix/ThreadedSocketAcceptor.h>
+#include <quickfix/ThreadedSocketConnection.h>
 #include <quickfix/ThreadedSocketInitiator.h>
+#include <quickfix/Utility.h>
+
+#ifndef _WIN32
+#include <fcntl.h>
+#include <netinet/tcp.h>
+#include <sys/stat.h>
+#include <sys/un.h>
+#endif
 
 #ifdef HAVE_SSL
 #include <quickfix/SSLSocketAcceptor.h>
+#include <quickfix/SSLSocketConnection.h>
 #include <quickfix/SSLSocketInitiator.h>
 #include <quickfix/ThreadedSSLSocketAcceptor.h>
+#include <quickfix/ThreadedSSLSocketConnection.h>
 #include <quickfix/ThreadedSSLSocketInitiator.h>
 #else
 
@@ -97,10 +131,10 @@ _IMPL_CLASS_STUB(ThreadedSSLSocketInitiator, StubInitiator);
     Fix_setLastError(ex, ERROR_DATA_DICTIONARY_NOT_FOUND);                                                             \
     return (_VAL_);                                                                                                    \
   } catch (FIX::FieldNotFound & ex) {                                                                                  \
-    Fix_setLastError(ex, ERROR_FIELD_NOT_FOUND);                                                                       \
+    Fix_setLastFieldError(ex, ERROR_FIELD_NOT_FOUND, ex.field);                                                        \
     return (_VAL_);                                                                                                    \
   } catch (FIX::FieldConvertError & ex) {                                                                              \
-    Fix_setLastError(ex, ERROR_FIELD_CONVERT_ERROR);                                                                   \
+    Fix_setLastFieldError(ex, ERROR_FIELD_CONVERT_ERROR, 0);                                                           \
     return (_VAL_);                                                                                                    \
   } catch (FIX::MessageParseError & ex) {                                                                              \
     Fix_setLastError(ex, ERROR_MESSAGE_PARSE_ERROR);                                                                   \
@@ -127,7 +161,7 @@ _IMPL_CLASS_STUB(ThreadedSSLSocketInitiator, StubInitiator);
     Fix_setLastError(ex, ERROR_NO_TAG_VALUE);                                                                          \
     return (_VAL_);                                                                                                    \
   } catch (FIX::IncorrectTagValue & ex) {                                                                              \
-    Fix_setLastError(ex, ERROR_INCORRECT_TAG_VALUE);                                                                   \
+    Fix_setLastFieldError(ex, ERROR_INCORRECT_TAG_VALUE, ex.field);                                                    \
     return (_VAL_);                                                                                                    \
   } catch (FIX::IncorrectDataFormat & ex) {                                                                            \
     Fix_setLastError(ex, ERROR_INCORRECT_DATA_FORMAT);                                                                 \
@@ -183,17 +217,46 @@ _IMPL_CLASS_STUB(ThreadedSSLSocketInitiator, StubInitiator);
   } catch (std::exception & e) {                                                                                       \
     Fix_setLastError(e, ERRNO_EXCEPTION);                                                                              \
     return (_VAL_);                                                                                                    \
+  } catch (...) {                                                                                                      \
+    Fix_setLastUnknownError();                                                                                         \
+    return (_VAL_);                                                                                                    \
   }
 
 #define CATCH_OR_RETURN_NULL(_XXX_) CATCH_OR_RETURN(NULL, _XXX_)
 
 #define CATCH_OR_RETURN_ERRNO(_XXX_) CATCH_OR_RETURN(ERRNO_EXCEPTION, _XXX_)
 
+// For functions without result, like destructors and callbacks quickfix does not expect to throw: an exception
+// escaping them would terminate the process, so it is only kept as last error.
+#define CATCH_OR_DISCARD(...)                                                                                          \
+  try {                                                                                                                \
+    __VA_ARGS__                                                                                                        \
+  } catch (std::exception & e) {                                                                                       \
+    Fix_setLastError(e, ERRNO_EXCEPTION);                                                                              \
+  } catch (...) {                                                                                                      \
+    Fix_setLastUnknownError();                                                                                         \
+  }
+
 extern "C" {
 namespace FIX {
 
 static thread_local char *lastError = nullptr;
 static thread_local int8_t lastErrorCode = 0;
+static thread_local int32_t lastErrorField = 0;
+static thread_local std::string lastErrorDetail;
+static thread_local std::string lastErrorType;
+
+// Demangled type name, e.g. `FIX::ConfigError`.
+static std::string Fix_demangleTypeName(const char *name) {
+#if defined(__GNUG__)
+  int status = 0;
+  std::unique_ptr<char, void (*)(void *)> demangled(abi::__cxa_demangle(name, nullptr, nullptr, &status), std::free);
+  if (status == 0 && demangled) {
+    return demangled.get();
+  }
+#endif
+  return name;
+}
 
 static void Fix_setLastError(std::exception &ex, int8_t code) {
   // Release previously set error if any
@@ -201,6 +264,9 @@ static void Fix_setLastError(std::exception &ex, int8_t code) {
 
   // Update last error code
   lastErrorCode = code;
+  lastErrorField = 0;
+  lastErrorDetail.clear();
+  lastErrorType = Fix_demangleTypeName(typeid(ex).name());
 
   // Get error message and copy it to thread local storage.
   std::string msg = ex.what();
@@ -211,10 +277,36 @@ static void Fix_setLastError(std::exception &ex, int8_t code) {
   strncpy(lastError, msg.c_str(), msg.size());
 }
 
+// Same as `Fix_setLastError` for exceptions not inheriting `std::exception`, must be called from a catch block.
+static void Fix_setLastUnknownError() {
+  std::runtime_error ex("Unknown exception");
+  Fix_setLastError(ex, ERRNO_EXCEPTION);
+#if defined(__GNUG__)
+  if (const std::type_info *type = abi::__cxa_current_exception_type()) {
+    lastErrorType = Fix_demangleTypeName(type->name());
+    return;
+  }
+#endif
+  lastErrorType = "unknown";
+}
+
+// Same as `Fix_setLastError`, also keeping tag and detail (offending value for conversion errors) of the exception.
+static void Fix_setLastFieldError(FIX::Exception &ex, int8_t code, int32_t field) {
+  Fix_setLastError(ex, code);
+  lastErrorField = field;
+  lastErrorDetail = ex.detail;
+}
+
 const char *Fix_getLastErrorMessage() { return lastError; }
 
 int8_t Fix_getLastErrorCode() { return lastErrorCode; }
 
+int32_t Fix_getLastErrorField() { return lastErrorField; }
+
+const char *Fix_getLastErrorDetail() { return lastErrorDetail.c_str(); }
+
+const char *Fix_getLastErrorType() { return lastErrorType.c_str(); }
+
 void Fix_clearLastErrorMessage() {
   if (lastError) {
     delete[] lastError;
@@ -247,249 +339,2804 @@ static int8_t FixFieldMap_isFieldEqual(const FieldMap *obj, int32_t tag, const c
     return 0;
   }
 
-  return obj->isFieldEqual(tag, value, static_cast<size_t>(value_len)) ? 1 : 0;
+  CATCH_OR_RETURN(0, { return obj->isFieldEqual(tag, value, static_cast<size_t>(value_len)) ? 1 : 0; });
 }
 
-class ApplicationBind : public Application {
-private:
-  const ApplicationCallbacks *callbacks;
-  const void *data;
-
-public:
-  ApplicationBind(const void *data, const ApplicationCallbacks *callbacks) : callbacks(callbacks), data(data) {}
+static int8_t FixFieldMap_setFieldRaw(FieldMap *obj, int32_t tag, const char *value, uint64_t value_len) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(value, ERRNO_INVAL);
 
-  ApplicationBind(const ApplicationBind &) = delete;
-  ApplicationBind &operator=(const ApplicationBind &) = delete;
+  if (value_len > static_cast<uint64_t>((std::numeric_limits<size_t>::max)())) {
+    return ERRNO_INVAL;
+  }
 
-  virtual ~ApplicationBind() {}
+  CATCH_OR_RETURN_ERRNO({
+    obj->setField(tag, std::string(value, static_cast<size_t>(value_len)));
+    return 0;
+  });
+}
 
-  void onCreate(const SessionID &session) override {
-    RETURN_IF_NULL(callbacks);
-    RETURN_IF_NULL(callbacks->onCreate);
-    callbacks->onCreate(data, &session);
-  }
+// Borrow value of `tag`, valid until field map is mutated or deleted. Return NULL when field is missing.
+static const char *FixFieldMap_getFieldValue(const FieldMap *obj, int32_t tag, uint64_t *value_len) {
+  RETURN_VAL_IF_NULL(obj, NULL);
+  RETURN_VAL_IF_NULL(value_len, NULL);
 
-  void onLogon(const SessionID &session) override {
-    RETURN_IF_NULL(callbacks);
-    RETURN_IF_NULL(callbacks->onLogon);
-    callbacks->onLogon(data, &session);
-  }
+  CATCH_OR_RETURN_NULL({
+    if (!obj->isSetField(tag)) {
+      return NULL;
+    }
+    const std::string &value = obj->getField(tag);
+    *value_len = value.size();
+    return value.data();
+  });
+}
 
-  void onLogout(const SessionID &session) override {
-    RETURN_IF_NULL(callbacks);
-    RETURN_IF_NULL(callbacks->onLogout);
-    callbacks->onLogout(data, &session);
+// Copy values of `tags` back to back into `buffer`, storing their length, or -1 when missing, into `lengths`.
+// Return total length of values: nothing is copied when `buffer_len` is lower, so caller can grow buffer and retry.
+static int64_t FixFieldMap_getFields(const FieldMap *obj, const int32_t *tags, uint64_t tags_len, char *buffer,
+                                     uint64_t buffer_len, int64_t *lengths) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  if (tags_len > 0) {
+    RETURN_VAL_IF_NULL(tags, ERRNO_INVAL);
+    RETURN_VAL_IF_NULL(lengths, ERRNO_INVAL);
   }
 
-  void toAdmin(Message &msg, const SessionID &session) override {
-    RETURN_IF_NULL(callbacks);
-    RETURN_IF_NULL(callbacks->toAdmin);
-    callbacks->toAdmin(data, &msg, &session);
-  }
+  CATCH_OR_RETURN_ERRNO({
+    uint64_t total = 0;
+    for (uint64_t i = 0; i < tags_len; i++) {
+      if (obj->isSetField(tags[i])) {
+        const std::string &value = obj->getField(tags[i]);
+        lengths[i] = static_cast<int64_t>(value.size());
+        total += value.size();
+      } else {
+        lengths[i] = -1;
+      }
+    }
+    if (total > static_cast<uint64_t>((std::numeric_limits<int64_t>::max)())) {
+      return ERRNO_INVAL;
+    }
 
-  void toApp(Message &msg, const SessionID &session) EXCEPT(DoNotSend) override {
-    RETURN_IF_NULL(callbacks);
-    RETURN_IF_NULL(callbacks->toApp);
-    int8_t result = callbacks->toApp(data, &msg, &session);
+    if (buffer != NULL && total <= buffer_len) {
+      uint64_t offset = 0;
+      for (uint64_t i = 0; i < tags_len; i++) {
+        if (lengths[i] > 0) {
+          std::memcpy(buffer + offset, obj->getField(tags[i]).data(), static_cast<size_t>(lengths[i]));
+          offset += static_cast<uint64_t>(lengths[i]);
+        }
+      }
+    }
+    return static_cast<int64_t>(total);
+  });
+}
 
-    if (result == CALLBACK_RESULT_DO_NOT_SEND)
-      throw DoNotSend();
+// Append one copy of `tmpl` per row, row `i` being made of `row_lens[i]` consecutive entries of `tags` / `values` /
+// `value_lens`. Every group is built before the first one is appended, so a failure leaves `obj` unchanged.
+extern "C++" { // Templates cannot have C linkage.
+template <typename T>
+static int8_t FixFieldMap_addGroups(T *obj, const Group *tmpl, const int32_t *tags, const char *const *values,
+                                    const uint64_t *value_lens, const uint64_t *row_lens, uint64_t rows_len) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(tmpl, ERRNO_INVAL);
+  if (rows_len > 0) {
+    RETURN_VAL_IF_NULL(row_lens, ERRNO_INVAL);
+  }
+  if (rows_len > static_cast<uint64_t>((std::numeric_limits<size_t>::max)())) {
+    return ERRNO_INVAL;
   }
 
-  void fromAdmin(Message &&msg, const SessionID &session)
-      EXCEPT(FieldNotFound, IncorrectDataFormat, IncorrectTagValue, RejectLogon) override {
-    RETURN_IF_NULL(callbacks);
-    RETURN_IF_NULL(callbacks->fromAdmin);
-    Message *ownedMessage = new Message(std::move(msg));
-    int8_t result = callbacks->fromAdmin(data, ownedMessage, &session);
+  CATCH_OR_RETURN_ERRNO({
+    std::vector<Group> groups;
+    groups.reserve(static_cast<size_t>(rows_len));
+
+    uint64_t offset = 0;
+    for (uint64_t row = 0; row < rows_len; row++) {
+      // Lengths come from caller: reject them instead of wrapping around.
+      if (row_lens[row] > (std::numeric_limits<uint64_t>::max)() - offset) {
+        return ERRNO_INVAL;
+      }
+      Group group(*tmpl);
+      for (uint64_t i = offset; i < offset + row_lens[row]; i++) {
+        if (tags == NULL || values == NULL || value_lens == NULL || values[i] == NULL) {
+          return ERRNO_INVAL;
+        }
+        if (value_lens[i] > static_cast<uint64_t>((std::numeric_limits<size_t>::max)())) {
+          return ERRNO_INVAL;
+        }
+        group.setField(tags[i], std::string(values[i], static_cast<size_t>(value_lens[i])));
+      }
+      offset += row_lens[row];
+      groups.push_back(group);
+    }
 
-    switch (result) {
-    case CALLBACK_RESULT_FIELD_NOT_FOUND:
-      throw FieldNotFound();
-    case CALLBACK_RESULT_INCORRECT_DATA_FORMAT:
-      throw IncorrectDataFormat();
-    case CALLBACK_RESULT_INCORRECT_TAG_VALUE:
-      throw IncorrectTagValue();
-    case CALLBACK_RESULT_REJECT_LOGON:
-      throw RejectLogon();
+    for (const auto &group : groups) {
+      obj->addGroup(group);
     }
-  }
+    return 0;
+  });
+}
+} // extern C++
 
-  void fromApp(Message &&msg, const SessionID &session)
-      EXCEPT(FieldNotFound, IncorrectDataFormat, IncorrectTagValue, UnsupportedMessageType) override {
-    RETURN_IF_NULL(callbacks);
-    RETURN_IF_NULL(callbacks->fromApp);
-    Message *ownedMessage = new Message(std::move(msg));
-    int8_t result = callbacks->fromApp(data, ownedMessage, &session);
+extern "C++" {
+template <typename T> static void FixConnectionHandler_logoutAll(T *obj, const std::string &reason) {
+  if (obj->isStopped()) {
+    return;
+  }
 
-    switch (result) {
-    case CALLBACK_RESULT_FIELD_NOT_FOUND:
-      throw FieldNotFound();
-    case CALLBACK_RESULT_INCORRECT_DATA_FORMAT:
-      throw IncorrectDataFormat();
-    case CALLBACK_RESULT_INCORRECT_TAG_VALUE:
-      throw IncorrectTagValue();
-    case CALLBACK_RESULT_UNSUPPORTED_MESSAGE_TYPE:
-      throw UnsupportedMessageType();
+  for (const auto &sessionId : obj->getSessions()) {
+    Session *session = obj->getSession(sessionId);
+    if (session && session->isEnabled()) {
+      session->logout(reason);
     }
   }
-};
-
-class ExternalLog : public Log {
-private:
-  const void *data;
-  const SessionID *sessionId;
-  const LogCallbacks *callbacks;
 
-public:
-  ExternalLog(const void *data, const SessionID *sessionId, const LogCallbacks *callbacks)
-      : data(data), sessionId(sessionId), callbacks(callbacks) {}
+  // Let sessions send their logout before `stop()` overrides reason (same 10 seconds timeout as `stop()`).
+  for (int second = 1; second <= 10 && obj->isLoggedOn(); ++second) {
+    process_sleep(1);
+  }
+}
+} // extern C++
 
-  ExternalLog(const ExternalLog &) = delete;
-  ExternalLog &operator=(const ExternalLog &) = delete;
+extern "C++" {
+template <typename T> static void FixConnectionHandler_stopForce(T *obj) {
+  if (obj->isStopped()) {
+    return;
+  }
 
-  virtual ~ExternalLog() {
-    if (sessionId) {
-      delete sessionId;
+  // `stop(true)` does not wait for logout, but the engine thread still waits for logged on sessions to disconnect.
+  // Close their sockets right away, and disable sessions so they do not log on again meanwhile.
+  std::vector<Session *> enabledSessions;
+  for (const auto &sessionId : obj->getSessions()) {
+    Session *session = obj->getSession(sessionId);
+    if (session && session->isEnabled()) {
+      enabledSessions.push_back(session);
+      session->logout();
+    }
+    if (session) {
+      session->disconnect();
     }
   }
 
-  void clear() override {}
-  void backup() override {}
+  obj->stop(true);
 
-  void onIncoming(const std::string &msg) override {
-    (void)msg;
+  // Same as non forced `stop()`, so sessions can log on again on next start.
+  for (Session *session : enabledSessions) {
+    session->logon();
   }
+}
+} // extern C++
 
-  void onOutgoing(const std::string &msg) override {
-    (void)msg;
+static SessionState &FixSession_getState(Session *session) {
+  // Session state is only exposed through the message store interface.
+  const SessionState *state = dynamic_cast<const SessionState *>(session->getStore());
+  if (!state) {
+    throw std::logic_error("Session state is not available");
   }
+  return const_cast<SessionState &>(*state);
+}
 
-  void onEvent(const std::string &msg) override {
-    (void)data;
-    (void)callbacks;
-
-    std::string printableMsg(msg);
-    for (char &ch : printableMsg) {
-      if (ch == '\x01') {
-        ch = '|';
-      }
-    }
+static bool FixSocket_formatAddress(const sockaddr_storage &addr, std::string &address) {
+  char host[INET6_ADDRSTRLEN] = {0};
+  if (addr.ss_family == AF_INET) {
+    const sockaddr_in *addr4 = reinterpret_cast<const sockaddr_in *>(&addr);
+    inet_ntop(AF_INET, &addr4->sin_addr, host, sizeof(host));
+    address = std::string(host) + ":" + std::to_string(ntohs(addr4->sin_port));
+    return true;
+  }
+  if (addr.ss_family == AF_INET6) {
+    const sockaddr_in6 *addr6 = reinterpret_cast<const sockaddr_in6 *>(&addr);
+    inet_ntop(AF_INET6, &addr6->sin6_addr, host, sizeof(host));
+    address = "[" + std::string(host) + "]:" + std::to_string(ntohs(addr6->sin6_port));
+    return true;
+  }
+  return false;
+}
 
-    if (sessionId) {
-      std::cout << "FIX event: " << sessionId->toString() << ": " << printableMsg << '\n';
-    } else {
-      std::cout << "FIX event: <none>: " << printableMsg << '\n';
-    }
+static bool FixSocket_getPeerAddress(socket_handle socket, std::string &address) {
+  sockaddr_storage addr;
+  socklen_t addrLen = sizeof(addr);
+  if (getpeername(socket, reinterpret_cast<sockaddr *>(&addr), &addrLen) != 0) {
+    return false;
   }
-};
+  return FixSocket_formatAddress(addr, address);
+}
 
-class ExternalLogFactory : public LogFactory {
-private:
-  const void *data;
-  const LogCallbacks *callbacks;
+static bool FixSocket_getLocalAddress(socket_handle socket, std::string &address) {
+  sockaddr_storage addr;
+  socklen_t addrLen = sizeof(addr);
+  if (getsockname(socket, reinterpret_cast<sockaddr *>(&addr), &addrLen) != 0) {
+    return false;
+  }
+  return FixSocket_formatAddress(addr, address);
+}
 
-public:
-  ExternalLogFactory(const void *data, const LogCallbacks *callbacks) : data(data), callbacks(callbacks) {}
+// Unix domain socket paths of sessions, used instead of their TCP host / port. Quickfix itself only knows TCP, so
+// these settings are handled by the bind.
+static const char SOCKET_ACCEPT_PATH[] = "SocketAcceptPath";
+static const char SOCKET_CONNECT_PATH[] = "SocketConnectPath";
 
-  ExternalLogFactory(const ExternalLogFactory &) = delete;
-  ExternalLogFactory &operator=(const ExternalLogFactory &) = delete;
+#ifndef _WIN32
+static sockaddr_un FixSocket_pathAddress(const std::string &path) {
+  sockaddr_un addr = {};
+  if (path.empty() || path.size() >= sizeof(addr.sun_path)) {
+    throw ConfigError("Invalid unix socket path \"" + path + "\"");
+  }
+  addr.sun_family = AF_UNIX;
+  memcpy(addr.sun_path, path.c_str(), path.size());
+  return addr;
+}
 
-  virtual ~ExternalLogFactory() {}
+static void FixSocket_checkPath(const std::string &path) { FixSocket_pathAddress(path); }
 
-  Log *create() override { return new ExternalLog(data, NULL, callbacks); }
+// Remove the socket file left behind by a listener which is gone, e.g. a crashed process. Files which are not
+// sockets, and sockets still accepting connections, are never removed.
+static void FixSocket_removeStalePath(const sockaddr_un &addr) {
+  const std::string path = addr.sun_path;
+  struct stat info;
+  if (lstat(addr.sun_path, &info) != 0) {
+    return;
+  }
+  if (!S_ISSOCK(info.st_mode)) {
+    throw RuntimeError("Unable to listen to " + path + ": file exists and is not a socket");
+  }
 
-  Log *create(const SessionID &sessionId) override {
-    auto sessionIdCopy = new SessionID(sessionId);
-    return new ExternalLog(data, sessionIdCopy, callbacks);
+  // Probe without blocking: a listener whose backlog is full is alive too.
+  socket_handle probe = ::socket(AF_UNIX, SOCK_STREAM, 0);
+  if (probe == INVALID_SOCKET_HANDLE) {
+    throw RuntimeError("Unable to create unix socket: " + std::string(strerror(errno)));
+  }
+  fcntl(probe, F_SETFL, O_NONBLOCK);
+  const bool stale =
+      ::connect(probe, reinterpret_cast<const sockaddr *>(&addr), sizeof(addr)) != 0 && errno == ECONNREFUSED;
+  socket_close(probe);
+  if (!stale) {
+    throw RuntimeError("Unable to listen to " + path + ": address already in use");
   }
+  if (unlink(addr.sun_path) != 0 && errno != ENOENT) {
+    throw RuntimeError("Unable to remove stale socket " + path + ": " + strerror(errno));
+  }
+}
 
-  void destroy(Log *log) override { delete log; }
-};
+static socket_handle FixSocket_listenPath(const std::string &path) {
+  const sockaddr_un addr = FixSocket_pathAddress(path);
+  FixSocket_removeStalePath(addr);
 
-SessionSettings *FixSessionSettings_new() {
-  CATCH_OR_RETURN_NULL({ return new SessionSettings(); });
+  socket_handle listener = ::socket(AF_UNIX, SOCK_STREAM, 0);
+  if (listener == INVALID_SOCKET_HANDLE) {
+    throw RuntimeError("Unable to create unix socket: " + std::string(strerror(errno)));
+  }
+  if (::bind(listener, reinterpret_cast<const sockaddr *>(&addr), sizeof(addr)) != 0 ||
+      ::listen(listener, SOMAXCONN) != 0) {
+    const std::string error = strerror(errno);
+    socket_close(listener);
+    throw RuntimeError("Unable to bind or listen to " + path + ": " + error);
+  }
+  return listener;
 }
 
-SessionSettings *FixSessionSettings_fromPath(const char *configPath) {
-  CATCH_OR_RETURN_NULL({ return new SessionSettings(configPath); });
-}
+static void FixSocket_unlinkPath(const std::string &path) { unlink(path.c_str()); }
 
-const Dictionary *FixSessionSettings_getGlobalRef(const SessionSettings *obj) {
-  RETURN_VAL_IF_NULL(obj, NULL);
-  CATCH_OR_RETURN_NULL({ return &obj->get(); });
+// Connect to `path` without blocking. Unix sockets connect at once, or fail with EAGAIN when the backlog of their
+// listener is full: return INVALID_SOCKET_HANDLE with `error` set on failure.
+static socket_handle FixSocket_connectPath(const std::string &path, int &error) {
+  const sockaddr_un addr = FixSocket_pathAddress(path);
+  socket_handle connection = ::socket(AF_UNIX, SOCK_STREAM, 0);
+  if (connection == INVALID_SOCKET_HANDLE) {
+    error = errno;
+    return INVALID_SOCKET_HANDLE;
+  }
+  fcntl(connection, F_SETFL, O_NONBLOCK);
+  if (::connect(connection, reinterpret_cast<const sockaddr *>(&addr), sizeof(addr)) != 0) {
+    error = errno;
+    socket_close(connection);
+    return INVALID_SOCKET_HANDLE;
+  }
+  error = 0;
+  return connection;
+}
+#else
+static void FixSocket_checkPath(const std::string &path) {
+  throw ConfigError("Unix domain sockets are not supported on this platform");
 }
 
-const Dictionary *FixSessionSettings_getSessionRef(const SessionSettings *obj, const SessionID *id) {
-  RETURN_VAL_IF_NULL(obj, NULL);
-  RETURN_VAL_IF_NULL(id, NULL);
-  CATCH_OR_RETURN_NULL({ return &obj->get(*id); });
+static socket_handle FixSocket_listenPath(const std::string &path) {
+  FixSocket_checkPath(path);
+  return INVALID_SOCKET_HANDLE;
 }
 
-int8_t FixSessionSettings_setGlobal(SessionSettings *obj, const Dictionary *value) {
-  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
-  RETURN_VAL_IF_NULL(value, ERRNO_INVAL);
+static void FixSocket_unlinkPath(const std::string &path) {}
 
-  CATCH_OR_RETURN_ERRNO({
-    obj->set(*value);
-    return 0;
-  })
+static socket_handle FixSocket_connectPath(const std::string &path, int &error) {
+  FixSocket_checkPath(path);
+  return INVALID_SOCKET_HANDLE;
+}
+#endif // _WIN32
+
+// Check paths of sessions configured with `key`, failing when handler cannot use them (`supported` is false).
+static void FixSessionSettings_checkPaths(const SessionSettings &settings, const char *key, bool supported) {
+  for (const auto &sessionId : settings.getSessions()) {
+    const Dictionary &dict = settings.get(sessionId);
+    if (!dict.has(key)) {
+      continue;
+    }
+    FixSocket_checkPath(dict.getString(key));
+    if (!supported) {
+      throw ConfigError(std::string(key) + " is only supported by single threaded handlers without SSL");
+    }
+  }
 }
 
-int8_t FixSessionSettings_setSession(SessionSettings *obj, const SessionID *id, const Dictionary *value) {
-  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
-  RETURN_VAL_IF_NULL(id, ERRNO_INVAL);
-  RETURN_VAL_IF_NULL(value, ERRNO_INVAL);
+static bool FixSessionSettings_hasKey(const SessionSettings &settings, const char *key) {
+  for (const auto &sessionId : settings.getSessions()) {
+    if (settings.get(sessionId).has(key)) {
+      return true;
+    }
+  }
+  return false;
+}
 
-  CATCH_OR_RETURN_ERRNO({
-    obj->set(*id, *value);
-    return 0;
-  })
+// Copy of `settings` without sessions configured with `key`, for quickfix implementations expecting TCP settings.
+static SessionSettings FixSessionSettings_withoutKey(const SessionSettings &settings, const char *key) {
+  SessionSettings result;
+  result.set(settings.get());
+  for (const auto &sessionId : settings.getSessions()) {
+    const Dictionary &dict = settings.get(sessionId);
+    if (!dict.has(key)) {
+      result.set(sessionId, dict);
+    }
+  }
+  return result;
 }
 
-void FixSessionSettings_delete(const SessionSettings *obj) {
-  RETURN_IF_NULL(obj);
-  delete obj;
+extern "C++" {
+template <typename T>
+static bool FixAcceptor_tryGetServerSockets(const Acceptor *obj, std::vector<socket_handle> &sockets) {
+  const T *acceptor = dynamic_cast<const T *>(obj);
+  if (!acceptor) {
+    return false;
+  }
+
+  SocketServer *server = acceptor->bindServer();
+  if (!server) {
+    return true;
+  }
+
+  // Server only knows configured ports, which may be 0 when port is assigned by the OS.
+  std::set<int> ports;
+  for (const auto &sessionId : obj->getSessions()) {
+    const Dictionary *settings = obj->getSessionSettings(sessionId);
+    if (settings && settings->has(SOCKET_ACCEPT_PORT)) {
+      ports.insert(settings->getInt(SOCKET_ACCEPT_PORT));
+    }
+  }
+  for (int port : ports) {
+    socket_handle socket = server->portToSocket(port);
+    if (socket != INVALID_SOCKET_HANDLE) {
+      sockets.push_back(socket);
+    }
+  }
+  return true;
 }
+} // extern C++
+
+extern "C++" {
+template <typename T>
+static bool FixAcceptor_tryGetThreadedSockets(const Acceptor *obj, std::vector<socket_handle> &sockets) {
+  const T *acceptor = dynamic_cast<const T *>(obj);
+  if (!acceptor) {
+    return false;
+  }
 
-Dictionary *FixDictionary_new(const char *name) {
-  CATCH_OR_RETURN_NULL({ return new Dictionary(name); });
+  const auto &listening = acceptor->bindSockets();
+  sockets.insert(sockets.end(), listening.begin(), listening.end());
+  return true;
 }
+} // extern C++
+
+// Get addresses the acceptor is listening on, empty when it is not running.
+// Acceptors release their listening sockets once stopped, so only live sockets are reported.
+static std::vector<std::string> FixAcceptor_getListenEndpoints(const Acceptor *obj) {
+  std::vector<std::string> endpoints;
+  std::vector<socket_handle> sockets;
+  bool found = false;
+#ifdef HAVE_SSL
+  found = FixAcceptor_tryGetServerSockets<SSLSocketAcceptor>(obj, sockets) ||
+          FixAcceptor_tryGetThreadedSockets<ThreadedSSLSocketAcceptor>(obj, sockets);
+#endif // HAVE_SSL
+  found = found || FixAcceptor_tryGetServerSockets<SocketAcceptor>(obj, sockets) ||
+          FixAcceptor_tryGetThreadedSockets<ThreadedSocketAcceptor>(obj, sockets);
+  if (!found) {
+    throw RuntimeError("Unsupported acceptor implementation");
+  }
 
-int8_t FixDictionary_setString(Dictionary *obj, const char *key, const char *value) {
-  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
-  RETURN_VAL_IF_NULL(key, ERRNO_INVAL);
-  RETURN_VAL_IF_NULL(value, ERRNO_INVAL);
+  for (socket_handle socket : sockets) {
+    std::string address;
+    if (FixSocket_getLocalAddress(socket, address)) {
+      endpoints.push_back(address);
+    }
+  }
+  return endpoints;
+}
 
-  CATCH_OR_RETURN_ERRNO({
-    obj->setString(key, value);
-    return 0;
-  })
+static int64_t FixSession_nowMillis() {
+  return std::chrono::duration_cast<std::chrono::milliseconds>(std::chrono::system_clock::now().time_since_epoch())
+      .count();
 }
 
-int8_t FixDictionary_setInt(Dictionary *obj, const char *key, int32_t value) {
-  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
-  RETURN_VAL_IF_NULL(key, ERRNO_INVAL);
+// Bind bookkeeping of a session is keyed by the handler owning it too, so sessions with the same id in different
+// handlers never share it. Entries are erased once their session is removed or their handler deleted.
+struct FixHandlerState;
+class ApplicationBind;
+typedef std::pair<FixHandlerState *, SessionID> FixSessionKey;
 
-  CATCH_OR_RETURN_ERRNO({
-    obj->setInt(key, value);
-    return 0;
-  })
+// Handler of every session, registered from `onCreate` until session is removed or its handler deleted.
+static std::mutex sessionHandlerStatesMutex;
+static std::map<const Session *, FixHandlerState *> sessionHandlerStates;
+
+// Handler whose sessions are being created on this thread.
+static thread_local FixHandlerState *creatingHandlerState = nullptr;
+
+static void FixSession_registerHandlerState(const Session *session, FixHandlerState *state) {
+  std::lock_guard<std::mutex> lock(sessionHandlerStatesMutex);
+  // Lookup by id finds the session of another handler when it already has the same id: keep its state.
+  sessionHandlerStates.emplace(session, state);
 }
 
-int8_t FixDictionary_setDouble(Dictionary *obj, const char *key, double value) {
-  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
-  RETURN_VAL_IF_NULL(key, ERRNO_INVAL);
+static void FixSession_unregisterHandlerState(const Session *session) {
+  std::lock_guard<std::mutex> lock(sessionHandlerStatesMutex);
+  sessionHandlerStates.erase(session);
+}
 
-  CATCH_OR_RETURN_ERRNO({
-    obj->setDouble(key, value);
-    return 0;
-  })
+// Return false when session is not registered, e.g. it has been removed.
+static bool FixSession_findKey(const Session *session, FixSessionKey &key) {
+  std::lock_guard<std::mutex> lock(sessionHandlerStatesMutex);
+  auto it = sessionHandlerStates.find(session);
+  if (it == sessionHandlerStates.end()) {
+    return false;
+  }
+  key = FixSessionKey(it->second, session->getSessionID());
+  return true;
 }
 
-int8_t FixDictionary_setBool(Dictionary *obj, const char *key, int8_t value) {
-  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
-  RETURN_VAL_IF_NULL(key, ERRNO_INVAL);
+// Return null when session is not found or not registered.
+static Session *FixSession_findKeyById(const SessionID &sessionId, FixSessionKey &key) {
+  Session *session = Session::lookupSession(sessionId);
+  return session && FixSession_findKey(session, key) ? session : nullptr;
+}
+
+// Register sessions created by handler on this thread until scope ends.
+class FixHandlerStateScope {
+  FixHandlerState *previous;
+
+public:
+  explicit FixHandlerStateScope(FixHandlerState *state) : previous(creatingHandlerState) {
+    creatingHandlerState = state;
+  }
+  ~FixHandlerStateScope() { creatingHandlerState = previous; }
+};
+
+// Sequence numbers of application messages persisted by a session but not written to the socket yet (ie: sent while
+// logged off), until counterparty gets them resent after next logon.
+static std::mutex outboundQueueMutex;
+static std::map<FixSessionKey, std::set<int>> outboundQueue;
+
+// Read integer value of `tag` from a serialized message.
+static bool FixRaw_getIntField(const std::string &raw, const std::string &tag, int &value) {
+  const std::string key = std::string(1, '\x01') + tag + "=";
+  const size_t start = raw.find(key);
+  if (start == std::string::npos) {
+    return false;
+  }
+  value = std::atoi(raw.c_str() + start + key.size());
+  return true;
+}
+
+static bool FixRaw_hasField(const std::string &raw, const std::string &tag, const std::string &value) {
+  return raw.find(std::string(1, '\x01') + tag + "=" + value + '\x01') != std::string::npos;
+}
+
+// Called from `toApp`, once message is accepted: quickfix persists it, then writes it only if logged on.
+static void FixSession_recordQueued(Session *session, const FixSessionKey &key, const Message &msg) {
+  MsgSeqNum seqNum;
+  // Messages not persisted are never resent, so they are not waiting for anything.
+  if (!session->getPersistMessages() || !msg.getHeader().getFieldIfSet(seqNum)) {
+    return;
+  }
+
+  std::lock_guard<std::mutex> lock(outboundQueueMutex);
+  outboundQueue[key].insert(seqNum.getValue());
+}
+
+// Called with every message written to the socket.
+static void FixSession_recordWritten(const FixSessionKey &key, const std::string &raw) {
+  int seqNum = 0;
+  if (!FixRaw_getIntField(raw, "34", seqNum)) {
+    return;
+  }
+
+  std::lock_guard<std::mutex> lock(outboundQueueMutex);
+  auto it = outboundQueue.find(key);
+  if (it == outboundQueue.end()) {
+    return;
+  }
+
+  std::set<int> &queue = it->second;
+  int newSeqNum = 0;
+  if (FixRaw_hasField(raw, "35", MsgType_Logon) && FixRaw_hasField(raw, "141", "Y")) {
+    // Sequence numbers are reset, so previous messages will never be resent.
+    queue.clear();
+  } else if (FixRaw_hasField(raw, "35", MsgType_SequenceReset) && FixRaw_getIntField(raw, "36", newSeqNum)) {
+    // Gap fill skips messages not worth resending.
+    queue.erase(queue.lower_bound(seqNum), queue.lower_bound(newSeqNum));
+  } else {
+    queue.erase(seqNum);
+  }
+}
+
+static void FixSession_clearOutboundQueue(const FixSessionKey &key) {
+  std::lock_guard<std::mutex> lock(outboundQueueMutex);
+  outboundQueue.erase(key);
+}
+
+static int64_t FixSession_countOutboundQueue(const FixSessionKey &key) {
+  std::lock_guard<std::mutex> lock(outboundQueueMutex);
+  auto it = outboundQueue.find(key);
+  return it == outboundQueue.end() ? 0 : it->second.size();
+}
+
+// Connections derive privately from `Responder`, which `dynamic_cast` cannot cross: compare exact types instead.
+extern "C++" {
+template <typename T> static bool FixResponder_tryGetSocket(Responder *responder, socket_handle &socket) {
+  if (typeid(*responder) != typeid(T)) {
+    return false;
+  }
+  socket = T::bindFromResponder(responder)->getSocket();
+  return true;
+}
+} // extern C++
+
+// Socket of a connection, quickfix does not expose it through `Responder`.
+static bool FixResponder_getSocket(Responder *responder, socket_handle &socket) {
+  if (!responder) {
+    return false;
+  }
+#ifdef HAVE_SSL
+  if (FixResponder_tryGetSocket<SSLSocketConnection>(responder, socket) ||
+      FixResponder_tryGetSocket<ThreadedSSLSocketConnection>(responder, socket)) {
+    return true;
+  }
+#endif // HAVE_SSL
+  return FixResponder_tryGetSocket<SocketConnection>(responder, socket) ||
+         FixResponder_tryGetSocket<ThreadedSocketConnection>(responder, socket);
+}
+
+// Defined with session lifecycles.
+static bool FixSession_recordTimeout(Session *session);
+// Defined with application callbacks.
+static void FixSession_notifyDisconnect(const FixSessionKey &key, int8_t reason);
+
+// Tell whether counterparty closed or reset the connection, peeking at the socket without consuming anything.
+static bool FixSocket_isClosedByPeer(socket_handle socket) {
+#ifndef _WIN32
+  char byte;
+  const ssize_t read = recv(socket, &byte, 1, MSG_PEEK | MSG_DONTWAIT);
+  return read == 0 || (read < 0 && errno != EAGAIN && errno != EWOULDBLOCK);
+#else
+  return false;
+#endif // _WIN32
+}
+
+// Installed in place of the session responder, so bytes written to the socket are counted, admin messages included.
+// Bytes read are counted by `FixTapLog`, which quickfix feeds with raw data read from the socket.
+// Connection is only written and closed through it once attached, so its mutex guards the socket against being closed
+// while it is used from another thread.
+class FixTransportTracker : public Responder {
+  const FixSessionKey key;
+  mutable std::mutex mutex;
+  Responder *inner = nullptr;
+  // Attached connection is connected, so closing it is notified.
+  bool connected = false;
+  // Time the connection not attached yet has been established, 0 when unknown.
+  std::atomic<int64_t> pendingConnectAt{0};
+
+public:
+  std::atomic<uint64_t> bytesSent{0};
+  std::atomic<uint64_t> bytesReceived{0};
+  std::atomic<uint64_t> connectCount{0};
+  std::atomic<int64_t> lastConnectAt{0};
+
+  explicit FixTransportTracker(const FixSessionKey &key) : key(key) {}
+
+  // Record when the next connection has been established, `overwrite` keeping the earliest time when false.
+  void setPendingConnectAt(int64_t at, bool overwrite) {
+    int64_t expected = 0;
+    if (overwrite) {
+      pendingConnectAt = at;
+    } else {
+      pendingConnectAt.compare_exchange_strong(expected, at);
+    }
+  }
+
+  // Wrap new session connection, counted only when its socket is actually connected.
+  void attach(Responder *responder, bool connected) {
+    {
+      std::lock_guard<std::mutex> lock(mutex);
+      inner = responder;
+      this->connected = connected;
+    }
+    const int64_t connectAt = pendingConnectAt.exchange(0);
+    if (connected) {
+      ++connectCount;
+      lastConnectAt = connectAt > 0 ? connectAt : FixSession_nowMillis();
+    }
+  }
+
+  // Call `f` with socket of the attached connection, return false when there is none.
+  bool withSocket(const std::function<void(socket_handle)> &f) const {
+    std::lock_guard<std::mutex> lock(mutex);
+    socket_handle socket;
+    if (!inner || !FixResponder_getSocket(inner, socket)) {
+      return false;
+    }
+    f(socket);
+    return true;
+  }
+
+  bool send(const std::string &data) override {
+    std::lock_guard<std::mutex> lock(mutex);
+    if (!inner) {
+      return false;
+    }
+    const bool sent = inner->send(data);
+    if (sent) {
+      bytesSent += data.size();
+      FixSession_recordWritten(key, data);
+    }
+    return sent;
+  }
+
+  void disconnect() override {
+    // Called by quickfix with session mutex held, before it resets session state.
+    Session *session = Session::lookupSession(key.second);
+    int8_t reason = session && FixSession_recordTimeout(session) ? DISCONNECT_REASON_TIMEOUT : DISCONNECT_REASON_LOCAL;
+
+    bool notify = false;
+    {
+      // Lock is held until socket is closed.
+      std::lock_guard<std::mutex> lock(mutex);
+      Responder *responder = inner;
+      inner = nullptr;
+      if (!responder) {
+        return;
+      }
+
+      // quickfix also closes connections counterparty closed, which are still readable.
+      socket_handle socket;
+      if (reason == DISCONNECT_REASON_LOCAL && FixResponder_getSocket(responder, socket) &&
+          FixSocket_isClosedByPeer(socket)) {
+        reason = DISCONNECT_REASON_REMOTE;
+      }
+      notify = connected;
+      connected = false;
+      responder->disconnect();
+    }
+
+    if (notify) {
+      FixSession_notifyDisconnect(key, reason);
+    }
+  }
+};
+
+#if defined(TCP_KEEPIDLE)
+#define FIX_TCP_KEEPIDLE TCP_KEEPIDLE
+#elif defined(TCP_KEEPALIVE)
+// macOS name for the same option.
+#define FIX_TCP_KEEPIDLE TCP_KEEPALIVE
+#endif
+
+// Options set with `FixSession_mergeSocketOptions`, applied again on every new connection of the session.
+static std::mutex socketOptionsMutex;
+static std::map<FixSessionKey, SocketOptions> socketOptions;
+
+static SocketOptions FixSocketOptions_unset() { return SocketOptions{-1, -1, -1, -1, -1, -1, -1}; }
+
+// Fail before touching any socket, so an unsupported option is never half applied.
+static void FixSocketOptions_check(const SocketOptions &options) {
+#ifndef FIX_TCP_KEEPIDLE
+  if (options.keepaliveIdle >= 0) {
+    throw RuntimeError("TCP keepalive idle time is not supported on this platform");
+  }
+#endif
+#ifndef TCP_KEEPINTVL
+  if (options.keepaliveInterval >= 0) {
+    throw RuntimeError("TCP keepalive interval is not supported on this platform");
+  }
+#endif
+#ifndef TCP_KEEPCNT
+  if (options.keepaliveCount >= 0) {
+    throw RuntimeError("TCP keepalive count is not supported on this platform");
+  }
+#endif
+  (void)options;
+}
+
+static void FixSocketOptions_merge(SocketOptions &target, const SocketOptions &options) {
+  auto mergeField = [](auto &dst, auto src) {
+    if (src >= 0) {
+      dst = src;
+    }
+  };
+  mergeField(target.nodelay, options.nodelay);
+  mergeField(target.keepalive, options.keepalive);
+  mergeField(target.keepaliveIdle, options.keepaliveIdle);
+  mergeField(target.keepaliveInterval, options.keepaliveInterval);
+  mergeField(target.keepaliveCount, options.keepaliveCount);
+  mergeField(target.sendBufferSize, options.sendBufferSize);
+  mergeField(target.receiveBufferSize, options.receiveBufferSize);
+}
+
+static void FixSocket_setIntOption(socket_handle socket, int level, int name, int value, const char *label) {
+  if (setsockopt(socket, level, name, reinterpret_cast<const char *>(&value), sizeof(value)) != 0) {
+    throw RuntimeError(std::string("Cannot set ") + label + ": " + strerror(errno));
+  }
+}
+
+static int FixSocket_getIntOption(socket_handle socket, int level, int name, const char *label) {
+  int value = 0;
+  socklen_t valueLen = sizeof(value);
+  if (getsockopt(socket, level, name, reinterpret_cast<char *>(&value), &valueLen) != 0) {
+    throw RuntimeError(std::string("Cannot get ") + label + ": " + strerror(errno));
+  }
+  return value;
+}
+
+static void FixSocket_applyOptions(socket_handle socket, const SocketOptions &options) {
+  FixSocketOptions_check(options);
+
+  if (options.nodelay >= 0) {
+    FixSocket_setIntOption(socket, IPPROTO_TCP, TCP_NODELAY, options.nodelay, "TCP_NODELAY");
+  }
+  if (options.keepalive >= 0) {
+    FixSocket_setIntOption(socket, SOL_SOCKET, SO_KEEPALIVE, options.keepalive, "SO_KEEPALIVE");
+  }
+#ifdef FIX_TCP_KEEPIDLE
+  if (options.keepaliveIdle >= 0) {
+    FixSocket_setIntOption(socket, IPPROTO_TCP, FIX_TCP_KEEPIDLE, options.keepaliveIdle, "TCP_KEEPIDLE");
+  }
+#endif
+#ifdef TCP_KEEPINTVL
+  if (options.keepaliveInterval >= 0) {
+    FixSocket_setIntOption(socket, IPPROTO_TCP, TCP_KEEPINTVL, options.keepaliveInterval, "TCP_KEEPINTVL");
+  }
+#endif
+#ifdef TCP_KEEPCNT
+  if (options.keepaliveCount >= 0) {
+    FixSocket_setIntOption(socket, IPPROTO_TCP, TCP_KEEPCNT, options.keepaliveCount, "TCP_KEEPCNT");
+  }
+#endif
+  if (options.sendBufferSize >= 0) {
+    FixSocket_setIntOption(socket, SOL_SOCKET, SO_SNDBUF, options.sendBufferSize, "SO_SNDBUF");
+  }
+  if (options.receiveBufferSize >= 0) {
+    FixSocket_setIntOption(socket, SOL_SOCKET, SO_RCVBUF, options.receiveBufferSize, "SO_RCVBUF");
+  }
+}
+
+static SocketOptions FixSocket_readOptions(socket_handle socket) {
+  SocketOptions options = FixSocketOptions_unset();
+  options.nodelay = FixSocket_getIntOption(socket, IPPROTO_TCP, TCP_NODELAY, "TCP_NODELAY") != 0;
+  options.keepalive = FixSocket_getIntOption(socket, SOL_SOCKET, SO_KEEPALIVE, "SO_KEEPALIVE") != 0;
+#ifdef FIX_TCP_KEEPIDLE
+  options.keepaliveIdle = FixSocket_getIntOption(socket, IPPROTO_TCP, FIX_TCP_KEEPIDLE, "TCP_KEEPIDLE");
+#endif
+#ifdef TCP_KEEPINTVL
+  options.keepaliveInterval = FixSocket_getIntOption(socket, IPPROTO_TCP, TCP_KEEPINTVL, "TCP_KEEPINTVL");
+#endif
+#ifdef TCP_KEEPCNT
+  options.keepaliveCount = FixSocket_getIntOption(socket, IPPROTO_TCP, TCP_KEEPCNT, "TCP_KEEPCNT");
+#endif
+  options.sendBufferSize = FixSocket_getIntOption(socket, SOL_SOCKET, SO_SNDBUF, "SO_SNDBUF");
+  options.receiveBufferSize = FixSocket_getIntOption(socket, SOL_SOCKET, SO_RCVBUF, "SO_RCVBUF");
+  return options;
+}
+
+// Apply remembered options to a new connection. They have been checked when set, so failures are not reported.
+static void FixSession_applyRememberedSocketOptions(const FixSessionKey &key, Responder *responder) {
+  SocketOptions options;
+  {
+    std::lock_guard<std::mutex> lock(socketOptionsMutex);
+    auto it = socketOptions.find(key);
+    if (it == socketOptions.end()) {
+      return;
+    }
+    options = it->second;
+  }
+
+  socket_handle socket;
+  if (FixResponder_getSocket(responder, socket)) {
+    try {
+      FixSocket_applyOptions(socket, options);
+    } catch (std::exception &) {
+    }
+  }
+}
+
+// Trackers are kept until their session is removed, so counters are never reset.
+static std::mutex transportTrackersMutex;
+static std::map<FixSessionKey, std::unique_ptr<FixTransportTracker>> transportTrackers;
+
+static FixTransportTracker &FixSession_getTransportTracker(const FixSessionKey &key) {
+  std::lock_guard<std::mutex> lock(transportTrackersMutex);
+  std::unique_ptr<FixTransportTracker> &tracker = transportTrackers[key];
+  if (!tracker) {
+    tracker.reset(new FixTransportTracker(key));
+  }
+  return *tracker;
+}
+
+// Connection attempts of initiator sessions: started by `FixConnectHooks` when initiator connects a session, and
+// completed once its socket connects or fails to, or once it is handed to the session.
+struct FixConnectAttempt {
+  bool inProgress = false;
+  uint32_t failures = 0;
+};
+
+// Failed connection attempt, to report to the application.
+struct FixConnectFailure {
+  uint32_t attempt = 0;
+  int8_t errorKind = CONNECT_ERROR_OTHER;
+  std::string message;
+};
+
+static std::mutex connectAttemptsMutex;
+static std::map<FixSessionKey, FixConnectAttempt> connectAttempts;
+
+static int8_t FixSocket_classifyConnectError(int error, std::string &message) {
+#ifndef _WIN32
+  message = strerror(error);
+  switch (error) {
+  case ECONNREFUSED:
+    return CONNECT_ERROR_REFUSED;
+  case ETIMEDOUT:
+    return CONNECT_ERROR_TIMED_OUT;
+  case ENETUNREACH:
+  case EHOSTUNREACH:
+    return CONNECT_ERROR_UNREACHABLE;
+  case ENOTCONN:
+    // Quickfix only skips connecting the socket when counterparty host cannot be resolved.
+    message = "Cannot resolve counterparty host";
+    return CONNECT_ERROR_DNS;
+  default:
+    return CONNECT_ERROR_OTHER;
+  }
+#else
+  message = "Connection failed (error " + std::to_string(error) + ")";
+  return CONNECT_ERROR_OTHER;
+#endif // _WIN32
+}
+
+// Get why connecting `socket` failed, 0 when it is connected.
+static int FixSocket_getConnectError(socket_handle socket) {
+  int error = 0;
+  socklen_t errorLen = sizeof(error);
+  if (getsockopt(socket, SOL_SOCKET, SO_ERROR, reinterpret_cast<char *>(&error), &errorLen) != 0) {
+    return errno;
+  }
+  if (error != 0) {
+    return error;
+  }
+
+  sockaddr_storage addr = {};
+  socklen_t addrLen = sizeof(addr);
+  return getpeername(socket, reinterpret_cast<sockaddr *>(&addr), &addrLen) == 0 ? 0 : errno;
+}
+
+// Start a connection attempt of a session. Return consecutive failures when previous attempt never completed, 0
+// otherwise: threaded initiators give up on failed connects without handing their socket to anyone.
+static uint32_t FixSession_beginConnectAttempt(const FixSessionKey &key) {
+  std::lock_guard<std::mutex> lock(connectAttemptsMutex);
+  FixConnectAttempt &attempt = connectAttempts[key];
+  const uint32_t failures = attempt.inProgress ? ++attempt.failures : 0;
+  attempt.inProgress = true;
+  return failures;
+}
+
+// Complete pending connection attempt of a session with `error` read from its socket, 0 when it is connected.
+// Return true when attempt failed, described in `failure`.
+static bool FixSession_completeConnectAttempt(const FixSessionKey &key, int error, FixConnectFailure &failure) {
+  std::lock_guard<std::mutex> lock(connectAttemptsMutex);
+  auto it = connectAttempts.find(key);
+  if (it == connectAttempts.end() || !it->second.inProgress) {
+    return false;
+  }
+
+  FixConnectAttempt &attempt = it->second;
+  attempt.inProgress = false;
+  if (error == 0) {
+    attempt.failures = 0;
+    return false;
+  }
+  failure.attempt = ++attempt.failures;
+  failure.errorKind = FixSocket_classifyConnectError(error, failure.message);
+  return true;
+}
+
+// Called before every admin message is sent: first message of a connection is always an admin one (Logon / Logout),
+// so new connections are wrapped before anything is written to them.
+// Return true when a new connected connection has been attached, with counterparty address in `peerAddress` (empty
+// when unknown).
+static bool FixSession_trackTransport(Session *session, const FixSessionKey &key, std::string &peerAddress) {
+  FixTransportTracker &tracker = FixSession_getTransportTracker(key);
+  Responder *&responder = session->bindResponder();
+  if (responder && responder != &tracker) {
+    socket_handle socket;
+    const int error = FixResponder_getSocket(responder, socket) ? FixSocket_getConnectError(socket) : 0;
+    // Socket initiators completed their attempt already, threaded ones only hand connected sockets over.
+    FixConnectFailure failure;
+    FixSession_completeConnectAttempt(key, error, failure);
+    FixSession_applyRememberedSocketOptions(key, responder);
+    tracker.attach(responder, error == 0);
+    responder = &tracker;
+    if (error == 0) {
+      FixSocket_getPeerAddress(socket, peerAddress);
+      return true;
+    }
+  }
+  return false;
+}
+
+// Called by `FixTapLog` with raw data of every message read from the session socket, garbled ones included.
+static void FixSession_recordIncoming(const SessionID &sessionId, const std::string &data) {
+  FixSessionKey key;
+  Session *session = FixSession_findKeyById(sessionId, key);
+  if (!session) {
+    return;
+  }
+  FixTransportTracker &tracker = FixSession_getTransportTracker(key);
+  tracker.bytesReceived += data.size();
+
+  // Acceptors only bind a connection to its session once a message is read from it. Session mutex is held by
+  // quickfix while reading, so responder can be checked.
+  if (session->bindResponder() != &tracker) {
+    tracker.setPendingConnectAt(FixSession_nowMillis(), false);
+  }
+}
+
+// Merged settings of every session, so that they can be read from any callback.
+static std::mutex sessionDictionariesMutex;
+static std::map<SessionID, Dictionary> sessionDictionaries;
+
+static void FixSession_setDictionary(const SessionID &sessionId, const Dictionary &dict) {
+  std::lock_guard<std::mutex> lock(sessionDictionariesMutex);
+  sessionDictionaries[sessionId] = dict;
+}
+
+// Must be called before creating handler: sessions are created, and `onCreate` called, from its constructor.
+static void FixSession_registerDictionaries(const SessionSettings &settings) {
+  for (const auto &sessionId : settings.getSessions()) {
+    FixSession_setDictionary(sessionId, settings.get(sessionId));
+  }
+}
+
+static void FixSession_releaseDictionaries(const std::set<SessionID> &sessionIds) {
+  std::lock_guard<std::mutex> lock(sessionDictionariesMutex);
+  for (const auto &sessionId : sessionIds) {
+    sessionDictionaries.erase(sessionId);
+  }
+}
+
+// Session connection is read through its transport tracker, which holds its lock while the connection is closed.
+// Connections not attached yet (i.e. before session has exchanged any message) are not visible.
+static bool FixSession_getPeerAddress(Session *session, std::string &address) {
+  FixSessionKey key;
+  if (!FixSession_findKey(session, key)) {
+    return false;
+  }
+  bool found = false;
+  FixSession_getTransportTracker(key).withSocket([&](socket_handle socket) {
+    found = FixSocket_getPeerAddress(socket, address);
+  });
+  return found;
+}
+
+static void FixSession_mergeSocketOptions(Session *session, const SocketOptions &options) {
+  FixSocketOptions_check(options);
+  FixSessionKey key;
+  if (!FixSession_findKey(session, key)) {
+    throw SessionNotFound("Session has been removed");
+  }
+
+  FixSession_getTransportTracker(key).withSocket([&](socket_handle socket) {
+    FixSocket_applyOptions(socket, options);
+  });
+
+  std::lock_guard<std::mutex> lock(socketOptionsMutex);
+  auto it = socketOptions.emplace(key, FixSocketOptions_unset()).first;
+  FixSocketOptions_merge(it->second, options);
+}
+
+static bool FixSession_getSocketOptions(Session *session, SocketOptions &options) {
+  FixSessionKey key;
+  if (!FixSession_findKey(session, key)) {
+    return false;
+  }
+  return FixSession_getTransportTracker(key).withSocket([&](socket_handle socket) {
+    options = FixSocket_readOptions(socket);
+  });
+}
+
+// Same as `Dictionary::getString` but keep `ConfigError` for missing key only, so conversion errors can be reported
+// as `FieldConvertError`.
+static std::string FixDictionary_getExistingString(const Dictionary &obj, const char *key) {
+  if (!obj.has(key)) {
+    throw ConfigError(std::string(key) + " not defined");
+  }
+  return obj.getString(key);
+}
+
+// Copy `value` to a C buffer. Return 1 on success, like other `FixDataDictionary_read*` functions.
+static int8_t FixDataDictionary_copyToBuffer(const std::string &value, char *buffer, uint64_t buffer_len) {
+  if (buffer_len <= value.size()) {
+    return ERRNO_BUFFER_TO_SMALL;
+  }
+
+  strncpy(buffer, value.c_str(), buffer_len);
+  buffer[value.size()] = '\0';
+  return 1;
+}
+
+// Same names as the `type` attribute of the XML spec files.
+static const char *FixDataDictionary_typeName(TYPE::Type type) {
+  switch (type) {
+  case TYPE::String:
+    return "STRING";
+  case TYPE::Char:
+    return "CHAR";
+  case TYPE::Price:
+    return "PRICE";
+  case TYPE::Int:
+    return "INT";
+  case TYPE::Amt:
+    return "AMT";
+  case TYPE::Qty:
+    return "QTY";
+  case TYPE::Currency:
+    return "CURRENCY";
+  case TYPE::MultipleValueString:
+    return "MULTIPLEVALUESTRING";
+  case TYPE::MultipleStringValue:
+    return "MULTIPLESTRINGVALUE";
+  case TYPE::MultipleCharValue:
+    return "MULTIPLECHARVALUE";
+  case TYPE::Exchange:
+    return "EXCHANGE";
+  case TYPE::UtcTimeStamp:
+    return "UTCTIMESTAMP";
+  case TYPE::Boolean:
+    return "BOOLEAN";
+  case TYPE::LocalMktDate:
+    return "LOCALMKTDATE";
+  case TYPE::Data:
+    return "DATA";
+  case TYPE::Float:
+    return "FLOAT";
+  case TYPE::PriceOffset:
+    return "PRICEOFFSET";
+  case TYPE::MonthYear:
+    return "MONTHYEAR";
+  case TYPE::DayOfMonth:
+    return "DAYOFMONTH";
+  case TYPE::UtcDate:
+    return "UTCDATE";
+  case TYPE::UtcDateOnly:
+    return "UTCDATEONLY";
+  case TYPE::UtcTimeOnly:
+    return "UTCTIMEONLY";
+  case TYPE::NumInGroup:
+    return "NUMINGROUP";
+  case TYPE::Percentage:
+    return "PERCENTAGE";
+  case TYPE::SeqNum:
+    return "SEQNUM";
+  case TYPE::Length:
+    return "LENGTH";
+  case TYPE::Country:
+    return "COUNTRY";
+  case TYPE::TzTimeOnly:
+    return "TZTIMEONLY";
+  case TYPE::TzTimeStamp:
+    return "TZTIMESTAMP";
+  case TYPE::XmlData:
+    return "XMLDATA";
+  case TYPE::Language:
+    return "LANGUAGE";
+  default:
+    return "UNKNOWN";
+  }
+}
+
+static bool FixSession_sendToSession(Message &msg, const SessionID &sessionId) {
+  Session *session = Session::lookupSession(sessionId);
+  if (!session) {
+    // Keep looked up session in error message so caller knows which one is missing.
+    throw SessionNotFound(sessionId.toString());
+  }
+
+  msg.setSessionID(sessionId);
+  return session->send(msg);
+}
+
+// Messages counters maintained from application callbacks, so admin messages handled by quickfix are included.
+static std::mutex sessionStatsMutex;
+static std::map<FixSessionKey, SessionStats> sessionStats;
+
+static void FixSession_recordSent(const FixSessionKey &key, bool isAdmin) {
+  std::lock_guard<std::mutex> lock(sessionStatsMutex);
+  SessionStats &stats = sessionStats[key];
+  ++(isAdmin ? stats.sentAdmin : stats.sentApp);
+  stats.lastSentAt = FixSession_nowMillis();
+}
+
+static void FixSession_recordReceived(const FixSessionKey &key, bool isAdmin) {
+  std::lock_guard<std::mutex> lock(sessionStatsMutex);
+  SessionStats &stats = sessionStats[key];
+  ++(isAdmin ? stats.receivedAdmin : stats.receivedApp);
+  stats.lastReceivedAt = FixSession_nowMillis();
+}
+
+static void FixSession_clearStats(const FixSessionKey &key) {
+  std::lock_guard<std::mutex> lock(sessionStatsMutex);
+  sessionStats.erase(key);
+}
+
+// Logon / Logout exchanged since session last logged out, to report how it logged on and why it logged out.
+struct FixSessionLifecycle {
+  bool logonPending = false;
+  bool loggedOn = false;
+  bool sequenceReset = false;
+  bool logoutSent = false;
+  bool logoutReceived = false;
+  bool disconnectedLocally = false;
+  bool initiatedLocally = false;
+  std::string reason;
+};
+
+static std::mutex sessionLifecyclesMutex;
+static std::map<FixSessionKey, FixSessionLifecycle> sessionLifecycles;
+
+static void FixSession_trackLifecycle(const FixSessionKey &key, const Message &msg, bool outgoing) {
+  MsgType msgType;
+  if (!msg.getHeader().getFieldIfSet(msgType)) {
+    return;
+  }
+
+  std::lock_guard<std::mutex> lock(sessionLifecyclesMutex);
+  if (msgType.getValue() == MsgType_Logon) {
+    FixSessionLifecycle &lifecycle = sessionLifecycles[key];
+    if (!lifecycle.logonPending && !lifecycle.loggedOn) {
+      // First Logon of the exchange, sequence reset may be requested by either side.
+      lifecycle = FixSessionLifecycle();
+      lifecycle.logonPending = true;
+    }
+    ResetSeqNumFlag resetSeqNumFlag;
+    if (msg.getFieldIfSet(resetSeqNumFlag) && resetSeqNumFlag.getValue()) {
+      lifecycle.sequenceReset = true;
+    }
+  } else if (msgType.getValue() == MsgType_Logout) {
+    FixSessionLifecycle &lifecycle = sessionLifecycles[key];
+    if (!lifecycle.logoutSent && !lifecycle.logoutReceived) {
+      lifecycle.initiatedLocally = outgoing;
+    }
+    (outgoing ? lifecycle.logoutSent : lifecycle.logoutReceived) = true;
+
+    Text text;
+    if (lifecycle.reason.empty() && msg.getFieldIfSet(text)) {
+      lifecycle.reason = text.getValue();
+    }
+  }
+}
+
+// Called before this side drops the connection without a Logout, only for sessions about to call `onLogout`.
+static void FixSession_recordLocalDisconnect(Session *session, const std::string &reason) {
+  const SessionState &state = FixSession_getState(session);
+  FixSessionKey key;
+  if ((!state.receivedLogon() && !state.sentLogon()) || !FixSession_findKey(session, key)) {
+    return;
+  }
+
+  std::lock_guard<std::mutex> lock(sessionLifecyclesMutex);
+  FixSessionLifecycle &lifecycle = sessionLifecycles[key];
+  if (lifecycle.logoutSent || lifecycle.logoutReceived || lifecycle.disconnectedLocally) {
+    return;
+  }
+  lifecycle.disconnectedLocally = true;
+  lifecycle.reason = reason;
+}
+
+// Record why session is disconnected when it timed out: quickfix drops the connection as soon as it notices, checking
+// session state the same way. Return true when it did.
+static bool FixSession_recordTimeout(Session *session) {
+  const SessionState &state = FixSession_getState(session);
+  const char *reason = nullptr;
+  if (!state.receivedLogon()) {
+    if (state.sentLogon() && state.logonTimedOut()) {
+      reason = "Timed out waiting for logon response";
+    }
+  } else if (state.heartBtInt().getValue() > 0) {
+    if (state.logoutTimedOut()) {
+      reason = "Timed out waiting for logout response";
+    } else if (!state.withinHeartBeat() && state.timedOut()) {
+      reason = "Timed out waiting for heartbeat";
+    }
+  }
+
+  if (!reason) {
+    return false;
+  }
+  FixSession_recordLocalDisconnect(session, reason);
+  return true;
+}
+
+static bool FixSession_takeLogonContext(const FixSessionKey &key) {
+  std::lock_guard<std::mutex> lock(sessionLifecyclesMutex);
+  FixSessionLifecycle &lifecycle = sessionLifecycles[key];
+  lifecycle.logonPending = false;
+  lifecycle.loggedOn = true;
+  return lifecycle.sequenceReset;
+}
+
+static FixSessionLifecycle FixSession_takeLogoutContext(const FixSessionKey &key) {
+  std::lock_guard<std::mutex> lock(sessionLifecyclesMutex);
+  FixSessionLifecycle lifecycle;
+  auto it = sessionLifecycles.find(key);
+  if (it != sessionLifecycles.end()) {
+    lifecycle = it->second;
+    sessionLifecycles.erase(it);
+  }
+  return lifecycle;
+}
+
+static void FixSession_clearLifecycle(const FixSessionKey &key) {
+  std::lock_guard<std::mutex> lock(sessionLifecyclesMutex);
+  sessionLifecycles.erase(key);
+}
+
+static int8_t FixSessionLifecycle_logoutKind(const FixSessionLifecycle &lifecycle) {
+  if (!lifecycle.logoutSent && !lifecycle.logoutReceived) {
+    return LOGOUT_KIND_DISCONNECT;
+  }
+  return lifecycle.loggedOn ? LOGOUT_KIND_NORMAL : LOGOUT_KIND_LOGON_REJECTED;
+}
+
+// Held shared by resolved sends from generation check until message is sent, and exclusively to invalidate them.
+// Sessions are only deleted after being invalidated, so they cannot be freed during a resolved send.
+static std::shared_mutex resolvedSessionMutex;
+
+// Resolved sends nested in application callbacks of this thread, which already hold the shared lock.
+static thread_local int resolvedSendDepth = 0;
+
+// Generation given to each resolved session, checked by resolved sends before touching the session pointer.
+// Entries are erased when their handler stops, and set to `RESOLVED_SESSION_RETIRED` until their session is deleted.
+// Generations are never reused, so a session allocated at the address of a deleted one is not mistaken for it.
+static std::mutex resolvedGenerationsMutex;
+static std::map<const Session *, uint64_t> resolvedGenerations;
+static uint64_t nextResolvedGeneration = 1;
+static const uint64_t RESOLVED_SESSION_RETIRED = 0;
+
+// Take shared resolved session lock, unless this thread already holds it from a resolved send.
+class FixResolvedSendScope {
+public:
+  FixResolvedSendScope() : lock(resolvedSessionMutex, std::defer_lock) {
+    if (resolvedSendDepth == 0) {
+      lock.lock();
+    }
+    ++resolvedSendDepth;
+  }
+  ~FixResolvedSendScope() { --resolvedSendDepth; }
+
+private:
+  // Shared locks are not reentrant: taking it again would deadlock with a pending invalidation.
+  std::shared_lock<std::shared_mutex> lock;
+};
+
+static void FixSession_invalidateResolved(const std::vector<const Session *> &sessions, bool retired) {
+  std::unique_lock<std::shared_mutex> lock(resolvedSessionMutex);
+  std::lock_guard<std::mutex> generationsLock(resolvedGenerationsMutex);
+  for (const Session *session : sessions) {
+    if (retired) {
+      resolvedGenerations[session] = RESOLVED_SESSION_RETIRED;
+    } else {
+      resolvedGenerations.erase(session);
+    }
+  }
+}
+
+// Forget deleted sessions, so their address can be resolved again once reused.
+static void FixSession_forgetResolved(const std::vector<const Session *> &sessions) {
+  std::lock_guard<std::mutex> generationsLock(resolvedGenerationsMutex);
+  for (const Session *session : sessions) {
+    resolvedGenerations.erase(session);
+  }
+}
+
+extern "C++" {
+template <typename T> static std::vector<const Session *> FixConnectionHandler_sessions(const T *obj) {
+  std::vector<const Session *> sessions;
+  for (const auto &sessionId : obj->getSessions()) {
+    if (const Session *session = obj->getSession(sessionId)) {
+      sessions.push_back(session);
+    }
+  }
+  return sessions;
+}
+
+// Invalidate sessions resolved from this handler only, sessions of other handlers keep their generation.
+template <typename T> static void FixConnectionHandler_invalidateResolved(const T *obj) {
+  FixSession_invalidateResolved(FixConnectionHandler_sessions(obj), false);
+}
+} // extern C++
+
+// Sessions disabled by user through `FixSession_logout*`, until `FixSession_logon`.
+// Session only exposes enabled flag, which does not tell user from reconnect gate disabling it.
+static std::mutex userDisabledSessionsMutex;
+static std::set<FixSessionKey> userDisabledSessions;
+
+static void FixSession_setUserDisabled(const Session *session, bool disabled) {
+  FixSessionKey key;
+  if (!FixSession_findKey(session, key)) {
+    return;
+  }
+
+  std::lock_guard<std::mutex> lock(userDisabledSessionsMutex);
+  if (disabled) {
+    userDisabledSessions.insert(key);
+  } else {
+    userDisabledSessions.erase(key);
+  }
+}
+
+static bool FixSession_isUserDisabled(const Session *session) {
+  FixSessionKey key;
+  if (!FixSession_findKey(session, key)) {
+    return false;
+  }
+
+  std::lock_guard<std::mutex> lock(userDisabledSessionsMutex);
+  return userDisabledSessions.count(key) > 0;
+}
+
+// Bookkeeping owner of a connection handler. Created with its log factory before the handler, and deleted with it
+// once every session of the handler is.
+struct FixHandlerState {
+  // Notified of connections opened / closed, may be null.
+  ApplicationBind *application = nullptr;
+  // Trackers of removed sessions, which may still be their responder until they are deleted.
+  std::mutex retiredTrackersMutex;
+  std::vector<std::unique_ptr<FixTransportTracker>> retiredTrackers;
+};
+
+extern "C++" {
+template <typename T>
+static void FixSessionKey_eraseMatching(T &entries, const std::function<bool(const FixSessionKey &)> &matches) {
+  for (auto it = entries.begin(); it != entries.end();) {
+    it = matches(it->first) ? entries.erase(it) : std::next(it);
+  }
+}
+} // extern C++
+
+// Erase bookkeeping of sessions matching key.
+static void FixHandlerState_forget(FixHandlerState *state, const std::function<bool(const FixSessionKey &)> &matches) {
+  {
+    std::lock_guard<std::mutex> lock(transportTrackersMutex);
+    std::lock_guard<std::mutex> retiredLock(state->retiredTrackersMutex);
+    for (auto it = transportTrackers.begin(); it != transportTrackers.end();) {
+      if (matches(it->first)) {
+        state->retiredTrackers.push_back(std::move(it->second));
+        it = transportTrackers.erase(it);
+      } else {
+        ++it;
+      }
+    }
+  }
+  {
+    std::lock_guard<std::mutex> lock(outboundQueueMutex);
+    FixSessionKey_eraseMatching(outboundQueue, matches);
+  }
+  {
+    std::lock_guard<std::mutex> lock(socketOptionsMutex);
+    FixSessionKey_eraseMatching(socketOptions, matches);
+  }
+  {
+    std::lock_guard<std::mutex> lock(connectAttemptsMutex);
+    FixSessionKey_eraseMatching(connectAttempts, matches);
+  }
+  {
+    std::lock_guard<std::mutex> lock(sessionStatsMutex);
+    FixSessionKey_eraseMatching(sessionStats, matches);
+  }
+  {
+    std::lock_guard<std::mutex> lock(sessionLifecyclesMutex);
+    FixSessionKey_eraseMatching(sessionLifecycles, matches);
+  }
+  {
+    std::lock_guard<std::mutex> lock(userDisabledSessionsMutex);
+    for (auto it = userDisabledSessions.begin(); it != userDisabledSessions.end();) {
+      it = matches(*it) ? userDisabledSessions.erase(it) : std::next(it);
+    }
+  }
+}
+
+// Called once handler and its sessions are deleted, right before its state is.
+static void FixHandlerState_release(FixHandlerState *state) {
+  {
+    std::lock_guard<std::mutex> lock(sessionHandlerStatesMutex);
+    for (auto it = sessionHandlerStates.begin(); it != sessionHandlerStates.end();) {
+      it = it->second == state ? sessionHandlerStates.erase(it) : std::next(it);
+    }
+  }
+  FixHandlerState_forget(state, [state](const FixSessionKey &key) { return key.first == state; });
+}
+
+// Sessions removed from a running acceptor / initiator.
+// Socket connections being closed may still reference them, so they are only deleted with their handler.
+static std::mutex removedSessionsMutex;
+static std::map<const void *, std::vector<std::unique_ptr<Session>>> removedSessions;
+
+static void FixConnectionHandler_retireSession(const void *handler, Session *session) {
+  // Unregistered sessions cannot be looked up anymore: counterparty logon and `sendToTarget` are refused.
+  Session::bindRemoveSession(*session);
+  FixSessionKey key;
+  if (FixSession_findKey(session, key)) {
+    FixSession_unregisterHandlerState(session);
+    FixHandlerState_forget(key.first, [&key](const FixSessionKey &other) { return other == key; });
+  }
+  FixSession_invalidateResolved({session}, true);
+
+  std::lock_guard<std::mutex> lock(removedSessionsMutex);
+  removedSessions[handler].emplace_back(session);
+}
+
+static void FixConnectionHandler_deleteRemovedSessions(const void *handler) {
+  std::lock_guard<std::mutex> lock(removedSessionsMutex);
+  auto it = removedSessions.find(handler);
+  if (it == removedSessions.end()) {
+    return;
+  }
+  std::vector<const Session *> deleted;
+  for (const auto &session : it->second) {
+    deleted.push_back(session.get());
+  }
+  removedSessions.erase(it);
+  FixSession_forgetResolved(deleted);
+}
+
+// `SessionSettings` cannot remove a session, so rebuild them without it. Session can then be added again.
+static void FixInitiator_removeSessionSettings(Initiator *obj, const SessionID &id) {
+  SessionSettings &settings = obj->bindSettings();
+
+  SessionSettings rebuilt;
+  rebuilt.set(settings.get());
+  for (const auto &sessionId : settings.getSessions()) {
+    if (sessionId != id) {
+      rebuilt.set(sessionId, settings.get(sessionId));
+    }
+  }
+  settings = rebuilt;
+}
+
+// Notified each time a session logs out, once quickfix has cleared its logon state.
+static std::mutex sessionLogoutMutex;
+static std::condition_variable sessionLogoutCondition;
+
+static void FixSession_notifyLogout() {
+  std::lock_guard<std::mutex> lock(sessionLogoutMutex);
+  sessionLogoutCondition.notify_all();
+}
+
+static void FixSession_logoutForRemoval(Session *session, bool forceDisconnect) {
+  // Disable session first, so it does not log on again.
+  session->logout("Session removed");
+
+  if (!forceDisconnect) {
+    // Wait for counterparty logout answer, quickfix disconnects by itself once logout timeout is reached.
+    std::unique_lock<std::mutex> lock(sessionLogoutMutex);
+    sessionLogoutCondition.wait_for(lock, std::chrono::seconds(session->getLogoutTimeout() + 1),
+                                    [session] { return !session->isLoggedOn(); });
+  }
+
+  session->disconnect();
+}
+
+// Delays initiator reconnections per session, on top of the initiator wide `ReconnectInterval`.
+// quickfix has no hook before connecting, so sessions are disabled once their connection ends, and enabled again when
+// their delay expires. Connection state is polled on a background thread.
+class FixReconnectGate {
+public:
+  struct Policy {
+    int64_t initialMillis;
+    int64_t maxMillis;
+    int64_t jitterMillis;
+  };
+
+  explicit FixReconnectGate(Initiator *initiator)
+      : initiator(initiator), random(std::random_device()()), thread(&FixReconnectGate::run, this) {}
+
+  ~FixReconnectGate() {
+    {
+      std::lock_guard<std::mutex> lock(mutex);
+      stopping = true;
+    }
+    wakeup.notify_all();
+    thread.join();
+  }
+
+  void setPolicy(const SessionID *id, const Policy &policy) {
+    {
+      std::lock_guard<std::mutex> lock(mutex);
+      if (id) {
+        policies[*id] = policy;
+      } else {
+        defaultPolicy.reset(new Policy(policy));
+      }
+
+      // Pending delays are recomputed, so a shorter policy applies right away.
+      for (auto &item : states) {
+        State &state = item.second;
+        const Policy *current = findPolicy(item.first);
+        if (state.disabledByGate && current) {
+          state.jitterMillis = (std::min)(state.jitterMillis, current->jitterMillis);
+          state.enableAt = state.endedAt + delayMillis(*current, state.failures - 1) + state.jitterMillis;
+        }
+      }
+    }
+
+    // Record current connections, so they are gated even if they end before next poll.
+    tick();
+  }
+
+private:
+  struct State {
+    bool wasActive = false;
+    int failures = 0;
+    int64_t endedAt = 0;
+    int64_t enableAt = 0;
+    int64_t jitterMillis = 0;
+    bool disabledByGate = false;
+  };
+
+  static int64_t delayMillis(const Policy &policy, int failures) {
+    int64_t delay = policy.initialMillis;
+    for (int i = 0; i < failures && delay < policy.maxMillis; ++i) {
+      delay *= 2;
+    }
+    return (std::min)(delay, policy.maxMillis);
+  }
+
+  const Policy *findPolicy(const SessionID &id) const {
+    auto found = policies.find(id);
+    if (found != policies.end()) {
+      return &found->second;
+    }
+    return defaultPolicy.get();
+  }
+
+  void run() {
+    std::unique_lock<std::mutex> lock(mutex);
+    while (!wakeup.wait_for(lock, std::chrono::milliseconds(50), [this] { return stopping; })) {
+      lock.unlock();
+      tick();
+      lock.lock();
+    }
+  }
+
+  void tick() {
+    std::vector<Session *> toDisable;
+    std::vector<Session *> toEnable;
+    int64_t now = FixSession_nowMillis();
+
+    {
+      Locker initiatorLocker(initiator->bindMutex());
+      std::lock_guard<std::mutex> lock(mutex);
+      const auto &pending = initiator->bindPending();
+      const auto &connected = initiator->bindConnected();
+      const auto &sessions = initiator->bindSessions();
+
+      for (const auto &item : sessions) {
+        const SessionID &id = item.first;
+        Session *session = item.second;
+        const Policy *policy = findPolicy(id);
+        if (!policy) {
+          continue;
+        }
+
+        State &state = states[id];
+        if (pending.count(id) || connected.count(id)) {
+          state.wasActive = true;
+          if (session->isLoggedOn()) {
+            state.failures = 0;
+          }
+          continue;
+        }
+
+        if (state.wasActive) {
+          // Connection attempt just ended, either failing or losing an established connection.
+          state.wasActive = false;
+          state.endedAt = now;
+          // Spread reconnections of sessions disconnected at once (e.g. counterparty restart).
+          state.jitterMillis = std::uniform_int_distribution<int64_t>(0, policy->jitterMillis)(random);
+          state.enableAt = now + delayMillis(*policy, state.failures) + state.jitterMillis;
+          ++state.failures;
+          // Sessions disabled by user stay disabled.
+          if (session->isEnabled() && !state.disabledByGate && !FixSession_isUserDisabled(session)) {
+            state.disabledByGate = true;
+            toDisable.push_back(session);
+          }
+        } else if (state.disabledByGate && FixSession_isUserDisabled(session)) {
+          // User disabled session meanwhile, it is no longer up to the gate to enable it.
+          state.disabledByGate = false;
+        } else if (state.disabledByGate && now >= state.enableAt) {
+          state.disabledByGate = false;
+          toEnable.push_back(session);
+        }
+      }
+    }
+
+    // Sessions lock themselves, do not hold initiator lock meanwhile.
+    for (Session *session : toDisable) {
+      session->logout();
+    }
+    for (Session *session : toEnable) {
+      session->logon();
+    }
+  }
+
+  Initiator *initiator;
+  std::mutex mutex;
+  std::condition_variable wakeup;
+  bool stopping = false;
+  std::unique_ptr<Policy> defaultPolicy;
+  std::map<SessionID, Policy> policies;
+  std::map<SessionID, State> states;
+  std::mt19937_64 random;
+  std::thread thread;
+};
+
+static std::mutex reconnectGatesMutex;
+static std::map<const Initiator *, std::unique_ptr<FixReconnectGate>> reconnectGates;
+
+static FixReconnectGate &FixInitiator_getReconnectGate(Initiator *obj) {
+  std::lock_guard<std::mutex> lock(reconnectGatesMutex);
+  auto &gate = reconnectGates[obj];
+  if (!gate) {
+    gate.reset(new FixReconnectGate(obj));
+  }
+  return *gate;
+}
+
+static void FixInitiator_deleteReconnectGate(const Initiator *obj) {
+  std::unique_ptr<FixReconnectGate> gate;
+  {
+    std::lock_guard<std::mutex> lock(reconnectGatesMutex);
+    auto found = reconnectGates.find(obj);
+    if (found == reconnectGates.end()) {
+      return;
+    }
+    gate = std::move(found->second);
+    reconnectGates.erase(found);
+  }
+  // Joining gate thread, which may be waiting for initiator lock.
+  gate.reset();
+}
+
+extern "C++" {
+template <typename T> static bool FixInitiator_trySetReconnectInterval(Initiator *obj, int seconds) {
+  T *initiator = dynamic_cast<T *>(obj);
+  if (!initiator) {
+    return false;
+  }
+  initiator->bindReconnectInterval() = seconds;
+  return true;
+}
+} // extern C++
+
+static bool FixInitiator_setSocketReconnectInterval(Initiator *obj, int seconds) {
+#ifdef HAVE_SSL
+  if (FixInitiator_trySetReconnectInterval<SSLSocketInitiator>(obj, seconds) ||
+      FixInitiator_trySetReconnectInterval<ThreadedSSLSocketInitiator>(obj, seconds)) {
+    return true;
+  }
+#endif // HAVE_SSL
+  return FixInitiator_trySetReconnectInterval<SocketInitiator>(obj, seconds) ||
+         FixInitiator_trySetReconnectInterval<ThreadedSocketInitiator>(obj, seconds);
+}
+
+static int8_t FixMessage_adminKind(const Message &msg) {
+  const Header &header = msg.getHeader();
+  if (!header.isSetField(FIELD::MsgType)) {
+    return ADMIN_MSG_KIND_OTHER;
+  }
+
+  const std::string &msgType = header.getField(FIELD::MsgType);
+  if (msgType.size() != 1) {
+    return ADMIN_MSG_KIND_OTHER;
+  }
+
+  switch (msgType[0]) {
+  case '0':
+    return ADMIN_MSG_KIND_HEARTBEAT;
+  case '1':
+    return ADMIN_MSG_KIND_TEST_REQUEST;
+  case '2':
+    return ADMIN_MSG_KIND_RESEND_REQUEST;
+  case '3':
+    return ADMIN_MSG_KIND_REJECT;
+  case '4':
+    return ADMIN_MSG_KIND_SEQUENCE_RESET;
+  case '5':
+    return ADMIN_MSG_KIND_LOGOUT;
+  case 'A':
+    return ADMIN_MSG_KIND_LOGON;
+  default:
+    return ADMIN_MSG_KIND_OTHER;
+  }
+}
+
+// Details of the error returned by the application callback running on this thread.
+struct FixCallbackError {
+  int field = 0;
+  std::string text;
+};
+
+static thread_local FixCallbackError callbackError;
+
+static FixCallbackError FixApplication_takeCallbackError() {
+  FixCallbackError error;
+  std::swap(error, callbackError);
+  return error;
+}
+
+class ApplicationBind : public Application {
+private:
+  const ApplicationCallbacks *callbacks;
+  const void *data;
+
+  std::mutex resendMutex;
+  std::set<SessionID> resendingSessions;
+
+  void onResendBegin(const Message &msg, const SessionID &session) {
+    BeginSeqNo beginSeqNo;
+    EndSeqNo endSeqNo;
+    msg.getFieldIfSet(beginSeqNo);
+    msg.getFieldIfSet(endSeqNo);
+
+    {
+      std::lock_guard<std::mutex> lock(resendMutex);
+      resendingSessions.insert(session);
+    }
+
+    RETURN_IF_NULL(callbacks);
+    RETURN_IF_NULL(callbacks->onResendBegin);
+    callbacks->onResendBegin(data, &session, beginSeqNo.getValue(), endSeqNo.getValue());
+  }
+
+  void checkResendComplete(const SessionID &session) {
+    {
+      std::lock_guard<std::mutex> lock(resendMutex);
+      if (resendingSessions.count(session) == 0) {
+        return;
+      }
+
+      // Resend range is cleared by quickfix once the last requested message has been verified.
+      Session *fixSession = Session::lookupSession(session);
+      if (fixSession && FixSession_getState(fixSession).resendRequested()) {
+        return;
+      }
+      resendingSessions.erase(session);
+    }
+
+    RETURN_IF_NULL(callbacks);
+    RETURN_IF_NULL(callbacks->onResendComplete);
+    callbacks->onResendComplete(data, &session);
+  }
+
+public:
+  ApplicationBind(const void *data, const ApplicationCallbacks *callbacks) : callbacks(callbacks), data(data) {}
+
+  ApplicationBind(const ApplicationBind &) = delete;
+  ApplicationBind &operator=(const ApplicationBind &) = delete;
+
+  virtual ~ApplicationBind() {}
+
+  void onConnectFailed(const SessionID &session, uint32_t attempt, int8_t errorKind, const std::string &message) {
+    CATCH_OR_DISCARD({
+      RETURN_IF_NULL(callbacks);
+      RETURN_IF_NULL(callbacks->onConnectFailed);
+      callbacks->onConnectFailed(data, &session, attempt, errorKind, message.c_str());
+    });
+  }
+
+  void onConnect(const SessionID &session, const std::string &peerAddress) {
+    CATCH_OR_DISCARD({
+      RETURN_IF_NULL(callbacks);
+      RETURN_IF_NULL(callbacks->onConnect);
+      callbacks->onConnect(data, &session, peerAddress.empty() ? nullptr : peerAddress.c_str());
+    });
+  }
+
+  void onDisconnect(const SessionID &session, int8_t reason) {
+    CATCH_OR_DISCARD({
+      RETURN_IF_NULL(callbacks);
+      RETURN_IF_NULL(callbacks->onDisconnect);
+      callbacks->onDisconnect(data, &session, reason);
+    });
+  }
+
+  void onRawIncoming(const SessionID &session, const std::string &raw) {
+    CATCH_OR_DISCARD({
+      RETURN_IF_NULL(callbacks);
+      RETURN_IF_NULL(callbacks->onRawIncoming);
+      callbacks->onRawIncoming(data, &session, raw.data(), raw.size());
+    });
+  }
+
+  void onRawOutgoing(const SessionID &session, const std::string &raw) {
+    CATCH_OR_DISCARD({
+      RETURN_IF_NULL(callbacks);
+      RETURN_IF_NULL(callbacks->onRawOutgoing);
+      callbacks->onRawOutgoing(data, &session, raw.data(), raw.size());
+    });
+  }
+
+  void onCreate(const SessionID &session) override {
+    CATCH_OR_DISCARD({
+      // Sessions are registered with their handler before quickfix notifies their creation.
+      if (creatingHandlerState) {
+        if (const Session *created = Session::lookupSession(session)) {
+          FixSession_registerHandlerState(created, creatingHandlerState);
+        }
+      }
+
+      RETURN_IF_NULL(callbacks);
+      RETURN_IF_NULL(callbacks->onCreate);
+      callbacks->onCreate(data, &session);
+    });
+  }
+
+  void onLogon(const SessionID &session) override {
+    CATCH_OR_DISCARD({
+      FixSessionKey key;
+      const bool sequenceReset = FixSession_findKeyById(session, key) && FixSession_takeLogonContext(key);
+
+      RETURN_IF_NULL(callbacks);
+      RETURN_IF_NULL(callbacks->onLogon);
+      callbacks->onLogon(data, &session, sequenceReset);
+    });
+  }
+
+  void onLogout(const SessionID &session) override {
+    CATCH_OR_DISCARD({
+      {
+        // Pending resend is abandoned on disconnect.
+        std::lock_guard<std::mutex> lock(resendMutex);
+        resendingSessions.erase(session);
+      }
+      FixSession_notifyLogout();
+      FixSessionKey key;
+      const FixSessionLifecycle lifecycle =
+          FixSession_findKeyById(session, key) ? FixSession_takeLogoutContext(key) : FixSessionLifecycle();
+      const bool initiatedLocally = lifecycle.logoutSent || lifecycle.logoutReceived ? lifecycle.initiatedLocally
+                                                                                     : lifecycle.disconnectedLocally;
+
+      RETURN_IF_NULL(callbacks);
+      RETURN_IF_NULL(callbacks->onLogout);
+      callbacks->onLogout(data, &session, FixSessionLifecycle_logoutKind(lifecycle), initiatedLocally,
+                          lifecycle.reason.empty() ? nullptr : lifecycle.reason.c_str());
+    });
+  }
+
+  void toAdmin(Message &msg, const SessionID &session) override {
+    CATCH_OR_DISCARD({
+      FixSessionKey key;
+      Session *tracked = FixSession_findKeyById(session, key);
+      if (tracked) {
+        FixSession_recordSent(key, true);
+        std::string peerAddress;
+        if (FixSession_trackTransport(tracked, key, peerAddress)) {
+          onConnect(session, peerAddress);
+        }
+      }
+
+      MsgType msgType;
+      if (msg.getHeader().getFieldIfSet(msgType) && msgType.getValue() == MsgType_ResendRequest) {
+        onResendBegin(msg, session);
+      }
+
+      if (callbacks && callbacks->toAdmin) {
+        callbacks->toAdmin(data, &msg, &session);
+      }
+      // Tracked once callback ran, so Logout Text(58) it sets is reported.
+      if (tracked) {
+        FixSession_trackLifecycle(key, msg, true);
+      }
+    });
+  }
+
+  void toApp(Message &msg, const SessionID &session) EXCEPT(DoNotSend) override {
+    if (callbacks && callbacks->toApp) {
+      int8_t result = callbacks->toApp(data, &msg, &session);
+
+      if (result == CALLBACK_RESULT_DO_NOT_SEND)
+        throw DoNotSend();
+    }
+
+    FixSessionKey key;
+    if (Session *tracked = FixSession_findKeyById(session, key)) {
+      FixSession_recordSent(key, false);
+      FixSession_recordQueued(tracked, key, msg);
+    }
+  }
+
+  void fromAdmin(Message &&msg, const SessionID &session)
+      EXCEPT(FieldNotFound, IncorrectDataFormat, IncorrectTagValue, RejectLogon) override {
+    FixSessionKey key;
+    Session *tracked = FixSession_findKeyById(session, key);
+    if (tracked) {
+      FixSession_recordReceived(key, true);
+      FixSession_trackLifecycle(key, msg, false);
+      // Accepted connections are bound to their session once Logon is read: track them before the application sees
+      // it.
+      std::string peerAddress;
+      if (FixSession_trackTransport(tracked, key, peerAddress)) {
+        onConnect(session, peerAddress);
+      }
+    }
+
+    RETURN_IF_NULL(callbacks);
+    RETURN_IF_NULL(callbacks->fromAdmin);
+    const int8_t kind = FixMessage_adminKind(msg);
+    Message *ownedMessage = new Message(std::move(msg));
+    int8_t result = callbacks->fromAdmin(data, ownedMessage, &session, kind);
+    const FixCallbackError error = FixApplication_takeCallbackError();
+    checkResendComplete(session);
+
+    switch (result) {
+    case CALLBACK_RESULT_FIELD_NOT_FOUND:
+      throw FieldNotFound(error.field);
+    case CALLBACK_RESULT_INCORRECT_DATA_FORMAT:
+      throw IncorrectDataFormat(error.field);
+    case CALLBACK_RESULT_INCORRECT_TAG_VALUE:
+      throw IncorrectTagValue(error.field);
+    case CALLBACK_RESULT_REJECT_LOGON:
+      // Session is disconnected without calling `onLogout`.
+      if (tracked) {
+        FixSession_clearLifecycle(key);
+      }
+      throw RejectLogon(error.text);
+    }
+  }
+
+  void fromApp(Message &&msg, const SessionID &session)
+      EXCEPT(FieldNotFound, IncorrectDataFormat, IncorrectTagValue, UnsupportedMessageType) override {
+    FixSessionKey key;
+    if (FixSession_findKeyById(session, key)) {
+      FixSession_recordReceived(key, false);
+    }
+
+    RETURN_IF_NULL(callbacks);
+    RETURN_IF_NULL(callbacks->fromApp);
+    Message *ownedMessage = new Message(std::move(msg));
+    int8_t result = callbacks->fromApp(data, ownedMessage, &session);
+    const FixCallbackError error = FixApplication_takeCallbackError();
+    checkResendComplete(session);
+
+    switch (result) {
+    case CALLBACK_RESULT_FIELD_NOT_FOUND:
+      throw FieldNotFound(error.field);
+    case CALLBACK_RESULT_INCORRECT_DATA_FORMAT:
+      throw IncorrectDataFormat(error.field);
+    case CALLBACK_RESULT_INCORRECT_TAG_VALUE:
+      throw IncorrectTagValue(error.field);
+    case CALLBACK_RESULT_UNSUPPORTED_MESSAGE_TYPE:
+      throw UnsupportedMessageType();
+    }
+  }
+};
+
+static void FixSession_notifyDisconnect(const FixSessionKey &key, int8_t reason) {
+  if (ApplicationBind *application = key.first->application) {
+    application->onDisconnect(key.second, reason);
+  }
+}
+
+// Session log tapping raw messages for the application.
+class FixTapLog : public Log {
+  Log *inner;
+  SessionID sessionId;
+  ApplicationBind *application;
+
+public:
+  FixTapLog(Log *inner, const SessionID &sessionId, ApplicationBind *application)
+      : inner(inner), sessionId(sessionId), application(application) {}
+
+  Log *release() {
+    Log *log = inner;
+    inner = nullptr;
+    return log;
+  }
+
+  void clear() override { inner->clear(); }
+  void backup() override { inner->backup(); }
+
+  void onIncoming(const std::string &value) override {
+    CATCH_OR_DISCARD({ FixSession_recordIncoming(sessionId, value); });
+    application->onRawIncoming(sessionId, value);
+    CATCH_OR_DISCARD({ inner->onIncoming(value); });
+  }
+
+  void onOutgoing(const std::string &value) override {
+    application->onRawOutgoing(sessionId, value);
+    CATCH_OR_DISCARD({ inner->onOutgoing(value); });
+  }
+
+  void onEvent(const std::string &value) override {
+    CATCH_OR_DISCARD({ inner->onEvent(value); });
+  }
+};
+
+// Wrap log factory of connection handlers with `FixTapLog`.
+class FixTapLogFactory : public LogFactory {
+  LogFactory &inner;
+  ApplicationBind *application;
+  std::unique_ptr<FixHandlerState> state;
+
+public:
+  FixTapLogFactory(LogFactory &inner, ApplicationBind *application)
+      : inner(inner), application(application), state(new FixHandlerState()) {
+    state->application = application;
+  }
+
+  ~FixTapLogFactory() { FixHandlerState_release(state.get()); }
+
+  ApplicationBind *getApplication() const { return application; }
+  FixHandlerState *getState() const { return state.get(); }
+
+  Log *create() override { return inner.create(); }
+
+  Log *create(const SessionID &sessionId) override {
+    Log *log = inner.create(sessionId);
+    return application ? new FixTapLog(log, sessionId, application) : log;
+  }
+
+  void destroy(Log *log) override {
+    if (auto *tap = dynamic_cast<FixTapLog *>(log)) {
+      inner.destroy(tap->release());
+      delete tap;
+    } else {
+      inner.destroy(log);
+    }
+  }
+};
+
+// Handler log factories must outlive their sessions, removed ones included.
+static std::mutex handlerLogFactoriesMutex;
+static std::map<const void *, std::unique_ptr<FixTapLogFactory>> handlerLogFactories;
+
+static void FixConnectionHandler_setLogFactory(const void *obj, std::unique_ptr<FixTapLogFactory> logFactory) {
+  std::lock_guard<std::mutex> lock(handlerLogFactoriesMutex);
+  handlerLogFactories[obj] = std::move(logFactory);
+}
+
+// Handler state is released with its log factory: bookkeeping left by handler sessions is erased.
+static void FixConnectionHandler_deleteLogFactory(const void *obj) {
+  std::unique_ptr<FixTapLogFactory> logFactory;
+  {
+    std::lock_guard<std::mutex> lock(handlerLogFactoriesMutex);
+    auto it = handlerLogFactories.find(obj);
+    if (it == handlerLogFactories.end()) {
+      return;
+    }
+    logFactory = std::move(it->second);
+    handlerLogFactories.erase(it);
+  }
+}
+
+static FixHandlerState *FixConnectionHandler_getState(const void *obj) {
+  std::lock_guard<std::mutex> lock(handlerLogFactoriesMutex);
+  auto it = handlerLogFactories.find(obj);
+  return it == handlerLogFactories.end() ? nullptr : it->second->getState();
+}
+
+static LogFactory *FixInitiator_getLogFactory(const Initiator *obj, LogFactory *fallback) {
+  std::lock_guard<std::mutex> lock(handlerLogFactoriesMutex);
+  auto it = handlerLogFactories.find(obj);
+  return it == handlerLogFactories.end() ? fallback : it->second.get();
+}
+
+// Attempts still in progress once initiator is stopped never got a socket, and no later attempt will report them.
+static void FixInitiator_reportPendingConnectFailures(const Initiator *obj) {
+  ApplicationBind *application = nullptr;
+  FixHandlerState *state = nullptr;
+  {
+    std::lock_guard<std::mutex> lock(handlerLogFactoriesMutex);
+    auto it = handlerLogFactories.find(obj);
+    if (it != handlerLogFactories.end()) {
+      application = it->second->getApplication();
+      state = it->second->getState();
+    }
+  }
+  if (!application) {
+    return;
+  }
+
+  std::vector<std::pair<SessionID, uint32_t>> failures;
+  {
+    std::lock_guard<std::mutex> lock(connectAttemptsMutex);
+    for (const auto &sessionId : obj->getSessions()) {
+      auto it = connectAttempts.find(FixSessionKey(state, sessionId));
+      if (it != connectAttempts.end() && it->second.inProgress) {
+        it->second.inProgress = false;
+        failures.emplace_back(sessionId, ++it->second.failures);
+      }
+    }
+  }
+  for (const auto &failure : failures) {
+    application->onConnectFailed(failure.first, failure.second, CONNECT_ERROR_OTHER, "Connection failed");
+  }
+}
+
+class ExternalLog : public Log {
+private:
+  const void *data;
+  const SessionID *sessionId;
+  const LogCallbacks *callbacks;
+
+public:
+  ExternalLog(const void *data, const SessionID *sessionId, const LogCallbacks *callbacks)
+      : data(data), sessionId(sessionId), callbacks(callbacks) {}
+
+  ExternalLog(const ExternalLog &) = delete;
+  ExternalLog &operator=(const ExternalLog &) = delete;
+
+  virtual ~ExternalLog() {
+    if (sessionId) {
+      delete sessionId;
+    }
+  }
+
+  void clear() override {}
+  void backup() override {}
+
+  void onIncoming(const std::string &msg) override {
+    CATCH_OR_DISCARD({ callbacks->onIncoming(data, sessionId, msg.data(), msg.size()); });
+  }
+
+  void onOutgoing(const std::string &msg) override {
+    CATCH_OR_DISCARD({ callbacks->onOutgoing(data, sessionId, msg.data(), msg.size()); });
+  }
+
+  void onEvent(const std::string &msg) override {
+    CATCH_OR_DISCARD({ callbacks->onEvent(data, sessionId, msg.data(), msg.size()); });
+  }
+};
+
+class ExternalLogFactory : public LogFactory {
+private:
+  const void *data;
+  const LogCallbacks *callbacks;
+
+public:
+  ExternalLogFactory(const void *data, const LogCallbacks *callbacks) : data(data), callbacks(callbacks) {}
+
+  ExternalLogFactory(const ExternalLogFactory &) = delete;
+  ExternalLogFactory &operator=(const ExternalLogFactory &) = delete;
+
+  virtual ~ExternalLogFactory() {}
+
+  Log *create() override { return new ExternalLog(data, NULL, callbacks); }
+
+  Log *create(const SessionID &sessionId) override {
+    auto sessionIdCopy = new SessionID(sessionId);
+    return new ExternalLog(data, sessionIdCopy, callbacks);
+  }
+
+  void destroy(Log *log) override { delete log; }
+};
+
+// Log of a single session, owning callbacks data created for it.
+class SessionExternalLog : public ExternalLog {
+private:
+  const void *factoryData;
+  const void *logData;
+  const SessionLogFactoryCallbacks *factoryCallbacks;
+
+public:
+  SessionExternalLog(const void *factoryData, const void *logData, const SessionID &sessionId,
+                     const SessionLogFactoryCallbacks *factoryCallbacks, const LogCallbacks *callbacks)
+      : ExternalLog(logData, new SessionID(sessionId), callbacks), factoryData(factoryData), logData(logData),
+        factoryCallbacks(factoryCallbacks) {}
+
+  virtual ~SessionExternalLog() { factoryCallbacks->destroy(factoryData, logData); }
+};
+
+// Log factory asking callbacks for a different log data for every session.
+class SessionLogFactory : public LogFactory {
+private:
+  const void *data;
+  const SessionLogFactoryCallbacks *factoryCallbacks;
+  const LogCallbacks *callbacks;
+
+public:
+  SessionLogFactory(const void *data, const SessionLogFactoryCallbacks *factoryCallbacks,
+                    const LogCallbacks *callbacks)
+      : data(data), factoryCallbacks(factoryCallbacks), callbacks(callbacks) {}
+
+  SessionLogFactory(const SessionLogFactory &) = delete;
+  SessionLogFactory &operator=(const SessionLogFactory &) = delete;
+
+  virtual ~SessionLogFactory() {}
+
+  // Logs not related to a session are dropped.
+  Log *create() override { return new NullLog(); }
+
+  Log *create(const SessionID &sessionId) override {
+    const void *logData = factoryCallbacks->create(data, &sessionId);
+    if (!logData) {
+      return new NullLog();
+    }
+    return new SessionExternalLog(data, logData, sessionId, factoryCallbacks, callbacks);
+  }
+
+  void destroy(Log *log) override { delete log; }
+};
+
+// Sequence number type of the quickfix version being built.
+using FixSeqNum = decltype(std::declval<MessageStore>().getNextSenderMsgSeqNum());
+
+static UtcTimeStamp FixUtcTimeStamp_fromMillis(int64_t millis) {
+  return UtcTimeStamp(static_cast<time_t>(millis / 1000), static_cast<int>(millis % 1000), 3);
+}
+
+static int64_t FixUtcTimeStamp_toMillis(const UtcTimeStamp &value) {
+  return static_cast<int64_t>(value.getTimeT()) * 1000 + value.getMillisecond();
+}
+
+// Error message of the custom message store callback failing on this thread, if any.
+static thread_local std::string customMessageStoreError;
+
+static int64_t FixCustomMessageStore_check(int64_t result, const char *operation) {
+  std::string error;
+  std::swap(error, customMessageStoreError);
+  if (result < 0) {
+    std::string message = std::string("Custom message store failed to ") + operation;
+    if (!error.empty()) {
+      message += ": " + error;
+    }
+    throw IOException(message);
+  }
+  return result;
+}
+
+class ExternalMessageStore : public MessageStore {
+private:
+  const void *data;
+  const SessionID sessionId;
+  const MessageStoreCallbacks *callbacks;
+
+  static int64_t check(int64_t result, const char *operation) { return FixCustomMessageStore_check(result, operation); }
+
+  static void push(void *output, const char *msg, uint64_t len) {
+    static_cast<std::vector<std::string> *>(output)->emplace_back(msg, len);
+  }
+
+public:
+  ExternalMessageStore(const void *data, const SessionID &sessionId, const MessageStoreCallbacks *callbacks)
+      : data(data), sessionId(sessionId), callbacks(callbacks) {}
+
+  ExternalMessageStore(const ExternalMessageStore &) = delete;
+  ExternalMessageStore &operator=(const ExternalMessageStore &) = delete;
+
+  virtual ~ExternalMessageStore() {}
+
+  bool set(FixSeqNum seqNum, const std::string &msg) EXCEPT(IOException) override {
+    return check(callbacks->set(data, &sessionId, static_cast<uint32_t>(seqNum), msg.data(), msg.size()),
+                 "store message") != 0;
+  }
+
+  void get(FixSeqNum begin, FixSeqNum end, std::vector<std::string> &output) const EXCEPT(IOException) override {
+    output.clear();
+    check(callbacks->get(data, &sessionId, static_cast<uint32_t>(begin), static_cast<uint32_t>(end), &output,
+                         &ExternalMessageStore::push),
+          "read messages");
+  }
+
+  FixSeqNum getNextSenderMsgSeqNum() const EXCEPT(IOException) override {
+    return static_cast<FixSeqNum>(
+        check(callbacks->getNextSenderMsgSeqNum(data, &sessionId), "read next sender sequence number"));
+  }
+
+  FixSeqNum getNextTargetMsgSeqNum() const EXCEPT(IOException) override {
+    return static_cast<FixSeqNum>(
+        check(callbacks->getNextTargetMsgSeqNum(data, &sessionId), "read next target sequence number"));
+  }
+
+  void setNextSenderMsgSeqNum(FixSeqNum value) EXCEPT(IOException) override {
+    check(callbacks->setNextSenderMsgSeqNum(data, &sessionId, static_cast<uint32_t>(value)),
+          "update next sender sequence number");
+  }
+
+  void setNextTargetMsgSeqNum(FixSeqNum value) EXCEPT(IOException) override {
+    check(callbacks->setNextTargetMsgSeqNum(data, &sessionId, static_cast<uint32_t>(value)),
+          "update next target sequence number");
+  }
+
+  void incrNextSenderMsgSeqNum() EXCEPT(IOException) override {
+    check(callbacks->incrNextSenderMsgSeqNum(data, &sessionId), "increment next sender sequence number");
+  }
+
+  void incrNextTargetMsgSeqNum() EXCEPT(IOException) override {
+    check(callbacks->incrNextTargetMsgSeqNum(data, &sessionId), "increment next target sequence number");
+  }
+
+  UtcTimeStamp getCreationTime() const EXCEPT(IOException) override {
+    return FixUtcTimeStamp_fromMillis(check(callbacks->getCreationTime(data, &sessionId), "read creation time"));
+  }
+
+  void reset(const UtcTimeStamp &now) EXCEPT(IOException) override {
+    check(callbacks->reset(data, &sessionId, FixUtcTimeStamp_toMillis(now)), "reset");
+  }
+
+  void refresh() EXCEPT(IOException) override { check(callbacks->refresh(data, &sessionId), "refresh"); }
+};
+
+class ExternalMessageStoreFactory : public MessageStoreFactory {
+private:
+  const void *data;
+  const MessageStoreCallbacks *callbacks;
+
+public:
+  ExternalMessageStoreFactory(const void *data, const MessageStoreCallbacks *callbacks)
+      : data(data), callbacks(callbacks) {}
+
+  ExternalMessageStoreFactory(const ExternalMessageStoreFactory &) = delete;
+  ExternalMessageStoreFactory &operator=(const ExternalMessageStoreFactory &) = delete;
+
+  virtual ~ExternalMessageStoreFactory() {}
+
+  MessageStore *create(const UtcTimeStamp &now, const SessionID &sessionId) override {
+    (void)now;
+    FixCustomMessageStore_check(callbacks->onCreate(data, &sessionId), "create session");
+    return new ExternalMessageStore(data, sessionId, callbacks);
+  }
+
+  void destroy(MessageStore *store) override { delete store; }
+};
+
+typedef void (*FixStoreDivergenceCallback)(const void *data, const SessionID *sessionId, const char *reason);
+
+// Write every change to both stores and read from primary only. Secondary failures and sequence numbers mismatches are
+// given to `onDivergence`, or thrown when there is none.
+class CompositeMessageStore : public MessageStore {
+private:
+  const SessionID sessionId;
+  MessageStoreFactory &primaryFactory;
+  MessageStoreFactory &secondaryFactory;
+  MessageStore *primary;
+  MessageStore *secondary;
+  const void *data;
+  FixStoreDivergenceCallback onDivergence;
+
+  void report(const std::string &reason) const {
+    if (onDivergence == NULL) {
+      throw IOException("Secondary message store diverged: " + reason);
+    }
+    onDivergence(data, &sessionId, reason.c_str());
+  }
+
+  void checkSeqNums() const {
+    if (primary->getNextSenderMsgSeqNum() != secondary->getNextSenderMsgSeqNum() ||
+        primary->getNextTargetMsgSeqNum() != secondary->getNextTargetMsgSeqNum()) {
+      report("sequence numbers are " + std::to_string(secondary->getNextSenderMsgSeqNum()) + "/" +
+             std::to_string(secondary->getNextTargetMsgSeqNum()) + " instead of " +
+             std::to_string(primary->getNextSenderMsgSeqNum()) + "/" +
+             std::to_string(primary->getNextTargetMsgSeqNum()));
+    }
+  }
+
+  void write(const char *operation, const std::function<void(MessageStore &)> &f) {
+    f(*primary);
+    try {
+      f(*secondary);
+    } catch (std::exception &e) {
+      report(std::string("cannot ") + operation + ": " + e.what());
+      return;
+    }
+    checkSeqNums();
+  }
+
+public:
+  CompositeMessageStore(const UtcTimeStamp &now, const SessionID &sessionId, MessageStoreFactory &primaryFactory,
+                        MessageStoreFactory &secondaryFactory, const void *data,
+                        FixStoreDivergenceCallback onDivergence)
+      : sessionId(sessionId), primaryFactory(primaryFactory), secondaryFactory(secondaryFactory), primary(NULL),
+        secondary(NULL), data(data), onDivergence(onDivergence) {
+    primary = primaryFactory.create(now, sessionId);
+    try {
+      secondary = secondaryFactory.create(now, sessionId);
+      checkSeqNums();
+    } catch (...) {
+      if (secondary != NULL) {
+        secondaryFactory.destroy(secondary);
+      }
+      primaryFactory.destroy(primary);
+      throw;
+    }
+  }
+
+  CompositeMessageStore(const CompositeMessageStore &) = delete;
+  CompositeMessageStore &operator=(const CompositeMessageStore &) = delete;
+
+  virtual ~CompositeMessageStore() {
+    secondaryFactory.destroy(secondary);
+    primaryFactory.destroy(primary);
+  }
+
+  bool set(FixSeqNum seqNum, const std::string &msg) EXCEPT(IOException) override {
+    bool stored = primary->set(seqNum, msg);
+    try {
+      if (secondary->set(seqNum, msg) != stored) {
+        report("message " + std::to_string(seqNum) + (stored ? " not stored" : " stored anyway"));
+      }
+    } catch (std::exception &e) {
+      report("cannot store message " + std::to_string(seqNum) + ": " + e.what());
+    }
+    return stored;
+  }
+
+  void get(FixSeqNum begin, FixSeqNum end, std::vector<std::string> &output) const EXCEPT(IOException) override {
+    primary->get(begin, end, output);
+  }
+
+  FixSeqNum getNextSenderMsgSeqNum() const EXCEPT(IOException) override { return primary->getNextSenderMsgSeqNum(); }
+  FixSeqNum getNextTargetMsgSeqNum() const EXCEPT(IOException) override { return primary->getNextTargetMsgSeqNum(); }
+
+  void setNextSenderMsgSeqNum(FixSeqNum value) EXCEPT(IOException) override {
+    write("update next sender sequence number", [&](MessageStore &store) { store.setNextSenderMsgSeqNum(value); });
+  }
+
+  void setNextTargetMsgSeqNum(FixSeqNum value) EXCEPT(IOException) override {
+    write("update next target sequence number", [&](MessageStore &store) { store.setNextTargetMsgSeqNum(value); });
+  }
+
+  void incrNextSenderMsgSeqNum() EXCEPT(IOException) override {
+    write("increment next sender sequence number", [](MessageStore &store) { store.incrNextSenderMsgSeqNum(); });
+  }
+
+  void incrNextTargetMsgSeqNum() EXCEPT(IOException) override {
+    write("increment next target sequence number", [](MessageStore &store) { store.incrNextTargetMsgSeqNum(); });
+  }
+
+  UtcTimeStamp getCreationTime() const EXCEPT(IOException) override { return primary->getCreationTime(); }
+
+  void reset(const UtcTimeStamp &now) EXCEPT(IOException) override {
+    write("reset", [&](MessageStore &store) { store.reset(now); });
+  }
+
+  void refresh() EXCEPT(IOException) override {
+    write("refresh", [](MessageStore &store) { store.refresh(); });
+  }
+};
+
+class CompositeMessageStoreFactory : public MessageStoreFactory {
+private:
+  MessageStoreFactory &primary;
+  MessageStoreFactory &secondary;
+  const void *data;
+  FixStoreDivergenceCallback onDivergence;
+
+public:
+  CompositeMessageStoreFactory(MessageStoreFactory &primary, MessageStoreFactory &secondary, const void *data,
+                               FixStoreDivergenceCallback onDivergence)
+      : primary(primary), secondary(secondary), data(data), onDivergence(onDivergence) {}
+
+  CompositeMessageStoreFactory(const CompositeMessageStoreFactory &) = delete;
+  CompositeMessageStoreFactory &operator=(const CompositeMessageStoreFactory &) = delete;
+
+  virtual ~CompositeMessageStoreFactory() {}
+
+  MessageStore *create(const UtcTimeStamp &now, const SessionID &sessionId) override {
+    return new CompositeMessageStore(now, sessionId, primary, secondary, data, onDivergence);
+  }
+
+  void destroy(MessageStore *store) override { delete store; }
+};
+
+static std::mutex fileStorePrefixesMutex;
+static std::map<const MessageStore *, std::string> fileStorePrefixes;
+
+// Same as `FileStoreFactory`, remembering where each store writes its files so their size can be reported.
+class FixFileStoreFactory : public FileStoreFactory {
+private:
+  SessionSettings settings;
+  std::string path;
+  bool useSettings;
+
+public:
+  explicit FixFileStoreFactory(const SessionSettings &settings)
+      : FileStoreFactory(settings), settings(settings), useSettings(true) {}
+  explicit FixFileStoreFactory(const std::string &path) : FileStoreFactory(path), path(path), useSettings(false) {}
+
+  MessageStore *create(const UtcTimeStamp &now, const SessionID &sessionId) override {
+    MessageStore *store = FileStoreFactory::create(now, sessionId);
+
+    // Same naming as `FileStore`.
+    std::string name = sessionId.getBeginString().getValue() + "-" + sessionId.getSenderCompID().getValue() + "-" +
+                       sessionId.getTargetCompID().getValue();
+    if (!sessionId.getSessionQualifier().empty()) {
+      name += "-" + sessionId.getSessionQualifier();
+    }
+    const std::string storePath = useSettings ? settings.get(sessionId).getString(FILE_STORE_PATH) : path;
+
+    std::lock_guard<std::mutex> lock(fileStorePrefixesMutex);
+    fileStorePrefixes[store] = file_appendpath(storePath, name + ".");
+    return store;
+  }
+
+  void destroy(MessageStore *store) override {
+    {
+      std::lock_guard<std::mutex> lock(fileStorePrefixesMutex);
+      fileStorePrefixes.erase(store);
+    }
+    FileStoreFactory::destroy(store);
+  }
+};
+
+// Size of the files written by a file store, -1 for other stores.
+static int64_t FixFileStore_getSize(const MessageStore *store) {
+  std::string prefix;
+  {
+    std::lock_guard<std::mutex> lock(fileStorePrefixesMutex);
+    auto it = fileStorePrefixes.find(store);
+    if (it == fileStorePrefixes.end()) {
+      return -1;
+    }
+    prefix = it->second;
+  }
+
+  int64_t size = 0;
+  for (const char *suffix : {"body", "header", "seqnums", "session"}) {
+    std::error_code error;
+    const std::uintmax_t fileSize = std::filesystem::file_size(prefix + suffix, error);
+    if (!error) {
+      size += static_cast<int64_t>(fileSize);
+    }
+  }
+  return size;
+}
+
+// Store of a single session, opened from its factory without any handler, e.g. while engine is down.
+class FixOfflineStore {
+private:
+  MessageStoreFactory &factory;
+  MessageStore *store;
+
+public:
+  FixOfflineStore(MessageStoreFactory &factory, const SessionID &sessionId)
+      : factory(factory), store(factory.create(UtcTimeStamp::now(), sessionId)) {}
+
+  FixOfflineStore(const FixOfflineStore &) = delete;
+  FixOfflineStore &operator=(const FixOfflineStore &) = delete;
+
+  ~FixOfflineStore() { factory.destroy(store); }
+
+  MessageStore *operator->() const { return store; }
+  MessageStore &operator*() const { return *store; }
+};
+
+// Replace whole store content with a snapshot, creation time included.
+static void FixMessageStore_import(MessageStore &store, const FixStoreSnapshot_t &snapshot) {
+  store.reset(FixUtcTimeStamp_fromMillis(snapshot.creationTime));
+  for (uint64_t i = 0; i < snapshot.recordsLen; i++) {
+    const FixStoreRecord_t &record = snapshot.records[i];
+    if (!store.set(record.seqNum, std::string(record.msg, record.len))) {
+      throw IOException("Cannot import message " + std::to_string(record.seqNum));
+    }
+  }
+  store.setNextSenderMsgSeqNum(snapshot.nextSender);
+  store.setNextTargetMsgSeqNum(snapshot.nextTarget);
+}
+
+#if defined(HAVE_MYSQL) || defined(HAVE_POSTGRESQL)
+// SQL stores connect when session is created and only report "Unable to connect to database": name the session and
+// the connection settings in use (password excluded), so failing handler start can be diagnosed.
+template <typename Factory> class DescribedSqlStoreFactory : public Factory {
+private:
+  SessionSettings settings;
+  std::string keyPrefix;
+
+  std::string describe(const SessionID &sessionId) const {
+    std::string output = keyPrefix + " of session " + sessionId.toString();
+    if (!settings.has(sessionId)) {
+      return output;
+    }
+
+    const Dictionary &dict = settings.get(sessionId);
+    for (const char *suffix : {"Database", "User", "Host", "Port"}) {
+      std::string key = keyPrefix + suffix;
+      if (dict.has(key)) {
+        output += ", " + key + "=" + dict.getString(key);
+      }
+    }
+    return output;
+  }
+
+public:
+  DescribedSqlStoreFactory(const SessionSettings &settings, const char *keyPrefix)
+      : Factory(settings), settings(settings), keyPrefix(keyPrefix) {}
+
+  MessageStore *create(const UtcTimeStamp &now, const SessionID &sessionId) override {
+    try {
+      return Factory::create(now, sessionId);
+    } catch (ConfigError &e) {
+      throw ConfigError(describe(sessionId) + ": " + e.detail);
+    }
+  }
+};
+#endif // HAVE_MYSQL || HAVE_POSTGRESQL
+
+SessionSettings *FixSessionSettings_new() {
+  CATCH_OR_RETURN_NULL({ return new SessionSettings(); });
+}
+
+SessionSettings *FixSessionSettings_copy(const SessionSettings *src) {
+  RETURN_VAL_IF_NULL(src, NULL);
+  CATCH_OR_RETURN_NULL({ return new SessionSettings(*src); });
+}
+
+SessionSettings *FixSessionSettings_fromPath(const char *configPath) {
+  CATCH_OR_RETURN_NULL({ return new SessionSettings(configPath); });
+}
+
+SessionSettings *FixSessionSettings_fromString(const char *text) {
+  RETURN_VAL_IF_NULL(text, NULL);
+  CATCH_OR_RETURN_NULL({
+    std::istringstream stream(text);
+    return new SessionSettings(stream);
+  });
+}
+
+int64_t FixSessionSettings_getStringLen(const SessionSettings *obj) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    std::ostringstream stream;
+    stream << *obj;
+    return stream.str().size() + 1;
+  });
+}
+
+int8_t FixSessionSettings_readString(const SessionSettings *obj, char *buffer, uint64_t buffer_len) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(buffer, ERRNO_INVAL);
+
+  CATCH_OR_RETURN_ERRNO({
+    std::ostringstream stream;
+    stream << *obj;
+    auto value = stream.str();
+    if (buffer_len <= value.size()) {
+      return ERRNO_BUFFER_TO_SMALL;
+    }
+
+    strncpy(buffer, value.c_str(), buffer_len);
+    buffer[value.size()] = '\0';
+
+    return 0;
+  })
+}
+
+const Dictionary *FixSessionSettings_getGlobalRef(const SessionSettings *obj) {
+  RETURN_VAL_IF_NULL(obj, NULL);
+  CATCH_OR_RETURN_NULL({ return &obj->get(); });
+}
+
+const Dictionary *FixSessionSettings_getSessionRef(const SessionSettings *obj, const SessionID *id) {
+  RETURN_VAL_IF_NULL(obj, NULL);
+  RETURN_VAL_IF_NULL(id, NULL);
+  CATCH_OR_RETURN_NULL({ return &obj->get(*id); });
+}
+
+int8_t FixSessionSettings_setGlobal(SessionSettings *obj, const Dictionary *value) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(value, ERRNO_INVAL);
+
+  CATCH_OR_RETURN_ERRNO({
+    obj->set(*value);
+    return 0;
+  })
+}
+
+int8_t FixSessionSettings_setSession(SessionSettings *obj, const SessionID *id, const Dictionary *value) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(id, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(value, ERRNO_INVAL);
+
+  CATCH_OR_RETURN_ERRNO({
+    if (!obj->has(*id)) {
+      obj->set(*id, *value);
+      return 0;
+    }
+
+    // QuickFIX refuses duplicated sessions. Let it merge defaults / validate dictionary
+    // in a scratch container, then replace existing session content.
+    SessionSettings scratch;
+    scratch.set(obj->get());
+    scratch.set(*id, *value);
+    const_cast<Dictionary &>(obj->get(*id)) = scratch.get(*id);
+    return 0;
+  })
+}
+
+int64_t FixSessionSettings_getSessionCount(const SessionSettings *obj) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({ return obj->getSessions().size(); });
+}
+
+FixSessionID_t *FixSessionSettings_getSessionIdAt(const SessionSettings *obj, uint64_t index) {
+  RETURN_VAL_IF_NULL(obj, NULL);
+  CATCH_OR_RETURN_NULL({
+    const std::set<SessionID> sessions = obj->getSessions();
+    if (index >= sessions.size()) {
+      throw std::out_of_range("Session index out of range");
+    }
+    return new SessionID(*std::next(sessions.begin(), index));
+  });
+}
+
+void FixSessionSettings_delete(const SessionSettings *obj) {
+  RETURN_IF_NULL(obj);
+  delete obj;
+}
+
+Dictionary *FixDictionary_new(const char *name) {
+  CATCH_OR_RETURN_NULL({ return new Dictionary(name); });
+}
+
+Dictionary *FixDictionary_copy(const Dictionary *src) {
+  RETURN_VAL_IF_NULL(src, NULL);
+  CATCH_OR_RETURN_NULL({ return new Dictionary(*src); });
+}
+
+int8_t FixDictionary_setString(Dictionary *obj, const char *key, const char *value) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(key, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(value, ERRNO_INVAL);
+
+  CATCH_OR_RETURN_ERRNO({
+    obj->setString(key, value);
+    return 0;
+  })
+}
+
+int8_t FixDictionary_setInt(Dictionary *obj, const char *key, int32_t value) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(key, ERRNO_INVAL);
+
+  CATCH_OR_RETURN_ERRNO({
+    obj->setInt(key, value);
+    return 0;
+  })
+}
+
+int8_t FixDictionary_setDouble(Dictionary *obj, const char *key, double value) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(key, ERRNO_INVAL);
+
+  CATCH_OR_RETURN_ERRNO({
+    obj->setDouble(key, value);
+    return 0;
+  })
+}
+
+int8_t FixDictionary_setBool(Dictionary *obj, const char *key, int8_t value) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(key, ERRNO_INVAL);
 
   CATCH_OR_RETURN_ERRNO({
     obj->setBool(key, value);
@@ -560,6 +3207,113 @@ int32_t FixDictionary_getDay(const Dictionary *obj, const char *key) {
   CATCH_OR_RETURN_ERRNO({ return obj->getDay(key); })
 }
 
+int8_t FixDictionary_readInt(const Dictionary *obj, const char *key, int32_t *value) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(key, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(value, ERRNO_INVAL);
+
+  CATCH_OR_RETURN_ERRNO({
+    *value = IntConvertor::convert(FixDictionary_getExistingString(*obj, key));
+    return 0;
+  })
+}
+
+int8_t FixDictionary_readDouble(const Dictionary *obj, const char *key, double *value) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(key, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(value, ERRNO_INVAL);
+
+  CATCH_OR_RETURN_ERRNO({
+    *value = DoubleConvertor::convert(FixDictionary_getExistingString(*obj, key));
+    return 0;
+  })
+}
+
+int8_t FixDictionary_readBool(const Dictionary *obj, const char *key, int8_t *value) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(key, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(value, ERRNO_INVAL);
+
+  CATCH_OR_RETURN_ERRNO({
+    *value = BoolConvertor::convert(FixDictionary_getExistingString(*obj, key));
+    return 0;
+  })
+}
+
+int8_t FixDictionary_readDay(const Dictionary *obj, const char *key, int32_t *value) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(key, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(value, ERRNO_INVAL);
+
+  CATCH_OR_RETURN_ERRNO({
+    const std::string day = FixDictionary_getExistingString(*obj, key);
+    try {
+      *value = obj->getDay(key);
+    } catch (ConfigError &e) {
+      // Key exists, so error can only come from an illegal day name.
+      throw FieldConvertError(e.what());
+    }
+    // Unknown names of at least two letters are not an error for quickfix.
+    if (*value == -1) {
+      throw FieldConvertError("Illegal value " + day + " for " + key);
+    }
+    return 0;
+  })
+}
+
+int8_t FixDictionary_merge(Dictionary *obj, const Dictionary *other) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(other, ERRNO_INVAL);
+
+  CATCH_OR_RETURN_ERRNO({
+    obj->merge(*other);
+    return 0;
+  })
+}
+
+// Walk a copy of a dictionary, so the source can change or be deleted while iterating.
+struct DictionaryCursor {
+  explicit DictionaryCursor(const Dictionary &source) : snapshot(source), current(snapshot.begin()) {}
+
+  const Dictionary snapshot;
+  Dictionary::iterator current;
+};
+
+DictionaryCursor *FixDictionary_cursor(const Dictionary *obj) {
+  RETURN_VAL_IF_NULL(obj, NULL);
+
+  CATCH_OR_RETURN_NULL({ return new DictionaryCursor(*obj); });
+}
+
+// Borrow current key / value, valid until cursor is deleted, then move to next entry.
+// Return 1 when an entry is read and 0 once all entries are read.
+int8_t FixDictionaryCursor_next(DictionaryCursor *obj, const char **key, uint64_t *key_len, const char **value,
+                                uint64_t *value_len) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(key, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(key_len, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(value, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(value_len, ERRNO_INVAL);
+
+  CATCH_OR_RETURN_ERRNO({
+    if (obj->current == obj->snapshot.end()) {
+      return 0;
+    }
+
+    *key = obj->current->first.data();
+    *key_len = obj->current->first.size();
+    *value = obj->current->second.data();
+    *value_len = obj->current->second.size();
+    ++obj->current;
+    return 1;
+  });
+}
+
+void FixDictionaryCursor_delete(const DictionaryCursor *obj) {
+  RETURN_IF_NULL(obj);
+  delete obj;
+}
+
 int8_t FixDictionary_hasKey(const Dictionary *obj, const char *key) {
   RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
   RETURN_VAL_IF_NULL(key, ERRNO_INVAL);
@@ -579,6 +3333,159 @@ DataDictionary *FixDataDictionary_fromPath(const char *configPath) {
   CATCH_OR_RETURN_NULL({ return new DataDictionary(configPath); });
 }
 
+int64_t FixDataDictionary_getFieldNameLen(const DataDictionary *obj, int32_t tag) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+
+  CATCH_OR_RETURN_ERRNO({
+    std::string name;
+    if (!obj->getFieldName(tag, name)) {
+      return 0;
+    }
+    return name.size() + 1;
+  });
+}
+
+int8_t FixDataDictionary_readFieldName(const DataDictionary *obj, int32_t tag, char *buffer, uint64_t buffer_len) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(buffer, ERRNO_INVAL);
+
+  CATCH_OR_RETURN_ERRNO({
+    std::string name;
+    if (!obj->getFieldName(tag, name)) {
+      return 0;
+    }
+    return FixDataDictionary_copyToBuffer(name, buffer, buffer_len);
+  });
+}
+
+int8_t FixDataDictionary_getFieldTag(const DataDictionary *obj, const char *name, int32_t *tag) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(name, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(tag, ERRNO_INVAL);
+
+  CATCH_OR_RETURN_ERRNO({
+    int field = 0;
+    if (!obj->getFieldTag(name, field)) {
+      return 0;
+    }
+    *tag = field;
+    return 1;
+  });
+}
+
+const char *FixDataDictionary_getFieldTypeName(const DataDictionary *obj, int32_t tag) {
+  RETURN_VAL_IF_NULL(obj, NULL);
+
+  CATCH_OR_RETURN_NULL({
+    TYPE::Type type = TYPE::Unknown;
+    if (!obj->getFieldType(tag, type)) {
+      return NULL;
+    }
+    return FixDataDictionary_typeName(type);
+  });
+}
+
+int64_t FixDataDictionary_getValueNameLen(const DataDictionary *obj, int32_t tag, const char *value) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(value, ERRNO_INVAL);
+
+  CATCH_OR_RETURN_ERRNO({
+    std::string name;
+    if (!obj->getValueName(tag, value, name)) {
+      return 0;
+    }
+    return name.size() + 1;
+  });
+}
+
+int8_t FixDataDictionary_readValueName(const DataDictionary *obj, int32_t tag, const char *value, char *buffer,
+                                       uint64_t buffer_len) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(value, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(buffer, ERRNO_INVAL);
+
+  CATCH_OR_RETURN_ERRNO({
+    std::string name;
+    if (!obj->getValueName(tag, value, name)) {
+      return 0;
+    }
+    return FixDataDictionary_copyToBuffer(name, buffer, buffer_len);
+  });
+}
+
+int64_t FixDataDictionary_getMessageFieldCount(const DataDictionary *obj, const char *msgType) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(msgType, ERRNO_INVAL);
+
+  CATCH_OR_RETURN_ERRNO({
+    const auto &messageFields = obj->bindMessageFields();
+    auto fields = messageFields.find(msgType);
+    if (fields == messageFields.end()) {
+      return 0;
+    }
+    return fields->second.size();
+  });
+}
+
+int8_t FixDataDictionary_getMessageFieldAt(const DataDictionary *obj, const char *msgType, uint64_t index,
+                                           int32_t *tag) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(msgType, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(tag, ERRNO_INVAL);
+
+  CATCH_OR_RETURN_ERRNO({
+    const auto &messageFields = obj->bindMessageFields();
+    auto fields = messageFields.find(msgType);
+    if (fields == messageFields.end() || index >= fields->second.size()) {
+      return ERRNO_INVAL;
+    }
+    *tag = *std::next(fields->second.begin(), index);
+    return 0;
+  });
+}
+
+int8_t FixDataDictionary_isRequiredField(const DataDictionary *obj, const char *msgType, int32_t tag) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(msgType, ERRNO_INVAL);
+
+  CATCH_OR_RETURN_ERRNO({ return obj->isRequiredField(msgType, tag); });
+}
+
+const DataDictionary *FixDataDictionary_getGroupRef(const DataDictionary *obj, const char *msgType, int32_t tag,
+                                                    int32_t *delimiter) {
+  RETURN_VAL_IF_NULL(obj, NULL);
+  RETURN_VAL_IF_NULL(msgType, NULL);
+  RETURN_VAL_IF_NULL(delimiter, NULL);
+
+  CATCH_OR_RETURN_NULL({
+    int delim = 0;
+    const DataDictionary *group = nullptr;
+    if (!obj->getGroup(msgType, tag, delim, group)) {
+      return (const DataDictionary *)nullptr;
+    }
+    *delimiter = delim;
+    return group;
+  });
+}
+
+int8_t FixDataDictionary_validate(const DataDictionary *obj, const Message *msg, int32_t *field) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(msg, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(field, ERRNO_INVAL);
+
+  CATCH_OR_RETURN_ERRNO({
+    *field = 0;
+    try {
+      obj->validate(*msg);
+    } catch (FieldException &e) {
+      // Keep failing tag, error code is set by the catch macro.
+      *field = e.field;
+      throw;
+    }
+    return 0;
+  });
+}
+
 void FixDataDictionary_delete(const DataDictionary *obj) {
   RETURN_IF_NULL(obj);
   delete obj;
@@ -586,7 +3493,12 @@ void FixDataDictionary_delete(const DataDictionary *obj) {
 
 MessageStoreFactory *FixFileMessageStoreFactory_new(const SessionSettings *settings) {
   RETURN_VAL_IF_NULL(settings, NULL);
-  CATCH_OR_RETURN_NULL({ return new FileStoreFactory(*settings); });
+  CATCH_OR_RETURN_NULL({ return new FixFileStoreFactory(*settings); });
+}
+
+MessageStoreFactory *FixFileMessageStoreFactory_newWithPath(const char *path) {
+  RETURN_VAL_IF_NULL(path, NULL);
+  CATCH_OR_RETURN_NULL({ return new FixFileStoreFactory(std::string(path)); });
 }
 
 MessageStoreFactory *FixMemoryMessageStoreFactory_new() {
@@ -597,20 +3509,86 @@ FixMessageStoreFactory_t *FixNullMessageStoreFactory_new() {
   CATCH_OR_RETURN_NULL({ return new NullStoreFactory(); });
 }
 
+FixMessageStoreFactory_t *FixCustomMessageStoreFactory_new(const void *data, const MessageStoreCallbacks *callbacks) {
+  RETURN_VAL_IF_NULL(callbacks, NULL);
+  CATCH_OR_RETURN_NULL({ return new ExternalMessageStoreFactory(data, callbacks); });
+}
+
+void FixCustomMessageStore_setError(const char *message) {
+  RETURN_IF_NULL(message);
+  CATCH_OR_DISCARD({ customMessageStoreError = message; });
+}
+
+FixMessageStoreFactory_t *FixCompositeMessageStoreFactory_new(MessageStoreFactory *primary,
+                                                              MessageStoreFactory *secondary, const void *data,
+                                                              FixStoreDivergenceCallback onDivergence) {
+  RETURN_VAL_IF_NULL(primary, NULL);
+  RETURN_VAL_IF_NULL(secondary, NULL);
+  CATCH_OR_RETURN_NULL({ return new CompositeMessageStoreFactory(*primary, *secondary, data, onDivergence); });
+}
+
 #ifdef HAVE_MYSQL
 MessageStoreFactory *FixMysqlMessageStoreFactory_new(const SessionSettings *settings) {
   RETURN_VAL_IF_NULL(settings, NULL);
-  CATCH_OR_RETURN_NULL({ return new MySQLStoreFactory(*settings); });
+  CATCH_OR_RETURN_NULL({ return new DescribedSqlStoreFactory<MySQLStoreFactory>(*settings, "MySQLStore"); });
 }
 #endif // HAVE_MYSQL
 
 #ifdef HAVE_POSTGRESQL
 MessageStoreFactory *FixPostgresMessageStoreFactory_new(const SessionSettings *settings) {
   RETURN_VAL_IF_NULL(settings, NULL);
-  CATCH_OR_RETURN_NULL({ return new PostgreSQLStoreFactory(*settings); });
+  CATCH_OR_RETURN_NULL({ return new DescribedSqlStoreFactory<PostgreSQLStoreFactory>(*settings, "PostgreSQLStore"); });
 }
 #endif // HAVE_POSTGRESQL
 
+int8_t FixMessageStoreFactory_resetStore(MessageStoreFactory *obj, const SessionID *sessionId) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(sessionId, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    FixOfflineStore store(*obj, *sessionId);
+    store->reset(UtcTimeStamp::now());
+    return 0;
+  });
+}
+
+int8_t FixMessageStoreFactory_getSequenceNumbers(MessageStoreFactory *obj, const SessionID *sessionId,
+                                                 uint32_t *nextSender, uint32_t *nextTarget) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(sessionId, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(nextSender, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(nextTarget, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    FixOfflineStore store(*obj, *sessionId);
+    *nextSender = static_cast<uint32_t>(store->getNextSenderMsgSeqNum());
+    *nextTarget = static_cast<uint32_t>(store->getNextTargetMsgSeqNum());
+    return 0;
+  });
+}
+
+int8_t FixMessageStoreFactory_setSequenceNumbers(MessageStoreFactory *obj, const SessionID *sessionId,
+                                                 uint32_t nextSender, uint32_t nextTarget) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(sessionId, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    FixOfflineStore store(*obj, *sessionId);
+    store->setNextSenderMsgSeqNum(nextSender);
+    store->setNextTargetMsgSeqNum(nextTarget);
+    return 0;
+  });
+}
+
+int8_t FixMessageStoreFactory_importStore(MessageStoreFactory *obj, const SessionID *sessionId,
+                                          const FixStoreSnapshot_t *snapshot) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(sessionId, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(snapshot, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    FixOfflineStore store(*obj, *sessionId);
+    FixMessageStore_import(*store, *snapshot);
+    return 0;
+  });
+}
+
 void FixMessageStoreFactory_delete(const MessageStoreFactory *obj) {
   RETURN_IF_NULL(obj);
   delete obj;
@@ -620,19 +3598,124 @@ LogFactory *FixLogFactory_new(const void *data, const LogCallbacks *callbacks) {
   CATCH_OR_RETURN_NULL({ return new ExternalLogFactory(data, callbacks); });
 }
 
-void FixLogFactory_delete(const LogFactory *obj) {
-  RETURN_IF_NULL(obj);
-  delete obj;
+LogFactory *FixSessionLogFactory_new(const void *data, const SessionLogFactoryCallbacks *factoryCallbacks,
+                                     const LogCallbacks *callbacks) {
+  RETURN_VAL_IF_NULL(factoryCallbacks, NULL);
+  RETURN_VAL_IF_NULL(callbacks, NULL);
+  CATCH_OR_RETURN_NULL({ return new SessionLogFactory(data, factoryCallbacks, callbacks); });
+}
+
+void FixLogFactory_delete(const LogFactory *obj) {
+  RETURN_IF_NULL(obj);
+  delete obj;
+}
+
+Application *FixApplication_new(const void *data, const ApplicationCallbacks *callbacks) {
+  RETURN_VAL_IF_NULL(callbacks, NULL);
+  CATCH_OR_RETURN_NULL({ return new ApplicationBind(data, callbacks); });
+}
+
+void FixApplication_delete(const Application *obj) {
+  RETURN_IF_NULL(obj);
+  delete obj;
+}
+
+void FixApplication_setCallbackErrorText(const char *text) {
+  CATCH_OR_DISCARD({ callbackError.text = text ? text : ""; });
+}
+
+void FixApplication_setCallbackErrorField(int32_t field) { callbackError.field = field; }
+
+// Acceptor sessions maps have no lock, quickfix only iterates over them once stopping.
+// Bind functions read them with the guard locked shared, and session removal modifies them locked exclusively.
+struct FixAcceptorSessionsGuard {
+  std::shared_mutex mutex;
+  // Set by stop functions before quickfix iterates over sessions, so they cannot be removed meanwhile.
+  bool stopping = false;
+};
+
+static std::mutex acceptorSessionsGuardsMutex;
+static std::map<const Acceptor *, std::unique_ptr<FixAcceptorSessionsGuard>> acceptorSessionsGuards;
+
+static FixAcceptorSessionsGuard &FixAcceptor_getSessionsGuard(const Acceptor *obj) {
+  std::lock_guard<std::mutex> lock(acceptorSessionsGuardsMutex);
+  auto &guard = acceptorSessionsGuards[obj];
+  if (!guard) {
+    guard.reset(new FixAcceptorSessionsGuard());
+  }
+  return *guard;
+}
+
+static void FixAcceptor_deleteSessionsGuard(const Acceptor *obj) {
+  std::lock_guard<std::mutex> lock(acceptorSessionsGuardsMutex);
+  acceptorSessionsGuards.erase(obj);
 }
 
-Application *FixApplication_new(const void *data, const ApplicationCallbacks *callbacks) {
-  RETURN_VAL_IF_NULL(callbacks, NULL);
-  CATCH_OR_RETURN_NULL({ return new ApplicationBind(data, callbacks); });
+static void FixAcceptor_setStopping(const Acceptor *obj, bool stopping) {
+  FixAcceptorSessionsGuard &guard = FixAcceptor_getSessionsGuard(obj);
+  std::unique_lock<std::shared_mutex> lock(guard.mutex);
+  guard.stopping = stopping;
 }
 
-void FixApplication_delete(const Application *obj) {
-  RETURN_IF_NULL(obj);
-  delete obj;
+// Socket acceptor listening on a unix domain socket for sessions configured with `SocketAcceptPath`, and on TCP
+// ports for other ones. Quickfix finds sessions of a connection from the port it was accepted on: every path is
+// mapped to a negative port of its own.
+class FixPathSocketAcceptor : public SocketAcceptor {
+  std::map<std::string, int> pathPorts;
+  std::set<std::string> boundPaths;
+
+public:
+  using SocketAcceptor::SocketAcceptor;
+
+  ~FixPathSocketAcceptor() override {
+    for (const auto &path : boundPaths) {
+      FixSocket_unlinkPath(path);
+    }
+  }
+
+private:
+  void onConfigure(const SessionSettings &settings) override {
+    bindOnConfigure(FixSessionSettings_withoutKey(settings, SOCKET_ACCEPT_PATH));
+  }
+
+  // Called on every start, once server has been created again.
+  void onInitialize(const SessionSettings &settings) override {
+    bindOnInitialize(FixSessionSettings_withoutKey(settings, SOCKET_ACCEPT_PATH));
+
+    SocketServer *server = bindServer();
+    auto &portToSessions = bindPortToSessions();
+    for (const auto &sessionId : settings.getSessions()) {
+      const Dictionary &dict = settings.get(sessionId);
+      if (!dict.has(SOCKET_ACCEPT_PATH)) {
+        continue;
+      }
+
+      const std::string path = dict.getString(SOCKET_ACCEPT_PATH);
+      const int port = pathPorts.emplace(path, -static_cast<int>(pathPorts.size()) - 1).first->second;
+      portToSessions[port].insert(sessionId);
+      if (server->portToSocket(port) == INVALID_SOCKET_HANDLE) {
+        server->bindAddListener(FixSocket_listenPath(path), port);
+        boundPaths.insert(path);
+      }
+    }
+  }
+};
+
+static Acceptor *FixAcceptor_create(Application &application, MessageStoreFactory &storeFactory,
+                                    const SessionSettings &settings, LogFactory &logFactory, int8_t isMultiThreaded,
+                                    int8_t isSslEnabled) {
+  FixSessionSettings_checkPaths(settings, SOCKET_ACCEPT_PATH, !isMultiThreaded && !isSslEnabled);
+  if (isMultiThreaded && isSslEnabled) {
+    return new ThreadedSSLSocketAcceptor(application, storeFactory, settings, logFactory);
+  } else if (isMultiThreaded && !isSslEnabled) {
+    return new ThreadedSocketAcceptor(application, storeFactory, settings, logFactory);
+  } else if (!isMultiThreaded && isSslEnabled) {
+    return new SSLSocketAcceptor(application, storeFactory, settings, logFactory);
+  } else if (FixSessionSettings_hasKey(settings, SOCKET_ACCEPT_PATH)) {
+    return new FixPathSocketAcceptor(application, storeFactory, settings, logFactory);
+  } else {
+    return new SocketAcceptor(application, storeFactory, settings, logFactory);
+  }
 }
 
 Acceptor *FixAcceptor_new(Application *application, MessageStoreFactory *storeFactory, const SessionSettings *settings,
@@ -643,21 +3726,22 @@ Acceptor *FixAcceptor_new(Application *application, MessageStoreFactory *storeFa
   RETURN_VAL_IF_NULL(settings, NULL);
 
   CATCH_OR_RETURN_NULL({
-    if (isMultiThreaded && isSslEnabled) {
-      return new ThreadedSSLSocketAcceptor(*application, *storeFactory, *settings, *logFactory);
-    } else if (isMultiThreaded && !isSslEnabled) {
-      return new ThreadedSocketAcceptor(*application, *storeFactory, *settings, *logFactory);
-    } else if (!isMultiThreaded && isSslEnabled) {
-      return new SSLSocketAcceptor(*application, *storeFactory, *settings, *logFactory);
-    } else {
-      return new SocketAcceptor(*application, *storeFactory, *settings, *logFactory);
-    }
+    FixSession_registerDictionaries(*settings);
+    auto wrappedLogFactory =
+        std::make_unique<FixTapLogFactory>(*logFactory, dynamic_cast<ApplicationBind *>(application));
+    FixHandlerStateScope scope(wrappedLogFactory->getState());
+    Acceptor *obj = FixAcceptor_create(*application, *storeFactory, *settings, *wrappedLogFactory, isMultiThreaded,
+                                       isSslEnabled);
+
+    FixConnectionHandler_setLogFactory(obj, std::move(wrappedLogFactory));
+    return obj;
   });
 }
 
 int8_t FixAcceptor_start(Acceptor *obj) {
   RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
   CATCH_OR_RETURN_ERRNO({
+    FixAcceptor_setStopping(obj, false);
     obj->start();
     return 0;
   });
@@ -666,19 +3750,44 @@ int8_t FixAcceptor_start(Acceptor *obj) {
 int8_t FixAcceptor_block(Acceptor *obj) {
   RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
   CATCH_OR_RETURN_ERRNO({
+    FixAcceptor_setStopping(obj, false);
     obj->block();
     return 0;
   });
 }
 
-int8_t FixAcceptor_poll(Acceptor *obj) {
+int8_t FixAcceptor_poll(Acceptor *obj, double timeout) {
   RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
-  CATCH_OR_RETURN_ERRNO({ return obj->poll(); });
+  CATCH_OR_RETURN_ERRNO({ return obj->poll(timeout); });
 }
 
 int8_t FixAcceptor_stop(Acceptor *obj) {
   RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
   CATCH_OR_RETURN_ERRNO({
+    FixAcceptor_setStopping(obj, true);
+    FixConnectionHandler_invalidateResolved(obj);
+    obj->stop();
+    return 0;
+  });
+}
+
+int8_t FixAcceptor_stopForce(Acceptor *obj) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    FixAcceptor_setStopping(obj, true);
+    FixConnectionHandler_invalidateResolved(obj);
+    FixConnectionHandler_stopForce(obj);
+    return 0;
+  });
+}
+
+int8_t FixAcceptor_stopWithReason(Acceptor *obj, const char *reason) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(reason, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    FixAcceptor_setStopping(obj, true);
+    FixConnectionHandler_invalidateResolved(obj);
+    FixConnectionHandler_logoutAll(obj, reason);
     obj->stop();
     return 0;
   });
@@ -686,7 +3795,10 @@ int8_t FixAcceptor_stop(Acceptor *obj) {
 
 int8_t FixAcceptor_isLoggedOn(const Acceptor *obj) {
   RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
-  CATCH_OR_RETURN_ERRNO({ return obj->isLoggedOn(); });
+  CATCH_OR_RETURN_ERRNO({
+    std::shared_lock<std::shared_mutex> lock(FixAcceptor_getSessionsGuard(obj).mutex);
+    return obj->isLoggedOn();
+  });
 }
 
 int8_t FixAcceptor_isStopped(const Acceptor *obj) {
@@ -697,12 +3809,230 @@ int8_t FixAcceptor_isStopped(const Acceptor *obj) {
 FixSession_t *FixAcceptor_getSession(const FixAcceptor_t *obj, const FixSessionID_t *sessionId) {
   RETURN_VAL_IF_NULL(obj, NULL);
   RETURN_VAL_IF_NULL(sessionId, NULL);
-  CATCH_OR_RETURN_NULL({ return obj->getSession(*sessionId); });
+  CATCH_OR_RETURN_NULL({
+    std::shared_lock<std::shared_mutex> lock(FixAcceptor_getSessionsGuard(obj).mutex);
+    return obj->getSession(*sessionId);
+  });
+}
+
+int64_t FixAcceptor_getSessionCount(const FixAcceptor_t *obj) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    std::shared_lock<std::shared_mutex> lock(FixAcceptor_getSessionsGuard(obj).mutex);
+    return obj->getSessions().size();
+  });
+}
+
+FixSessionID_t *FixAcceptor_getSessionIdAt(const FixAcceptor_t *obj, uint64_t index) {
+  RETURN_VAL_IF_NULL(obj, NULL);
+  CATCH_OR_RETURN_NULL({
+    std::shared_lock<std::shared_mutex> lock(FixAcceptor_getSessionsGuard(obj).mutex);
+    const std::set<SessionID> sessions = obj->getSessions();
+    if (index >= sessions.size()) {
+      throw std::out_of_range("Session index out of range");
+    }
+    return new SessionID(*std::next(sessions.begin(), index));
+  });
+}
+
+int8_t FixAcceptor_removeSession(Acceptor *obj, const SessionID *id, int8_t forceDisconnect) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(id, ERRNO_INVAL);
+
+  CATCH_OR_RETURN_ERRNO({
+    FixAcceptorSessionsGuard &guard = FixAcceptor_getSessionsGuard(obj);
+    auto &sessions = obj->bindSessions();
+
+    Session *session = nullptr;
+    {
+      std::shared_lock<std::shared_mutex> lock(guard.mutex);
+      if (guard.stopping) {
+        throw RuntimeError("Cannot remove session from a stopping acceptor");
+      }
+      auto it = sessions.find(*id);
+      if (it == sessions.end()) {
+        throw SessionNotFound("No session found");
+      }
+      session = it->second;
+    }
+
+    // Logout may wait for counterparty, do not block readers meanwhile.
+    FixSession_logoutForRemoval(session, forceDisconnect);
+
+    {
+      std::unique_lock<std::shared_mutex> lock(guard.mutex);
+      if (guard.stopping) {
+        throw RuntimeError("Cannot remove session from a stopping acceptor");
+      }
+      auto it = sessions.find(*id);
+      if (it == sessions.end() || it->second != session) {
+        throw SessionNotFound("No session found");
+      }
+      sessions.erase(it);
+      obj->bindSessionIDs().erase(*id);
+    }
+    FixConnectionHandler_retireSession(obj, session);
+    return 0;
+  });
 }
 
 void FixAcceptor_delete(const Acceptor *obj) {
   RETURN_IF_NULL(obj);
-  delete obj;
+  CATCH_OR_DISCARD({
+    const std::vector<const Session *> sessions = FixConnectionHandler_sessions(obj);
+    FixSession_invalidateResolved(sessions, true);
+    const std::set<SessionID> sessionIds = obj->getSessions();
+    delete obj;
+    FixSession_forgetResolved(sessions);
+    FixAcceptor_deleteSessionsGuard(obj);
+    FixConnectionHandler_deleteRemovedSessions(obj);
+    FixSession_releaseDictionaries(sessionIds);
+    FixConnectionHandler_deleteLogFactory(obj);
+  });
+}
+
+extern "C++" {
+// Initiator reporting connection attempts of its sessions from its connect path.
+// Threaded initiators connect on a thread of their own which only logs failures, so their errors are unknown: a failed
+// attempt is reported once the session is connected again.
+template <typename T> class FixConnectHooks : public T {
+protected:
+  FixHandlerState *state;
+  ApplicationBind *application;
+
+  void reportFailure(const SessionID &sessionId, const FixConnectFailure &failure) {
+    if (application) {
+      application->onConnectFailed(sessionId, failure.attempt, failure.errorKind, failure.message);
+    }
+  }
+
+public:
+  template <typename... Args>
+  FixConnectHooks(FixHandlerState *state, ApplicationBind *application, Args &&...args)
+      : T(std::forward<Args>(args)...), state(state), application(application) {}
+
+protected:
+  // Connect a session, return the error of a connection failing at once, which is never handed to the initiator.
+  virtual int connectSession(const SessionID &sessionId, const Dictionary &dict) {
+    this->bindDoConnect(sessionId, dict);
+    return 0;
+  }
+
+private:
+  // Called with initiator mutex held, which connections need to be marked connected: attempt is begun before any
+  // socket is handed over.
+  void doConnect(const SessionID &sessionId, const Dictionary &dict) override {
+    const int error = connectSession(sessionId, dict);
+    // Nothing is attempted outside session time.
+    if (!error && !this->bindPending().count(sessionId) && !this->bindConnected().count(sessionId)) {
+      return;
+    }
+
+    const FixSessionKey key(state, sessionId);
+    FixConnectFailure failure;
+    failure.attempt = FixSession_beginConnectAttempt(key);
+    failure.message = "Connection failed";
+    if (failure.attempt > 0) {
+      reportFailure(sessionId, failure);
+    }
+    if (error && FixSession_completeConnectAttempt(key, error, failure)) {
+      reportFailure(sessionId, failure);
+    }
+  }
+};
+
+// Socket initiators complete attempts as soon as their socket connects or fails to, with the error read from it.
+template <typename T> class FixSocketConnectHooks : public FixConnectHooks<T> {
+public:
+  using FixConnectHooks<T>::FixConnectHooks;
+
+private:
+  void onConnect(SocketConnector &connector, socket_handle socket) override {
+    const auto &pending = this->bindPendingConnections();
+    auto it = pending.find(socket);
+    const Session *session = it == pending.end() ? nullptr : it->second->getSession();
+    if (!session) {
+      this->bindOnConnect(connector, socket);
+      return;
+    }
+
+    const SessionID sessionId = session->getSessionID();
+    const FixSessionKey key(this->state, sessionId);
+    const int error = FixSocket_getConnectError(socket);
+    FixConnectFailure failure;
+    const bool failed = FixSession_completeConnectAttempt(key, error, failure);
+    if (error == 0) {
+      FixSession_getTransportTracker(key).setPendingConnectAt(FixSession_nowMillis(), true);
+    }
+
+    // Failed sockets are handed to the session too, which disconnects once it cannot write its Logon.
+    this->bindOnConnect(connector, socket);
+    if (failed) {
+      this->reportFailure(sessionId, failure);
+    }
+  }
+};
+} // extern C++
+
+// Socket initiator connecting sessions configured with `SocketConnectPath` to a unix domain socket, and other ones
+// over TCP.
+class FixPathSocketInitiator : public FixSocketConnectHooks<SocketInitiator> {
+public:
+  using FixSocketConnectHooks<SocketInitiator>::FixSocketConnectHooks;
+
+protected:
+  int connectSession(const SessionID &sessionId, const Dictionary &dict) override {
+    if (!dict.has(SOCKET_CONNECT_PATH)) {
+      return FixSocketConnectHooks<SocketInitiator>::connectSession(sessionId, dict);
+    }
+
+    Session *session = Session::lookupSession(sessionId);
+    if (!session->isSessionTime(UtcTimeStamp::now())) {
+      return 0;
+    }
+
+    const std::string path = dict.getString(SOCKET_CONNECT_PATH);
+    session->getLog()->onEvent("Connecting to " + path);
+    int error = 0;
+    const socket_handle socket = FixSocket_connectPath(path, error);
+    if (socket == INVALID_SOCKET_HANDLE) {
+      session->getLog()->onEvent("Connection failed: " + std::string(strerror(error)));
+      return error;
+    }
+
+    // Handed over like quickfix does for TCP: session is connected once its socket is reported writable.
+    SocketMonitor &monitor = bindConnector().getMonitor();
+    setPending(sessionId);
+    bindPendingConnections()[socket] = new SocketConnection(*this, sessionId, socket, &monitor);
+    monitor.addConnect(socket);
+    return 0;
+  }
+};
+
+static Initiator *FixInitiator_create(Application &application, MessageStoreFactory &storeFactory,
+                                      const SessionSettings &settings, FixTapLogFactory &logFactory,
+                                      int8_t isMultiThreaded, int8_t isSslEnabled) {
+  FixSessionSettings_checkPaths(settings, SOCKET_CONNECT_PATH, !isMultiThreaded && !isSslEnabled);
+  FixHandlerState *state = logFactory.getState();
+  ApplicationBind *bind = logFactory.getApplication();
+  if (isMultiThreaded && isSslEnabled) {
+#ifdef HAVE_SSL
+    return new FixConnectHooks<ThreadedSSLSocketInitiator>(state, bind, application, storeFactory, settings,
+                                                           logFactory);
+#else
+    return new ThreadedSSLSocketInitiator(application, storeFactory, settings, logFactory);
+#endif // HAVE_SSL
+  } else if (isMultiThreaded && !isSslEnabled) {
+    return new FixConnectHooks<ThreadedSocketInitiator>(state, bind, application, storeFactory, settings, logFactory);
+  } else if (!isMultiThreaded && isSslEnabled) {
+#ifdef HAVE_SSL
+    return new FixSocketConnectHooks<SSLSocketInitiator>(state, bind, application, storeFactory, settings, logFactory);
+#else
+    return new SSLSocketInitiator(application, storeFactory, settings, logFactory);
+#endif // HAVE_SSL
+  } else {
+    return new FixPathSocketInitiator(state, bind, application, storeFactory, settings, logFactory);
+  }
 }
 
 Initiator *FixInitiator_new(Application *application, MessageStoreFactory *storeFactory,
@@ -714,15 +4044,15 @@ Initiator *FixInitiator_new(Application *application, MessageStoreFactory *store
   RETURN_VAL_IF_NULL(settings, NULL);
 
   CATCH_OR_RETURN_NULL({
-    if (isMultiThreaded && isSslEnabled) {
-      return new ThreadedSSLSocketInitiator(*application, *storeFactory, *settings, *logFactory);
-    } else if (isMultiThreaded && !isSslEnabled) {
-      return new ThreadedSocketInitiator(*application, *storeFactory, *settings, *logFactory);
-    } else if (!isMultiThreaded && isSslEnabled) {
-      return new SSLSocketInitiator(*application, *storeFactory, *settings, *logFactory);
-    } else {
-      return new SocketInitiator(*application, *storeFactory, *settings, *logFactory);
-    }
+    FixSession_registerDictionaries(*settings);
+    auto wrappedLogFactory =
+        std::make_unique<FixTapLogFactory>(*logFactory, dynamic_cast<ApplicationBind *>(application));
+    FixHandlerStateScope scope(wrappedLogFactory->getState());
+    Initiator *obj = FixInitiator_create(*application, *storeFactory, *settings, *wrappedLogFactory, isMultiThreaded,
+                                         isSslEnabled);
+
+    FixConnectionHandler_setLogFactory(obj, std::move(wrappedLogFactory));
+    return obj;
   });
 }
 
@@ -742,15 +4072,39 @@ int8_t FixInitiator_block(Initiator *obj) {
   });
 }
 
-int8_t FixInitiator_poll(Initiator *obj) {
+int8_t FixInitiator_poll(Initiator *obj, double timeout) {
   RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
-  CATCH_OR_RETURN_ERRNO({ return obj->poll(); });
+  CATCH_OR_RETURN_ERRNO({ return obj->poll(timeout); });
 }
 
 int8_t FixInitiator_stop(Initiator *obj) {
   RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
   CATCH_OR_RETURN_ERRNO({
+    FixConnectionHandler_invalidateResolved(obj);
+    obj->stop();
+    FixInitiator_reportPendingConnectFailures(obj);
+    return 0;
+  });
+}
+
+int8_t FixInitiator_stopForce(Initiator *obj) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    FixConnectionHandler_invalidateResolved(obj);
+    FixConnectionHandler_stopForce(obj);
+    FixInitiator_reportPendingConnectFailures(obj);
+    return 0;
+  });
+}
+
+int8_t FixInitiator_stopWithReason(Initiator *obj, const char *reason) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(reason, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    FixConnectionHandler_invalidateResolved(obj);
+    FixConnectionHandler_logoutAll(obj, reason);
     obj->stop();
+    FixInitiator_reportPendingConnectFailures(obj);
     return 0;
   });
 }
@@ -771,9 +4125,193 @@ FixSession_t *FixInitiator_getSession(const FixInitiator_t *obj, const FixSessio
   CATCH_OR_RETURN_NULL({ return obj->getSession(*sessionId); });
 }
 
+int64_t FixInitiator_getSessionCount(const FixInitiator_t *obj) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({ return obj->getSessions().size(); });
+}
+
+FixSessionID_t *FixInitiator_getSessionIdAt(const FixInitiator_t *obj, uint64_t index) {
+  RETURN_VAL_IF_NULL(obj, NULL);
+  CATCH_OR_RETURN_NULL({
+    const std::set<SessionID> sessions = obj->getSessions();
+    if (index >= sessions.size()) {
+      throw std::out_of_range("Session index out of range");
+    }
+    return new SessionID(*std::next(sessions.begin(), index));
+  });
+}
+
+const Dictionary *FixInitiator_getSessionSettingsRef(const Initiator *obj, const SessionID *id) {
+  RETURN_VAL_IF_NULL(obj, NULL);
+  RETURN_VAL_IF_NULL(id, NULL);
+
+  CATCH_OR_RETURN_NULL({
+    const SessionSettings &settings = obj->bindSettings();
+    if (!settings.has(*id)) {
+      throw SessionNotFound("No session settings found");
+    }
+    return &settings.get(*id);
+  });
+}
+
+int8_t FixInitiator_setSessionSettings(Initiator *obj, const SessionID *id, const Dictionary *value) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(id, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(value, ERRNO_INVAL);
+
+  CATCH_OR_RETURN_ERRNO({
+    SessionSettings &settings = obj->bindSettings();
+    if (!settings.has(*id)) {
+      throw SessionNotFound("No session settings found");
+    }
+
+    // Validate dictionary the same way `SessionSettings::set` does.
+    SessionSettings scratch;
+    scratch.set(*id, *value);
+
+    // Initiator reads session settings under this lock when (re)connecting.
+    Locker locker(obj->bindMutex());
+    const_cast<Dictionary &>(settings.get(*id)) = scratch.get(*id);
+    FixSession_setDictionary(*id, scratch.get(*id));
+    return 0;
+  })
+}
+
+int8_t FixInitiator_addSession(Initiator *obj, Application *application, MessageStoreFactory *storeFactory,
+                               LogFactory *logFactory, const SessionID *id, const Dictionary *value) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(application, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(storeFactory, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(logFactory, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(id, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(value, ERRNO_INVAL);
+
+  CATCH_OR_RETURN_ERRNO({
+    // Initiator reads its sessions under this lock when (re)connecting.
+    Locker locker(obj->bindMutex());
+
+    auto &sessions = obj->bindSessions();
+    if (sessions.find(*id) != sessions.end()) {
+      throw ConfigError("Duplicate Session " + id->toString());
+    }
+
+    // Merge and validate dictionary the same way `Initiator::initialize` does.
+    SessionSettings &settings = obj->bindSettings();
+    SessionSettings scratch;
+    scratch.set(settings.get());
+    scratch.set(*id, *value);
+
+    const Dictionary &dict = scratch.get(*id);
+    if (dict.getString(CONNECTION_TYPE) != "initiator") {
+      throw ConfigError("Session " + id->toString() + " is not an initiator session");
+    }
+    FixSessionSettings_checkPaths(scratch, SOCKET_CONNECT_PATH,
+                                  dynamic_cast<FixPathSocketInitiator *>(obj) != nullptr);
+
+    FixSession_setDictionary(*id, dict);
+    SessionFactory factory(*application, *storeFactory, FixInitiator_getLogFactory(obj, logFactory));
+    FixHandlerStateScope scope(FixConnectionHandler_getState(obj));
+    Session *session = factory.create(*id, dict);
+
+    settings.set(*id, dict);
+    sessions[*id] = session;
+    obj->bindSessionIDs().insert(*id);
+    obj->bindSetDisconnected(*id);
+    return 0;
+  })
+}
+
+int8_t FixInitiator_removeSession(Initiator *obj, const SessionID *id, int8_t forceDisconnect) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(id, ERRNO_INVAL);
+
+  CATCH_OR_RETURN_ERRNO({
+    Session *session = obj->getSession(*id);
+    if (!session) {
+      throw SessionNotFound("No session found");
+    }
+
+    FixSession_logoutForRemoval(session, forceDisconnect);
+
+    // Initiator keeps looking up sessions it is (re)connecting, so wait for the socket to be released on the
+    // initiator thread before removing it.
+    for (int tick = 0;; ++tick) {
+      {
+        Locker locker(obj->bindMutex());
+        const auto &pending = obj->bindPending();
+        const auto &connected = obj->bindConnected();
+        auto &disconnected = obj->bindDisconnected();
+
+        if (!pending.count(*id) && !connected.count(*id)) {
+          disconnected.erase(*id);
+          obj->bindSessions().erase(*id);
+          obj->bindSessionIDs().erase(*id);
+          FixInitiator_removeSessionSettings(obj, *id);
+          FixConnectionHandler_retireSession(obj, session);
+          return 0;
+        }
+      }
+
+      if (tick >= 100) {
+        throw RuntimeError("Timeout waiting for session " + id->toString() + " to disconnect");
+      }
+      process_sleep(0.1);
+    }
+  });
+}
+
+int8_t FixInitiator_setReconnectInterval(Initiator *obj, int32_t seconds) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  if (seconds <= 0) {
+    return ERRNO_INVAL;
+  }
+
+  CATCH_OR_RETURN_ERRNO({
+    Locker locker(obj->bindMutex());
+
+    // Keep settings in sync, they are read again on next `start()`.
+    SessionSettings &settings = obj->bindSettings();
+    Dictionary defaults = settings.get();
+    defaults.setInt(RECONNECT_INTERVAL, seconds);
+    settings.set(defaults);
+
+    if (!FixInitiator_setSocketReconnectInterval(obj, seconds)) {
+      throw RuntimeError("Unsupported initiator implementation");
+    }
+    return 0;
+  });
+}
+
+int8_t FixInitiator_setReconnectPolicy(Initiator *obj, const SessionID *id, int64_t initialMillis,
+                                       int64_t maxMillis, int64_t jitterMillis) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  if (initialMillis <= 0 || maxMillis < initialMillis || jitterMillis < 0) {
+    return ERRNO_INVAL;
+  }
+
+  CATCH_OR_RETURN_ERRNO({
+    if (id && !obj->getSession(*id)) {
+      throw SessionNotFound("No session found");
+    }
+
+    FixInitiator_getReconnectGate(obj).setPolicy(id, FixReconnectGate::Policy{initialMillis, maxMillis, jitterMillis});
+    return 0;
+  });
+}
+
 void FixInitiator_delete(const Initiator *obj) {
   RETURN_IF_NULL(obj);
-  delete obj;
+  CATCH_OR_DISCARD({
+    FixInitiator_deleteReconnectGate(obj);
+    const std::vector<const Session *> sessions = FixConnectionHandler_sessions(obj);
+    FixSession_invalidateResolved(sessions, true);
+    const std::set<SessionID> sessionIds = obj->getSessions();
+    delete obj;
+    FixSession_forgetResolved(sessions);
+    FixConnectionHandler_deleteRemovedSessions(obj);
+    FixSession_releaseDictionaries(sessionIds);
+    FixConnectionHandler_deleteLogFactory(obj);
+  });
 }
 
 SessionID *FixSessionID_new(const char *beginString, const char *senderCompID, const char *targetCompID,
@@ -834,16 +4372,16 @@ Message *FixMessage_copy(const Message *src) {
   CATCH_OR_RETURN_NULL({ return new Message(*src); });
 }
 
-Message *FixMessage_fromString(const char *text) {
+Message *FixMessage_fromString(const char *text, uint64_t text_len) {
   RETURN_VAL_IF_NULL(text, NULL);
-  CATCH_OR_RETURN_NULL({ return new Message(text, /* validate = */ false); });
+  CATCH_OR_RETURN_NULL({ return new Message(std::string(text, text_len), /* validate = */ false); });
 }
 
-Message *FixMessage_fromStringAndDictionary(const char *text, const DataDictionary *dictionary) {
+Message *FixMessage_fromStringAndDictionary(const char *text, uint64_t text_len, const DataDictionary *dictionary) {
   RETURN_VAL_IF_NULL(text, NULL);
   RETURN_VAL_IF_NULL(dictionary, NULL);
 
-  CATCH_OR_RETURN_NULL({ return new Message(text, *dictionary, /* validate = */ true); });
+  CATCH_OR_RETURN_NULL({ return new Message(std::string(text, text_len), *dictionary, /* validate = */ true); });
 }
 
 const char *FixMessage_getField(const Message *obj, int32_t tag) {
@@ -855,6 +4393,15 @@ int8_t FixMessage_isFieldEqual(const Message *obj, int32_t tag, const char *valu
   return FixFieldMap_isFieldEqual(obj, tag, value, value_len);
 }
 
+const char *FixMessage_getFieldValue(const Message *obj, int32_t tag, uint64_t *value_len) {
+  return FixFieldMap_getFieldValue(obj, tag, value_len);
+}
+
+int64_t FixMessage_getFields(const Message *obj, const int32_t *tags, uint64_t tags_len, char *buffer,
+                             uint64_t buffer_len, int64_t *lengths) {
+  return FixFieldMap_getFields(obj, tags, tags_len, buffer, buffer_len, lengths);
+}
+
 int8_t FixMessage_setField(Message *obj, int32_t tag, const char *value) {
   RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
   RETURN_VAL_IF_NULL(value, ERRNO_INVAL);
@@ -864,6 +4411,10 @@ int8_t FixMessage_setField(Message *obj, int32_t tag, const char *value) {
   });
 }
 
+int8_t FixMessage_setFieldRaw(Message *obj, int32_t tag, const char *value, uint64_t value_len) {
+  return FixFieldMap_setFieldRaw(obj, tag, value, value_len);
+}
+
 int8_t FixMessage_removeField(Message *obj, int32_t tag) {
   RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
   CATCH_OR_RETURN_ERRNO({
@@ -872,6 +4423,29 @@ int8_t FixMessage_removeField(Message *obj, int32_t tag) {
   });
 }
 
+int8_t FixMessage_clear(Message *obj) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    obj->clear();
+    return 0;
+  });
+}
+
+int8_t FixMessage_assign(Message *obj, const Message *src) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(src, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    // Copy-assignment reuses already allocated field storage of `obj`.
+    *obj = *src;
+    return 0;
+  });
+}
+
+int8_t FixMessage_addGroups(Message *obj, const Group *tmpl, const int32_t *tags, const char *const *values,
+                            const uint64_t *value_lens, const uint64_t *row_lens, uint64_t rows_len) {
+  return FixFieldMap_addGroups(obj, tmpl, tags, values, value_lens, row_lens, rows_len);
+}
+
 int8_t FixMessage_addGroup(Message *obj, const Group *group) {
   RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
   RETURN_VAL_IF_NULL(group, ERRNO_INVAL);
@@ -886,6 +4460,30 @@ const char *FixMessage_toString(FixMessage_t *obj) {
   CATCH_OR_RETURN_NULL({ return obj->toStringFrozen().c_str(); });
 }
 
+const char *FixMessage_toStringRef(FixMessage_t *obj, uint64_t *len) {
+  RETURN_VAL_IF_NULL(obj, NULL);
+  RETURN_VAL_IF_NULL(len, NULL);
+  CATCH_OR_RETURN_NULL({
+    const std::string &value = obj->toStringFrozen();
+    *len = value.size();
+    return value.data();
+  });
+}
+
+int64_t FixMessage_toBuffer(const FixMessage_t *obj, char *buffer, uint64_t buffer_len) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+
+  CATCH_OR_RETURN_ERRNO({
+    // Scratch string keeps its capacity between calls, so steady-state serialization does not allocate.
+    thread_local std::string value;
+    obj->toString(value);
+    if (buffer != NULL && value.size() <= buffer_len) {
+      std::memcpy(buffer, value.data(), value.size());
+    }
+    return static_cast<int64_t>(value.size());
+  });
+}
+
 int64_t FixMessage_getStringLen(const FixMessage_t *obj) {
   RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
 
@@ -942,6 +4540,15 @@ int8_t FixHeader_isFieldEqual(const Header *obj, int32_t tag, const char *value,
   return FixFieldMap_isFieldEqual(obj, tag, value, value_len);
 }
 
+const char *FixHeader_getFieldValue(const Header *obj, int32_t tag, uint64_t *value_len) {
+  return FixFieldMap_getFieldValue(obj, tag, value_len);
+}
+
+int64_t FixHeader_getFields(const Header *obj, const int32_t *tags, uint64_t tags_len, char *buffer,
+                            uint64_t buffer_len, int64_t *lengths) {
+  return FixFieldMap_getFields(obj, tags, tags_len, buffer, buffer_len, lengths);
+}
+
 int8_t FixHeader_setField(Header *obj, int32_t tag, const char *value) {
   RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
   RETURN_VAL_IF_NULL(value, ERRNO_INVAL);
@@ -951,6 +4558,10 @@ int8_t FixHeader_setField(Header *obj, int32_t tag, const char *value) {
   });
 }
 
+int8_t FixHeader_setFieldRaw(Header *obj, int32_t tag, const char *value, uint64_t value_len) {
+  return FixFieldMap_setFieldRaw(obj, tag, value, value_len);
+}
+
 int8_t FixHeader_removeField(Header *obj, int32_t tag) {
   RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
   CATCH_OR_RETURN_ERRNO({
@@ -959,6 +4570,11 @@ int8_t FixHeader_removeField(Header *obj, int32_t tag) {
   });
 }
 
+int8_t FixHeader_addGroups(Header *obj, const Group *tmpl, const int32_t *tags, const char *const *values,
+                           const uint64_t *value_lens, const uint64_t *row_lens, uint64_t rows_len) {
+  return FixFieldMap_addGroups(obj, tmpl, tags, values, value_lens, row_lens, rows_len);
+}
+
 int8_t FixHeader_addGroup(Header *obj, const Group *group) {
   RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
   RETURN_VAL_IF_NULL(group, ERRNO_INVAL);
@@ -1001,6 +4617,15 @@ int8_t FixTrailer_isFieldEqual(const Trailer *obj, int32_t tag, const char *valu
   return FixFieldMap_isFieldEqual(obj, tag, value, value_len);
 }
 
+const char *FixTrailer_getFieldValue(const Trailer *obj, int32_t tag, uint64_t *value_len) {
+  return FixFieldMap_getFieldValue(obj, tag, value_len);
+}
+
+int64_t FixTrailer_getFields(const Trailer *obj, const int32_t *tags, uint64_t tags_len, char *buffer,
+                             uint64_t buffer_len, int64_t *lengths) {
+  return FixFieldMap_getFields(obj, tags, tags_len, buffer, buffer_len, lengths);
+}
+
 int8_t FixTrailer_setField(Trailer *obj, int32_t tag, const char *value) {
   RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
   RETURN_VAL_IF_NULL(value, ERRNO_INVAL);
@@ -1010,6 +4635,10 @@ int8_t FixTrailer_setField(Trailer *obj, int32_t tag, const char *value) {
   });
 }
 
+int8_t FixTrailer_setFieldRaw(Trailer *obj, int32_t tag, const char *value, uint64_t value_len) {
+  return FixFieldMap_setFieldRaw(obj, tag, value, value_len);
+}
+
 int8_t FixTrailer_removeField(Trailer *obj, int32_t tag) {
   RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
   CATCH_OR_RETURN_ERRNO({
@@ -1018,6 +4647,11 @@ int8_t FixTrailer_removeField(Trailer *obj, int32_t tag) {
   });
 }
 
+int8_t FixTrailer_addGroups(Trailer *obj, const Group *tmpl, const int32_t *tags, const char *const *values,
+                            const uint64_t *value_lens, const uint64_t *row_lens, uint64_t rows_len) {
+  return FixFieldMap_addGroups(obj, tmpl, tags, values, value_lens, row_lens, rows_len);
+}
+
 int8_t FixTrailer_addGroup(Trailer *obj, const Group *group) {
   RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
   RETURN_VAL_IF_NULL(group, ERRNO_INVAL);
@@ -1098,6 +4732,15 @@ int8_t FixGroup_isFieldEqual(const Group *obj, int32_t tag, const char *value, u
   return FixFieldMap_isFieldEqual(obj, tag, value, value_len);
 }
 
+const char *FixGroup_getFieldValue(const Group *obj, int32_t tag, uint64_t *value_len) {
+  return FixFieldMap_getFieldValue(obj, tag, value_len);
+}
+
+int64_t FixGroup_getFields(const Group *obj, const int32_t *tags, uint64_t tags_len, char *buffer, uint64_t buffer_len,
+                           int64_t *lengths) {
+  return FixFieldMap_getFields(obj, tags, tags_len, buffer, buffer_len, lengths);
+}
+
 int8_t FixGroup_setField(Group *obj, int32_t tag, const char *value) {
   RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
   RETURN_VAL_IF_NULL(value, ERRNO_INVAL);
@@ -1107,6 +4750,10 @@ int8_t FixGroup_setField(Group *obj, int32_t tag, const char *value) {
   });
 }
 
+int8_t FixGroup_setFieldRaw(Group *obj, int32_t tag, const char *value, uint64_t value_len) {
+  return FixFieldMap_setFieldRaw(obj, tag, value, value_len);
+}
+
 int8_t FixGroup_removeField(Group *obj, int32_t tag) {
   RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
   CATCH_OR_RETURN_ERRNO({
@@ -1115,6 +4762,11 @@ int8_t FixGroup_removeField(Group *obj, int32_t tag) {
   });
 }
 
+int8_t FixGroup_addGroups(Group *obj, const Group *tmpl, const int32_t *tags, const char *const *values,
+                          const uint64_t *value_lens, const uint64_t *row_lens, uint64_t rows_len) {
+  return FixFieldMap_addGroups(obj, tmpl, tags, values, value_lens, row_lens, rows_len);
+}
+
 int8_t FixGroup_addGroup(Group *obj, const Group *group) {
   RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
   RETURN_VAL_IF_NULL(group, ERRNO_INVAL);
@@ -1134,7 +4786,76 @@ int8_t FixSession_sendToTarget(Message *msg, const SessionID *session_id) {
   RETURN_VAL_IF_NULL(session_id, ERRNO_INVAL);
 
   CATCH_OR_RETURN_ERRNO({
-    Session::sendToTarget(*msg, *session_id);
+    FixSession_sendToSession(*msg, *session_id);
+    return 0;
+  });
+}
+
+int8_t FixSession_sendToTargetWithCompIds(FixMessage_t *msg, const char *beginString, const char *senderCompId,
+                                          const char *targetCompId, const char *qualifier) {
+  RETURN_VAL_IF_NULL(msg, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(beginString, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(senderCompId, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(targetCompId, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(qualifier, ERRNO_INVAL);
+
+  CATCH_OR_RETURN_ERRNO({
+    msg->getHeader().setField(BeginString(beginString));
+    msg->getHeader().setField(SenderCompID(senderCompId));
+    msg->getHeader().setField(TargetCompID(targetCompId));
+    FixSession_sendToSession(*msg, msg->getSessionID(qualifier));
+    return 0;
+  });
+}
+
+int64_t FixSession_sendAllToTarget(FixMessage_t *const *msgs, uint64_t msgs_len, const FixSessionID_t *session_id) {
+  RETURN_VAL_IF_NULL(msgs, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(session_id, ERRNO_INVAL);
+
+  CATCH_OR_RETURN_ERRNO({
+    // Resolve session only once for the whole batch.
+    Session *session = Session::lookupSession(*session_id);
+    if (!session) {
+      throw SessionNotFound(session_id->toString());
+    }
+
+    int64_t queued = 0;
+    for (uint64_t i = 0; i < msgs_len; ++i) {
+      if (!msgs[i]) {
+        break;
+      }
+
+      // Messages sent while logged off are persisted for next logon, only refused ones (sequence number not
+      // consumed, e.g. `DoNotSend` from callbacks) stop the batch.
+      const int32_t seqNum = session->getExpectedSenderNum();
+      if (!session->send(*msgs[i]) && session->getExpectedSenderNum() == seqNum) {
+        break;
+      }
+      ++queued;
+    }
+    return queued;
+  });
+}
+
+int8_t FixSession_trySendToTarget(FixMessage_t *msg, const FixSessionID_t *session_id) {
+  RETURN_VAL_IF_NULL(msg, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(session_id, ERRNO_INVAL);
+
+  CATCH_OR_RETURN_ERRNO({
+    Session *session = Session::lookupSession(*session_id);
+    if (!session) {
+      throw SessionNotFound(session_id->toString());
+    }
+
+    // Do not let quickfix queue the message when there is nobody to deliver it to.
+    if (!session->isLoggedOn()) {
+      std::runtime_error ex("Session is not logged on");
+      Fix_setLastError(ex, ERROR_SESSION_NOT_LOGGED_ON);
+      return ERROR_SESSION_NOT_LOGGED_ON;
+    }
+
+    msg->setSessionID(*session_id);
+    session->send(*msg);
     return 0;
   });
 }
@@ -1147,25 +4868,92 @@ FixSession_t *FixSession_lookup(const FixSessionID_t *session_id) {
 int8_t FixSession_logout(FixSession_t *session) {
   RETURN_VAL_IF_NULL(session, ERRNO_INVAL);
   CATCH_OR_RETURN_ERRNO({
+    FixSession_setUserDisabled(session, true);
     session->logout();
     return 0;
   });
 }
 
+int8_t FixSession_logoutWithReason(FixSession_t *session, const char *reason) {
+  RETURN_VAL_IF_NULL(session, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(reason, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    FixSession_setUserDisabled(session, true);
+    session->logout(reason);
+    return 0;
+  });
+}
+
 int8_t FixSession_isLoggedOn(FixSession_t *session) {
   RETURN_VAL_IF_NULL(session, ERRNO_INVAL);
   CATCH_OR_RETURN_ERRNO({ return session->isLoggedOn(); });
 }
 
+int8_t FixSession_isEnabled(FixSession_t *session) {
+  RETURN_VAL_IF_NULL(session, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({ return session->isEnabled(); });
+}
+
 int8_t FixSession_send(FixSession_t *session, FixMessage_t *msg) {
   RETURN_VAL_IF_NULL(session, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(msg, ERRNO_INVAL);
   CATCH_OR_RETURN_ERRNO({ return session->send(*msg); });
 }
 
+FixSession_t *FixSession_resolve(const FixSessionID_t *session_id, uint64_t *generation) {
+  RETURN_VAL_IF_NULL(session_id, NULL);
+  RETURN_VAL_IF_NULL(generation, NULL);
+  CATCH_OR_RETURN_NULL({
+    // Session cannot be invalidated between lookup and generation assignment.
+    FixResolvedSendScope scope;
+    Session *session = Session::lookupSession(*session_id);
+    if (!session) {
+      throw SessionNotFound(session_id->toString());
+    }
+
+    std::lock_guard<std::mutex> generationsLock(resolvedGenerationsMutex);
+    auto inserted = resolvedGenerations.emplace(session, nextResolvedGeneration);
+    if (inserted.second) {
+      ++nextResolvedGeneration;
+    }
+    if (inserted.first->second == RESOLVED_SESSION_RETIRED) {
+      throw SessionNotFound(session_id->toString());
+    }
+    *generation = inserted.first->second;
+    return session;
+  });
+}
+
+int8_t FixSession_sendResolved(FixSession_t *session, uint64_t generation, FixMessage_t *msg) {
+  RETURN_VAL_IF_NULL(session, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(msg, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    FixResolvedSendScope scope;
+
+    // Do not touch session pointer if its connection handler may have been stopped.
+    bool valid;
+    {
+      std::lock_guard<std::mutex> generationsLock(resolvedGenerationsMutex);
+      auto it = resolvedGenerations.find(session);
+      valid = it != resolvedGenerations.end() && it->second == generation;
+    }
+    if (!valid) {
+      throw SessionNotFound("Resolved session is no longer valid");
+    }
+    session->send(*msg);
+    return 0;
+  });
+}
+
 int8_t FixSession_reset(FixSession_t *session) {
   RETURN_VAL_IF_NULL(session, ERRNO_INVAL);
   CATCH_OR_RETURN_ERRNO({
     session->reset();
+    FixSessionKey key;
+    if (FixSession_findKey(session, key)) {
+      FixSession_clearStats(key);
+      FixSession_clearOutboundQueue(key);
+    }
     return 0;
   });
 }
@@ -1173,10 +4961,83 @@ int8_t FixSession_reset(FixSession_t *session) {
 int8_t FixSession_logon(FixSession_t *session) {
   RETURN_VAL_IF_NULL(session, ERRNO_INVAL);
   CATCH_OR_RETURN_ERRNO({
+    FixSession_setUserDisabled(session, false);
     session->logon();
     return 0;
   });
 }
+
+int8_t FixSession_disconnect(FixSession_t *session) {
+  RETURN_VAL_IF_NULL(session, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    FixSession_recordLocalDisconnect(session, "");
+    session->disconnect();
+    return 0;
+  });
+}
+
+int8_t FixSession_requestResend(FixSession_t *session, int32_t beginSeqNo, int32_t endSeqNo) {
+  RETURN_VAL_IF_NULL(session, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    Message msg;
+    msg.getHeader().setField(FIELD::MsgType, "2");
+    msg.setField(BeginSeqNo(beginSeqNo));
+    msg.setField(EndSeqNo(endSeqNo));
+    session->send(msg);
+    return 0;
+  });
+}
+
+int8_t FixSession_sendGapFill(FixSession_t *session, int32_t newSeqNo) {
+  RETURN_VAL_IF_NULL(session, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    Message msg;
+    msg.getHeader().setField(FIELD::MsgType, "4");
+    msg.setField(GapFillFlag(true));
+    msg.setField(NewSeqNo(newSeqNo));
+    session->send(msg);
+
+    // Skipped sequence numbers will never be used.
+    session->setNextSenderMsgSeqNum(newSeqNo);
+    return 0;
+  });
+}
+
+int8_t FixSession_next(FixSession_t *session, const char *msg) {
+  RETURN_VAL_IF_NULL(session, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(msg, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    session->next(std::string(msg), UtcTimeStamp::now());
+    return 0;
+  });
+}
+
+int8_t FixSession_tick(FixSession_t *session, int64_t nowMillis) {
+  RETURN_VAL_IF_NULL(session, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    session->next(FixUtcTimeStamp_fromMillis(nowMillis));
+    return 0;
+  });
+}
+
+int8_t FixSession_refresh(FixSession_t *session) {
+  RETURN_VAL_IF_NULL(session, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    session->refresh();
+    return 0;
+  });
+}
+
+int8_t FixSession_sendTestRequest(FixSession_t *session, const char *testReqId) {
+  RETURN_VAL_IF_NULL(session, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(testReqId, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    Message msg;
+    msg.getHeader().setField(FIELD::MsgType, "1");
+    msg.setField(FIELD::TestReqID, testReqId);
+    return session->send(msg);
+  });
+}
 int8_t FixSession_setNextSenderMsgSeqNum(FixSession_t *session, int32_t num) {
   RETURN_VAL_IF_NULL(session, ERRNO_INVAL);
   CATCH_OR_RETURN_ERRNO({
@@ -1196,10 +5057,247 @@ int32_t FixSession_getExpectedSenderNum(FixSession_t *session) {
   CATCH_OR_RETURN_ERRNO({ return session->getExpectedSenderNum(); });
 }
 
+FixSessionID_t *FixSession_getSessionID(FixSession_t *session) {
+  RETURN_VAL_IF_NULL(session, NULL);
+  CATCH_OR_RETURN_NULL({ return new SessionID(session->getSessionID()); });
+}
+
 int32_t FixSession_getExpectedTargetNum(FixSession_t *session) {
   RETURN_VAL_IF_NULL(session, ERRNO_INVAL);
   CATCH_OR_RETURN_ERRNO({ return session->getExpectedTargetNum(); });
 }
+
+int64_t FixSession_getOutboundQueueLen(FixSession_t *session) {
+  RETURN_VAL_IF_NULL(session, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    FixSessionKey key;
+    return FixSession_findKey(session, key) ? FixSession_countOutboundQueue(key) : 0;
+  });
+}
+
+int64_t FixSession_getOutboundQueueLenById(const FixSessionID_t *session_id) {
+  RETURN_VAL_IF_NULL(session_id, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    // Queue of removed sessions is erased with them.
+    FixSessionKey key;
+    return FixSession_findKeyById(*session_id, key) ? FixSession_countOutboundQueue(key) : 0;
+  });
+}
+
+int8_t FixSession_getStats(FixSession_t *session, FixSessionStats_t *stats) {
+  RETURN_VAL_IF_NULL(session, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(stats, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    FixSessionKey key;
+    const bool tracked = FixSession_findKey(session, key);
+    std::lock_guard<std::mutex> lock(sessionStatsMutex);
+    auto it = tracked ? sessionStats.find(key) : sessionStats.end();
+    *stats = it == sessionStats.end() ? SessionStats() : it->second;
+    return 0;
+  });
+}
+
+FixDictionary_t *FixSession_getSettings(FixSession_t *session) {
+  RETURN_VAL_IF_NULL(session, NULL);
+  CATCH_OR_RETURN_NULL({
+    std::lock_guard<std::mutex> lock(sessionDictionariesMutex);
+    auto it = sessionDictionaries.find(session->getSessionID());
+    if (it == sessionDictionaries.end()) {
+      throw ConfigError("No settings found for session " + session->getSessionID().toString());
+    }
+    return new Dictionary(it->second);
+  });
+}
+
+int8_t FixSession_getTransportStats(FixSession_t *session, FixTransportStats_t *stats) {
+  RETURN_VAL_IF_NULL(session, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(stats, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    FixSessionKey key;
+    if (!FixSession_findKey(session, key)) {
+      *stats = FixTransportStats_t();
+      return 0;
+    }
+    const FixTransportTracker &tracker = FixSession_getTransportTracker(key);
+    stats->bytesSent = tracker.bytesSent;
+    stats->bytesReceived = tracker.bytesReceived;
+    stats->connectCount = tracker.connectCount;
+    stats->lastConnectAt = tracker.lastConnectAt;
+    return 0;
+  });
+}
+
+int8_t FixSession_setSocketOptions(FixSession_t *session, const FixSocketOptions_t *options) {
+  RETURN_VAL_IF_NULL(session, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(options, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    FixSession_mergeSocketOptions(session, *options);
+    return 0;
+  });
+}
+
+int8_t FixSession_readSocketOptions(FixSession_t *session, FixSocketOptions_t *options) {
+  RETURN_VAL_IF_NULL(session, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(options, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({ return FixSession_getSocketOptions(session, *options) ? 1 : 0; });
+}
+
+int8_t FixSession_isResendRequested(FixSession_t *session) {
+  RETURN_VAL_IF_NULL(session, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({ return FixSession_getState(session).resendRequested(); });
+}
+
+int32_t FixSession_getHeartBtInt(FixSession_t *session) {
+  RETURN_VAL_IF_NULL(session, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({ return FixSession_getState(session).heartBtInt().getValue(); });
+}
+
+int8_t FixSession_setHeartBtInt(FixSession_t *session, int32_t value) {
+  RETURN_VAL_IF_NULL(session, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    FixSession_getState(session).heartBtInt(HeartBtInt(value));
+    return 0;
+  });
+}
+
+int8_t FixSession_isSessionTime(FixSession_t *session) {
+  RETURN_VAL_IF_NULL(session, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({ return session->isSessionTime(UtcTimeStamp::now()); });
+}
+
+int8_t FixSession_isSessionTimeAt(FixSession_t *session, int64_t nowMillis) {
+  RETURN_VAL_IF_NULL(session, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({ return session->isSessionTime(FixUtcTimeStamp_fromMillis(nowMillis)); });
+}
+
+int64_t FixSession_getMillisSinceLastReceived(FixSession_t *session) {
+  RETURN_VAL_IF_NULL(session, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    const UtcTimeStamp now = UtcTimeStamp::now();
+    const UtcTimeStamp &lastReceived = FixSession_getState(session).lastReceivedTime();
+    const int64_t elapsed = (static_cast<int64_t>(now.getTimeT()) - lastReceived.getTimeT()) * 1000 +
+                            now.getMillisecond() - lastReceived.getMillisecond();
+    return std::max<int64_t>(elapsed, 0);
+  });
+}
+
+int64_t FixSession_getStoreCreationTime(FixSession_t *session) {
+  RETURN_VAL_IF_NULL(session, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({ return FixUtcTimeStamp_toMillis(session->getStore()->getCreationTime()); });
+}
+
+int8_t FixSession_getStoreMetrics(FixSession_t *session, FixStoreMetrics_t *metrics) {
+  RETURN_VAL_IF_NULL(session, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(metrics, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    std::vector<std::string> messages;
+    const MessageStore *store = session->getStore();
+    const auto nextSender = store->getNextSenderMsgSeqNum();
+    if (nextSender > 1) {
+      store->get(1, nextSender - 1, messages);
+    }
+
+    metrics->messageCount = messages.size();
+    metrics->approxBytes = FixFileStore_getSize(FixSession_getState(session).store());
+    return 0;
+  });
+}
+
+int8_t FixSession_importStore(FixSession_t *session, const FixStoreSnapshot_t *snapshot) {
+  RETURN_VAL_IF_NULL(session, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(snapshot, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    // Go through session state, so its lock is held while store is updated.
+    FixMessageStore_import(FixSession_getState(session), *snapshot);
+    return 0;
+  });
+}
+
+int8_t FixSession_setResetOnLogon(FixSession_t *session, int8_t value) {
+  RETURN_VAL_IF_NULL(session, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    session->setResetOnLogon(value);
+    return 0;
+  });
+}
+
+int8_t FixSession_setResetOnLogout(FixSession_t *session, int8_t value) {
+  RETURN_VAL_IF_NULL(session, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    session->setResetOnLogout(value);
+    return 0;
+  });
+}
+
+int8_t FixSession_setResetOnDisconnect(FixSession_t *session, int8_t value) {
+  RETURN_VAL_IF_NULL(session, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({
+    session->setResetOnDisconnect(value);
+    return 0;
+  });
+}
+
+int64_t FixAcceptor_getListenEndpointCount(const FixAcceptor_t *obj) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  CATCH_OR_RETURN_ERRNO({ return FixAcceptor_getListenEndpoints(obj).size(); });
+}
+
+int8_t FixAcceptor_readListenEndpointAt(const FixAcceptor_t *obj, uint64_t index, char *buffer, uint64_t buffer_len) {
+  RETURN_VAL_IF_NULL(obj, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(buffer, ERRNO_INVAL);
+
+  CATCH_OR_RETURN_ERRNO({
+    const std::vector<std::string> endpoints = FixAcceptor_getListenEndpoints(obj);
+    if (index >= endpoints.size()) {
+      return 0;
+    }
+
+    const std::string &address = endpoints[index];
+    if (buffer_len <= address.size()) {
+      return ERRNO_BUFFER_TO_SMALL;
+    }
+
+    strncpy(buffer, address.c_str(), buffer_len);
+    buffer[address.size()] = '\0';
+
+    return 1;
+  })
+}
+
+int8_t FixSession_readPeerAddress(FixSession_t *session, char *buffer, uint64_t buffer_len) {
+  RETURN_VAL_IF_NULL(session, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(buffer, ERRNO_INVAL);
+
+  CATCH_OR_RETURN_ERRNO({
+    std::string address;
+    if (!FixSession_getPeerAddress(session, address)) {
+      return 0;
+    }
+    if (buffer_len <= address.size()) {
+      return ERRNO_BUFFER_TO_SMALL;
+    }
+
+    strncpy(buffer, address.c_str(), buffer_len);
+    buffer[address.size()] = '\0';
+
+    return 1;
+  })
+}
+
+int8_t FixSession_getStoredMessages(FixSession_t *session, uint32_t begin, uint32_t end, void *output,
+                                    void (*push)(void *output, const char *msg, uint64_t len)) {
+  RETURN_VAL_IF_NULL(session, ERRNO_INVAL);
+  RETURN_VAL_IF_NULL(push, ERRNO_INVAL);
+
+  CATCH_OR_RETURN_ERRNO({
+    std::vector<std::string> messages;
+    session->getStore()->get(begin, end, messages);
+    for (const std::string &msg : messages) {
+      push(output, msg.data(), msg.size());
+    }
+    return 0;
+  });
+}
 } // namespace FIX
 // namespace FIX
 } // extern C
@@ -16,6 +16,29 @@ pub const CALLBACK_RESULT_INCORRECT_TAG_VALUE: i8 = -4;
 pub const CALLBACK_RESULT_REJECT_LOGON: i8 = -5;
 pub const CALLBACK_RESULT_UNSUPPORTED_MESSAGE_TYPE: i8 = -6;
 
+pub const CONNECT_ERROR_OTHER: i8 = 0;
+pub const CONNECT_ERROR_REFUSED: i8 = 1;
+pub const CONNECT_ERROR_TIMED_OUT: i8 = 2;
+pub const CONNECT_ERROR_UNREACHABLE: i8 = 3;
+pub const CONNECT_ERROR_DNS: i8 = 4;
+
+pub const ADMIN_MSG_KIND_OTHER: i8 = 0;
+pub const ADMIN_MSG_KIND_HEARTBEAT: i8 = 1;
+pub const ADMIN_MSG_KIND_TEST_REQUEST: i8 = 2;
+pub const ADMIN_MSG_KIND_RESEND_REQUEST: i8 = 3;
+pub const ADMIN_MSG_KIND_REJECT: i8 = 4;
+pub const ADMIN_MSG_KIND_SEQUENCE_RESET: i8 = 5;
+pub const ADMIN_MSG_KIND_LOGOUT: i8 = 6;
+pub const ADMIN_MSG_KIND_LOGON: i8 = 7;
+
+pub const LOGOUT_KIND_NORMAL: i8 = 0;
+pub const LOGOUT_KIND_DISCONNECT: i8 = 1;
+pub const LOGOUT_KIND_LOGON_REJECTED: i8 = 2;
+
+pub const DISCONNECT_REASON_LOCAL: i8 = 0;
+pub const DISCONNECT_REASON_REMOTE: i8 = 1;
+pub const DISCONNECT_REASON_TIMEOUT: i8 = 2;
+
 pub type NullableCStr = Option<NonNull<ffi::c_char>>;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -26,6 +49,10 @@ pub struct FixSessionSettings_t(NonNull<ffi::c_void>);
 #[repr(transparent)]
 pub struct FixDictionary_t(NonNull<ffi::c_void>);
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct FixDictionaryCursor_t(NonNull<ffi::c_void>);
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(transparent)]
 pub struct FixDataDictionary_t(NonNull<ffi::c_void>);
@@ -78,12 +105,77 @@ pub struct FixGroup_t(NonNull<ffi::c_void>);
 #[repr(C)]
 pub struct FixApplicationCallbacks_t {
     pub onCreate: extern "C" fn(*const ffi::c_void, FixSessionID_t),
-    pub onLogon: extern "C" fn(*const ffi::c_void, FixSessionID_t),
-    pub onLogout: extern "C" fn(*const ffi::c_void, FixSessionID_t),
+    pub onLogon: extern "C" fn(*const ffi::c_void, FixSessionID_t, i8),
+    pub onLogout: extern "C" fn(*const ffi::c_void, FixSessionID_t, i8, i8, *const ffi::c_char),
     pub toAdmin: extern "C" fn(*const ffi::c_void, FixMessage_t, FixSessionID_t),
     pub toApp: extern "C" fn(*const ffi::c_void, FixMessage_t, FixSessionID_t) -> i8,
-    pub fromAdmin: extern "C" fn(*const ffi::c_void, FixMessage_t, FixSessionID_t) -> i8,
+    pub fromAdmin: extern "C" fn(*const ffi::c_void, FixMessage_t, FixSessionID_t, i8) -> i8,
     pub fromApp: extern "C" fn(*const ffi::c_void, FixMessage_t, FixSessionID_t) -> i8,
+    pub onResendBegin: extern "C" fn(*const ffi::c_void, FixSessionID_t, u32, u32),
+    pub onResendComplete: extern "C" fn(*const ffi::c_void, FixSessionID_t),
+    pub onConnectFailed:
+        extern "C" fn(*const ffi::c_void, FixSessionID_t, u32, i8, *const ffi::c_char),
+    pub onConnect: extern "C" fn(*const ffi::c_void, FixSessionID_t, *const ffi::c_char),
+    pub onDisconnect: extern "C" fn(*const ffi::c_void, FixSessionID_t, i8),
+    pub onRawIncoming: extern "C" fn(*const ffi::c_void, FixSessionID_t, *const ffi::c_char, u64),
+    pub onRawOutgoing: extern "C" fn(*const ffi::c_void, FixSessionID_t, *const ffi::c_char, u64),
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+#[repr(C)]
+pub struct FixSessionStats_t {
+    pub sentApp: u64,
+    pub sentAdmin: u64,
+    pub receivedApp: u64,
+    pub receivedAdmin: u64,
+    pub lastReceivedAt: i64,
+    pub lastSentAt: i64,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+#[repr(C)]
+pub struct FixTransportStats_t {
+    pub bytesSent: u64,
+    pub bytesReceived: u64,
+    pub connectCount: u64,
+    pub lastConnectAt: i64,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+#[repr(C)]
+pub struct FixStoreMetrics_t {
+    pub messageCount: u64,
+    pub approxBytes: i64,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct FixStoreRecord_t {
+    pub seqNum: u32,
+    pub msg: *const ffi::c_char,
+    pub len: u64,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct FixStoreSnapshot_t {
+    pub nextSender: u32,
+    pub nextTarget: u32,
+    pub creationTime: i64,
+    pub records: *const FixStoreRecord_t,
+    pub recordsLen: u64,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct FixSocketOptions_t {
+    pub nodelay: i8,
+    pub keepalive: i8,
+    pub keepaliveIdle: i32,
+    pub keepaliveInterval: i32,
+    pub keepaliveCount: i32,
+    pub sendBufferSize: i32,
+    pub receiveBufferSize: i32,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -93,19 +185,66 @@ pub struct FixLogCallbacks_t {
         data: *const ffi::c_void,
         sessionId: Option<FixSessionID_t>,
         msg: *const ffi::c_char,
+        msg_len: u64,
     ),
     pub onOutgoing: extern "C" fn(
         data: *const ffi::c_void,
         sessionId: Option<FixSessionID_t>,
         msg: *const ffi::c_char,
+        msg_len: u64,
     ),
     pub onEvent: extern "C" fn(
         data: *const ffi::c_void,
         sessionId: Option<FixSessionID_t>,
         msg: *const ffi::c_char,
+        msg_len: u64,
     ),
 }
 
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct FixSessionLogFactoryCallbacks_t {
+    pub create:
+        extern "C" fn(data: *const ffi::c_void, sessionId: FixSessionID_t) -> *const ffi::c_void,
+    pub destroy: extern "C" fn(data: *const ffi::c_void, logData: *const ffi::c_void),
+}
+
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct FixMessageStoreCallbacks_t {
+    pub onCreate: extern "C" fn(data: *const ffi::c_void, session: FixSessionID_t) -> i8,
+    pub set: extern "C" fn(
+        data: *const ffi::c_void,
+        session: FixSessionID_t,
+        seqNum: u32,
+        msg: *const ffi::c_char,
+        len: u64,
+    ) -> i8,
+    pub get: extern "C" fn(
+        data: *const ffi::c_void,
+        session: FixSessionID_t,
+        begin: u32,
+        end: u32,
+        output: *mut ffi::c_void,
+        push: extern "C" fn(output: *mut ffi::c_void, msg: *const ffi::c_char, len: u64),
+    ) -> i8,
+    pub getNextSenderMsgSeqNum:
+        extern "C" fn(data: *const ffi::c_void, session: FixSessionID_t) -> i64,
+    pub getNextTargetMsgSeqNum:
+        extern "C" fn(data: *const ffi::c_void, session: FixSessionID_t) -> i64,
+    pub setNextSenderMsgSeqNum:
+        extern "C" fn(data: *const ffi::c_void, session: FixSessionID_t, value: u32) -> i8,
+    pub setNextTargetMsgSeqNum:
+        extern "C" fn(data: *const ffi::c_void, session: FixSessionID_t, value: u32) -> i8,
+    pub incrNextSenderMsgSeqNum:
+        extern "C" fn(data: *const ffi::c_void, session: FixSessionID_t) -> i8,
+    pub incrNextTargetMsgSeqNum:
+        extern "C" fn(data: *const ffi::c_void, session: FixSessionID_t) -> i8,
+    pub getCreationTime: extern "C" fn(data: *const ffi::c_void, session: FixSessionID_t) -> i64,
+    pub reset: extern "C" fn(data: *const ffi::c_void, session: FixSessionID_t, now: i64) -> i8,
+    pub refresh: extern "C" fn(data: *const ffi::c_void, session: FixSessionID_t) -> i8,
+}
+
 #[link(name = "quickfixbind")]
 extern "C" {
 
@@ -115,6 +254,12 @@ extern "C" {
 
     pub fn Fix_getLastErrorCode() -> i8;
 
+    pub fn Fix_getLastErrorField() -> i32;
+
+    pub fn Fix_getLastErrorDetail() -> *const ffi::c_char;
+
+    pub fn Fix_getLastErrorType() -> *const ffi::c_char;
+
     pub fn Fix_clearLastErrorMessage();
 
     pub fn FixBenchmark_noop() -> i8;
@@ -130,10 +275,24 @@ extern "C" {
 
     pub fn FixSessionSettings_new() -> Option<FixSessionSettings_t>;
 
+    pub fn FixSessionSettings_copy(src: FixSessionSettings_t) -> Option<FixSessionSettings_t>;
+
     pub fn FixSessionSettings_fromPath(
         configPath: *const ffi::c_char,
     ) -> Option<FixSessionSettings_t>;
 
+    pub fn FixSessionSettings_fromString(text: *const ffi::c_char) -> Option<FixSessionSettings_t>;
+
+    #[must_use]
+    pub fn FixSessionSettings_getStringLen(obj: FixSessionSettings_t) -> i64;
+
+    #[must_use]
+    pub fn FixSessionSettings_readString(
+        obj: FixSessionSettings_t,
+        buffer: *mut ffi::c_char,
+        buffer_len: u64,
+    ) -> i8;
+
     pub fn FixSessionSettings_getGlobalRef(obj: FixSessionSettings_t) -> Option<FixDictionary_t>;
 
     pub fn FixSessionSettings_getSessionRef(
@@ -151,12 +310,21 @@ extern "C" {
         value: FixDictionary_t,
     ) -> i8;
 
+    pub fn FixSessionSettings_getSessionCount(obj: FixSessionSettings_t) -> i64;
+
+    pub fn FixSessionSettings_getSessionIdAt(
+        obj: FixSessionSettings_t,
+        index: u64,
+    ) -> Option<FixSessionID_t>;
+
     pub fn FixSessionSettings_delete(obj: FixSessionSettings_t);
 
     // Dictionary
 
     pub fn FixDictionary_new(name: *const ffi::c_char) -> Option<FixDictionary_t>;
 
+    pub fn FixDictionary_copy(src: FixDictionary_t) -> Option<FixDictionary_t>;
+
     #[must_use]
     pub fn FixDictionary_setString(
         obj: FixDictionary_t,
@@ -187,6 +355,23 @@ extern "C" {
         buffer_len: u64,
     ) -> i8;
 
+    #[must_use]
+    pub fn FixDictionary_merge(obj: FixDictionary_t, other: FixDictionary_t) -> i8;
+
+    #[must_use]
+    pub fn FixDictionary_cursor(obj: FixDictionary_t) -> Option<FixDictionaryCursor_t>;
+
+    #[must_use]
+    pub fn FixDictionaryCursor_next(
+        obj: FixDictionaryCursor_t,
+        key: *mut *const ffi::c_char,
+        key_len: *mut u64,
+        value: *mut *const ffi::c_char,
+        value_len: *mut u64,
+    ) -> i8;
+
+    pub fn FixDictionaryCursor_delete(obj: FixDictionaryCursor_t);
+
     pub fn FixDictionary_getInt(obj: FixDictionary_t, key: *const ffi::c_char) -> i32;
 
     pub fn FixDictionary_getDouble(obj: FixDictionary_t, key: *const ffi::c_char) -> f64;
@@ -195,6 +380,34 @@ extern "C" {
 
     pub fn FixDictionary_getDay(obj: FixDictionary_t, key: *const ffi::c_char) -> i32;
 
+    #[must_use]
+    pub fn FixDictionary_readInt(
+        obj: FixDictionary_t,
+        key: *const ffi::c_char,
+        value: *mut i32,
+    ) -> i8;
+
+    #[must_use]
+    pub fn FixDictionary_readDouble(
+        obj: FixDictionary_t,
+        key: *const ffi::c_char,
+        value: *mut f64,
+    ) -> i8;
+
+    #[must_use]
+    pub fn FixDictionary_readBool(
+        obj: FixDictionary_t,
+        key: *const ffi::c_char,
+        value: *mut i8,
+    ) -> i8;
+
+    #[must_use]
+    pub fn FixDictionary_readDay(
+        obj: FixDictionary_t,
+        key: *const ffi::c_char,
+        value: *mut i32,
+    ) -> i8;
+
     pub fn FixDictionary_hasKey(obj: FixDictionary_t, key: *const ffi::c_char) -> i8;
 
     pub fn FixDictionary_delete(obj: FixDictionary_t);
@@ -207,6 +420,77 @@ extern "C" {
         configPath: *const ffi::c_char,
     ) -> Option<FixDataDictionary_t>;
 
+    #[must_use]
+    pub fn FixDataDictionary_getFieldNameLen(obj: FixDataDictionary_t, tag: i32) -> i64;
+
+    #[must_use]
+    pub fn FixDataDictionary_readFieldName(
+        obj: FixDataDictionary_t,
+        tag: i32,
+        buffer: *mut ffi::c_char,
+        buffer_len: u64,
+    ) -> i8;
+
+    #[must_use]
+    pub fn FixDataDictionary_getFieldTag(
+        obj: FixDataDictionary_t,
+        name: *const ffi::c_char,
+        tag: *mut i32,
+    ) -> i8;
+
+    pub fn FixDataDictionary_getFieldTypeName(obj: FixDataDictionary_t, tag: i32) -> NullableCStr;
+
+    #[must_use]
+    pub fn FixDataDictionary_getValueNameLen(
+        obj: FixDataDictionary_t,
+        tag: i32,
+        value: *const ffi::c_char,
+    ) -> i64;
+
+    #[must_use]
+    pub fn FixDataDictionary_readValueName(
+        obj: FixDataDictionary_t,
+        tag: i32,
+        value: *const ffi::c_char,
+        buffer: *mut ffi::c_char,
+        buffer_len: u64,
+    ) -> i8;
+
+    #[must_use]
+    pub fn FixDataDictionary_getMessageFieldCount(
+        obj: FixDataDictionary_t,
+        msgType: *const ffi::c_char,
+    ) -> i64;
+
+    #[must_use]
+    pub fn FixDataDictionary_getMessageFieldAt(
+        obj: FixDataDictionary_t,
+        msgType: *const ffi::c_char,
+        index: u64,
+        tag: *mut i32,
+    ) -> i8;
+
+    #[must_use]
+    pub fn FixDataDictionary_isRequiredField(
+        obj: FixDataDictionary_t,
+        msgType: *const ffi::c_char,
+        tag: i32,
+    ) -> i8;
+
+    pub fn FixDataDictionary_getGroupRef(
+        obj: FixDataDictionary_t,
+        msgType: *const ffi::c_char,
+        tag: i32,
+        delimiter: *mut i32,
+    ) -> Option<FixDataDictionary_t>;
+
+    #[must_use]
+    pub fn FixDataDictionary_validate(
+        obj: FixDataDictionary_t,
+        msg: FixMessage_t,
+        field: *mut i32,
+    ) -> i8;
+
     pub fn FixDataDictionary_delete(obj: FixDataDictionary_t);
 
     // Message store factory
@@ -215,10 +499,28 @@ extern "C" {
         settings: FixSessionSettings_t,
     ) -> Option<FixMessageStoreFactory_t>;
 
+    pub fn FixFileMessageStoreFactory_newWithPath(
+        path: *const ffi::c_char,
+    ) -> Option<FixMessageStoreFactory_t>;
+
     pub fn FixMemoryMessageStoreFactory_new() -> Option<FixMessageStoreFactory_t>;
 
     pub fn FixNullMessageStoreFactory_new() -> Option<FixMessageStoreFactory_t>;
 
+    pub fn FixCustomMessageStoreFactory_new(
+        data: *const ffi::c_void,
+        callbacks: *const FixMessageStoreCallbacks_t,
+    ) -> Option<FixMessageStoreFactory_t>;
+
+    pub fn FixCustomMessageStore_setError(message: *const ffi::c_char);
+
+    pub fn FixCompositeMessageStoreFactory_new(
+        primary: FixMessageStoreFactory_t,
+        secondary: FixMessageStoreFactory_t,
+        data: *const ffi::c_void,
+        onDivergence: Option<extern "C" fn(*const ffi::c_void, FixSessionID_t, *const ffi::c_char)>,
+    ) -> Option<FixMessageStoreFactory_t>;
+
     #[cfg(feature = "build-with-mysql")]
     pub fn FixMysqlMessageStoreFactory_new(
         settings: FixSessionSettings_t,
@@ -229,6 +531,31 @@ extern "C" {
         settings: FixSessionSettings_t,
     ) -> Option<FixMessageStoreFactory_t>;
 
+    #[must_use]
+    pub fn FixMessageStoreFactory_resetStore(
+        obj: FixMessageStoreFactory_t,
+        sessionId: FixSessionID_t,
+    ) -> i8;
+    #[must_use]
+    pub fn FixMessageStoreFactory_getSequenceNumbers(
+        obj: FixMessageStoreFactory_t,
+        sessionId: FixSessionID_t,
+        nextSender: *mut u32,
+        nextTarget: *mut u32,
+    ) -> i8;
+    #[must_use]
+    pub fn FixMessageStoreFactory_setSequenceNumbers(
+        obj: FixMessageStoreFactory_t,
+        sessionId: FixSessionID_t,
+        nextSender: u32,
+        nextTarget: u32,
+    ) -> i8;
+    #[must_use]
+    pub fn FixMessageStoreFactory_importStore(
+        obj: FixMessageStoreFactory_t,
+        sessionId: FixSessionID_t,
+        snapshot: *const FixStoreSnapshot_t,
+    ) -> i8;
     pub fn FixMessageStoreFactory_delete(obj: FixMessageStoreFactory_t);
 
     // Log factory
@@ -238,6 +565,12 @@ extern "C" {
         callbacks: *const FixLogCallbacks_t,
     ) -> Option<FixLogFactory_t>;
 
+    pub fn FixSessionLogFactory_new(
+        data: *const ffi::c_void,
+        factoryCallbacks: *const FixSessionLogFactoryCallbacks_t,
+        callbacks: *const FixLogCallbacks_t,
+    ) -> Option<FixLogFactory_t>;
+
     pub fn FixLogFactory_delete(obj: FixLogFactory_t);
 
     // Application
@@ -249,6 +582,10 @@ extern "C" {
 
     pub fn FixApplication_delete(obj: FixApplication_t);
 
+    pub fn FixApplication_setCallbackErrorText(text: *const ffi::c_char);
+
+    pub fn FixApplication_setCallbackErrorField(field: i32);
+
     // Socket acceptor
 
     pub fn FixAcceptor_new(
@@ -267,11 +604,17 @@ extern "C" {
     pub fn FixAcceptor_block(obj: FixAcceptor_t) -> i8;
 
     #[must_use]
-    pub fn FixAcceptor_poll(obj: FixAcceptor_t) -> i8;
+    pub fn FixAcceptor_poll(obj: FixAcceptor_t, timeout: f64) -> i8;
 
     #[must_use]
     pub fn FixAcceptor_stop(obj: FixAcceptor_t) -> i8;
 
+    #[must_use]
+    pub fn FixAcceptor_stopForce(obj: FixAcceptor_t) -> i8;
+
+    #[must_use]
+    pub fn FixAcceptor_stopWithReason(obj: FixAcceptor_t, reason: *const ffi::c_char) -> i8;
+
     #[must_use]
     pub fn FixAcceptor_isLoggedOn(obj: FixAcceptor_t) -> i8;
 
@@ -283,6 +626,28 @@ extern "C" {
         sessionId: FixSessionID_t,
     ) -> Option<FixSession_t>;
 
+    #[must_use]
+    pub fn FixAcceptor_getSessionCount(obj: FixAcceptor_t) -> i64;
+
+    pub fn FixAcceptor_getSessionIdAt(obj: FixAcceptor_t, index: u64) -> Option<FixSessionID_t>;
+
+    #[must_use]
+    pub fn FixAcceptor_removeSession(
+        obj: FixAcceptor_t,
+        id: FixSessionID_t,
+        forceDisconnect: i8,
+    ) -> i8;
+
+    pub fn FixAcceptor_getListenEndpointCount(obj: FixAcceptor_t) -> i64;
+
+    #[must_use]
+    pub fn FixAcceptor_readListenEndpointAt(
+        obj: FixAcceptor_t,
+        index: u64,
+        buffer: *mut ffi::c_char,
+        buffer_len: u64,
+    ) -> i8;
+
     pub fn FixAcceptor_delete(obj: FixAcceptor_t);
 
     // Socket initiator
@@ -303,11 +668,17 @@ extern "C" {
     pub fn FixInitiator_block(obj: FixInitiator_t) -> i8;
 
     #[must_use]
-    pub fn FixInitiator_poll(obj: FixInitiator_t) -> i8;
+    pub fn FixInitiator_poll(obj: FixInitiator_t, timeout: f64) -> i8;
 
     #[must_use]
     pub fn FixInitiator_stop(obj: FixInitiator_t) -> i8;
 
+    #[must_use]
+    pub fn FixInitiator_stopForce(obj: FixInitiator_t) -> i8;
+
+    #[must_use]
+    pub fn FixInitiator_stopWithReason(obj: FixInitiator_t, reason: *const ffi::c_char) -> i8;
+
     #[must_use]
     pub fn FixInitiator_isLoggedOn(obj: FixInitiator_t) -> i8;
 
@@ -319,6 +690,52 @@ extern "C" {
         sessionId: FixSessionID_t,
     ) -> Option<FixSession_t>;
 
+    #[must_use]
+    pub fn FixInitiator_getSessionCount(obj: FixInitiator_t) -> i64;
+
+    pub fn FixInitiator_getSessionIdAt(obj: FixInitiator_t, index: u64) -> Option<FixSessionID_t>;
+
+    pub fn FixInitiator_getSessionSettingsRef(
+        obj: FixInitiator_t,
+        id: FixSessionID_t,
+    ) -> Option<FixDictionary_t>;
+
+    #[must_use]
+    pub fn FixInitiator_setSessionSettings(
+        obj: FixInitiator_t,
+        id: FixSessionID_t,
+        value: FixDictionary_t,
+    ) -> i8;
+
+    #[must_use]
+    pub fn FixInitiator_addSession(
+        obj: FixInitiator_t,
+        application: FixApplication_t,
+        storeFactory: FixMessageStoreFactory_t,
+        logFactory: FixLogFactory_t,
+        id: FixSessionID_t,
+        value: FixDictionary_t,
+    ) -> i8;
+
+    #[must_use]
+    pub fn FixInitiator_removeSession(
+        obj: FixInitiator_t,
+        id: FixSessionID_t,
+        forceDisconnect: i8,
+    ) -> i8;
+
+    #[must_use]
+    pub fn FixInitiator_setReconnectInterval(obj: FixInitiator_t, seconds: i32) -> i8;
+
+    #[must_use]
+    pub fn FixInitiator_setReconnectPolicy(
+        obj: FixInitiator_t,
+        id: Option<FixSessionID_t>,
+        initialMillis: i64,
+        maxMillis: i64,
+        jitterMillis: i64,
+    ) -> i8;
+
     pub fn FixInitiator_delete(obj: FixInitiator_t);
 
     // Session ID
@@ -352,16 +769,25 @@ extern "C" {
 
     pub fn FixMessage_copy(src: FixMessage_t) -> Option<FixMessage_t>;
 
-    pub fn FixMessage_fromString(text: *const ffi::c_char) -> Option<FixMessage_t>;
+    pub fn FixMessage_fromString(text: *const ffi::c_char, text_len: u64) -> Option<FixMessage_t>;
 
     pub fn FixMessage_fromStringAndDictionary(
         text: *const ffi::c_char,
+        text_len: u64,
         dictionary: FixDataDictionary_t,
     ) -> Option<FixMessage_t>;
 
     #[must_use]
     pub fn FixMessage_setField(obj: FixMessage_t, tag: i32, value: *const ffi::c_char) -> i8;
 
+    #[must_use]
+    pub fn FixMessage_setFieldRaw(
+        obj: FixMessage_t,
+        tag: i32,
+        value: *const ffi::c_char,
+        value_len: u64,
+    ) -> i8;
+
     #[must_use]
     pub fn FixMessage_getField(obj: FixMessage_t, tag: i32) -> NullableCStr;
 
@@ -373,14 +799,53 @@ extern "C" {
         value_len: u64,
     ) -> i8;
 
+    #[must_use]
+    pub fn FixMessage_getFieldValue(
+        obj: FixMessage_t,
+        tag: i32,
+        value_len: *mut u64,
+    ) -> NullableCStr;
+
+    #[must_use]
+    pub fn FixMessage_getFields(
+        obj: FixMessage_t,
+        tags: *const i32,
+        tags_len: u64,
+        buffer: *mut ffi::c_char,
+        buffer_len: u64,
+        lengths: *mut i64,
+    ) -> i64;
+
     #[must_use]
     pub fn FixMessage_removeField(obj: FixMessage_t, tag: i32) -> i8;
 
+    #[must_use]
+    pub fn FixMessage_clear(obj: FixMessage_t) -> i8;
+
+    #[must_use]
+    pub fn FixMessage_assign(obj: FixMessage_t, src: FixMessage_t) -> i8;
+
     #[must_use]
     pub fn FixMessage_addGroup(obj: FixMessage_t, group: FixGroup_t) -> i8;
 
+    #[must_use]
+    pub fn FixMessage_addGroups(
+        obj: FixMessage_t,
+        tmpl: FixGroup_t,
+        tags: *const i32,
+        values: *const *const ffi::c_char,
+        value_lens: *const u64,
+        row_lens: *const u64,
+        rows_len: u64,
+    ) -> i8;
+
     pub fn FixMessage_toString(obj: FixMessage_t) -> NullableCStr;
 
+    pub fn FixMessage_toStringRef(obj: FixMessage_t, len: *mut u64) -> NullableCStr;
+
+    pub fn FixMessage_toBuffer(obj: FixMessage_t, buffer: *mut ffi::c_char, buffer_len: u64)
+        -> i64;
+
     pub fn FixMessage_getStringLen(obj: FixMessage_t) -> i64;
 
     #[must_use]
@@ -412,15 +877,48 @@ extern "C" {
         value_len: u64,
     ) -> i8;
 
+    #[must_use]
+    pub fn FixHeader_getFieldValue(obj: FixHeader_t, tag: i32, value_len: *mut u64)
+        -> NullableCStr;
+
+    #[must_use]
+    pub fn FixHeader_getFields(
+        obj: FixHeader_t,
+        tags: *const i32,
+        tags_len: u64,
+        buffer: *mut ffi::c_char,
+        buffer_len: u64,
+        lengths: *mut i64,
+    ) -> i64;
+
     #[must_use]
     pub fn FixHeader_setField(obj: FixHeader_t, tag: i32, value: *const ffi::c_char) -> i8;
 
+    #[must_use]
+    pub fn FixHeader_setFieldRaw(
+        obj: FixHeader_t,
+        tag: i32,
+        value: *const ffi::c_char,
+        value_len: u64,
+    ) -> i8;
+
     #[must_use]
     pub fn FixHeader_removeField(obj: FixHeader_t, tag: i32) -> i8;
 
     #[must_use]
     pub fn FixHeader_addGroup(obj: FixHeader_t, group: FixGroup_t) -> i8;
 
+    #[must_use]
+    pub fn FixHeader_addGroups(
+        obj: FixHeader_t,
+        tmpl: FixGroup_t,
+        tags: *const i32,
+        values: *const *const ffi::c_char,
+        value_lens: *const u64,
+        row_lens: *const u64,
+        rows_len: u64,
+    ) -> i8;
+
     pub fn FixHeader_delete(obj: FixHeader_t);
 
     // Trailer
@@ -443,15 +941,51 @@ extern "C" {
         value_len: u64,
     ) -> i8;
 
+    #[must_use]
+    pub fn FixTrailer_getFieldValue(
+        obj: FixTrailer_t,
+        tag: i32,
+        value_len: *mut u64,
+    ) -> NullableCStr;
+
+    #[must_use]
+    pub fn FixTrailer_getFields(
+        obj: FixTrailer_t,
+        tags: *const i32,
+        tags_len: u64,
+        buffer: *mut ffi::c_char,
+        buffer_len: u64,
+        lengths: *mut i64,
+    ) -> i64;
+
     #[must_use]
     pub fn FixTrailer_setField(obj: FixTrailer_t, tag: i32, value: *const ffi::c_char) -> i8;
 
+    #[must_use]
+    pub fn FixTrailer_setFieldRaw(
+        obj: FixTrailer_t,
+        tag: i32,
+        value: *const ffi::c_char,
+        value_len: u64,
+    ) -> i8;
+
     #[must_use]
     pub fn FixTrailer_removeField(obj: FixTrailer_t, tag: i32) -> i8;
 
     #[must_use]
     pub fn FixTrailer_addGroup(obj: FixTrailer_t, group: FixGroup_t) -> i8;
 
+    #[must_use]
+    pub fn FixTrailer_addGroups(
+        obj: FixTrailer_t,
+        tmpl: FixGroup_t,
+        tags: *const i32,
+        values: *const *const ffi::c_char,
+        value_lens: *const u64,
+        row_lens: *const u64,
+        rows_len: u64,
+    ) -> i8;
+
     pub fn FixTrailer_delete(obj: FixTrailer_t);
 
     // Group
@@ -484,32 +1018,106 @@ extern "C" {
         value_len: u64,
     ) -> i8;
 
+    #[must_use]
+    pub fn FixGroup_getFieldValue(obj: FixGroup_t, tag: i32, value_len: *mut u64) -> NullableCStr;
+
+    #[must_use]
+    pub fn FixGroup_getFields(
+        obj: FixGroup_t,
+        tags: *const i32,
+        tags_len: u64,
+        buffer: *mut ffi::c_char,
+        buffer_len: u64,
+        lengths: *mut i64,
+    ) -> i64;
+
     #[must_use]
     pub fn FixGroup_setField(obj: FixGroup_t, tag: i32, value: *const ffi::c_char) -> i8;
 
+    #[must_use]
+    pub fn FixGroup_setFieldRaw(
+        obj: FixGroup_t,
+        tag: i32,
+        value: *const ffi::c_char,
+        value_len: u64,
+    ) -> i8;
+
     #[must_use]
     pub fn FixGroup_removeField(obj: FixGroup_t, tag: i32) -> i8;
 
     #[must_use]
     pub fn FixGroup_addGroup(obj: FixGroup_t, group: FixGroup_t) -> i8;
 
+    #[must_use]
+    pub fn FixGroup_addGroups(
+        obj: FixGroup_t,
+        tmpl: FixGroup_t,
+        tags: *const i32,
+        values: *const *const ffi::c_char,
+        value_lens: *const u64,
+        row_lens: *const u64,
+        rows_len: u64,
+    ) -> i8;
+
     pub fn FixGroup_delete(obj: FixGroup_t);
 
     // Session
 
     pub fn FixSession_sendToTarget(msg: FixMessage_t, session_id: FixSessionID_t) -> i8;
+    #[must_use]
+    pub fn FixSession_sendToTargetWithCompIds(
+        msg: FixMessage_t,
+        beginString: *const ffi::c_char,
+        senderCompId: *const ffi::c_char,
+        targetCompId: *const ffi::c_char,
+        qualifier: *const ffi::c_char,
+    ) -> i8;
+    #[must_use]
+    pub fn FixSession_sendAllToTarget(
+        msgs: *const FixMessage_t,
+        msgs_len: u64,
+        session_id: FixSessionID_t,
+    ) -> i64;
+    #[must_use]
+    pub fn FixSession_trySendToTarget(msg: FixMessage_t, session_id: FixSessionID_t) -> i8;
     pub fn FixSession_lookup(session_id: FixSessionID_t) -> Option<FixSession_t>;
     #[must_use]
     pub fn FixSession_logout(session: FixSession_t) -> i8;
     #[must_use]
+    pub fn FixSession_logoutWithReason(session: FixSession_t, reason: *const ffi::c_char) -> i8;
+    #[must_use]
     pub fn FixSession_isLoggedOn(session: FixSession_t) -> i8;
     #[must_use]
+    pub fn FixSession_isEnabled(session: FixSession_t) -> i8;
+    #[must_use]
     pub fn FixSession_send(session: FixSession_t, msg: FixMessage_t) -> i8;
+    pub fn FixSession_resolve(
+        session_id: FixSessionID_t,
+        generation: *mut u64,
+    ) -> Option<FixSession_t>;
+    #[must_use]
+    pub fn FixSession_sendResolved(session: FixSession_t, generation: u64, msg: FixMessage_t)
+        -> i8;
     #[must_use]
     pub fn FixSession_reset(session: FixSession_t) -> i8;
     #[must_use]
     pub fn FixSession_logon(session: FixSession_t) -> i8;
     #[must_use]
+    pub fn FixSession_disconnect(session: FixSession_t) -> i8;
+    #[must_use]
+    pub fn FixSession_requestResend(session: FixSession_t, beginSeqNo: i32, endSeqNo: i32) -> i8;
+    #[must_use]
+    pub fn FixSession_sendGapFill(session: FixSession_t, newSeqNo: i32) -> i8;
+    #[must_use]
+    pub fn FixSession_next(session: FixSession_t, msg: *const ffi::c_char) -> i8;
+    #[must_use]
+    pub fn FixSession_tick(session: FixSession_t, nowMillis: i64) -> i8;
+    #[must_use]
+    pub fn FixSession_refresh(session: FixSession_t) -> i8;
+    #[must_use]
+    pub fn FixSession_sendTestRequest(session: FixSession_t, test_req_id: *const ffi::c_char)
+        -> i8;
+    #[must_use]
     pub fn FixSession_setNextSenderMsgSeqNum(session: FixSession_t, num: i32) -> i8;
     #[must_use]
     pub fn FixSession_setNextTargetMsgSeqNum(session: FixSession_t, num: i32) -> i8;
@@ -517,4 +1125,69 @@ extern "C" {
     pub fn FixSession_getExpectedTargetNum(session: FixSession_t) -> i32;
     #[must_use]
     pub fn FixSession_getExpectedSenderNum(session: FixSession_t) -> i32;
+    #[must_use]
+    pub fn FixSession_getSessionID(session: FixSession_t) -> Option<FixSessionID_t>;
+    #[must_use]
+    pub fn FixSession_getOutboundQueueLen(session: FixSession_t) -> i64;
+    #[must_use]
+    pub fn FixSession_getOutboundQueueLenById(session_id: FixSessionID_t) -> i64;
+    #[must_use]
+    pub fn FixSession_getStats(session: FixSession_t, stats: *mut FixSessionStats_t) -> i8;
+    #[must_use]
+    pub fn FixSession_getTransportStats(
+        session: FixSession_t,
+        stats: *mut FixTransportStats_t,
+    ) -> i8;
+    #[must_use]
+    pub fn FixSession_getSettings(session: FixSession_t) -> Option<FixDictionary_t>;
+    #[must_use]
+    pub fn FixSession_isResendRequested(session: FixSession_t) -> i8;
+    #[must_use]
+    pub fn FixSession_getHeartBtInt(session: FixSession_t) -> i32;
+    #[must_use]
+    pub fn FixSession_setHeartBtInt(session: FixSession_t, value: i32) -> i8;
+    #[must_use]
+    pub fn FixSession_isSessionTime(session: FixSession_t) -> i8;
+    #[must_use]
+    pub fn FixSession_isSessionTimeAt(session: FixSession_t, nowMillis: i64) -> i8;
+    #[must_use]
+    pub fn FixSession_getMillisSinceLastReceived(session: FixSession_t) -> i64;
+    #[must_use]
+    pub fn FixSession_getStoreCreationTime(session: FixSession_t) -> i64;
+    #[must_use]
+    pub fn FixSession_getStoreMetrics(session: FixSession_t, metrics: *mut FixStoreMetrics_t)
+        -> i8;
+    #[must_use]
+    pub fn FixSession_importStore(session: FixSession_t, snapshot: *const FixStoreSnapshot_t)
+        -> i8;
+    #[must_use]
+    pub fn FixSession_setResetOnLogon(session: FixSession_t, value: i8) -> i8;
+    #[must_use]
+    pub fn FixSession_setResetOnLogout(session: FixSession_t, value: i8) -> i8;
+    #[must_use]
+    pub fn FixSession_setResetOnDisconnect(session: FixSession_t, value: i8) -> i8;
+    #[must_use]
+    pub fn FixSession_readPeerAddress(
+        session: FixSession_t,
+        buffer: *mut ffi::c_char,
+        buffer_len: u64,
+    ) -> i8;
+    #[must_use]
+    pub fn FixSession_setSocketOptions(
+        session: FixSession_t,
+        options: *const FixSocketOptions_t,
+    ) -> i8;
+    #[must_use]
+    pub fn FixSession_readSocketOptions(
+        session: FixSession_t,
+        options: *mut FixSocketOptions_t,
+    ) -> i8;
+    #[must_use]
+    pub fn FixSession_getStoredMessages(
+        session: FixSession_t,
+        begin: u32,
+        end: u32,
+        output: *mut ffi::c_void,
+        push: extern "C" fn(output: *mut ffi::c_void, msg: *const ffi::c_char, len: u64),
+    ) -> i8;
 }
@@ -0,0 +1,91 @@
+//! Regenerate `quickfix::tags` and `quickfix::msg_types` modules.
+//!
+//! ```sh
+//! cargo run -p quickfix-msg-gen --example core-constants -- \
+//!     quickfix/src \
+//!     quickfix-ffi/libquickfix/spec/FIXT11.xml \
+//!     quickfix-ffi/libquickfix/spec/FIX50.xml
+//! ```
+
+use std::{env::args, fmt::Write, fs, path::Path};
+
+use convert_case::{Case, Casing};
+use quickfix_spec_parser::*;
+
+const HEADER: &str = "\
+// Generated by `quickfix-msg-gen/examples/core-constants.rs` from the FIX 5.0 / FIXT 1.1 dictionary.
+// Do not edit by hand.
+
+";
+
+const TAGS_DOC: &str = r#"//! Field tag number of every standard FIX field.
+//!
+//! Constants are named after FIX field name, so they can be used without any generated message crate:
+//!
+//! ```
+//! use quickfix::{tags, Message};
+//!
+//! # fn main() -> Result<(), quickfix::QuickFixError> {
+//! let mut msg = Message::new();
+//! msg.set_field(tags::CL_ORD_ID, "ORDER-1")?;
+//! assert_eq!(msg.get_field(tags::CL_ORD_ID).as_deref(), Some("ORDER-1"));
+//! # Ok(())
+//! # }
+//! ```
+
+"#;
+
+const MSG_TYPES_DOC: &str = r#"//! `MsgType` (tag 35) value of every standard FIX message.
+//!
+//! ```
+//! use quickfix::{msg_types, tags, Message};
+//!
+//! # fn main() -> Result<(), quickfix::QuickFixError> {
+//! let mut msg = Message::new();
+//! msg.with_header_mut(|h| h.set_field(tags::MSG_TYPE, msg_types::NEW_ORDER_SINGLE))?;
+//! # Ok(())
+//! # }
+//! ```
+
+"#;
+
+fn main() {
+    let mut args = args().skip(1);
+    let dst = args.next().expect("Missing destination directory");
+    let spec = merge_specs(args.map(|filename| {
+        eprintln!("Parsing file: {filename}");
+        let file_content = fs::read(filename).expect("Cannot read file");
+        parse_spec(&file_content).expect("Cannot parse spec")
+    }));
+
+    let mut field_specs: Vec<_> = spec.field_specs.iter().collect();
+    field_specs.sort_by_key(|field_spec| field_spec.number);
+
+    let mut tags = format!("{TAGS_DOC}{HEADER}");
+    for field_spec in field_specs {
+        writeln!(
+            tags,
+            "/// `{}` field.\npub const {}: i32 = {};",
+            field_spec.name,
+            field_spec.name.to_case(Case::Constant),
+            field_spec.number
+        )
+        .unwrap();
+    }
+
+    let mut msg_types = format!("{MSG_TYPES_DOC}{HEADER}");
+    for message in &spec.messages {
+        writeln!(
+            msg_types,
+            "/// `{}` message.\npub const {}: &str = \"{}\";",
+            message.name,
+            message.name.to_case(Case::Constant),
+            message.msg_type
+        )
+        .unwrap();
+    }
+
+    let dst = Path::new(&dst);
+    fs::write(dst.join("tags.rs"), tags).expect("Cannot write tags");
+    fs::write(dst.join("msg_types.rs"), msg_types).expect("Cannot write msg types");
+}
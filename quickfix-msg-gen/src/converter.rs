@@ -1,14 +1,15 @@
 use std::collections::HashSet;
 
-use quickfix_spec_parser::{FieldValue, FixSpec, Message};
+use quickfix_spec_parser::{FieldSpec, FieldType, FieldValue, FixSpec, Message};
 
-use crate::{FixCodeSpec, MessageField, MessageGroup, MessageSpec, SubComponent};
+use crate::{FixCodeSpec, MessageComponent, MessageField, MessageGroup, MessageSpec, SubComponent};
 
 pub fn convert_spec(src: FixSpec) -> FixCodeSpec {
     FixCodeSpec {
         headers: convert_field_value_list(&src, &src.headers),
         trailers: convert_field_value_list(&src, &src.trailers),
         messages: convert_messages(&src, &src.messages),
+        components: convert_components(&src),
         field_specs: src.field_specs,
     }
 }
@@ -22,9 +23,16 @@ fn convert_field_value_list(spec: &FixSpec, values: &[FieldValue]) -> Vec<SubCom
             FieldValue::Field(x) => output.push(SubComponent::Field(MessageField {
                 name: x.name.clone(),
                 required: x.required,
+                is_enum: find_field_spec(spec, &x.name)
+                    .is_some_and(|field_spec| !field_spec.values.is_empty()),
+                is_bool: find_field_spec(spec, &x.name)
+                    .is_some_and(|field_spec| matches!(field_spec.r#type, FieldType::Boolean)),
+                is_utc_timestamp: find_field_spec(spec, &x.name)
+                    .is_some_and(|field_spec| matches!(field_spec.r#type, FieldType::UtcTimeStamp)),
             })),
             FieldValue::Group(x) => output.push(SubComponent::Group(MessageGroup {
                 name: x.name.clone(),
+                component: None,
                 components: convert_field_value_list(spec, &x.values),
             })),
             FieldValue::Component(component) => {
@@ -34,7 +42,16 @@ fn convert_field_value_list(spec: &FixSpec, values: &[FieldValue]) -> Vec<SubCom
                     .find(|x| x.name == component.name)
                     .expect("Cannot find component");
 
-                output.extend(convert_field_value_list(spec, &component_spec.values));
+                let mut values = convert_field_value_list(spec, &component_spec.values);
+
+                // Component only wrapping a repeating group (ex: `Parties`) gives it a nicer name.
+                if let [SubComponent::Group(group)] = values.as_mut_slice() {
+                    group
+                        .component
+                        .get_or_insert_with(|| component.name.clone());
+                }
+
+                output.extend(values);
             }
         }
     }
@@ -46,13 +63,38 @@ fn convert_field_value_list(spec: &FixSpec, values: &[FieldValue]) -> Vec<SubCom
     output
 }
 
+fn find_field_spec<'a>(spec: &'a FixSpec, name: &str) -> Option<&'a FieldSpec> {
+    spec.field_specs
+        .iter()
+        .find(|field_spec| field_spec.name == name)
+}
+
 fn convert_messages(spec: &FixSpec, messages: &[Message]) -> Vec<MessageSpec> {
     messages
         .iter()
         .map(|message| MessageSpec {
             name: message.name.clone(),
             msg_type: message.msg_type.clone(),
+            category: message.category,
             components: convert_field_value_list(spec, &message.values),
+            component_names: message
+                .values
+                .iter()
+                .filter_map(|value| match value {
+                    FieldValue::Component(component) => Some(component.name.clone()),
+                    _ => None,
+                })
+                .collect(),
+        })
+        .collect()
+}
+
+fn convert_components(spec: &FixSpec) -> Vec<MessageComponent> {
+    spec.component_specs
+        .iter()
+        .map(|component_spec| MessageComponent {
+            name: component_spec.name.clone(),
+            components: convert_field_value_list(spec, &component_spec.values),
         })
         .collect()
 }
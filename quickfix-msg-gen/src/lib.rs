@@ -1,6 +1,7 @@
 /*! Code generator from XML FIX dictionary spec file. */
 
 use std::{
+    collections::HashSet,
     fs,
     io::{self, Write},
     path::Path,
@@ -15,7 +16,7 @@ use convert_case::{Case, Casing};
 mod converter;
 mod model;
 
-use quickfix_spec_parser::{FieldSpec, FieldType};
+use quickfix_spec_parser::{FieldSpec, FieldType, FixSpec, FixSpecError, MessageCategory};
 
 trait FieldAccessorGenerator {
     fn getter_prefix_text(&self) -> &'static str;
@@ -24,57 +25,313 @@ trait FieldAccessorGenerator {
 }
 
 /// Take a FIX XML spec file as `src` parameter and generated code to `dst` parameter.
+///
+/// It is meant to be called from a `build.rs`, so custom dictionaries get the same typed messages,
+/// fields and groups as the crates published from official specs:
+///
+/// ```no_run
+/// // build.rs
+/// fn main() -> std::io::Result<()> {
+///     let out_dir = std::env::var("OUT_DIR").expect("Missing OUT_DIR");
+///     println!("cargo:rerun-if-changed=spec/OUR_FIX44.xml");
+///     quickfix_msg_gen::generate("spec/OUR_FIX44.xml", format!("{out_dir}/code.rs"), "FIX.4.4")
+/// }
+/// ```
+///
+/// Then include generated code in the crate: `include!(concat!(env!("OUT_DIR"), "/code.rs"));`.
+///
+/// # Errors
+///
+/// When spec cannot be read, or is invalid (malformed XML, duplicated field, reference to an
+/// unknown field or component). Error message starts with `file:line:column` where the problem
+/// has been detected, so the build fails with a readable location.
 pub fn generate<S: AsRef<Path>, D: AsRef<Path>>(
     src: S,
     dst: D,
     begin_string: &str,
 ) -> io::Result<()> {
+    Generator::new().generate(src, dst, begin_string)
+}
+
+/// Take FIXT transport spec file and one of FIX 5.0 application spec file, merge them and
+/// generate code to `dst` parameter.
+///
+/// Generated application messages have their `ApplVerID` (1128) header field set to
+/// `appl_ver_id`, e.g. `"9"` for FIX 5.0 SP2.
+pub fn generate_fixt<T: AsRef<Path>, A: AsRef<Path>, D: AsRef<Path>>(
+    transport_src: T,
+    app_src: A,
+    dst: D,
+    appl_ver_id: &str,
+) -> io::Result<()> {
+    Generator::new().generate_fixt(transport_src, app_src, dst, appl_ver_id)
+}
+
+/// Case of field names in serialized messages, see [`Generator::serde_field_case`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SerdeFieldCase {
+    /// Ex: `clOrdId`.
+    #[default]
+    Camel,
+    /// Ex: `cl_ord_id`.
+    Snake,
+}
+
+impl SerdeFieldCase {
+    fn format(self, name: &str) -> String {
+        match self {
+            Self::Camel => name.to_case(Case::Camel),
+            Self::Snake => name.to_case(Case::Snake),
+        }
+    }
+}
+
+/// Code generator with options, [`generate`] and [`generate_fixt`] use default ones.
+///
+/// Output only depends on spec and options, so generated code can be committed and diffed.
+///
+/// ```no_run
+/// // build.rs
+/// use quickfix_msg_gen::{Generator, SerdeFieldCase};
+///
+/// fn main() -> std::io::Result<()> {
+///     let out_dir = std::env::var("OUT_DIR").expect("Missing OUT_DIR");
+///     Generator::new()
+///         .serde(std::env::var_os("CARGO_FEATURE_SERDE").is_some())
+///         .serde_field_case(SerdeFieldCase::Snake)
+///         .generate("spec/OUR_FIX44.xml", format!("{out_dir}/code.rs"), "FIX.4.4")
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Generator {
+    serde: bool,
+    serde_field_case: SerdeFieldCase,
+    components: bool,
+    chrono: bool,
+}
+
+impl Default for Generator {
+    fn default() -> Self {
+        Self {
+            serde: false,
+            serde_field_case: SerdeFieldCase::default(),
+            components: true,
+            chrono: false,
+        }
+    }
+}
+
+impl Generator {
+    /// Create new generator with default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Generate `components` module, with one struct per component block, and matching message
+    /// accessors. Enabled by default.
+    pub fn components(mut self, enabled: bool) -> Self {
+        self.components = enabled;
+        self
+    }
+
+    /// Implement `serde::Serialize` / `serde::Deserialize` for messages, groups and enumerated
+    /// field types.
+    ///
+    /// Generated crate must then depend on `serde`.
+    /// Messages are serialized as a map of their body fields, by name. Enumerated values use
+    /// their symbolic name (ex: `"Buy"`), and are checked when deserializing.
+    pub fn serde(mut self, enabled: bool) -> Self {
+        self.serde = enabled;
+        self
+    }
+
+    /// Change case of serialized field names, default is [`SerdeFieldCase::Camel`].
+    pub fn serde_field_case(mut self, case: SerdeFieldCase) -> Self {
+        self.serde_field_case = case;
+        self
+    }
+
+    /// Add `chrono` accessors to header and trailer UTC timestamp fields (ex:
+    /// `get_sending_time_utc()` / `set_sending_time_utc()`).
+    ///
+    /// Generated crate must then depend on `chrono`.
+    pub fn chrono(mut self, enabled: bool) -> Self {
+        self.chrono = enabled;
+        self
+    }
+
+    /// Same as [`generate`], with current options.
+    pub fn generate<S: AsRef<Path>, D: AsRef<Path>>(
+        &self,
+        src: S,
+        dst: D,
+        begin_string: &str,
+    ) -> io::Result<()> {
+        let spec = read_spec(src)?;
+        self.generate_spec(spec, dst, begin_string, None)
+    }
+
+    /// Same as [`generate_fixt`], with current options.
+    pub fn generate_fixt<T: AsRef<Path>, A: AsRef<Path>, D: AsRef<Path>>(
+        &self,
+        transport_src: T,
+        app_src: A,
+        dst: D,
+        appl_ver_id: &str,
+    ) -> io::Result<()> {
+        let spec =
+            quickfix_spec_parser::merge_specs([read_spec(transport_src)?, read_spec(app_src)?]);
+        self.generate_spec(spec, dst, "FIXT.1.1", Some(appl_ver_id))
+    }
+
+    fn serde_case(&self) -> Option<SerdeFieldCase> {
+        self.serde.then_some(self.serde_field_case)
+    }
+
+    fn generate_spec<D: AsRef<Path>>(
+        &self,
+        spec: FixSpec,
+        dst: D,
+        begin_string: &str,
+        appl_ver_id: Option<&str>,
+    ) -> io::Result<()> {
+        let spec = convert_spec(spec);
+        let serde = self.serde_case();
+        let version = format_version_name(begin_string, appl_ver_id);
+
+        // Generate the code.
+        println!("Generating code ...");
+        let mut output = String::with_capacity(5 << 20); // 5Mo initial buffer
+        generate_root(&mut output, begin_string, appl_ver_id, serde);
+        generate_field_ids(&mut output, &spec.field_specs);
+        generate_field_types(&mut output, &spec.field_specs, serde.is_some());
+        generate_field_names(&mut output, &spec.field_specs);
+        if self.components {
+            generate_components(&mut output, &spec.components);
+        }
+        generate_headers(&mut output, &spec.headers, &version, self.chrono);
+        generate_trailers(&mut output, &spec.trailers, &version, self.chrono);
+        generate_messages(
+            &mut output,
+            &spec.messages,
+            appl_ver_id.is_some(),
+            serde,
+            self.components,
+        );
+        generate_message_cracker(&mut output, &spec.messages);
+        generate_message_cracker_trait(&mut output, &spec.messages, &version);
+        generate_typed_application(&mut output, &spec.messages);
+
+        // Spawn a rustfmt daemon.
+        let mut rustfmt = process::Command::new("rustfmt")
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()?;
+
+        // Send the code to rustfmt.
+        println!("Formatting code ...");
+        let mut rustfmt_in = rustfmt.stdin.take().expect("Fail to take rustfmt stdin");
+        rustfmt_in.write_all(output.as_bytes())?;
+        rustfmt_in.flush()?;
+        drop(rustfmt_in); // Avoid infinite waiting !
+
+        // Check output and write result.
+        let rustfmt_out = rustfmt.wait_with_output()?;
+        if !rustfmt_out.status.success() {
+            println!("rustfmt stdout =======================");
+            println!("{}", String::from_utf8_lossy(&rustfmt_out.stdout));
+            println!("rustfmt stderr =======================");
+            println!("{}", String::from_utf8_lossy(&rustfmt_out.stderr));
+
+            panic!("Fail to run rustfmt");
+        }
+
+        // Write code to disk.
+        println!("Writing code to disk ...");
+        fs::write(dst, rustfmt_out.stdout)?;
+        Ok(())
+    }
+}
+
+/// Read and validate FIX XML spec file, failing with the same errors as [`generate`].
+pub fn read_spec<S: AsRef<Path>>(src: S) -> io::Result<FixSpec> {
+    let src = src.as_ref();
     let spec_data = fs::read(src)?;
-    let spec = quickfix_spec_parser::parse_spec(&spec_data).expect("Cannot parse FIX spec");
-    let spec = convert_spec(spec);
-
-    // Generate the code.
-    println!("Generating code ...");
-    let mut output = String::with_capacity(5 << 20); // 5Mo initial buffer
-    generate_root(&mut output, begin_string);
-    generate_field_ids(&mut output, &spec.field_specs);
-    generate_field_types(&mut output, &spec.field_specs);
-    generate_headers(&mut output, &spec.headers);
-    generate_trailers(&mut output, &spec.trailers);
-    generate_messages(&mut output, &spec.messages);
-    generate_message_cracker(&mut output, &spec.messages);
-
-    // Spawn a rustfmt daemon.
-    let mut rustfmt = process::Command::new("rustfmt")
-        .stdin(Stdio::piped())
-        .stdout(Stdio::piped())
-        .spawn()?;
-
-    // Send the code to rustfmt.
-    println!("Formatting code ...");
-    let mut rustfmt_in = rustfmt.stdin.take().expect("Fail to take rustfmt stdin");
-    rustfmt_in.write_all(output.as_bytes())?;
-    rustfmt_in.flush()?;
-    drop(rustfmt_in); // Avoid infinite waiting !
-
-    // Check output and write result.
-    let rustfmt_out = rustfmt.wait_with_output()?;
-    if !rustfmt_out.status.success() {
-        println!("rustfmt stdout =======================");
-        println!("{}", String::from_utf8_lossy(&rustfmt_out.stdout));
-        println!("rustfmt stderr =======================");
-        println!("{}", String::from_utf8_lossy(&rustfmt_out.stderr));
-
-        panic!("Fail to run rustfmt");
-    }
-
-    // Write code to disk.
-    println!("Writing code to disk ...");
-    fs::write(dst, rustfmt_out.stdout)?;
-    Ok(())
-}
-
-fn generate_root(output: &mut String, begin_string: &str) {
+
+    let spec = quickfix_spec_parser::parse_spec_located(&spec_data)
+        .map_err(|err| spec_error(src, &spec_data, Some(err.offset as usize), &err.error))?;
+    quickfix_spec_parser::validate_spec(&spec)
+        .map_err(|err| spec_error(src, &spec_data, locate_spec_error(&spec_data, &err), &err))?;
+
+    Ok(spec)
+}
+
+/// Find offset of the XML node which is the most likely cause of `err`.
+fn locate_spec_error(spec_data: &[u8], err: &FixSpecError) -> Option<usize> {
+    let find_last = |attribute: &str, value: &str| {
+        [
+            format!("{attribute}=\"{value}\""),
+            format!("{attribute}='{value}'"),
+        ]
+        .iter()
+        .filter_map(|needle| {
+            spec_data
+                .windows(needle.len())
+                .rposition(|window| window == needle.as_bytes())
+        })
+        .max()
+    };
+    let find_first = |prefix: &str, value: &str| {
+        [format!("{prefix}\"{value}\""), format!("{prefix}'{value}'")]
+            .iter()
+            .filter_map(|needle| {
+                spec_data
+                    .windows(needle.len())
+                    .position(|window| window == needle.as_bytes())
+            })
+            .min()
+    };
+
+    match err {
+        // Duplicate is defined after the original one.
+        FixSpecError::DuplicateField { number, .. } => find_last("number", &number.to_string()),
+        // Unknown name is only used as a reference.
+        FixSpecError::UnknownField(name) | FixSpecError::UnknownComponent(name) => {
+            find_last("name", name)
+        }
+        // Field with the same name is defined later, so look for the group node itself.
+        FixSpecError::EmptyGroup(name) => find_first("<group name=", name),
+        _ => None,
+    }
+}
+
+fn spec_error(
+    src: &Path,
+    spec_data: &[u8],
+    offset: Option<usize>,
+    err: &FixSpecError,
+) -> io::Error {
+    let location = match offset {
+        Some(offset) => {
+            let before = &spec_data[..offset.min(spec_data.len())];
+            let line = before.iter().filter(|x| **x == b'\n').count() + 1;
+            let column = before.iter().rev().take_while(|x| **x != b'\n').count() + 1;
+            format!("{}:{line}:{column}", src.display())
+        }
+        None => src.display().to_string(),
+    };
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("{location}: invalid FIX spec: {err}"),
+    )
+}
+
+fn generate_root(
+    output: &mut String,
+    begin_string: &str,
+    appl_ver_id: Option<&str>,
+    serde: Option<SerdeFieldCase>,
+) {
     output.push_str(&format!(
         r#" #[allow(unused_imports)]
             use quickfix::*;
@@ -92,6 +349,90 @@ fn generate_root(output: &mut String, begin_string: &str) {
 
             impl std::error::Error for FixParseError {{}}
 
+            /// Value received or given for an enumerated field is not defined by the dictionary.
+            #[derive(Debug, PartialEq, Eq, Clone, Hash)]
+            pub struct FixValueError {{
+                /// Field name.
+                pub field: &'static str,
+                /// Offending value.
+                pub value: String,
+            }}
+
+            impl std::fmt::Display for FixValueError {{
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{
+                    write!(f, "invalid {{}} value: {{:?}}", self.field, self.value)
+                }}
+            }}
+
+            impl std::error::Error for FixValueError {{}}
+
+            /// Reason why a [`quickfix::Message`] cannot be converted to a typed message.
+            #[derive(Debug, PartialEq, Eq, Clone, Hash)]
+            pub enum ConversionError {{
+                /// MsgType(35) is missing or is not the one of the typed message.
+                WrongMsgType {{
+                    /// Expected message type.
+                    expected: &'static str,
+                    /// Message type of the converted message.
+                    actual: Option<String>,
+                }},
+                /// A required field is missing.
+                MissingField {{
+                    /// Missing tag.
+                    tag: i32,
+                }},
+                /// Field value cannot be parsed to its type.
+                InvalidField {{
+                    /// Offending tag.
+                    tag: i32,
+                    /// Raw value.
+                    value: String,
+                }},
+            }}
+
+            impl std::fmt::Display for ConversionError {{
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{
+                    match self {{
+                        Self::WrongMsgType {{ expected, actual }} => {{
+                            write!(f, "wrong message type: expected {{expected:?}}, got {{actual:?}}")
+                        }}
+                        Self::MissingField {{ tag }} => write!(f, "missing required field (tag {{tag}})"),
+                        Self::InvalidField {{ tag, value }} => {{
+                            write!(f, "invalid field value (tag {{tag}}, value {{value:?}})")
+                        }}
+                    }}
+                }}
+            }}
+
+            impl std::error::Error for ConversionError {{}}
+
+            impl From<ConversionError> for quickfix::MsgFromAppError {{
+                fn from(err: ConversionError) -> Self {{
+                    match err {{
+                        ConversionError::WrongMsgType {{ .. }} => Self::UnsupportedMessageType,
+                        ConversionError::MissingField {{ tag }} => Self::FieldNotFound {{ tag }},
+                        ConversionError::InvalidField {{ tag, .. }} => Self::IncorrectDataFormat {{ tag }},
+                    }}
+                }}
+            }}
+
+            /// Check field read from a message can be parsed to its typed value.
+            #[allow(dead_code)]
+            fn check_field<T: std::str::FromStr>(
+                tag: i32,
+                value: Option<String>,
+                required: bool,
+            ) -> Result<(), ConversionError> {{
+                match value {{
+                    Some(value) if value.parse::<T>().is_err() => {{
+                        Err(ConversionError::InvalidField {{ tag, value }})
+                    }}
+                    Some(_) => Ok(()),
+                    None if required => Err(ConversionError::MissingField {{ tag }}),
+                    None => Ok(()),
+                }}
+            }}
+
             pub struct GroupIterator<'a, T, I> {{
                 parent: &'a T,
                 clone_group_func: fn(&'a T, usize) -> Option<I>,
@@ -108,7 +449,54 @@ fn generate_root(output: &mut String, begin_string: &str) {
             }}
 
             "#
-    ))
+    ));
+
+    if let Some(appl_ver_id) = appl_ver_id {
+        output.push_str(&format!(
+            "/// Application version, set on every application message header.\n\
+             pub const FIX_APPL_VER_ID: &str = \"{appl_ver_id}\";\n\n"
+        ));
+    }
+
+    if serde.is_some() {
+        output.push_str(
+            r#" /// Helpers shared by generated `serde` implementations.
+                mod serde_support {
+                    use serde::{de, ser};
+
+                    /// Parse field read from a message before serializing it.
+                    pub fn parse<T: std::str::FromStr, E: ser::Error>(
+                        key: &str,
+                        value: String,
+                    ) -> Result<T, E> {
+                        value
+                            .parse()
+                            .map_err(|_| E::custom(format!("invalid {key} value: {value:?}")))
+                    }
+
+                    /// Parse FIX boolean (`Y` / `N`) before serializing it.
+                    pub fn parse_bool<E: ser::Error>(key: &str, value: String) -> Result<bool, E> {
+                        match value.as_str() {
+                            "Y" => Ok(true),
+                            "N" => Ok(false),
+                            _ => Err(E::custom(format!("invalid {key} value: {value:?}"))),
+                        }
+                    }
+
+                    /// Deserialize map value, naming field in error.
+                    pub fn next_value<'de, T, A>(map: &mut A, key: &str) -> Result<T, A::Error>
+                    where
+                        T: de::Deserialize<'de>,
+                        A: de::MapAccess<'de>,
+                    {
+                        map.next_value()
+                            .map_err(|err| de::Error::custom(format!("{key}: {err}")))
+                    }
+                }
+
+                "#,
+        );
+    }
 }
 
 fn generate_field_ids(output: &mut String, field_specs: &[FieldSpec]) {
@@ -125,7 +513,60 @@ fn generate_field_ids(output: &mut String, field_specs: &[FieldSpec]) {
     output.push_str("} // field_id\n\n");
 }
 
-fn generate_field_types(output: &mut String, field_specs: &[FieldSpec]) {
+fn generate_field_names(output: &mut String, field_specs: &[FieldSpec]) {
+    output.push_str(
+        r#" /// Field and value names of the dictionary, used to `Display` messages.
+            #[derive(Debug, Clone, Copy, Default)]
+            pub struct FieldNames;
+
+            impl quickfix::FieldNameResolver for FieldNames {
+                fn field_name(&self, tag: i32) -> Option<std::borrow::Cow<'_, str>> {
+                    let name = match tag {
+            "#,
+    );
+    for field_spec in field_specs {
+        output.push_str(&format!(
+            "crate::field_id::{} => \"{}\",\n",
+            field_spec.name.to_case(Case::Constant),
+            field_spec.name,
+        ));
+    }
+    output.push_str(
+        r#"             _ => return None,
+                    };
+                    Some(std::borrow::Cow::Borrowed(name))
+                }
+
+                fn value_name(&self, tag: i32, value: &str) -> Option<std::borrow::Cow<'_, str>> {
+                    let name = match tag {
+            "#,
+    );
+    for field_spec in field_specs.iter().filter(|x| !x.values.is_empty()) {
+        output.push_str(&format!(
+            "crate::field_id::{} => match value {{\n",
+            field_spec.name.to_case(Case::Constant),
+        ));
+        for value in &field_spec.values {
+            output.push_str(&format!(
+                "\"{}\" => \"{}\",\n",
+                value.value,
+                value.description.to_case(Case::UpperCamel),
+            ));
+        }
+        output.push_str("_ => return None,\n},\n");
+    }
+    output.push_str(
+        r#"             _ => return None,
+                    };
+                    Some(std::borrow::Cow::Borrowed(name))
+                }
+            }
+
+            "#,
+    );
+}
+
+fn generate_field_types(output: &mut String, field_specs: &[FieldSpec], serde: bool) {
     output.push_str("pub mod field_types {\n");
 
     for field_spec in field_specs {
@@ -138,6 +579,9 @@ fn generate_field_types(output: &mut String, field_specs: &[FieldSpec]) {
                     generate_field_type_char_values(output, field_spec);
                 }
             }
+            if serde {
+                generate_field_type_serde(output, field_spec);
+            }
         } else {
             generate_field_type_alias(output, field_spec);
         }
@@ -248,6 +692,65 @@ fn generate_field_type_values(output: &mut String, field_spec: &FieldSpec) {
             "#,
     );
 
+    // Generate FIX value accessors.
+    let variants = field_spec
+        .values
+        .iter()
+        .map(|value| format!("Self::{}", value.description.to_case(Case::UpperCamel)))
+        .collect::<Vec<_>>()
+        .join(", ");
+    output.push_str(&format!(
+        r#" impl {type_name} {{
+                /// Every value defined by the dictionary.
+                pub const ALL: &'static [Self] = &[{variants}];
+
+                /// Get FIX value.
+                #[inline(always)]
+                pub const fn as_fix_str(&self) -> &'static str {{
+                    match self {{
+                    "#
+    ));
+    for value in &field_spec.values {
+        output.push_str(&format!(
+            "    Self::{} => \"{}\",\n",
+            value.description.to_case(Case::UpperCamel),
+            value.value,
+        ));
+    }
+    output.push_str(
+        r#"         }
+                }
+            "#,
+    );
+    if matches!(field_spec.r#type, FieldType::Char)
+        && field_spec.values.iter().all(|x| x.value.len() == 1)
+    {
+        output.push_str(
+            r#" /// Get FIX value as a single char.
+                #[inline(always)]
+                pub const fn as_fix_char(&self) -> char {
+                    self.as_fix_str().as_bytes()[0] as char
+                }
+            "#,
+        );
+    }
+    output.push_str("}\n\n");
+
+    // Generate `TryFrom<&str>`.
+    output.push_str(&format!(
+        r#" impl TryFrom<&str> for {type_name} {{
+                type Error = crate::FixValueError;
+                fn try_from(s: &str) -> Result<Self, crate::FixValueError> {{
+                    Self::from_const_bytes(s.as_bytes()).map_err(|_| crate::FixValueError {{
+                        field: "{type_name}",
+                        value: s.to_string(),
+                    }})
+                }}
+            }}
+
+            "#
+    ));
+
     // Generate `FromStr`.
     output.push_str(&format!(
         r#" impl std::str::FromStr for {type_name} {{
@@ -264,15 +767,33 @@ fn generate_field_type_values(output: &mut String, field_spec: &FieldSpec) {
     output.push_str(&format!(
         r#" impl quickfix::IntoFixValue for {type_name} {{
                 fn into_fix_value(self) -> Result<std::ffi::CString, std::ffi::NulError> {{
-                    std::ffi::CString::new(match self {{
+                    std::ffi::CString::new(self.as_fix_str())
+                }}
+            }}
+
+            "#
+    ));
+}
+
+fn generate_field_type_serde(output: &mut String, field_spec: &FieldSpec) {
+    assert!(!field_spec.values.is_empty());
+
+    let type_name = field_spec.name.as_str();
+    let variants = field_spec
+        .values
+        .iter()
+        .map(|value| value.description.to_case(Case::UpperCamel))
+        .collect::<Vec<_>>();
+
+    // Serialize symbolic name rather than FIX value.
+    output.push_str(&format!(
+        r#" impl serde::Serialize for {type_name} {{
+                fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{
+                    serializer.serialize_str(match self {{
                     "#
     ));
-    for value in &field_spec.values {
-        output.push_str(&format!(
-            "    Self::{} => \"{}\",\n",
-            value.description.to_case(Case::UpperCamel),
-            value.value,
-        ));
+    for variant in &variants {
+        output.push_str(&format!("    Self::{variant} => \"{variant}\",\n"));
     }
     output.push_str(
         r#"         })
@@ -281,6 +802,28 @@ fn generate_field_type_values(output: &mut String, field_spec: &FieldSpec) {
 
             "#,
     );
+
+    output.push_str(&format!(
+        r#" impl<'de> serde::Deserialize<'de> for {type_name} {{
+                fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{
+                    let value = <String as serde::Deserialize>::deserialize(deserializer)?;
+                    match value.as_str() {{
+                    "#
+    ));
+    for variant in &variants {
+        output.push_str(&format!("    \"{variant}\" => Ok(Self::{variant}),\n"));
+    }
+    output.push_str(&format!(
+        r#"             _ => Err(serde::de::Error::custom(crate::FixValueError {{
+                            field: "{type_name}",
+                            value,
+                        }})),
+                    }}
+                }}
+            }}
+
+            "#
+    ));
 }
 
 fn generate_field_type_alias(output: &mut String, field_spec: &FieldSpec) {
@@ -334,7 +877,245 @@ fn generate_field_type_alias(output: &mut String, field_spec: &FieldSpec) {
     output.push_str(&format!("pub type {type_name} = {rust_type};\n\n"));
 }
 
-fn generate_headers(output: &mut String, components: &[SubComponent]) {
+fn generate_components(output: &mut String, components: &[MessageComponent]) {
+    output.push_str(
+        r#" /// Component blocks of the dictionary, as plain data readable from and writable to any
+            /// message including them.
+            pub mod components {
+            "#,
+    );
+
+    // Merged FIXT dictionaries can define the same component twice (e.g. `HopGrp`).
+    let mut generated = HashSet::new();
+    for component in components {
+        let struct_name = component.name.as_str();
+        if !generated.insert(struct_name) {
+            continue;
+        }
+        let module_name = struct_name.to_case(Case::Snake);
+
+        if let [SubComponent::Group(group)] = component.components.as_slice() {
+            // Component only wrapping a repeating group is the list of its entries.
+            let group_name = group.name.as_str();
+            let read_group = format_read_group(&module_name, group);
+            output.push_str(&format!(
+                r#" /// `{struct_name}` component.
+                    #[derive(Debug, Clone, Default, PartialEq)]
+                    pub struct {struct_name}(pub Vec<self::{module_name}::{group_name}>);
+
+                    /// Entry of `{struct_name}` component.
+                    pub type {struct_name}Group = self::{module_name}::{group_name};
+
+                    impl {struct_name} {{
+                        /// Read component from a message or a group.
+                        pub fn read_from<M: quickfix::FieldMap>(map: &M) -> Self {{
+                            Self({read_group})
+                        }}
+
+                        /// Add component entries to a message or a group, after existing ones.
+                        pub fn write_to<M: quickfix::FieldMap>(
+                            &self,
+                            map: &mut M,
+                        ) -> Result<(), quickfix::QuickFixError> {{
+                            for entry in &self.0 {{
+                                map.add_group(&entry.to_group()?)?;
+                            }}
+                            Ok(())
+                        }}
+                    }}
+
+                    "#
+            ));
+        } else {
+            generate_component_data(
+                output,
+                struct_name,
+                &format!("`{struct_name}` component."),
+                &component.components,
+            );
+        }
+
+        generate_component_groups(output, &module_name, &component.components);
+    }
+
+    output.push_str("} // components\n\n");
+}
+
+fn generate_component_groups(output: &mut String, module_name: &str, components: &[SubComponent]) {
+    if !components
+        .iter()
+        .any(|x| matches!(x, SubComponent::Group(_)))
+    {
+        return;
+    }
+
+    output.push_str(&format!(
+        r#" pub mod {module_name} {{
+                "#
+    ));
+
+    for component in components {
+        let SubComponent::Group(group) = component else {
+            continue;
+        };
+        let struct_name = group.name.as_str();
+        let group_id = format_field_id(&group.name);
+        let group_delim = format_field_id(
+            group
+                .components
+                .first()
+                .expect("Group cannot be empty")
+                .name(),
+        );
+        let group_value_ids = group
+            .components
+            .iter()
+            .map(|x| format_field_id(x.name()))
+            .collect::<Vec<_>>()
+            .join(",");
+
+        generate_component_data(
+            output,
+            struct_name,
+            &format!("Entry of `{struct_name}` repeating group."),
+            &group.components,
+        );
+        output.push_str(&format!(
+            r#" impl {struct_name} {{
+                    /// Build group entry, ready to be added to a message.
+                    pub fn to_group(&self) -> Result<quickfix::Group, quickfix::QuickFixError> {{
+                        let mut group = quickfix::Group::try_with_orders(
+                            {group_id},
+                            {group_delim},
+                            &[{group_value_ids}],
+                        )?;
+                        self.write_to(&mut group)?;
+                        Ok(group)
+                    }}
+                }}
+
+                "#
+        ));
+
+        generate_component_groups(output, &struct_name.to_case(Case::Snake), &group.components);
+    }
+
+    output.push_str("}\n\n");
+}
+
+/// Generate plain struct holding `components`, read from / written to any `FieldMap`.
+fn generate_component_data(
+    output: &mut String,
+    struct_name: &str,
+    doc: &str,
+    components: &[SubComponent],
+) {
+    let module_name = struct_name.to_case(Case::Snake);
+
+    output.push_str(&format!(
+        r#" /// {doc}
+            #[derive(Debug, Clone, Default, PartialEq)]
+            pub struct {struct_name} {{
+            "#
+    ));
+    for component in components {
+        match component {
+            SubComponent::Field(x) => output.push_str(&format!(
+                "pub {}: Option<crate::field_types::{}>,\n",
+                format_ident(&x.name.to_case(Case::Snake)),
+                x.name
+            )),
+            SubComponent::Group(x) => output.push_str(&format!(
+                "pub {}: Vec<self::{module_name}::{}>,\n",
+                x.name.to_case(Case::Snake),
+                x.name
+            )),
+        }
+    }
+    output.push_str("}\n\n");
+
+    let mut read_values = String::new();
+    let mut write_values = String::new();
+    for component in components {
+        match component {
+            SubComponent::Field(x) => {
+                let field_name = format_ident(&x.name.to_case(Case::Snake));
+                let field_id = format_field_id(&x.name);
+                let parse = if x.is_bool && !x.is_enum {
+                    ".map(|x| x == \"Y\")"
+                } else {
+                    ".and_then(|x| x.parse().ok())"
+                };
+                read_values.push_str(&format!(
+                    "{field_name}: map.get_field({field_id}){parse},\n"
+                ));
+                write_values.push_str(&format!(
+                    r#" match &self.{field_name} {{
+                            Some(value) => map.set_field({field_id}, value.clone())?,
+                            None => map.remove_field({field_id})?,
+                        }}
+                    "#
+                ));
+            }
+            SubComponent::Group(x) => {
+                let group_name = x.name.to_case(Case::Snake);
+                read_values.push_str(&format!(
+                    "{group_name}: {},\n",
+                    format_read_group(&module_name, x)
+                ));
+                write_values.push_str(&format!(
+                    r#" for entry in &self.{group_name} {{
+                            map.add_group(&entry.to_group()?)?;
+                        }}
+                    "#
+                ));
+            }
+        }
+    }
+    let allow_unused = if components.is_empty() {
+        "#[allow(unused_variables)]"
+    } else {
+        ""
+    };
+
+    output.push_str(&format!(
+        r#" impl {struct_name} {{
+                {allow_unused}
+                /// Read fields from a message or a group, ignoring values which cannot be parsed.
+                pub fn read_from<M: quickfix::FieldMap>(map: &M) -> Self {{
+                    Self {{
+                        {read_values}
+                    }}
+                }}
+
+                {allow_unused}
+                /// Write fields to a message or a group, removing the ones set to `None`.
+                ///
+                /// Repeating groups entries are added after existing ones.
+                #[allow(clippy::clone_on_copy)]
+                pub fn write_to<M: quickfix::FieldMap>(
+                    &self,
+                    map: &mut M,
+                ) -> Result<(), quickfix::QuickFixError> {{
+                    {write_values}
+                    Ok(())
+                }}
+            }}
+
+            "#
+    ));
+}
+
+/// Read every entry of `group` from `map` variable.
+fn format_read_group(module_name: &str, group: &MessageGroup) -> String {
+    format!(
+        "(1..).map_while(|index| map.clone_group(index, {})).map(|group| self::{module_name}::{}::read_from(&group)).collect()",
+        format_field_id(&group.name),
+        group.name,
+    )
+}
+
+fn generate_headers(output: &mut String, components: &[SubComponent], version: &str, chrono: bool) {
     struct Accessor;
 
     impl FieldAccessorGenerator for Accessor {
@@ -349,10 +1130,15 @@ fn generate_headers(output: &mut String, components: &[SubComponent]) {
         }
     }
 
-    generate_message_wrapper(output, "Header", components, &Accessor);
+    generate_message_wrapper(output, "Header", version, components, chrono, &Accessor);
 }
 
-fn generate_trailers(output: &mut String, components: &[SubComponent]) {
+fn generate_trailers(
+    output: &mut String,
+    components: &[SubComponent],
+    version: &str,
+    chrono: bool,
+) {
     struct Accessor;
 
     impl FieldAccessorGenerator for Accessor {
@@ -367,47 +1153,140 @@ fn generate_trailers(output: &mut String, components: &[SubComponent]) {
         }
     }
 
-    generate_message_wrapper(output, "Trailer", components, &Accessor);
+    generate_message_wrapper(output, "Trailer", version, components, chrono, &Accessor);
 }
 
 fn generate_message_wrapper(
     output: &mut String,
     struct_name: &str,
+    version: &str,
     components: &[SubComponent],
+    chrono: bool,
     accessor: &impl FieldAccessorGenerator,
 ) {
+    let section = struct_name.to_lowercase();
+    let timestamps: Vec<_> = components
+        .iter()
+        .filter_map(|component| match component {
+            SubComponent::Field(x) if chrono && x.is_utc_timestamp => Some(x),
+            _ => None,
+        })
+        .collect();
+
     output.push_str(&format!(
-        r#" #[derive(Debug)]
+        r#" /// Typed access to message {section} fields.
+            #[derive(Debug)]
             pub struct {struct_name}<'a> {{ inner: &'a quickfix::Message }}
 
+            /// Alias of [`{struct_name}`] naming its FIX version.
+            pub type {struct_name}{version}<'a> = {struct_name}<'a>;
+
             "#
     ));
 
-    generate_sub_components(output, &struct_name.to_case(Case::Snake), components);
+    generate_sub_components(output, &struct_name.to_case(Case::Snake), components, None);
 
     output.push_str(&format!("impl {struct_name}<'_> {{\n"));
     generate_components_getters(output, struct_name, components, accessor);
+    for field in &timestamps {
+        generate_field_chrono_getter(output, field, accessor);
+    }
     output.push_str("}\n\n");
 
     output.push_str(&format!(
-        r#" #[derive(Debug)]
+        r#" /// Typed write access to message {section} fields.
+            #[derive(Debug)]
             pub struct {struct_name}Mut<'a> {{ inner: &'a mut quickfix::Message }}
 
+            /// Alias of [`{struct_name}Mut`] naming its FIX version.
+            pub type {struct_name}{version}Mut<'a> = {struct_name}Mut<'a>;
+
             "#
     ));
 
     output.push_str(&format!("impl {struct_name}Mut<'_> {{\n"));
     generate_components_setters(output, struct_name, components, accessor);
+    for field in &timestamps {
+        generate_field_chrono_setter(output, field, accessor);
+    }
     output.push_str("}\n\n");
 }
 
-fn generate_messages(output: &mut String, messages: &[MessageSpec]) {
+/// UTC timestamp format, with optional fractional seconds when parsing.
+const UTC_TIMESTAMP_PARSE_FORMAT: &str = "%Y%m%d-%H:%M:%S%.f";
+const UTC_TIMESTAMP_FORMAT: &str = "%Y%m%d-%H:%M:%S%.3f";
+
+fn generate_field_chrono_getter(
+    output: &mut String,
+    field: &MessageField,
+    accessor: &impl FieldAccessorGenerator,
+) {
+    let call_get_prefix = accessor.getter_prefix_text();
+    let call_suffix = accessor.caller_suffix_text();
+
+    let fun_name = format!("get_{}", field.name.to_case(Case::Snake));
+    let field_id = format_field_id(&field.name);
+
+    output.push_str(&format!(
+        r#" /// Same as [`Self::{fun_name}`], parsed as a UTC date time.
+            #[inline(always)]
+            pub fn {fun_name}_utc(&self) -> Option<chrono::DateTime<chrono::Utc>> {{
+                let value = self.{call_get_prefix}get_field({field_id}){call_suffix}?;
+                chrono::NaiveDateTime::parse_from_str(&value, "{UTC_TIMESTAMP_PARSE_FORMAT}")
+                    .ok()
+                    .map(|x| x.and_utc())
+            }}
+
+            "#
+    ));
+}
+
+fn generate_field_chrono_setter(
+    output: &mut String,
+    field: &MessageField,
+    accessor: &impl FieldAccessorGenerator,
+) {
+    let call_set_prefix = accessor.setter_prefix_text();
+    let call_suffix = accessor.caller_suffix_text();
+
+    let field_name = field.name.to_case(Case::Snake);
+    let field_id = format_field_id(&field.name);
+
+    output.push_str(&format!(
+        r#" /// Same as [`Self::set_{field_name}`], formatting a UTC date time with milliseconds.
+            #[inline(always)]
+            pub fn set_{field_name}_utc(
+                &mut self,
+                value: chrono::DateTime<chrono::Utc>,
+            ) -> Result<&Self, quickfix::QuickFixError> {{
+                let value = value.format("{UTC_TIMESTAMP_FORMAT}").to_string();
+                self.{call_set_prefix}set_field({field_id}, value.as_str()){call_suffix}?;
+                Ok(self)
+            }}
+
+            "#
+    ));
+}
+
+fn generate_messages(
+    output: &mut String,
+    messages: &[MessageSpec],
+    with_appl_ver_id: bool,
+    serde: Option<SerdeFieldCase>,
+    components: bool,
+) {
     for message in messages {
-        generate_message(output, message);
+        generate_message(output, message, with_appl_ver_id, serde, components);
     }
 }
 
-fn generate_message(output: &mut String, message: &MessageSpec) {
+fn generate_message(
+    output: &mut String,
+    message: &MessageSpec,
+    with_appl_ver_id: bool,
+    serde: Option<SerdeFieldCase>,
+    components: bool,
+) {
     let struct_name = message.name.as_str();
     let msg_type = message.msg_type.as_str();
 
@@ -427,7 +1306,7 @@ fn generate_message(output: &mut String, message: &MessageSpec) {
                     }};
 
                 #[inline(always)]
-                pub fn header(&mut self) -> Header<'_> {{
+                pub fn header(&self) -> Header<'_> {{
                     Header {{ inner: &self.inner }}
                 }}
 
@@ -437,7 +1316,7 @@ fn generate_message(output: &mut String, message: &MessageSpec) {
                 }}
 
                 #[inline(always)]
-                pub fn trailer(&mut self) -> Trailer<'_> {{
+                pub fn trailer(&self) -> Trailer<'_> {{
                     Trailer {{ inner: &self.inner }}
                 }}
 
@@ -472,29 +1351,62 @@ fn generate_message(output: &mut String, message: &MessageSpec) {
                 }}
             }}
 
-            impl From<quickfix::Message> for {struct_name} {{
-                fn from(input: quickfix::Message) -> Self {{
-                    assert_eq!(
-                        input
-                            .with_header(|h| h.get_field(field_id::MSG_TYPE))
-                            .and_then(|x| crate::field_types::MsgType::from_const_bytes(x.as_bytes()).ok()),
-                        Some(Self::MSG_TYPE),
-                    );
-                    Self {{ inner: input }}
+            /// Render message with field and value names, see [`quickfix::Message::to_pretty_string`].
+            impl std::fmt::Display for {struct_name} {{
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{
+                    f.write_str(&self.inner.to_pretty_string_with(&crate::FieldNames, '|'))
                 }}
             }}
 
-            "#
+            /// Check MsgType(35) and body fields, keeping every field of input message
+            /// (including custom tags) when converting back.
+            impl TryFrom<quickfix::Message> for {struct_name} {{
+                type Error = crate::ConversionError;
+
+                fn try_from(input: quickfix::Message) -> Result<Self, Self::Error> {{
+                    let msg_type = input.with_header(|h| h.get_field(field_id::MSG_TYPE));
+                    if msg_type.as_deref() != Some(Self::MSG_TYPE_BYTES) {{
+                        return Err(crate::ConversionError::WrongMsgType {{
+                            expected: Self::MSG_TYPE_BYTES,
+                            actual: msg_type,
+                        }});
+                    }}
+
+                    {field_checks}
+                    Ok(Self {{ inner: input }})
+                }}
+            }}
+
+            "#,
+        field_checks = format_field_checks(&message.components),
     ));
 
     // Generate default constructor
     let required_params = format_required_params(&message.components);
     let new_setters = format_new_setters(&message.components);
+    let appl_ver_id_setter = if with_appl_ver_id && message.category == MessageCategory::App {
+        r#"inner.with_header_mut(|h| {
+               h.set_field(crate::field_id::APPL_VER_ID, crate::FIX_APPL_VER_ID)
+           })?;"#
+    } else {
+        ""
+    };
 
     output.push_str(&format!(
         r#" impl {struct_name} {{
                 #[allow(clippy::too_many_arguments)]
                 pub fn try_new({required_params}) -> Result<Self, quickfix::QuickFixError> {{
+                    #[allow(unused_mut)]
+                    let mut inner = Self::new_inner()?;
+
+                    // Set required attributes.
+                    {new_setters}
+
+                    Ok(Self {{ inner }})
+                }}
+
+                /// Create message with its header only.
+                fn new_inner() -> Result<quickfix::Message, quickfix::QuickFixError> {{
                     let mut inner = quickfix::Message::new();
 
                     // Set headers (most of them will be set by quickfix library).
@@ -504,17 +1416,28 @@ fn generate_message(output: &mut String, message: &MessageSpec) {
                     inner.with_header_mut(|h| {{
                         h.set_field(crate::field_id::MSG_TYPE, Self::MSG_TYPE)
                     }})?;
+                    {appl_ver_id_setter}
 
-                    // Set required attributes.
-                    {new_setters}
-
-                    Ok(Self {{ inner }})
+                    Ok(inner)
                 }}
             }}
 
             "#
     ));
 
+    generate_message_builder(output, message);
+
+    if let Some(case) = serde {
+        generate_serde_impls(
+            output,
+            struct_name,
+            "message",
+            &message.components,
+            &format!("{struct_name}::new_inner().map_err(A::Error::custom)?"),
+            case,
+        );
+    }
+
     // Generate getter / setters and sub-components.
     struct Accessor;
 
@@ -530,19 +1453,153 @@ fn generate_message(output: &mut String, message: &MessageSpec) {
         }
     }
 
-    generate_sub_components(
-        output,
-        &message.name.to_case(Case::Snake),
-        &message.components,
-    );
-
-    output.push_str(&format!("impl {struct_name} {{\n\n"));
-    generate_components_getters(output, struct_name, &message.components, &Accessor);
-    generate_components_setters(output, struct_name, &message.components, &Accessor);
+    generate_sub_components(
+        output,
+        &message.name.to_case(Case::Snake),
+        &message.components,
+        serde,
+    );
+
+    output.push_str(&format!("impl {struct_name} {{\n\n"));
+    generate_components_getters(output, struct_name, &message.components, &Accessor);
+    generate_components_setters(output, struct_name, &message.components, &Accessor);
+    if components {
+        generate_component_accessors(output, &message.component_names);
+    }
+    output.push_str("}\n\n");
+}
+
+fn generate_component_accessors(output: &mut String, component_names: &[String]) {
+    for component in component_names {
+        let component_name = component.to_case(Case::Snake);
+        output.push_str(&format!(
+            r#" /// Read every field of `{component}` component.
+                #[inline(always)]
+                pub fn get_{component_name}(&self) -> crate::components::{component} {{
+                    crate::components::{component}::read_from(&self.inner)
+                }}
+
+                /// Write every field of `{component}` component, see [`crate::components::{component}::write_to`].
+                #[inline(always)]
+                pub fn set_{component_name}(
+                    &mut self,
+                    value: &crate::components::{component},
+                ) -> Result<&Self, quickfix::QuickFixError> {{
+                    value.write_to(&mut self.inner)?;
+                    Ok(self)
+                }}
+
+                "#
+        ));
+    }
+}
+
+fn generate_message_builder(output: &mut String, message: &MessageSpec) {
+    let struct_name = message.name.as_str();
+    let required_params = format_required_params(&message.components);
+    let required_args = message
+        .components
+        .iter()
+        .filter(|x| x.is_required())
+        .map(|x| x.name().to_case(Case::Snake))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    output.push_str(&format!(
+        r#" /// Builder for [`{struct_name}`], see [`{struct_name}::builder`].
+            #[derive(Debug)]
+            pub struct {struct_name}Builder {{
+                inner: Result<{struct_name}, quickfix::QuickFixError>,
+            }}
+
+            impl {struct_name} {{
+                /// Start building message from its required fields, optional ones are set by
+                /// chaining builder methods.
+                #[allow(clippy::too_many_arguments)]
+                pub fn builder({required_params}) -> {struct_name}Builder {{
+                    {struct_name}Builder {{ inner: Self::try_new({required_args}) }}
+                }}
+            }}
+
+            impl {struct_name}Builder {{
+                /// Get built message, or first error that occurs while setting its fields.
+                pub fn build(self) -> Result<{struct_name}, quickfix::QuickFixError> {{
+                    self.inner
+                }}
+
+            "#
+    ));
+
+    // Only needed by setters of optional fields and groups.
+    if message
+        .components
+        .iter()
+        .any(|x| !matches!(x, SubComponent::Field(x) if x.required))
+    {
+        output.push_str(&format!(
+            r#" fn and_then<F>(mut self, f: F) -> Self
+                where
+                    F: FnOnce(&mut {struct_name}) -> Result<(), quickfix::QuickFixError>,
+                {{
+                    if let Ok(msg) = &mut self.inner {{
+                        if let Err(err) = f(msg) {{
+                            self.inner = Err(err);
+                        }}
+                    }}
+                    self
+                }}
+
+                "#
+        ));
+    }
+
+    for component in &message.components {
+        match component {
+            SubComponent::Field(x) if !x.required => {
+                let field_name = x.name.to_case(Case::Snake);
+                let method_name = format_ident(&field_name);
+                let field_type = format!("crate::field_types::{}", x.name);
+                output.push_str(&format!(
+                    r#" #[inline(always)]
+                        pub fn {method_name}(self, value: {field_type}) -> Self {{
+                            self.and_then(|msg| msg.set_{field_name}(value).map(|_| ()))
+                        }}
+
+                        "#
+                ));
+            }
+            SubComponent::Field(_) => {} // Already given to `builder()`.
+            SubComponent::Group(x) => {
+                let group_name = x.name.to_case(Case::Snake);
+                let group_type = format!("self::{}::{}", struct_name.to_case(Case::Snake), x.name);
+                output.push_str(&format!(
+                    r#" #[inline(always)]
+                        pub fn add_{group_name}(self, value: {group_type}) -> Self {{
+                            self.and_then(|msg| msg.add_{group_name}(value).map(|_| ()))
+                        }}
+
+                        "#
+                ));
+
+                if let Some(component) = &x.component {
+                    let entry_name = singular(&component.to_case(Case::Snake));
+                    output.push_str(&format!(
+                        r#" #[inline(always)]
+                            pub fn add_{entry_name}(self, value: {group_type}) -> Self {{
+                                self.add_{group_name}(value)
+                            }}
+
+                            "#
+                    ));
+                }
+            }
+        }
+    }
+
     output.push_str("}\n\n");
 }
 
-fn generate_group(output: &mut String, group: &MessageGroup) {
+fn generate_group(output: &mut String, group: &MessageGroup, serde: Option<SerdeFieldCase>) {
     let struct_name = group.name.as_str();
     let group_id = format_field_id(&group.name);
     let group_delim = format_field_id(
@@ -576,21 +1633,37 @@ fn generate_group(output: &mut String, group: &MessageGroup) {
                 #[allow(clippy::too_many_arguments)]
                 pub fn try_new({required_params}) -> Result<Self, quickfix::QuickFixError> {{
                     #[allow(unused_mut)]
-                    let mut inner = quickfix::Group::try_with_orders(
-                        Self::FIELD_ID,
-                        Self::DELIMITER,
-                        &[{group_value_ids}],
-                    ).expect("Fail to build group {struct_name}");
+                    let mut inner = Self::new_inner();
 
                     {new_setters}
 
                     Ok(Self {{ inner }})
                 }}
+
+                /// Create empty group entry.
+                fn new_inner() -> quickfix::Group {{
+                    quickfix::Group::try_with_orders(
+                        Self::FIELD_ID,
+                        Self::DELIMITER,
+                        &[{group_value_ids}],
+                    ).expect("Fail to build group {struct_name}")
+                }}
             }}
 
             "#
     ));
 
+    if let Some(case) = serde {
+        generate_serde_impls(
+            output,
+            struct_name,
+            "group entry",
+            &group.components,
+            &format!("{struct_name}::new_inner()"),
+            case,
+        );
+    }
+
     // Generate getter / setters and sub-components.
     struct Accessor;
 
@@ -606,7 +1679,12 @@ fn generate_group(output: &mut String, group: &MessageGroup) {
         }
     }
 
-    generate_sub_components(output, &group.name.to_case(Case::Snake), &group.components);
+    generate_sub_components(
+        output,
+        &group.name.to_case(Case::Snake),
+        &group.components,
+        serde,
+    );
 
     output.push_str(&format!("impl {struct_name} {{\n\n"));
     generate_components_getters(output, struct_name, &group.components, &Accessor);
@@ -614,7 +1692,168 @@ fn generate_group(output: &mut String, group: &MessageGroup) {
     output.push_str("}\n\n");
 }
 
-fn generate_sub_components(output: &mut String, module_name: &str, components: &[SubComponent]) {
+/// Serialize message or group as a map of its fields, keyed by name.
+fn generate_serde_impls(
+    output: &mut String,
+    struct_name: &str,
+    kind: &str,
+    components: &[SubComponent],
+    init: &str,
+    case: SerdeFieldCase,
+) {
+    let module_name = struct_name.to_case(Case::Snake);
+    let keys = components
+        .iter()
+        .map(|x| case.format(x.name()))
+        .collect::<Vec<_>>();
+
+    // Generate `Serialize`.
+    output.push_str(&format!(
+        r#" impl serde::Serialize for {struct_name} {{
+                fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{
+                    use serde::ser::SerializeMap;
+
+                    #[allow(unused_mut)]
+                    let mut map = serializer.serialize_map(None)?;
+            "#
+    ));
+    for (component, key) in components.iter().zip(&keys) {
+        match component {
+            SubComponent::Field(x) => {
+                let field_id = format_field_id(&x.name);
+                let parse = if x.is_bool && !x.is_enum {
+                    format!("crate::serde_support::parse_bool::<S::Error>(\"{key}\", value)?")
+                } else {
+                    format!(
+                        "crate::serde_support::parse::<crate::field_types::{}, S::Error>(\"{key}\", value)?",
+                        x.name
+                    )
+                };
+                output.push_str(&format!(
+                    r#" if let Some(value) = self.inner.get_field({field_id}) {{
+                            map.serialize_entry("{key}", &{parse})?;
+                        }}
+                    "#
+                ));
+            }
+            SubComponent::Group(x) => {
+                let group_name = x.name.to_case(Case::Snake);
+                output.push_str(&format!(
+                    r#" let entries: Vec<_> = self.iter_{group_name}().collect();
+                        if !entries.is_empty() {{
+                            map.serialize_entry("{key}", &entries)?;
+                        }}
+                    "#
+                ));
+            }
+        }
+    }
+    output.push_str(
+        r#"         map.end()
+                }
+            }
+
+            "#,
+    );
+
+    // Generate `Deserialize`, checking required fields are present.
+    let fields = keys
+        .iter()
+        .map(|key| format!("\"{key}\""))
+        .collect::<Vec<_>>()
+        .join(", ");
+    output.push_str(&format!(
+        r#" impl<'de> serde::Deserialize<'de> for {struct_name} {{
+                fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{
+                    struct Visitor;
+
+                    impl<'de> serde::de::Visitor<'de> for Visitor {{
+                        type Value = {struct_name};
+
+                        fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{
+                            f.write_str("{struct_name} {kind}")
+                        }}
+
+                        #[allow(clippy::match_single_binding)]
+                        fn visit_map<A: serde::de::MapAccess<'de>>(
+                            self,
+                            mut map: A,
+                        ) -> Result<Self::Value, A::Error> {{
+                            use serde::de::Error;
+
+                            const FIELDS: &[&str] = &[{fields}];
+
+                            #[allow(unused_mut)]
+                            let mut inner = {init};
+                            while let Some(key) = map.next_key::<String>()? {{
+                                match key.as_str() {{
+            "#
+    ));
+    for (component, key) in components.iter().zip(&keys) {
+        match component {
+            SubComponent::Field(x) => {
+                let field_id = format_field_id(&x.name);
+                output.push_str(&format!(
+                    r#" "{key}" => {{
+                            let value: crate::field_types::{} =
+                                crate::serde_support::next_value(&mut map, "{key}")?;
+                            inner.set_field({field_id}, value).map_err(A::Error::custom)?;
+                        }}
+                    "#,
+                    x.name
+                ));
+            }
+            SubComponent::Group(x) => {
+                output.push_str(&format!(
+                    r#" "{key}" => {{
+                            let entries: Vec<self::{module_name}::{}> =
+                                crate::serde_support::next_value(&mut map, "{key}")?;
+                            for entry in entries {{
+                                inner.add_group(&entry.inner).map_err(A::Error::custom)?;
+                            }}
+                        }}
+                    "#,
+                    x.name
+                ));
+            }
+        }
+    }
+    output.push_str(
+        r#"                     _ => return Err(A::Error::unknown_field(&key, FIELDS)),
+                                }
+                            }
+            "#,
+    );
+    for (component, key) in components.iter().zip(&keys) {
+        if component.is_required() {
+            let field_id = format_field_id(component.name());
+            output.push_str(&format!(
+                r#" if inner.get_field({field_id}).is_none() {{
+                        return Err(A::Error::missing_field("{key}"));
+                    }}
+                "#
+            ));
+        }
+    }
+    output.push_str(&format!(
+        r#"                 Ok({struct_name} {{ inner }})
+                        }}
+                    }}
+
+                    deserializer.deserialize_map(Visitor)
+                }}
+            }}
+
+            "#
+    ));
+}
+
+fn generate_sub_components(
+    output: &mut String,
+    module_name: &str,
+    components: &[SubComponent],
+    serde: Option<SerdeFieldCase>,
+) {
     // Check if message has some sub components
     if components
         .iter()
@@ -632,7 +1871,15 @@ fn generate_sub_components(output: &mut String, module_name: &str, components: &
             match value {
                 SubComponent::Field(_) => {} // There is no sub-components to generate for a basic field
                 SubComponent::Group(x) => {
-                    generate_group(output, x);
+                    generate_group(output, x, serde);
+
+                    if let Some(component) = &x.component {
+                        output.push_str(&format!(
+                            "/// Entry of `{component}` component.\n\
+                             pub type {component}Group = {};\n\n",
+                            x.name
+                        ));
+                    }
                 }
             }
         }
@@ -649,7 +1896,7 @@ fn generate_components_getters(
     for component in components {
         match component {
             SubComponent::Field(x) => {
-                generate_field_getter(output, &x.name, x.required, accessor);
+                generate_field_getter(output, x, accessor);
             }
             SubComponent::Group(x) => {
                 generate_group_reader(output, struct_name, x);
@@ -678,10 +1925,12 @@ fn generate_components_setters(
 
 fn generate_field_getter(
     output: &mut String,
-    field_name: &str,
-    field_required: bool,
+    field: &MessageField,
     accessor: &impl FieldAccessorGenerator,
 ) {
+    let field_name = field.name.as_str();
+    let field_required = field.required;
+
     // Eval trait and make some string alias.
     let call_get_prefix = accessor.getter_prefix_text();
     let call_suffix = accessor.caller_suffix_text();
@@ -715,6 +1964,27 @@ fn generate_field_getter(
                 "#
         ));
     }
+
+    // Enumerated values unknown to the dictionary are not dropped silently.
+    if field.is_enum {
+        output.push_str(&format!(
+            r#" /// Same as [`Self::{fun_name}`], but fails when value is not defined by the dictionary.
+                #[inline(always)]
+                pub fn try_{fun_name}(&self) -> Result<Option<{field_type}>, crate::FixValueError> {{
+                    self.{call_get_prefix}get_field({field_id}){call_suffix}
+                        .map(|x| {field_type}::try_from(x.as_str()))
+                        .transpose()
+                }}
+
+                /// Get field value as received, even if it is not defined by the dictionary.
+                #[inline(always)]
+                pub fn {fun_name}_raw(&self) -> Option<String> {{
+                    self.{call_get_prefix}get_field({field_id}){call_suffix}
+                }}
+
+                "#
+        ));
+    }
 }
 
 fn generate_field_setters(
@@ -741,6 +2011,20 @@ fn generate_field_setters(
             "#
     ));
 
+    // Allow sending values not defined by the dictionary (e.g. counterparty extensions).
+    if field.is_enum {
+        output.push_str(&format!(
+            r#" /// Same as [`Self::set_{field_name}`], with a value not checked against the dictionary.
+                #[inline(always)]
+                pub fn set_{field_name}_raw(&mut self, value: &str) -> Result<&Self, quickfix::QuickFixError> {{
+                    self.{call_set_prefix}set_field({field_id}, value){call_suffix}?;
+                    Ok(self)
+                }}
+
+                "#
+        ));
+    }
+
     // If field is optional, we can generate a remover.
     if !field.required {
         output.push_str(&format!(
@@ -788,6 +2072,25 @@ fn generate_group_reader(output: &mut String, struct_name: &str, group: &Message
 
             "#
     ));
+
+    if let Some(component) = &group.component {
+        let component_name = component.to_case(Case::Snake);
+        output.push_str(&format!(
+            r#" /// Iterate over `{component}` component entries, alias for [`Self::iter_{group_name}`].
+                #[inline(always)]
+                pub fn {component_name}(&self) -> GroupIterator<'_, Self, {group_type}> {{
+                    self.iter_{group_name}()
+                }}
+
+                /// Number of `{component}` component entries, alias for [`Self::{group_name}_len`].
+                #[inline(always)]
+                pub fn {component_name}_len(&self) -> usize {{
+                    self.{group_name}_len()
+                }}
+
+                "#
+        ));
+    }
 }
 
 fn generate_fn_add_group(output: &mut String, struct_name: &str, group: &MessageGroup) {
@@ -805,6 +2108,30 @@ fn generate_fn_add_group(output: &mut String, struct_name: &str, group: &Message
 
             "#
     ));
+
+    if let Some(component) = &group.component {
+        let entry_name = singular(&component.to_case(Case::Snake));
+        output.push_str(&format!(
+            r#" /// Add a `{component}` component entry, alias for [`Self::add_{group_name}`].
+                #[inline(always)]
+                pub fn add_{entry_name}(&mut self, value: {group_type}) -> Result<&Self, quickfix::QuickFixError> {{
+                    self.add_{group_name}(value)
+                }}
+
+                "#
+        ));
+    }
+}
+
+/// Name one entry of a component, ex: `parties` -> `party`.
+fn singular(name: &str) -> String {
+    if let Some(stem) = name.strip_suffix("ies") {
+        format!("{stem}y")
+    } else if let Some(stem) = name.strip_suffix('s') {
+        stem.to_string()
+    } else {
+        name.to_string()
+    }
 }
 
 fn generate_message_cracker(output: &mut String, messages: &[MessageSpec]) {
@@ -840,7 +2167,7 @@ fn generate_message_cracker(output: &mut String, messages: &[MessageSpec]) {
         let message_type = &message.msg_type;
 
         output.push_str(&format!(
-            "  Some(\"{message_type}\") => Ok(Self::{struct_name}(input.into())),\n"
+            "  Some(\"{message_type}\") => Ok(Self::{struct_name}({struct_name} {{ inner: input }})),\n"
         ));
     }
     output.push_str(
@@ -852,10 +2179,336 @@ fn generate_message_cracker(output: &mut String, messages: &[MessageSpec]) {
     );
 }
 
+fn generate_message_cracker_trait(output: &mut String, messages: &[MessageSpec], version: &str) {
+    let trait_name = format!("MessageCracker{version}");
+
+    // Generate trait with one callback per message.
+    output.push_str(&format!(
+        r#" /// Handler of every message type, called by [`crack`].
+            ///
+            /// Every callback does nothing unless overridden, except [`Self::on_unknown_message`]
+            /// receiving messages of unknown types.
+            #[allow(unused_variables)]
+            pub trait {trait_name} {{
+                /// Called with messages not matching any known message type.
+                fn on_unknown_message(&mut self, msg: quickfix::Message, session: &quickfix::SessionId) {{}}
+
+            "#
+    ));
+    for message in messages {
+        let struct_name = &message.name;
+        let method_name = format!("on_{}", struct_name.to_case(Case::Snake));
+
+        output.push_str(&format!(
+            r#" /// Called with a [`{struct_name}`] message.
+                fn {method_name}(&mut self, msg: {struct_name}, session: &quickfix::SessionId) {{}}
+
+                "#
+        ));
+    }
+    output.push_str("}\n\n");
+
+    // Generate dispatch function.
+    output.push_str(&format!(
+        r#" /// Convert message from its MsgType(35) and give it to matching `handler` callback.
+            ///
+            /// Fails without calling `handler` when message does not match its typed version, see
+            /// [`ConversionError`].
+            pub fn crack<H: {trait_name} + ?Sized>(
+                msg: quickfix::Message,
+                session: &quickfix::SessionId,
+                handler: &mut H,
+            ) -> Result<(), ConversionError> {{
+                match input_msg_type(&msg).as_deref() {{
+            "#
+    ));
+    for message in messages {
+        let struct_name = &message.name;
+        let message_type = &message.msg_type;
+        let method_name = format!("on_{}", struct_name.to_case(Case::Snake));
+
+        output.push_str(&format!(
+            "  Some(\"{message_type}\") => handler.{method_name}({struct_name}::try_from(msg)?, session),\n"
+        ));
+    }
+    output.push_str(
+        r#"         _ => handler.on_unknown_message(msg, session),
+                }
+                Ok(())
+            }
+
+            "#,
+    );
+}
+
+/// Name FIX version in generated items, ex: `44` for `FIX.4.4` or `50SP2` for FIX 5.0 SP2.
+fn format_version_name(begin_string: &str, appl_ver_id: Option<&str>) -> String {
+    let appl_ver_name = match appl_ver_id {
+        Some("2") => Some("40"),
+        Some("3") => Some("41"),
+        Some("4") => Some("42"),
+        Some("5") => Some("43"),
+        Some("6") => Some("44"),
+        Some("7") => Some("50"),
+        Some("8") => Some("50SP1"),
+        Some("9") => Some("50SP2"),
+        _ => None,
+    };
+
+    match appl_ver_name {
+        Some(name) => name.to_string(),
+        None => begin_string
+            .trim_start_matches("FIX")
+            .chars()
+            .filter(|x| x.is_ascii_alphanumeric())
+            .collect(),
+    }
+}
+
+fn generate_typed_application(output: &mut String, messages: &[MessageSpec]) {
+    let app_messages: Vec<_> = messages
+        .iter()
+        .filter(|message| message.category == MessageCategory::App)
+        .collect();
+
+    // Generate trait with one callback per application message.
+    output.push_str(
+        r#" /// Application callbacks receiving typed application messages.
+            ///
+            /// Give implementation to the FIX engine wrapped in a [`TypedApplicationAdapter`].
+            /// Every callback rejects its message with `UnsupportedMessageType` unless overridden.
+            #[allow(unused_variables)]
+            pub trait TypedApplication {
+            "#,
+    );
+    for message in &app_messages {
+        let struct_name = &message.name;
+        let method_name = format!("on_{}", struct_name.to_case(Case::Snake));
+
+        output.push_str(&format!(
+            r#" /// Called after received a [`{struct_name}`] message.
+                fn {method_name}(
+                    &self,
+                    msg: {struct_name},
+                    session: &quickfix::SessionId,
+                ) -> Result<(), quickfix::MsgFromAppError> {{
+                    Err(quickfix::MsgFromAppError::UnsupportedMessageType)
+                }}
+
+                "#
+        ));
+    }
+    output.push_str(
+        r#" }
+
+            "#,
+    );
+
+    // Generate adapter.
+    output.push_str(
+        r#" /// Application callbacks converting received application messages before calling [`TypedApplication`].
+            ///
+            /// Messages are converted from their MsgType(35). Messages of unknown types go to the raw
+            /// [`quickfix::ApplicationCallback::on_msg_from_app`] of the wrapped callbacks, as well as
+            /// every other callback. Errors returned by callbacks are reported to the FIX engine.
+            #[derive(Debug)]
+            pub struct TypedApplicationAdapter<T>(pub T);
+
+            impl<T> TypedApplicationAdapter<T>
+            where
+                T: TypedApplication + quickfix::ApplicationCallback,
+            {
+                /// Convert received application message and dispatch it to its typed callback.
+                pub fn dispatch(
+                    &self,
+                    msg: quickfix::Message,
+                    session: &quickfix::SessionId,
+                ) -> Result<(), quickfix::MsgFromAppError> {
+                    match input_msg_type(&msg).as_deref() {
+            "#,
+    );
+    for message in &app_messages {
+        let struct_name = &message.name;
+        let message_type = &message.msg_type;
+        let method_name = format!("on_{}", struct_name.to_case(Case::Snake));
+
+        output.push_str(&format!(
+            "  Some(\"{message_type}\") => TypedApplication::{method_name}(&self.0, {struct_name}::try_from(msg)?, session),\n"
+        ));
+    }
+    output.push_str(
+        r#"             _ => quickfix::ApplicationCallback::on_msg_from_app(&self.0, msg, session),
+                    }
+                }
+            }
+
+            fn input_msg_type(msg: &quickfix::Message) -> Option<String> {
+                msg.with_header(|h| h.get_field(crate::field_id::MSG_TYPE))
+            }
+
+            impl<T> quickfix::ApplicationCallback for TypedApplicationAdapter<T>
+            where
+                T: TypedApplication + quickfix::ApplicationCallback,
+            {
+                fn on_create(&self, session: &quickfix::SessionId) {
+                    self.0.on_create(session)
+                }
+
+                fn on_logon(&self, session: &quickfix::SessionId) {
+                    quickfix::ApplicationCallback::on_logon(&self.0, session)
+                }
+
+                fn on_logon_with_context(
+                    &self,
+                    session: &quickfix::SessionId,
+                    context: quickfix::LogonContext,
+                ) {
+                    self.0.on_logon_with_context(session, context)
+                }
+
+                fn on_logout(&self, session: &quickfix::SessionId) {
+                    quickfix::ApplicationCallback::on_logout(&self.0, session)
+                }
+
+                fn on_logout_with_context(
+                    &self,
+                    session: &quickfix::SessionId,
+                    context: quickfix::LogoutContext,
+                ) {
+                    self.0.on_logout_with_context(session, context)
+                }
+
+                fn on_msg_to_admin(&self, msg: &mut quickfix::Message, session: &quickfix::SessionId) {
+                    self.0.on_msg_to_admin(msg, session)
+                }
+
+                fn on_msg_to_app(
+                    &self,
+                    msg: &mut quickfix::Message,
+                    session: &quickfix::SessionId,
+                ) -> Result<(), quickfix::MsgToAppError> {
+                    self.0.on_msg_to_app(msg, session)
+                }
+
+                fn on_msg_from_admin(
+                    &self,
+                    msg: quickfix::Message,
+                    session: &quickfix::SessionId,
+                ) -> Result<(), quickfix::MsgFromAdminError> {
+                    self.0.on_msg_from_admin(msg, session)
+                }
+
+                fn on_msg_from_admin_with_kind(
+                    &self,
+                    kind: quickfix::AdminMsgKind,
+                    msg: quickfix::Message,
+                    session: &quickfix::SessionId,
+                ) -> Result<(), quickfix::MsgFromAdminError> {
+                    self.0.on_msg_from_admin_with_kind(kind, msg, session)
+                }
+
+                fn on_msg_from_app(
+                    &self,
+                    msg: quickfix::Message,
+                    session: &quickfix::SessionId,
+                ) -> Result<(), quickfix::MsgFromAppError> {
+                    self.dispatch(msg, session)
+                }
+
+                fn on_resend_begin(&self, session: &quickfix::SessionId, begin: u32, end: u32) {
+                    self.0.on_resend_begin(session, begin, end)
+                }
+
+                fn on_resend_complete(&self, session: &quickfix::SessionId) {
+                    self.0.on_resend_complete(session)
+                }
+
+                fn on_connect_failed(
+                    &self,
+                    session: &quickfix::SessionId,
+                    attempt: u32,
+                    error: quickfix::ConnectError,
+                ) {
+                    self.0.on_connect_failed(session, attempt, error)
+                }
+
+                fn on_connect(&self, session: &quickfix::SessionId, peer: Option<std::net::SocketAddr>) {
+                    self.0.on_connect(session, peer)
+                }
+
+                fn on_disconnect(
+                    &self,
+                    session: &quickfix::SessionId,
+                    reason: quickfix::DisconnectReason,
+                ) {
+                    self.0.on_disconnect(session, reason)
+                }
+
+                fn on_remove(&self, session: &quickfix::SessionId) {
+                    self.0.on_remove(session)
+                }
+
+                fn on_raw_incoming(&self, session: &quickfix::SessionId, raw: &[u8]) {
+                    self.0.on_raw_incoming(session, raw)
+                }
+
+                fn on_raw_outgoing(&self, session: &quickfix::SessionId, raw: &[u8]) {
+                    self.0.on_raw_outgoing(session, raw)
+                }
+            }
+            "#,
+    );
+}
+
 fn format_field_id(input: &str) -> String {
     format!("crate::field_id::{}", input.to_case(Case::Constant))
 }
 
+/// Escape `name` if it is a Rust keyword, ex: `Yield` field.
+fn format_ident(name: &str) -> String {
+    const KEYWORDS: &[&str] = &[
+        "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do",
+        "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "if", "impl", "in", "let",
+        "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return",
+        "static", "struct", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use",
+        "virtual", "where", "while", "yield",
+    ];
+
+    if KEYWORDS.contains(&name) {
+        format!("r#{name}")
+    } else {
+        name.to_string()
+    }
+}
+
+fn format_field_checks(components: &[SubComponent]) -> String {
+    components
+        .iter()
+        .filter_map(|x| match x {
+            SubComponent::Field(field) => Some(field),
+            SubComponent::Group(_) => None,
+        })
+        .filter_map(|field| {
+            let field_id = format_field_id(&field.name);
+            // Unknown values of optional enums are readable with `try_get_*` / `get_*_raw`.
+            let check_value = !field.is_bool && (field.required || !field.is_enum);
+            if !check_value && !field.required {
+                return None;
+            }
+            let field_type = if check_value {
+                format!("crate::field_types::{}", field.name)
+            } else {
+                "String".to_string()
+            };
+            Some(format!(
+                "crate::check_field::<{field_type}>({field_id}, input.get_field({field_id}), {})?;",
+                field.required,
+            ))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 fn format_required_params(components: &[SubComponent]) -> String {
     components
         .iter()
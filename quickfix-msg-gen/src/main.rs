@@ -0,0 +1,189 @@
+//! Generate a crate of FIX messages from a XML dictionary, for projects committing generated code
+//! instead of generating it from a `build.rs`.
+
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use quickfix_msg_gen::{read_spec, Generator, SerdeFieldCase};
+
+const USAGE: &str = "\
+Usage: quickfix-msg-gen --spec <FILE> --out <DIR> --crate-name <NAME> [OPTIONS]
+
+Generate a crate of typed FIX messages in <DIR>: `src/code.rs` is always overwritten,
+`src/lib.rs` and `Cargo.toml` are only created when missing.
+
+Options:
+    --spec <FILE>             FIX XML dictionary
+    --out <DIR>               Output crate directory
+    --crate-name <NAME>       Name of generated crate
+    --begin-string <VALUE>    Messages BeginString, deduced from dictionary version by default
+    --transport-spec <FILE>   FIXT transport dictionary, merged with `--spec` application one
+    --appl-ver-id <VALUE>     Application messages ApplVerID, required with `--transport-spec`
+    --serde                   Implement serde traits, generated crate depends on serde
+    --serde-snake-case        Serialize field names in snake case, instead of camel case
+    --components              Generate component blocks structs and message accessors
+    --chrono                  Add chrono accessors to header timestamps, crate depends on chrono
+    -h, --help                Print this help
+";
+
+#[derive(Debug, Default)]
+struct Args {
+    spec: Option<PathBuf>,
+    out: Option<PathBuf>,
+    crate_name: Option<String>,
+    begin_string: Option<String>,
+    transport_spec: Option<PathBuf>,
+    appl_ver_id: Option<String>,
+    serde: bool,
+    serde_snake_case: bool,
+    components: bool,
+    chrono: bool,
+}
+
+impl Args {
+    fn parse<I: Iterator<Item = String>>(mut args: I) -> Result<Option<Self>, String> {
+        let mut output = Self::default();
+        while let Some(arg) = args.next() {
+            let mut value = || {
+                args.next()
+                    .ok_or_else(|| format!("missing value for {arg}"))
+            };
+            match arg.as_str() {
+                "--spec" => output.spec = Some(value()?.into()),
+                "--out" => output.out = Some(value()?.into()),
+                "--crate-name" => output.crate_name = Some(value()?),
+                "--begin-string" => output.begin_string = Some(value()?),
+                "--transport-spec" => output.transport_spec = Some(value()?.into()),
+                "--appl-ver-id" => output.appl_ver_id = Some(value()?),
+                "--serde" => output.serde = true,
+                "--serde-snake-case" => output.serde_snake_case = true,
+                "--components" => output.components = true,
+                "--chrono" => output.chrono = true,
+                "-h" | "--help" => return Ok(None),
+                _ => return Err(format!("unknown argument: {arg}")),
+            }
+        }
+        Ok(Some(output))
+    }
+}
+
+fn main() -> ExitCode {
+    let args = match Args::parse(env::args().skip(1)) {
+        Ok(Some(args)) => args,
+        Ok(None) => {
+            print!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Err(err) => {
+            eprintln!("error: {err}\n\n{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match run(args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: Args) -> io::Result<()> {
+    let invalid_input = |msg: &str| io::Error::new(io::ErrorKind::InvalidInput, msg.to_string());
+
+    let spec_path = args.spec.ok_or_else(|| invalid_input("missing --spec"))?;
+    let out = args.out.ok_or_else(|| invalid_input("missing --out"))?;
+    let crate_name = args
+        .crate_name
+        .ok_or_else(|| invalid_input("missing --crate-name"))?;
+    if crate_name.is_empty()
+        || !crate_name
+            .chars()
+            .all(|x| x.is_ascii_alphanumeric() || x == '_' || x == '-')
+    {
+        return Err(invalid_input(&format!("invalid crate name: {crate_name}")));
+    }
+
+    // Validate every spec before writing anything.
+    let spec = read_spec(&spec_path)?;
+    if let Some(transport_spec) = &args.transport_spec {
+        read_spec(transport_spec)?;
+    }
+
+    let generator = Generator::new()
+        .serde(args.serde)
+        .serde_field_case(if args.serde_snake_case {
+            SerdeFieldCase::Snake
+        } else {
+            SerdeFieldCase::Camel
+        })
+        .components(args.components)
+        .chrono(args.chrono);
+
+    fs::create_dir_all(out.join("src"))?;
+    let code_path = out.join("src").join("code.rs");
+    match (&args.transport_spec, &args.appl_ver_id) {
+        (Some(transport_spec), Some(appl_ver_id)) => {
+            generator.generate_fixt(transport_spec, &spec_path, &code_path, appl_ver_id)?
+        }
+        (Some(_), None) => return Err(invalid_input("missing --appl-ver-id")),
+        (None, _) => {
+            let begin_string = args.begin_string.unwrap_or_else(|| {
+                let (major, minor, _) = spec.version;
+                let kind = if spec.is_fixt { "FIXT" } else { "FIX" };
+                format!("{kind}.{major}.{minor}")
+            });
+            generator.generate(&spec_path, &code_path, &begin_string)?
+        }
+    }
+
+    let spec_name = spec_path
+        .file_name()
+        .map(|x| x.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    write_if_missing(
+        &out.join("src").join("lib.rs"),
+        &format!(
+            "//! Messages generated by `quickfix-msg-gen` from `{spec_name}` dictionary.\n\
+             \n\
+             include!(\"code.rs\");\n"
+        ),
+    )?;
+
+    let serde_dependency = if args.serde { "serde = \"1.0\"\n" } else { "" };
+    let chrono_dependency = if args.chrono {
+        "chrono = \"0.4\"\n"
+    } else {
+        ""
+    };
+    write_if_missing(
+        &out.join("Cargo.toml"),
+        &format!(
+            "[package]\n\
+             name = \"{crate_name}\"\n\
+             version = \"0.1.0\"\n\
+             edition = \"2021\"\n\
+             publish = false\n\
+             \n\
+             [dependencies]\n\
+             quickfix = \"{}\"\n\
+             {serde_dependency}\
+             {chrono_dependency}",
+            env!("CARGO_PKG_VERSION"),
+        ),
+    )?;
+
+    eprintln!("Generated {}", code_path.display());
+    Ok(())
+}
+
+fn write_if_missing(path: &Path, content: &str) -> io::Result<()> {
+    if path.exists() {
+        return Ok(());
+    }
+    fs::write(path, content)
+}
@@ -1,15 +1,25 @@
-use quickfix_spec_parser::FieldSpec;
+use quickfix_spec_parser::{FieldSpec, MessageCategory};
 
 pub struct FixCodeSpec {
     pub field_specs: Vec<FieldSpec>,
     pub headers: Vec<SubComponent>,
     pub trailers: Vec<SubComponent>,
     pub messages: Vec<MessageSpec>,
+    pub components: Vec<MessageComponent>,
 }
 
 pub struct MessageSpec {
     pub name: String,
     pub msg_type: String,
+    pub category: MessageCategory,
+    pub components: Vec<SubComponent>,
+    /// Name of components directly included by the message.
+    pub component_names: Vec<String>,
+}
+
+/// Component block of the dictionary, with nested components flattened.
+pub struct MessageComponent {
+    pub name: String,
     pub components: Vec<SubComponent>,
 }
 
@@ -21,10 +31,18 @@ pub enum SubComponent {
 pub struct MessageField {
     pub name: String,
     pub required: bool,
+    /// Field has enumerated values, so it is generated as an `enum`.
+    pub is_enum: bool,
+    /// Field is a FIX boolean (`Y` / `N`), which is not parsable by Rust `bool`.
+    pub is_bool: bool,
+    /// Field is a FIX UTC timestamp, which can be read as a `chrono` date time.
+    pub is_utc_timestamp: bool,
 }
 
 pub struct MessageGroup {
     pub name: String,
+    /// Name of the component wrapping this group, if it contains nothing else.
+    pub component: Option<String>,
     pub components: Vec<SubComponent>,
 }
 
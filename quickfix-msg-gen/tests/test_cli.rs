@@ -0,0 +1,199 @@
+use std::{
+    env::{self, temp_dir},
+    fs,
+    path::{Path, PathBuf},
+    process::{Command, Output},
+};
+
+const FIX44_SPEC: &str = "../quickfix-msg44/src/FIX44.xml";
+
+fn run_cli(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_quickfix-msg-gen"))
+        .args(args)
+        .output()
+        .expect("Fail to run quickfix-msg-gen")
+}
+
+fn generate(out: &Path, extra_args: &[&str]) {
+    let out = out.to_str().unwrap();
+    let mut args = vec![
+        "--spec",
+        FIX44_SPEC,
+        "--out",
+        out,
+        "--crate-name",
+        "my_fix44",
+    ];
+    args.extend_from_slice(extra_args);
+
+    let output = run_cli(&args);
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+/// Find `quickfix` library built as a dependency of this package.
+fn find_quickfix_rlib(deps_dir: &Path) -> PathBuf {
+    fs::read_dir(deps_dir)
+        .expect("Cannot read deps directory")
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            name.starts_with("libquickfix-") && name.ends_with(".rlib")
+        })
+        .max_by_key(|entry| entry.metadata().and_then(|x| x.modified()).ok())
+        .map(|entry| entry.path())
+        .expect("Cannot find quickfix rlib")
+}
+
+fn compile_check(crate_dir: &Path) {
+    let deps_dir = env::current_exe().unwrap().parent().unwrap().to_path_buf();
+    let quickfix_rlib = find_quickfix_rlib(&deps_dir);
+
+    let output = Command::new(env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string()))
+        .arg("--edition")
+        .arg("2021")
+        .arg("--crate-type")
+        .arg("lib")
+        .arg("--crate-name")
+        .arg("my_fix44")
+        .arg("--emit")
+        .arg("metadata")
+        .arg("--out-dir")
+        .arg(crate_dir.join("target"))
+        .arg("-L")
+        .arg(format!("dependency={}", deps_dir.display()))
+        .arg("--extern")
+        .arg(format!("quickfix={}", quickfix_rlib.display()))
+        .arg(crate_dir.join("src").join("lib.rs"))
+        .output()
+        .expect("Fail to run rustc");
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+fn test_generate_crate() {
+    let out = temp_dir().join("test_quickfix_msg_gen_cli");
+    let _ = fs::remove_dir_all(&out);
+
+    generate(&out, &["--components"]);
+    let code = fs::read_to_string(out.join("src/code.rs")).unwrap();
+    assert!(code.contains(r#"pub const FIX_BEGIN_STRING: &str = "FIX.4.4";"#));
+    assert!(code.contains("pub mod components"));
+
+    let manifest = fs::read_to_string(out.join("Cargo.toml")).unwrap();
+    assert!(manifest.contains(r#"name = "my_fix44""#), "{manifest}");
+    assert!(fs::read_to_string(out.join("src/lib.rs"))
+        .unwrap()
+        .contains(r#"include!("code.rs");"#));
+
+    // Output is stable, so generated code can be committed.
+    generate(&out, &["--components"]);
+    assert_eq!(fs::read_to_string(out.join("src/code.rs")).unwrap(), code);
+
+    compile_check(&out);
+
+    // Components are opt-in.
+    generate(&out, &[]);
+    let code = fs::read_to_string(out.join("src/code.rs")).unwrap();
+    assert!(!code.contains("pub mod components"));
+}
+
+fn write_spec(name: &str, spec: &str) -> String {
+    let dump_path = temp_dir().join("test_quickfix_msg_gen_cli_specs");
+    fs::create_dir_all(&dump_path).unwrap();
+    let spec_path = dump_path.join(name);
+    fs::write(&spec_path, spec).unwrap();
+    spec_path.to_string_lossy().into_owned()
+}
+
+fn generate_invalid(name: &str, spec: &str) -> String {
+    let spec_path = write_spec(name, spec);
+    let out = temp_dir().join("test_quickfix_msg_gen_cli_invalid");
+    let output = run_cli(&[
+        "--spec",
+        &spec_path,
+        "--out",
+        out.to_str().unwrap(),
+        "--crate-name",
+        "invalid",
+    ]);
+    assert!(!output.status.success());
+    assert!(!out.join("src/code.rs").exists());
+    String::from_utf8_lossy(&output.stderr).into_owned()
+}
+
+const VALID_SPEC: &str = r#"<fix major="4" minor="4" servicepack="0" type="FIX">
+ <header>
+  <field name="MsgType" required="Y"/>
+ </header>
+ <messages>
+  <message name="News" msgtype="B" msgcat="app">
+   <field name="Headline" required="Y"/>
+   <group name="NoLinesOfText" required="N">
+    <field name="Text" required="Y"/>
+   </group>
+  </message>
+ </messages>
+ <trailer>
+ </trailer>
+ <components>
+ </components>
+ <fields>
+  <field number="35" name="MsgType" type="STRING">
+   <value enum="B" description="NEWS"/>
+  </field>
+  <field number="148" name="Headline" type="STRING"/>
+  <field number="33" name="NoLinesOfText" type="NUMINGROUP"/>
+  <field number="58" name="Text" type="STRING"/>
+ </fields>
+</fix>
+"#;
+
+#[test]
+fn test_duplicate_tag() {
+    let err = generate_invalid(
+        "duplicate_tag.xml",
+        &VALID_SPEC.replace(
+            r#"  <field number="58" name="Text" type="STRING"/>"#,
+            r#"  <field number="58" name="Text" type="STRING"/>
+  <field number="148" name="Title" type="STRING"/>"#,
+        ),
+    );
+    assert!(err.contains("duplicate_tag.xml:24:"), "{err}");
+    assert!(err.contains("duplicate field: Title (148)"), "{err}");
+}
+
+#[test]
+fn test_group_without_delimiter() {
+    let err = generate_invalid(
+        "empty_group.xml",
+        &VALID_SPEC.replace(r#"    <field name="Text" required="Y"/>"#, ""),
+    );
+    assert!(err.contains("empty_group.xml:8:"), "{err}");
+    assert!(
+        err.contains("group without delimiter: NoLinesOfText has no field"),
+        "{err}"
+    );
+}
+
+#[test]
+fn test_invalid_args() {
+    let output = run_cli(&["--spec", FIX44_SPEC]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("missing --out"));
+
+    let output = run_cli(&["--unknown"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("unknown argument: --unknown"));
+
+    let output = run_cli(&["--help"]);
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("Usage: quickfix-msg-gen"));
+}
@@ -1,6 +1,6 @@
 use std::{env::temp_dir, fs, io};
 
-use quickfix_msg_gen::generate;
+use quickfix_msg_gen::{generate, generate_fixt, Generator, SerdeFieldCase};
 
 #[test]
 fn test_no_crash() -> io::Result<()> {
@@ -36,6 +36,162 @@ fn test_no_crash() -> io::Result<()> {
         dump_path.join("out44.rs"),
         "FIX.4.4",
     )?;
+    generate_fixt(
+        "../quickfix-ffi/libquickfix/spec/FIXT11.xml",
+        "../quickfix-ffi/libquickfix/spec/FIX50SP2.xml",
+        dump_path.join("out50sp2.rs"),
+        "9",
+    )?;
+
+    Ok(())
+}
+
+fn generate_invalid(name: &str, spec: &str) -> String {
+    let dump_path = temp_dir().join("test_quickfix_msg_gen");
+    fs::create_dir_all(&dump_path).unwrap();
+    let spec_path = dump_path.join(name);
+    fs::write(&spec_path, spec).unwrap();
+
+    let err = generate(&spec_path, dump_path.join("invalid.rs"), "FIX.4.4").unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    err.to_string()
+}
+
+const VALID_SPEC: &str = r#"<fix major="4" minor="4" servicepack="0" type="FIX">
+ <header>
+  <field name="MsgType" required="Y"/>
+ </header>
+ <messages>
+  <message name="News" msgtype="B" msgcat="app">
+   <field name="Headline" required="Y"/>
+  </message>
+ </messages>
+ <trailer>
+ </trailer>
+ <components>
+ </components>
+ <fields>
+  <field number="35" name="MsgType" type="STRING">
+   <value enum="B" description="NEWS"/>
+  </field>
+  <field number="148" name="Headline" type="STRING"/>
+ </fields>
+</fix>
+"#;
+
+#[test]
+fn test_custom_spec() -> io::Result<()> {
+    let dump_path = temp_dir().join("test_quickfix_msg_gen");
+    fs::create_dir_all(&dump_path)?;
+    let spec_path = dump_path.join("custom.xml");
+    fs::write(
+        &spec_path,
+        VALID_SPEC.replace(
+            r#"  <field number="148" name="Headline" type="STRING"/>"#,
+            r#"  <field number="148" name="Headline" type="STRING"/>
+  <field number="20001" name="VenueRiskGroup" type="STRING"/>"#,
+        ),
+    )?;
+
+    generate(&spec_path, dump_path.join("custom.rs"), "FIX.4.4")?;
+    let code = fs::read_to_string(dump_path.join("custom.rs"))?;
+    assert!(code.contains("pub const VENUE_RISK_GROUP: i32 = 20001;"));
+    Ok(())
+}
+
+#[test]
+fn test_serde_field_case() -> io::Result<()> {
+    let dump_path = temp_dir().join("test_quickfix_msg_gen");
+    fs::create_dir_all(&dump_path)?;
+    let spec_path = dump_path.join("serde.xml");
+    fs::write(
+        &spec_path,
+        VALID_SPEC
+            .replace(
+                r#"<field name="Headline" required="Y"/>"#,
+                r#"<field name="Headline" required="Y"/>
+   <field name="URLLink" required="N"/>"#,
+            )
+            .replace(
+                r#"  <field number="148" name="Headline" type="STRING"/>"#,
+                r#"  <field number="148" name="Headline" type="STRING"/>
+  <field number="149" name="URLLink" type="STRING"/>"#,
+            ),
+    )?;
+
+    // Serde code is opt-in.
+    generate(&spec_path, dump_path.join("no_serde.rs"), "FIX.4.4")?;
+    let code = fs::read_to_string(dump_path.join("no_serde.rs"))?;
+    assert!(!code.contains("serde"));
+
+    Generator::new().serde(true).generate(
+        &spec_path,
+        dump_path.join("serde_camel.rs"),
+        "FIX.4.4",
+    )?;
+    let code = fs::read_to_string(dump_path.join("serde_camel.rs"))?;
+    assert!(code.contains("impl serde::Serialize for News"));
+    assert!(code.contains("impl<'de> serde::Deserialize<'de> for News"));
+    assert!(code.contains("impl serde::Serialize for MsgType"));
+    assert!(code.contains(r#""urlLink""#), "{code}");
 
+    Generator::new()
+        .serde(true)
+        .serde_field_case(SerdeFieldCase::Snake)
+        .generate(&spec_path, dump_path.join("serde_snake.rs"), "FIX.4.4")?;
+    let code = fs::read_to_string(dump_path.join("serde_snake.rs"))?;
+    assert!(code.contains(r#""url_link""#), "{code}");
+    assert!(!code.contains(r#""urlLink""#), "{code}");
     Ok(())
 }
+
+#[test]
+fn test_invalid_xml() {
+    let err = generate_invalid(
+        "invalid_xml.xml",
+        &VALID_SPEC.replace(r#"<field name="Headline" required="Y"/>"#, "<field"),
+    );
+    assert!(err.contains("invalid_xml.xml:"), "{err}");
+    assert!(err.contains("invalid FIX spec"), "{err}");
+}
+
+#[test]
+fn test_duplicate_field() {
+    let err = generate_invalid(
+        "duplicate_field.xml",
+        &VALID_SPEC.replace(
+            r#"  <field number="148" name="Headline" type="STRING"/>"#,
+            r#"  <field number="148" name="Headline" type="STRING"/>
+  <field number="148" name="OtherHeadline" type="STRING"/>"#,
+        ),
+    );
+    assert!(err.contains("duplicate_field.xml:19:"), "{err}");
+    assert!(
+        err.contains("duplicate field: OtherHeadline (148)"),
+        "{err}"
+    );
+}
+
+#[test]
+fn test_unknown_field() {
+    let err = generate_invalid(
+        "unknown_field.xml",
+        &VALID_SPEC.replace(r#"name="Headline" required"#, r#"name="Title" required"#),
+    );
+    assert!(err.contains("unknown_field.xml:7:"), "{err}");
+    assert!(err.contains("unknown field: Title"), "{err}");
+}
+
+#[test]
+fn test_unknown_component() {
+    let err = generate_invalid(
+        "unknown_component.xml",
+        &VALID_SPEC.replace(
+            r#"<field name="Headline" required="Y"/>"#,
+            r#"<field name="Headline" required="Y"/>
+   <component name="Instrument" required="N"/>"#,
+        ),
+    );
+    assert!(err.contains("unknown_component.xml:8:"), "{err}");
+    assert!(err.contains("unknown component: Instrument"), "{err}");
+}
@@ -0,0 +1,171 @@
+use std::{
+    sync::Mutex,
+    thread,
+    time::{Duration, Instant},
+};
+
+use quickfix::{dictionary_item::*, testing::apply_session_items, *};
+use quickfix_msg40::{
+    field_id,
+    field_types::{ExecTransType, HandlInst, OrdStatus, OrdType, Side},
+    ExecutionReport, NewOrderSingle, FIX_BEGIN_STRING,
+};
+
+#[derive(Debug, Default)]
+struct Recorder {
+    messages: Mutex<Vec<Message>>,
+}
+
+impl ApplicationCallback for Recorder {
+    fn on_msg_from_app(&self, msg: Message, _session: &SessionId) -> Result<(), MsgFromAppError> {
+        self.messages.lock().unwrap().push(msg);
+        Ok(())
+    }
+}
+
+impl Recorder {
+    fn wait_message(&self, timeout: Duration) -> Message {
+        let start = Instant::now();
+        loop {
+            if let Some(msg) = self.messages.lock().unwrap().pop() {
+                return msg;
+            }
+            assert!(start.elapsed() < timeout, "No message after {timeout:?}");
+            thread::sleep(Duration::from_millis(50));
+        }
+    }
+}
+
+fn build_settings(
+    session_id: SessionId,
+    connection_type: ConnectionType,
+    port: u16,
+) -> Result<SessionSettings, QuickFixError> {
+    SessionSettingsBuilder::new()
+        .default(Dictionary::try_from_items(&[
+            &connection_type,
+            &ReconnectInterval(60),
+            &UseDataDictionary(false),
+        ])?)
+        .session(session_id, |dict| apply_session_items(dict, port))
+        .build()
+}
+
+fn wait_until<F: FnMut() -> bool>(timeout: Duration, mut predicate: F) {
+    let start = Instant::now();
+    while !predicate() {
+        assert!(
+            start.elapsed() < timeout,
+            "Condition not met after {timeout:?}"
+        );
+        thread::sleep(Duration::from_millis(50));
+    }
+}
+
+fn assert_begin_string(msg: &Message) {
+    assert_eq!(
+        msg.with_header(|header| header.get_field(field_id::BEGIN_STRING))
+            .as_deref(),
+        Some(FIX_BEGIN_STRING)
+    );
+}
+
+#[test]
+fn test_session_round_trip() -> Result<(), QuickFixError> {
+    assert_eq!(FIX_BEGIN_STRING, "FIX.4.0");
+
+    let broker_id = SessionId::try_new(FIX_BEGIN_STRING, "BROKER", "CLIENT", "")?;
+    let client_id = SessionId::try_new(FIX_BEGIN_STRING, "CLIENT", "BROKER", "")?;
+
+    let broker = Recorder::default();
+    let client = Recorder::default();
+    let app_broker = Application::try_new(&broker)?;
+    let app_client = Application::try_new(&client)?;
+    let store_broker = MemoryMessageStoreFactory::new();
+    let store_client = MemoryMessageStoreFactory::new();
+
+    let settings_broker = build_settings(broker_id.clone(), ConnectionType::Acceptor, 0)?;
+    let mut acceptor = Acceptor::try_new(
+        &settings_broker,
+        &app_broker,
+        &store_broker,
+        FixSocketServerKind::default(),
+    )?;
+    acceptor.start()?;
+    let port = acceptor.listen_endpoints()?[0].port();
+
+    let settings_client = build_settings(client_id.clone(), ConnectionType::Initiator, port)?;
+    let mut initiator = Initiator::try_new(
+        &settings_client,
+        &app_client,
+        &store_client,
+        FixSocketServerKind::default(),
+    )?;
+    initiator.start()?;
+    wait_until(Duration::from_secs(10), || {
+        initiator.is_logged_on().unwrap_or(false) && acceptor.is_logged_on().unwrap_or(false)
+    });
+
+    // Client sends an order.
+    let mut order = NewOrderSingle::try_new(
+        "ORDER-1".to_string(),
+        HandlInst::AutomatedExecutionNoIntervention,
+        "AAPL".to_string(),
+        Side::Buy,
+        100,
+        OrdType::Limit,
+    )?;
+    order.set_price(189.5)?;
+    send_to_target(order.into(), &client_id)?;
+
+    let msg = broker.wait_message(Duration::from_secs(5));
+    assert_begin_string(&msg);
+    let order = NewOrderSingle::try_from(msg).expect("Invalid NewOrderSingle");
+    assert_eq!(order.get_cl_ord_id(), "ORDER-1");
+    assert_eq!(
+        order.get_handl_inst(),
+        HandlInst::AutomatedExecutionNoIntervention
+    );
+    assert_eq!(order.get_symbol(), "AAPL");
+    assert_eq!(order.get_side(), Side::Buy);
+    assert_eq!(order.get_order_qty(), 100);
+    assert_eq!(order.get_ord_type(), OrdType::Limit);
+    assert_eq!(order.get_price(), Some(189.5));
+
+    // Broker fills it, FIX 4.0 execution ID is an integer.
+    let mut report = ExecutionReport::try_new(
+        "BROKER-1".to_string(),
+        1,
+        ExecTransType::New,
+        OrdStatus::Filled,
+        order.get_symbol(),
+        order.get_side(),
+        order.get_order_qty(),
+        100,
+        189.5,
+        100,
+        189.5,
+    )?;
+    report.set_cl_ord_id(order.get_cl_ord_id())?;
+    send_to_target(report.into(), &broker_id)?;
+
+    let msg = client.wait_message(Duration::from_secs(5));
+    assert_begin_string(&msg);
+    let report = ExecutionReport::try_from(msg).expect("Invalid ExecutionReport");
+    assert_eq!(report.get_order_id(), "BROKER-1");
+    assert_eq!(report.get_cl_ord_id().as_deref(), Some("ORDER-1"));
+    assert_eq!(report.get_exec_id(), 1);
+    assert_eq!(report.get_exec_trans_type(), ExecTransType::New);
+    assert_eq!(report.get_ord_status(), OrdStatus::Filled);
+    assert_eq!(report.get_symbol(), "AAPL");
+    assert_eq!(report.get_side(), Side::Buy);
+    assert_eq!(report.get_order_qty(), 100);
+    assert_eq!(report.get_last_shares(), 100);
+    assert_eq!(report.get_last_px(), 189.5);
+    assert_eq!(report.get_cum_qty(), 100);
+    assert_eq!(report.get_avg_px(), 189.5);
+
+    initiator.stop()?;
+    acceptor.stop()?;
+    Ok(())
+}
@@ -8,10 +8,13 @@ const BEGIN_STRING: &str = "FIX.4.3";
 fn main() -> io::Result<()> {
     let out_dir = env::var("OUT_DIR").expect("Missing OUT_DIR");
 
-    generate(SPEC_FILENAME, format!("{out_dir}/code.rs"), BEGIN_STRING)?;
+    let generator = Generator::new()
+        .serde(env::var_os("CARGO_FEATURE_SERDE").is_some())
+        .chrono(env::var_os("CARGO_FEATURE_CHRONO").is_some());
+    generator.generate(SPEC_FILENAME, format!("{out_dir}/code.rs"), BEGIN_STRING)?;
 
     // Uncomment bellow line to show generated code
-    // generate(SPEC_FILENAME, "src/out.rs", BEGIN_STRING)?;
+    // generator.generate(SPEC_FILENAME, "src/out.rs", BEGIN_STRING)?;
 
     Ok(())
 }
@@ -1,4 +1,37 @@
-/*! Auto-generated messages for `FIX44.xml` dictionary. */
+/*! Auto-generated messages for `FIX44.xml` dictionary.
+ *
+ * Every message has a builder taking its required fields, optional ones are chained:
+ *
+ * ```
+ * use quickfix_msg44::{field_types::{OrdType, Side}, NewOrderSingle};
+ *
+ * # fn main() -> Result<(), quickfix::QuickFixError> {
+ * let order = NewOrderSingle::builder(
+ *     "ORDER-1".to_string(),
+ *     Side::Buy,
+ *     "20240101-10:00:00".to_string(),
+ *     OrdType::Limit,
+ * )
+ * .symbol("AAPL".to_string())
+ * .order_qty(100.0)
+ * .price(189.5)
+ * .build()?;
+ *
+ * let msg = quickfix::Message::from(order);
+ * # Ok(())
+ * # }
+ * ```
+ *
+ * Forgetting a required field does not compile:
+ *
+ * ```compile_fail
+ * use quickfix_msg44::{field_types::Side, NewOrderSingle};
+ *
+ * // Missing `OrdType`.
+ * let order = NewOrderSingle::builder("ORDER-1".to_string(), Side::Buy, "20240101-10:00:00".to_string())
+ *     .build();
+ * ```
+ */
 
 // include!("out.rs");
 include!(concat!(env!("OUT_DIR"), "/code.rs"));
@@ -0,0 +1,103 @@
+use quickfix::{DataDictionary, FieldMap, Message, QuickFixError, ValidationError};
+use quickfix_msg44::{
+    field_id,
+    field_types::{OrdType, PartyRole, Side},
+    new_order_single::PartiesGroup,
+    NewOrderSingle,
+};
+
+fn build_order() -> NewOrderSingle {
+    NewOrderSingle::builder(
+        "ORDER-1".to_string(),
+        Side::Buy,
+        "20240101-10:00:00".to_string(),
+        OrdType::Limit,
+    )
+    .symbol("AAPL".to_string())
+    .order_qty(100.0)
+    .price(189.5)
+    .build()
+    .unwrap()
+}
+
+fn with_session_header(mut msg: Message) -> Result<Message, QuickFixError> {
+    msg.with_header_mut(|h| {
+        h.set_field(field_id::SENDER_COMP_ID, "ME")?;
+        h.set_field(field_id::TARGET_COMP_ID, "THEM")?;
+        h.set_field(field_id::MSG_SEQ_NUM, 1)?;
+        h.set_field(field_id::SENDING_TIME, "20240101-10:00:00")
+    })?;
+    Ok(msg)
+}
+
+#[test]
+fn test_builder() -> Result<(), QuickFixError> {
+    let order = build_order();
+    assert_eq!(order.get_cl_ord_id(), "ORDER-1");
+    assert_eq!(order.get_side(), Side::Buy);
+    assert_eq!(order.get_ord_type(), OrdType::Limit);
+    assert_eq!(order.get_symbol().as_deref(), Some("AAPL"));
+    assert_eq!(order.get_order_qty(), Some(100.0));
+    assert_eq!(order.get_price(), Some(189.5));
+
+    let msg = Message::from(order);
+    assert_eq!(
+        msg.get_field(field_id::CL_ORD_ID).as_deref(),
+        Some("ORDER-1")
+    );
+    assert_eq!(
+        msg.with_header(|h| h.get_field(field_id::MSG_TYPE))
+            .as_deref(),
+        Some("D")
+    );
+    Ok(())
+}
+
+#[test]
+fn test_builder_validates_against_dictionary() -> Result<(), QuickFixError> {
+    let dictionary = DataDictionary::try_from_path("../quickfix-ffi/libquickfix/spec/FIX44.xml")?;
+
+    let msg = with_session_header(Message::from(build_order()))?;
+    assert_eq!(dictionary.validate(&msg), Ok(()));
+
+    // Groups can be chained too.
+    let mut party = PartiesGroup::try_new()?;
+    party.set_party_id("BROKER".to_string())?;
+    party.set_party_role(PartyRole::ExecutingFirm)?;
+    let order = NewOrderSingle::builder(
+        "ORDER-2".to_string(),
+        Side::Sell,
+        "20240101-10:00:00".to_string(),
+        OrdType::Market,
+    )
+    .symbol("AAPL".to_string())
+    .order_qty(10.0)
+    .add_party(party)
+    .build()?;
+    let msg = with_session_header(Message::from(order))?;
+    assert_eq!(dictionary.validate(&msg), Ok(()));
+
+    // Raw message without required fields is rejected.
+    let mut msg = with_session_header(Message::from(build_order()))?;
+    msg.remove_field(field_id::CL_ORD_ID)?;
+    assert_eq!(
+        dictionary.validate(&msg),
+        Err(ValidationError::RequiredTagMissing { tag: 11 })
+    );
+    Ok(())
+}
+
+#[test]
+fn test_builder_error() {
+    let err = NewOrderSingle::builder(
+        "ORDER-1".to_string(),
+        Side::Buy,
+        "20240101-10:00:00".to_string(),
+        OrdType::Limit,
+    )
+    .symbol("AA\0PL".to_string())
+    .order_qty(100.0)
+    .build()
+    .unwrap_err();
+    assert!(matches!(err, QuickFixError::InvalidArgument(_)), "{err:?}");
+}
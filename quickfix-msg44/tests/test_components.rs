@@ -0,0 +1,160 @@
+use quickfix::{FieldMap, Message, QuickFixError};
+use quickfix_msg44::{
+    components::{self, instrument, parties, Instrument, OrderQtyData, Parties, PartiesGroup},
+    field_id,
+    field_types::{ExecType, OrdStatus, OrdType, PartyIDSource, PartyRole, SecurityIDSource, Side},
+    ExecutionReport, MarketDataSnapshotFullRefresh, NewOrderSingle,
+};
+
+fn new_order() -> Result<NewOrderSingle, QuickFixError> {
+    NewOrderSingle::try_new(
+        "ORDER-1".to_string(),
+        Side::Buy,
+        "20240101-10:00:00".to_string(),
+        OrdType::Market,
+    )
+}
+
+fn new_execution_report() -> Result<ExecutionReport, QuickFixError> {
+    ExecutionReport::try_new(
+        "EXCH-1".to_string(),
+        "EXEC-1".to_string(),
+        ExecType::New,
+        OrdStatus::New,
+        Side::Buy,
+        100.0,
+        0.0,
+        0.0,
+    )
+}
+
+fn build_instrument() -> Instrument {
+    Instrument {
+        symbol: Some("AAPL".to_string()),
+        security_id: Some("US0378331005".to_string()),
+        security_id_source: Some(SecurityIDSource::IsinNumber),
+        no_security_alt_id: vec![instrument::NoSecurityAltID {
+            security_alt_id: Some("037833100".to_string()),
+            ..Default::default()
+        }],
+        ..Default::default()
+    }
+}
+
+fn build_parties() -> Parties {
+    Parties(vec![
+        PartiesGroup {
+            party_id: Some("BROKER".to_string()),
+            party_id_source: Some(PartyIDSource::Proprietary),
+            party_role: Some(PartyRole::ExecutingFirm),
+            no_party_sub_i_ds: vec![parties::no_party_i_ds::NoPartySubIDs {
+                party_sub_id: Some("DESK-1".to_string()),
+                ..Default::default()
+            }],
+        },
+        PartiesGroup {
+            party_id: Some("CLIENT".to_string()),
+            party_role: Some(PartyRole::ClientId),
+            ..Default::default()
+        },
+    ])
+}
+
+#[test]
+fn test_instrument_across_messages() -> Result<(), QuickFixError> {
+    let instrument = build_instrument();
+
+    let mut order = new_order()?;
+    order.set_instrument(&instrument)?;
+    assert_eq!(order.get_symbol().as_deref(), Some("AAPL"));
+    assert_eq!(order.get_instrument(), instrument);
+
+    // Same component read from other message types.
+    let mut report = new_execution_report()?;
+    report.set_instrument(&order.get_instrument())?;
+    assert_eq!(report.get_instrument(), instrument);
+
+    let mut snapshot = MarketDataSnapshotFullRefresh::try_new()?;
+    snapshot.set_instrument(&report.get_instrument())?;
+    assert_eq!(snapshot.get_instrument(), instrument);
+    Ok(())
+}
+
+#[test]
+fn test_instrument_from_copied_tags() -> Result<(), QuickFixError> {
+    let mut order = new_order()?;
+    order.set_instrument(&build_instrument())?;
+    let order = Message::from(order);
+
+    // Copy tags by hand, as a message from a counterparty would contain them.
+    let mut report = Message::from(new_execution_report()?);
+    for tag in [
+        field_id::SYMBOL,
+        field_id::SECURITY_ID,
+        field_id::SECURITY_ID_SOURCE,
+    ] {
+        report.set_field(tag, order.get_field(tag).unwrap())?;
+    }
+    let alt_id = order.clone_group(1, field_id::NO_SECURITY_ALT_ID).unwrap();
+    report.add_group(&alt_id)?;
+
+    let report = ExecutionReport::try_from(report).unwrap();
+    assert_eq!(report.get_instrument(), build_instrument());
+    assert_eq!(Instrument::read_from(&order), build_instrument());
+    Ok(())
+}
+
+#[test]
+fn test_set_removes_missing_fields() -> Result<(), QuickFixError> {
+    let mut order = new_order()?;
+    order.set_instrument(&build_instrument())?;
+    order.set_instrument(&Instrument {
+        symbol: Some("MSFT".to_string()),
+        ..Default::default()
+    })?;
+
+    assert_eq!(order.get_symbol().as_deref(), Some("MSFT"));
+    assert_eq!(order.get_security_id(), None);
+    assert_eq!(order.get_security_id_source(), None);
+    Ok(())
+}
+
+#[test]
+fn test_parties() -> Result<(), QuickFixError> {
+    let mut order = new_order()?;
+    order.set_parties(&build_parties())?;
+    assert_eq!(order.get_parties(), build_parties());
+
+    // Typed group accessors see the same entries.
+    assert_eq!(order.parties_len(), 2);
+    let entries: Vec<_> = order.parties().collect();
+    assert_eq!(entries[0].get_party_id().as_deref(), Some("BROKER"));
+    assert_eq!(entries[0].no_party_sub_i_ds_len(), 1);
+    assert_eq!(entries[1].get_party_role(), Some(PartyRole::ClientId));
+
+    let mut report = new_execution_report()?;
+    report.set_parties(&order.get_parties())?;
+    assert_eq!(report.get_parties(), build_parties());
+    assert_eq!(
+        components::Parties::read_from(&Message::from(report)),
+        build_parties()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_order_qty_data() -> Result<(), QuickFixError> {
+    let qty = OrderQtyData {
+        order_qty: Some(100.0),
+        ..Default::default()
+    };
+
+    let mut order = new_order()?;
+    order.set_order_qty_data(&qty)?;
+    assert_eq!(order.get_order_qty(), Some(100.0));
+
+    let mut report = new_execution_report()?;
+    report.set_order_qty_data(&order.get_order_qty_data())?;
+    assert_eq!(report.get_order_qty_data(), qty);
+    Ok(())
+}
@@ -0,0 +1,97 @@
+use quickfix::{FieldMap, Message, MsgFromAppError, QuickFixError};
+use quickfix_msg44::{field_types::ExecType, ConversionError, ExecutionReport, News};
+
+const ER_BODY: &str =
+    "37=O1\u{1}17=E1\u{1}150=F\u{1}39=2\u{1}54=1\u{1}151=0\u{1}14=100\u{1}6=189.5\u{1}";
+
+fn build_message(msg_type: &str, body: &str) -> Message {
+    let text = format!("8=FIX.4.4\u{1}9=0\u{1}35={msg_type}\u{1}{body}10=000\u{1}");
+    Message::try_from_text(&text).unwrap()
+}
+
+#[test]
+fn test_valid_execution_report() -> Result<(), QuickFixError> {
+    // Custom tag is kept by the typed wrapper.
+    let msg = build_message("8", &format!("{ER_BODY}20001=DESK-42\u{1}"));
+    let report = ExecutionReport::try_from(msg).unwrap();
+    assert_eq!(report.get_order_id(), "O1");
+    assert_eq!(report.get_exec_type(), ExecType::Trade);
+    assert_eq!(report.get_avg_px(), 189.5);
+
+    let msg = Message::from(report);
+    assert_eq!(msg.get_field(20001).as_deref(), Some("DESK-42"));
+    Ok(())
+}
+
+#[test]
+fn test_missing_required_field() {
+    let msg = build_message("8", &ER_BODY.replace("37=O1\u{1}", ""));
+    let err = ExecutionReport::try_from(msg).unwrap_err();
+    assert_eq!(err, ConversionError::MissingField { tag: 37 });
+    assert_eq!(err.to_string(), "missing required field (tag 37)");
+    assert!(matches!(
+        MsgFromAppError::from(err),
+        MsgFromAppError::FieldNotFound { tag: 37 }
+    ));
+}
+
+#[test]
+fn test_invalid_field() {
+    let msg = build_message("8", &ER_BODY.replace("151=0", "151=abc"));
+    assert_eq!(
+        ExecutionReport::try_from(msg).unwrap_err(),
+        ConversionError::InvalidField {
+            tag: 151,
+            value: "abc".to_string()
+        }
+    );
+
+    // Unknown value of a required enum.
+    let msg = build_message("8", &ER_BODY.replace("150=F", "150=Z"));
+    assert_eq!(
+        ExecutionReport::try_from(msg).unwrap_err(),
+        ConversionError::InvalidField {
+            tag: 150,
+            value: "Z".to_string()
+        }
+    );
+
+    // Unknown value of an optional enum is accepted, and readable raw.
+    let msg = build_message("8", &format!("{ER_BODY}40=Z\u{1}"));
+    let report = ExecutionReport::try_from(msg).unwrap();
+    assert_eq!(report.get_ord_type(), None);
+    assert_eq!(report.get_ord_type_raw().as_deref(), Some("Z"));
+}
+
+#[test]
+fn test_wrong_msg_type() {
+    let news = Message::from(News::try_new("Hello".to_string()).unwrap());
+    let err = ExecutionReport::try_from(news).unwrap_err();
+    assert_eq!(
+        err,
+        ConversionError::WrongMsgType {
+            expected: "8",
+            actual: Some("B".to_string())
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        r#"wrong message type: expected "8", got Some("B")"#
+    );
+
+    // News body labeled as an execution report.
+    let msg = build_message("8", "148=Hello\u{1}");
+    assert!(matches!(
+        ExecutionReport::try_from(msg),
+        Err(ConversionError::MissingField { .. })
+    ));
+
+    // Missing message type.
+    assert_eq!(
+        ExecutionReport::try_from(Message::new()).unwrap_err(),
+        ConversionError::WrongMsgType {
+            expected: "8",
+            actual: None
+        }
+    );
+}
@@ -0,0 +1,99 @@
+use quickfix::{IntoFixValue, Message, QuickFixError};
+use quickfix_msg44::{
+    field_types::{ExecType, OrdType, Side},
+    ExecutionReport, FixValueError, NewOrderSingle,
+};
+
+fn new_execution_report() -> Result<ExecutionReport, QuickFixError> {
+    let msg = Message::try_from_text(
+        "8=FIX.4.4\u{1}9=0\u{1}35=8\u{1}\
+         37=O1\u{1}17=E1\u{1}150=0\u{1}39=0\u{1}54=1\u{1}151=100\u{1}14=0\u{1}6=0\u{1}\
+         10=000\u{1}",
+    )?;
+    Ok(ExecutionReport::try_from(msg).unwrap())
+}
+
+macro_rules! check_round_trip {
+    ($enum:ty) => {
+        assert!(!<$enum>::ALL.is_empty());
+        for value in <$enum>::ALL {
+            let text = value.as_fix_str();
+            assert_eq!(<$enum>::try_from(text), Ok(*value));
+            assert_eq!(text.parse::<$enum>(), Ok(*value));
+            assert_eq!(
+                value.into_fix_value().unwrap().to_str().unwrap(),
+                text,
+                "{value:?}"
+            );
+        }
+    };
+}
+
+#[test]
+fn test_round_trip() {
+    check_round_trip!(Side);
+    check_round_trip!(OrdType);
+    check_round_trip!(ExecType);
+
+    assert_eq!(Side::Buy.as_fix_str(), "1");
+    assert_eq!(Side::Sell.as_fix_char(), '2');
+    assert_eq!(OrdType::Limit.as_fix_char(), '2');
+    assert_eq!(ExecType::Trade.as_fix_char(), 'F');
+}
+
+#[test]
+fn test_unknown_value() {
+    let err = Side::try_from("Z").unwrap_err();
+    assert_eq!(
+        err,
+        FixValueError {
+            field: "Side",
+            value: "Z".to_string()
+        }
+    );
+    assert_eq!(err.to_string(), r#"invalid Side value: "Z""#);
+    assert!(OrdType::try_from("").is_err());
+    assert!(ExecType::try_from("00").is_err());
+}
+
+#[test]
+fn test_message_accessors() -> Result<(), QuickFixError> {
+    let mut order = NewOrderSingle::try_new(
+        "ORDER-1".to_string(),
+        Side::Buy,
+        "20240101-10:00:00".to_string(),
+        OrdType::Market,
+    )?;
+    for side in Side::ALL {
+        order.set_side(*side)?;
+        assert_eq!(order.get_side(), *side);
+        assert_eq!(order.try_get_side(), Ok(Some(*side)));
+        assert_eq!(order.get_side_raw().as_deref(), Some(side.as_fix_str()));
+    }
+    for ord_type in OrdType::ALL {
+        order.set_ord_type(*ord_type)?;
+        assert_eq!(order.get_ord_type(), *ord_type);
+    }
+
+    let mut report = new_execution_report()?;
+    assert_eq!(report.try_get_ord_type(), Ok(None));
+    assert_eq!(report.get_ord_type_raw(), None);
+    for exec_type in ExecType::ALL {
+        report.set_exec_type(*exec_type)?;
+        assert_eq!(report.get_exec_type(), *exec_type);
+        assert_eq!(report.try_get_exec_type(), Ok(Some(*exec_type)));
+    }
+
+    // Values from counterparty extensions can be sent and read back.
+    report.set_ord_type_raw("Z")?;
+    assert_eq!(report.get_ord_type(), None);
+    assert_eq!(report.get_ord_type_raw().as_deref(), Some("Z"));
+    assert_eq!(
+        report.try_get_ord_type(),
+        Err(FixValueError {
+            field: "OrdType",
+            value: "Z".to_string()
+        })
+    );
+    Ok(())
+}
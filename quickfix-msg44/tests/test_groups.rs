@@ -0,0 +1,103 @@
+use quickfix::{DataDictionary, QuickFixError};
+use quickfix_msg44::{
+    field_types::{OrdType, PartyRole, Side},
+    new_order_single, NewOrderSingle,
+};
+
+use new_order_single::{no_party_i_ds::NoPartySubIDs, PartiesGroup};
+
+fn new_order() -> Result<NewOrderSingle, QuickFixError> {
+    NewOrderSingle::try_new(
+        "ORDER-1".to_string(),
+        Side::Buy,
+        "20240101-10:00:00".to_string(),
+        OrdType::Market,
+    )
+}
+
+fn build_party(
+    party_id: &str,
+    role: PartyRole,
+    sub_id: Option<&str>,
+) -> Result<PartiesGroup, QuickFixError> {
+    let mut party = PartiesGroup::try_new()?;
+    party.set_party_id(party_id.to_string())?;
+    party.set_party_role(role)?;
+    if let Some(sub_id) = sub_id {
+        let mut sub = NoPartySubIDs::try_new()?;
+        sub.set_party_sub_id(sub_id.to_string())?;
+        party.add_no_party_sub_i_ds(sub)?;
+    }
+    Ok(party)
+}
+
+fn check_parties(order: &NewOrderSingle) {
+    assert_eq!(order.parties_len(), 2);
+    assert_eq!(order.parties_len(), order.no_party_i_ds_len());
+
+    let parties: Vec<_> = order.parties().collect();
+    assert_eq!(parties.len(), 2);
+
+    assert_eq!(parties[0].get_party_id().as_deref(), Some("BROKER"));
+    assert_eq!(parties[0].get_party_role(), Some(PartyRole::ExecutingFirm));
+    assert_eq!(parties[0].no_party_sub_i_ds_len(), 1);
+    let sub_ids: Vec<_> = parties[0]
+        .iter_no_party_sub_i_ds()
+        .map(|sub| sub.get_party_sub_id())
+        .collect();
+    assert_eq!(sub_ids, vec![Some("DESK-1".to_string())]);
+
+    assert_eq!(parties[1].get_party_id().as_deref(), Some("CLIENT"));
+    assert_eq!(parties[1].get_party_role(), Some(PartyRole::ClientId));
+    assert_eq!(parties[1].no_party_sub_i_ds_len(), 0);
+    assert_eq!(parties[1].iter_no_party_sub_i_ds().count(), 0);
+}
+
+#[test]
+fn test_parties_round_trip() -> Result<(), QuickFixError> {
+    let mut order = new_order()?;
+    assert_eq!(order.parties_len(), 0);
+    assert_eq!(order.parties().count(), 0);
+
+    order.add_party(build_party(
+        "BROKER",
+        PartyRole::ExecutingFirm,
+        Some("DESK-1"),
+    )?)?;
+    order.add_party(build_party("CLIENT", PartyRole::ClientId, None)?)?;
+    check_parties(&order);
+
+    // Groups are serialized in dictionary order, nested sub ID included.
+    let text = order.to_fix_string();
+    assert!(
+        text.contains("\u{1}453=2\u{1}448=BROKER\u{1}452=1\u{1}802=1\u{1}523=DESK-1\u{1}448=CLIENT\u{1}452=3\u{1}"),
+        "{text}"
+    );
+
+    // Parsing it back with the dictionary gives the same groups.
+    let dictionary = DataDictionary::try_from_path("../quickfix-ffi/libquickfix/spec/FIX44.xml")?;
+    let parsed = NewOrderSingle::try_from(dictionary.try_build_message(&text)?).unwrap();
+    check_parties(&parsed);
+    Ok(())
+}
+
+#[test]
+fn test_display() -> Result<(), QuickFixError> {
+    let mut order = new_order()?;
+    order.add_party(build_party(
+        "BROKER",
+        PartyRole::ExecutingFirm,
+        Some("DESK-1"),
+    )?)?;
+    order.add_party(build_party("CLIENT", PartyRole::ClientId, None)?)?;
+
+    assert_eq!(
+        order.to_string(),
+        "BeginString=FIX.4.4|BodyLength=104|MsgType=NewOrderSingle|\
+         ClOrdID=ORDER-1|OrdType=Market|Side=Buy|TransactTime=20240101-10:00:00|NoPartyIDs=2|\
+         [PartyID=BROKER|PartyRole=ExecutingFirm|NoPartySubIDs=1|[PartySubID=DESK-1]]|\
+         [PartyID=CLIENT|PartyRole=ClientId]|\
+         CheckSum=244"
+    );
+    Ok(())
+}
@@ -0,0 +1,97 @@
+use quickfix::{FieldMap, Message, QuickFixError};
+use quickfix_msg44::{
+    field_id,
+    field_types::{OrdType, Side},
+    Header44, NewOrderSingle, Trailer44,
+};
+
+fn new_order() -> Result<NewOrderSingle, QuickFixError> {
+    NewOrderSingle::try_new(
+        "ORDER-1".to_string(),
+        Side::Buy,
+        "20240101-10:00:00".to_string(),
+        OrdType::Market,
+    )
+}
+
+#[test]
+fn test_on_behalf_of_comp_id() -> Result<(), QuickFixError> {
+    let mut order = new_order()?;
+    order.header_mut().set_sender_comp_id("ME".to_string())?;
+    order.header_mut().set_target_comp_id("THEM".to_string())?;
+    order
+        .header_mut()
+        .set_on_behalf_of_comp_id("ZeCorp".to_string())?;
+
+    let header: Header44<'_> = order.header();
+    assert_eq!(header.get_sender_comp_id(), "ME");
+    assert_eq!(header.get_target_comp_id(), "THEM");
+    assert_eq!(header.get_on_behalf_of_comp_id().as_deref(), Some("ZeCorp"));
+
+    // Field is serialized in header section, before the body.
+    let text = order.to_fix_string();
+    let header_pos = text.find("\u{1}115=ZeCorp\u{1}").expect(&text);
+    let body_pos = text.find("\u{1}11=ORDER-1\u{1}").expect(&text);
+    assert!(header_pos < body_pos, "{text}");
+
+    let msg = Message::from(order);
+    assert_eq!(
+        msg.with_header(|h| h.get_field(field_id::ON_BEHALF_OF_COMP_ID))
+            .as_deref(),
+        Some("ZeCorp")
+    );
+    assert_eq!(msg.get_field(field_id::ON_BEHALF_OF_COMP_ID), None);
+    Ok(())
+}
+
+#[test]
+fn test_trailer() -> Result<(), QuickFixError> {
+    let mut order = new_order()?;
+    order.trailer_mut().set_signature("SIGNED".to_string())?;
+
+    let trailer: Trailer44<'_> = order.trailer();
+    assert_eq!(trailer.get_signature().as_deref(), Some("SIGNED"));
+
+    let text = order.to_fix_string();
+    assert!(
+        text.ends_with(&format!(
+            "\u{1}89=SIGNED\u{1}10={}\u{1}",
+            order.trailer().get_check_sum()
+        )),
+        "{text}"
+    );
+
+    order.trailer_mut().remove_signature()?;
+    assert_eq!(order.trailer().get_signature(), None);
+    Ok(())
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_sending_time() -> Result<(), QuickFixError> {
+    use chrono::{TimeZone, Utc};
+
+    let mut order = new_order()?;
+    assert_eq!(order.header().get_sending_time_utc(), None);
+
+    let sending_time =
+        Utc.with_ymd_and_hms(2024, 1, 2, 10, 30, 45).unwrap() + chrono::Duration::milliseconds(123);
+    order.header_mut().set_sending_time_utc(sending_time)?;
+    assert_eq!(order.header().get_sending_time(), "20240102-10:30:45.123");
+    assert_eq!(order.header().get_sending_time_utc(), Some(sending_time));
+
+    // Values without fractional seconds are valid too.
+    order
+        .header_mut()
+        .set_orig_sending_time("20240102-10:30:00".to_string())?;
+    assert_eq!(
+        order.header().get_orig_sending_time_utc(),
+        Some(Utc.with_ymd_and_hms(2024, 1, 2, 10, 30, 0).unwrap())
+    );
+
+    order
+        .header_mut()
+        .set_orig_sending_time("invalid".to_string())?;
+    assert_eq!(order.header().get_orig_sending_time_utc(), None);
+    Ok(())
+}
@@ -0,0 +1,101 @@
+use quickfix::{FieldMap, Message, QuickFixError, SessionId};
+use quickfix_msg44::{
+    crack, field_id,
+    field_types::{OrdType, Side},
+    ConversionError, ExecutionReport, MessageCracker44, NewOrderSingle, News,
+};
+
+#[derive(Debug, Default)]
+struct Recorder {
+    events: Vec<String>,
+}
+
+impl MessageCracker44 for Recorder {
+    fn on_unknown_message(&mut self, msg: Message, _session: &SessionId) {
+        let msg_type = msg.with_header(|h| h.get_field(field_id::MSG_TYPE));
+        self.events.push(format!("unknown {msg_type:?}"));
+    }
+
+    fn on_new_order_single(&mut self, msg: NewOrderSingle, _session: &SessionId) {
+        self.events
+            .push(format!("new order single {}", msg.get_cl_ord_id()));
+    }
+
+    fn on_execution_report(&mut self, msg: ExecutionReport, _session: &SessionId) {
+        self.events
+            .push(format!("execution report {}", msg.get_order_id()));
+    }
+
+    fn on_news(&mut self, msg: News, _session: &SessionId) {
+        self.events.push(format!("news {}", msg.get_headline()));
+    }
+}
+
+fn build_execution_report(body: &str) -> Message {
+    let text = format!("8=FIX.4.4\u{1}9=0\u{1}35=8\u{1}{body}10=000\u{1}");
+    Message::try_from_text(&text).unwrap()
+}
+
+#[test]
+fn test_crack() -> Result<(), QuickFixError> {
+    let session = SessionId::try_new("FIX.4.4", "ME", "THEM", "")?;
+    let mut recorder = Recorder::default();
+
+    let order = NewOrderSingle::try_new(
+        "ORDER-1".to_string(),
+        Side::Buy,
+        "20240101-10:00:00".to_string(),
+        OrdType::Market,
+    )?;
+    crack(order.into(), &session, &mut recorder).unwrap();
+
+    let report = build_execution_report(
+        "37=O1\u{1}17=E1\u{1}150=0\u{1}39=0\u{1}54=1\u{1}151=100\u{1}14=0\u{1}6=0\u{1}",
+    );
+    crack(report, &session, &mut recorder).unwrap();
+
+    crack(
+        News::try_new("Hello".to_string())?.into(),
+        &session,
+        &mut recorder,
+    )
+    .unwrap();
+
+    // Custom message type goes to fallback.
+    let mut custom = Message::new();
+    custom.with_header_mut(|h| h.set_field(field_id::MSG_TYPE, "U1"))?;
+    crack(custom, &session, &mut recorder).unwrap();
+
+    // Known message type without override does nothing.
+    let mut heartbeat = Message::new();
+    heartbeat.with_header_mut(|h| h.set_field(field_id::MSG_TYPE, "0"))?;
+    crack(heartbeat, &session, &mut recorder).unwrap();
+
+    assert_eq!(
+        recorder.events,
+        vec![
+            "new order single ORDER-1",
+            "execution report O1",
+            "news Hello",
+            "unknown Some(\"U1\")",
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_crack_invalid_message() -> Result<(), QuickFixError> {
+    let session = SessionId::try_new("FIX.4.4", "ME", "THEM", "")?;
+    let mut recorder = Recorder::default();
+
+    // Execution report without OrderID is not given to handler.
+    let report = build_execution_report(
+        "17=E1\u{1}150=0\u{1}39=0\u{1}54=1\u{1}151=100\u{1}14=0\u{1}6=0\u{1}",
+    );
+    assert_eq!(
+        crack(report, &session, &mut recorder),
+        Err(ConversionError::MissingField { tag: 37 })
+    );
+    assert!(recorder.events.is_empty());
+    Ok(())
+}
@@ -0,0 +1,147 @@
+#![cfg(feature = "serde")]
+
+use quickfix::Message;
+use quickfix_msg44::{
+    field_types::{OrdType, PartyRole, Side},
+    new_order_single::{no_party_i_ds::NoPartySubIDs, PartiesGroup},
+    NewOrderSingle,
+};
+use serde_json::json;
+
+fn build_party(party_id: &str, role: PartyRole, sub_id: Option<&str>) -> PartiesGroup {
+    let mut party = PartiesGroup::try_new().unwrap();
+    party.set_party_id(party_id.to_string()).unwrap();
+    party.set_party_role(role).unwrap();
+    if let Some(sub_id) = sub_id {
+        let mut sub = NoPartySubIDs::try_new().unwrap();
+        sub.set_party_sub_id(sub_id.to_string()).unwrap();
+        party.add_no_party_sub_i_ds(sub).unwrap();
+    }
+    party
+}
+
+fn build_order() -> NewOrderSingle {
+    NewOrderSingle::builder(
+        "ORDER-1".to_string(),
+        Side::Buy,
+        "20240101-10:00:00".to_string(),
+        OrdType::Limit,
+    )
+    .symbol("AAPL".to_string())
+    .order_qty(100.0)
+    .price(189.5)
+    .add_party(build_party(
+        "BROKER",
+        PartyRole::ExecutingFirm,
+        Some("DESK-1"),
+    ))
+    .add_party(build_party("CLIENT", PartyRole::ClientId, None))
+    .build()
+    .unwrap()
+}
+
+#[test]
+fn test_serialize() {
+    let value = serde_json::to_value(build_order()).unwrap();
+    assert_eq!(
+        value,
+        json!({
+            "clOrdId": "ORDER-1",
+            "noPartyIDs": [
+                {
+                    "partyId": "BROKER",
+                    "partyRole": "ExecutingFirm",
+                    "noPartySubIDs": [{ "partySubId": "DESK-1" }],
+                },
+                { "partyId": "CLIENT", "partyRole": "ClientId" },
+            ],
+            "symbol": "AAPL",
+            "side": "Buy",
+            "transactTime": "20240101-10:00:00",
+            "orderQty": 100.0,
+            "ordType": "Limit",
+            "price": 189.5,
+        })
+    );
+}
+
+#[test]
+fn test_round_trip() {
+    let order = build_order();
+    let expected = Message::from(order.clone()).to_fix_string().unwrap();
+
+    let txt = serde_json::to_string(&order).unwrap();
+    let decoded: NewOrderSingle = serde_json::from_str(&txt).unwrap();
+    assert_eq!(decoded.get_side(), Side::Buy);
+    assert_eq!(decoded.parties_len(), 2);
+
+    let msg = Message::from(decoded);
+    assert_eq!(msg.to_fix_string().unwrap(), expected);
+}
+
+#[test]
+fn test_invalid_enum() {
+    let err = serde_json::from_value::<NewOrderSingle>(json!({
+        "clOrdId": "ORDER-1",
+        "side": "Sideways",
+        "transactTime": "20240101-10:00:00",
+        "ordType": "Limit",
+    }))
+    .unwrap_err()
+    .to_string();
+    assert!(
+        err.contains("side: invalid Side value: \"Sideways\""),
+        "{err}"
+    );
+
+    // FIX value is not accepted in place of symbolic name.
+    let err = serde_json::from_value::<NewOrderSingle>(json!({
+        "clOrdId": "ORDER-1",
+        "side": "1",
+        "transactTime": "20240101-10:00:00",
+        "ordType": "Limit",
+    }))
+    .unwrap_err()
+    .to_string();
+    assert!(err.contains("side: invalid Side value: \"1\""), "{err}");
+}
+
+#[test]
+fn test_invalid_group_entry() {
+    let err = serde_json::from_value::<NewOrderSingle>(json!({
+        "clOrdId": "ORDER-1",
+        "side": "Buy",
+        "transactTime": "20240101-10:00:00",
+        "ordType": "Limit",
+        "noPartyIDs": [{ "partyId": "BROKER", "partyRole": "Nobody" }],
+    }))
+    .unwrap_err()
+    .to_string();
+    assert!(err.contains("partyRole: invalid PartyRole value"), "{err}");
+}
+
+#[test]
+fn test_missing_field() {
+    let err = serde_json::from_value::<NewOrderSingle>(json!({
+        "clOrdId": "ORDER-1",
+        "side": "Buy",
+        "transactTime": "20240101-10:00:00",
+    }))
+    .unwrap_err()
+    .to_string();
+    assert!(err.contains("missing field `ordType`"), "{err}");
+}
+
+#[test]
+fn test_unknown_field() {
+    let err = serde_json::from_value::<NewOrderSingle>(json!({
+        "clOrdId": "ORDER-1",
+        "side": "Buy",
+        "transactTime": "20240101-10:00:00",
+        "ordType": "Limit",
+        "colour": "blue",
+    }))
+    .unwrap_err()
+    .to_string();
+    assert!(err.contains("unknown field `colour`"), "{err}");
+}
@@ -0,0 +1,119 @@
+use std::{
+    sync::Mutex,
+    thread,
+    time::{Duration, Instant},
+};
+
+use quickfix::{dictionary_item::*, testing::apply_session_items, *};
+use quickfix_msg44::{News, TypedApplication, TypedApplicationAdapter};
+
+const MSG_TYPE: i32 = 35;
+const MSG_HEADLINE: i32 = 148;
+
+#[derive(Debug, Default)]
+struct Recorder {
+    headlines: Mutex<Vec<String>>,
+    raw_msg_types: Mutex<Vec<String>>,
+}
+
+impl TypedApplication for Recorder {
+    fn on_news(&self, msg: News, _session: &SessionId) -> Result<(), MsgFromAppError> {
+        let headline = Message::from(msg)
+            .get_field(MSG_HEADLINE)
+            .unwrap_or_default();
+        self.headlines.lock().unwrap().push(headline);
+        Ok(())
+    }
+}
+
+impl ApplicationCallback for Recorder {
+    fn on_msg_from_app(&self, msg: Message, _session: &SessionId) -> Result<(), MsgFromAppError> {
+        let msg_type = msg
+            .with_header(|header| header.get_field(MSG_TYPE))
+            .unwrap_or_default();
+        self.raw_msg_types.lock().unwrap().push(msg_type);
+        Ok(())
+    }
+}
+
+struct NullApplication;
+
+impl ApplicationCallback for NullApplication {}
+
+fn build_settings(
+    session_id: SessionId,
+    connection_type: ConnectionType,
+    port: u16,
+) -> Result<SessionSettings, QuickFixError> {
+    SessionSettingsBuilder::new()
+        .default(Dictionary::try_from_items(&[
+            &connection_type,
+            &ReconnectInterval(60),
+            &UseDataDictionary(false),
+        ])?)
+        .session(session_id, |dict| apply_session_items(dict, port))
+        .build()
+}
+
+fn wait_until<F: FnMut() -> bool>(timeout: Duration, mut predicate: F) {
+    let start = Instant::now();
+    while !predicate() {
+        assert!(
+            start.elapsed() < timeout,
+            "Condition not met after {timeout:?}"
+        );
+        thread::sleep(Duration::from_millis(50));
+    }
+}
+
+#[test]
+fn test_typed_application() -> Result<(), QuickFixError> {
+    let receiver_id = SessionId::try_new("FIX.4.4", "RECEIVER", "SENDER", "")?;
+    let sender_id = SessionId::try_new("FIX.4.4", "SENDER", "RECEIVER", "")?;
+
+    let receiver = TypedApplicationAdapter(Recorder::default());
+    let app_receiver = Application::try_new(&receiver)?;
+    let app_sender = Application::try_new(&NullApplication)?;
+    let store_receiver = MemoryMessageStoreFactory::new();
+    let store_sender = MemoryMessageStoreFactory::new();
+
+    let settings_receiver = build_settings(receiver_id, ConnectionType::Acceptor, 0)?;
+    let mut acceptor = Acceptor::try_new(
+        &settings_receiver,
+        &app_receiver,
+        &store_receiver,
+        FixSocketServerKind::default(),
+    )?;
+    acceptor.start()?;
+    let port = acceptor.listen_endpoints()?[0].port();
+
+    let settings_sender = build_settings(sender_id.clone(), ConnectionType::Initiator, port)?;
+    let mut initiator = Initiator::try_new(
+        &settings_sender,
+        &app_sender,
+        &store_sender,
+        FixSocketServerKind::default(),
+    )?;
+    initiator.start()?;
+    wait_until(Duration::from_secs(10), || {
+        initiator.is_logged_on().unwrap_or(false) && acceptor.is_logged_on().unwrap_or(false)
+    });
+
+    // Known message goes to its typed callback.
+    send_to_target(News::try_new("Hello".to_string())?.into(), &sender_id)?;
+
+    // Unknown one goes to raw callback.
+    let mut custom = Message::new();
+    custom.with_header_mut(|header| header.set_field(MSG_TYPE, "U1"))?;
+    send_to_target(custom, &sender_id)?;
+
+    wait_until(Duration::from_secs(5), || {
+        !receiver.0.raw_msg_types.lock().unwrap().is_empty()
+    });
+    assert_eq!(*receiver.0.headlines.lock().unwrap(), vec!["Hello"]);
+    assert_eq!(*receiver.0.raw_msg_types.lock().unwrap(), vec!["U1"]);
+
+    initiator.stop()?;
+    acceptor.stop()?;
+    Ok(())
+}
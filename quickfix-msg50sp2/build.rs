@@ -0,0 +1,30 @@
+use std::{env, io};
+
+use quickfix_msg_gen::*;
+
+// Specs are merged at build time, directly from quickfix C++ library sources.
+const TRANSPORT_SPEC_FILENAME: &str = "../quickfix-ffi/libquickfix/spec/FIXT11.xml";
+const APP_SPEC_FILENAME: &str = "../quickfix-ffi/libquickfix/spec/FIX50SP2.xml";
+const APPL_VER_ID: &str = "9";
+
+fn main() -> io::Result<()> {
+    let out_dir = env::var("OUT_DIR").expect("Missing OUT_DIR");
+
+    println!("cargo:rerun-if-changed={TRANSPORT_SPEC_FILENAME}");
+    println!("cargo:rerun-if-changed={APP_SPEC_FILENAME}");
+
+    let generator = Generator::new()
+        .serde(env::var_os("CARGO_FEATURE_SERDE").is_some())
+        .chrono(env::var_os("CARGO_FEATURE_CHRONO").is_some());
+    generator.generate_fixt(
+        TRANSPORT_SPEC_FILENAME,
+        APP_SPEC_FILENAME,
+        format!("{out_dir}/code.rs"),
+        APPL_VER_ID,
+    )?;
+
+    // Uncomment bellow line to show generated code
+    // generator.generate_fixt(TRANSPORT_SPEC_FILENAME, APP_SPEC_FILENAME, "src/out.rs", APPL_VER_ID)?;
+
+    Ok(())
+}
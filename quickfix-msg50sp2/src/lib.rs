@@ -0,0 +1,4 @@
+/*! Auto-generated messages for `FIX50SP2.xml` and `FIXT11.xml` dictionaries. */
+
+// include!("out.rs");
+include!(concat!(env!("OUT_DIR"), "/code.rs"));
@@ -0,0 +1,153 @@
+use std::{
+    sync::Mutex,
+    thread,
+    time::{Duration, Instant},
+};
+
+use quickfix::{dictionary_item::*, testing::apply_session_items, *};
+use quickfix_msg50sp2::{field_id, News, FIX_APPL_VER_ID, FIX_BEGIN_STRING};
+
+const MSG_TYPE_NEWS: &str = "B";
+const MSG_TYPE_LOGON: &str = "A";
+
+#[derive(Debug, Default)]
+struct Recorder {
+    raw_incoming: Mutex<Vec<String>>,
+    appl_ver_ids: Mutex<Vec<Option<String>>>,
+}
+
+impl ApplicationCallback for Recorder {
+    fn on_msg_from_app(&self, msg: Message, _session: &SessionId) -> Result<(), MsgFromAppError> {
+        let appl_ver_id = msg.with_header(|header| header.get_field(field_id::APPL_VER_ID));
+        self.appl_ver_ids.lock().unwrap().push(appl_ver_id);
+        Ok(())
+    }
+
+    fn on_raw_incoming(&self, _session: &SessionId, raw: &[u8]) {
+        let raw = String::from_utf8_lossy(raw).into_owned();
+        self.raw_incoming.lock().unwrap().push(raw);
+    }
+}
+
+impl Recorder {
+    fn raw_of_type(&self, msg_type: &str) -> Vec<String> {
+        let needle = format!("\u{1}35={msg_type}\u{1}");
+        self.raw_incoming
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|raw| raw.contains(&needle))
+            .cloned()
+            .collect()
+    }
+}
+
+struct NullApplication;
+
+impl ApplicationCallback for NullApplication {}
+
+fn build_settings(
+    session_id: SessionId,
+    connection_type: ConnectionType,
+    port: u16,
+) -> Result<SessionSettings, QuickFixError> {
+    SessionSettingsBuilder::new()
+        .default(Dictionary::try_from_items(&[
+            &connection_type,
+            &ReconnectInterval(60),
+            &UseDataDictionary(false),
+            &DefaultApplVerID("FIX.5.0SP2"),
+        ])?)
+        .session(session_id, |dict| apply_session_items(dict, port))
+        .build()
+}
+
+fn wait_until<F: FnMut() -> bool>(timeout: Duration, mut predicate: F) {
+    let start = Instant::now();
+    while !predicate() {
+        assert!(
+            start.elapsed() < timeout,
+            "Condition not met after {timeout:?}"
+        );
+        thread::sleep(Duration::from_millis(50));
+    }
+}
+
+#[test]
+fn test_generated_message() -> Result<(), QuickFixError> {
+    assert_eq!(FIX_BEGIN_STRING, "FIXT.1.1");
+    assert_eq!(FIX_APPL_VER_ID, "9");
+
+    let msg = Message::from(News::try_new("Hello".to_string())?);
+    msg.with_header(|header| {
+        assert_eq!(
+            header.get_field(field_id::BEGIN_STRING).as_deref(),
+            Some("FIXT.1.1")
+        );
+        assert_eq!(
+            header.get_field(field_id::APPL_VER_ID).as_deref(),
+            Some("9")
+        );
+    });
+    Ok(())
+}
+
+#[test]
+fn test_fixt_session() -> Result<(), QuickFixError> {
+    let receiver_id = SessionId::try_new(FIX_BEGIN_STRING, "RECEIVER", "SENDER", "")?;
+    let sender_id = SessionId::try_new(FIX_BEGIN_STRING, "SENDER", "RECEIVER", "")?;
+
+    let receiver = Recorder::default();
+    let app_receiver = Application::try_new(&receiver)?;
+    let app_sender = Application::try_new(&NullApplication)?;
+    let store_receiver = MemoryMessageStoreFactory::new();
+    let store_sender = MemoryMessageStoreFactory::new();
+
+    let settings_receiver = build_settings(receiver_id, ConnectionType::Acceptor, 0)?;
+    let mut acceptor = Acceptor::try_new(
+        &settings_receiver,
+        &app_receiver,
+        &store_receiver,
+        FixSocketServerKind::default(),
+    )?;
+    acceptor.start()?;
+    let port = acceptor.listen_endpoints()?[0].port();
+
+    let settings_sender = build_settings(sender_id.clone(), ConnectionType::Initiator, port)?;
+    let mut initiator = Initiator::try_new(
+        &settings_sender,
+        &app_sender,
+        &store_sender,
+        FixSocketServerKind::default(),
+    )?;
+    initiator.start()?;
+    wait_until(Duration::from_secs(10), || {
+        initiator.is_logged_on().unwrap_or(false) && acceptor.is_logged_on().unwrap_or(false)
+    });
+
+    send_to_target(News::try_new("Hello".to_string())?.into(), &sender_id)?;
+    wait_until(Duration::from_secs(5), || {
+        !receiver.appl_ver_ids.lock().unwrap().is_empty()
+    });
+
+    // Logon advertises default application version.
+    let logons = receiver.raw_of_type(MSG_TYPE_LOGON);
+    assert_eq!(logons.len(), 1);
+    assert!(logons[0].starts_with("8=FIXT.1.1\u{1}"), "{}", logons[0]);
+    assert!(logons[0].contains("\u{1}1137=9\u{1}"), "{}", logons[0]);
+
+    // Application message carries its own version on the wire.
+    let news = receiver.raw_of_type(MSG_TYPE_NEWS);
+    assert_eq!(news.len(), 1);
+    assert!(news[0].starts_with("8=FIXT.1.1\u{1}"), "{}", news[0]);
+    assert!(news[0].contains("\u{1}1128=9\u{1}"), "{}", news[0]);
+    assert!(news[0].contains("\u{1}148=Hello\u{1}"), "{}", news[0]);
+    assert_eq!(
+        *receiver.appl_ver_ids.lock().unwrap(),
+        vec![Some("9".to_string())]
+    );
+
+    initiator.stop()?;
+    acceptor.stop()?;
+    Ok(())
+}
@@ -17,6 +17,28 @@ pub enum FixSpecError {
 
     #[error("xml error: {0}")]
     Xml(String),
+
+    #[error("duplicate field: {name} ({number})")]
+    DuplicateField { number: u32, name: String },
+
+    #[error("unknown field: {0}")]
+    UnknownField(String),
+
+    #[error("unknown component: {0}")]
+    UnknownComponent(String),
+
+    #[error("group without delimiter: {0} has no field")]
+    EmptyGroup(String),
+}
+
+/// [`FixSpecError`] with the byte offset in parsed document where it has been detected.
+#[derive(Debug, Error, PartialEq, Eq, Clone)]
+#[error("{error} (at byte {offset})")]
+pub struct LocatedFixSpecError {
+    /// Byte offset in parsed document.
+    pub offset: u64,
+    /// Parse error.
+    pub error: FixSpecError,
 }
 
 impl From<FromUtf8Error> for FixSpecError {
@@ -7,11 +7,12 @@
  * **NOTE** This crate is not a code generator. It only help having a clear representation of what FIX dictionary are.
  */
 
-use std::io;
+use std::{collections::HashMap, io};
 
 use quick_xml::{events::Event, Reader, Writer};
 
 mod error;
+mod validate;
 mod model {
     mod component;
     mod component_spec;
@@ -41,6 +42,7 @@ mod xml_ext;
 
 pub use error::*;
 pub use model::*;
+pub use validate::validate_spec;
 use xml_ext::*;
 
 #[doc(hidden)] // For testing
@@ -51,13 +53,25 @@ type XmlReader<'a> = Reader<&'a [u8]>;
 
 /// Try converting byte array into a FIX spec tree.
 pub fn parse_spec(input: &[u8]) -> Result<FixSpec, FixSpecError> {
+    parse_spec_located(input).map_err(|err| err.error)
+}
+
+/// Same as [`parse_spec`], but error tells where parsing stopped in `input`.
+pub fn parse_spec_located(input: &[u8]) -> Result<FixSpec, LocatedFixSpecError> {
     let mut reader = Reader::from_reader(input);
     reader.config_mut().trim_text(true);
 
+    parse_root(&mut reader).map_err(|error| LocatedFixSpecError {
+        offset: reader.buffer_position(),
+        error,
+    })
+}
+
+fn parse_root(reader: &mut XmlReader) -> Result<FixSpec, FixSpecError> {
     match reader.read_event()? {
         // If we are at start of FIX spec.
         Event::Start(e) if e.name().as_ref() == FixSpec::TAG_NAME.as_bytes() => {
-            FixSpec::parse_xml_tree(&e, &mut reader)
+            FixSpec::parse_xml_tree(&e, reader)
         }
         // Otherwise document is invalid
         _ => Err(FixSpecError::InvalidDocument("invalid root")),
@@ -72,3 +86,54 @@ pub fn write_spec(spec: &FixSpec) -> io::Result<Vec<u8>> {
 
     Ok(writer.into_inner())
 }
+
+/// Merge FIX specs into a single FIXT spec.
+///
+/// This is mostly useful to combine `FIXT11.xml` transport dictionary with one of the `FIX50*.xml`
+/// application dictionaries. Messages are sorted by message type and fields are merged by number.
+pub fn merge_specs<I: IntoIterator<Item = FixSpec>>(specs: I) -> FixSpec {
+    specs
+        .into_iter()
+        .fold(FixSpec::new_fixt(), |mut acc, item| {
+            // Extend un-sortable fields
+            acc.headers.extend(item.headers);
+            acc.trailers.extend(item.trailers);
+            acc.component_specs.extend(item.component_specs);
+
+            // Extends messages and sort them by msgtype
+            acc.messages.extend(item.messages);
+            acc.messages.sort_by_key(|x| x.msg_type.clone());
+
+            // Merge fields
+            acc.field_specs = merge_field_specs(&acc.field_specs, &item.field_specs);
+
+            acc
+        })
+}
+
+fn merge_field_specs(a: &[FieldSpec], b: &[FieldSpec]) -> Vec<FieldSpec> {
+    // Create a new map to merge all fields using their FIX number.
+    let mut all_fields: HashMap<u32, FieldSpec> = HashMap::with_capacity(a.len() + b.len());
+
+    for field_spec in a.iter().chain(b) {
+        let entry = all_fields
+            .entry(field_spec.number)
+            .or_insert_with(|| FieldSpec {
+                number: field_spec.number,
+                name: field_spec.name.clone(),
+                r#type: field_spec.r#type,
+                values: Vec::new(),
+            });
+
+        entry.values.extend(field_spec.values.clone());
+        entry
+            .values
+            .sort_by_key(|x| (x.value.len(), x.value.clone()));
+        entry.values.dedup_by_key(|x| x.value.clone());
+    }
+
+    // Build output vec.
+    let mut output: Vec<_> = all_fields.into_values().collect();
+    output.sort_by_key(|x| x.number);
+    output
+}
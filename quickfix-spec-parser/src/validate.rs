@@ -0,0 +1,67 @@
+use std::collections::HashSet;
+
+use crate::{FieldValue, FixSpec, FixSpecError};
+
+/// Check spec is consistent: field numbers and names are unique, every field / component
+/// referenced by messages, components or groups is defined, and every group has a first field
+/// to use as delimiter.
+///
+/// This is not done by [`crate::parse_spec`], since some venue dictionaries are not consistent
+/// but are still useful to read.
+pub fn validate_spec(spec: &FixSpec) -> Result<(), FixSpecError> {
+    let mut numbers = HashSet::with_capacity(spec.field_specs.len());
+    let mut names = HashSet::with_capacity(spec.field_specs.len());
+    for field_spec in &spec.field_specs {
+        if !numbers.insert(field_spec.number) || !names.insert(field_spec.name.as_str()) {
+            return Err(FixSpecError::DuplicateField {
+                number: field_spec.number,
+                name: field_spec.name.clone(),
+            });
+        }
+    }
+
+    let components: HashSet<_> = spec
+        .component_specs
+        .iter()
+        .map(|component| component.name.as_str())
+        .collect();
+
+    let check = |values: &[FieldValue]| check_values(values, &names, &components);
+    check(&spec.headers)?;
+    check(&spec.trailers)?;
+    for message in &spec.messages {
+        check(&message.values)?;
+    }
+    for component in &spec.component_specs {
+        check(&component.values)?;
+    }
+    Ok(())
+}
+
+fn check_values(
+    values: &[FieldValue],
+    fields: &HashSet<&str>,
+    components: &HashSet<&str>,
+) -> Result<(), FixSpecError> {
+    for value in values {
+        match value {
+            FieldValue::Field(field) if !fields.contains(field.name.as_str()) => {
+                return Err(FixSpecError::UnknownField(field.name.clone()));
+            }
+            FieldValue::Group(group) => {
+                if !fields.contains(group.name.as_str()) {
+                    return Err(FixSpecError::UnknownField(group.name.clone()));
+                }
+                if group.values.is_empty() {
+                    return Err(FixSpecError::EmptyGroup(group.name.clone()));
+                }
+                check_values(&group.values, fields, components)?;
+            }
+            FieldValue::Component(component) if !components.contains(component.name.as_str()) => {
+                return Err(FixSpecError::UnknownComponent(component.name.clone()));
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
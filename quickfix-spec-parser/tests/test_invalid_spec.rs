@@ -24,3 +24,12 @@ fn test_invalid_parse() {
     // Invalid UTF8 attribute.
     check_text(b"<fix type='\xFF\xFF' major='1' minor='1' servicepack='0'><head");
 }
+
+#[test]
+fn test_parse_error_offset() {
+    let input = b"<fix type='FIX' major='4' minor='4' servicepack='0'>\n<fields>\n<field number='x' name='A' type='STRING'/>\n</fields>\n</fix>";
+    let err = parse_spec_located(input).unwrap_err();
+    assert!(matches!(err.error, FixSpecError::InvalidContent(_)));
+    assert!(err.offset > 62, "{err}");
+    assert!(err.offset <= input.len() as u64, "{err}");
+}
@@ -80,6 +80,36 @@ fn test_fixt11() {
     ));
 }
 
+#[test]
+fn test_merge_fixt11_fix50sp2() {
+    let transport = parse_spec(include_bytes!(
+        "../../quickfix-ffi/libquickfix/spec/FIXT11.xml"
+    ))
+    .unwrap();
+    let app = parse_spec(include_bytes!(
+        "../../quickfix-ffi/libquickfix/spec/FIX50SP2.xml"
+    ))
+    .unwrap();
+    let field_count = app.field_specs.len();
+
+    let spec = merge_specs([transport, app]);
+    assert!(spec.is_fixt);
+
+    // Messages from both specs, sorted by message type.
+    let msg_types: Vec<_> = spec.messages.iter().map(|x| x.msg_type.as_str()).collect();
+    assert!(msg_types.contains(&"A"));
+    assert!(msg_types.contains(&"B"));
+    assert!(msg_types.windows(2).all(|x| x[0] <= x[1]));
+
+    // Fields are merged by number, without duplicates.
+    assert!(spec.field_specs.len() >= field_count);
+    assert!(spec
+        .field_specs
+        .windows(2)
+        .all(|x| x[0].number < x[1].number));
+    assert!(spec.field_specs.iter().any(|x| x.number == 1128));
+}
+
 #[test]
 fn test_coinbase_order_entry() {
     // Cannot check parsed content, since comments are dropped.
@@ -98,3 +128,41 @@ fn test_parse_with_comment() {
     // 2. Check parser do not crash if there is unhandled node
     parse_spec(include_bytes!("data/commented_file.xml")).unwrap();
 }
+
+#[test]
+fn test_validate() {
+    let spec = parse_spec(include_bytes!("data/cb-FIX50-prod-sand.xml")).unwrap();
+    assert_eq!(validate_spec(&spec), Ok(()));
+
+    // Coinbase order entry spec define tag 297 twice.
+    let spec = parse_spec(include_bytes!("data/cb-FIX42-prod-sand.xml")).unwrap();
+    assert_eq!(
+        validate_spec(&spec),
+        Err(FixSpecError::DuplicateField {
+            number: 297,
+            name: "QuoteStatus".to_string()
+        })
+    );
+
+    // Group delimiter is its first field.
+    let spec = parse_spec(
+        br#"<fix type="FIX" major="4" minor="4" servicepack="0">
+            <header/>
+            <messages>
+                <message name="News" msgtype="B" msgcat="app">
+                    <group name="NoLinesOfText" required="N"></group>
+                </message>
+            </messages>
+            <trailer/>
+            <components/>
+            <fields>
+                <field number="33" name="NoLinesOfText" type="NUMINGROUP"/>
+            </fields>
+        </fix>"#,
+    )
+    .unwrap();
+    assert_eq!(
+        validate_spec(&spec),
+        Err(FixSpecError::EmptyGroup("NoLinesOfText".to_string()))
+    );
+}
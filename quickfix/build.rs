@@ -0,0 +1,15 @@
+use std::env;
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    // Forward build configuration of `quickfix-ffi` as `cfg(quickfix_<name>)`.
+    for name in ["vendored", "ssl", "mysql", "postgres", "io_uring"] {
+        let key = format!("DEP_QUICKFIX_{}", name.to_uppercase());
+        println!("cargo:rerun-if-env-changed={key}");
+        println!("cargo:rustc-check-cfg=cfg(quickfix_{name})");
+        if env::var(&key).as_deref() == Ok("1") {
+            println!("cargo:rustc-cfg=quickfix_{name}");
+        }
+    }
+}
@@ -52,7 +52,7 @@ impl FixShell<'_> {
                 connection_handler.is_stopped(),
             ),
             ShellCommand::Block => println!("RESULT: {:?}", connection_handler.block()),
-            ShellCommand::Poll => println!("RESULT: {:?}", connection_handler.poll()),
+            ShellCommand::Poll => println!("RESULT: {:?}", connection_handler.poll(None)),
             ShellCommand::SendMessage(msg, session_id) => {
                 println!("Sending {msg:?} to {session_id:?}");
                 println!("SEND_RESULT: {:?}", send_to_target(msg, &session_id));
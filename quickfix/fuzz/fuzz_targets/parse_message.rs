@@ -0,0 +1,17 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use quickfix::*;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(msg) = Message::try_from_bytes(data) else {
+        return;
+    };
+
+    // Read parsed message back, like applications do with received ones.
+    let _ = msg.with_header(|h| h.get_field(tags::MSG_TYPE));
+    let _ = msg.get_field_lossy(tags::TEXT);
+    let _ = msg.to_pretty_string(None, '|');
+    let _ = msg.to_fix_bytes();
+    let _ = msg.to_fix_string();
+});
@@ -0,0 +1,32 @@
+#![no_main]
+
+use std::sync::OnceLock;
+
+use libfuzzer_sys::fuzz_target;
+use quickfix::*;
+
+const FIX44_SPEC: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../../quickfix-ffi/libquickfix/spec/FIX44.xml"
+);
+
+fn dictionary() -> &'static DataDictionary {
+    static DICTIONARY: OnceLock<DataDictionary> = OnceLock::new();
+    DICTIONARY
+        .get_or_init(|| DataDictionary::try_from_path(FIX44_SPEC).expect("Missing FIX44 spec"))
+}
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(text) = std::str::from_utf8(data) else {
+        return;
+    };
+    let dictionary = dictionary();
+    let Ok(msg) = dictionary.try_build_message(text) else {
+        return;
+    };
+
+    // Walk repeating groups structure, which is rebuilt from count fields.
+    let _ = msg.to_pretty_string(Some(dictionary), '|');
+    let _ = msg.to_fixml(dictionary);
+    let _ = dictionary.validate(&msg);
+});
@@ -1,16 +1,29 @@
-use std::marker::PhantomData;
+use std::{
+    any::Any,
+    ffi::{CStr, CString},
+    marker::PhantomData,
+    net::SocketAddr,
+    sync::Arc,
+    time::Duration,
+};
 
 use quickfix_ffi::{
-    FixAcceptor_block, FixAcceptor_delete, FixAcceptor_getSession, FixAcceptor_isLoggedOn,
-    FixAcceptor_isStopped, FixAcceptor_new, FixAcceptor_poll, FixAcceptor_start, FixAcceptor_stop,
-    FixAcceptor_t,
+    FixAcceptor_block, FixAcceptor_delete, FixAcceptor_getListenEndpointCount,
+    FixAcceptor_getSession, FixAcceptor_getSessionCount, FixAcceptor_getSessionIdAt,
+    FixAcceptor_isLoggedOn, FixAcceptor_isStopped, FixAcceptor_new, FixAcceptor_poll,
+    FixAcceptor_readListenEndpointAt, FixAcceptor_removeSession, FixAcceptor_start,
+    FixAcceptor_stop, FixAcceptor_stopForce, FixAcceptor_stopWithReason, FixAcceptor_t,
+    FixLogFactory_t,
 };
 
 use crate::{
+    application::notify_session_removed,
+    stop_handle::StopTarget,
     utils::{ffi_code_to_bool, ffi_code_to_result},
-    Application, ApplicationCallback, ConnectionHandler, FfiMessageStoreFactory,
-    FixSocketServerKind, LogFactory, QuickFixError, Session, SessionContainer, SessionId,
-    SessionSettings, StdLogger,
+    Application, ApplicationCallback, ConnectionHandler, ConnectionHandlerBuilder,
+    FfiMessageStoreFactory, FixSocketServerKind, ForAcceptor, LogFactory,
+    MemoryMessageStoreFactory, QuickFixError, Session, SessionContainer, SessionId,
+    SessionSettings, StdLogger, StopHandle,
 };
 
 /// Socket implementation of incoming connections handler.
@@ -21,9 +34,32 @@ where
     S: FfiMessageStoreFactory,
 {
     inner: FixAcceptor_t,
+    server_mode: FixSocketServerKind,
+    stop_handle: StopHandle,
     phantom_application: PhantomData<&'a A>,
+    application_callbacks: *const A,
     phantom_message_store_factory: PhantomData<&'a S>,
-    _log_factory: LogFactory<'static, StdLogger>,
+    // Components kept alive by the handler: its log factory, and application / store factory
+    // when they are shared.
+    _owned: Vec<Arc<dyn Any + Send + Sync>>,
+}
+
+// SAFETY: C++ acceptor can be moved to another thread, and its public API locks its own mutex so
+// it can be called concurrently (this is how `StopHandle` works). Borrowed application callbacks
+// and store factory are called from quickfix threads whatever the owner thread is, so both must
+// be `Sync` for the acceptor to be moved or shared.
+unsafe impl<'a, A, S> Send for Acceptor<'a, A, S>
+where
+    A: ApplicationCallback + Sync,
+    S: FfiMessageStoreFactory + Sync,
+{
+}
+
+unsafe impl<'a, A, S> Sync for Acceptor<'a, A, S>
+where
+    A: ApplicationCallback + Sync,
+    S: FfiMessageStoreFactory + Sync,
+{
 }
 
 impl<'a, A, S> Acceptor<'a, A, S>
@@ -38,27 +74,125 @@ where
         store_factory: &'a S,
         server_mode: FixSocketServerKind,
     ) -> Result<Self, QuickFixError> {
-        let log_factory = LogFactory::try_new(&StdLogger::Stdout)?;
+        let log_factory = Arc::new(LogFactory::try_new(&StdLogger::Stdout)?);
+        Self::try_new_unbound(
+            settings,
+            application,
+            store_factory,
+            log_factory.0,
+            server_mode,
+            vec![log_factory as Arc<dyn Any + Send + Sync>],
+        )
+    }
 
+    // Components not in `owned` must be kept alive by the caller as long as the handler.
+    pub(crate) fn try_new_unbound(
+        settings: &SessionSettings,
+        application: &Application<A>,
+        store_factory: &S,
+        log_factory: FixLogFactory_t,
+        server_mode: FixSocketServerKind,
+        owned: Vec<Arc<dyn Any + Send + Sync>>,
+    ) -> Result<Self, QuickFixError> {
         match unsafe {
             FixAcceptor_new(
                 application.0,
                 store_factory.as_ffi_ptr(),
                 settings.0,
-                log_factory.0,
+                log_factory,
                 server_mode.is_multi_threaded() as i8,
                 server_mode.is_ssl_enabled() as i8,
             )
         } {
             Some(inner) => Ok(Self {
                 inner,
+                server_mode,
+                stop_handle: StopHandle::new(StopTarget::Acceptor(inner)),
                 phantom_application: PhantomData,
+                application_callbacks: application.callbacks_ptr(),
                 phantom_message_store_factory: PhantomData,
-                _log_factory: log_factory,
+                _owned: owned,
             }),
             None => Err(QuickFixError::from_last_error()),
         }
     }
+
+    /// Get addresses the acceptor is listening on, empty when it is not started.
+    ///
+    /// Use it to find port assigned by the OS when `SocketAcceptPort` is 0. Unix domain sockets
+    /// (`SocketAcceptPath`) are not listed.
+    pub fn listen_endpoints(&self) -> Result<Vec<SocketAddr>, QuickFixError> {
+        let endpoint_count: u64 = unsafe { FixAcceptor_getListenEndpointCount(self.inner) }
+            .try_into()
+            .map_err(|_err| QuickFixError::from_last_error())?;
+
+        let mut endpoints = Vec::new();
+        for index in 0..endpoint_count {
+            // Large enough for any "[IPv6]:port" address.
+            let mut buffer = [0_u8; 64];
+            let found = ffi_code_to_bool(unsafe {
+                FixAcceptor_readListenEndpointAt(
+                    self.inner,
+                    index,
+                    buffer.as_mut_ptr().cast(),
+                    buffer.len() as u64,
+                )
+            })?;
+            // Acceptor has been stopped meanwhile.
+            if !found {
+                break;
+            }
+
+            let endpoint = CStr::from_bytes_until_nul(&buffer)
+                .ok()
+                .and_then(|text| text.to_str().ok())
+                .and_then(|text| text.parse().ok())
+                .ok_or_else(|| QuickFixError::invalid_argument("Invalid listen endpoint"))?;
+            endpoints.push(endpoint);
+        }
+        Ok(endpoints)
+    }
+}
+
+impl<A, S> Acceptor<'static, A, S>
+where
+    A: ApplicationCallback + Send + Sync + 'static,
+    S: FfiMessageStoreFactory + Send + Sync + 'static,
+{
+    /// Same as `try_new`, but share ownership of application and store factory instead of
+    /// borrowing them.
+    ///
+    /// Resulting acceptor does not borrow anything: it can be stored in the same struct as other
+    /// handlers and moved to another thread. See `Application::try_new_shared` for an example.
+    pub fn try_new_shared(
+        settings: &SessionSettings,
+        application: Arc<Application<'static, A>>,
+        store_factory: Arc<S>,
+        server_mode: FixSocketServerKind,
+    ) -> Result<Self, QuickFixError> {
+        let log_factory = Arc::new(LogFactory::try_new(&StdLogger::Stdout)?);
+        let log_factory_ptr = log_factory.0;
+        let owned: [Arc<dyn Any + Send + Sync>; 3] =
+            [log_factory, application.clone(), store_factory.clone()];
+        Self::try_new_unbound(
+            settings,
+            &application,
+            &store_factory,
+            log_factory_ptr,
+            server_mode,
+            owned.into(),
+        )
+    }
+}
+
+impl<A> Acceptor<'static, A, MemoryMessageStoreFactory>
+where
+    A: ApplicationCallback + Send + Sync + 'static,
+{
+    /// Start building a new acceptor, see [`ConnectionHandlerBuilder`].
+    pub fn builder(settings: &SessionSettings) -> ConnectionHandlerBuilder<'_, ForAcceptor, A> {
+        ConnectionHandlerBuilder::new(settings)
+    }
 }
 
 impl<A, S> ConnectionHandler for Acceptor<'_, A, S>
@@ -74,14 +208,34 @@ where
         ffi_code_to_result(unsafe { FixAcceptor_block(self.inner) })
     }
 
-    fn poll(&mut self) -> Result<bool, QuickFixError> {
-        ffi_code_to_bool(unsafe { FixAcceptor_poll(self.inner) })
+    fn poll(&mut self, timeout: Option<Duration>) -> Result<bool, QuickFixError> {
+        if self.server_mode.is_multi_threaded() {
+            return Err(QuickFixError::invalid_argument(
+                "poll is only supported by single threaded acceptor",
+            ));
+        }
+
+        let timeout = timeout.map_or(0.0, |timeout| timeout.as_secs_f64());
+        ffi_code_to_bool(unsafe { FixAcceptor_poll(self.inner, timeout) })
     }
 
     fn stop(&mut self) -> Result<(), QuickFixError> {
         ffi_code_to_result(unsafe { FixAcceptor_stop(self.inner) })
     }
 
+    fn stop_force(&mut self) -> Result<(), QuickFixError> {
+        ffi_code_to_result(unsafe { FixAcceptor_stopForce(self.inner) })
+    }
+
+    fn stop_handle(&self) -> StopHandle {
+        self.stop_handle.clone()
+    }
+
+    fn stop_with_reason(&mut self, reason: &str) -> Result<(), QuickFixError> {
+        let ffi_reason = CString::new(reason)?;
+        ffi_code_to_result(unsafe { FixAcceptor_stopWithReason(self.inner, ffi_reason.as_ptr()) })
+    }
+
     fn is_logged_on(&self) -> Result<bool, QuickFixError> {
         ffi_code_to_bool(unsafe { FixAcceptor_isLoggedOn(self.inner) })
     }
@@ -89,6 +243,33 @@ where
     fn is_stopped(&self) -> Result<bool, QuickFixError> {
         ffi_code_to_bool(unsafe { FixAcceptor_isStopped(self.inner) })
     }
+
+    fn session_ids(&self) -> Result<Vec<SessionId>, QuickFixError> {
+        let session_count: u64 = unsafe { FixAcceptor_getSessionCount(self.inner) }
+            .try_into()
+            .map_err(|_err| QuickFixError::from_last_error())?;
+
+        (0..session_count)
+            .map(|index| {
+                unsafe { FixAcceptor_getSessionIdAt(self.inner, index) }
+                    .map(SessionId::from_ffi)
+                    .ok_or_else(QuickFixError::from_last_error)
+            })
+            .collect()
+    }
+
+    fn remove_session(
+        &mut self,
+        session_id: &SessionId,
+        force_disconnect: bool,
+    ) -> Result<(), QuickFixError> {
+        ffi_code_to_result(unsafe {
+            FixAcceptor_removeSession(self.inner, session_id.0, force_disconnect as i8)
+        })?;
+        // SAFETY: application outlives handler, it is either borrowed or in `_owned`.
+        unsafe { notify_session_removed(self.application_callbacks, session_id) };
+        Ok(())
+    }
 }
 
 impl<A, S> SessionContainer for Acceptor<'_, A, S>
@@ -116,7 +297,10 @@ where
     S: FfiMessageStoreFactory,
 {
     fn drop(&mut self) {
-        let _ = self.stop();
-        unsafe { FixAcceptor_delete(self.inner) }
+        let stop_handle = self.stop_handle.clone();
+        stop_handle.detach(|| {
+            let _ = self.stop();
+            unsafe { FixAcceptor_delete(self.inner) }
+        });
     }
 }
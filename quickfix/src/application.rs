@@ -1,11 +1,22 @@
-use std::{any::Any, ffi, marker::PhantomData, mem::ManuallyDrop, panic::catch_unwind};
+use std::{
+    any::Any,
+    ffi::{self, CString},
+    marker::PhantomData,
+    mem::ManuallyDrop,
+    net::SocketAddr,
+    panic::{catch_unwind, AssertUnwindSafe},
+    sync::Arc,
+};
 
 use quickfix_ffi::{
-    FixApplicationCallbacks_t, FixApplication_delete, FixApplication_new, FixApplication_t,
+    FixApplicationCallbacks_t, FixApplication_delete, FixApplication_new,
+    FixApplication_setCallbackErrorField, FixApplication_setCallbackErrorText, FixApplication_t,
     FixMessage_t, FixSessionID_t,
 };
 
-use crate::{Message, QuickFixError, SessionId};
+#[cfg(feature = "metrics")]
+use crate::engine_metrics::{self, Direction};
+use crate::{panic_policy::handle_application_panic, Message, QuickFixError, SessionId};
 
 /// Error result that can occurs from a `on_msg_to_app` callback.
 #[derive(Debug)]
@@ -18,37 +29,222 @@ pub enum MsgToAppError {
 #[derive(Debug)]
 pub enum MsgFromAdminError {
     /// Field not found inside a message.
-    FieldNotFound,
+    FieldNotFound {
+        /// Missing tag.
+        tag: i32,
+    },
     /// Field has a badly formatted value.
-    IncorrectDataFormat,
+    IncorrectDataFormat {
+        /// Offending tag.
+        tag: i32,
+    },
     /// Field has a value that is out of range.
-    IncorrectTagValue,
+    IncorrectTagValue {
+        /// Offending tag.
+        tag: i32,
+    },
     /// User wants to reject permission to logon.
-    RejectLogon,
+    ///
+    /// Counterparty receives a Logout(5) with `reason` as Text(58), then gets disconnected.
+    RejectLogon {
+        /// Logout reason, may be empty.
+        reason: String,
+    },
 }
 
 /// Error result that can occurs from a `on_msg_from_app` callback.
+///
+/// Quickfix answers counterparty with a Reject(3) referencing `tag` as RefTagID(371), or with a
+/// BusinessMessageReject(j) referencing the received RefMsgType(372).
 #[derive(Debug)]
 pub enum MsgFromAppError {
     /// Field not found inside a message.
-    FieldNotFound,
+    FieldNotFound {
+        /// Missing tag.
+        tag: i32,
+    },
     /// Field has a badly formatted value.
-    IncorrectDataFormat,
+    IncorrectDataFormat {
+        /// Offending tag.
+        tag: i32,
+    },
     /// Field has a value that is out of range.
-    IncorrectTagValue,
+    IncorrectTagValue {
+        /// Offending tag.
+        tag: i32,
+    },
     /// Message type not supported by application.
     UnsupportedMessageType,
 }
 
+/// Reason why an initiator could not connect to its counterparty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectErrorKind {
+    /// Counterparty actively refused connection (nothing listening on port).
+    Refused,
+    /// Counterparty did not answer in time.
+    TimedOut,
+    /// No route to counterparty network or host.
+    Unreachable,
+    /// Counterparty host name cannot be resolved.
+    Dns,
+    /// Any other failure.
+    Other,
+}
+
+impl ConnectErrorKind {
+    fn from_ffi(value: i8) -> Self {
+        match value {
+            quickfix_ffi::CONNECT_ERROR_REFUSED => Self::Refused,
+            quickfix_ffi::CONNECT_ERROR_TIMED_OUT => Self::TimedOut,
+            quickfix_ffi::CONNECT_ERROR_UNREACHABLE => Self::Unreachable,
+            quickfix_ffi::CONNECT_ERROR_DNS => Self::Dns,
+            _ => Self::Other,
+        }
+    }
+}
+
+/// Admin message classification, from MsgType(35).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdminMsgKind {
+    /// Heartbeat(0).
+    Heartbeat,
+    /// TestRequest(1).
+    TestRequest,
+    /// ResendRequest(2).
+    ResendRequest,
+    /// Reject(3).
+    Reject,
+    /// SequenceReset(4), gap fill or reset.
+    SequenceReset,
+    /// Logout(5).
+    Logout,
+    /// Logon(A).
+    Logon,
+    /// Any other admin message.
+    Other,
+}
+
+impl AdminMsgKind {
+    fn from_ffi(value: i8) -> Self {
+        match value {
+            quickfix_ffi::ADMIN_MSG_KIND_HEARTBEAT => Self::Heartbeat,
+            quickfix_ffi::ADMIN_MSG_KIND_TEST_REQUEST => Self::TestRequest,
+            quickfix_ffi::ADMIN_MSG_KIND_RESEND_REQUEST => Self::ResendRequest,
+            quickfix_ffi::ADMIN_MSG_KIND_REJECT => Self::Reject,
+            quickfix_ffi::ADMIN_MSG_KIND_SEQUENCE_RESET => Self::SequenceReset,
+            quickfix_ffi::ADMIN_MSG_KIND_LOGOUT => Self::Logout,
+            quickfix_ffi::ADMIN_MSG_KIND_LOGON => Self::Logon,
+            _ => Self::Other,
+        }
+    }
+}
+
+/// Details of a failed connection attempt.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConnectError {
+    /// Failure classification.
+    pub kind: ConnectErrorKind,
+    /// Human readable description of the failure, as reported by the OS.
+    pub message: String,
+}
+
+/// Reason why the connection of a session has been closed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DisconnectReason {
+    /// This side closed the connection: logout completed, session disconnected or handler stopped.
+    Local,
+    /// Counterparty closed or reset the connection.
+    Remote,
+    /// This side dropped the connection waiting for a heartbeat, logon or logout response.
+    Timeout,
+}
+
+impl DisconnectReason {
+    fn from_ffi(value: i8) -> Self {
+        match value {
+            quickfix_ffi::DISCONNECT_REASON_REMOTE => Self::Remote,
+            quickfix_ffi::DISCONNECT_REASON_TIMEOUT => Self::Timeout,
+            _ => Self::Local,
+        }
+    }
+}
+
+/// Details of a session logon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LogonContext {
+    /// Sequence numbers were reset to 1, as ResetSeqNumFlag(141) was set on a Logon(A) exchanged.
+    pub sequence_reset: bool,
+}
+
+/// How a session got logged out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogoutKind {
+    /// Logout(5) was exchanged once logged on.
+    Normal,
+    /// Connection was dropped without any Logout(5): explicit disconnect, timeout, socket error...
+    Disconnect,
+    /// Counterparty answered our Logon(A) with a Logout(5).
+    LogonRejected,
+}
+
+impl LogoutKind {
+    fn from_ffi(value: i8) -> Self {
+        match value {
+            quickfix_ffi::LOGOUT_KIND_NORMAL => Self::Normal,
+            quickfix_ffi::LOGOUT_KIND_LOGON_REJECTED => Self::LogonRejected,
+            _ => Self::Disconnect,
+        }
+    }
+}
+
+/// Details of a session logout.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogoutContext {
+    /// This side sent the first Logout(5), or dropped the connection itself.
+    pub initiated_locally: bool,
+    /// Text(58) of the Logout(5) exchanged, or local disconnect reason (e.g. heartbeat timeout).
+    pub reason: Option<String>,
+    /// Logout classification.
+    pub kind: LogoutKind,
+}
+
 trait AsFixCallbackCode {
     fn as_callback_code(&self) -> i8;
+
+    /// Text attached to the error code, if any.
+    fn error_text(&self) -> Option<&str> {
+        None
+    }
+
+    /// Tag attached to the error code, if any.
+    fn error_field(&self) -> Option<i32> {
+        None
+    }
 }
 
-fn callback_to_code<T: AsFixCallbackCode>(input: Result<Result<(), T>, Box<dyn Any + Send>>) -> i8 {
+fn callback_to_code<T: AsFixCallbackCode>(
+    callback: &str,
+    session_id: &SessionId,
+    input: Result<Result<(), T>, Box<dyn Any + Send>>,
+) -> i8 {
     match input {
-        Err(_) => 0, // Just ignore the panic from rust and let FIX engine continue its workflow.
+        Err(payload) => {
+            // Report the panic from rust and let FIX engine continue its workflow (unless told otherwise).
+            handle_application_panic(callback, session_id, payload);
+            0
+        }
         Ok(Ok(())) => 0, // Everything goes right 🎇!
-        Ok(Err(x)) => x.as_callback_code(), // Use as deliberately change the control flow.
+        Ok(Err(x)) => {
+            // Use as deliberately change the control flow.
+            if let Some(text) = x.error_text().and_then(|text| CString::new(text).ok()) {
+                unsafe { FixApplication_setCallbackErrorText(text.as_ptr()) };
+            }
+            if let Some(field) = x.error_field() {
+                unsafe { FixApplication_setCallbackErrorField(field) };
+            }
+            x.as_callback_code()
+        }
     }
 }
 
@@ -61,10 +257,26 @@ impl AsFixCallbackCode for MsgToAppError {
 impl AsFixCallbackCode for MsgFromAdminError {
     fn as_callback_code(&self) -> i8 {
         match self {
-            Self::FieldNotFound => quickfix_ffi::CALLBACK_RESULT_FIELD_NOT_FOUND,
-            Self::IncorrectDataFormat => quickfix_ffi::CALLBACK_RESULT_INCORRECT_DATA_FORMAT,
-            Self::IncorrectTagValue => quickfix_ffi::CALLBACK_RESULT_INCORRECT_TAG_VALUE,
-            Self::RejectLogon => quickfix_ffi::CALLBACK_RESULT_REJECT_LOGON,
+            Self::FieldNotFound { .. } => quickfix_ffi::CALLBACK_RESULT_FIELD_NOT_FOUND,
+            Self::IncorrectDataFormat { .. } => quickfix_ffi::CALLBACK_RESULT_INCORRECT_DATA_FORMAT,
+            Self::IncorrectTagValue { .. } => quickfix_ffi::CALLBACK_RESULT_INCORRECT_TAG_VALUE,
+            Self::RejectLogon { .. } => quickfix_ffi::CALLBACK_RESULT_REJECT_LOGON,
+        }
+    }
+
+    fn error_text(&self) -> Option<&str> {
+        match self {
+            Self::RejectLogon { reason } => Some(reason),
+            _ => None,
+        }
+    }
+
+    fn error_field(&self) -> Option<i32> {
+        match self {
+            Self::FieldNotFound { tag }
+            | Self::IncorrectDataFormat { tag }
+            | Self::IncorrectTagValue { tag } => Some(*tag),
+            Self::RejectLogon { .. } => None,
         }
     }
 }
@@ -72,17 +284,46 @@ impl AsFixCallbackCode for MsgFromAdminError {
 impl AsFixCallbackCode for MsgFromAppError {
     fn as_callback_code(&self) -> i8 {
         match self {
-            Self::FieldNotFound => quickfix_ffi::CALLBACK_RESULT_FIELD_NOT_FOUND,
-            Self::IncorrectDataFormat => quickfix_ffi::CALLBACK_RESULT_INCORRECT_DATA_FORMAT,
-            Self::IncorrectTagValue => quickfix_ffi::CALLBACK_RESULT_INCORRECT_TAG_VALUE,
+            Self::FieldNotFound { .. } => quickfix_ffi::CALLBACK_RESULT_FIELD_NOT_FOUND,
+            Self::IncorrectDataFormat { .. } => quickfix_ffi::CALLBACK_RESULT_INCORRECT_DATA_FORMAT,
+            Self::IncorrectTagValue { .. } => quickfix_ffi::CALLBACK_RESULT_INCORRECT_TAG_VALUE,
             Self::UnsupportedMessageType => quickfix_ffi::CALLBACK_RESULT_UNSUPPORTED_MESSAGE_TYPE,
         }
     }
+
+    fn error_field(&self) -> Option<i32> {
+        match self {
+            Self::FieldNotFound { tag }
+            | Self::IncorrectDataFormat { tag }
+            | Self::IncorrectTagValue { tag } => Some(*tag),
+            Self::UnsupportedMessageType => None,
+        }
+    }
 }
 
 /// These methods notify your application about events that happen on active FIX sessions.
 ///
 /// There is no guarantee how many threads will be calling these functions.
+///
+/// Every method does nothing by default, so only the relevant ones have to be implemented:
+///
+/// ```rust
+/// # use quickfix::*;
+/// struct MyApp;
+///
+/// impl ApplicationCallback for MyApp {
+///     fn on_msg_from_app(&self, msg: Message, session: &SessionId) -> Result<(), MsgFromAppError> {
+///         println!("{session:?}: {msg:?}");
+///         Ok(())
+///     }
+/// }
+///
+/// let _app = Application::try_new(&MyApp)?;
+/// # Ok::<(), QuickFixError>(())
+/// ```
+///
+/// The flip side is that the compiler does not notice new callbacks added by future versions:
+/// check the changelog when upgrading.
 #[allow(unused_variables)]
 pub trait ApplicationCallback {
     /// On session created.
@@ -91,17 +332,38 @@ pub trait ApplicationCallback {
     /// On session logon.
     fn on_logon(&self, session: &SessionId) {}
 
+    /// Same as [`Self::on_logon`], with logon details.
+    ///
+    /// This is the callback FIX engine calls: default implementation forwards to
+    /// [`Self::on_logon`].
+    fn on_logon_with_context(&self, session: &SessionId, context: LogonContext) {
+        self.on_logon(session)
+    }
+
     /// On session logout.
     fn on_logout(&self, session: &SessionId) {}
 
+    /// Same as [`Self::on_logout`], with logout details.
+    ///
+    /// This is the callback FIX engine calls: default implementation forwards to
+    /// [`Self::on_logout`]. Logon refused by this side from [`Self::on_msg_from_admin`] never
+    /// reaches logout callbacks, as session was not logged on.
+    fn on_logout_with_context(&self, session: &SessionId, context: LogoutContext) {
+        self.on_logout(session)
+    }
+
     /// Called before sending message to admin level.
     ///
-    /// Message can be updated at this stage.
+    /// Message can be updated at this stage. Header is already filled, MsgSeqNum(34) and
+    /// SendingTime(52) included, and message is serialized after this call.
     fn on_msg_to_admin(&self, msg: &mut Message, session: &SessionId) {}
 
     /// Called before sending message to application level.
     ///
-    /// Message can be updated at this stage.
+    /// Message can be updated at this stage, see [`Self::on_msg_to_admin`].
+    /// Returning `Err(MsgToAppError::DoNotSend)` drops it:
+    /// when the message is being resent (PossDupFlag set), counterparty receives a
+    /// SequenceReset-GapFill in place of it.
     fn on_msg_to_app(&self, msg: &mut Message, session: &SessionId) -> Result<(), MsgToAppError> {
         Ok(())
     }
@@ -117,24 +379,227 @@ pub trait ApplicationCallback {
         Ok(())
     }
 
+    /// Same as [`Self::on_msg_from_admin`], with message already classified.
+    ///
+    /// This is the callback FIX engine calls: default implementation forwards to
+    /// [`Self::on_msg_from_admin`], override it to avoid reading MsgType(35) again.
+    fn on_msg_from_admin_with_kind(
+        &self,
+        kind: AdminMsgKind,
+        msg: Message,
+        session: &SessionId,
+    ) -> Result<(), MsgFromAdminError> {
+        self.on_msg_from_admin(msg, session)
+    }
+
     /// Called after received a message from application level.
     ///
     /// Callback receives ownership of the message.
     fn on_msg_from_app(&self, msg: Message, session: &SessionId) -> Result<(), MsgFromAppError> {
         Ok(())
     }
+
+    /// Called when a resend request is about to be sent to counterparty.
+    ///
+    /// `end` is 0 when every message following `begin` is requested.
+    fn on_resend_begin(&self, session: &SessionId, begin: u32, end: u32) {}
+
+    /// Called once every message requested by the last resend request has been received.
+    fn on_resend_complete(&self, session: &SessionId) {}
+
+    /// Called when an initiator session failed to connect to its counterparty.
+    ///
+    /// `attempt` counts consecutive failures since the last successful connection, starting at 1.
+    /// Error is read from the socket as soon as its connection completes or fails, from the
+    /// initiator connect path. Multi-threaded initiators connect on a thread of their own which
+    /// only logs failures, so their failures are detected once the next attempt starts, or the
+    /// initiator is stopped, and reported as [`ConnectErrorKind::Other`].
+    fn on_connect_failed(&self, session: &SessionId, attempt: u32, error: ConnectError) {}
+
+    /// Called once a socket connection is bound to its session, before its first message is
+    /// handled.
+    ///
+    /// Initiators bind their connection right before sending Logon(A), and acceptors once they
+    /// read the Logon(A) of counterparty: a counterparty that connects and never sends a valid
+    /// Logon(A) cannot be told from any session, so it is not reported. Called before
+    /// [`Self::on_logon`].
+    ///
+    /// `peer` is `None` for unix domain socket connections, which have no IP address.
+    fn on_connect(&self, session: &SessionId, peer: Option<SocketAddr>) {}
+
+    /// Called once the socket connection of a session has been closed, for every connection
+    /// [`Self::on_connect`] reported.
+    ///
+    /// Called before [`Self::on_logout`], when session was logged on.
+    fn on_disconnect(&self, session: &SessionId, reason: DisconnectReason) {}
+
+    /// Called once session has been removed from its handler with
+    /// [`ConnectionHandler::remove_session`](crate::ConnectionHandler::remove_session).
+    fn on_remove(&self, session: &SessionId) {}
+
+    /// Called with the exact bytes received from counterparty, before they are parsed.
+    ///
+    /// This is the message as seen on the wire: use it to archive what has really been received,
+    /// serializing it again may reorder fields or normalize values. Called before
+    /// [`Self::on_msg_from_admin`] / [`Self::on_msg_from_app`], even for messages the engine rejects
+    /// later on.
+    fn on_raw_incoming(&self, session: &SessionId, raw: &[u8]) {}
+
+    /// Called with the exact bytes about to be written to counterparty, once serialized.
+    ///
+    /// Called after [`Self::on_msg_to_admin`] / [`Self::on_msg_to_app`], so every change they
+    /// made is included. Resent messages are reported again.
+    fn on_raw_outgoing(&self, session: &SessionId, raw: &[u8]) {}
 }
 
+/// Implement listed [`ApplicationCallback`] methods by forwarding them as is to `self.$inner`.
+///
+/// Used by callback wrappers, which then only implement the callbacks they change.
+macro_rules! forward_callbacks {
+    ($inner:ident => $($callback:ident),+ $(,)?) => {
+        $(forward_callbacks!(@callback $inner $callback);)+
+    };
+
+    (@callback $inner:ident on_create) => {
+        fn on_create(&self, session: &$crate::SessionId) {
+            self.$inner.on_create(session)
+        }
+    };
+    (@callback $inner:ident on_logon) => {
+        fn on_logon(&self, session: &$crate::SessionId) {
+            self.$inner.on_logon(session)
+        }
+    };
+    (@callback $inner:ident on_logon_with_context) => {
+        fn on_logon_with_context(&self, session: &$crate::SessionId, context: $crate::LogonContext) {
+            self.$inner.on_logon_with_context(session, context)
+        }
+    };
+    (@callback $inner:ident on_logout) => {
+        fn on_logout(&self, session: &$crate::SessionId) {
+            self.$inner.on_logout(session)
+        }
+    };
+    (@callback $inner:ident on_logout_with_context) => {
+        fn on_logout_with_context(
+            &self,
+            session: &$crate::SessionId,
+            context: $crate::LogoutContext,
+        ) {
+            self.$inner.on_logout_with_context(session, context)
+        }
+    };
+    (@callback $inner:ident on_msg_to_admin) => {
+        fn on_msg_to_admin(&self, msg: &mut $crate::Message, session: &$crate::SessionId) {
+            self.$inner.on_msg_to_admin(msg, session)
+        }
+    };
+    (@callback $inner:ident on_msg_to_app) => {
+        fn on_msg_to_app(
+            &self,
+            msg: &mut $crate::Message,
+            session: &$crate::SessionId,
+        ) -> Result<(), $crate::MsgToAppError> {
+            self.$inner.on_msg_to_app(msg, session)
+        }
+    };
+    (@callback $inner:ident on_msg_from_admin) => {
+        fn on_msg_from_admin(
+            &self,
+            msg: $crate::Message,
+            session: &$crate::SessionId,
+        ) -> Result<(), $crate::MsgFromAdminError> {
+            self.$inner.on_msg_from_admin(msg, session)
+        }
+    };
+    (@callback $inner:ident on_msg_from_admin_with_kind) => {
+        fn on_msg_from_admin_with_kind(
+            &self,
+            kind: $crate::AdminMsgKind,
+            msg: $crate::Message,
+            session: &$crate::SessionId,
+        ) -> Result<(), $crate::MsgFromAdminError> {
+            self.$inner.on_msg_from_admin_with_kind(kind, msg, session)
+        }
+    };
+    (@callback $inner:ident on_msg_from_app) => {
+        fn on_msg_from_app(
+            &self,
+            msg: $crate::Message,
+            session: &$crate::SessionId,
+        ) -> Result<(), $crate::MsgFromAppError> {
+            self.$inner.on_msg_from_app(msg, session)
+        }
+    };
+    (@callback $inner:ident on_resend_begin) => {
+        fn on_resend_begin(&self, session: &$crate::SessionId, begin: u32, end: u32) {
+            self.$inner.on_resend_begin(session, begin, end)
+        }
+    };
+    (@callback $inner:ident on_resend_complete) => {
+        fn on_resend_complete(&self, session: &$crate::SessionId) {
+            self.$inner.on_resend_complete(session)
+        }
+    };
+    (@callback $inner:ident on_connect_failed) => {
+        fn on_connect_failed(
+            &self,
+            session: &$crate::SessionId,
+            attempt: u32,
+            error: $crate::ConnectError,
+        ) {
+            self.$inner.on_connect_failed(session, attempt, error)
+        }
+    };
+    (@callback $inner:ident on_connect) => {
+        fn on_connect(&self, session: &$crate::SessionId, peer: Option<std::net::SocketAddr>) {
+            self.$inner.on_connect(session, peer)
+        }
+    };
+    (@callback $inner:ident on_disconnect) => {
+        fn on_disconnect(&self, session: &$crate::SessionId, reason: $crate::DisconnectReason) {
+            self.$inner.on_disconnect(session, reason)
+        }
+    };
+    (@callback $inner:ident on_remove) => {
+        fn on_remove(&self, session: &$crate::SessionId) {
+            self.$inner.on_remove(session)
+        }
+    };
+    (@callback $inner:ident on_raw_incoming) => {
+        fn on_raw_incoming(&self, session: &$crate::SessionId, raw: &[u8]) {
+            self.$inner.on_raw_incoming(session, raw)
+        }
+    };
+    (@callback $inner:ident on_raw_outgoing) => {
+        fn on_raw_outgoing(&self, session: &$crate::SessionId, raw: &[u8]) {
+            self.$inner.on_raw_outgoing(session, raw)
+        }
+    };
+}
+
+pub(crate) use forward_callbacks;
+
 /// Application callback wrapper.
 #[derive(Debug)]
-pub struct Application<'a, C: ApplicationCallback>(pub(crate) FixApplication_t, PhantomData<&'a C>);
-
-unsafe impl<'a, C: ApplicationCallback> Send for Application<'a, C> {}
-unsafe impl<'a, C: ApplicationCallback> Sync for Application<'a, C> {}
+pub struct Application<'a, C: ApplicationCallback>(
+    pub(crate) FixApplication_t,
+    PhantomData<&'a C>,
+    // Callbacks owned by the application, when created with `try_new_shared`. Only kept alive.
+    #[allow(dead_code)] Option<Arc<C>>,
+    // Callbacks given to the FIX engine, for events raised from rust.
+    *const C,
+);
+
+// SAFETY: C++ application only keeps a pointer to the callbacks. They are called concurrently from
+// quickfix threads, and from every thread sending messages (`on_msg_to_app`), so they must be
+// `Sync` for the application to be moved or shared.
+unsafe impl<'a, C: ApplicationCallback + Sync> Send for Application<'a, C> {}
+unsafe impl<'a, C: ApplicationCallback + Sync> Sync for Application<'a, C> {}
 
 impl<'a, C> Application<'a, C>
 where
-    C: ApplicationCallback + 'static,
+    C: ApplicationCallback + 'a,
 {
     /// Try create new struct from its underlying components.
     pub fn try_new(callbacks: &'a C) -> Result<Self, QuickFixError> {
@@ -144,11 +609,110 @@ where
                 &Self::CALLBACKS,
             )
         } {
-            Some(fix_application) => Ok(Self(fix_application, PhantomData)),
+            Some(fix_application) => Ok(Self(fix_application, PhantomData, None, callbacks)),
             None => Err(QuickFixError::from_last_error()),
         }
     }
 
+    /// Same as `try_new`, but share ownership of the callbacks instead of borrowing them.
+    ///
+    /// Combined with `Acceptor::try_new_shared` / `Initiator::try_new_shared`, this allows to
+    /// bundle handlers and everything they depend on in a single struct:
+    ///
+    /// ```rust
+    /// # use std::{sync::Arc, thread};
+    /// # use quickfix::*;
+    /// # use quickfix::dictionary_item::*;
+    /// struct MyApplication;
+    ///
+    /// impl ApplicationCallback for MyApplication {}
+    ///
+    /// struct Engine {
+    ///     acceptor: Acceptor<'static, MyApplication, MemoryMessageStoreFactory>,
+    ///     initiator: Initiator<'static, MyApplication, MemoryMessageStoreFactory>,
+    /// }
+    ///
+    /// impl Engine {
+    ///     fn try_new(
+    ///         acceptor_settings: &SessionSettings,
+    ///         initiator_settings: &SessionSettings,
+    ///     ) -> Result<Self, QuickFixError> {
+    ///         // Same application and store factory for both handlers.
+    ///         let app = Arc::new(Application::try_new_shared(Arc::new(MyApplication))?);
+    ///         let store_factory = Arc::new(MemoryMessageStoreFactory::new());
+    ///
+    ///         Ok(Self {
+    ///             acceptor: Acceptor::try_new_shared(
+    ///                 acceptor_settings,
+    ///                 app.clone(),
+    ///                 store_factory.clone(),
+    ///                 FixSocketServerKind::SingleThreaded,
+    ///             )?,
+    ///             initiator: Initiator::try_new_shared(
+    ///                 initiator_settings,
+    ///                 app,
+    ///                 store_factory,
+    ///                 FixSocketServerKind::SingleThreaded,
+    ///             )?,
+    ///         })
+    ///     }
+    /// }
+    ///
+    /// let build_settings = |connection_type: ConnectionType, sender: &str, target: &str| {
+    ///     let mut settings = SessionSettings::new();
+    ///     settings.set(None, Dictionary::try_from_items(&[&connection_type])?)?;
+    ///     settings.set(
+    ///         Some(&SessionId::try_new("FIX.4.4", sender, target, "")?),
+    ///         Dictionary::try_from_items(&[
+    ///             &StartTime("00:00:00"),
+    ///             &EndTime("23:59:59"),
+    ///             &HeartBtInt(30),
+    ///             &SocketAcceptPort(0),
+    ///             &SocketConnectHost("127.0.0.1"),
+    ///             &SocketConnectPort(4000),
+    ///             &DataDictionary("../quickfix-ffi/libquickfix/spec/FIX44.xml"),
+    ///         ])?,
+    ///     )?;
+    ///     Ok::<_, QuickFixError>(settings)
+    /// };
+    ///
+    /// let engine = Engine::try_new(
+    ///     &build_settings(ConnectionType::Acceptor, "ME", "THEM")?,
+    ///     &build_settings(ConnectionType::Initiator, "ME", "OTHER")?,
+    /// )?;
+    ///
+    /// // Engine owns everything, so it can be moved to another thread.
+    /// thread::spawn(move || {
+    ///     let mut engine = engine;
+    ///     engine.acceptor.start()?;
+    ///     engine.acceptor.stop()?;
+    ///     drop(engine.initiator);
+    ///     Ok::<_, QuickFixError>(())
+    /// })
+    /// .join()
+    /// .expect("engine thread panicked")?;
+    /// # Ok::<(), QuickFixError>(())
+    /// ```
+    pub fn try_new_shared(callbacks: Arc<C>) -> Result<Application<'static, C>, QuickFixError>
+    where
+        C: Send + Sync + 'static,
+    {
+        let callbacks_ptr = Arc::as_ptr(&callbacks);
+        match unsafe { FixApplication_new(callbacks_ptr.cast(), &Self::CALLBACKS) } {
+            Some(fix_application) => Ok(Application(
+                fix_application,
+                PhantomData,
+                Some(callbacks),
+                callbacks_ptr,
+            )),
+            None => Err(QuickFixError::from_last_error()),
+        }
+    }
+
+    pub(crate) fn callbacks_ptr(&self) -> *const C {
+        self.3
+    }
+
     const CALLBACKS: FixApplicationCallbacks_t = FixApplicationCallbacks_t {
         onCreate: Self::on_create,
         onLogon: Self::on_logon,
@@ -157,43 +721,81 @@ where
         toApp: Self::to_app,
         fromAdmin: Self::from_admin,
         fromApp: Self::from_app,
+        onResendBegin: Self::on_resend_begin,
+        onResendComplete: Self::on_resend_complete,
+        onConnectFailed: Self::on_connect_failed,
+        onConnect: Self::on_connect,
+        onDisconnect: Self::on_disconnect,
+        onRawIncoming: Self::on_raw_incoming,
+        onRawOutgoing: Self::on_raw_outgoing,
     };
 
     extern "C" fn on_create(data: *const ffi::c_void, session: FixSessionID_t) {
-        let session_id = ManuallyDrop::new(SessionId(session));
+        let session_id = SessionId::borrow_ffi(session);
 
-        let _ = catch_unwind(|| {
+        if let Err(payload) = catch_unwind(|| {
             let this = unsafe { &*(data as *const C) };
             this.on_create(&session_id);
-        });
+        }) {
+            handle_application_panic("on_create", &session_id, payload);
+        }
     }
 
-    extern "C" fn on_logon(data: *const ffi::c_void, session: FixSessionID_t) {
-        let session_id = ManuallyDrop::new(SessionId(session));
+    extern "C" fn on_logon(data: *const ffi::c_void, session: FixSessionID_t, sequence_reset: i8) {
+        let session_id = SessionId::borrow_ffi(session);
+        #[cfg(feature = "metrics")]
+        engine_metrics::record_logon(&session_id);
 
-        let _ = catch_unwind(|| {
+        if let Err(payload) = catch_unwind(|| {
             let this = unsafe { &*(data as *const C) };
-            this.on_logon(&session_id);
-        });
+            let context = LogonContext {
+                sequence_reset: sequence_reset != 0,
+            };
+            this.on_logon_with_context(&session_id, context);
+        }) {
+            handle_application_panic("on_logon", &session_id, payload);
+        }
     }
 
-    extern "C" fn on_logout(data: *const ffi::c_void, session: FixSessionID_t) {
-        let session_id = ManuallyDrop::new(SessionId(session));
-
-        let _ = catch_unwind(|| {
+    extern "C" fn on_logout(
+        data: *const ffi::c_void,
+        session: FixSessionID_t,
+        kind: i8,
+        initiated_locally: i8,
+        reason: *const ffi::c_char,
+    ) {
+        let session_id = SessionId::borrow_ffi(session);
+        #[cfg(feature = "metrics")]
+        engine_metrics::record_logout(&session_id);
+
+        if let Err(payload) = catch_unwind(|| {
             let this = unsafe { &*(data as *const C) };
-            this.on_logout(&session_id);
-        });
+            let reason = (!reason.is_null()).then(|| {
+                unsafe { ffi::CStr::from_ptr(reason) }
+                    .to_string_lossy()
+                    .into_owned()
+            });
+            let context = LogoutContext {
+                initiated_locally: initiated_locally != 0,
+                reason,
+                kind: LogoutKind::from_ffi(kind),
+            };
+            this.on_logout_with_context(&session_id, context);
+        }) {
+            handle_application_panic("on_logout", &session_id, payload);
+        }
     }
 
     extern "C" fn to_admin(data: *const ffi::c_void, msg: FixMessage_t, session: FixSessionID_t) {
-        let session_id = ManuallyDrop::new(SessionId(session));
+        let session_id = SessionId::borrow_ffi(session);
 
-        let _ = catch_unwind(|| {
+        if let Err(payload) = catch_unwind(|| {
             let this = unsafe { &*(data as *const C) };
             let mut msg = ManuallyDrop::new(Message(msg));
             this.on_msg_to_admin(&mut msg, &session_id);
-        });
+        }) {
+            handle_application_panic("on_msg_to_admin", &session_id, payload);
+        }
     }
 
     extern "C" fn to_app(
@@ -201,7 +803,7 @@ where
         msg: FixMessage_t,
         session: FixSessionID_t,
     ) -> i8 {
-        let session_id = ManuallyDrop::new(SessionId(session));
+        let session_id = SessionId::borrow_ffi(session);
 
         let output_code = catch_unwind(|| {
             let this = unsafe { &*(data as *const C) };
@@ -209,22 +811,27 @@ where
             this.on_msg_to_app(&mut msg, &session_id)
         });
 
-        callback_to_code(output_code)
+        callback_to_code("on_msg_to_app", &session_id, output_code)
     }
 
     extern "C" fn from_admin(
         data: *const ffi::c_void,
         msg: FixMessage_t,
         session: FixSessionID_t,
+        kind: i8,
     ) -> i8 {
-        let session_id = ManuallyDrop::new(SessionId(session));
+        let session_id = SessionId::borrow_ffi(session);
 
-        let output_code = catch_unwind(move || {
+        let output_code = catch_unwind(|| {
             let this = unsafe { &*(data as *const C) };
-            this.on_msg_from_admin(Message(msg), &session_id)
+            this.on_msg_from_admin_with_kind(
+                AdminMsgKind::from_ffi(kind),
+                Message(msg),
+                &session_id,
+            )
         });
 
-        callback_to_code(output_code)
+        callback_to_code("on_msg_from_admin", &session_id, output_code)
     }
 
     extern "C" fn from_app(
@@ -232,14 +839,171 @@ where
         msg: FixMessage_t,
         session: FixSessionID_t,
     ) -> i8 {
-        let session_id = ManuallyDrop::new(SessionId(session));
+        let session_id = SessionId::borrow_ffi(session);
 
-        let output_code = catch_unwind(move || {
+        let output_code = catch_unwind(|| {
             let this = unsafe { &*(data as *const C) };
             this.on_msg_from_app(Message(msg), &session_id)
         });
 
-        callback_to_code(output_code)
+        callback_to_code("on_msg_from_app", &session_id, output_code)
+    }
+
+    extern "C" fn on_resend_begin(
+        data: *const ffi::c_void,
+        session: FixSessionID_t,
+        begin: u32,
+        end: u32,
+    ) {
+        let session_id = SessionId::borrow_ffi(session);
+        #[cfg(feature = "metrics")]
+        engine_metrics::record_resend_request(&session_id);
+
+        if let Err(payload) = catch_unwind(|| {
+            let this = unsafe { &*(data as *const C) };
+            this.on_resend_begin(&session_id, begin, end);
+        }) {
+            handle_application_panic("on_resend_begin", &session_id, payload);
+        }
+    }
+
+    extern "C" fn on_resend_complete(data: *const ffi::c_void, session: FixSessionID_t) {
+        let session_id = SessionId::borrow_ffi(session);
+
+        if let Err(payload) = catch_unwind(|| {
+            let this = unsafe { &*(data as *const C) };
+            this.on_resend_complete(&session_id);
+        }) {
+            handle_application_panic("on_resend_complete", &session_id, payload);
+        }
+    }
+
+    extern "C" fn on_connect_failed(
+        data: *const ffi::c_void,
+        session: FixSessionID_t,
+        attempt: u32,
+        error_kind: i8,
+        message: *const ffi::c_char,
+    ) {
+        let session_id = SessionId::borrow_ffi(session);
+        #[cfg(feature = "metrics")]
+        engine_metrics::record_connect_failure(&session_id);
+
+        if let Err(payload) = catch_unwind(|| {
+            let this = unsafe { &*(data as *const C) };
+            let message = if message.is_null() {
+                String::new()
+            } else {
+                unsafe { ffi::CStr::from_ptr(message) }
+                    .to_string_lossy()
+                    .into_owned()
+            };
+            let error = ConnectError {
+                kind: ConnectErrorKind::from_ffi(error_kind),
+                message,
+            };
+            this.on_connect_failed(&session_id, attempt, error);
+        }) {
+            handle_application_panic("on_connect_failed", &session_id, payload);
+        }
+    }
+
+    extern "C" fn on_connect(
+        data: *const ffi::c_void,
+        session: FixSessionID_t,
+        peer_address: *const ffi::c_char,
+    ) {
+        let session_id = SessionId::borrow_ffi(session);
+        let peer = (!peer_address.is_null())
+            .then(|| unsafe { ffi::CStr::from_ptr(peer_address) })
+            .and_then(|text| text.to_str().ok())
+            .and_then(|text| text.parse().ok());
+
+        if let Err(payload) = catch_unwind(|| {
+            let this = unsafe { &*(data as *const C) };
+            this.on_connect(&session_id, peer);
+        }) {
+            handle_application_panic("on_connect", &session_id, payload);
+        }
+    }
+
+    extern "C" fn on_disconnect(data: *const ffi::c_void, session: FixSessionID_t, reason: i8) {
+        let session_id = SessionId::borrow_ffi(session);
+
+        if let Err(payload) = catch_unwind(|| {
+            let this = unsafe { &*(data as *const C) };
+            this.on_disconnect(&session_id, DisconnectReason::from_ffi(reason));
+        }) {
+            handle_application_panic("on_disconnect", &session_id, payload);
+        }
+    }
+
+    extern "C" fn on_raw_incoming(
+        data: *const ffi::c_void,
+        session: FixSessionID_t,
+        raw: *const ffi::c_char,
+        len: u64,
+    ) {
+        let session_id = SessionId::borrow_ffi(session);
+        let raw = unsafe { raw_bytes(raw, len) };
+        #[cfg(feature = "metrics")]
+        engine_metrics::record_raw(&session_id, Direction::In, raw);
+
+        if let Err(payload) = catch_unwind(|| {
+            let this = unsafe { &*(data as *const C) };
+            this.on_raw_incoming(&session_id, raw);
+        }) {
+            handle_application_panic("on_raw_incoming", &session_id, payload);
+        }
+    }
+
+    extern "C" fn on_raw_outgoing(
+        data: *const ffi::c_void,
+        session: FixSessionID_t,
+        raw: *const ffi::c_char,
+        len: u64,
+    ) {
+        let session_id = SessionId::borrow_ffi(session);
+        let raw = unsafe { raw_bytes(raw, len) };
+        #[cfg(feature = "metrics")]
+        engine_metrics::record_raw(&session_id, Direction::Out, raw);
+
+        if let Err(payload) = catch_unwind(|| {
+            let this = unsafe { &*(data as *const C) };
+            this.on_raw_outgoing(&session_id, raw);
+        }) {
+            handle_application_panic("on_raw_outgoing", &session_id, payload);
+        }
+    }
+}
+
+/// Borrow raw message given by the FIX engine.
+///
+/// # Safety
+///
+/// `raw` must point to `len` readable bytes, or be null.
+unsafe fn raw_bytes<'a>(raw: *const ffi::c_char, len: u64) -> &'a [u8] {
+    if raw.is_null() {
+        return &[];
+    }
+    unsafe { std::slice::from_raw_parts(raw.cast(), len as usize) }
+}
+
+/// Tell callbacks a session has been removed from its handler.
+///
+/// # Safety
+///
+/// `callbacks` must come from [`Application::callbacks_ptr`] of an application still alive.
+pub(crate) unsafe fn notify_session_removed<C: ApplicationCallback>(
+    callbacks: *const C,
+    session_id: &SessionId,
+) {
+    // Callbacks are only borrowed, and never used again by this function after a panic.
+    if let Err(payload) = catch_unwind(AssertUnwindSafe(|| {
+        let this = unsafe { &*callbacks };
+        this.on_remove(session_id);
+    })) {
+        handle_application_panic("on_remove", session_id, payload);
     }
 }
 
@@ -0,0 +1,297 @@
+use std::{
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex, MutexGuard, PoisonError,
+    },
+};
+
+use crate::{
+    AdminMsgKind, ApplicationCallback, ConnectError, DisconnectReason, LogonContext, LogoutContext,
+    Message, MsgFromAdminError, MsgFromAppError, MsgToAppError, SessionId,
+};
+
+/// Same as [`ApplicationCallback`], with callbacks taking `&mut self`.
+///
+/// Wrap implementation in [`ExclusiveApplication`] to give it to the FIX engine.
+#[allow(unused_variables)]
+pub trait ApplicationCallbackMut {
+    /// On session created.
+    fn on_create(&mut self, session: &SessionId) {}
+
+    /// On session logon.
+    fn on_logon(&mut self, session: &SessionId) {}
+
+    /// Same as [`Self::on_logon`], with logon details.
+    ///
+    /// See [`ApplicationCallback::on_logon_with_context`].
+    fn on_logon_with_context(&mut self, session: &SessionId, context: LogonContext) {
+        self.on_logon(session)
+    }
+
+    /// On session logout.
+    fn on_logout(&mut self, session: &SessionId) {}
+
+    /// Same as [`Self::on_logout`], with logout details.
+    ///
+    /// See [`ApplicationCallback::on_logout_with_context`].
+    fn on_logout_with_context(&mut self, session: &SessionId, context: LogoutContext) {
+        self.on_logout(session)
+    }
+
+    /// Called before sending message to admin level.
+    ///
+    /// See [`ApplicationCallback::on_msg_to_admin`].
+    fn on_msg_to_admin(&mut self, msg: &mut Message, session: &SessionId) {}
+
+    /// Called before sending message to application level.
+    ///
+    /// See [`ApplicationCallback::on_msg_to_app`].
+    fn on_msg_to_app(
+        &mut self,
+        msg: &mut Message,
+        session: &SessionId,
+    ) -> Result<(), MsgToAppError> {
+        Ok(())
+    }
+
+    /// Called after received a message from admin level.
+    fn on_msg_from_admin(
+        &mut self,
+        msg: Message,
+        session: &SessionId,
+    ) -> Result<(), MsgFromAdminError> {
+        Ok(())
+    }
+
+    /// Same as [`Self::on_msg_from_admin`], with message already classified.
+    ///
+    /// See [`ApplicationCallback::on_msg_from_admin_with_kind`].
+    fn on_msg_from_admin_with_kind(
+        &mut self,
+        kind: AdminMsgKind,
+        msg: Message,
+        session: &SessionId,
+    ) -> Result<(), MsgFromAdminError> {
+        self.on_msg_from_admin(msg, session)
+    }
+
+    /// Called after received a message from application level.
+    fn on_msg_from_app(
+        &mut self,
+        msg: Message,
+        session: &SessionId,
+    ) -> Result<(), MsgFromAppError> {
+        Ok(())
+    }
+
+    /// Called when a resend request is about to be sent to counterparty.
+    fn on_resend_begin(&mut self, session: &SessionId, begin: u32, end: u32) {}
+
+    /// Called once every message requested by the last resend request has been received.
+    fn on_resend_complete(&mut self, session: &SessionId) {}
+
+    /// Called when an initiator session failed to connect to its counterparty.
+    fn on_connect_failed(&mut self, session: &SessionId, attempt: u32, error: ConnectError) {}
+
+    /// Called once a socket connection is bound to its session.
+    fn on_connect(&mut self, session: &SessionId, peer: Option<SocketAddr>) {}
+
+    /// Called once the socket connection of a session has been closed.
+    fn on_disconnect(&mut self, session: &SessionId, reason: DisconnectReason) {}
+
+    /// Called once session has been removed from its handler.
+    fn on_remove(&mut self, session: &SessionId) {}
+
+    /// Called with the exact bytes received from counterparty, before they are parsed.
+    fn on_raw_incoming(&mut self, session: &SessionId, raw: &[u8]) {}
+
+    /// Called with the exact bytes about to be written to counterparty, once serialized.
+    fn on_raw_outgoing(&mut self, session: &SessionId, raw: &[u8]) {}
+}
+
+thread_local! {
+    static THREAD_TOKEN: u8 = const { 0 };
+}
+
+fn thread_token() -> usize {
+    THREAD_TOKEN.with(|token| token as *const u8 as usize)
+}
+
+/// Run [`ApplicationCallbackMut`] callbacks one at a time.
+///
+/// Every callback locks a mutex around the wrapped state, so no synchronization is left to the
+/// user. With `FixSocketServerKind::SingleThreaded` all callbacks already run on the engine thread,
+/// so lock is never contended. Threaded kinds run one thread per session: sessions then wait for
+/// each other while a callback is running.
+///
+/// Callbacks triggered from another callback on the same thread (e.g. `on_msg_to_app` of a
+/// message sent from `on_msg_from_app`) cannot get `&mut self` again: they are skipped, and the
+/// message is sent as is.
+///
+/// ```rust
+/// # use quickfix::*;
+/// #[derive(Default)]
+/// struct OrderCounter {
+///     orders: usize,
+/// }
+///
+/// impl ApplicationCallbackMut for OrderCounter {
+///     fn on_msg_from_app(
+///         &mut self,
+///         _msg: Message,
+///         _session: &SessionId,
+///     ) -> Result<(), MsgFromAppError> {
+///         self.orders += 1;
+///         Ok(())
+///     }
+/// }
+///
+/// let callbacks = ExclusiveApplication::new(OrderCounter::default());
+/// let _application = Application::try_new(&callbacks)?;
+/// // ... run acceptor / initiator ...
+/// assert_eq!(callbacks.lock().orders, 0);
+/// # Ok::<(), QuickFixError>(())
+/// ```
+#[derive(Debug)]
+pub struct ExclusiveApplication<C> {
+    inner: Mutex<C>,
+    // Token of the thread currently running a callback, 0 when none.
+    owner: AtomicUsize,
+}
+
+impl<C: ApplicationCallbackMut> ExclusiveApplication<C> {
+    /// Wrap callbacks.
+    pub fn new(inner: C) -> Self {
+        Self {
+            inner: Mutex::new(inner),
+            owner: AtomicUsize::new(0),
+        }
+    }
+
+    /// Lock wrapped callbacks, to read or update their state.
+    ///
+    /// FIX engine callbacks wait until guard is dropped.
+    pub fn lock(&self) -> MutexGuard<'_, C> {
+        self.inner.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Unwrap callbacks.
+    pub fn into_inner(self) -> C {
+        self.inner
+            .into_inner()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn with<R>(&self, skipped: R, f: impl FnOnce(&mut C) -> R) -> R {
+        let token = thread_token();
+        if self.owner.load(Ordering::Acquire) == token {
+            return skipped;
+        }
+
+        let mut inner = self.lock();
+        let _owner = OwnerGuard::new(&self.owner, token);
+        f(&mut inner)
+    }
+}
+
+// Release ownership even if callback panics.
+struct OwnerGuard<'a>(&'a AtomicUsize);
+
+impl<'a> OwnerGuard<'a> {
+    fn new(owner: &'a AtomicUsize, token: usize) -> Self {
+        owner.store(token, Ordering::Release);
+        Self(owner)
+    }
+}
+
+impl Drop for OwnerGuard<'_> {
+    fn drop(&mut self) {
+        self.0.store(0, Ordering::Release);
+    }
+}
+
+impl<C: ApplicationCallbackMut> ApplicationCallback for ExclusiveApplication<C> {
+    fn on_create(&self, session: &SessionId) {
+        self.with((), |inner| inner.on_create(session))
+    }
+
+    fn on_logon(&self, session: &SessionId) {
+        self.with((), |inner| inner.on_logon(session))
+    }
+
+    fn on_logon_with_context(&self, session: &SessionId, context: LogonContext) {
+        self.with((), |inner| inner.on_logon_with_context(session, context))
+    }
+
+    fn on_logout(&self, session: &SessionId) {
+        self.with((), |inner| inner.on_logout(session))
+    }
+
+    fn on_logout_with_context(&self, session: &SessionId, context: LogoutContext) {
+        self.with((), |inner| inner.on_logout_with_context(session, context))
+    }
+
+    fn on_msg_to_admin(&self, msg: &mut Message, session: &SessionId) {
+        self.with((), |inner| inner.on_msg_to_admin(msg, session))
+    }
+
+    fn on_msg_to_app(&self, msg: &mut Message, session: &SessionId) -> Result<(), MsgToAppError> {
+        self.with(Ok(()), |inner| inner.on_msg_to_app(msg, session))
+    }
+
+    fn on_msg_from_admin(
+        &self,
+        msg: Message,
+        session: &SessionId,
+    ) -> Result<(), MsgFromAdminError> {
+        self.with(Ok(()), |inner| inner.on_msg_from_admin(msg, session))
+    }
+
+    fn on_msg_from_admin_with_kind(
+        &self,
+        kind: AdminMsgKind,
+        msg: Message,
+        session: &SessionId,
+    ) -> Result<(), MsgFromAdminError> {
+        self.with(Ok(()), |inner| {
+            inner.on_msg_from_admin_with_kind(kind, msg, session)
+        })
+    }
+
+    fn on_msg_from_app(&self, msg: Message, session: &SessionId) -> Result<(), MsgFromAppError> {
+        self.with(Ok(()), |inner| inner.on_msg_from_app(msg, session))
+    }
+
+    fn on_resend_begin(&self, session: &SessionId, begin: u32, end: u32) {
+        self.with((), |inner| inner.on_resend_begin(session, begin, end))
+    }
+
+    fn on_resend_complete(&self, session: &SessionId) {
+        self.with((), |inner| inner.on_resend_complete(session))
+    }
+
+    fn on_connect_failed(&self, session: &SessionId, attempt: u32, error: ConnectError) {
+        self.with((), |inner| inner.on_connect_failed(session, attempt, error))
+    }
+
+    fn on_connect(&self, session: &SessionId, peer: Option<SocketAddr>) {
+        self.with((), |inner| inner.on_connect(session, peer))
+    }
+
+    fn on_disconnect(&self, session: &SessionId, reason: DisconnectReason) {
+        self.with((), |inner| inner.on_disconnect(session, reason))
+    }
+
+    fn on_remove(&self, session: &SessionId) {
+        self.with((), |inner| inner.on_remove(session))
+    }
+
+    fn on_raw_incoming(&self, session: &SessionId, raw: &[u8]) {
+        self.with((), |inner| inner.on_raw_incoming(session, raw))
+    }
+
+    fn on_raw_outgoing(&self, session: &SessionId, raw: &[u8]) {
+        self.with((), |inner| inner.on_raw_outgoing(session, raw))
+    }
+}
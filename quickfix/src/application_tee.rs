@@ -0,0 +1,185 @@
+use std::{fmt, net::SocketAddr};
+
+use crate::{
+    AdminMsgKind, ApplicationCallback, ConnectError, DisconnectReason, LogonContext, LogoutContext,
+    Message, MsgFromAdminError, MsgFromAppError, MsgToAppError, SessionId,
+};
+
+/// Forward every callback to multiple applications.
+///
+/// Children are called in order. Every child always sees every event, even when a previous one
+/// failed: the first error is then returned to the FIX engine and the following ones are ignored.
+/// Received messages are cloned for every child but the last one. Messages about to be sent are
+/// shared, so children see changes made by previous ones.
+///
+/// ```rust
+/// # use quickfix::*;
+/// struct Business;
+/// impl ApplicationCallback for Business {}
+///
+/// struct Audit;
+/// impl ApplicationCallback for Audit {}
+///
+/// let (business, audit) = (Business, Audit);
+/// let tee = ApplicationTee::new(vec![&business, &audit]);
+/// let _app = Application::try_new(&tee)?;
+/// # Ok::<(), QuickFixError>(())
+/// ```
+pub struct ApplicationTee<'a>(Vec<&'a dyn ApplicationCallback>);
+
+impl<'a> ApplicationTee<'a> {
+    /// Create tee forwarding callbacks to `children`.
+    pub fn new(children: Vec<&'a dyn ApplicationCallback>) -> Self {
+        Self(children)
+    }
+
+    /// Number of children.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Check if there is no children.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn dispatch_owned<E>(
+        &self,
+        msg: Message,
+        f: impl Fn(&dyn ApplicationCallback, Message) -> Result<(), E>,
+    ) -> Result<(), E> {
+        let Some((last, others)) = self.0.split_last() else {
+            return Ok(());
+        };
+
+        let mut output = Ok(());
+        for child in others {
+            let result = f(*child, msg.clone());
+            if output.is_ok() {
+                output = result;
+            }
+        }
+        let result = f(*last, msg);
+        output.and(result)
+    }
+}
+
+impl fmt::Debug for ApplicationTee<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ApplicationTee")
+            .field("len", &self.0.len())
+            .finish()
+    }
+}
+
+impl ApplicationCallback for ApplicationTee<'_> {
+    fn on_create(&self, session: &SessionId) {
+        self.0.iter().for_each(|child| child.on_create(session));
+    }
+
+    fn on_logon(&self, session: &SessionId) {
+        self.0.iter().for_each(|child| child.on_logon(session));
+    }
+
+    fn on_logon_with_context(&self, session: &SessionId, context: LogonContext) {
+        self.0
+            .iter()
+            .for_each(|child| child.on_logon_with_context(session, context));
+    }
+
+    fn on_logout(&self, session: &SessionId) {
+        self.0.iter().for_each(|child| child.on_logout(session));
+    }
+
+    fn on_logout_with_context(&self, session: &SessionId, context: LogoutContext) {
+        self.0
+            .iter()
+            .for_each(|child| child.on_logout_with_context(session, context.clone()));
+    }
+
+    fn on_msg_to_admin(&self, msg: &mut Message, session: &SessionId) {
+        self.0
+            .iter()
+            .for_each(|child| child.on_msg_to_admin(msg, session));
+    }
+
+    fn on_msg_to_app(&self, msg: &mut Message, session: &SessionId) -> Result<(), MsgToAppError> {
+        let mut output = Ok(());
+        for child in &self.0 {
+            let result = child.on_msg_to_app(msg, session);
+            if output.is_ok() {
+                output = result;
+            }
+        }
+        output
+    }
+
+    fn on_msg_from_admin(
+        &self,
+        msg: Message,
+        session: &SessionId,
+    ) -> Result<(), MsgFromAdminError> {
+        self.dispatch_owned(msg, |child, msg| child.on_msg_from_admin(msg, session))
+    }
+
+    fn on_msg_from_admin_with_kind(
+        &self,
+        kind: AdminMsgKind,
+        msg: Message,
+        session: &SessionId,
+    ) -> Result<(), MsgFromAdminError> {
+        self.dispatch_owned(msg, |child, msg| {
+            child.on_msg_from_admin_with_kind(kind, msg, session)
+        })
+    }
+
+    fn on_msg_from_app(&self, msg: Message, session: &SessionId) -> Result<(), MsgFromAppError> {
+        self.dispatch_owned(msg, |child, msg| child.on_msg_from_app(msg, session))
+    }
+
+    fn on_resend_begin(&self, session: &SessionId, begin: u32, end: u32) {
+        self.0
+            .iter()
+            .for_each(|child| child.on_resend_begin(session, begin, end));
+    }
+
+    fn on_resend_complete(&self, session: &SessionId) {
+        self.0
+            .iter()
+            .for_each(|child| child.on_resend_complete(session));
+    }
+
+    fn on_connect_failed(&self, session: &SessionId, attempt: u32, error: ConnectError) {
+        self.0
+            .iter()
+            .for_each(|child| child.on_connect_failed(session, attempt, error.clone()));
+    }
+
+    fn on_connect(&self, session: &SessionId, peer: Option<SocketAddr>) {
+        self.0
+            .iter()
+            .for_each(|child| child.on_connect(session, peer));
+    }
+
+    fn on_disconnect(&self, session: &SessionId, reason: DisconnectReason) {
+        self.0
+            .iter()
+            .for_each(|child| child.on_disconnect(session, reason));
+    }
+
+    fn on_remove(&self, session: &SessionId) {
+        self.0.iter().for_each(|child| child.on_remove(session));
+    }
+
+    fn on_raw_incoming(&self, session: &SessionId, raw: &[u8]) {
+        self.0
+            .iter()
+            .for_each(|child| child.on_raw_incoming(session, raw));
+    }
+
+    fn on_raw_outgoing(&self, session: &SessionId, raw: &[u8]) {
+        self.0
+            .iter()
+            .for_each(|child| child.on_raw_outgoing(session, raw));
+    }
+}
@@ -0,0 +1,189 @@
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Mutex, PoisonError,
+};
+
+use tokio::sync::mpsc::{self, error::TrySendError};
+use tokio_stream::{wrappers::ReceiverStream, Stream};
+
+use crate::{
+    ApplicationCallback, Message, MsgFromAdminError, MsgFromAppError, MsgToAppError, SessionId,
+};
+
+/// Event emitted by [`AsyncApplication`].
+#[derive(Debug, Clone)]
+pub enum FixEvent {
+    /// Session has been created.
+    Created(SessionId),
+    /// Session logged on.
+    Logon(SessionId),
+    /// Session logged out.
+    Logout(SessionId),
+    /// Admin message has been received.
+    FromAdmin(SessionId, Message),
+    /// Application message has been received.
+    FromApp(SessionId, Message),
+    /// Application message is about to be sent.
+    ToApp(SessionId, Message),
+    /// Events have been dropped because the channel was full, see [`OverflowPolicy::Error`].
+    Overflow {
+        /// Number of dropped events since previous overflow event.
+        dropped: u64,
+    },
+}
+
+/// What to do with an event when [`AsyncApplication`] channel is full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverflowPolicy {
+    /// Wait for the consumer to make room.
+    ///
+    /// This stalls the FIX engine thread running the callback, so every session it handles.
+    /// Waiting from an async task panics, so do not use it when FIX engine is driven from an
+    /// async task with `ConnectionHandler::poll`.
+    #[default]
+    Block,
+    /// Silently drop the event.
+    Drop,
+    /// Drop the event and tell the consumer with a [`FixEvent::Overflow`] event, sent as soon as
+    /// there is room again.
+    Error,
+}
+
+/// Application callbacks forwarding FIX events to an async stream.
+///
+/// Events are queued in a bounded channel of `capacity` events, [`OverflowPolicy`] tells what
+/// happens when it is full. Callbacks never fail: received messages are always accepted by the
+/// FIX engine and sent messages are never vetoed.
+///
+/// ```rust,no_run
+/// # use quickfix::*;
+/// # use tokio_stream::StreamExt;
+/// # async fn run(settings: SessionSettings) -> Result<(), QuickFixError> {
+/// let callbacks = AsyncApplication::new(1024, OverflowPolicy::Block);
+/// let mut events = callbacks.events().expect("events already taken");
+///
+/// let application = Application::try_new(&callbacks)?;
+/// let store_factory = MemoryMessageStoreFactory::new();
+/// let mut acceptor = Acceptor::try_new(
+///     &settings,
+///     &application,
+///     &store_factory,
+///     FixSocketServerKind::SingleThreaded,
+/// )?;
+/// acceptor.start()?;
+///
+/// while let Some(event) = events.next().await {
+///     println!("{event:?}");
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct AsyncApplication {
+    sender: mpsc::Sender<FixEvent>,
+    receiver: Mutex<Option<mpsc::Receiver<FixEvent>>>,
+    policy: OverflowPolicy,
+    dropped: AtomicU64,
+    pending_overflow: AtomicU64,
+}
+
+impl AsyncApplication {
+    /// Create new adapter queuing up to `capacity` events.
+    ///
+    /// # Panics
+    ///
+    /// When `capacity` is 0.
+    pub fn new(capacity: usize, policy: OverflowPolicy) -> Self {
+        let (sender, receiver) = mpsc::channel(capacity);
+        Self {
+            sender,
+            receiver: Mutex::new(Some(receiver)),
+            policy,
+            dropped: AtomicU64::new(0),
+            pending_overflow: AtomicU64::new(0),
+        }
+    }
+
+    /// Take stream of events.
+    ///
+    /// There is only one stream per adapter: return `None` once it has been taken.
+    pub fn events(&self) -> Option<impl Stream<Item = FixEvent>> {
+        self.receiver
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .take()
+            .map(ReceiverStream::new)
+    }
+
+    /// Number of events dropped so far because channel was full.
+    pub fn dropped_count(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+
+    fn emit(&self, event: FixEvent) {
+        self.flush_overflow();
+
+        // Other errors only mean stream has been dropped: nobody cares about events anymore.
+        if let Err(TrySendError::Full(event)) = self.sender.try_send(event) {
+            match self.policy {
+                OverflowPolicy::Block => {
+                    let _ = self.sender.blocking_send(event);
+                }
+                OverflowPolicy::Drop => {
+                    self.dropped.fetch_add(1, Ordering::Relaxed);
+                }
+                OverflowPolicy::Error => {
+                    self.dropped.fetch_add(1, Ordering::Relaxed);
+                    self.pending_overflow.fetch_add(1, Ordering::Relaxed);
+                }
+            }
+        }
+    }
+
+    fn flush_overflow(&self) {
+        let dropped = self.pending_overflow.swap(0, Ordering::Relaxed);
+        if dropped == 0 {
+            return;
+        }
+        if self
+            .sender
+            .try_send(FixEvent::Overflow { dropped })
+            .is_err()
+        {
+            self.pending_overflow.fetch_add(dropped, Ordering::Relaxed);
+        }
+    }
+}
+
+impl ApplicationCallback for AsyncApplication {
+    fn on_create(&self, session: &SessionId) {
+        self.emit(FixEvent::Created(session.clone()));
+    }
+
+    fn on_logon(&self, session: &SessionId) {
+        self.emit(FixEvent::Logon(session.clone()));
+    }
+
+    fn on_logout(&self, session: &SessionId) {
+        self.emit(FixEvent::Logout(session.clone()));
+    }
+
+    fn on_msg_to_app(&self, msg: &mut Message, session: &SessionId) -> Result<(), MsgToAppError> {
+        self.emit(FixEvent::ToApp(session.clone(), msg.clone()));
+        Ok(())
+    }
+
+    fn on_msg_from_admin(
+        &self,
+        msg: Message,
+        session: &SessionId,
+    ) -> Result<(), MsgFromAdminError> {
+        self.emit(FixEvent::FromAdmin(session.clone(), msg));
+        Ok(())
+    }
+
+    fn on_msg_from_app(&self, msg: Message, session: &SessionId) -> Result<(), MsgFromAppError> {
+        self.emit(FixEvent::FromApp(session.clone(), msg));
+        Ok(())
+    }
+}
@@ -0,0 +1,309 @@
+//! Run acceptors / initiators from async code without blocking the runtime.
+//!
+//! Handlers of this module wrap [`Acceptor`] / [`Initiator`]: every blocking call (start, send,
+//! shutdown) runs on tokio blocking threads, and logon state is published on a watch channel by
+//! application callbacks, so nothing has to poll it.
+//!
+//! Methods must be called from within a tokio runtime.
+//!
+//! Module is not named `tokio`: it would make `tokio` ambiguous in code glob importing this crate.
+//!
+//! ```rust,no_run
+//! # use std::{sync::Arc, time::Duration};
+//! # use quickfix::{async_handler::AsyncInitiator, *};
+//! # async fn run(settings: SessionSettings, order: Message) -> Result<(), QuickFixError> {
+//! struct MyApplication;
+//!
+//! impl ApplicationCallback for MyApplication {}
+//!
+//! let initiator = AsyncInitiator::start(
+//!     &settings,
+//!     Arc::new(MyApplication),
+//!     Arc::new(MemoryMessageStoreFactory::new()),
+//!     FixSocketServerKind::SingleThreaded,
+//! )
+//! .await?;
+//! initiator.wait_logged_on(Duration::from_secs(10)).await?;
+//!
+//! let session_id = initiator.handler().session_ids()?.remove(0);
+//! initiator.send(order, &session_id).await?;
+//!
+//! initiator.shutdown(Duration::from_secs(5)).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{fmt, net::SocketAddr, panic, sync::Arc, time::Duration};
+
+use tokio::{
+    sync::watch,
+    task::{self, JoinHandle},
+    time,
+};
+
+use crate::{
+    send_to_target, Acceptor, AdminMsgKind, Application, ApplicationCallback, ConnectError,
+    ConnectionHandler, DisconnectReason, FfiMessageStoreFactory, FixSocketServerKind, Initiator,
+    LogonContext, LogoutContext, MemoryMessageStoreFactory, Message, MsgFromAdminError,
+    MsgFromAppError, MsgToAppError, QuickFixError, SessionContainer, SessionId, SessionSettings,
+    ShutdownReport,
+};
+
+/// Async [`Initiator`], see [module documentation](self).
+pub type AsyncInitiator<A, S = MemoryMessageStoreFactory> =
+    AsyncHandler<Initiator<'static, LogonWatcher<A>, S>>;
+
+/// Async [`Acceptor`], see [module documentation](self).
+pub type AsyncAcceptor<A, S = MemoryMessageStoreFactory> =
+    AsyncHandler<Acceptor<'static, LogonWatcher<A>, S>>;
+
+/// Started connection handler, driven from async code.
+///
+/// Use [`Self::shutdown`] to stop it: dropping it stops handler on the current thread, which
+/// blocks the runtime until sessions are logged out.
+#[derive(Debug)]
+pub struct AsyncHandler<H> {
+    handler: H,
+    logged_on: watch::Receiver<usize>,
+}
+
+impl<A, S> AsyncInitiator<A, S>
+where
+    A: ApplicationCallback + Send + Sync + 'static,
+    S: FfiMessageStoreFactory + Send + Sync + 'static,
+{
+    /// Create initiator from its mandatory components, and start it on a blocking thread.
+    ///
+    /// Sessions start connecting right away, see [`Self::wait_logged_on`].
+    pub async fn start(
+        settings: &SessionSettings,
+        callbacks: Arc<A>,
+        store_factory: Arc<S>,
+        server_mode: FixSocketServerKind,
+    ) -> Result<Self, QuickFixError> {
+        let (callbacks, logged_on) = LogonWatcher::new(callbacks);
+        let application = Application::try_new_shared(Arc::new(callbacks))?;
+        let handler =
+            Initiator::try_new_shared(settings, Arc::new(application), store_factory, server_mode)?;
+        AsyncHandler::spawn_start(handler, logged_on).await
+    }
+}
+
+impl<A, S> AsyncAcceptor<A, S>
+where
+    A: ApplicationCallback + Send + Sync + 'static,
+    S: FfiMessageStoreFactory + Send + Sync + 'static,
+{
+    /// Create acceptor from its mandatory components, and start it on a blocking thread.
+    ///
+    /// Sockets are listening once this returns.
+    pub async fn start(
+        settings: &SessionSettings,
+        callbacks: Arc<A>,
+        store_factory: Arc<S>,
+        server_mode: FixSocketServerKind,
+    ) -> Result<Self, QuickFixError> {
+        let (callbacks, logged_on) = LogonWatcher::new(callbacks);
+        let application = Application::try_new_shared(Arc::new(callbacks))?;
+        let handler =
+            Acceptor::try_new_shared(settings, Arc::new(application), store_factory, server_mode)?;
+        AsyncHandler::spawn_start(handler, logged_on).await
+    }
+}
+
+impl<H> AsyncHandler<H>
+where
+    H: ConnectionHandler + SessionContainer + Send + 'static,
+{
+    async fn spawn_start(
+        mut handler: H,
+        logged_on: watch::Receiver<usize>,
+    ) -> Result<Self, QuickFixError> {
+        let handler = join(task::spawn_blocking(move || {
+            handler.start()?;
+            Ok::<_, QuickFixError>(handler)
+        }))
+        .await?;
+        Ok(Self { handler, logged_on })
+    }
+
+    /// Get wrapped handler, to inspect its sessions.
+    ///
+    /// Its methods are blocking: only call quick ones (ie: `session_ids`, `is_logged_on`) from
+    /// async code.
+    pub fn handler(&self) -> &H {
+        &self.handler
+    }
+
+    /// Number of sessions currently logged on.
+    pub fn logged_on_count(&self) -> usize {
+        *self.logged_on.borrow()
+    }
+
+    /// Wait until at least one session of the handler is logged on.
+    ///
+    /// Fails with [`QuickFixError::SessionNotLoggedOn`] when none logged on within `timeout`.
+    pub async fn wait_logged_on(&self, timeout: Duration) -> Result<(), QuickFixError> {
+        let mut logged_on = self.logged_on.clone();
+        match time::timeout(timeout, logged_on.wait_for(|count| *count > 0)).await {
+            Ok(Ok(_)) => Ok(()),
+            // Sender lives in handler application, which outlives `self`.
+            Ok(Err(_)) => unreachable!("logon watcher dropped before handler"),
+            Err(_) => Err(QuickFixError::SessionNotLoggedOn(format!(
+                "No session logged on after {timeout:?}"
+            ))),
+        }
+    }
+
+    /// Send message to target design in session ID, from a blocking thread.
+    ///
+    /// Errors are the same as [`send_to_target`].
+    pub async fn send(&self, msg: Message, session_id: &SessionId) -> Result<(), QuickFixError> {
+        let session_id = session_id.clone();
+        join(task::spawn_blocking(move || {
+            send_to_target(msg, &session_id)
+        }))
+        .await
+    }
+
+    /// Logout every session, then stop and release handler from a blocking thread.
+    ///
+    /// See [`ConnectionHandler::shutdown`]: sessions still logged on after `timeout` are
+    /// disconnected.
+    pub async fn shutdown(self, timeout: Duration) -> Result<ShutdownReport, QuickFixError> {
+        let mut handler = self.handler;
+        join(task::spawn_blocking(move || {
+            let report = handler.shutdown(timeout);
+            drop(handler);
+            report
+        }))
+        .await
+    }
+}
+
+/// Wait for blocking task, forwarding its panic if any.
+async fn join<T>(task: JoinHandle<T>) -> T {
+    task.await
+        .unwrap_or_else(|err| panic::resume_unwind(err.into_panic()))
+}
+
+/// Application callbacks of async handlers, counting logged on sessions.
+///
+/// Every callback is forwarded to wrapped callbacks.
+pub struct LogonWatcher<A> {
+    callbacks: Arc<A>,
+    logged_on: watch::Sender<usize>,
+}
+
+impl<A> LogonWatcher<A> {
+    fn new(callbacks: Arc<A>) -> (Self, watch::Receiver<usize>) {
+        let (logged_on, receiver) = watch::channel(0);
+        (
+            Self {
+                callbacks,
+                logged_on,
+            },
+            receiver,
+        )
+    }
+
+    /// Get wrapped callbacks.
+    pub fn callbacks(&self) -> &A {
+        &self.callbacks
+    }
+}
+
+impl<A> fmt::Debug for LogonWatcher<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LogonWatcher")
+            .field("logged_on", &*self.logged_on.borrow())
+            .finish()
+    }
+}
+
+impl<A: ApplicationCallback> ApplicationCallback for LogonWatcher<A> {
+    fn on_create(&self, session: &SessionId) {
+        self.callbacks.on_create(session);
+    }
+
+    fn on_logon(&self, session: &SessionId) {
+        self.callbacks.on_logon(session);
+    }
+
+    fn on_logon_with_context(&self, session: &SessionId, context: LogonContext) {
+        self.callbacks.on_logon_with_context(session, context);
+        self.logged_on.send_modify(|count| *count += 1);
+    }
+
+    fn on_logout(&self, session: &SessionId) {
+        self.callbacks.on_logout(session);
+    }
+
+    fn on_logout_with_context(&self, session: &SessionId, context: LogoutContext) {
+        self.callbacks.on_logout_with_context(session, context);
+        self.logged_on
+            .send_modify(|count| *count = count.saturating_sub(1));
+    }
+
+    fn on_msg_to_admin(&self, msg: &mut Message, session: &SessionId) {
+        self.callbacks.on_msg_to_admin(msg, session);
+    }
+
+    fn on_msg_to_app(&self, msg: &mut Message, session: &SessionId) -> Result<(), MsgToAppError> {
+        self.callbacks.on_msg_to_app(msg, session)
+    }
+
+    fn on_msg_from_admin(
+        &self,
+        msg: Message,
+        session: &SessionId,
+    ) -> Result<(), MsgFromAdminError> {
+        self.callbacks.on_msg_from_admin(msg, session)
+    }
+
+    fn on_msg_from_admin_with_kind(
+        &self,
+        kind: AdminMsgKind,
+        msg: Message,
+        session: &SessionId,
+    ) -> Result<(), MsgFromAdminError> {
+        self.callbacks
+            .on_msg_from_admin_with_kind(kind, msg, session)
+    }
+
+    fn on_msg_from_app(&self, msg: Message, session: &SessionId) -> Result<(), MsgFromAppError> {
+        self.callbacks.on_msg_from_app(msg, session)
+    }
+
+    fn on_resend_begin(&self, session: &SessionId, begin: u32, end: u32) {
+        self.callbacks.on_resend_begin(session, begin, end);
+    }
+
+    fn on_resend_complete(&self, session: &SessionId) {
+        self.callbacks.on_resend_complete(session);
+    }
+
+    fn on_connect_failed(&self, session: &SessionId, attempt: u32, error: ConnectError) {
+        self.callbacks.on_connect_failed(session, attempt, error);
+    }
+
+    fn on_connect(&self, session: &SessionId, peer: Option<SocketAddr>) {
+        self.callbacks.on_connect(session, peer);
+    }
+
+    fn on_disconnect(&self, session: &SessionId, reason: DisconnectReason) {
+        self.callbacks.on_disconnect(session, reason);
+    }
+
+    fn on_remove(&self, session: &SessionId) {
+        self.callbacks.on_remove(session);
+    }
+
+    fn on_raw_incoming(&self, session: &SessionId, raw: &[u8]) {
+        self.callbacks.on_raw_incoming(session, raw);
+    }
+
+    fn on_raw_outgoing(&self, session: &SessionId, raw: &[u8]) {
+        self.callbacks.on_raw_outgoing(session, raw);
+    }
+}
@@ -0,0 +1,144 @@
+use std::{
+    fmt,
+    sync::{Arc, Mutex, PoisonError},
+    time::{Duration, SystemTime},
+};
+
+use crate::{
+    application::forward_callbacks, log_factory::timestamp::UtcDateTime, tags, ApplicationCallback,
+    FieldMap, Message, MsgToAppError, SessionId,
+};
+
+/// Source of current time.
+pub trait Clock: Send + Sync {
+    /// Get current time.
+    fn now(&self) -> SystemTime;
+}
+
+/// Wall clock.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+/// Clock only moving when told so, for deterministic tests.
+///
+/// Clones share the same time, so keep one to drive the clock given to the engine.
+#[derive(Clone)]
+pub struct ManualClock(Arc<Mutex<SystemTime>>);
+
+impl ManualClock {
+    /// Create new clock frozen at `start`.
+    pub fn new(start: SystemTime) -> Self {
+        Self(Arc::new(Mutex::new(start)))
+    }
+
+    /// Set current time.
+    pub fn set(&self, now: SystemTime) {
+        *self.0.lock().unwrap_or_else(PoisonError::into_inner) = now;
+    }
+
+    /// Move current time forward.
+    pub fn advance(&self, duration: Duration) {
+        *self.0.lock().unwrap_or_else(PoisonError::into_inner) += duration;
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> SystemTime {
+        *self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl fmt::Debug for ManualClock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ManualClock").field(&self.now()).finish()
+    }
+}
+
+/// Application callbacks stamping SendingTime(52) of every outgoing message from a [`Clock`].
+///
+/// The C++ engine stamps messages with the wall clock. This wrapper stamps them again from
+/// `clock`, right before they are serialized, so messages on the wire only depend on it, resends
+/// included. Timer checks (heartbeats, session time, ...) can follow the same clock with
+/// `Session::tick`.
+///
+/// Every callback is then forwarded as is to the inner callbacks.
+///
+/// ```rust
+/// # use std::time::{Duration, UNIX_EPOCH};
+/// # use quickfix::*;
+/// let clock = ManualClock::new(UNIX_EPOCH + Duration::from_secs(1_700_000_000));
+/// let application = ClockedApplication::new(NullCallbacks, clock.clone());
+///
+/// clock.advance(Duration::from_secs(30));
+/// # struct NullCallbacks;
+/// # impl ApplicationCallback for NullCallbacks {}
+/// # let _ = application;
+/// ```
+///
+/// Counterparties check SendingTime accuracy: disable `CheckLatency` on both sides when clock is
+/// far from wall clock.
+#[derive(Debug)]
+pub struct ClockedApplication<C, K = SystemClock> {
+    inner: C,
+    clock: K,
+}
+
+impl<C: ApplicationCallback, K: Clock> ClockedApplication<C, K> {
+    /// Wrap `inner` callbacks.
+    pub fn new(inner: C, clock: K) -> Self {
+        Self { inner, clock }
+    }
+
+    /// Read clock.
+    pub fn clock(&self) -> &K {
+        &self.clock
+    }
+
+    /// Read inner callbacks.
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+
+    fn stamp(&self, msg: &mut Message) {
+        let sending_time = UtcDateTime::new(self.clock.now()).format("%Y%m%d-%H:%M:%S%.3f");
+        let _ = msg
+            .with_header_mut(|header| header.set_field(tags::SENDING_TIME, sending_time.as_str()));
+    }
+}
+
+impl<C: ApplicationCallback, K: Clock> ApplicationCallback for ClockedApplication<C, K> {
+    fn on_msg_to_admin(&self, msg: &mut Message, session: &SessionId) {
+        self.stamp(msg);
+        self.inner.on_msg_to_admin(msg, session)
+    }
+
+    fn on_msg_to_app(&self, msg: &mut Message, session: &SessionId) -> Result<(), MsgToAppError> {
+        self.stamp(msg);
+        self.inner.on_msg_to_app(msg, session)
+    }
+
+    forward_callbacks!(inner =>
+        on_create,
+        on_logon,
+        on_logon_with_context,
+        on_logout,
+        on_logout_with_context,
+        on_msg_from_admin,
+        on_msg_from_admin_with_kind,
+        on_msg_from_app,
+        on_resend_begin,
+        on_resend_complete,
+        on_connect_failed,
+        on_connect,
+        on_disconnect,
+        on_remove,
+        on_raw_incoming,
+        on_raw_outgoing,
+    );
+}
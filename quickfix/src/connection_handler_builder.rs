@@ -0,0 +1,208 @@
+use std::{any::Any, marker::PhantomData, sync::Arc};
+
+use quickfix_ffi::FixLogFactory_t;
+
+use crate::{
+    Acceptor, Application, ApplicationCallback, FfiLogFactory, FfiMessageStoreFactory,
+    FixSocketServerKind, Initiator, LogFactory, MemoryMessageStoreFactory, NullLogger,
+    QuickFixError, SessionSettings,
+};
+
+/// Marker of a [`ConnectionHandlerBuilder`] building an [`Acceptor`].
+#[derive(Debug)]
+pub enum ForAcceptor {}
+
+/// Marker of a [`ConnectionHandlerBuilder`] building an [`Initiator`].
+#[derive(Debug)]
+pub enum ForInitiator {}
+
+/// Builder of [`Acceptor`] / [`Initiator`].
+///
+/// Only settings and application are mandatory. By default handler uses an in memory store,
+/// a null logger and a single threaded server.
+///
+/// Built handler owns every component given to the builder, so it does not borrow anything.
+///
+/// ```rust
+/// # use quickfix::*;
+/// # fn build(settings: &SessionSettings) -> Result<(), QuickFixError> {
+/// struct MyApplication;
+///
+/// impl ApplicationCallback for MyApplication {}
+///
+/// let _initiator = Initiator::builder(settings)
+///     .application(Application::try_new_shared(std::sync::Arc::new(MyApplication))?)
+///     .store(MemoryMessageStoreFactory::new())
+///     .log(LogFactory::try_new(&NullLogger)?)
+///     .kind(FixSocketServerKind::SingleThreaded)
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ConnectionHandlerBuilder<'a, T, A, S = MemoryMessageStoreFactory>
+where
+    A: ApplicationCallback + 'static,
+    S: FfiMessageStoreFactory,
+{
+    settings: &'a SessionSettings,
+    application: Option<Arc<Application<'static, A>>>,
+    store_factory: Arc<S>,
+    log_factory: Option<(FixLogFactory_t, Arc<dyn Any + Send + Sync>)>,
+    server_mode: FixSocketServerKind,
+    phantom_target: PhantomData<T>,
+}
+
+impl<'a, T, A> ConnectionHandlerBuilder<'a, T, A>
+where
+    A: ApplicationCallback + Send + Sync + 'static,
+{
+    pub(crate) fn new(settings: &'a SessionSettings) -> Self {
+        Self {
+            settings,
+            application: None,
+            store_factory: Arc::new(MemoryMessageStoreFactory::new()),
+            log_factory: None,
+            server_mode: FixSocketServerKind::SingleThreaded,
+            phantom_target: PhantomData,
+        }
+    }
+}
+
+impl<'a, T, A, S> ConnectionHandlerBuilder<'a, T, A, S>
+where
+    A: ApplicationCallback + Send + Sync + 'static,
+    S: FfiMessageStoreFactory + Send + Sync + 'static,
+{
+    /// Set application callbacks.
+    pub fn application(self, application: Application<'static, A>) -> Self {
+        self.shared_application(Arc::new(application))
+    }
+
+    /// Set application callbacks, shared with other handlers.
+    pub fn shared_application(mut self, application: Arc<Application<'static, A>>) -> Self {
+        self.application = Some(application);
+        self
+    }
+
+    /// Set message store factory.
+    pub fn store<S2>(self, store_factory: S2) -> ConnectionHandlerBuilder<'a, T, A, S2>
+    where
+        S2: FfiMessageStoreFactory + Send + Sync + 'static,
+    {
+        self.shared_store(Arc::new(store_factory))
+    }
+
+    /// Set message store factory, shared with other handlers.
+    pub fn shared_store<S2>(self, store_factory: Arc<S2>) -> ConnectionHandlerBuilder<'a, T, A, S2>
+    where
+        S2: FfiMessageStoreFactory + Send + Sync + 'static,
+    {
+        ConnectionHandlerBuilder {
+            settings: self.settings,
+            application: self.application,
+            store_factory,
+            log_factory: self.log_factory,
+            server_mode: self.server_mode,
+            phantom_target: PhantomData,
+        }
+    }
+
+    /// Set log factory.
+    ///
+    /// Accepts a [`LogFactory`] as well as a [`crate::PerSessionLogFactory`].
+    pub fn log<L>(mut self, log_factory: L) -> Self
+    where
+        L: FfiLogFactory + Send + Sync + 'static,
+    {
+        let log_factory = Arc::new(log_factory);
+        self.log_factory = Some((
+            log_factory.as_ffi_ptr(),
+            log_factory as Arc<dyn Any + Send + Sync>,
+        ));
+        self
+    }
+
+    /// Set socket server kind.
+    pub fn kind(mut self, server_mode: FixSocketServerKind) -> Self {
+        self.server_mode = server_mode;
+        self
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn into_components(
+        self,
+    ) -> Result<
+        (
+            &'a SessionSettings,
+            Arc<Application<'static, A>>,
+            Arc<S>,
+            FixLogFactory_t,
+            FixSocketServerKind,
+            Vec<Arc<dyn Any + Send + Sync>>,
+        ),
+        QuickFixError,
+    > {
+        let application = self
+            .application
+            .ok_or_else(|| QuickFixError::invalid_argument("Missing application"))?;
+        let (log_factory_ptr, log_factory) = match self.log_factory {
+            Some(log_factory) => log_factory,
+            None => {
+                let log_factory = Arc::new(LogFactory::try_new(&NullLogger)?);
+                (log_factory.0, log_factory as Arc<dyn Any + Send + Sync>)
+            }
+        };
+
+        let owned: [Arc<dyn Any + Send + Sync>; 3] =
+            [log_factory, application.clone(), self.store_factory.clone()];
+        Ok((
+            self.settings,
+            application,
+            self.store_factory,
+            log_factory_ptr,
+            self.server_mode,
+            owned.into(),
+        ))
+    }
+}
+
+impl<A, S> ConnectionHandlerBuilder<'_, ForAcceptor, A, S>
+where
+    A: ApplicationCallback + Send + Sync + 'static,
+    S: FfiMessageStoreFactory + Send + Sync + 'static,
+{
+    /// Create the acceptor.
+    pub fn build(self) -> Result<Acceptor<'static, A, S>, QuickFixError> {
+        let (settings, application, store_factory, log_factory, server_mode, owned) =
+            self.into_components()?;
+        Acceptor::try_new_unbound(
+            settings,
+            &application,
+            &store_factory,
+            log_factory,
+            server_mode,
+            owned,
+        )
+    }
+}
+
+impl<A, S> ConnectionHandlerBuilder<'_, ForInitiator, A, S>
+where
+    A: ApplicationCallback + Send + Sync + 'static,
+    S: FfiMessageStoreFactory + Send + Sync + 'static,
+{
+    /// Create the initiator.
+    pub fn build(self) -> Result<Initiator<'static, A, S>, QuickFixError> {
+        let (settings, application, store_factory, log_factory, server_mode, owned) =
+            self.into_components()?;
+        Initiator::try_new_unbound(
+            settings,
+            &application,
+            &store_factory,
+            log_factory,
+            server_mode,
+            owned,
+        )
+    }
+}
@@ -1,11 +1,232 @@
-use std::{ffi::CString, fmt, path::Path};
+use std::{
+    ffi::{self, CString},
+    fmt,
+    mem::ManuallyDrop,
+    path::Path,
+};
 
 use quickfix_ffi::{
-    FixDataDictionary_delete, FixDataDictionary_fromPath, FixDataDictionary_new,
-    FixDataDictionary_t, FixMessage_fromStringAndDictionary,
+    FixDataDictionary_delete, FixDataDictionary_fromPath, FixDataDictionary_getFieldNameLen,
+    FixDataDictionary_getFieldTag, FixDataDictionary_getFieldTypeName,
+    FixDataDictionary_getGroupRef, FixDataDictionary_getMessageFieldAt,
+    FixDataDictionary_getMessageFieldCount, FixDataDictionary_getValueNameLen,
+    FixDataDictionary_isRequiredField, FixDataDictionary_new, FixDataDictionary_readFieldName,
+    FixDataDictionary_readValueName, FixDataDictionary_t, FixDataDictionary_validate,
+    FixMessage_fromStringAndDictionary,
+};
+
+use thiserror::Error;
+
+use crate::{
+    utils::{ffi_code_to_bool, ffi_code_to_result, read_checked_cstr},
+    Message, ParseLimits, QuickFixError,
 };
 
-use crate::{Message, QuickFixError};
+/// FIX field value type, as declared in data dictionary XML file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+#[allow(missing_docs)]
+pub enum FixFieldType {
+    String,
+    Char,
+    Price,
+    Int,
+    Amount,
+    Quantity,
+    Currency,
+    MultipleValueString,
+    MultipleStringValue,
+    MultipleCharValue,
+    Exchange,
+    UtcTimeStamp,
+    Boolean,
+    LocalMarketDate,
+    Data,
+    Float,
+    PriceOffset,
+    MonthYear,
+    DayOfMonth,
+    UtcDate,
+    UtcDateOnly,
+    UtcTimeOnly,
+    NumberInGroup,
+    Percentage,
+    SequenceNumber,
+    Length,
+    Country,
+    TzTimeOnly,
+    TzTimestamp,
+    XmlData,
+    Language,
+    /// Type not known by quickfix.
+    Unknown,
+}
+
+impl FixFieldType {
+    fn from_type_name(name: &str) -> Self {
+        match name {
+            "STRING" => Self::String,
+            "CHAR" => Self::Char,
+            "PRICE" => Self::Price,
+            "INT" => Self::Int,
+            "AMT" => Self::Amount,
+            "QTY" => Self::Quantity,
+            "CURRENCY" => Self::Currency,
+            "MULTIPLEVALUESTRING" => Self::MultipleValueString,
+            "MULTIPLESTRINGVALUE" => Self::MultipleStringValue,
+            "MULTIPLECHARVALUE" => Self::MultipleCharValue,
+            "EXCHANGE" => Self::Exchange,
+            "UTCTIMESTAMP" => Self::UtcTimeStamp,
+            "BOOLEAN" => Self::Boolean,
+            "LOCALMKTDATE" => Self::LocalMarketDate,
+            "DATA" => Self::Data,
+            "FLOAT" => Self::Float,
+            "PRICEOFFSET" => Self::PriceOffset,
+            "MONTHYEAR" => Self::MonthYear,
+            "DAYOFMONTH" => Self::DayOfMonth,
+            "UTCDATE" => Self::UtcDate,
+            "UTCDATEONLY" => Self::UtcDateOnly,
+            "UTCTIMEONLY" => Self::UtcTimeOnly,
+            "NUMINGROUP" => Self::NumberInGroup,
+            "PERCENTAGE" => Self::Percentage,
+            "SEQNUM" => Self::SequenceNumber,
+            "LENGTH" => Self::Length,
+            "COUNTRY" => Self::Country,
+            "TZTIMEONLY" => Self::TzTimeOnly,
+            "TZTIMESTAMP" => Self::TzTimestamp,
+            "XMLDATA" => Self::XmlData,
+            "LANGUAGE" => Self::Language,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+/// Field definition of a message type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldSpec {
+    /// Field tag.
+    pub tag: i32,
+    /// Field name.
+    pub name: String,
+    /// Field value type.
+    pub field_type: FixFieldType,
+    /// Whether field is required by the message type.
+    pub required: bool,
+}
+
+/// Repeating group definition of a message type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GroupSpec {
+    /// Field holding the number of group entries (ex: `NoPartyIDs`).
+    pub count_field: FieldSpec,
+    /// Tag of the first field of every group entry.
+    pub delimiter: i32,
+    /// Fields of every group entry.
+    pub fields: Vec<FieldSpec>,
+    /// Nested repeating groups.
+    pub groups: Vec<GroupSpec>,
+}
+
+/// Reason why a message does not match a [`DataDictionary`].
+#[derive(Debug, Error, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum ValidationError {
+    /// A required field is missing.
+    #[error("required tag missing: {tag}")]
+    RequiredTagMissing {
+        /// Failing tag.
+        tag: i32,
+    },
+
+    /// Field is not defined for this message type.
+    #[error("tag not defined for message: {tag}")]
+    TagNotDefinedForMessage {
+        /// Failing tag.
+        tag: i32,
+    },
+
+    /// Field has no value.
+    #[error("no tag value: {tag}")]
+    NoTagValue {
+        /// Failing tag.
+        tag: i32,
+    },
+
+    /// Field value is not one of the values allowed by the dictionary.
+    #[error("incorrect tag value: {tag}")]
+    IncorrectTagValue {
+        /// Failing tag.
+        tag: i32,
+    },
+
+    /// Field value cannot be converted to the field type.
+    #[error("incorrect data format: {tag}")]
+    IncorrectDataFormat {
+        /// Failing tag.
+        tag: i32,
+    },
+
+    /// Field is unknown by the dictionary.
+    #[error("invalid tag number: {tag}")]
+    InvalidTagNumber {
+        /// Failing tag.
+        tag: i32,
+    },
+
+    /// Field is not in the expected message part (header / body / trailer).
+    #[error("tag out of order: {tag}")]
+    TagOutOfOrder {
+        /// Failing tag.
+        tag: i32,
+    },
+
+    /// Field appears more than once.
+    #[error("repeated tag: {tag}")]
+    RepeatedTag {
+        /// Failing tag.
+        tag: i32,
+    },
+
+    /// Repeating group count does not match the number of entries.
+    #[error("repeating group count mismatch: {tag}")]
+    RepeatingGroupCountMismatch {
+        /// Group count tag.
+        tag: i32,
+    },
+
+    /// Message type is not defined by the dictionary.
+    #[error("invalid message type")]
+    InvalidMessageType,
+
+    /// Message `BeginString` does not match the dictionary.
+    #[error("unsupported version")]
+    UnsupportedVersion,
+
+    /// Any other error.
+    #[error("{0}")]
+    Other(QuickFixError),
+}
+
+impl ValidationError {
+    fn from_error(error: QuickFixError, tag: i32) -> Self {
+        match error {
+            QuickFixError::RequiredTagMissing(_) => Self::RequiredTagMissing { tag },
+            QuickFixError::TagNotDefinedForMessage(_) => Self::TagNotDefinedForMessage { tag },
+            QuickFixError::NoTagValue(_) => Self::NoTagValue { tag },
+            QuickFixError::IncorrectTagValue { .. } => Self::IncorrectTagValue { tag },
+            QuickFixError::IncorrectDataFormat(_) => Self::IncorrectDataFormat { tag },
+            QuickFixError::InvalidTagNumber(_) => Self::InvalidTagNumber { tag },
+            QuickFixError::TagOutOfOrder(_) => Self::TagOutOfOrder { tag },
+            QuickFixError::RepeatedTag(_) => Self::RepeatedTag { tag },
+            QuickFixError::RepeatingGroupCountMismatch(_) => {
+                Self::RepeatingGroupCountMismatch { tag }
+            }
+            QuickFixError::InvalidMessageType(_) => Self::InvalidMessageType,
+            QuickFixError::UnsupportedVersion(_) => Self::UnsupportedVersion,
+            error => Self::Other(error),
+        }
+    }
+}
 
 /// Represents a data dictionary for a version of FIX.
 pub struct DataDictionary(FixDataDictionary_t);
@@ -31,15 +252,170 @@ impl DataDictionary {
             .ok_or_else(QuickFixError::from_last_error)
     }
 
-    /// Create a new FIX messages using current dictionary.
+    /// Get field name from its tag.
+    pub fn field_name(&self, tag: i32) -> Option<String> {
+        read_optional_text(
+            || unsafe { FixDataDictionary_getFieldNameLen(self.0, tag) },
+            |buffer, buffer_len| unsafe {
+                FixDataDictionary_readFieldName(self.0, tag, buffer, buffer_len)
+            },
+        )
+    }
+
+    /// Get field tag from its name.
+    pub fn field_tag(&self, name: &str) -> Option<i32> {
+        let ffi_name = CString::new(name).ok()?;
+        let mut tag = 0;
+        match ffi_code_to_bool(unsafe {
+            FixDataDictionary_getFieldTag(self.0, ffi_name.as_ptr(), &mut tag)
+        }) {
+            Ok(true) => Some(tag),
+            _ => None,
+        }
+    }
+
+    /// Get field value type from its tag.
+    pub fn field_type(&self, tag: i32) -> Option<FixFieldType> {
+        unsafe { FixDataDictionary_getFieldTypeName(self.0, tag) }
+            .map(read_checked_cstr)
+            .map(|name| FixFieldType::from_type_name(&name))
+    }
+
+    /// Get description of an enumerated field value (ex: `BUY` for tag 54 and value `1`).
+    pub fn value_name(&self, tag: i32, value: &str) -> Option<String> {
+        let ffi_value = CString::new(value).ok()?;
+        read_optional_text(
+            || unsafe { FixDataDictionary_getValueNameLen(self.0, tag, ffi_value.as_ptr()) },
+            |buffer, buffer_len| unsafe {
+                FixDataDictionary_readValueName(self.0, tag, ffi_value.as_ptr(), buffer, buffer_len)
+            },
+        )
+    }
+
+    /// List fields defined for a message type, including repeating group count fields.
+    ///
+    /// Result is sorted by tag and empty for unknown message type.
+    pub fn message_fields(&self, msg_type: &str) -> Vec<FieldSpec> {
+        let Ok(ffi_msg_type) = CString::new(msg_type) else {
+            return Vec::new();
+        };
+        let field_count: u64 =
+            unsafe { FixDataDictionary_getMessageFieldCount(self.0, ffi_msg_type.as_ptr()) }
+                .try_into()
+                .unwrap_or(0);
+
+        (0..field_count)
+            .filter_map(|index| {
+                let mut tag = 0;
+                ffi_code_to_result(unsafe {
+                    FixDataDictionary_getMessageFieldAt(
+                        self.0,
+                        ffi_msg_type.as_ptr(),
+                        index,
+                        &mut tag,
+                    )
+                })
+                .ok()?;
+                Some(self.field_spec(&ffi_msg_type, tag))
+            })
+            .collect()
+    }
+
+    /// List repeating groups defined for a message type.
+    pub fn message_groups(&self, msg_type: &str) -> Vec<GroupSpec> {
+        let Ok(ffi_msg_type) = CString::new(msg_type) else {
+            return Vec::new();
+        };
+
+        self.message_fields(msg_type)
+            .into_iter()
+            .filter_map(|count_field| {
+                let mut delimiter = 0;
+                let ptr = unsafe {
+                    FixDataDictionary_getGroupRef(
+                        self.0,
+                        ffi_msg_type.as_ptr(),
+                        count_field.tag,
+                        &mut delimiter,
+                    )
+                }?;
+
+                // Group dictionary is owned by current one.
+                let group = ManuallyDrop::new(DataDictionary(ptr));
+                Some(GroupSpec {
+                    count_field,
+                    delimiter,
+                    fields: group.message_fields(msg_type),
+                    groups: group.message_groups(msg_type),
+                })
+            })
+            .collect()
+    }
+
+    fn field_spec(&self, ffi_msg_type: &CString, tag: i32) -> FieldSpec {
+        FieldSpec {
+            tag,
+            name: self.field_name(tag).unwrap_or_default(),
+            field_type: self.field_type(tag).unwrap_or(FixFieldType::Unknown),
+            required: ffi_code_to_bool(unsafe {
+                FixDataDictionary_isRequiredField(self.0, ffi_msg_type.as_ptr(), tag)
+            })
+            .unwrap_or(false),
+        }
+    }
+
+    /// Check message content against dictionary rules, outside of any session.
+    pub fn validate(&self, msg: &Message) -> Result<(), ValidationError> {
+        let mut tag = 0;
+        ffi_code_to_result(unsafe { FixDataDictionary_validate(self.0, msg.0, &mut tag) })
+            .map_err(|error| ValidationError::from_error(error, tag))
+    }
+
+    /// Create a new FIX messages using current dictionary, within default [`ParseLimits`].
     pub fn try_build_message(&self, text: &str) -> Result<Message, QuickFixError> {
-        let ffi_text = CString::new(text)?;
-        unsafe { FixMessage_fromStringAndDictionary(ffi_text.as_ptr(), self.0) }
-            .map(Message)
-            .ok_or_else(QuickFixError::from_last_error)
+        self.try_build_message_with_limits(text, &ParseLimits::default())
+    }
+
+    /// Same as [`Self::try_build_message`], within given `limits`.
+    ///
+    /// Repeating group count fields are known from this dictionary, so
+    /// [`ParseLimits::max_groups`] is checked too.
+    pub fn try_build_message_with_limits(
+        &self,
+        text: &str,
+        limits: &ParseLimits,
+    ) -> Result<Message, QuickFixError> {
+        limits.check(text.as_bytes(), Some(self))?;
+        unsafe {
+            FixMessage_fromStringAndDictionary(text.as_ptr().cast(), text.len() as u64, self.0)
+        }
+        .map(Message)
+        .ok_or_else(QuickFixError::from_last_error)
     }
 }
 
+/// Read text using the `get*Len` / `read*` FFI pattern, where 0 means "not found".
+fn read_optional_text<L, R>(get_len: L, read: R) -> Option<String>
+where
+    L: FnOnce() -> i64,
+    R: FnOnce(*mut ffi::c_char, u64) -> i8,
+{
+    // Prepare output buffer
+    let buffer_len: u64 = get_len().try_into().ok().filter(|len| *len > 0)?;
+
+    // Allocate buffer on rust side
+    let mut buffer = vec![0_u8; buffer_len as usize];
+
+    // Read text
+    if read(buffer.as_mut_ptr().cast(), buffer_len) != 1 {
+        return None;
+    }
+
+    // Convert to String
+    let text = CString::from_vec_with_nul(buffer).ok()?;
+    Some(text.to_string_lossy().to_string())
+}
+
 impl fmt::Debug for DataDictionary {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_tuple("DataDictionary").finish()
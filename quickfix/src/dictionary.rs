@@ -1,14 +1,17 @@
-use std::{ffi::CString, fmt};
+use std::{ffi::CString, fmt, iter::FusedIterator, ptr};
 
 use quickfix_ffi::{
-    FixDictionary_delete, FixDictionary_getBool, FixDictionary_getDay, FixDictionary_getDouble,
-    FixDictionary_getInt, FixDictionary_getStringLen, FixDictionary_hasKey, FixDictionary_new,
+    FixDictionaryCursor_delete, FixDictionaryCursor_next, FixDictionaryCursor_t,
+    FixDictionary_copy, FixDictionary_cursor, FixDictionary_delete, FixDictionary_getBool,
+    FixDictionary_getDay, FixDictionary_getDouble, FixDictionary_getInt,
+    FixDictionary_getStringLen, FixDictionary_hasKey, FixDictionary_merge, FixDictionary_new,
+    FixDictionary_readBool, FixDictionary_readDay, FixDictionary_readDouble, FixDictionary_readInt,
     FixDictionary_readString, FixDictionary_setBool, FixDictionary_setDay, FixDictionary_setDouble,
     FixDictionary_setInt, FixDictionary_setString, FixDictionary_t,
 };
 
 use crate::{
-    utils::{ffi_code_to_bool, ffi_code_to_result},
+    utils::{ffi_code_to_bool, ffi_code_to_result, utf8_to_string},
     DayOfWeek, ForeignPropertyGetter, ForeignPropertySetter, QuickFixError,
 };
 
@@ -46,6 +49,77 @@ impl Dictionary {
         self.ffi_get(c_key)
     }
 
+    /// Copy keys from `other` that are not defined yet in this dictionary.
+    ///
+    /// Existing values are kept. This is how QuickFIX applies `[DEFAULT]` section to sessions.
+    pub fn merge_missing_from(&mut self, other: &Dictionary) -> Result<(), QuickFixError> {
+        ffi_code_to_result(unsafe { FixDictionary_merge(self.0, other.0) })
+    }
+
+    /// Copy all keys from `other` into this dictionary, replacing existing values.
+    pub fn merge_overwrite(&mut self, other: &Dictionary) -> Result<(), QuickFixError> {
+        for entry in other.iter() {
+            let (key, value) = entry?;
+            self.set(&key, value)?;
+        }
+        Ok(())
+    }
+
+    /// Iterate over all key / value pairs of the dictionary.
+    ///
+    /// Keys are returned the way QuickFIX stores them (ie: upper case).
+    /// Content is copied when the function is called, so later dictionary changes are not visible.
+    /// Entries not valid as UTF-8 are returned as [`QuickFixError::InvalidUtf8`], and iteration
+    /// ends after any other error.
+    pub fn iter(&self) -> DictionaryIter {
+        DictionaryIter(Some(
+            unsafe { FixDictionary_cursor(self.0) }.ok_or_else(QuickFixError::from_last_error),
+        ))
+    }
+
+    /// Read integer value for a given key.
+    ///
+    /// Unlike [`Dictionary::get`], returns [`QuickFixError::ConfigError`] when key is missing
+    /// and [`QuickFixError::FieldConvertError`] when value is not an integer.
+    pub fn get_int(&self, key: &str) -> Result<i32, QuickFixError> {
+        let c_key = CString::new(key)?;
+        let mut value = 0;
+        ffi_code_to_result(unsafe { FixDictionary_readInt(self.0, c_key.as_ptr(), &mut value) })?;
+        Ok(value)
+    }
+
+    /// Read floating point value for a given key.
+    ///
+    /// Errors are the same as [`Dictionary::get_int`].
+    pub fn get_double(&self, key: &str) -> Result<f64, QuickFixError> {
+        let c_key = CString::new(key)?;
+        let mut value = 0.0;
+        ffi_code_to_result(unsafe {
+            FixDictionary_readDouble(self.0, c_key.as_ptr(), &mut value)
+        })?;
+        Ok(value)
+    }
+
+    /// Read `Y` / `N` value for a given key.
+    ///
+    /// Errors are the same as [`Dictionary::get_int`].
+    pub fn get_bool(&self, key: &str) -> Result<bool, QuickFixError> {
+        let c_key = CString::new(key)?;
+        let mut value = 0;
+        ffi_code_to_result(unsafe { FixDictionary_readBool(self.0, c_key.as_ptr(), &mut value) })?;
+        Ok(value != 0)
+    }
+
+    /// Read day of week value (ex: `Monday`, `MO`) for a given key.
+    ///
+    /// Errors are the same as [`Dictionary::get_int`].
+    pub fn get_day(&self, key: &str) -> Result<DayOfWeek, QuickFixError> {
+        let c_key = CString::new(key)?;
+        let mut value = 0;
+        ffi_code_to_result(unsafe { FixDictionary_readDay(self.0, c_key.as_ptr(), &mut value) })?;
+        DayOfWeek::try_from(value)
+    }
+
     /// Write value into dictionary for a given key.
     pub fn set<T>(&mut self, key: &str, value: T) -> Result<(), QuickFixError>
     where
@@ -162,8 +236,77 @@ impl Default for Dictionary {
     }
 }
 
+impl Clone for Dictionary {
+    fn clone(&self) -> Self {
+        Self(unsafe { FixDictionary_copy(self.0) }.expect("Fail to clone Dictionary"))
+    }
+}
+
 impl Drop for Dictionary {
     fn drop(&mut self) {
         unsafe { FixDictionary_delete(self.0) }
     }
 }
+
+/// Iterator over key / value pairs of a [`Dictionary`], see [`Dictionary::iter`].
+pub struct DictionaryIter(Option<Result<FixDictionaryCursor_t, QuickFixError>>);
+unsafe impl Send for DictionaryIter {}
+
+impl Iterator for DictionaryIter {
+    type Item = Result<(String, String), QuickFixError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let cursor = match self.0.take()? {
+            Ok(cursor) => cursor,
+            Err(err) => return Some(Err(err)),
+        };
+
+        let mut key = ptr::null();
+        let mut key_len = 0;
+        let mut value = ptr::null();
+        let mut value_len = 0;
+        match unsafe {
+            FixDictionaryCursor_next(cursor, &mut key, &mut key_len, &mut value, &mut value_len)
+        } {
+            0 => {
+                unsafe { FixDictionaryCursor_delete(cursor) };
+                None
+            }
+            1 => {
+                self.0 = Some(Ok(cursor));
+                // Copy entry now: it lives in the cursor, which is not bound to any lifetime.
+                let (key, value) = unsafe {
+                    (
+                        std::slice::from_raw_parts(key.cast::<u8>(), key_len as usize).to_vec(),
+                        std::slice::from_raw_parts(value.cast::<u8>(), value_len as usize).to_vec(),
+                    )
+                };
+                Some(utf8_to_string(None, key).and_then(|key| {
+                    let value = utf8_to_string(None, value)?;
+                    Ok((key, value))
+                }))
+            }
+            _ => {
+                let err = QuickFixError::from_last_error();
+                unsafe { FixDictionaryCursor_delete(cursor) };
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+impl FusedIterator for DictionaryIter {}
+
+impl fmt::Debug for DictionaryIter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("DictionaryIter").finish()
+    }
+}
+
+impl Drop for DictionaryIter {
+    fn drop(&mut self) {
+        if let Some(Ok(cursor)) = self.0.take() {
+            unsafe { FixDictionaryCursor_delete(cursor) }
+        }
+    }
+}
@@ -85,6 +85,19 @@ impl_dictionary_item!(SocketConnectSourcePort as i32);
 pub struct SocketConnectSourceHost<'a>(pub &'a str);
 impl_dictionary_item!(SocketConnectSourceHost as String);
 
+/// Acceptor unix domain socket path, used instead of `SocketAcceptPort`.
+///
+/// Only supported by single threaded acceptors without SSL. Stale socket file is removed first.
+pub struct SocketAcceptPath<'a>(pub &'a str);
+impl_dictionary_item!(SocketAcceptPath as String);
+
+/// Initiator unix domain socket path to connect to, used instead of `SocketConnectHost` /
+/// `SocketConnectPort`.
+///
+/// Only supported by single threaded initiators without SSL.
+pub struct SocketConnectPath<'a>(pub &'a str);
+impl_dictionary_item!(SocketConnectPath as String);
+
 /// Set SO_REUSEADDR flag when creating socket.
 pub struct SocketReuseAddress(pub bool);
 impl_dictionary_item!(SocketReuseAddress);
@@ -177,6 +190,10 @@ impl_dictionary_item!(DataDictionary as String);
 pub struct TransportDataDictionary<'a>(pub &'a str);
 impl_dictionary_item!(TransportDataDictionary as String);
 
+/// Application data dictionary XML spec path.
+pub struct AppDataDictionary<'a>(pub &'a str);
+impl_dictionary_item!(AppDataDictionary as String);
+
 /// Message store path.
 pub struct FileStorePath<'a>(pub &'a str);
 impl_dictionary_item!(FileStorePath as String);
@@ -188,6 +205,54 @@ impl_dictionary_item!(FileStorePath as String);
 pub struct FileStoreSync(pub bool);
 impl_dictionary_item!(FileStoreSync);
 
+/// PostgreSQL store database name.
+pub struct PostgreSQLStoreDatabase<'a>(pub &'a str);
+impl_dictionary_item!(PostgreSQLStoreDatabase as String);
+
+/// PostgreSQL store user name.
+pub struct PostgreSQLStoreUser<'a>(pub &'a str);
+impl_dictionary_item!(PostgreSQLStoreUser as String);
+
+/// PostgreSQL store user password.
+pub struct PostgreSQLStorePassword<'a>(pub &'a str);
+impl_dictionary_item!(PostgreSQLStorePassword as String);
+
+/// PostgreSQL store server host.
+pub struct PostgreSQLStoreHost<'a>(pub &'a str);
+impl_dictionary_item!(PostgreSQLStoreHost as String);
+
+/// PostgreSQL store server port.
+pub struct PostgreSQLStorePort(pub u16);
+impl_dictionary_item!(PostgreSQLStorePort as i32);
+
+/// Share PostgreSQL store connections between sessions using the same database.
+pub struct PostgreSQLStoreUseConnectionPool(pub bool);
+impl_dictionary_item!(PostgreSQLStoreUseConnectionPool);
+
+/// MySQL store database name.
+pub struct MySQLStoreDatabase<'a>(pub &'a str);
+impl_dictionary_item!(MySQLStoreDatabase as String);
+
+/// MySQL store user name.
+pub struct MySQLStoreUser<'a>(pub &'a str);
+impl_dictionary_item!(MySQLStoreUser as String);
+
+/// MySQL store user password.
+pub struct MySQLStorePassword<'a>(pub &'a str);
+impl_dictionary_item!(MySQLStorePassword as String);
+
+/// MySQL store server host.
+pub struct MySQLStoreHost<'a>(pub &'a str);
+impl_dictionary_item!(MySQLStoreHost as String);
+
+/// MySQL store server port.
+pub struct MySQLStorePort(pub u16);
+impl_dictionary_item!(MySQLStorePort as i32);
+
+/// Share MySQL store connections between sessions using the same database.
+pub struct MySQLStoreUseConnectionPool(pub bool);
+impl_dictionary_item!(MySQLStoreUseConnectionPool);
+
 /// Validate message comp ID.
 pub struct CheckCompID(pub bool);
 impl_dictionary_item!(CheckCompID);
@@ -272,6 +337,64 @@ impl_dictionary_item!(ClientCertificateFile as String);
 pub struct ClientCertificateKeyFile<'a>(pub &'a str);
 impl_dictionary_item!(ClientCertificateKeyFile as String);
 
+// Settings below are only read by SSL connection handlers, so they only exist when quickfix is built
+// with SSL support (`build-with-ssl` feature).
+
+/// Path of the file containing certificates of trusted certification authorities.
+#[cfg(quickfix_ssl)]
+pub struct CertificationAuthoritiesFile<'a>(pub &'a str);
+#[cfg(quickfix_ssl)]
+impl_dictionary_item!(CertificationAuthoritiesFile as String);
+
+/// Path of the directory containing certificates of trusted certification authorities.
+#[cfg(quickfix_ssl)]
+pub struct CertificationAuthoritiesDirectory<'a>(pub &'a str);
+#[cfg(quickfix_ssl)]
+impl_dictionary_item!(CertificationAuthoritiesDirectory as String);
+
+/// Path of the file containing certificate revocation list.
+#[cfg(quickfix_ssl)]
+pub struct CertificateRevocationListFile<'a>(pub &'a str);
+#[cfg(quickfix_ssl)]
+impl_dictionary_item!(CertificateRevocationListFile as String);
+
+/// Path of the directory containing certificate revocation lists.
+#[cfg(quickfix_ssl)]
+pub struct CertificateRevocationListDirectory<'a>(pub &'a str);
+#[cfg(quickfix_ssl)]
+impl_dictionary_item!(CertificateRevocationListDirectory as String);
+
+/// OpenSSL cipher list to use.
+#[cfg(quickfix_ssl)]
+pub struct SSLCipherSuite<'a>(pub &'a str);
+#[cfg(quickfix_ssl)]
+impl_dictionary_item!(SSLCipherSuite as String);
+
+/// Level of peer certificate verification.
+#[cfg(quickfix_ssl)]
+pub enum CertificateVerifyLevel {
+    /// Peer certificate is not required nor checked.
+    None,
+    /// Peer may present a certificate, which must be valid.
+    Optional,
+    /// Peer must present a valid certificate.
+    Require,
+}
+
+#[cfg(quickfix_ssl)]
+impl DictionaryItem for CertificateVerifyLevel {
+    fn apply_param(&self, dict: &mut Dictionary) -> Result<(), QuickFixError> {
+        dict.set(
+            "CertificateVerifyLevel",
+            match self {
+                Self::None => 0,
+                Self::Optional => 1,
+                Self::Require => 2,
+            },
+        )
+    }
+}
+
 /// Enabled and active SSL protocol.
 pub enum SSLProtocol {
     /// This is the Secure Sockets Layer (SSL) protocol, version 2.0. It is the
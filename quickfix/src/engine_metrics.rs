@@ -0,0 +1,159 @@
+//! Metrics published through the [metrics](https://crates.io/crates/metrics) crate facade.
+//!
+//! Install any `metrics` recorder / exporter (Prometheus, statsd ...) to collect them.
+//! Every metric has a `session` label, formatted like `FIX.4.4:SENDER->TARGET`.
+//!
+//! | Name | Kind | Extra labels |
+//! |------|------|--------------|
+//! | [`MESSAGES`] | counter | `direction`, `msg_type` |
+//! | [`TRANSPORT_BYTES`] | counter | `direction` |
+//! | [`SEND_DURATION`] | histogram | |
+//! | [`SEND_ERRORS`] | counter | |
+//! | [`OUTBOUND_QUEUE_LEN`] | gauge | |
+//! | [`LOGONS`] | counter | |
+//! | [`LOGOUTS`] | counter | |
+//! | [`CONNECT_FAILURES`] | counter | |
+//! | [`RESEND_REQUESTS`] | counter | |
+//!
+//! `direction` is `in` or `out`. To keep label cardinality bounded, `msg_type` is the raw
+//! MsgType(35) of [`KNOWN_MSG_TYPES`], and `other` for every other one (custom messages included).
+
+use std::time::Duration;
+
+use metrics::{counter, gauge, histogram};
+use quickfix_ffi::FixSession_getOutboundQueueLenById;
+
+use crate::{msg_types, SessionId};
+
+/// Messages written to / read from the socket, admin messages and resends included.
+pub const MESSAGES: &str = "quickfix_messages_total";
+/// Raw bytes written to / read from the socket.
+pub const TRANSPORT_BYTES: &str = "quickfix_transport_bytes_total";
+/// Duration of send functions calls, in seconds.
+pub const SEND_DURATION: &str = "quickfix_send_duration_seconds";
+/// Failed send functions calls.
+pub const SEND_ERRORS: &str = "quickfix_send_errors_total";
+/// Messages persisted but not written to the socket yet, see `Session::outbound_queue_len`.
+pub const OUTBOUND_QUEUE_LEN: &str = "quickfix_outbound_queue_len";
+/// Sessions logons, so reconnections are counted once first logon is done.
+pub const LOGONS: &str = "quickfix_logons_total";
+/// Sessions logouts.
+pub const LOGOUTS: &str = "quickfix_logouts_total";
+/// Failed initiator connection attempts.
+pub const CONNECT_FAILURES: &str = "quickfix_connect_failures_total";
+/// ResendRequest(2) about to be sent to counterparty.
+pub const RESEND_REQUESTS: &str = "quickfix_resend_requests_total";
+
+/// MsgType(35) values reported as is in `msg_type` label.
+pub const KNOWN_MSG_TYPES: &[&str] = &[
+    msg_types::HEARTBEAT,
+    msg_types::TEST_REQUEST,
+    msg_types::RESEND_REQUEST,
+    msg_types::REJECT,
+    msg_types::SEQUENCE_RESET,
+    msg_types::LOGOUT,
+    msg_types::LOGON,
+    msg_types::NEWS,
+    msg_types::EMAIL,
+    msg_types::NEW_ORDER_SINGLE,
+    msg_types::NEW_ORDER_LIST,
+    msg_types::ORDER_CANCEL_REQUEST,
+    msg_types::ORDER_CANCEL_REPLACE_REQUEST,
+    msg_types::ORDER_CANCEL_REJECT,
+    msg_types::ORDER_STATUS_REQUEST,
+    msg_types::EXECUTION_REPORT,
+    msg_types::LIST_STATUS,
+    msg_types::BUSINESS_MESSAGE_REJECT,
+    msg_types::QUOTE_REQUEST,
+    msg_types::QUOTE,
+    msg_types::MASS_QUOTE,
+    msg_types::MARKET_DATA_REQUEST,
+    msg_types::MARKET_DATA_SNAPSHOT_FULL_REFRESH,
+    msg_types::MARKET_DATA_INCREMENTAL_REFRESH,
+    msg_types::MARKET_DATA_REQUEST_REJECT,
+    msg_types::SECURITY_DEFINITION_REQUEST,
+    msg_types::SECURITY_DEFINITION,
+    msg_types::TRADE_CAPTURE_REPORT,
+];
+
+const OTHER_MSG_TYPE: &str = "other";
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Direction {
+    In,
+    Out,
+}
+
+impl Direction {
+    fn label(self) -> &'static str {
+        match self {
+            Self::In => "in",
+            Self::Out => "out",
+        }
+    }
+}
+
+/// Get bounded `msg_type` label of a raw FIX message.
+fn msg_type_label(raw: &[u8]) -> &'static str {
+    let Some(start) = raw
+        .windows(4)
+        .position(|window| window == b"\x0135=")
+        .map(|index| index + 4)
+    else {
+        return OTHER_MSG_TYPE;
+    };
+    let value = raw[start..].split(|x| *x == b'\x01').next().unwrap_or(&[]);
+
+    KNOWN_MSG_TYPES
+        .iter()
+        .find(|msg_type| msg_type.as_bytes() == value)
+        .copied()
+        .unwrap_or(OTHER_MSG_TYPE)
+}
+
+pub(crate) fn record_raw(session_id: &SessionId, direction: Direction, raw: &[u8]) {
+    let session = session_id.to_repr();
+    counter!(
+        MESSAGES,
+        "session" => session.clone(),
+        "direction" => direction.label(),
+        "msg_type" => msg_type_label(raw),
+    )
+    .increment(1);
+    counter!(TRANSPORT_BYTES, "session" => session.clone(), "direction" => direction.label())
+        .increment(raw.len() as u64);
+    // Resent messages are written after being logged, so gauge catches up on next message.
+    record_outbound_queue_len(session_id, session);
+}
+
+pub(crate) fn record_send(session_id: &SessionId, elapsed: Duration, failed: bool) {
+    let session = session_id.to_repr();
+    histogram!(SEND_DURATION, "session" => session.clone()).record(elapsed.as_secs_f64());
+    if failed {
+        counter!(SEND_ERRORS, "session" => session.clone()).increment(1);
+    }
+    record_outbound_queue_len(session_id, session);
+}
+
+pub(crate) fn record_logon(session_id: &SessionId) {
+    counter!(LOGONS, "session" => session_id.to_repr()).increment(1);
+}
+
+pub(crate) fn record_logout(session_id: &SessionId) {
+    counter!(LOGOUTS, "session" => session_id.to_repr()).increment(1);
+}
+
+pub(crate) fn record_connect_failure(session_id: &SessionId) {
+    counter!(CONNECT_FAILURES, "session" => session_id.to_repr()).increment(1);
+}
+
+pub(crate) fn record_resend_request(session_id: &SessionId) {
+    counter!(RESEND_REQUESTS, "session" => session_id.to_repr()).increment(1);
+}
+
+fn record_outbound_queue_len(session_id: &SessionId, session: String) {
+    let len = unsafe { FixSession_getOutboundQueueLenById(session_id.0) };
+    if len >= 0 {
+        gauge!(OUTBOUND_QUEUE_LEN, "session" => session).set(len as f64);
+    }
+}
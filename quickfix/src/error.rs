@@ -1,15 +1,37 @@
-use std::ffi::{CStr, NulError};
+use std::ffi::{self, CStr, NulError};
 
-use quickfix_ffi::{Fix_clearLastErrorMessage, Fix_getLastErrorCode, Fix_getLastErrorMessage};
+use quickfix_ffi::{
+    Fix_clearLastErrorMessage, Fix_getLastErrorCode, Fix_getLastErrorDetail, Fix_getLastErrorField,
+    Fix_getLastErrorMessage, Fix_getLastErrorType,
+};
 use thiserror::Error;
 
+use crate::SessionId;
+
 /// Represent all possible error that can occurs with quickfix.
+///
+/// C++ exceptions thrown by quickfix are caught at the FFI boundary by their type and classified
+/// into the variant of the same name, so callers can match on them instead of parsing messages.
+/// Other exceptions become [`QuickFixError::CppException`], keeping their dynamic type name.
+/// No exception crosses the FFI boundary: those raised where no error can be returned, like in
+/// destructors or in log and application callbacks, are dropped instead of aborting the process.
+/// New variants may be added with new quickfix features.
 #[derive(Debug, Error, PartialEq, Eq, Clone)]
+#[non_exhaustive]
 pub enum QuickFixError {
     /// Foreign function as return an invalid return code.
     #[error("invalid function return code: code={0}, msg={1}")]
     InvalidFunctionReturnCode(i8, String),
 
+    /// C++ exception which cannot be classified into another variant.
+    #[error("{exception_type}: {msg}")]
+    CppException {
+        /// Demangled exception type name, e.g. `std::out_of_range`.
+        exception_type: String,
+        /// Exception message, from `what()`.
+        msg: String,
+    },
+
     /// Cannot pass function argument to quickfix.
     #[error("Invalid argument: {0}")]
     InvalidArgument(String),
@@ -19,12 +41,26 @@ pub enum QuickFixError {
     DataDictionaryNotFound(String),
 
     /// Direct mapping to quickfix `FIX::FieldNotFound` exception found in `Exceptions.h`.
-    #[error("quickfix: {0}")]
-    FieldNotFound(String),
+    #[error("quickfix: {msg} (tag {tag})")]
+    FieldNotFound {
+        /// Missing field tag.
+        tag: i32,
+        /// Exception message.
+        msg: String,
+    },
 
     /// Direct mapping to quickfix `FIX::FieldConvertError` exception found in `Exceptions.h`.
-    #[error("quickfix: {0}")]
-    FieldConvertError(String),
+    ///
+    /// Also returned by typed getters like [`crate::FieldMap::get_field_as`].
+    #[error("quickfix: {msg} ({})", convert_context(.tag, .value))]
+    FieldConvertError {
+        /// Tag of the converted field, when known.
+        tag: Option<i32>,
+        /// Value which cannot be converted.
+        value: String,
+        /// Exception message.
+        msg: String,
+    },
 
     /// Direct mapping to quickfix `FIX::MessageParseError` exception found in `Exceptions.h`.
     #[error("quickfix: {0}")]
@@ -59,8 +95,13 @@ pub enum QuickFixError {
     NoTagValue(String),
 
     /// Direct mapping to quickfix `FIX::IncorrectTagValue` exception found in `Exceptions.h`.
-    #[error("quickfix: {0}")]
-    IncorrectTagValue(String),
+    #[error("quickfix: {msg} (tag {tag})")]
+    IncorrectTagValue {
+        /// Tag of the field with an incorrect value.
+        tag: i32,
+        /// Exception message.
+        msg: String,
+    },
 
     /// Direct mapping to quickfix `FIX::IncorrectDataFormat` exception found in `Exceptions.h`.
     #[error("quickfix: {0}")]
@@ -107,10 +148,14 @@ pub enum QuickFixError {
     RejectLogon(String),
 
     /// Direct mapping to quickfix `FIX::SessionNotFound` exception found in `Exceptions.h`.
+    ///
+    /// Message describes the session which has been looked up.
     #[error("quickfix: {0}")]
     SessionNotFound(String),
 
     /// Direct mapping to quickfix `FIX::IOException` exception found in `Exceptions.h`.
+    ///
+    /// Also used for I/O errors of Rust stores and loggers.
     #[error("quickfix: {0}")]
     IOException(String),
 
@@ -129,6 +174,85 @@ pub enum QuickFixError {
     /// Direct mapping to quickfix `FIX::SocketCloseFailed` exception found in `Exceptions.h`.
     #[error("quickfix: {0}")]
     SocketCloseFailed(String),
+
+    /// Session is not logged on and message has not been queued for later delivery.
+    #[error("quickfix: {0}")]
+    SessionNotLoggedOn(String),
+
+    /// Text read from quickfix is not valid UTF-8, e.g. Latin-1 Text(58) sent by counterparty.
+    ///
+    /// Original bytes are kept, so callers can decode them by themselves.
+    #[error("Invalid UTF-8: {} bytes{}", .bytes.len(), tag_context(.tag))]
+    InvalidUtf8 {
+        /// Tag of the field, when reading a single field.
+        tag: Option<i32>,
+        /// Raw bytes, without trailing NUL.
+        bytes: Vec<u8>,
+    },
+
+    /// Message is longer than [`crate::ParseLimits::max_message_len`], or its BodyLength(9)
+    /// declares so.
+    #[error("Message too large: {len} bytes (max {max})")]
+    MessageTooLarge {
+        /// Message length, or declared body length.
+        len: usize,
+        /// Configured limit.
+        max: usize,
+    },
+
+    /// Field value is longer than [`crate::ParseLimits::max_field_len`].
+    #[error("Field too large: {len} bytes{} (max {max})", tag_context(.tag))]
+    FieldTooLarge {
+        /// Field tag, when it is a valid number.
+        tag: Option<i32>,
+        /// Field value length.
+        len: usize,
+        /// Configured limit.
+        max: usize,
+    },
+
+    /// Repeating group count field declares more entries than
+    /// [`crate::ParseLimits::max_groups`].
+    #[error("Too many group entries: {count} (tag {tag}, max {max})")]
+    TooManyGroups {
+        /// Count field tag.
+        tag: i32,
+        /// Declared number of entries.
+        count: usize,
+        /// Configured limit.
+        max: usize,
+    },
+
+    /// Error related to a given session, see [`QuickFixError::with_session`].
+    #[error("{source} [{session_id}]")]
+    InSession {
+        /// Session ID, formatted like `FIX.4.4:SENDER->TARGET`.
+        session_id: String,
+        /// Error without session context.
+        source: Box<QuickFixError>,
+    },
+}
+
+/// Broad category of a [`QuickFixError`], see [`QuickFixError::kind`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// Invalid function argument.
+    InvalidArgument,
+    /// Invalid settings or data dictionary.
+    Config,
+    /// Missing field, or field value which cannot be converted.
+    Conversion,
+    /// Message cannot be parsed or does not match data dictionary.
+    InvalidMessage,
+    /// Message or logon rejected by application.
+    Rejected,
+    /// Session does not exist yet or is not logged on.
+    Session,
+    /// Socket or file I/O failure.
+    Io,
+    /// Unexpected error of quickfix or of this crate.
+    Internal,
 }
 
 impl QuickFixError {
@@ -137,13 +261,205 @@ impl QuickFixError {
         Self::InvalidArgument(msg.into())
     }
 
+    /// Helper to create a new `Self::FieldNotFound` value, with same message as quickfix.
+    pub fn field_not_found(tag: i32) -> Self {
+        Self::FieldNotFound {
+            tag,
+            msg: "Field not found".to_string(),
+        }
+    }
+
+    /// Helper to create a new `Self::FieldConvertError` value for field `tag`.
+    pub fn field_convert_error<T: Into<String>>(tag: i32, value: T) -> Self {
+        Self::FieldConvertError {
+            tag: Some(tag),
+            value: value.into(),
+            msg: "Could not convert field".to_string(),
+        }
+    }
+
+    /// Attach session which this error relates to.
+    ///
+    /// Error is wrapped in [`Self::InSession`], and `[FIX.4.4:SENDER->TARGET]` is appended to
+    /// its message. Errors already having a session, or being [`Self::SessionNotFound`] which
+    /// describes looked up session by itself, are returned unchanged.
+    ///
+    /// Crate attaches session to errors of send functions and [`crate::Session`] methods, use
+    /// [`Self::without_session`] to match on the underlying error.
+    pub fn with_session(self, session_id: &SessionId) -> Self {
+        match self {
+            Self::InSession { .. } | Self::SessionNotFound(_) => self,
+            source => Self::InSession {
+                session_id: session_id.to_repr(),
+                source: Box::new(source),
+            },
+        }
+    }
+
+    /// Session this error relates to, formatted like `FIX.4.4:SENDER->TARGET`.
+    pub fn session_id(&self) -> Option<&str> {
+        match self {
+            Self::InSession { session_id, .. } => Some(session_id),
+            _ => None,
+        }
+    }
+
+    /// Error without session context.
+    pub fn without_session(&self) -> &Self {
+        match self {
+            Self::InSession { source, .. } => source,
+            _ => self,
+        }
+    }
+
+    /// Same as [`Self::without_session`], taking ownership of error.
+    pub fn into_without_session(self) -> Self {
+        match self {
+            Self::InSession { source, .. } => *source,
+            _ => self,
+        }
+    }
+
+    /// Tag of the field this error relates to, if any.
+    pub fn tag(&self) -> Option<i32> {
+        match self.without_session() {
+            Self::FieldNotFound { tag, .. } | Self::IncorrectTagValue { tag, .. } => Some(*tag),
+            Self::TooManyGroups { tag, .. } => Some(*tag),
+            Self::FieldConvertError { tag, .. }
+            | Self::InvalidUtf8 { tag, .. }
+            | Self::FieldTooLarge { tag, .. } => *tag,
+            _ => None,
+        }
+    }
+
+    /// Raw value which cannot be converted, for conversion errors.
+    pub fn value(&self) -> Option<&str> {
+        match self.without_session() {
+            Self::FieldConvertError { value, .. } => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Raw bytes which are not valid UTF-8, for [`Self::InvalidUtf8`].
+    pub fn bytes(&self) -> Option<&[u8]> {
+        match self.without_session() {
+            Self::InvalidUtf8 { bytes, .. } => Some(bytes),
+            _ => None,
+        }
+    }
+
+    /// Broad category of this error, ignoring session context.
+    pub fn kind(&self) -> ErrorKind {
+        match self.without_session() {
+            Self::InvalidArgument(_) => ErrorKind::InvalidArgument,
+            Self::DataDictionaryNotFound(_) | Self::ConfigError(_) => ErrorKind::Config,
+            Self::FieldNotFound { .. }
+            | Self::FieldConvertError { .. }
+            | Self::InvalidUtf8 { .. } => ErrorKind::Conversion,
+            Self::MessageParseError(_)
+            | Self::InvalidMessage(_)
+            | Self::InvalidTagNumber(_)
+            | Self::RequiredTagMissing(_)
+            | Self::TagNotDefinedForMessage(_)
+            | Self::NoTagValue(_)
+            | Self::IncorrectTagValue { .. }
+            | Self::IncorrectDataFormat(_)
+            | Self::IncorrectMessageStructure(_)
+            | Self::DuplicateFieldNumber(_)
+            | Self::InvalidMessageType(_)
+            | Self::UnsupportedMessageType(_)
+            | Self::UnsupportedVersion(_)
+            | Self::TagOutOfOrder(_)
+            | Self::RepeatedTag(_)
+            | Self::RepeatingGroupCountMismatch(_)
+            | Self::MessageTooLarge { .. }
+            | Self::FieldTooLarge { .. }
+            | Self::TooManyGroups { .. } => ErrorKind::InvalidMessage,
+            Self::DoNotSend(_) | Self::RejectLogon(_) => ErrorKind::Rejected,
+            Self::SessionNotFound(_) | Self::SessionNotLoggedOn(_) => ErrorKind::Session,
+            Self::IOException(_)
+            | Self::SocketException(_)
+            | Self::SocketSendFailed(_)
+            | Self::SocketRecvFailed(_)
+            | Self::SocketCloseFailed(_) => ErrorKind::Io,
+            Self::InvalidFunctionReturnCode(..)
+            | Self::CppException { .. }
+            | Self::RuntimeError(_) => ErrorKind::Internal,
+            Self::InSession { source, .. } => source.kind(),
+        }
+    }
+
+    /// Check if failed operation may succeed when retried later, without any change.
+    ///
+    /// Only [`ErrorKind::Session`] (session not created yet, or not logged on) and
+    /// [`ErrorKind::Io`] errors are retryable. Everything else, like invalid settings, invalid
+    /// messages or conversion errors, fails again the same way.
+    pub fn is_retryable(&self) -> bool {
+        matches!(self.kind(), ErrorKind::Session | ErrorKind::Io)
+    }
+
+    /// Type name of the C++ exception this error comes from, e.g. `FIX::ConfigError`.
+    ///
+    /// This is the quickfix exception class caught at the FFI boundary for classified variants,
+    /// and the dynamic type recorded with the exception for [`Self::CppException`]. `None` for
+    /// errors which do not come from C++ exceptions.
+    pub fn exception_type(&self) -> Option<&str> {
+        Some(match self.without_session() {
+            Self::CppException { exception_type, .. } => exception_type,
+            Self::DataDictionaryNotFound(_) => "FIX::DataDictionaryNotFound",
+            Self::FieldNotFound { .. } => "FIX::FieldNotFound",
+            Self::FieldConvertError { .. } => "FIX::FieldConvertError",
+            Self::MessageParseError(_) => "FIX::MessageParseError",
+            Self::InvalidMessage(_) => "FIX::InvalidMessage",
+            Self::ConfigError(_) => "FIX::ConfigError",
+            Self::RuntimeError(_) => "FIX::RuntimeError",
+            Self::InvalidTagNumber(_) => "FIX::InvalidTagNumber",
+            Self::RequiredTagMissing(_) => "FIX::RequiredTagMissing",
+            Self::TagNotDefinedForMessage(_) => "FIX::TagNotDefinedForMessage",
+            Self::NoTagValue(_) => "FIX::NoTagValue",
+            Self::IncorrectTagValue { .. } => "FIX::IncorrectTagValue",
+            Self::IncorrectDataFormat(_) => "FIX::IncorrectDataFormat",
+            Self::IncorrectMessageStructure(_) => "FIX::IncorrectMessageStructure",
+            Self::DuplicateFieldNumber(_) => "FIX::DuplicateFieldNumber",
+            Self::InvalidMessageType(_) => "FIX::InvalidMessageType",
+            Self::UnsupportedMessageType(_) => "FIX::UnsupportedMessageType",
+            Self::UnsupportedVersion(_) => "FIX::UnsupportedVersion",
+            Self::TagOutOfOrder(_) => "FIX::TagOutOfOrder",
+            Self::RepeatedTag(_) => "FIX::RepeatedTag",
+            Self::RepeatingGroupCountMismatch(_) => "FIX::RepeatingGroupCountMismatch",
+            Self::DoNotSend(_) => "FIX::DoNotSend",
+            Self::RejectLogon(_) => "FIX::RejectLogon",
+            Self::SessionNotFound(_) => "FIX::SessionNotFound",
+            Self::IOException(_) => "FIX::IOException",
+            Self::SocketException(_) => "FIX::SocketException",
+            Self::SocketSendFailed(_) => "FIX::SocketSendFailed",
+            Self::SocketRecvFailed(_) => "FIX::SocketRecvFailed",
+            Self::SocketCloseFailed(_) => "FIX::SocketCloseFailed",
+            Self::InvalidFunctionReturnCode(..)
+            | Self::InvalidArgument(_)
+            | Self::SessionNotLoggedOn(_)
+            | Self::InvalidUtf8 { .. }
+            | Self::MessageTooLarge { .. }
+            | Self::FieldTooLarge { .. }
+            | Self::TooManyGroups { .. }
+            | Self::InSession { .. } => return None,
+        })
+    }
+
     /// Build a null function return and read associated error if any.
     pub fn from_last_error() -> Self {
         // Bellow error code should match what we have in quickfix_bind library.
         match unsafe { Fix_getLastErrorCode() } {
             -10 => Self::DataDictionaryNotFound(last_quickfix_error_message_or_default()),
-            -11 => Self::FieldNotFound(last_quickfix_error_message_or_default()),
-            -12 => Self::FieldConvertError(last_quickfix_error_message_or_default()),
+            -11 => Self::FieldNotFound {
+                tag: unsafe { Fix_getLastErrorField() },
+                msg: last_quickfix_error_message_or_default(),
+            },
+            -12 => Self::FieldConvertError {
+                tag: None,
+                value: last_quickfix_error_detail(),
+                msg: last_quickfix_error_message_or_default(),
+            },
             -13 => Self::MessageParseError(last_quickfix_error_message_or_default()),
             -14 => Self::InvalidMessage(last_quickfix_error_message_or_default()),
             -15 => Self::ConfigError(last_quickfix_error_message_or_default()),
@@ -152,7 +468,10 @@ impl QuickFixError {
             -18 => Self::RequiredTagMissing(last_quickfix_error_message_or_default()),
             -19 => Self::TagNotDefinedForMessage(last_quickfix_error_message_or_default()),
             -20 => Self::NoTagValue(last_quickfix_error_message_or_default()),
-            -21 => Self::IncorrectTagValue(last_quickfix_error_message_or_default()),
+            -21 => Self::IncorrectTagValue {
+                tag: unsafe { Fix_getLastErrorField() },
+                msg: last_quickfix_error_message_or_default(),
+            },
             -22 => Self::IncorrectDataFormat(last_quickfix_error_message_or_default()),
             -23 => Self::IncorrectMessageStructure(last_quickfix_error_message_or_default()),
             -24 => Self::DuplicateFieldNumber(last_quickfix_error_message_or_default()),
@@ -170,6 +489,15 @@ impl QuickFixError {
             -36 => Self::SocketSendFailed(last_quickfix_error_message_or_default()),
             -37 => Self::SocketRecvFailed(last_quickfix_error_message_or_default()),
             -38 => Self::SocketCloseFailed(last_quickfix_error_message_or_default()),
+            -39 => Self::SessionNotLoggedOn(last_quickfix_error_message_or_default()),
+            // Any other `std::exception`.
+            -2 => match last_quickfix_error_type() {
+                exception_type if !exception_type.is_empty() => Self::CppException {
+                    exception_type,
+                    msg: last_quickfix_error_message_or_default(),
+                },
+                _ => Self::InvalidFunctionReturnCode(-2, last_quickfix_error_message_or_default()),
+            },
             value => {
                 Self::InvalidFunctionReturnCode(value, last_quickfix_error_message_or_default())
             }
@@ -183,11 +511,44 @@ impl From<NulError> for QuickFixError {
     }
 }
 
+fn convert_context(tag: &Option<i32>, value: &str) -> String {
+    match tag {
+        Some(tag) => format!("tag {tag}, value {value:?}"),
+        None => format!("value {value:?}"),
+    }
+}
+
+fn tag_context(tag: &Option<i32>) -> String {
+    match tag {
+        Some(tag) => format!(" (tag {tag})"),
+        None => String::new(),
+    }
+}
+
 fn last_quickfix_error_message_or_default() -> String {
     last_quickfix_error_message()
         .unwrap_or_else(|| "Cannot get last error message from quickfix library".to_string())
 }
 
+/// Exception detail, must be read before message as reading message clears last error.
+fn last_quickfix_error_detail() -> String {
+    read_error_str(unsafe { Fix_getLastErrorDetail() })
+}
+
+/// Exception type name, must be read before message as reading message clears last error.
+fn last_quickfix_error_type() -> String {
+    read_error_str(unsafe { Fix_getLastErrorType() })
+}
+
+fn read_error_str(text: *const ffi::c_char) -> String {
+    if text.is_null() {
+        return String::new();
+    }
+    unsafe { CStr::from_ptr(text) }
+        .to_string_lossy()
+        .into_owned()
+}
+
 fn last_quickfix_error_message() -> Option<String> {
     unsafe {
         let raw_error = Fix_getLastErrorMessage()?;
@@ -0,0 +1,145 @@
+use std::{ffi, ops::Range};
+
+use crate::QuickFixError;
+
+/// Reusable buffer holding values of several fields, see [`crate::FieldMap::get_fields_into`].
+///
+/// Values are stored back to back in a single buffer, so reading them again with the same batch
+/// does not allocate once buffer is large enough.
+///
+/// ```
+/// use quickfix::*;
+///
+/// # fn read(msg: &Message) -> Result<(), QuickFixError> {
+/// let mut batch = FieldBatch::new();
+/// msg.get_fields_into(&[11, 55, 54], &mut batch)?;
+///
+/// let symbol = batch.get_by_tag(55);
+/// for value in batch.iter() {
+///     println!("{value:?}");
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct FieldBatch {
+    tags: Vec<i32>,
+    buffer: Vec<u8>,
+    lengths: Vec<i64>,
+    ranges: Vec<Option<Range<usize>>>,
+}
+
+impl FieldBatch {
+    /// Create new empty struct.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of tags read.
+    pub fn len(&self) -> usize {
+        self.ranges.len()
+    }
+
+    /// Check if no tag has been read.
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Tags read, in request order.
+    pub fn tags(&self) -> &[i32] {
+        &self.tags
+    }
+
+    /// Get value of the `index`-th requested tag, `None` when field is missing.
+    pub fn get(&self, index: usize) -> Option<&str> {
+        let range = self.ranges.get(index)?.clone()?;
+        // SAFETY: every value is checked to be valid UTF-8 when batch is filled.
+        Some(unsafe { std::str::from_utf8_unchecked(&self.buffer[range]) })
+    }
+
+    /// Get value of first requested `tag`, `None` when field is missing or was not requested.
+    pub fn get_by_tag(&self, tag: i32) -> Option<&str> {
+        let index = self.tags().iter().position(|requested| *requested == tag)?;
+        self.get(index)
+    }
+
+    /// Iterate over values, in request order.
+    pub fn iter(&self) -> impl Iterator<Item = Option<&str>> + '_ {
+        (0..self.len()).map(|index| self.get(index))
+    }
+
+    fn reset(&mut self, tags: &[i32]) {
+        self.tags.clear();
+        self.tags.extend_from_slice(tags);
+        self.lengths.clear();
+        self.lengths.resize(tags.len(), -1);
+        self.ranges.clear();
+    }
+
+    /// Fill batch from values returned one by one.
+    pub(crate) fn fill_with<F>(&mut self, tags: &[i32], mut get: F) -> Result<(), QuickFixError>
+    where
+        F: FnMut(i32) -> Option<Vec<u8>>,
+    {
+        self.reset(tags);
+        self.buffer.clear();
+        for (tag, length) in tags.iter().zip(&mut self.lengths) {
+            if let Some(value) = get(*tag) {
+                *length = value.len() as i64;
+                self.buffer.extend_from_slice(&value);
+            }
+        }
+        self.index()
+    }
+
+    /// Fill batch by calling one of `Fix*_getFields`, growing buffer until values fit in.
+    pub(crate) fn fill_from_ffi<F>(
+        &mut self,
+        tags: &[i32],
+        mut read: F,
+    ) -> Result<(), QuickFixError>
+    where
+        F: FnMut(*mut ffi::c_char, u64, *mut i64) -> i64,
+    {
+        self.reset(tags);
+        loop {
+            let buffer_len = self.buffer.len();
+            let needed: usize = read(
+                self.buffer.as_mut_ptr().cast(),
+                buffer_len as u64,
+                self.lengths.as_mut_ptr(),
+            )
+            .try_into()
+            .map_err(|_err| QuickFixError::from_last_error())?;
+
+            if needed <= buffer_len {
+                return self.index();
+            }
+            self.buffer.resize(needed, 0);
+        }
+    }
+
+    fn index(&mut self) -> Result<(), QuickFixError> {
+        let mut offset = 0;
+        for index in 0..self.tags.len() {
+            let Ok(length) = usize::try_from(self.lengths[index]) else {
+                self.ranges.push(None);
+                continue;
+            };
+            let range = offset..offset + length;
+            if let Err(_err) = std::str::from_utf8(&self.buffer[range.clone()]) {
+                let bytes = self.buffer[range].to_vec();
+                let tag = self.tags[index];
+                self.tags.clear();
+                self.ranges.clear();
+                return Err(QuickFixError::InvalidUtf8 {
+                    tag: Some(tag),
+                    bytes,
+                });
+            }
+            offset = range.end;
+            self.ranges.push(Some(range));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,105 @@
+use std::borrow::Cow;
+
+use crate::{
+    message_tree::{FieldNode, MessageTree},
+    DataDictionary, Message,
+};
+
+/// Resolve field and value names, to render messages for humans.
+///
+/// Implemented by [`DataDictionary`] at run time, and by generated message crates from their
+/// compile time spec.
+pub trait FieldNameResolver {
+    /// Get field name from its tag (ex: `Side` for tag 54).
+    fn field_name(&self, tag: i32) -> Option<Cow<'_, str>>;
+
+    /// Get name of an enumerated field value (ex: `BUY` for tag 54 and value `1`).
+    fn value_name(&self, tag: i32, value: &str) -> Option<Cow<'_, str>>;
+}
+
+impl FieldNameResolver for DataDictionary {
+    fn field_name(&self, tag: i32) -> Option<Cow<'_, str>> {
+        DataDictionary::field_name(self, tag).map(Cow::Owned)
+    }
+
+    fn value_name(&self, tag: i32, value: &str) -> Option<Cow<'_, str>> {
+        DataDictionary::value_name(self, tag, value).map(Cow::Owned)
+    }
+}
+
+/// Render `msg` fields in wire order, with repeating group entries between brackets.
+pub(crate) fn pretty_string(
+    msg: &Message,
+    names: Option<&dyn FieldNameResolver>,
+    delim: char,
+) -> String {
+    let Ok(text) = msg.to_fix_string_lossy() else {
+        return String::new();
+    };
+    let tree = MessageTree::read(msg, &text);
+
+    let mut printer = PrettyPrinter {
+        names,
+        delim,
+        output: String::with_capacity(text.len() * 2),
+        separated: true,
+    };
+    for section in [&tree.header, &tree.body, &tree.trailer, &tree.unmatched] {
+        printer.write_nodes(section);
+    }
+    printer.output
+}
+
+struct PrettyPrinter<'a> {
+    names: Option<&'a dyn FieldNameResolver>,
+    delim: char,
+    output: String,
+    /// Nothing was written since message or group entry start.
+    separated: bool,
+}
+
+impl PrettyPrinter<'_> {
+    fn write_nodes(&mut self, nodes: &[FieldNode]) {
+        for node in nodes {
+            match node {
+                FieldNode::Field { tag, value } => self.write_field(*tag, value),
+                FieldNode::Group {
+                    tag,
+                    count,
+                    entries,
+                } => {
+                    self.write_field(*tag, count);
+                    for entry in entries {
+                        self.write_separator();
+                        self.output.push('[');
+                        self.separated = true;
+                        self.write_nodes(entry);
+                        self.output.push(']');
+                    }
+                }
+            }
+        }
+    }
+
+    fn write_field(&mut self, tag: i32, value: &str) {
+        self.write_separator();
+
+        let names = self.names;
+        match names.and_then(|names| names.field_name(tag)) {
+            Some(name) => self.output.push_str(&name),
+            None => self.output.push_str(&tag.to_string()),
+        }
+        self.output.push('=');
+        match names.and_then(|names| names.value_name(tag, value)) {
+            Some(name) => self.output.push_str(&name),
+            None => self.output.push_str(value),
+        }
+    }
+
+    fn write_separator(&mut self) {
+        if !self.separated {
+            self.output.push(self.delim);
+        }
+        self.separated = false;
+    }
+}
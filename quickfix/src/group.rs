@@ -1,19 +1,24 @@
 use std::fmt;
 
 use quickfix_ffi::{
-    FixGroup_addGroup, FixGroup_copy, FixGroup_copyGroup, FixGroup_delete, FixGroup_getDelim,
-    FixGroup_getField, FixGroup_getFieldId, FixGroup_isFieldEqual, FixGroup_new,
-    FixGroup_removeField, FixGroup_setField, FixGroup_t,
+    FixGroup_addGroup, FixGroup_addGroups, FixGroup_copy, FixGroup_copyGroup, FixGroup_delete,
+    FixGroup_getDelim, FixGroup_getFieldId, FixGroup_getFieldValue, FixGroup_getFields,
+    FixGroup_isFieldEqual, FixGroup_new, FixGroup_removeField, FixGroup_setFieldRaw, FixGroup_t,
 };
 
 use crate::{
-    utils::{ffi_code_to_result, read_checked_cstr},
-    FieldMap, IntoFixValue, QuickFixError,
+    utils::{append_field_value, ffi_code_to_result, with_flat_group_rows},
+    FieldBatch, FieldMap, IntoFixValue, QuickFixError,
 };
 
 /// Base class for all FIX repeating groups.
 pub struct Group(pub(crate) FixGroup_t);
 
+// SAFETY: group is always owned and only mutated through `&mut self`.
+// Unlike `Message`, it has no `const` C++ function rewriting its fields, so it can be shared.
+unsafe impl Send for Group {}
+unsafe impl Sync for Group {}
+
 impl Group {
     /// Create new empty struct.
     pub fn try_new(field_id: i32, delim: i32) -> Result<Self, QuickFixError> {
@@ -50,7 +55,36 @@ impl Group {
 
 impl FieldMap for Group {
     fn get_field(&self, tag: i32) -> Option<String> {
-        unsafe { FixGroup_getField(self.0, tag) }.map(read_checked_cstr)
+        self.try_get_field(tag).ok().flatten()
+    }
+
+    fn get_field_bytes(&self, tag: i32) -> Option<Vec<u8>> {
+        // Read with length, so values holding NUL bytes are not truncated.
+        let mut buf = Vec::new();
+        self.get_field_bytes_into(tag, &mut buf)
+            .ok()
+            .map(|_len| buf)
+    }
+
+    fn get_field_bytes_into(&self, tag: i32, buf: &mut Vec<u8>) -> Result<usize, QuickFixError> {
+        let mut value_len = 0;
+        unsafe {
+            let value = FixGroup_getFieldValue(self.0, tag, &mut value_len);
+            append_field_value(tag, value, value_len, buf)
+        }
+    }
+
+    fn get_fields_into(&self, tags: &[i32], batch: &mut FieldBatch) -> Result<(), QuickFixError> {
+        batch.fill_from_ffi(tags, |buffer, buffer_len, lengths| unsafe {
+            FixGroup_getFields(
+                self.0,
+                tags.as_ptr(),
+                tags.len() as u64,
+                buffer,
+                buffer_len,
+                lengths,
+            )
+        })
     }
 
     fn is_field_equal(&self, tag: i32, value: &str) -> bool {
@@ -61,7 +95,13 @@ impl FieldMap for Group {
 
     fn set_field<V: IntoFixValue>(&mut self, tag: i32, value: V) -> Result<(), QuickFixError> {
         let fix_value = value.into_fix_value()?;
-        ffi_code_to_result(unsafe { FixGroup_setField(self.0, tag, fix_value.as_ptr()) })
+        self.set_field_raw(tag, fix_value.as_bytes())
+    }
+
+    fn set_field_raw(&mut self, tag: i32, value: &[u8]) -> Result<(), QuickFixError> {
+        ffi_code_to_result(unsafe {
+            FixGroup_setFieldRaw(self.0, tag, value.as_ptr().cast(), value.len() as u64)
+        })
     }
 
     fn remove_field(&mut self, tag: i32) -> Result<(), QuickFixError> {
@@ -73,6 +113,21 @@ impl FieldMap for Group {
         Ok(())
     }
 
+    fn add_groups(
+        &mut self,
+        template: &Group,
+        rows: &[&[(i32, &str)]],
+    ) -> Result<(), QuickFixError> {
+        ffi_code_to_result(with_flat_group_rows(
+            rows,
+            |tags, values, value_lens, row_lens, rows_len| unsafe {
+                FixGroup_addGroups(
+                    self.0, template.0, tags, values, value_lens, row_lens, rows_len,
+                )
+            },
+        ))
+    }
+
     fn clone_group(&self, index: i32, tag: i32) -> Option<Group> {
         unsafe { FixGroup_copyGroup(self.0, index, tag) }.map(Group)
     }
@@ -1,17 +1,26 @@
-use std::ffi::CStr;
-use std::fmt;
+use std::{
+    fmt,
+    marker::PhantomData,
+    mem::ManuallyDrop,
+    ops::{Deref, DerefMut},
+};
 
 use quickfix_ffi::{
-    FixHeader_addGroup, FixHeader_copy, FixHeader_copyGroup, FixHeader_delete, FixHeader_getField,
-    FixHeader_isFieldEqual, FixHeader_new, FixHeader_removeField, FixHeader_setField, FixHeader_t,
+    FixHeader_addGroup, FixHeader_addGroups, FixHeader_copy, FixHeader_copyGroup, FixHeader_delete,
+    FixHeader_getFieldValue, FixHeader_getFields, FixHeader_isFieldEqual, FixHeader_new,
+    FixHeader_removeField, FixHeader_setFieldRaw, FixHeader_t,
 };
 
 use crate::{
-    utils::{ffi_code_to_result, read_checked_cstr},
-    FieldMap, Group, IntoFixValue, QuickFixError,
+    utils::{append_field_value, ffi_code_to_result, field_value_str, with_flat_group_rows},
+    FieldBatch, FieldMap, Group, IntoFixValue, Message, QuickFixError,
 };
 
 /// Header part of a FIX message.
+///
+/// Not `Send`: `Message::get_header` returns a header pointing into its message, which must not
+/// be used from another thread while the message is. Use `Message::clone_header` or send the
+/// whole message instead.
 pub struct Header(pub(crate) FixHeader_t);
 
 impl Header {
@@ -20,16 +29,48 @@ impl Header {
         Self::default()
     }
 
+    /// Borrow field value without copying it, `None` when field is missing or not UTF-8.
     pub fn get_field_str(&self, tag: i32) -> Option<&str> {
+        let mut value_len = 0;
         unsafe {
-            FixHeader_getField(self.0, tag).map(|pr| CStr::from_ptr(pr.as_ptr()).to_str().unwrap())
+            let value = FixHeader_getFieldValue(self.0, tag, &mut value_len);
+            field_value_str(value, value_len)
         }
     }
 }
 
 impl FieldMap for Header {
     fn get_field(&self, tag: i32) -> Option<String> {
-        unsafe { FixHeader_getField(self.0, tag) }.map(read_checked_cstr)
+        self.try_get_field(tag).ok().flatten()
+    }
+
+    fn get_field_bytes(&self, tag: i32) -> Option<Vec<u8>> {
+        // Read with length, so values holding NUL bytes are not truncated.
+        let mut buf = Vec::new();
+        self.get_field_bytes_into(tag, &mut buf)
+            .ok()
+            .map(|_len| buf)
+    }
+
+    fn get_field_bytes_into(&self, tag: i32, buf: &mut Vec<u8>) -> Result<usize, QuickFixError> {
+        let mut value_len = 0;
+        unsafe {
+            let value = FixHeader_getFieldValue(self.0, tag, &mut value_len);
+            append_field_value(tag, value, value_len, buf)
+        }
+    }
+
+    fn get_fields_into(&self, tags: &[i32], batch: &mut FieldBatch) -> Result<(), QuickFixError> {
+        batch.fill_from_ffi(tags, |buffer, buffer_len, lengths| unsafe {
+            FixHeader_getFields(
+                self.0,
+                tags.as_ptr(),
+                tags.len() as u64,
+                buffer,
+                buffer_len,
+                lengths,
+            )
+        })
     }
 
     fn is_field_equal(&self, tag: i32, value: &str) -> bool {
@@ -40,7 +81,13 @@ impl FieldMap for Header {
 
     fn set_field<V: IntoFixValue>(&mut self, tag: i32, value: V) -> Result<(), QuickFixError> {
         let fix_value = value.into_fix_value()?;
-        ffi_code_to_result(unsafe { FixHeader_setField(self.0, tag, fix_value.as_ptr()) })
+        self.set_field_raw(tag, fix_value.as_bytes())
+    }
+
+    fn set_field_raw(&mut self, tag: i32, value: &[u8]) -> Result<(), QuickFixError> {
+        ffi_code_to_result(unsafe {
+            FixHeader_setFieldRaw(self.0, tag, value.as_ptr().cast(), value.len() as u64)
+        })
     }
 
     fn remove_field(&mut self, tag: i32) -> Result<(), QuickFixError> {
@@ -52,6 +99,21 @@ impl FieldMap for Header {
         Ok(())
     }
 
+    fn add_groups(
+        &mut self,
+        template: &Group,
+        rows: &[&[(i32, &str)]],
+    ) -> Result<(), QuickFixError> {
+        ffi_code_to_result(with_flat_group_rows(
+            rows,
+            |tags, values, value_lens, row_lens, rows_len| unsafe {
+                FixHeader_addGroups(
+                    self.0, template.0, tags, values, value_lens, row_lens, rows_len,
+                )
+            },
+        ))
+    }
+
     fn clone_group(&self, index: i32, tag: i32) -> Option<Group> {
         unsafe { FixHeader_copyGroup(self.0, index, tag) }.map(Group)
     }
@@ -82,3 +144,141 @@ impl Drop for Header {
         unsafe { FixHeader_delete(self.0) }
     }
 }
+
+/// Read-only header of a message, borrowed with [`Message::header`].
+///
+/// Dereference to [`Header`], so every read method of [`FieldMap`] can be used on it.
+/// Guard borrows the message, so it cannot outlive it:
+///
+/// ```compile_fail
+/// use quickfix::*;
+///
+/// let header = {
+///     let msg = Message::new();
+///     msg.header()
+/// };
+/// let _ = header.get_field(35);
+/// ```
+pub struct HeaderRef<'a> {
+    header: ManuallyDrop<Header>,
+    _message: PhantomData<&'a Message>,
+}
+
+impl HeaderRef<'_> {
+    pub(crate) fn new(ptr: FixHeader_t) -> Self {
+        Self {
+            header: ManuallyDrop::new(Header(ptr)),
+            _message: PhantomData,
+        }
+    }
+}
+
+impl Deref for HeaderRef<'_> {
+    type Target = Header;
+
+    fn deref(&self) -> &Self::Target {
+        &self.header
+    }
+}
+
+impl fmt::Debug for HeaderRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("HeaderRef").finish()
+    }
+}
+
+/// Mutable header of a message, borrowed with [`Message::header_mut`].
+///
+/// Message cannot be used while guard is alive:
+///
+/// ```compile_fail
+/// use quickfix::*;
+///
+/// let mut msg = Message::new();
+/// let mut header = msg.header_mut();
+/// msg.set_field(148, "Hello").unwrap();
+/// header.set_field(35, "B").unwrap();
+/// ```
+pub struct HeaderMut<'a> {
+    header: ManuallyDrop<Header>,
+    _message: PhantomData<&'a mut Message>,
+}
+
+impl HeaderMut<'_> {
+    pub(crate) fn new(ptr: FixHeader_t) -> Self {
+        Self {
+            header: ManuallyDrop::new(Header(ptr)),
+            _message: PhantomData,
+        }
+    }
+}
+
+impl Deref for HeaderMut<'_> {
+    type Target = Header;
+
+    fn deref(&self) -> &Self::Target {
+        &self.header
+    }
+}
+
+impl DerefMut for HeaderMut<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.header
+    }
+}
+
+impl FieldMap for HeaderMut<'_> {
+    fn get_field(&self, tag: i32) -> Option<String> {
+        self.header.get_field(tag)
+    }
+
+    fn get_field_bytes(&self, tag: i32) -> Option<Vec<u8>> {
+        self.header.get_field_bytes(tag)
+    }
+
+    fn get_field_bytes_into(&self, tag: i32, buf: &mut Vec<u8>) -> Result<usize, QuickFixError> {
+        self.header.get_field_bytes_into(tag, buf)
+    }
+
+    fn get_fields_into(&self, tags: &[i32], batch: &mut FieldBatch) -> Result<(), QuickFixError> {
+        self.header.get_fields_into(tags, batch)
+    }
+
+    fn is_field_equal(&self, tag: i32, value: &str) -> bool {
+        self.header.is_field_equal(tag, value)
+    }
+
+    fn set_field<V: IntoFixValue>(&mut self, tag: i32, value: V) -> Result<(), QuickFixError> {
+        self.header.set_field(tag, value)
+    }
+
+    fn set_field_raw(&mut self, tag: i32, value: &[u8]) -> Result<(), QuickFixError> {
+        self.header.set_field_raw(tag, value)
+    }
+
+    fn remove_field(&mut self, tag: i32) -> Result<(), QuickFixError> {
+        self.header.remove_field(tag)
+    }
+
+    fn add_group(&mut self, group: &Group) -> Result<(), QuickFixError> {
+        self.header.add_group(group)
+    }
+
+    fn add_groups(
+        &mut self,
+        template: &Group,
+        rows: &[&[(i32, &str)]],
+    ) -> Result<(), QuickFixError> {
+        self.header.add_groups(template, rows)
+    }
+
+    fn clone_group(&self, index: i32, tag: i32) -> Option<Group> {
+        self.header.clone_group(index, tag)
+    }
+}
+
+impl fmt::Debug for HeaderMut<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("HeaderMut").finish()
+    }
+}
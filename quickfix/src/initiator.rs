@@ -1,18 +1,107 @@
-use std::marker::PhantomData;
+use std::{
+    any::Any, collections::HashMap, ffi::CString, marker::PhantomData, mem::ManuallyDrop,
+    sync::Arc, time::Duration,
+};
 
 use quickfix_ffi::{
-    FixInitiator_block, FixInitiator_delete, FixInitiator_getSession, FixInitiator_isLoggedOn,
-    FixInitiator_isStopped, FixInitiator_new, FixInitiator_poll, FixInitiator_start,
-    FixInitiator_stop, FixInitiator_t,
+    FixApplication_t, FixInitiator_addSession, FixInitiator_block, FixInitiator_delete,
+    FixInitiator_getSession, FixInitiator_getSessionCount, FixInitiator_getSessionIdAt,
+    FixInitiator_getSessionSettingsRef, FixInitiator_isLoggedOn, FixInitiator_isStopped,
+    FixInitiator_new, FixInitiator_poll, FixInitiator_removeSession,
+    FixInitiator_setReconnectInterval, FixInitiator_setReconnectPolicy,
+    FixInitiator_setSessionSettings, FixInitiator_start, FixInitiator_stop, FixInitiator_stopForce,
+    FixInitiator_stopWithReason, FixInitiator_t, FixLogFactory_t, FixMessageStoreFactory_t,
 };
 
 use crate::{
-    utils::{ffi_code_to_bool, ffi_code_to_result},
-    Application, ApplicationCallback, ConnectionHandler, FfiMessageStoreFactory,
-    FixSocketServerKind, LogFactory, QuickFixError, Session, SessionContainer, SessionId,
-    SessionSettings, StdLogger,
+    application::notify_session_removed,
+    stop_handle::StopTarget,
+    utils::{duration_to_millis, ffi_code_to_bool, ffi_code_to_result},
+    Application, ApplicationCallback, ConnectionHandler, ConnectionHandlerBuilder, Dictionary,
+    FfiMessageStoreFactory, FixSocketServerKind, ForInitiator, LogFactory,
+    MemoryMessageStoreFactory, QuickFixError, Session, SessionContainer, SessionId,
+    SessionSettings, StdLogger, StopHandle,
 };
 
+/// Outcome of [`Initiator::reload_settings`].
+#[derive(Debug, Default, Clone)]
+pub struct ReloadReport {
+    /// Sessions with new settings applied.
+    pub updated: Vec<SessionId>,
+    /// Sessions that could not be changed live, with the reason.
+    pub rejected: Vec<(SessionId, String)>,
+    /// Sessions missing from new settings, which have been logged out.
+    pub removed: Vec<SessionId>,
+}
+
+/// Delay applied before reconnecting an initiator session once its connection ends.
+///
+/// See [`Initiator::set_reconnect_policy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReconnectPolicy {
+    /// Always wait the same delay.
+    Fixed(Duration),
+    /// Double the delay after each connection ending without a logon, up to `max`.
+    /// Delay is reset to `initial` once session logs on.
+    Exponential {
+        /// First delay.
+        initial: Duration,
+        /// Maximum delay.
+        max: Duration,
+        /// Random extra delay, between zero and this value, added to each delay so sessions
+        /// disconnected at once do not all reconnect at once.
+        jitter: Duration,
+    },
+}
+
+impl ReconnectPolicy {
+    fn bounds_millis(&self) -> Result<(i64, i64, i64), QuickFixError> {
+        let (initial, max, jitter) = match *self {
+            Self::Fixed(delay) => (delay, delay, Duration::ZERO),
+            Self::Exponential {
+                initial,
+                max,
+                jitter,
+            } => (initial, max, jitter),
+        };
+        if initial.is_zero() || max < initial {
+            return Err(QuickFixError::invalid_argument(format!(
+                "Invalid reconnect policy: {self:?}"
+            )));
+        }
+
+        Ok((
+            duration_to_millis(initial),
+            duration_to_millis(max),
+            duration_to_millis(jitter),
+        ))
+    }
+}
+
+/// Check if setting can be changed on a running session.
+///
+/// NOTE: keys are compared using quickfix normalized form (ie: upper case).
+fn is_live_setting(key: &str) -> bool {
+    key.starts_with("SOCKETCONNECTHOST")
+        || key.starts_with("SOCKETCONNECTPORT")
+        || key == "HEARTBTINT"
+}
+
+fn changed_keys(current: &Dictionary, new: &Dictionary) -> Result<Vec<String>, QuickFixError> {
+    let current: HashMap<_, _> = current.iter().collect::<Result<_, _>>()?;
+    let new: HashMap<_, _> = new.iter().collect::<Result<_, _>>()?;
+
+    let mut keys: Vec<_> = current
+        .keys()
+        .chain(new.keys())
+        .filter(|key| current.get(*key) != new.get(*key))
+        .cloned()
+        .collect();
+    keys.sort();
+    keys.dedup();
+    Ok(keys)
+}
+
 /// Socket implementation of establishing connections handler.
 #[derive(Debug)]
 pub struct Initiator<'a, A, S>
@@ -21,22 +110,31 @@ where
     S: FfiMessageStoreFactory,
 {
     inner: FixInitiator_t,
+    application: FixApplication_t,
+    store_factory: FixMessageStoreFactory_t,
+    server_mode: FixSocketServerKind,
+    stop_handle: StopHandle,
     phantom_application: PhantomData<&'a A>,
+    application_callbacks: *const A,
     phantom_message_store_factory: PhantomData<&'a S>,
-    _log_factory: LogFactory<'static, StdLogger>,
+    log_factory: FixLogFactory_t,
+    // Components kept alive by the handler: its log factory, and application / store factory
+    // when they are shared.
+    _owned: Vec<Arc<dyn Any + Send + Sync>>,
 }
 
+// SAFETY: same as `Acceptor`.
 unsafe impl<'a, A, S> Send for Initiator<'a, A, S>
 where
-    A: ApplicationCallback,
-    S: FfiMessageStoreFactory,
+    A: ApplicationCallback + Sync,
+    S: FfiMessageStoreFactory + Sync,
 {
 }
 
 unsafe impl<'a, A, S> Sync for Initiator<'a, A, S>
 where
-    A: ApplicationCallback,
-    S: FfiMessageStoreFactory,
+    A: ApplicationCallback + Sync,
+    S: FfiMessageStoreFactory + Sync,
 {
 }
 
@@ -52,27 +150,255 @@ where
         store_factory: &'a S,
         server_mode: FixSocketServerKind,
     ) -> Result<Self, QuickFixError> {
-        let log_factory = LogFactory::try_new(&StdLogger::Stdout)?;
+        let log_factory = Arc::new(LogFactory::try_new(&StdLogger::Stdout)?);
+        Self::try_new_unbound(
+            settings,
+            application,
+            store_factory,
+            log_factory.0,
+            server_mode,
+            vec![log_factory as Arc<dyn Any + Send + Sync>],
+        )
+    }
 
+    // Components not in `owned` must be kept alive by the caller as long as the handler.
+    pub(crate) fn try_new_unbound(
+        settings: &SessionSettings,
+        application: &Application<A>,
+        store_factory: &S,
+        log_factory: FixLogFactory_t,
+        server_mode: FixSocketServerKind,
+        owned: Vec<Arc<dyn Any + Send + Sync>>,
+    ) -> Result<Self, QuickFixError> {
         match unsafe {
             FixInitiator_new(
                 application.0,
                 store_factory.as_ffi_ptr(),
                 settings.0,
-                log_factory.0,
+                log_factory,
                 server_mode.is_multi_threaded() as i8,
                 server_mode.is_ssl_enabled() as i8,
             )
         } {
             Some(inner) => Ok(Self {
                 inner,
+                server_mode,
+                stop_handle: StopHandle::new(StopTarget::Initiator(inner)),
+                application: application.0,
+                store_factory: store_factory.as_ffi_ptr(),
                 phantom_application: PhantomData,
+                application_callbacks: application.callbacks_ptr(),
                 phantom_message_store_factory: PhantomData,
-                _log_factory: log_factory,
+                log_factory,
+                _owned: owned,
             }),
             None => Err(QuickFixError::from_last_error()),
         }
     }
+
+    /// Add a new session to the initiator.
+    ///
+    /// Session settings are merged with the initiator default settings and session store / log
+    /// are created from factories given at construction.
+    /// If the initiator is running, it will start connecting the session on next reconnect attempt
+    /// (see `ReconnectInterval`).
+    pub fn add_session(
+        &mut self,
+        session_id: &SessionId,
+        settings: &Dictionary,
+    ) -> Result<(), QuickFixError> {
+        ffi_code_to_result(unsafe {
+            FixInitiator_addSession(
+                self.inner,
+                self.application,
+                self.store_factory,
+                self.log_factory,
+                session_id.0,
+                settings.0,
+            )
+        })
+    }
+
+    /// Apply new settings to sessions of a running initiator.
+    ///
+    /// Only `SocketConnectHost*`, `SocketConnectPort*` and `HeartBtInt` can be changed.
+    /// Sessions changing other keys, or not known by the initiator, are reported as rejected.
+    /// Host and port changes take effect on next reconnect.
+    ///
+    /// Sessions not present in `settings` are left untouched, unless `remove_missing` is set.
+    /// In this case they are logged out and will not reconnect.
+    pub fn reload_settings(
+        &mut self,
+        settings: &SessionSettings,
+        remove_missing: bool,
+    ) -> Result<ReloadReport, QuickFixError> {
+        let current_ids = self.session_ids()?;
+        let new_ids = settings.session_ids();
+        let mut report = ReloadReport::default();
+
+        for session_id in &new_ids {
+            let new_dict = settings.get(session_id)?;
+            let Some(current_dict) = self.session_settings(session_id) else {
+                report.rejected.push((
+                    session_id.clone(),
+                    "unknown session, use `add_session` to add it".to_string(),
+                ));
+                continue;
+            };
+
+            let changed = changed_keys(&current_dict, &new_dict)?;
+            if changed.is_empty() {
+                continue;
+            }
+
+            let unsupported: Vec<_> = changed
+                .iter()
+                .filter(|key| !is_live_setting(key))
+                .map(String::as_str)
+                .collect();
+            if !unsupported.is_empty() {
+                report.rejected.push((
+                    session_id.clone(),
+                    format!("keys cannot be changed live: {}", unsupported.join(", ")),
+                ));
+                continue;
+            }
+
+            let heartbeat_interval = if changed.iter().any(|key| key == "HEARTBTINT") {
+                let value = new_dict.get_int("HeartBtInt")?;
+                Some(u32::try_from(value).map_err(|_err| {
+                    QuickFixError::invalid_argument(format!("Invalid HeartBtInt: {value}"))
+                })?)
+            } else {
+                None
+            };
+
+            ffi_code_to_result(unsafe {
+                FixInitiator_setSessionSettings(self.inner, session_id.0, new_dict.0)
+            })?;
+            if let Some(secs) = heartbeat_interval {
+                self.session(session_id.clone())?
+                    .set_heartbeat_interval(secs)?;
+            }
+            report.updated.push(session_id.clone());
+        }
+
+        if remove_missing {
+            let new_reprs: Vec<_> = new_ids.iter().map(SessionId::to_repr).collect();
+            for session_id in current_ids {
+                if new_reprs.contains(&session_id.to_repr()) {
+                    continue;
+                }
+                self.session(session_id.clone())?
+                    .logout_with_reason("Session removed from settings")?;
+                report.removed.push(session_id);
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// Change delay between reconnection attempts, the initiator may be running.
+    ///
+    /// Without session, this changes initiator `ReconnectInterval`: every disconnected session is
+    /// connected again on each interval tick. Interval is rounded up to whole seconds.
+    ///
+    /// With a session, this is the same as `set_reconnect_policy` with a fixed delay.
+    pub fn set_reconnect_interval(
+        &mut self,
+        session_id: Option<&SessionId>,
+        interval: Duration,
+    ) -> Result<(), QuickFixError> {
+        if let Some(session_id) = session_id {
+            return self.set_reconnect_policy(Some(session_id), ReconnectPolicy::Fixed(interval));
+        }
+
+        let mut seconds = interval.as_secs();
+        if interval.subsec_nanos() > 0 {
+            seconds += 1;
+        }
+        let seconds = i32::try_from(seconds)
+            .ok()
+            .filter(|seconds| *seconds > 0)
+            .ok_or_else(|| {
+                QuickFixError::invalid_argument(format!("Invalid reconnect interval: {interval:?}"))
+            })?;
+
+        ffi_code_to_result(unsafe { FixInitiator_setReconnectInterval(self.inner, seconds) })
+    }
+
+    /// Set reconnection policy of a session, or default policy of every session when `None`.
+    ///
+    /// Once a session connection ends (connect failure or disconnection), session is disabled until
+    /// policy delay expires, then reconnects on next initiator `ReconnectInterval` tick.
+    /// Keep `ReconnectInterval` short (ie: 1 second) so delays are applied accurately.
+    ///
+    /// Sessions disabled by user (ie: `Session::logout`) are left untouched, also when disabled while
+    /// waiting for policy delay: they stay disabled until `Session::logon`.
+    pub fn set_reconnect_policy(
+        &mut self,
+        session_id: Option<&SessionId>,
+        policy: ReconnectPolicy,
+    ) -> Result<(), QuickFixError> {
+        let (initial_millis, max_millis, jitter_millis) = policy.bounds_millis()?;
+        ffi_code_to_result(unsafe {
+            FixInitiator_setReconnectPolicy(
+                self.inner,
+                session_id.map(|session_id| session_id.0),
+                initial_millis,
+                max_millis,
+                jitter_millis,
+            )
+        })
+    }
+
+    /// Get a copy of the settings currently used by a session, or `None` if not found.
+    fn session_settings(&self, session_id: &SessionId) -> Option<Dictionary> {
+        let ptr = unsafe { FixInitiator_getSessionSettingsRef(self.inner, session_id.0) }?;
+        let current = ManuallyDrop::new(Dictionary(ptr));
+        Some(Dictionary::clone(&current))
+    }
+}
+
+impl<A, S> Initiator<'static, A, S>
+where
+    A: ApplicationCallback + Send + Sync + 'static,
+    S: FfiMessageStoreFactory + Send + Sync + 'static,
+{
+    /// Same as `try_new`, but share ownership of application and store factory instead of
+    /// borrowing them.
+    ///
+    /// Resulting initiator does not borrow anything: it can be stored in the same struct as other
+    /// handlers and moved to another thread. See `Application::try_new_shared` for an example.
+    pub fn try_new_shared(
+        settings: &SessionSettings,
+        application: Arc<Application<'static, A>>,
+        store_factory: Arc<S>,
+        server_mode: FixSocketServerKind,
+    ) -> Result<Self, QuickFixError> {
+        let log_factory = Arc::new(LogFactory::try_new(&StdLogger::Stdout)?);
+        let log_factory_ptr = log_factory.0;
+        let owned: [Arc<dyn Any + Send + Sync>; 3] =
+            [log_factory, application.clone(), store_factory.clone()];
+        Self::try_new_unbound(
+            settings,
+            &application,
+            &store_factory,
+            log_factory_ptr,
+            server_mode,
+            owned.into(),
+        )
+    }
+}
+
+impl<A> Initiator<'static, A, MemoryMessageStoreFactory>
+where
+    A: ApplicationCallback + Send + Sync + 'static,
+{
+    /// Start building a new initiator, see [`ConnectionHandlerBuilder`].
+    pub fn builder(settings: &SessionSettings) -> ConnectionHandlerBuilder<'_, ForInitiator, A> {
+        ConnectionHandlerBuilder::new(settings)
+    }
 }
 
 impl<A, S> ConnectionHandler for Initiator<'_, A, S>
@@ -88,14 +414,34 @@ where
         ffi_code_to_result(unsafe { FixInitiator_block(self.inner) })
     }
 
-    fn poll(&mut self) -> Result<bool, QuickFixError> {
-        ffi_code_to_bool(unsafe { FixInitiator_poll(self.inner) })
+    fn poll(&mut self, timeout: Option<Duration>) -> Result<bool, QuickFixError> {
+        if self.server_mode.is_multi_threaded() {
+            return Err(QuickFixError::invalid_argument(
+                "poll is only supported by single threaded initiator",
+            ));
+        }
+
+        let timeout = timeout.map_or(0.0, |timeout| timeout.as_secs_f64());
+        ffi_code_to_bool(unsafe { FixInitiator_poll(self.inner, timeout) })
     }
 
     fn stop(&mut self) -> Result<(), QuickFixError> {
         ffi_code_to_result(unsafe { FixInitiator_stop(self.inner) })
     }
 
+    fn stop_force(&mut self) -> Result<(), QuickFixError> {
+        ffi_code_to_result(unsafe { FixInitiator_stopForce(self.inner) })
+    }
+
+    fn stop_handle(&self) -> StopHandle {
+        self.stop_handle.clone()
+    }
+
+    fn stop_with_reason(&mut self, reason: &str) -> Result<(), QuickFixError> {
+        let ffi_reason = CString::new(reason)?;
+        ffi_code_to_result(unsafe { FixInitiator_stopWithReason(self.inner, ffi_reason.as_ptr()) })
+    }
+
     fn is_logged_on(&self) -> Result<bool, QuickFixError> {
         ffi_code_to_bool(unsafe { FixInitiator_isLoggedOn(self.inner) })
     }
@@ -103,6 +449,33 @@ where
     fn is_stopped(&self) -> Result<bool, QuickFixError> {
         ffi_code_to_bool(unsafe { FixInitiator_isStopped(self.inner) })
     }
+
+    fn session_ids(&self) -> Result<Vec<SessionId>, QuickFixError> {
+        let session_count: u64 = unsafe { FixInitiator_getSessionCount(self.inner) }
+            .try_into()
+            .map_err(|_err| QuickFixError::from_last_error())?;
+
+        (0..session_count)
+            .map(|index| {
+                unsafe { FixInitiator_getSessionIdAt(self.inner, index) }
+                    .map(SessionId::from_ffi)
+                    .ok_or_else(QuickFixError::from_last_error)
+            })
+            .collect()
+    }
+
+    fn remove_session(
+        &mut self,
+        session_id: &SessionId,
+        force_disconnect: bool,
+    ) -> Result<(), QuickFixError> {
+        ffi_code_to_result(unsafe {
+            FixInitiator_removeSession(self.inner, session_id.0, force_disconnect as i8)
+        })?;
+        // SAFETY: application outlives handler, it is either borrowed or in `_owned`.
+        unsafe { notify_session_removed(self.application_callbacks, session_id) };
+        Ok(())
+    }
 }
 
 impl<A, S> SessionContainer for Initiator<'_, A, S>
@@ -130,7 +503,10 @@ where
     S: FfiMessageStoreFactory,
 {
     fn drop(&mut self) {
-        let _ = self.stop();
-        unsafe { FixInitiator_delete(self.inner) }
+        let stop_handle = self.stop_handle.clone();
+        stop_handle.detach(|| {
+            let _ = self.stop();
+            unsafe { FixInitiator_delete(self.inner) }
+        });
     }
 }
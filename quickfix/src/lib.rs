@@ -104,75 +104,310 @@ acceptor.stop()?;
 
 mod acceptor;
 mod application;
+mod application_mut;
+mod application_tee;
+#[cfg(feature = "tokio")]
+mod async_application;
+#[cfg(feature = "tokio")]
+pub mod async_handler;
+mod clock;
+mod connection_handler_builder;
 mod data_dictionary;
 mod days;
 mod dictionary;
 /// Common dictionary configuration parameters.
 pub mod dictionary_item;
+#[cfg(feature = "metrics")]
+pub mod engine_metrics;
 mod error;
+mod field_batch;
+mod field_name_resolver;
 mod group;
 mod header;
 mod initiator;
 mod log_factory;
 mod message;
+mod message_fixml;
+#[cfg(feature = "json")]
+mod message_json;
+mod message_pool;
+mod message_router;
+#[cfg(feature = "serde")]
+mod message_serde;
 mod message_store_factory;
+mod message_tree;
+pub mod msg_types;
+mod outgoing_enricher;
+mod panic_policy;
+mod parse_limits;
+pub mod replay;
 mod session;
+mod session_applications;
 mod session_id;
 mod session_settings;
+#[cfg(feature = "toml")]
+mod session_settings_toml;
+mod socket_options;
+mod stop_handle;
+mod store_snapshot;
+#[cfg(all(feature = "syslog", unix))]
+mod syslog_logger;
+pub mod tags;
+#[cfg(feature = "testing")]
+pub mod testing;
+#[cfg(feature = "tracing")]
+mod tracing_logger;
 mod trailer;
 
 mod utils;
 
-use std::ffi::{CString, NulError};
+use std::{
+    ffi::{CString, NulError},
+    str::FromStr,
+    thread,
+    time::{Duration, Instant},
+};
+
+use utils::utf8_to_string;
 
 pub use acceptor::Acceptor;
 pub use application::{
-    Application, ApplicationCallback, MsgFromAdminError, MsgFromAppError, MsgToAppError,
+    AdminMsgKind, Application, ApplicationCallback, ConnectError, ConnectErrorKind,
+    DisconnectReason, LogonContext, LogoutContext, LogoutKind, MsgFromAdminError, MsgFromAppError,
+    MsgToAppError,
 };
-pub use data_dictionary::DataDictionary;
+pub use application_mut::{ApplicationCallbackMut, ExclusiveApplication};
+pub use application_tee::ApplicationTee;
+pub use clock::{Clock, ClockedApplication, ManualClock, SystemClock};
+pub use connection_handler_builder::{ConnectionHandlerBuilder, ForAcceptor, ForInitiator};
+pub use data_dictionary::{DataDictionary, FieldSpec, FixFieldType, GroupSpec, ValidationError};
 pub use days::DayOfWeek;
-pub use dictionary::Dictionary;
-pub use error::QuickFixError;
+pub use dictionary::{Dictionary, DictionaryIter};
+pub use error::{ErrorKind, QuickFixError};
+pub use field_batch::FieldBatch;
+pub use field_name_resolver::FieldNameResolver;
 pub use group::Group;
-pub use header::Header;
-pub use initiator::Initiator;
-pub use log_factory::{LogCallback, LogFactory, NullLogger, StdLogger};
+pub use header::{Header, HeaderMut, HeaderRef};
+pub use initiator::{Initiator, ReconnectPolicy, ReloadReport};
+pub use log_factory::{
+    AsyncLogger, CompositeLogger, EventSeverity, FfiLogFactory, FileLogger, FilteredLogger,
+    LogCallback, LogDirection, LogFactory, LogFilter, LogOverflowPolicy, MessageLogRecord,
+    NullLogger, PerSessionLogFactory, Rotation, SampledLogger, SamplingPolicy, ScreenLogger,
+    StdLogger, StructuredLogCallback, StructuredLogger,
+};
 pub use message::Message;
+pub use message_pool::{MessagePool, PooledMessage};
+pub use message_router::{MessageRouter, RoutedApplication};
 pub use message_store_factory::{
-    FfiMessageStoreFactory, FileMessageStoreFactory, MemoryMessageStoreFactory,
+    CompositeMessageStoreFactory, CustomMessageStoreFactory, FfiMessageStoreFactory,
+    FileMessageStoreFactory, MemoryMessageStoreFactory, MessageStoreCallback,
     NullMessageStoreFactory,
 };
-pub use session::{send_to_target, send_to_target_by_ref_mut, Session};
+pub use outgoing_enricher::{EnrichScope, EnrichedApplication, OutgoingEnricher};
+pub use panic_policy::{panic_policy, set_panic_policy, PanicPolicy};
+pub use parse_limits::ParseLimits;
+pub use session::{
+    send_all_to_target, send_to_target, send_to_target_by_ref_mut, send_to_target_with_comp_ids,
+    try_send_to_target, ResolvedSession, Session, SessionStats, SessionStatus, StoreMetrics,
+    StoredMessage, TransportStats,
+};
+pub use session_applications::{SessionApplications, SessionCallbacks};
 pub use session_id::SessionId;
-pub use session_settings::SessionSettings;
+pub use session_settings::{SessionSettings, SessionSettingsBuilder, SettingsError};
+pub use socket_options::SocketOptions;
+pub use stop_handle::StopHandle;
 pub use trailer::Trailer;
 
+#[cfg(feature = "tokio")]
+pub use async_application::{AsyncApplication, FixEvent, OverflowPolicy};
 #[cfg(feature = "log")]
-pub use log_factory::RustLogger;
+pub use log_factory::{LogCrateLogger, RustLogger};
+#[cfg(feature = "gzip")]
+pub use message_store_factory::compressed::{
+    CompressedFileMessageStoreFactory, CompressedFileStore,
+};
 #[cfg(feature = "build-with-mysql")]
 pub use message_store_factory::mysql::MySqlMessageStoreFactory;
 #[cfg(feature = "build-with-postgres")]
 pub use message_store_factory::postgres::PostgresMessageStoreFactory;
+#[cfg(feature = "sqlite")]
+pub use message_store_factory::sqlite::SqliteMessageStoreFactory;
+#[cfg(all(feature = "syslog", unix))]
+pub use syslog_logger::{SyslogFacility, SyslogFormat, SyslogLogger};
+#[cfg(feature = "tracing")]
+pub use tracing_logger::TracingLogger;
+
+/// Outcome of [`ConnectionHandler::shutdown`].
+#[derive(Debug, Default, Clone)]
+pub struct ShutdownReport {
+    /// Sessions which completed the logout handshake.
+    pub clean: Vec<SessionId>,
+    /// Sessions which did not answer logout in time and have been disconnected.
+    pub forced: Vec<SessionId>,
+}
 
 /// Permit control of an underlying socket connection.
 pub trait ConnectionHandler {
     /// Start handler.
     fn start(&mut self) -> Result<(), QuickFixError>;
 
-    /// Block handler.
+    /// Run handler on the calling thread.
+    ///
+    /// This function does not return until the handler is stopped.
+    /// Use a [`StopHandle`] from [`ConnectionHandler::stop_handle`] to stop it from another thread.
     fn block(&mut self) -> Result<(), QuickFixError>;
 
-    /// Poll handler.
-    fn poll(&mut self) -> Result<bool, QuickFixError>;
+    /// Run a single iteration of the handler on the calling thread.
+    ///
+    /// Useful to drive the FIX engine from an existing event loop, without calling `start`.
+    /// `timeout` is forwarded to quickfix socket monitor, `None` means do not wait.
+    /// Return `false` once handler has been stopped.
+    ///
+    /// Only available with single threaded servers, other kinds return an error.
+    fn poll(&mut self, timeout: Option<Duration>) -> Result<bool, QuickFixError>;
 
     /// Stop handler.
     fn stop(&mut self) -> Result<(), QuickFixError>;
 
-    /// Check if handler has sent logging message or not.
+    /// Stop handler without waiting for sessions to logout.
+    ///
+    /// Sockets are closed right away, so this does not hang when counterparties stop responding.
+    /// No logout message is sent and `ApplicationCallback::on_logout` may not be called for every
+    /// session.
+    ///
+    /// Default implementation calls [`Self::stop`].
+    fn stop_force(&mut self) -> Result<(), QuickFixError> {
+        self.stop()
+    }
+
+    /// Stop handler, then start it again.
+    ///
+    /// Sessions are not recreated: sequence numbers and stored messages survive a restart (even
+    /// with a memory store), so counterparties see a regular reconnection.
+    /// Use `Session::reset` to start again from sequence number 1.
+    ///
+    /// Acceptors bind their ports again, so a `SocketAcceptPort` of 0 gets a new port from the OS.
+    fn restart(&mut self) -> Result<(), QuickFixError> {
+        self.stop()?;
+        self.start()
+    }
+
+    /// Logout every session, wait up to `timeout` for counterparties to answer, then stop handler.
+    ///
+    /// Sessions still logged on once `timeout` is reached are disconnected.
+    /// Report only contains sessions that were logged on when shutdown started.
+    fn shutdown(&mut self, timeout: Duration) -> Result<ShutdownReport, QuickFixError>
+    where
+        Self: SessionContainer,
+    {
+        let mut logged_out = Vec::new();
+        for session_id in self.session_ids()? {
+            let mut session = self.session(session_id.clone())?;
+            if session.is_logged_on()? {
+                session.logout()?;
+                logged_out.push(session_id);
+            }
+        }
+
+        let deadline = Instant::now() + timeout;
+        let mut report = ShutdownReport::default();
+        let mut pending = logged_out.clone();
+        loop {
+            let mut still_logged_on = Vec::new();
+            for session_id in pending {
+                if self.is_session_logged_on(&session_id)? {
+                    still_logged_on.push(session_id);
+                } else {
+                    report.clean.push(session_id);
+                }
+            }
+            pending = still_logged_on;
+
+            if pending.is_empty() || Instant::now() >= deadline {
+                break;
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+        report.forced = pending;
+
+        self.stop_force()?;
+
+        // Same as `stop`, so sessions can log on again on next start.
+        for session_id in logged_out {
+            self.session(session_id)?.logon()?;
+        }
+
+        Ok(report)
+    }
+
+    /// Get a handle that can stop the handler from any thread.
+    fn stop_handle(&self) -> StopHandle;
+
+    /// Logout every session with the given reason, then stop handler.
+    ///
+    /// Reason is sent to the counterparties in the Text(58) field of the Logout messages.
+    ///
+    /// Default implementation returns an error without stopping, handlers must override it to
+    /// send `reason`. Use [`Self::stop`] to stop them with a plain logout.
+    fn stop_with_reason(&mut self, reason: &str) -> Result<(), QuickFixError> {
+        let _ = reason;
+        Err(QuickFixError::invalid_argument(
+            "stop_with_reason is not supported by this handler",
+        ))
+    }
+
+    /// Check if at least one session of the handler is logged on.
+    ///
+    /// Use `SessionContainer::is_session_logged_on` to get the status of a specific session.
     fn is_logged_on(&self) -> Result<bool, QuickFixError>;
 
     /// Check if handler is currently working or not.
+    ///
+    /// Handler is stopped until `start` is called, and again after `stop`.
     fn is_stopped(&self) -> Result<bool, QuickFixError>;
+
+    /// List ID of every session registered in the handler.
+    ///
+    /// Default implementation returns an error, handlers must override it to be enumerable.
+    fn session_ids(&self) -> Result<Vec<SessionId>, QuickFixError> {
+        Err(QuickFixError::invalid_argument(
+            "session_ids is not supported by this handler",
+        ))
+    }
+
+    /// Borrow every session registered in the handler.
+    ///
+    /// Sessions borrow the handler, so they cannot be used once it is stopped or dropped.
+    fn sessions(&self) -> Result<Vec<Session<'_>>, QuickFixError>
+    where
+        Self: SessionContainer,
+    {
+        self.session_ids()?
+            .into_iter()
+            .map(|session_id| self.session(session_id))
+            .collect()
+    }
+
+    /// Logout and permanently remove a session from a running handler.
+    ///
+    /// Once removed, messages cannot be sent to the session anymore and counterparty logon
+    /// attempts are refused.
+    /// When `force_disconnect` is `false`, wait for counterparty to answer logout before
+    /// disconnecting (up to session `LogoutTimeout`).
+    ///
+    /// Handler must be started, so that it can release the session connection.
+    ///
+    /// Default implementation returns an error, sessions of such handlers cannot be removed.
+    fn remove_session(
+        &mut self,
+        session_id: &SessionId,
+        force_disconnect: bool,
+    ) -> Result<(), QuickFixError> {
+        let _ = (session_id, force_disconnect);
+        Err(QuickFixError::invalid_argument(
+            "remove_session is not supported by this handler",
+        ))
+    }
 }
 
 /// Define a container of session
@@ -183,6 +418,11 @@ pub trait SessionContainer {
     ///
     /// Session is lookup using its ID.
     fn session(&self, session_id: SessionId) -> Result<Session<'_>, QuickFixError>;
+
+    /// Check if the session with the given ID is logged on.
+    fn is_session_logged_on(&self, session_id: &SessionId) -> Result<bool, QuickFixError> {
+        self.session(session_id.clone())?.is_logged_on()
+    }
 }
 
 /// Convert object to FIX value.
@@ -233,6 +473,18 @@ impl IntoFixValue for &str {
     }
 }
 
+impl IntoFixValue for &[u8] {
+    fn into_fix_value(self) -> Result<CString, NulError> {
+        CString::new(self)
+    }
+}
+
+impl IntoFixValue for Vec<u8> {
+    fn into_fix_value(self) -> Result<CString, NulError> {
+        CString::new(self)
+    }
+}
+
 impl IntoFixValue for bool {
     fn into_fix_value(self) -> Result<CString, NulError> {
         // Check reference here: https://www.onixs.biz/fix-dictionary/4.3/tagNum_575.html
@@ -246,8 +498,139 @@ impl IntoFixValue for bool {
 /// class uses a sorter to keep the fields in a particular order.
 pub trait FieldMap {
     /// Get field value from its tag number.
+    ///
+    /// Returns `None` when field is missing or is not valid UTF-8, use [`Self::try_get_field`]
+    /// to tell them apart.
     fn get_field(&self, tag: i32) -> Option<String>;
 
+    /// Get raw field value from its tag number, whatever its encoding.
+    ///
+    /// Default implementation reads [`Self::get_field`], FFI backed implementations override it
+    /// to return original bytes.
+    fn get_field_bytes(&self, tag: i32) -> Option<Vec<u8>> {
+        self.get_field(tag).map(String::into_bytes)
+    }
+
+    /// Get field value from its tag number, failing with [`QuickFixError::InvalidUtf8`] when
+    /// value is not valid UTF-8.
+    fn try_get_field(&self, tag: i32) -> Result<Option<String>, QuickFixError> {
+        self.get_field_bytes(tag)
+            .map(|bytes| utf8_to_string(Some(tag), bytes))
+            .transpose()
+    }
+
+    /// Get field value from its tag number, replacing invalid UTF-8 sequences with `U+FFFD`.
+    fn get_field_lossy(&self, tag: i32) -> Option<String> {
+        self.get_field_bytes(tag)
+            .map(|bytes| String::from_utf8_lossy(&bytes).into_owned())
+    }
+
+    /// Get field value from its tag number, parsed as `T`.
+    ///
+    /// Fails with [`QuickFixError::FieldNotFound`] when field is missing, with
+    /// [`QuickFixError::InvalidUtf8`] when it is not valid UTF-8 and with
+    /// [`QuickFixError::FieldConvertError`] when value cannot be parsed. All carry `tag`, see
+    /// [`QuickFixError::tag`].
+    fn get_field_as<T: FromStr>(&self, tag: i32) -> Result<T, QuickFixError> {
+        let value = self
+            .try_get_field(tag)?
+            .ok_or_else(|| QuickFixError::field_not_found(tag))?;
+        value
+            .parse()
+            .map_err(|_err| QuickFixError::field_convert_error(tag, value))
+    }
+
+    /// Append raw value of field `tag` to `buf`, returning number of bytes appended.
+    ///
+    /// Fails with [`QuickFixError::FieldNotFound`] when field is missing, leaving `buf` unchanged.
+    /// Truncate and reuse `buf` between calls so no allocation happens once it is large enough.
+    ///
+    /// `buf` holds a copy of the value: it is not updated by later mutations of the field map, and
+    /// stays valid after the field map is mutated or dropped. FFI backed implementations borrow
+    /// C++ value only for the duration of the call, which `&self` protects from mutations.
+    ///
+    /// Default implementation reads [`Self::get_field_bytes`], FFI backed implementations override
+    /// it to copy value straight from C++ memory.
+    fn get_field_bytes_into(&self, tag: i32, buf: &mut Vec<u8>) -> Result<usize, QuickFixError> {
+        let value = self
+            .get_field_bytes(tag)
+            .ok_or_else(|| QuickFixError::field_not_found(tag))?;
+        buf.extend_from_slice(&value);
+        Ok(value.len())
+    }
+
+    /// Same as [`Self::get_field_bytes_into`], appending to a `String`.
+    ///
+    /// Fails with [`QuickFixError::InvalidUtf8`] when value is not valid UTF-8, leaving `buf`
+    /// unchanged.
+    ///
+    /// ```
+    /// use quickfix::*;
+    ///
+    /// # fn read(msgs: &[Message]) -> Result<(), QuickFixError> {
+    /// let mut symbol = String::with_capacity(32);
+    /// for msg in msgs {
+    ///     symbol.clear();
+    ///     msg.get_field_into(55, &mut symbol)?;
+    ///     println!("{symbol}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn get_field_into(&self, tag: i32, buf: &mut String) -> Result<usize, QuickFixError> {
+        /// Truncates appended bytes back to `start` unless disarmed, including on unwind.
+        struct Utf8Guard<'a> {
+            bytes: &'a mut Vec<u8>,
+            start: usize,
+        }
+
+        impl Drop for Utf8Guard<'_> {
+            fn drop(&mut self) {
+                self.bytes.truncate(self.start);
+            }
+        }
+
+        let start = buf.len();
+        // SAFETY: `get_field_bytes_into` may be overridden, return early or panic after
+        // appending anything. The guard removes appended bytes on every path but the one where
+        // they are checked to be valid UTF-8, so `buf` never holds invalid UTF-8 once released.
+        let mut guard = Utf8Guard {
+            bytes: unsafe { buf.as_mut_vec() },
+            start,
+        };
+        let len = self.get_field_bytes_into(tag, guard.bytes)?;
+        if let Err(_err) = std::str::from_utf8(&guard.bytes[start..]) {
+            let invalid = guard.bytes[start..].to_vec();
+            return Err(QuickFixError::InvalidUtf8 {
+                tag: Some(tag),
+                bytes: invalid,
+            });
+        }
+        guard.start = guard.bytes.len();
+        Ok(len)
+    }
+
+    /// Get values of several fields at once, in `tags` order, `None` for missing ones.
+    ///
+    /// Fails with [`QuickFixError::InvalidUtf8`] when one of the values is not valid UTF-8.
+    fn get_fields(&self, tags: &[i32]) -> Result<Vec<Option<String>>, QuickFixError> {
+        let mut batch = FieldBatch::new();
+        self.get_fields_into(tags, &mut batch)?;
+        Ok(batch
+            .iter()
+            .map(|value| value.map(str::to_string))
+            .collect())
+    }
+
+    /// Same as [`Self::get_fields`], reading values into a reusable `batch` instead of
+    /// allocating new strings.
+    ///
+    /// Default implementation reads fields one by one, FFI backed implementations override it to
+    /// read them all with a single FFI call.
+    fn get_fields_into(&self, tags: &[i32], batch: &mut FieldBatch) -> Result<(), QuickFixError> {
+        batch.fill_with(tags, |tag| self.get_field_bytes(tag))
+    }
+
     /// Compare field value with an input string.
     ///
     /// Default implementation fetches and compares in Rust.
@@ -259,12 +642,70 @@ pub trait FieldMap {
     /// Set field value for a given tag number.
     fn set_field<V: IntoFixValue>(&mut self, tag: i32, value: V) -> Result<(), QuickFixError>;
 
+    /// Set raw field value for a given tag number, whatever its encoding.
+    ///
+    /// Value is passed with its length and no NUL terminator, so no intermediate `CString` is
+    /// allocated and value may contain NUL bytes, e.g. for binary `data` fields.
+    ///
+    /// Default implementation calls [`Self::set_field`], FFI backed implementations override it
+    /// to pass bytes as is.
+    fn set_field_raw(&mut self, tag: i32, value: &[u8]) -> Result<(), QuickFixError> {
+        self.set_field(tag, value)
+    }
+
+    /// Set string field value for a given tag number, see [`Self::set_field_raw`].
+    fn set_field_str(&mut self, tag: i32, value: &str) -> Result<(), QuickFixError> {
+        self.set_field_raw(tag, value.as_bytes())
+    }
+
     /// Remove a field from  collection.
     fn remove_field(&mut self, tag: i32) -> Result<(), QuickFixError>;
 
     /// Add a new group to storage and update count.
     fn add_group(&mut self, group: &Group) -> Result<(), QuickFixError>;
 
+    /// Add one copy of `template` per row, with fields of the row set on it, and update count.
+    ///
+    /// Rows are added in order after already existing groups. When a row cannot be added, no
+    /// group is added at all.
+    ///
+    /// Default implementation adds groups one by one, FFI backed implementations override it to
+    /// add them all with a single FFI call.
+    ///
+    /// ```
+    /// use quickfix::*;
+    ///
+    /// # fn build() -> Result<(), QuickFixError> {
+    /// let mut msg = Message::new();
+    /// let mut template = Group::try_new(268, 269)?; // NoMDEntries, MDEntryType
+    /// template.set_field(269, "0")?;
+    ///
+    /// msg.add_groups(&template, &[&[(270, "1.25"), (271, "100")], &[(270, "1.26")]])?;
+    /// assert_eq!(msg.get_field(268).as_deref(), Some("2"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn add_groups(
+        &mut self,
+        template: &Group,
+        rows: &[&[(i32, &str)]],
+    ) -> Result<(), QuickFixError> {
+        let groups = rows
+            .iter()
+            .map(|row| {
+                let mut group = template.clone();
+                for (tag, value) in row.iter() {
+                    group.set_field(*tag, *value)?;
+                }
+                Ok(group)
+            })
+            .collect::<Result<Vec<_>, QuickFixError>>()?;
+        for group in &groups {
+            self.add_group(group)?;
+        }
+        Ok(())
+    }
+
     /// Clone group part for a given tag and group index.
     fn clone_group(&self, index: i32, tag: i32) -> Option<Group>;
 }
@@ -291,10 +732,10 @@ pub enum FixSocketServerKind {
     /// Multi threaded version of Acceptor and Initiator.
     MultiThreaded,
     /// Single threaded version of Acceptor and Initiator with SSL support.
-    #[cfg(feature = "build-with-ssl")]
+    #[cfg(quickfix_ssl)]
     SslSingleThreaded,
     /// Multi threaded version of Acceptor and Initiator with SSL support.
-    #[cfg(feature = "build-with-ssl")]
+    #[cfg(quickfix_ssl)]
     SslMultiThreaded,
 }
 
@@ -307,9 +748,9 @@ impl FixSocketServerKind {
         match self {
             Self::SingleThreaded => true,
             Self::MultiThreaded => false,
-            #[cfg(feature = "build-with-ssl")]
+            #[cfg(quickfix_ssl)]
             Self::SslSingleThreaded => true,
-            #[cfg(feature = "build-with-ssl")]
+            #[cfg(quickfix_ssl)]
             Self::SslMultiThreaded => false,
         }
     }
@@ -318,9 +759,9 @@ impl FixSocketServerKind {
         match self {
             Self::SingleThreaded => false,
             Self::MultiThreaded => false,
-            #[cfg(feature = "build-with-ssl")]
+            #[cfg(quickfix_ssl)]
             Self::SslSingleThreaded => true,
-            #[cfg(feature = "build-with-ssl")]
+            #[cfg(quickfix_ssl)]
             Self::SslMultiThreaded => true,
         }
     }
@@ -2,7 +2,6 @@ use std::{
     ffi, fmt,
     io::{self, Write},
     marker::PhantomData,
-    mem::ManuallyDrop,
     panic::catch_unwind,
 };
 
@@ -10,7 +9,28 @@ use quickfix_ffi::{
     FixLogCallbacks_t, FixLogFactory_delete, FixLogFactory_new, FixLogFactory_t, FixSessionID_t,
 };
 
-use crate::{utils::from_ffi_str, QuickFixError, SessionId};
+use crate::{panic_policy::handle_log_panic, utils::from_ffi_str, QuickFixError, SessionId};
+
+mod async_logger;
+mod composite;
+mod file;
+mod filter;
+mod per_session;
+mod sampled;
+mod screen;
+mod severity;
+mod structured;
+pub(crate) mod timestamp;
+
+pub use async_logger::{AsyncLogger, LogOverflowPolicy};
+pub use composite::CompositeLogger;
+pub use file::{FileLogger, Rotation};
+pub use filter::{FilteredLogger, LogDirection, LogFilter};
+pub use per_session::PerSessionLogFactory;
+pub use sampled::{SampledLogger, SamplingPolicy};
+pub use screen::ScreenLogger;
+pub use severity::EventSeverity;
+pub use structured::{MessageLogRecord, StructuredLogCallback, StructuredLogger};
 
 /// Log event that can occurs in quickfix library.
 ///
@@ -25,11 +45,36 @@ pub trait LogCallback {
 
     /// Other FIX event has occurred.
     fn on_event(&self, session_id: Option<&SessionId>, msg: &str) {}
+
+    /// Other FIX event has occurred, with its severity.
+    ///
+    /// This is what the FIX engine calls, with a severity inferred by [`EventSeverity::infer`].
+    /// Default implementation ignores severity and calls [`Self::on_event`], so override this one
+    /// to route warnings and errors to another sink.
+    fn on_event_with_severity(
+        &self,
+        session_id: Option<&SessionId>,
+        msg: &str,
+        severity: EventSeverity,
+    ) {
+        self.on_event(session_id, msg);
+    }
+}
+
+/// Object can be converted as a foreign object representing a `LogFactory`.
+pub trait FfiLogFactory {
+    /// Get a representation of the log factory as a FFI pointer.
+    fn as_ffi_ptr(&self) -> FixLogFactory_t;
 }
 
 /// Logging factory.
 pub struct LogFactory<'a, C: LogCallback>(pub(crate) FixLogFactory_t, PhantomData<&'a C>);
 
+// SAFETY: C++ factory only keeps a pointer to the callbacks, which are called from quickfix threads
+// anyway, so they must be `Sync` for the factory to be moved or shared.
+unsafe impl<C: LogCallback + Sync> Send for LogFactory<'_, C> {}
+unsafe impl<C: LogCallback + Sync> Sync for LogFactory<'_, C> {}
+
 impl<'a, C> LogFactory<'a, C>
 where
     C: LogCallback + 'static,
@@ -57,42 +102,57 @@ where
         data: *const ffi::c_void,
         session_id_ptr: Option<FixSessionID_t>,
         msg_ptr: *const ffi::c_char,
+        msg_len: u64,
     ) {
-        let session_id = session_id_ptr.map(|ptr| ManuallyDrop::new(SessionId(ptr)));
-        let msg = unsafe { from_ffi_str(msg_ptr) };
+        let session_id = session_id_ptr.map(SessionId::borrow_ffi);
+        let msg = unsafe { from_ffi_str(msg_ptr, msg_len) };
 
-        let _ = catch_unwind(|| {
+        if let Err(payload) = catch_unwind(|| {
             let this = unsafe { &*(data as *const C) };
             this.on_incoming(session_id.as_deref(), msg);
-        });
+        }) {
+            handle_log_panic("on_incoming", session_id.as_deref(), payload);
+        }
     }
 
     extern "C" fn on_outgoing(
         data: *const ffi::c_void,
         session_id_ptr: Option<FixSessionID_t>,
         msg_ptr: *const ffi::c_char,
+        msg_len: u64,
     ) {
-        let session_id = session_id_ptr.map(|ptr| ManuallyDrop::new(SessionId(ptr)));
-        let msg = unsafe { from_ffi_str(msg_ptr) };
+        let session_id = session_id_ptr.map(SessionId::borrow_ffi);
+        let msg = unsafe { from_ffi_str(msg_ptr, msg_len) };
 
-        let _ = catch_unwind(|| {
+        if let Err(payload) = catch_unwind(|| {
             let this = unsafe { &*(data as *const C) };
             this.on_outgoing(session_id.as_deref(), msg);
-        });
+        }) {
+            handle_log_panic("on_outgoing", session_id.as_deref(), payload);
+        }
     }
 
     extern "C" fn on_event(
         data: *const ffi::c_void,
         session_id_ptr: Option<FixSessionID_t>,
         msg_ptr: *const ffi::c_char,
+        msg_len: u64,
     ) {
-        let session_id = session_id_ptr.map(|ptr| ManuallyDrop::new(SessionId(ptr)));
-        let msg = unsafe { from_ffi_str(msg_ptr) };
+        let session_id = session_id_ptr.map(SessionId::borrow_ffi);
+        let msg = unsafe { from_ffi_str(msg_ptr, msg_len) };
 
-        let _ = catch_unwind(|| {
+        if let Err(payload) = catch_unwind(|| {
             let this = unsafe { &*(data as *const C) };
-            this.on_event(session_id.as_deref(), msg);
-        });
+            this.on_event_with_severity(session_id.as_deref(), msg, EventSeverity::infer(msg));
+        }) {
+            handle_log_panic("on_event", session_id.as_deref(), payload);
+        }
+    }
+}
+
+impl<C: LogCallback> FfiLogFactory for LogFactory<'_, C> {
+    fn as_ffi_ptr(&self) -> FixLogFactory_t {
+        self.0
     }
 }
 
@@ -115,6 +175,8 @@ pub struct NullLogger;
 impl LogCallback for NullLogger {}
 
 /// Log message to std file descriptors.
+///
+/// Only FIX events are logged, use [`ScreenLogger`] to log messages too and to configure output.
 pub enum StdLogger {
     /// Log to stdout.
     Stdout,
@@ -148,6 +210,8 @@ impl LogCallback for StdLogger {
 }
 
 /// Log message using `log` crate.
+///
+/// Only FIX events are logged, use [`LogCrateLogger`] to log messages too.
 #[derive(Debug)]
 #[cfg(feature = "log")]
 pub struct RustLogger;
@@ -159,3 +223,54 @@ impl LogCallback for RustLogger {
         log::info!("FIX: Event: {session_id:?}: {msg}");
     }
 }
+
+/// Forward everything to `log` crate, so FIX logs end up with the rest of the application ones.
+///
+/// Every record starts with the session it belongs to, SOH separators are displayed as `|`:
+/// - events are logged at info, warn or error level depending on their [`EventSeverity`], with
+///   target `quickfix::event`,
+/// - incoming messages at debug level, with target `quickfix::incoming`,
+/// - outgoing messages at debug level, with target `quickfix::outgoing`.
+#[derive(Debug)]
+#[cfg(feature = "log")]
+pub struct LogCrateLogger;
+
+#[cfg(feature = "log")]
+impl LogCrateLogger {
+    fn log(level: log::Level, target: &str, session_id: Option<&SessionId>, msg: &str) {
+        if !log::log_enabled!(target: target, level) {
+            return;
+        }
+        let session = session_id.map_or_else(|| "-".to_string(), SessionId::to_repr);
+        log::log!(target: target, level, "{session}: {}", msg.replace('\x01', "|"));
+    }
+}
+
+#[cfg(feature = "log")]
+impl LogCallback for LogCrateLogger {
+    fn on_incoming(&self, session_id: Option<&SessionId>, msg: &str) {
+        Self::log(log::Level::Debug, "quickfix::incoming", session_id, msg);
+    }
+
+    fn on_outgoing(&self, session_id: Option<&SessionId>, msg: &str) {
+        Self::log(log::Level::Debug, "quickfix::outgoing", session_id, msg);
+    }
+
+    fn on_event(&self, session_id: Option<&SessionId>, msg: &str) {
+        self.on_event_with_severity(session_id, msg, EventSeverity::infer(msg));
+    }
+
+    fn on_event_with_severity(
+        &self,
+        session_id: Option<&SessionId>,
+        msg: &str,
+        severity: EventSeverity,
+    ) {
+        let level = match severity {
+            EventSeverity::Info => log::Level::Info,
+            EventSeverity::Warning => log::Level::Warn,
+            EventSeverity::Error => log::Level::Error,
+        };
+        Self::log(level, "quickfix::event", session_id, msg);
+    }
+}
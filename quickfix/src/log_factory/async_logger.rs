@@ -0,0 +1,236 @@
+use std::{
+    collections::VecDeque,
+    fmt,
+    panic::{catch_unwind, AssertUnwindSafe},
+    sync::{Arc, Condvar, Mutex, MutexGuard, PoisonError},
+    thread::{self, JoinHandle},
+};
+
+use crate::{panic_policy::handle_log_panic, EventSeverity, LogCallback, SessionId};
+
+/// What to do with a log record when [`AsyncLogger`] queue is full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LogOverflowPolicy {
+    /// Wait for the background thread to make room, stalling the FIX engine thread.
+    #[default]
+    Block,
+    /// Drop the oldest queued record to make room for the new one.
+    DropOldest,
+    /// Drop the new record.
+    DropNewest,
+}
+
+#[derive(Debug)]
+enum LogRecord {
+    Incoming(Option<SessionId>, String),
+    Outgoing(Option<SessionId>, String),
+    Event(Option<SessionId>, String, EventSeverity),
+}
+
+impl LogRecord {
+    fn dispatch(&self, logger: &impl LogCallback) {
+        let (callback, session_id) = match self {
+            Self::Incoming(session_id, _) => ("on_incoming", session_id.as_ref()),
+            Self::Outgoing(session_id, _) => ("on_outgoing", session_id.as_ref()),
+            Self::Event(session_id, _, _) => ("on_event", session_id.as_ref()),
+        };
+
+        if let Err(payload) = catch_unwind(AssertUnwindSafe(|| match self {
+            Self::Incoming(_, msg) => logger.on_incoming(session_id, msg),
+            Self::Outgoing(_, msg) => logger.on_outgoing(session_id, msg),
+            Self::Event(_, msg, severity) => {
+                logger.on_event_with_severity(session_id, msg, *severity)
+            }
+        })) {
+            handle_log_panic(callback, session_id, payload);
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    queue: VecDeque<LogRecord>,
+    dropped: u64,
+    /// Background thread is running a record out of the queue.
+    busy: bool,
+    closed: bool,
+}
+
+#[derive(Debug, Default)]
+struct Shared {
+    state: Mutex<State>,
+    /// Notified on every state change.
+    changed: Condvar,
+}
+
+impl Shared {
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn wait<'a>(&self, state: MutexGuard<'a, State>) -> MutexGuard<'a, State> {
+        self.changed
+            .wait(state)
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Run another logger from a background thread, so FIX engine threads only queue log records.
+///
+/// Up to `capacity` records are queued, [`LogOverflowPolicy`] tells what happens when the queue is
+/// full. Records are given to the wrapped logger in the order they have been queued.
+///
+/// Queued records are written when the logger is dropped: drop it after the handlers using it, or
+/// call [`Self::flush`].
+pub struct AsyncLogger<C> {
+    inner: Arc<C>,
+    shared: Arc<Shared>,
+    capacity: usize,
+    policy: LogOverflowPolicy,
+    worker: Option<JoinHandle<()>>,
+}
+
+impl<C> AsyncLogger<C>
+where
+    C: LogCallback + Send + Sync + 'static,
+{
+    /// Wrap `inner` logger, queuing up to `capacity` records.
+    ///
+    /// # Panics
+    ///
+    /// When `capacity` is 0 or background thread cannot be spawned.
+    pub fn new(inner: C, capacity: usize, policy: LogOverflowPolicy) -> Self {
+        assert!(capacity > 0, "AsyncLogger capacity cannot be 0");
+
+        let inner = Arc::new(inner);
+        let shared = Arc::new(Shared::default());
+        let worker = thread::Builder::new()
+            .name("quickfix-async-logger".to_string())
+            .spawn({
+                let inner = inner.clone();
+                let shared = shared.clone();
+                move || Self::run(&inner, &shared)
+            })
+            .expect("Cannot spawn async logger thread");
+
+        Self {
+            inner,
+            shared,
+            capacity,
+            policy,
+            worker: Some(worker),
+        }
+    }
+
+    fn run(inner: &C, shared: &Shared) {
+        let mut state = shared.lock();
+        loop {
+            if let Some(record) = state.queue.pop_front() {
+                state.busy = true;
+                drop(state);
+                shared.changed.notify_all();
+
+                record.dispatch(inner);
+
+                state = shared.lock();
+                state.busy = false;
+                shared.changed.notify_all();
+            } else if state.closed {
+                return;
+            } else {
+                state = shared.wait(state);
+            }
+        }
+    }
+}
+
+impl<C> AsyncLogger<C> {
+    /// Get wrapped logger.
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+
+    /// Number of records dropped because the queue was full.
+    pub fn dropped_count(&self) -> u64 {
+        self.shared.lock().dropped
+    }
+
+    /// Wait until every queued record has been given to the wrapped logger.
+    pub fn flush(&self) {
+        let mut state = self.shared.lock();
+        while !state.queue.is_empty() || state.busy {
+            state = self.shared.wait(state);
+        }
+    }
+
+    fn push(&self, record: LogRecord) {
+        let mut state = self.shared.lock();
+        if state.queue.len() >= self.capacity {
+            match self.policy {
+                LogOverflowPolicy::Block => {
+                    while state.queue.len() >= self.capacity {
+                        state = self.shared.wait(state);
+                    }
+                }
+                LogOverflowPolicy::DropOldest => {
+                    state.queue.pop_front();
+                    state.dropped += 1;
+                }
+                LogOverflowPolicy::DropNewest => {
+                    state.dropped += 1;
+                    return;
+                }
+            }
+        }
+        state.queue.push_back(record);
+        drop(state);
+        self.shared.changed.notify_all();
+    }
+}
+
+impl<C> LogCallback for AsyncLogger<C> {
+    fn on_incoming(&self, session_id: Option<&SessionId>, msg: &str) {
+        self.push(LogRecord::Incoming(session_id.cloned(), msg.to_string()));
+    }
+
+    fn on_outgoing(&self, session_id: Option<&SessionId>, msg: &str) {
+        self.push(LogRecord::Outgoing(session_id.cloned(), msg.to_string()));
+    }
+
+    fn on_event(&self, session_id: Option<&SessionId>, msg: &str) {
+        self.on_event_with_severity(session_id, msg, EventSeverity::infer(msg));
+    }
+
+    fn on_event_with_severity(
+        &self,
+        session_id: Option<&SessionId>,
+        msg: &str,
+        severity: EventSeverity,
+    ) {
+        self.push(LogRecord::Event(
+            session_id.cloned(),
+            msg.to_string(),
+            severity,
+        ));
+    }
+}
+
+impl<C> fmt::Debug for AsyncLogger<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AsyncLogger")
+            .field("capacity", &self.capacity)
+            .field("policy", &self.policy)
+            .field("dropped", &self.dropped_count())
+            .finish()
+    }
+}
+
+impl<C> Drop for AsyncLogger<C> {
+    fn drop(&mut self) {
+        self.shared.lock().closed = true;
+        self.shared.changed.notify_all();
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+}
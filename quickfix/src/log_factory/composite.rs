@@ -0,0 +1,80 @@
+use std::{
+    fmt,
+    panic::{catch_unwind, AssertUnwindSafe},
+};
+
+use crate::{panic_policy::handle_log_panic, EventSeverity, LogCallback, SessionId};
+
+/// Forward every log to several loggers, in order.
+///
+/// A panicking logger is reported like any other log callback panic, then skipped: following
+/// loggers still get the log (unless [`PanicPolicy::Abort`](crate::PanicPolicy::Abort) is set).
+///
+/// ```rust
+/// # use quickfix::*;
+/// let logger = CompositeLogger::new(vec![
+///     Box::new(StdLogger::Stdout),
+///     Box::new(FilteredLogger::new(StdLogger::Stderr, LogFilter::no_admin())),
+/// ]);
+/// # let _ = logger;
+/// ```
+pub struct CompositeLogger {
+    children: Vec<Box<dyn LogCallback + Send + Sync>>,
+}
+
+impl CompositeLogger {
+    /// Create new struct forwarding to `children`.
+    pub fn new(children: Vec<Box<dyn LogCallback + Send + Sync>>) -> Self {
+        Self { children }
+    }
+
+    fn forward(
+        &self,
+        callback: &str,
+        session_id: Option<&SessionId>,
+        f: impl Fn(&dyn LogCallback),
+    ) {
+        for (index, child) in self.children.iter().enumerate() {
+            if let Err(payload) = catch_unwind(AssertUnwindSafe(|| f(child.as_ref()))) {
+                handle_log_panic(&format!("{callback}[{index}]"), session_id, payload);
+            }
+        }
+    }
+}
+
+impl LogCallback for CompositeLogger {
+    fn on_incoming(&self, session_id: Option<&SessionId>, msg: &str) {
+        self.forward("on_incoming", session_id, |child| {
+            child.on_incoming(session_id, msg)
+        });
+    }
+
+    fn on_outgoing(&self, session_id: Option<&SessionId>, msg: &str) {
+        self.forward("on_outgoing", session_id, |child| {
+            child.on_outgoing(session_id, msg)
+        });
+    }
+
+    fn on_event(&self, session_id: Option<&SessionId>, msg: &str) {
+        self.on_event_with_severity(session_id, msg, EventSeverity::infer(msg));
+    }
+
+    fn on_event_with_severity(
+        &self,
+        session_id: Option<&SessionId>,
+        msg: &str,
+        severity: EventSeverity,
+    ) {
+        self.forward("on_event", session_id, |child| {
+            child.on_event_with_severity(session_id, msg, severity)
+        });
+    }
+}
+
+impl fmt::Debug for CompositeLogger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CompositeLogger")
+            .field("children", &self.children.len())
+            .finish()
+    }
+}
@@ -0,0 +1,201 @@
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    fmt,
+    fs::{self, File, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    sync::{Mutex, PoisonError},
+    time::SystemTime,
+};
+
+use super::timestamp::UtcDateTime;
+use crate::{LogCallback, QuickFixError, SessionId};
+
+/// When [`FileLogger`] starts a new file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rotation {
+    /// Always write to the same file.
+    Never,
+    /// Start a new file on first write of every UTC day.
+    Daily,
+    /// Start a new file before it grows over given size in bytes.
+    ///
+    /// A single line larger than this limit still gets written, alone in its file.
+    MaxBytes(u64),
+}
+
+/// A log file, with the files it has already been rotated to.
+struct RotatingFile {
+    /// Path without `.current.log` / `.<index>.log` suffix.
+    prefix: PathBuf,
+    file: File,
+    len: u64,
+    day: u64,
+    next_index: u32,
+}
+
+impl RotatingFile {
+    fn path(prefix: &Path, suffix: &str) -> PathBuf {
+        let mut path = prefix.as_os_str().to_owned();
+        path.push(format!(".{suffix}.log"));
+        PathBuf::from(path)
+    }
+
+    fn open_current(prefix: &Path) -> io::Result<File> {
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(Self::path(prefix, "current"))
+    }
+
+    fn open(prefix: PathBuf, day: u64) -> io::Result<Self> {
+        let file = Self::open_current(&prefix)?;
+        let metadata = file.metadata()?;
+        let len = metadata.len();
+        // Content left by a previous run belongs to the day it has been written.
+        let day = match metadata.modified() {
+            Ok(modified) if len > 0 => UtcDateTime::new(modified).days,
+            _ => day,
+        };
+
+        // Continue numbering after files rotated by previous runs.
+        let file_name = prefix
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let mut next_index = 1;
+        for entry in fs::read_dir(prefix.parent().unwrap_or(Path::new(".")))? {
+            let name = entry?.file_name();
+            let index = name
+                .to_str()
+                .and_then(|name| name.strip_prefix(&file_name)?.strip_prefix('.'))
+                .and_then(|name| name.strip_suffix(".log")?.parse::<u32>().ok());
+            if let Some(index) = index {
+                next_index = next_index.max(index + 1);
+            }
+        }
+
+        Ok(Self {
+            prefix,
+            file,
+            len,
+            day,
+            next_index,
+        })
+    }
+
+    fn write_line(&mut self, rotation: Rotation, day: u64, line: &str) -> io::Result<()> {
+        let rotate = self.len > 0
+            && match rotation {
+                Rotation::Never => false,
+                Rotation::Daily => day != self.day,
+                Rotation::MaxBytes(max_len) => self.len + line.len() as u64 > max_len,
+            };
+        if rotate {
+            fs::rename(
+                Self::path(&self.prefix, "current"),
+                Self::path(&self.prefix, &self.next_index.to_string()),
+            )?;
+            self.next_index += 1;
+            self.file = Self::open_current(&self.prefix)?;
+            self.len = 0;
+        }
+
+        self.file.write_all(line.as_bytes())?;
+        self.len += line.len() as u64;
+        self.day = day;
+        Ok(())
+    }
+}
+
+/// Log to files, with the same layout as quickfix `FileLog`.
+///
+/// Every session logs to `<BeginString>-<SenderCompID>-<TargetCompID>.messages.current.log` for
+/// incoming and outgoing messages, and to `<...>.event.current.log` for other events. Logs not
+/// related to a session go to `GLOBAL.messages.current.log` / `GLOBAL.event.current.log`.
+///
+/// When [`Rotation`] requires it, current file is renamed to `<...>.<index>.log`, with `index`
+/// starting at 1 and increasing with every rotation, then a new current file is started.
+///
+/// Write failures are ignored: they must not stop FIX sessions.
+pub struct FileLogger {
+    dir: PathBuf,
+    rotation: Rotation,
+    files: Mutex<HashMap<PathBuf, RotatingFile>>,
+}
+
+impl FileLogger {
+    /// Try to create new struct logging to directory `path`, created if missing.
+    pub fn try_new(path: &Path, rotation: Rotation) -> Result<Self, QuickFixError> {
+        fs::create_dir_all(path).map_err(|err| {
+            QuickFixError::IOException(format!(
+                "Log path {} is not writable: {err}",
+                path.display()
+            ))
+        })?;
+
+        Ok(Self {
+            dir: path.to_path_buf(),
+            rotation,
+            files: Mutex::new(HashMap::new()),
+        })
+    }
+
+    fn log(&self, session_id: Option<&SessionId>, kind: &str, msg: &str) {
+        let name = match session_id {
+            Some(session_id) => {
+                let mut name = format!(
+                    "{}-{}-{}",
+                    session_id.get_begin_string().unwrap_or_default(),
+                    session_id.get_sender_comp_id().unwrap_or_default(),
+                    session_id.get_target_comp_id().unwrap_or_default(),
+                );
+                if let Some(qualifier) = session_id
+                    .get_session_qualifier()
+                    .filter(|qualifier| !qualifier.is_empty())
+                {
+                    name.push('-');
+                    name.push_str(&qualifier);
+                }
+                name
+            }
+            None => "GLOBAL".to_string(),
+        };
+        let prefix = self.dir.join(format!("{name}.{kind}"));
+        let now = UtcDateTime::new(SystemTime::now());
+        let line = format!("{} : {msg}\n", now.format("%Y%m%d-%H:%M:%S%.3f"));
+
+        let mut files = self.files.lock().unwrap_or_else(PoisonError::into_inner);
+        let file = match files.entry(prefix) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => match RotatingFile::open(entry.key().clone(), now.days) {
+                Ok(file) => entry.insert(file),
+                Err(_) => return,
+            },
+        };
+        let _ = file.write_line(self.rotation, now.days, &line);
+    }
+}
+
+impl LogCallback for FileLogger {
+    fn on_incoming(&self, session_id: Option<&SessionId>, msg: &str) {
+        self.log(session_id, "messages", msg);
+    }
+
+    fn on_outgoing(&self, session_id: Option<&SessionId>, msg: &str) {
+        self.log(session_id, "messages", msg);
+    }
+
+    fn on_event(&self, session_id: Option<&SessionId>, msg: &str) {
+        self.log(session_id, "event", msg);
+    }
+}
+
+impl fmt::Debug for FileLogger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FileLogger")
+            .field("dir", &self.dir)
+            .field("rotation", &self.rotation)
+            .finish()
+    }
+}
@@ -0,0 +1,163 @@
+use std::collections::HashSet;
+
+use crate::{
+    utils::{raw_field, ADMIN_MSG_TYPES},
+    EventSeverity, LogCallback, SessionId,
+};
+
+/// Direction of a logged FIX message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LogDirection {
+    /// Message received from counterparty.
+    Incoming,
+    /// Message sent to counterparty.
+    Outgoing,
+}
+
+/// Logs dropped by a [`FilteredLogger`].
+///
+/// Filter starts empty, keeping every log, and every exclusion only drops more:
+/// ```rust
+/// # use quickfix::*;
+/// let filter = LogFilter::new()
+///     .exclude_msg_type("0")
+///     .exclude_direction(LogDirection::Incoming);
+///
+/// assert!(!filter.accepts_message(LogDirection::Outgoing, None, "8=FIX.4.4\x019=5\x0135=0\x01"));
+/// assert!(filter.accepts_message(LogDirection::Outgoing, None, "8=FIX.4.4\x019=5\x0135=D\x01"));
+/// assert!(!filter.accepts_message(LogDirection::Incoming, None, "8=FIX.4.4\x019=5\x0135=D\x01"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct LogFilter {
+    msg_types: HashSet<String>,
+    directions: HashSet<LogDirection>,
+    sessions: HashSet<String>,
+}
+
+impl LogFilter {
+    /// Create a filter keeping every log.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a filter dropping admin messages: Heartbeat, TestRequest, ResendRequest, Reject,
+    /// SequenceReset, Logout and Logon.
+    ///
+    /// Session events (logon, disconnection...) are still logged.
+    pub fn no_admin() -> Self {
+        ADMIN_MSG_TYPES
+            .into_iter()
+            .fold(Self::new(), Self::exclude_msg_type)
+    }
+
+    /// Drop messages with MsgType(35) `msg_type`.
+    pub fn exclude_msg_type(mut self, msg_type: &str) -> Self {
+        self.msg_types.insert(msg_type.to_string());
+        self
+    }
+
+    /// Drop every message going in `direction`.
+    pub fn exclude_direction(mut self, direction: LogDirection) -> Self {
+        self.directions.insert(direction);
+        self
+    }
+
+    /// Drop every message and event of `session_id`.
+    pub fn exclude_session(mut self, session_id: &SessionId) -> Self {
+        self.sessions.insert(session_id.to_repr());
+        self
+    }
+
+    fn accepts_session(&self, session_id: Option<&SessionId>) -> bool {
+        self.sessions.is_empty()
+            || session_id.map_or(true, |session_id| {
+                !self.sessions.contains(&session_id.to_repr())
+            })
+    }
+
+    /// Check if raw message `msg` should be logged.
+    ///
+    /// MsgType(35) is looked up in raw text, message is not parsed.
+    pub fn accepts_message(
+        &self,
+        direction: LogDirection,
+        session_id: Option<&SessionId>,
+        msg: &str,
+    ) -> bool {
+        !self.directions.contains(&direction)
+            && (self.msg_types.is_empty()
+                || raw_field(msg, 35).map_or(true, |msg_type| !self.msg_types.contains(msg_type)))
+            && self.accepts_session(session_id)
+    }
+
+    /// Check if event of `session_id` should be logged.
+    pub fn accepts_event(&self, session_id: Option<&SessionId>) -> bool {
+        self.accepts_session(session_id)
+    }
+}
+
+/// Forward to another logger only logs accepted by a [`LogFilter`].
+///
+/// ```rust
+/// # use quickfix::*;
+/// let logger = FilteredLogger::new(StdLogger::Stdout, LogFilter::no_admin());
+/// let log_factory = LogFactory::try_new(Box::leak(Box::new(logger)));
+/// # let _ = log_factory;
+/// ```
+#[derive(Debug)]
+pub struct FilteredLogger<C> {
+    inner: C,
+    filter: LogFilter,
+}
+
+impl<C: LogCallback> FilteredLogger<C> {
+    /// Wrap `inner` logger.
+    pub fn new(inner: C, filter: LogFilter) -> Self {
+        Self { inner, filter }
+    }
+
+    /// Get wrapped logger.
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+
+    /// Get filter in use.
+    pub fn filter(&self) -> &LogFilter {
+        &self.filter
+    }
+}
+
+impl<C: LogCallback> LogCallback for FilteredLogger<C> {
+    fn on_incoming(&self, session_id: Option<&SessionId>, msg: &str) {
+        if self
+            .filter
+            .accepts_message(LogDirection::Incoming, session_id, msg)
+        {
+            self.inner.on_incoming(session_id, msg);
+        }
+    }
+
+    fn on_outgoing(&self, session_id: Option<&SessionId>, msg: &str) {
+        if self
+            .filter
+            .accepts_message(LogDirection::Outgoing, session_id, msg)
+        {
+            self.inner.on_outgoing(session_id, msg);
+        }
+    }
+
+    fn on_event(&self, session_id: Option<&SessionId>, msg: &str) {
+        self.on_event_with_severity(session_id, msg, EventSeverity::infer(msg));
+    }
+
+    fn on_event_with_severity(
+        &self,
+        session_id: Option<&SessionId>,
+        msg: &str,
+        severity: EventSeverity,
+    ) {
+        if self.filter.accepts_event(session_id) {
+            self.inner.on_event_with_severity(session_id, msg, severity);
+        }
+    }
+}
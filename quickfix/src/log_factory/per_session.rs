@@ -0,0 +1,140 @@
+use std::{
+    ffi, fmt,
+    panic::{catch_unwind, AssertUnwindSafe},
+    ptr,
+};
+
+use quickfix_ffi::{
+    FixLogFactory_delete, FixLogFactory_t, FixSessionID_t, FixSessionLogFactoryCallbacks_t,
+    FixSessionLogFactory_new,
+};
+
+use super::{EventSeverity, FfiLogFactory, LogCallback, LogFactory};
+use crate::{panic_policy::handle_log_panic, QuickFixError, SessionId};
+
+type SessionLogger = Box<dyn LogCallback + Send + Sync>;
+type Selector = dyn Fn(&SessionId) -> SessionLogger + Send + Sync;
+
+/// Logger created for a single session, boxed again so C++ gets a thin pointer to it.
+struct SessionLog(SessionLogger);
+
+impl LogCallback for SessionLog {
+    fn on_incoming(&self, session_id: Option<&SessionId>, msg: &str) {
+        self.0.on_incoming(session_id, msg);
+    }
+
+    fn on_outgoing(&self, session_id: Option<&SessionId>, msg: &str) {
+        self.0.on_outgoing(session_id, msg);
+    }
+
+    fn on_event(&self, session_id: Option<&SessionId>, msg: &str) {
+        self.0.on_event(session_id, msg);
+    }
+
+    fn on_event_with_severity(
+        &self,
+        session_id: Option<&SessionId>,
+        msg: &str,
+        severity: EventSeverity,
+    ) {
+        self.0.on_event_with_severity(session_id, msg, severity);
+    }
+}
+
+/// Logging factory giving its own logger to every session.
+///
+/// `select` is called each time the FIX engine creates the log of a session, like C++
+/// `LogFactory::create(SessionID)` does, so sessions can use different loggers or configurations.
+/// Returned logger lives as long as the session log, and is dropped with it.
+///
+/// Logs not related to a session (e.g. acceptor events) are dropped. When `select` panics, session
+/// is created without log.
+///
+/// ```
+/// use quickfix::*;
+///
+/// # fn build() -> Result<(), QuickFixError> {
+/// let _log_factory = PerSessionLogFactory::try_new(|session_id| {
+///     if session_id.get_target_comp_id().as_deref() == Some("NOISY") {
+///         Box::new(StdLogger::Stdout) as Box<dyn LogCallback + Send + Sync>
+///     } else {
+///         Box::new(FilteredLogger::new(StdLogger::Stdout, LogFilter::no_admin()))
+///     }
+/// })?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct PerSessionLogFactory {
+    inner: FixLogFactory_t,
+    // Boxed so C++ factory keeps a stable pointer to it, dropped after factory.
+    _select: Box<Box<Selector>>,
+}
+
+// SAFETY: selector and loggers it creates are `Send + Sync`.
+unsafe impl Send for PerSessionLogFactory {}
+unsafe impl Sync for PerSessionLogFactory {}
+
+impl PerSessionLogFactory {
+    /// Try to create new struct calling `select` to get the logger of every new session.
+    pub fn try_new<F>(select: F) -> Result<Self, QuickFixError>
+    where
+        F: Fn(&SessionId) -> Box<dyn LogCallback + Send + Sync> + Send + Sync + 'static,
+    {
+        let select: Box<Box<Selector>> = Box::new(Box::new(select));
+        match unsafe {
+            FixSessionLogFactory_new(
+                select.as_ref() as *const Box<Selector> as *const ffi::c_void,
+                &Self::FACTORY_CALLBACKS,
+                &LogFactory::<SessionLog>::CALLBACKS,
+            )
+        } {
+            Some(inner) => Ok(Self {
+                inner,
+                _select: select,
+            }),
+            None => Err(QuickFixError::from_last_error()),
+        }
+    }
+
+    const FACTORY_CALLBACKS: FixSessionLogFactoryCallbacks_t = FixSessionLogFactoryCallbacks_t {
+        create: Self::create,
+        destroy: Self::destroy,
+    };
+
+    extern "C" fn create(data: *const ffi::c_void, session: FixSessionID_t) -> *const ffi::c_void {
+        let session_id = SessionId::borrow_ffi(session);
+
+        match catch_unwind(AssertUnwindSafe(|| {
+            let select = unsafe { &*(data as *const Box<Selector>) };
+            select(&session_id)
+        })) {
+            Ok(logger) => Box::into_raw(Box::new(SessionLog(logger))) as *const ffi::c_void,
+            Err(payload) => {
+                handle_log_panic("select", Some(&*session_id), payload);
+                ptr::null()
+            }
+        }
+    }
+
+    extern "C" fn destroy(_data: *const ffi::c_void, log_data: *const ffi::c_void) {
+        drop(unsafe { Box::from_raw(log_data as *mut SessionLog) });
+    }
+}
+
+impl FfiLogFactory for PerSessionLogFactory {
+    fn as_ffi_ptr(&self) -> FixLogFactory_t {
+        self.inner
+    }
+}
+
+impl fmt::Debug for PerSessionLogFactory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("PerSessionLogFactory").finish()
+    }
+}
+
+impl Drop for PerSessionLogFactory {
+    fn drop(&mut self) {
+        unsafe { FixLogFactory_delete(self.inner) }
+    }
+}
@@ -0,0 +1,205 @@
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{Mutex, PoisonError},
+    time::{Duration, Instant},
+};
+
+use crate::{utils::raw_field, EventSeverity, LogCallback, LogDirection, SessionId};
+
+/// Reject(3), Logout(5) and BusinessMessageReject(j) are too important to be sampled out.
+const ALWAYS_LOGGED_MSG_TYPES: [&str; 3] = ["3", "5", "j"];
+
+/// How often dropped records are summarized.
+const SUMMARY_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Which messages a [`SampledLogger`] keeps.
+///
+/// Limits apply to each session and direction separately. When both are set, messages kept by
+/// "1 in K" sampling still have to fit in the rate limit.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SamplingPolicy {
+    max_per_second: Option<u32>,
+    one_in: Option<u32>,
+}
+
+impl SamplingPolicy {
+    /// Create a policy keeping every message.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keep at most `count` messages per second, allowing bursts of the same size.
+    pub fn max_per_second(mut self, count: u32) -> Self {
+        self.max_per_second = Some(count);
+        self
+    }
+
+    /// Keep only one message every `count` ones, starting with the first one.
+    pub fn one_in(mut self, count: u32) -> Self {
+        self.one_in = Some(count.max(1));
+        self
+    }
+}
+
+/// Sampling state of a session in one direction.
+#[derive(Debug)]
+struct Stream {
+    seen: u64,
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+#[derive(Debug)]
+struct State {
+    streams: HashMap<(Option<String>, LogDirection), Stream>,
+    /// Messages dropped since last summary, by session.
+    dropped: HashMap<Option<String>, (Option<SessionId>, u64)>,
+    summarized_at: Instant,
+}
+
+/// Forward to another logger only a sample of incoming and outgoing messages.
+///
+/// Events, Reject(3), BusinessMessageReject(j) and Logout(5) messages are always forwarded. Every
+/// second, and when the logger is dropped, number of messages dropped since previous summary is
+/// given to the wrapped logger as an event of the session they belong to.
+///
+/// ```rust
+/// # use quickfix::*;
+/// let policy = SamplingPolicy::new().max_per_second(100).one_in(10);
+/// let logger = SampledLogger::new(StdLogger::Stdout, policy);
+/// # let _ = logger;
+/// ```
+pub struct SampledLogger<C: LogCallback> {
+    inner: C,
+    policy: SamplingPolicy,
+    state: Mutex<State>,
+}
+
+impl<C: LogCallback> SampledLogger<C> {
+    /// Wrap `inner` logger.
+    pub fn new(inner: C, policy: SamplingPolicy) -> Self {
+        Self {
+            inner,
+            policy,
+            state: Mutex::new(State {
+                streams: HashMap::new(),
+                dropped: HashMap::new(),
+                summarized_at: Instant::now(),
+            }),
+        }
+    }
+
+    /// Get wrapped logger.
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+
+    fn accepts(&self, direction: LogDirection, session_id: Option<&SessionId>, msg: &str) -> bool {
+        if raw_field(msg, 35).is_some_and(|msg_type| ALWAYS_LOGGED_MSG_TYPES.contains(&msg_type)) {
+            return true;
+        }
+
+        let now = Instant::now();
+        let session = session_id.map(SessionId::to_repr);
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        let stream = state
+            .streams
+            .entry((session.clone(), direction))
+            .or_insert_with(|| Stream {
+                seen: 0,
+                tokens: f64::from(self.policy.max_per_second.unwrap_or_default()),
+                refilled_at: now,
+            });
+
+        stream.seen += 1;
+        let mut accepted = self
+            .policy
+            .one_in
+            .map_or(true, |count| (stream.seen - 1) % u64::from(count) == 0);
+        if let (true, Some(max_per_second)) = (accepted, self.policy.max_per_second) {
+            let max_per_second = f64::from(max_per_second);
+            let elapsed = now.duration_since(stream.refilled_at).as_secs_f64();
+            stream.tokens = (stream.tokens + elapsed * max_per_second).min(max_per_second);
+            stream.refilled_at = now;
+            accepted = stream.tokens >= 1.0;
+            if accepted {
+                stream.tokens -= 1.0;
+            }
+        }
+
+        if !accepted {
+            state
+                .dropped
+                .entry(session)
+                .or_insert_with(|| (session_id.cloned(), 0))
+                .1 += 1;
+        }
+        accepted
+    }
+
+    /// Give pending summaries to the wrapped logger, if it is time to or `force` is set.
+    fn summarize(&self, force: bool) {
+        let summaries = {
+            let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+            if state.dropped.is_empty()
+                || (!force && state.summarized_at.elapsed() < SUMMARY_INTERVAL)
+            {
+                return;
+            }
+            state.summarized_at = Instant::now();
+            std::mem::take(&mut state.dropped)
+        };
+
+        for (session_id, dropped) in summaries.into_values() {
+            self.inner.on_event(
+                session_id.as_ref(),
+                &format!("Log sampling dropped {dropped} messages"),
+            );
+        }
+    }
+}
+
+impl<C: LogCallback> LogCallback for SampledLogger<C> {
+    fn on_incoming(&self, session_id: Option<&SessionId>, msg: &str) {
+        if self.accepts(LogDirection::Incoming, session_id, msg) {
+            self.inner.on_incoming(session_id, msg);
+        }
+        self.summarize(false);
+    }
+
+    fn on_outgoing(&self, session_id: Option<&SessionId>, msg: &str) {
+        if self.accepts(LogDirection::Outgoing, session_id, msg) {
+            self.inner.on_outgoing(session_id, msg);
+        }
+        self.summarize(false);
+    }
+
+    fn on_event(&self, session_id: Option<&SessionId>, msg: &str) {
+        self.on_event_with_severity(session_id, msg, EventSeverity::infer(msg));
+    }
+
+    fn on_event_with_severity(
+        &self,
+        session_id: Option<&SessionId>,
+        msg: &str,
+        severity: EventSeverity,
+    ) {
+        self.inner.on_event_with_severity(session_id, msg, severity);
+        self.summarize(false);
+    }
+}
+
+impl<C: LogCallback> fmt::Debug for SampledLogger<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SampledLogger")
+            .field("policy", &self.policy)
+            .finish()
+    }
+}
+
+impl<C: LogCallback> Drop for SampledLogger<C> {
+    fn drop(&mut self) {
+        self.summarize(true);
+    }
+}
@@ -0,0 +1,205 @@
+use std::{
+    fmt,
+    io::{self, Write},
+    sync::{Mutex, PoisonError},
+    time::SystemTime,
+};
+
+use super::timestamp::UtcDateTime;
+use crate::{LogCallback, SessionId};
+
+const COLOR_INCOMING: &str = "\x1b[32m";
+const COLOR_OUTGOING: &str = "\x1b[36m";
+const COLOR_EVENT: &str = "\x1b[33m";
+const COLOR_RESET: &str = "\x1b[0m";
+
+enum Output {
+    Stdout,
+    Stderr,
+    Writer(Mutex<Box<dyn Write + Send>>),
+}
+
+/// Print FIX logs to the terminal, one line per record.
+///
+/// Every line is `<timestamp> <category> <session>: <text>`, with category being `event`,
+/// `incoming` or `outgoing`, and session `-` for records not related to a session.
+///
+/// ```
+/// use quickfix::ScreenLogger;
+///
+/// let logger = ScreenLogger::stderr()
+///     .delimiter('|')
+///     .timestamp_format(ScreenLogger::RFC3339)
+///     .color(true)
+///     .outgoing(false);
+/// ```
+pub struct ScreenLogger {
+    output: Output,
+    delimiter: Option<char>,
+    timestamp_format: Option<String>,
+    color: bool,
+    events: bool,
+    incoming: bool,
+    outgoing: bool,
+}
+
+impl ScreenLogger {
+    /// RFC 3339 timestamp pattern, in UTC with milliseconds.
+    pub const RFC3339: &'static str = "%Y-%m-%dT%H:%M:%S%.3fZ";
+
+    /// quickfix timestamp pattern, used by default.
+    pub const QUICKFIX: &'static str = "%Y%m%d-%H:%M:%S%.3f";
+
+    fn with_output(output: Output) -> Self {
+        Self {
+            output,
+            delimiter: None,
+            timestamp_format: Some(Self::QUICKFIX.to_string()),
+            color: false,
+            events: true,
+            incoming: true,
+            outgoing: true,
+        }
+    }
+
+    /// Create new struct printing to stdout.
+    pub fn stdout() -> Self {
+        Self::with_output(Output::Stdout)
+    }
+
+    /// Create new struct printing to stderr.
+    pub fn stderr() -> Self {
+        Self::with_output(Output::Stderr)
+    }
+
+    /// Create new struct printing to any writer, e.g. a pipe or a buffer.
+    pub fn with_writer(writer: impl Write + Send + 'static) -> Self {
+        Self::with_output(Output::Writer(Mutex::new(Box::new(writer))))
+    }
+
+    /// Replace SOH field separators with `delimiter` in printed messages.
+    ///
+    /// SOH is printed as is by default.
+    pub fn delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = Some(delimiter);
+        self
+    }
+
+    /// Prefix lines with current UTC time, formatted using strftime-like `pattern`.
+    ///
+    /// Supported specifiers are `%Y`, `%m`, `%d`, `%H`, `%M`, `%S`, `%s` (seconds since epoch),
+    /// `%.3f` / `%.6f` / `%.9f` (fraction of second including leading dot) and `%%`.
+    /// Default is [`Self::QUICKFIX`], see also [`Self::RFC3339`].
+    pub fn timestamp_format(mut self, pattern: &str) -> Self {
+        self.timestamp_format = Some(pattern.to_string());
+        self
+    }
+
+    /// Do not prefix lines with a timestamp.
+    pub fn no_timestamp(mut self) -> Self {
+        self.timestamp_format = None;
+        self
+    }
+
+    /// Color lines with ANSI escape codes depending on their category.
+    pub fn color(mut self, enabled: bool) -> Self {
+        self.color = enabled;
+        self
+    }
+
+    /// Print FIX events, default is `true`.
+    pub fn events(mut self, enabled: bool) -> Self {
+        self.events = enabled;
+        self
+    }
+
+    /// Print incoming messages, default is `true`.
+    pub fn incoming(mut self, enabled: bool) -> Self {
+        self.incoming = enabled;
+        self
+    }
+
+    /// Print outgoing messages, default is `true`.
+    pub fn outgoing(mut self, enabled: bool) -> Self {
+        self.outgoing = enabled;
+        self
+    }
+
+    fn print(&self, category: &str, color: &str, session_id: Option<&SessionId>, text: &str) {
+        let mut line = String::with_capacity(text.len() + 64);
+        if self.color {
+            line.push_str(color);
+        }
+        if let Some(pattern) = &self.timestamp_format {
+            line.push_str(&UtcDateTime::new(SystemTime::now()).format(pattern));
+            line.push(' ');
+        }
+        line.push_str(category);
+        line.push(' ');
+        match session_id {
+            Some(session_id) => line.push_str(&session_id.to_repr()),
+            None => line.push('-'),
+        }
+        line.push_str(": ");
+        match self.delimiter {
+            Some(delimiter) => line.extend(text.chars().map(|c| match c {
+                '\x01' => delimiter,
+                c => c,
+            })),
+            None => line.push_str(text),
+        }
+        if self.color {
+            line.push_str(COLOR_RESET);
+        }
+        line.push('\n');
+
+        // Whole line is written at once, so lines of concurrent sessions do not interleave.
+        let _ = match &self.output {
+            Output::Stdout => io::stdout().write_all(line.as_bytes()),
+            Output::Stderr => io::stderr().write_all(line.as_bytes()),
+            Output::Writer(writer) => writer
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .write_all(line.as_bytes()),
+        };
+    }
+}
+
+impl LogCallback for ScreenLogger {
+    fn on_incoming(&self, session_id: Option<&SessionId>, msg: &str) {
+        if self.incoming {
+            self.print("incoming", COLOR_INCOMING, session_id, msg);
+        }
+    }
+
+    fn on_outgoing(&self, session_id: Option<&SessionId>, msg: &str) {
+        if self.outgoing {
+            self.print("outgoing", COLOR_OUTGOING, session_id, msg);
+        }
+    }
+
+    fn on_event(&self, session_id: Option<&SessionId>, msg: &str) {
+        if self.events {
+            self.print("event", COLOR_EVENT, session_id, msg);
+        }
+    }
+}
+
+impl fmt::Debug for ScreenLogger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let output = match self.output {
+            Output::Stdout => "stdout",
+            Output::Stderr => "stderr",
+            Output::Writer(_) => "writer",
+        };
+        f.debug_struct("ScreenLogger")
+            .field("output", &output)
+            .field("delimiter", &self.delimiter)
+            .field("timestamp_format", &self.timestamp_format)
+            .field("color", &self.color)
+            .field("events", &self.events)
+            .field("incoming", &self.incoming)
+            .field("outgoing", &self.outgoing)
+            .finish()
+    }
+}
@@ -0,0 +1,56 @@
+/// Severity of a FIX event, see [`crate::LogCallback::on_event_with_severity`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum EventSeverity {
+    /// Normal session lifecycle: connection, logon, heartbeat...
+    Info,
+    /// Session recovers by itself: disconnection, logout, sequence reset, resend...
+    Warning,
+    /// Something is wrong with the counterparty or the engine: rejected or invalid messages,
+    /// socket errors...
+    Error,
+}
+
+/// Lower case fragments of quickfix event texts, by severity.
+const ERROR_PATTERNS: &[&str] = &[
+    "error",
+    "exception",
+    "fail",
+    "invalid",
+    "reject",
+    "garbled",
+    "too low",
+    "not valid",
+    "unsupported",
+    "cannot",
+];
+const WARNING_PATTERNS: &[&str] = &[
+    "disconnect",
+    "connection reset",
+    "timed out",
+    "timeout",
+    "logout",
+    "reset",
+    "resend",
+    "too high",
+    "test request",
+    "refused",
+];
+
+impl EventSeverity {
+    /// Infer severity from quickfix event text.
+    ///
+    /// quickfix C++ `Log` has no notion of severity, so this relies on the wording of its events,
+    /// e.g. `Disconnecting` is a warning and `Message 3 Rejected: Invalid tag number` an error.
+    pub fn infer(msg: &str) -> Self {
+        let msg = msg.to_ascii_lowercase();
+        let matches = |patterns: &[&str]| patterns.iter().any(|pattern| msg.contains(pattern));
+
+        if matches(ERROR_PATTERNS) {
+            Self::Error
+        } else if matches(WARNING_PATTERNS) {
+            Self::Warning
+        } else {
+            Self::Info
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use std::time::SystemTime;
+
+use crate::{utils::raw_field, LogCallback, LogDirection, SessionId};
+
+/// FIX message logged by quickfix, with the fields needed to index it.
+///
+/// Fields are read from raw text, message is not parsed: they are `None` when missing or invalid.
+#[derive(Debug, Clone, Copy)]
+pub struct MessageLogRecord<'a> {
+    /// Session message belongs to.
+    pub session_id: Option<&'a SessionId>,
+    /// Whether message has been received or sent.
+    pub direction: LogDirection,
+    /// MsgType(35).
+    pub msg_type: Option<&'a str>,
+    /// MsgSeqNum(34).
+    pub seq_num: Option<u32>,
+    /// Time quickfix logged it.
+    pub timestamp: SystemTime,
+    /// Message text, with SOH separators.
+    pub raw: &'a str,
+}
+
+impl<'a> MessageLogRecord<'a> {
+    /// Extract record fields from raw message `msg`.
+    pub fn new(session_id: Option<&'a SessionId>, direction: LogDirection, msg: &'a str) -> Self {
+        Self {
+            session_id,
+            direction,
+            msg_type: raw_field(msg, 35),
+            seq_num: raw_field(msg, 34).and_then(|value| value.parse().ok()),
+            timestamp: SystemTime::now(),
+            raw: msg,
+        }
+    }
+}
+
+/// Same as [`LogCallback`], receiving messages as [`MessageLogRecord`].
+///
+/// Use it with [`LogFactory`](crate::LogFactory) through [`StructuredLogger`].
+#[allow(unused_variables)]
+pub trait StructuredLogCallback {
+    /// Message has been received or is being sent.
+    fn on_message(&self, record: MessageLogRecord<'_>) {}
+
+    /// Other FIX event has occurred.
+    fn on_event(&self, session_id: Option<&SessionId>, msg: &str) {}
+}
+
+/// Adapter using a [`StructuredLogCallback`] wherever a [`LogCallback`] is expected.
+#[derive(Debug)]
+pub struct StructuredLogger<C>(pub C);
+
+impl<C: StructuredLogCallback> LogCallback for StructuredLogger<C> {
+    fn on_incoming(&self, session_id: Option<&SessionId>, msg: &str) {
+        self.0.on_message(MessageLogRecord::new(
+            session_id,
+            LogDirection::Incoming,
+            msg,
+        ));
+    }
+
+    fn on_outgoing(&self, session_id: Option<&SessionId>, msg: &str) {
+        self.0.on_message(MessageLogRecord::new(
+            session_id,
+            LogDirection::Outgoing,
+            msg,
+        ));
+    }
+
+    fn on_event(&self, session_id: Option<&SessionId>, msg: &str) {
+        self.0.on_event(session_id, msg);
+    }
+}
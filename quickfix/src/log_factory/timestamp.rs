@@ -0,0 +1,134 @@
+use std::{
+    fmt::Write,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// UTC calendar representation of a [`SystemTime`].
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct UtcDateTime {
+    pub(super) days: u64,
+    year: i64,
+    month: i64,
+    day: i64,
+    secs: u64,
+    nanos: u32,
+}
+
+impl UtcDateTime {
+    pub(crate) fn new(time: SystemTime) -> Self {
+        let elapsed = time.duration_since(UNIX_EPOCH).unwrap_or_default();
+        let secs = elapsed.as_secs();
+        let days = secs / 86_400;
+
+        // Civil date from days since epoch, see http://howardhinnant.github.io/date_algorithms.html
+        let z = days as i64 + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z.rem_euclid(146_097);
+        let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = doy - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = yoe + era * 400 + i64::from(month <= 2);
+
+        Self {
+            days,
+            year,
+            month,
+            day,
+            secs,
+            nanos: elapsed.subsec_nanos(),
+        }
+    }
+
+    /// Format using strftime-like `pattern`.
+    ///
+    /// Supported specifiers are `%Y`, `%m`, `%d`, `%H`, `%M`, `%S`, `%s` (seconds since epoch),
+    /// `%.3f` / `%.6f` / `%.9f` (fraction of second including leading dot) and `%%`. Anything
+    /// else is copied as is.
+    pub(crate) fn format(&self, pattern: &str) -> String {
+        let secs_of_day = self.secs % 86_400;
+        let mut output = String::with_capacity(pattern.len() + 16);
+        let mut rest = pattern;
+
+        while let Some(index) = rest.find('%') {
+            output.push_str(&rest[..index]);
+            rest = &rest[index..];
+
+            let spec_len = if rest.starts_with("%.") { 4 } else { 2 };
+            let spec = rest.get(..spec_len).unwrap_or(rest);
+            let _ = match spec {
+                "%Y" => write!(output, "{:04}", self.year),
+                "%m" => write!(output, "{:02}", self.month),
+                "%d" => write!(output, "{:02}", self.day),
+                "%H" => write!(output, "{:02}", secs_of_day / 3_600),
+                "%M" => write!(output, "{:02}", secs_of_day / 60 % 60),
+                "%S" => write!(output, "{:02}", secs_of_day % 60),
+                "%s" => write!(output, "{}", self.secs),
+                "%.3f" => write!(output, ".{:03}", self.nanos / 1_000_000),
+                "%.6f" => write!(output, ".{:06}", self.nanos / 1_000),
+                "%.9f" => write!(output, ".{:09}", self.nanos),
+                "%%" => write!(output, "%"),
+                _ => {
+                    output.push('%');
+                    rest = &rest[1..];
+                    continue;
+                }
+            };
+            rest = &rest[spec.len()..];
+        }
+        output.push_str(rest);
+        output
+    }
+}
+
+/// Parse FIX UTCTimestamp `YYYYMMDD-HH:MM:SS[.sss...]`, with up to 9 fraction digits.
+pub(crate) fn parse_utc_timestamp(text: &str) -> Option<SystemTime> {
+    fn number(text: &str, range: std::ops::Range<usize>) -> Option<i64> {
+        let digits = text.get(range)?;
+        if !digits.bytes().all(|x| x.is_ascii_digit()) {
+            return None;
+        }
+        digits.parse().ok()
+    }
+
+    let (date_time, fraction) = text.split_once('.').unwrap_or((text, ""));
+    let bytes = date_time.as_bytes();
+    if bytes.len() != 17 || bytes[8] != b'-' || bytes[11] != b':' || bytes[14] != b':' {
+        return None;
+    }
+    let year = number(date_time, 0..4)?;
+    let month = number(date_time, 4..6)?;
+    let day = number(date_time, 6..8)?;
+    let hour = number(date_time, 9..11)?;
+    let minute = number(date_time, 12..14)?;
+    let second = number(date_time, 15..17)?;
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+    if hour > 23 || minute > 59 || second > 60 {
+        return None;
+    }
+
+    let nanos = match fraction.len() {
+        0 if !text.ends_with('.') => 0,
+        1..=9 => number(fraction, 0..fraction.len())? * 10_i64.pow(9 - fraction.len() as u32),
+        _ => return None,
+    };
+
+    // Days since epoch from civil date, inverse of `UtcDateTime::new`.
+    let y = year - i64::from(month <= 2);
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let doy = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146_097 + doe - 719_468;
+
+    let secs = days * 86_400 + hour * 3_600 + minute * 60 + second;
+    let time = if secs >= 0 {
+        UNIX_EPOCH.checked_add(Duration::from_secs(secs.unsigned_abs()))
+    } else {
+        UNIX_EPOCH.checked_sub(Duration::from_secs(secs.unsigned_abs()))
+    };
+    time?.checked_add(Duration::from_nanos(nanos as u64))
+}
@@ -1,26 +1,34 @@
 use quickfix_ffi::{
-    FixMessage_addGroup, FixMessage_copy, FixMessage_copyGroup, FixMessage_copyHeader,
-    FixMessage_copyTrailer, FixMessage_delete, FixMessage_fromString, FixMessage_getField,
-    FixMessage_getGroupRef, FixMessage_getHeaderRef, FixMessage_getStringLen,
-    FixMessage_getTrailerRef, FixMessage_isFieldEqual, FixMessage_new, FixMessage_readString,
-    FixMessage_removeField, FixMessage_setField, FixMessage_t, FixMessage_toString,
+    FixMessage_addGroup, FixMessage_addGroups, FixMessage_assign, FixMessage_clear,
+    FixMessage_copy, FixMessage_copyGroup, FixMessage_copyHeader, FixMessage_copyTrailer,
+    FixMessage_delete, FixMessage_fromString, FixMessage_getFieldValue, FixMessage_getFields,
+    FixMessage_getGroupRef, FixMessage_getHeaderRef, FixMessage_getTrailerRef,
+    FixMessage_isFieldEqual, FixMessage_new, FixMessage_removeField, FixMessage_setFieldRaw,
+    FixMessage_t, FixMessage_toBuffer, FixMessage_toStringRef,
 };
-use std::ffi::CStr;
-use std::{ffi::CString, fmt, mem::ManuallyDrop};
+use std::{fmt, mem::ManuallyDrop};
 
 use crate::{
+    field_name_resolver::pretty_string,
     group::Group,
-    header::Header,
+    header::{Header, HeaderMut, HeaderRef},
     trailer::Trailer,
-    utils::{ffi_code_to_result, read_checked_cstr},
-    FieldMap, IntoFixValue, QuickFixError,
+    utils::{
+        append_field_value, ffi_code_to_result, field_value_str, utf8_to_string,
+        with_flat_group_rows,
+    },
+    DataDictionary, FieldBatch, FieldMap, FieldNameResolver, IntoFixValue, ParseLimits,
+    QuickFixError,
 };
 
 /// Base class for all FIX messages.
 pub struct Message(pub(crate) FixMessage_t);
 
+// SAFETY: message is owned by this struct and C++ `FIX::Message` has no thread affinity.
+//
+// Not `Sync`: serializing takes `&self`, but C++ `toString() const` still rewrites BodyLength(9)
+// and CheckSum(10) in place, so two threads serializing the same message would race.
 unsafe impl Send for Message {}
-unsafe impl Sync for Message {}
 
 impl Message {
     /// Create new empty struct.
@@ -28,10 +36,35 @@ impl Message {
         Self::default()
     }
 
-    /// Try create new struct from raw text message.
+    /// Try create new struct from raw text message, within default [`ParseLimits`].
     pub fn try_from_text(text: &str) -> Result<Self, QuickFixError> {
-        let ffi_text = CString::new(text)?;
-        unsafe { FixMessage_fromString(ffi_text.as_ptr()) }
+        Self::try_from_bytes(text.as_bytes())
+    }
+
+    /// Same as [`Self::try_from_text`], within given `limits`.
+    pub fn try_from_text_with_limits(
+        text: &str,
+        limits: &ParseLimits,
+    ) -> Result<Self, QuickFixError> {
+        Self::try_from_bytes_with_limits(text.as_bytes(), limits)
+    }
+
+    /// Try create new struct from raw message bytes, which do not have to be valid UTF-8, within
+    /// default [`ParseLimits`].
+    ///
+    /// Bytes cross FFI with their length, so values may contain NUL bytes (e.g. binary `data`
+    /// fields).
+    pub fn try_from_bytes(raw: &[u8]) -> Result<Self, QuickFixError> {
+        Self::try_from_bytes_with_limits(raw, &ParseLimits::default())
+    }
+
+    /// Same as [`Self::try_from_bytes`], within given `limits`.
+    pub fn try_from_bytes_with_limits(
+        raw: &[u8],
+        limits: &ParseLimits,
+    ) -> Result<Self, QuickFixError> {
+        limits.check(raw, None)?;
+        unsafe { FixMessage_fromString(raw.as_ptr().cast(), raw.len() as u64) }
             .map(Self)
             .ok_or_else(QuickFixError::from_last_error)
     }
@@ -50,49 +83,88 @@ impl Message {
     ///
     /// # Performances
     ///
-    /// Do not use this method in latency sensitive code.
+    /// Do not use this method in latency sensitive code: a new string is allocated on every call.
+    /// See [`Self::to_fix_bytes_into`] to reuse a buffer.
     ///
-    /// String will be generated twice in C++ code:
-    /// - Once for getting a safe buffer length.
-    /// - Then to copy buffer to rust "memory".
+    /// Fails with [`QuickFixError::InvalidUtf8`] when message contains non UTF-8 data, see
+    /// [`Self::to_fix_bytes`] and [`Self::to_fix_string_lossy`].
     pub fn to_fix_string(&self) -> Result<String, QuickFixError> {
-        unsafe {
-            // Prepare output buffer
-            let buffer_len = FixMessage_getStringLen(self.0)
-                .try_into()
-                .map_err(|_err| QuickFixError::from_last_error())?;
+        utf8_to_string(None, self.to_fix_bytes()?)
+    }
 
-            // Allocate buffer on rust side
-            let mut buffer = vec![0_u8; buffer_len as usize];
-            assert_eq!(buffer.len(), buffer_len as usize);
+    /// Same as [`Self::to_fix_string`], replacing invalid UTF-8 sequences with `U+FFFD`.
+    pub fn to_fix_string_lossy(&self) -> Result<String, QuickFixError> {
+        Ok(String::from_utf8_lossy(&self.to_fix_bytes()?).into_owned())
+    }
 
-            // Read text
-            ffi_code_to_result(FixMessage_readString(
-                self.0,
-                buffer.as_mut_ptr().cast(),
-                buffer_len,
-            ))?;
-
-            // Convert to String
-            //
-            // NOTE: Here, I deliberately made the choice to drop C weird string / invalid UTF8 string
-            //       content. If this happen, there is not so much we can do about ...
-            //       Returning no error is sometime nicer, than an incomprehensible error.
-            let text = CString::from_vec_with_nul(buffer).unwrap_or_default();
-            Ok(text.to_string_lossy().to_string())
+    /// Try reading underlying struct buffer as raw FIX bytes, whatever their encoding.
+    pub fn to_fix_bytes(&self) -> Result<Vec<u8>, QuickFixError> {
+        let mut buffer = Vec::new();
+        self.to_fix_bytes_into(&mut buffer)?;
+        Ok(buffer)
+    }
+
+    /// Serialize message at the end of `buf`, and return number of bytes written.
+    ///
+    /// # Performances
+    ///
+    /// Message is serialized once when `buf` spare capacity is large enough, without any
+    /// allocation in steady state: C++ side serializes into a per-thread scratch buffer which
+    /// keeps its capacity, and `buf` is only grown when it is too small. Reuse the same `buf`
+    /// (cleared between calls), or create it with [`Vec::with_capacity`] using the expected
+    /// message size, so it never grows.
+    ///
+    /// Only serialization buffers can be preallocated: QuickFIX does not expose any way to
+    /// reserve field storage of a message. Field storage of a [cleared](Self::clear) message is
+    /// kept though, so recycling messages with a [`crate::MessagePool`] gives the same effect.
+    pub fn to_fix_bytes_into(&self, buf: &mut Vec<u8>) -> Result<usize, QuickFixError> {
+        let start = buf.len();
+        loop {
+            let spare = buf.capacity() - start;
+            let needed: usize = unsafe {
+                FixMessage_toBuffer(self.0, buf.as_mut_ptr().add(start).cast(), spare as u64)
+            }
+            .try_into()
+            .map_err(|_err| QuickFixError::from_last_error())?;
+
+            if needed <= spare {
+                // SAFETY: C++ code has written `needed` bytes after `start`.
+                unsafe { buf.set_len(start + needed) };
+                return Ok(needed);
+            }
+            buf.reserve(needed);
         }
     }
 
+    /// Borrow message as raw FIX bytes without Rust-side allocation nor UTF-8 validation.
+    ///
+    /// This is the fastest way to get serialized message, e.g. to write it to a socket or a file.
+    /// Use [`std::str::from_utf8`] on the result to opt into validation.
+    ///
+    /// NOTE: this borrows an internal C++ buffer and therefore requires `&mut self`.
+    /// Keep the returned bytes for short-lived use and avoid storing them.
+    pub fn to_fix_bytes_ref(&mut self) -> Result<&[u8], QuickFixError> {
+        let mut len = 0;
+        let ptr = unsafe { FixMessage_toStringRef(self.0, &mut len) }
+            .ok_or_else(QuickFixError::from_last_error)?;
+        Ok(unsafe { std::slice::from_raw_parts(ptr.as_ptr().cast::<u8>(), len as usize) })
+    }
+
     /// Borrow message as FIX text without Rust-side allocation.
     ///
+    /// Whole message is checked to be valid UTF-8 on every call: fields can be set from raw
+    /// bytes (see [`FieldMap::set_field_raw`]), parsed from raw text or changed through a lent
+    /// header, so cleanliness cannot be tracked at set time. Use [`Self::to_fix_bytes_ref`] to
+    /// skip this check.
+    ///
     /// NOTE: this borrows an internal C++ buffer and therefore requires `&mut self`.
     /// Keep the returned string for short-lived use and avoid storing it.
     pub fn to_fix_str(&mut self) -> Result<&str, QuickFixError> {
-        let ptr =
-            unsafe { FixMessage_toString(self.0) }.ok_or_else(QuickFixError::from_last_error)?;
-        unsafe { CStr::from_ptr(ptr.as_ptr()) }
-            .to_str()
-            .map_err(|err| QuickFixError::invalid_argument(err.to_string()))
+        let bytes = self.to_fix_bytes_ref()?;
+        std::str::from_utf8(bytes).map_err(|_err| QuickFixError::InvalidUtf8 {
+            tag: None,
+            bytes: bytes.to_vec(),
+        })
     }
 
     /// Clone struct header part.
@@ -123,6 +195,34 @@ impl Message {
         f(&obj)
     }
 
+    /// Borrow struct header part.
+    ///
+    /// Same as [`Message::with_header`], without closure.
+    ///
+    /// # Panic
+    ///
+    /// When struct pointer cannot be read from `FIX::Message`. This is
+    /// something that could not be theoretically possible.
+    pub fn header(&self) -> HeaderRef<'_> {
+        let ptr =
+            unsafe { FixMessage_getHeaderRef(self.0) }.expect("Fail to get ptr on message header");
+        HeaderRef::new(ptr)
+    }
+
+    /// Borrow struct header part for writing.
+    ///
+    /// Same as [`Message::with_header_mut`], without closure.
+    ///
+    /// # Panic
+    ///
+    /// When struct pointer cannot be read from `FIX::Message`. This is
+    /// something that could not be theoretically possible.
+    pub fn header_mut(&mut self) -> HeaderMut<'_> {
+        let ptr =
+            unsafe { FixMessage_getHeaderRef(self.0) }.expect("Fail to get ptr on message header");
+        HeaderMut::new(ptr)
+    }
+
     /// Get struct header part.
     pub fn get_header(&self) -> ManuallyDrop<Header> {
         let ptr =
@@ -217,16 +317,76 @@ impl Message {
         }
     }
 
+    /// Remove every field of header, body and trailer, keeping underlying C++ object.
+    pub fn clear(&mut self) -> Result<(), QuickFixError> {
+        ffi_code_to_result(unsafe { FixMessage_clear(self.0) })
+    }
+
+    /// Replace content of this message with a copy of `other`, keeping underlying C++ object.
+    ///
+    /// Unlike [`Clone::clone`], which always allocates a new message, storage already allocated
+    /// by this message is reused. Use it with a [`crate::MessagePool`] to fan out a template
+    /// message without allocating a new one per copy.
+    pub fn copy_from(&mut self, other: &Message) -> Result<(), QuickFixError> {
+        ffi_code_to_result(unsafe { FixMessage_assign(self.0, other.0) })
+    }
+
+    /// Render message for humans, with field and value names resolved by `dict` when supplied
+    /// (ex: `BeginString=FIX.4.4|...|Side=BUY|NoPartyIDs=1|[PartyID=P1|PartyRole=EXECUTING_FIRM]`).
+    ///
+    /// Fields are separated by `delim`, and every repeating group entry is written between
+    /// brackets. Unknown fields and values are left as numbers.
+    pub fn to_pretty_string(&self, dict: Option<&DataDictionary>, delim: char) -> String {
+        pretty_string(self, dict.map(|dict| dict as &dyn FieldNameResolver), delim)
+    }
+
+    /// Same as [`Message::to_pretty_string`], resolving names with `names`.
+    pub fn to_pretty_string_with<R: FieldNameResolver>(&self, names: &R, delim: char) -> String {
+        pretty_string(self, Some(names), delim)
+    }
+
+    /// Borrow field value without copying it, `None` when field is missing or not UTF-8.
     pub fn get_field_str(&self, tag: i32) -> Option<&str> {
+        let mut value_len = 0;
         unsafe {
-            FixMessage_getField(self.0, tag).map(|pr| CStr::from_ptr(pr.as_ptr()).to_str().unwrap())
+            let value = FixMessage_getFieldValue(self.0, tag, &mut value_len);
+            field_value_str(value, value_len)
         }
     }
 }
 
 impl FieldMap for Message {
     fn get_field(&self, tag: i32) -> Option<String> {
-        unsafe { FixMessage_getField(self.0, tag) }.map(read_checked_cstr)
+        self.try_get_field(tag).ok().flatten()
+    }
+
+    fn get_field_bytes(&self, tag: i32) -> Option<Vec<u8>> {
+        // Read with length, so values holding NUL bytes are not truncated.
+        let mut buf = Vec::new();
+        self.get_field_bytes_into(tag, &mut buf)
+            .ok()
+            .map(|_len| buf)
+    }
+
+    fn get_field_bytes_into(&self, tag: i32, buf: &mut Vec<u8>) -> Result<usize, QuickFixError> {
+        let mut value_len = 0;
+        unsafe {
+            let value = FixMessage_getFieldValue(self.0, tag, &mut value_len);
+            append_field_value(tag, value, value_len, buf)
+        }
+    }
+
+    fn get_fields_into(&self, tags: &[i32], batch: &mut FieldBatch) -> Result<(), QuickFixError> {
+        batch.fill_from_ffi(tags, |buffer, buffer_len, lengths| unsafe {
+            FixMessage_getFields(
+                self.0,
+                tags.as_ptr(),
+                tags.len() as u64,
+                buffer,
+                buffer_len,
+                lengths,
+            )
+        })
     }
 
     fn is_field_equal(&self, tag: i32, value: &str) -> bool {
@@ -237,7 +397,13 @@ impl FieldMap for Message {
 
     fn set_field<V: IntoFixValue>(&mut self, tag: i32, value: V) -> Result<(), QuickFixError> {
         let fix_value = value.into_fix_value()?;
-        ffi_code_to_result(unsafe { FixMessage_setField(self.0, tag, fix_value.as_ptr()) })
+        self.set_field_raw(tag, fix_value.as_bytes())
+    }
+
+    fn set_field_raw(&mut self, tag: i32, value: &[u8]) -> Result<(), QuickFixError> {
+        ffi_code_to_result(unsafe {
+            FixMessage_setFieldRaw(self.0, tag, value.as_ptr().cast(), value.len() as u64)
+        })
     }
 
     fn remove_field(&mut self, tag: i32) -> Result<(), QuickFixError> {
@@ -249,6 +415,21 @@ impl FieldMap for Message {
         Ok(())
     }
 
+    fn add_groups(
+        &mut self,
+        template: &Group,
+        rows: &[&[(i32, &str)]],
+    ) -> Result<(), QuickFixError> {
+        ffi_code_to_result(with_flat_group_rows(
+            rows,
+            |tags, values, value_lens, row_lens, rows_len| unsafe {
+                FixMessage_addGroups(
+                    self.0, template.0, tags, values, value_lens, row_lens, rows_len,
+                )
+            },
+        ))
+    }
+
     fn clone_group(&self, index: i32, tag: i32) -> Option<Group> {
         unsafe { FixMessage_copyGroup(self.0, index, tag) }.map(Group)
     }
@@ -258,6 +439,12 @@ impl Clone for Message {
     fn clone(&self) -> Self {
         Self(unsafe { FixMessage_copy(self.0) }.expect("Fail to clone Message"))
     }
+
+    fn clone_from(&mut self, source: &Self) {
+        if self.copy_from(source).is_err() {
+            *self = source.clone();
+        }
+    }
 }
 
 impl fmt::Debug for Message {
@@ -0,0 +1,182 @@
+use std::fmt::Write;
+
+use crate::{
+    message_tree::{FieldNode, MessageTree},
+    tags, DataDictionary, FieldMap, Message, QuickFixError,
+};
+
+/// Fields implied by FIXML document structure, which are never rendered.
+const IMPLIED_TAGS: [i32; 4] = [
+    tags::BEGIN_STRING,
+    tags::BODY_LENGTH,
+    tags::MSG_TYPE,
+    tags::CHECK_SUM,
+];
+
+impl Message {
+    /// Render message as FIXML-style XML, with element and attribute names from `dict`.
+    ///
+    /// Targeted flavor is the FIXML document model (fields as attributes, repeating groups as
+    /// nested elements) using full dictionary names, not the abbreviated names of the official
+    /// FIXML schema (ie: `<ExecutionReport>` / `OrderID`, not `<ExecRpt>` / `OrdID`):
+    ///
+    /// ```xml
+    /// <ExecutionReport OrderID="ORDER-1" Side="1">
+    ///   <Header SenderCompID="BROKER" TargetCompID="CLIENT"/>
+    ///   <PartyIDs PartyID="PARTY-1" PartyIDSource="D" PartyRole="1"/>
+    /// </ExecutionReport>
+    /// ```
+    ///
+    /// (Output has no indentation nor XML declaration.)
+    ///
+    /// - Root element is the message name, derived from MsgType(35) description (ie:
+    ///   `EXECUTION_REPORT` becomes `ExecutionReport`).
+    /// - Body fields are attributes of the root element, header and trailer fields attributes of
+    ///   `<Header>` / `<Trailer>` children (omitted when empty), all in wire order with raw values.
+    /// - Every repeating group entry is an element named after its count field without `No`
+    ///   prefix (ie: `<PartyIDs>` for `NoPartyIDs`), the count itself is not rendered.
+    /// - BeginString(8), BodyLength(9), MsgType(35) and CheckSum(10) are implied by the document.
+    /// - Fields and groups unknown to `dict` are kept in extension form: `Tag{number}` (ie:
+    ///   `Tag5001="value"`).
+    ///
+    /// Fails with [`QuickFixError::InvalidArgument`] when MsgType is missing or not defined by
+    /// `dict`.
+    pub fn to_fixml(&self, dict: &DataDictionary) -> Result<String, QuickFixError> {
+        let msg_type = self
+            .with_header(|header| header.get_field(tags::MSG_TYPE))
+            .ok_or_else(|| QuickFixError::invalid_argument("Missing MsgType"))?;
+        let root = dict
+            .value_name(tags::MSG_TYPE, &msg_type)
+            .map(|name| message_name(&name))
+            .ok_or_else(|| {
+                QuickFixError::invalid_argument(format!(
+                    "MsgType {msg_type} is not defined by dictionary"
+                ))
+            })?;
+
+        let text = self.to_fix_string_lossy()?;
+        let tree = MessageTree::read(self, &text);
+        let body: Vec<_> = tree.body.iter().chain(&tree.unmatched).collect();
+
+        let mut writer = FixmlWriter {
+            dict,
+            output: String::with_capacity(text.len() * 2),
+        };
+        writer.write_start(&root, &body, true);
+        writer.write_section("Header", &tree.header);
+        writer.write_groups(&body);
+        writer.write_section("Trailer", &tree.trailer);
+        writer.write_end(&root);
+        Ok(writer.output)
+    }
+}
+
+/// Convert MsgType description to message name, if it is not one already.
+fn message_name(description: &str) -> String {
+    if !description.contains('_') {
+        return description.to_string();
+    }
+    description
+        .split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            chars.next().map_or_else(String::new, |first| {
+                first
+                    .to_uppercase()
+                    .chain(chars.flat_map(char::to_lowercase))
+                    .collect()
+            })
+        })
+        .collect()
+}
+
+struct FixmlWriter<'a> {
+    dict: &'a DataDictionary,
+    output: String,
+}
+
+impl FixmlWriter<'_> {
+    fn name(&self, tag: i32) -> String {
+        self.dict
+            .field_name(tag)
+            .unwrap_or_else(|| format!("Tag{tag}"))
+    }
+
+    fn entry_name(&self, tag: i32) -> String {
+        match self.dict.field_name(tag) {
+            Some(name) => match name.strip_prefix("No") {
+                Some(stripped) if !stripped.is_empty() => stripped.to_string(),
+                _ => name,
+            },
+            None => format!("Tag{tag}"),
+        }
+    }
+
+    /// Write header / trailer element, skipping it when there is nothing to write.
+    fn write_section(&mut self, name: &str, nodes: &[FieldNode]) {
+        let nodes: Vec<_> = nodes.iter().filter(|node| !is_implied(node)).collect();
+        if !nodes.is_empty() {
+            self.write_element(name, &nodes);
+        }
+    }
+
+    fn write_element(&mut self, name: &str, nodes: &[&FieldNode]) {
+        let has_children = nodes
+            .iter()
+            .any(|node| matches!(node, FieldNode::Group { .. }));
+        self.write_start(name, nodes, has_children);
+        if has_children {
+            self.write_groups(nodes);
+            self.write_end(name);
+        }
+    }
+
+    /// Write start tag with fields of `nodes` as attributes, self-closed without children.
+    fn write_start(&mut self, name: &str, nodes: &[&FieldNode], has_children: bool) {
+        self.output.push('<');
+        self.output.push_str(name);
+        for node in nodes {
+            if let FieldNode::Field { tag, value } = node {
+                if is_implied(node) {
+                    continue;
+                }
+                let _ = write!(self.output, " {}=\"", self.name(*tag));
+                escape_into(&mut self.output, value);
+                self.output.push('"');
+            }
+        }
+        self.output.push_str(if has_children { ">" } else { "/>" });
+    }
+
+    fn write_groups(&mut self, nodes: &[&FieldNode]) {
+        for node in nodes {
+            if let FieldNode::Group { tag, entries, .. } = node {
+                let name = self.entry_name(*tag);
+                for entry in entries {
+                    self.write_element(&name, &entry.iter().collect::<Vec<_>>());
+                }
+            }
+        }
+    }
+
+    fn write_end(&mut self, name: &str) {
+        let _ = write!(self.output, "</{name}>");
+    }
+}
+
+fn is_implied(node: &FieldNode) -> bool {
+    matches!(node, FieldNode::Field { tag, .. } if IMPLIED_TAGS.contains(tag))
+}
+
+fn escape_into(output: &mut String, value: &str) {
+    for c in value.chars() {
+        match c {
+            '&' => output.push_str("&amp;"),
+            '<' => output.push_str("&lt;"),
+            '>' => output.push_str("&gt;"),
+            '"' => output.push_str("&quot;"),
+            '\'' => output.push_str("&apos;"),
+            c => output.push(c),
+        }
+    }
+}
@@ -0,0 +1,277 @@
+use std::fmt;
+
+use serde::{
+    de::{self, MapAccess, SeqAccess, Visitor},
+    ser::SerializeMap,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{
+    message_tree::{FieldNode, MessageTree},
+    FieldMap, Group, Message, QuickFixError,
+};
+
+impl Message {
+    /// Export message as JSON, with a schema stable across FIX versions and crate releases:
+    ///
+    /// ```json
+    /// {
+    ///   "header": { "8": "FIX.4.4", "35": "D", "49": "SENDER", "56": "TARGET" },
+    ///   "body": { "11": "ORDER-1", "54": "1", "55": "AAPL" },
+    ///   "groups": {
+    ///     "453": [
+    ///       { "448": "PARTY-1", "447": "D", "452": "1" },
+    ///       { "448": "PARTY-2", "447": "D", "452": "3", "802": [{ "523": "DESK-1" }] }
+    ///     ]
+    ///   },
+    ///   "trailer": { "10": "123" }
+    /// }
+    /// ```
+    ///
+    /// - Every section is an object keyed by field number, fields being written in wire order.
+    ///   Unknown and custom tags are kept like any other field.
+    /// - Values are raw FIX text, always written as strings.
+    /// - Repeating groups of the body are in `groups`, keyed by their count field, which is not
+    ///   repeated in `body`. Every entry is an object whose first key is the group delimiter.
+    /// - Nested groups, and groups of header or trailer, are arrays of entries in place of their
+    ///   count field.
+    pub fn to_json(&self) -> Result<String, QuickFixError> {
+        let text = self.to_fix_string_lossy()?;
+        let data = JsonMessage::from_tree(&MessageTree::read(self, &text));
+        serde_json::to_string(&data).map_err(|err| invalid_json(&err.to_string()))
+    }
+
+    /// Import message from JSON, following schema described in [`Self::to_json`].
+    ///
+    /// Missing sections are considered empty, and numbers or booleans (as `Y` / `N`) are
+    /// accepted as values. `BodyLength` and `CheckSum` are recomputed when message is rendered.
+    ///
+    /// Fails with [`QuickFixError::MessageParseError`] on malformed JSON, non numeric tags or
+    /// values not matching their section.
+    pub fn try_from_json(text: &str) -> Result<Self, QuickFixError> {
+        let data: JsonMessage =
+            serde_json::from_str(text).map_err(|err| invalid_json(&err.to_string()))?;
+        data.into_message()
+    }
+}
+
+fn invalid_json(reason: &str) -> QuickFixError {
+    QuickFixError::MessageParseError(format!("Invalid JSON message: {reason}"))
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct JsonMessage {
+    header: JsonFields,
+    body: JsonFields,
+    groups: JsonFields,
+    trailer: JsonFields,
+}
+
+/// Fields of a section or group entry, kept in JSON document order.
+#[derive(Debug, Default)]
+struct JsonFields(Vec<(i32, JsonField)>);
+
+#[derive(Debug)]
+enum JsonField {
+    Value(String),
+    Group(Vec<JsonFields>),
+}
+
+impl JsonMessage {
+    fn from_tree(tree: &MessageTree) -> Self {
+        let mut output = Self {
+            header: JsonFields::from_nodes(&tree.header),
+            trailer: JsonFields::from_nodes(&tree.trailer),
+            ..Default::default()
+        };
+        for field in JsonFields::from_nodes(&tree.body).0 {
+            if matches!(field.1, JsonField::Group(_)) {
+                output.groups.0.push(field);
+            } else {
+                output.body.0.push(field);
+            }
+        }
+        output
+            .body
+            .0
+            .extend(JsonFields::from_nodes(&tree.unmatched).0);
+        output
+    }
+
+    fn into_message(self) -> Result<Message, QuickFixError> {
+        if let Some((tag, _)) = self.body.find(|field| matches!(field, JsonField::Group(_))) {
+            return Err(invalid_json(&format!(
+                "body field {tag} is a group, it must be in `groups`"
+            )));
+        }
+        if let Some((tag, _)) = self
+            .groups
+            .find(|field| matches!(field, JsonField::Value(_)))
+        {
+            return Err(invalid_json(&format!(
+                "group {tag} must be an array of entries"
+            )));
+        }
+
+        let mut msg = Message::new();
+        msg.with_header_mut(|header| write_fields(header, self.header))?;
+        write_fields(&mut msg, self.body)?;
+        write_fields(&mut msg, self.groups)?;
+        msg.with_trailer_mut(|trailer| write_fields(trailer, self.trailer))?;
+        Ok(msg)
+    }
+}
+
+impl JsonFields {
+    fn from_nodes(nodes: &[FieldNode]) -> Self {
+        Self(
+            nodes
+                .iter()
+                .map(|node| match node {
+                    FieldNode::Field { tag, value } => (*tag, JsonField::Value(value.to_string())),
+                    FieldNode::Group { tag, entries, .. } => (
+                        *tag,
+                        JsonField::Group(
+                            entries
+                                .iter()
+                                .map(|entry| Self::from_nodes(entry))
+                                .collect(),
+                        ),
+                    ),
+                })
+                .collect(),
+        )
+    }
+
+    fn find(&self, predicate: impl Fn(&JsonField) -> bool) -> Option<&(i32, JsonField)> {
+        self.0.iter().find(|(_, field)| predicate(field))
+    }
+}
+
+fn write_fields<M: FieldMap>(map: &mut M, fields: JsonFields) -> Result<(), QuickFixError> {
+    for (tag, field) in fields.0 {
+        match field {
+            JsonField::Value(value) => map.set_field(tag, value)?,
+            JsonField::Group(entries) if entries.is_empty() => map.set_field(tag, 0)?,
+            JsonField::Group(entries) => {
+                for entry in entries {
+                    map.add_group(&build_group(tag, entry)?)?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn build_group(tag: i32, entry: JsonFields) -> Result<Group, QuickFixError> {
+    let orders: Vec<_> = entry.0.iter().map(|(tag, _)| *tag).collect();
+    let delim = *orders
+        .first()
+        .ok_or_else(|| invalid_json(&format!("empty entry in group {tag}")))?;
+
+    let mut group = Group::try_with_orders(tag, delim, &orders)?;
+    write_fields(&mut group, entry)?;
+    Ok(group)
+}
+
+impl Serialize for JsonFields {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (tag, field) in &self.0 {
+            map.serialize_entry(tag, field)?;
+        }
+        map.end()
+    }
+}
+
+impl Serialize for JsonField {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Value(value) => serializer.serialize_str(value),
+            Self::Group(entries) => entries.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for JsonFields {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(JsonFieldsVisitor)
+    }
+}
+
+struct JsonFieldsVisitor;
+
+impl<'de> Visitor<'de> for JsonFieldsVisitor {
+    type Value = JsonFields;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an object keyed by field number")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut fields: Vec<(i32, JsonField)> = Vec::new();
+        while let Some(key) = map.next_key::<String>()? {
+            let tag = key
+                .parse::<i32>()
+                .ok()
+                .filter(|tag| *tag > 0)
+                .ok_or_else(|| {
+                    de::Error::custom(format!("invalid tag `{key}`, expected a field number"))
+                })?;
+            if fields.iter().any(|(other, _)| *other == tag) {
+                return Err(de::Error::custom(format!("duplicate tag {tag}")));
+            }
+            fields.push((tag, map.next_value()?));
+        }
+        Ok(JsonFields(fields))
+    }
+}
+
+impl<'de> Deserialize<'de> for JsonField {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(JsonFieldVisitor)
+    }
+}
+
+struct JsonFieldVisitor;
+
+impl<'de> Visitor<'de> for JsonFieldVisitor {
+    type Value = JsonField;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a field value or an array of group entries")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        Ok(JsonField::Value(value.to_string()))
+    }
+
+    fn visit_string<E: de::Error>(self, value: String) -> Result<Self::Value, E> {
+        Ok(JsonField::Value(value))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+        Ok(JsonField::Value(value.to_string()))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        Ok(JsonField::Value(value.to_string()))
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<Self::Value, E> {
+        Ok(JsonField::Value(value.to_string()))
+    }
+
+    fn visit_bool<E: de::Error>(self, value: bool) -> Result<Self::Value, E> {
+        Ok(JsonField::Value(if value { "Y" } else { "N" }.to_string()))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut entries = Vec::new();
+        while let Some(entry) = seq.next_element()? {
+            entries.push(entry);
+        }
+        Ok(JsonField::Group(entries))
+    }
+}
@@ -0,0 +1,133 @@
+use std::{
+    fmt,
+    mem::ManuallyDrop,
+    ops::{Deref, DerefMut},
+    sync::{Mutex, MutexGuard, PoisonError},
+};
+
+use crate::Message;
+
+/// Pool of messages, recycling C++ objects instead of allocating a new one per send.
+///
+/// Messages returned to the pool are [cleared](Message::clear), so an acquired message never
+/// contains fields of a previous use.
+///
+/// Combine it with [`crate::send_to_target_by_ref_mut`] or [`crate::ResolvedSession::send`],
+/// which do not consume the message:
+///
+/// ```
+/// use quickfix::*;
+///
+/// # fn send(session_id: &SessionId) -> Result<(), QuickFixError> {
+/// let pool = MessagePool::with_capacity(16);
+///
+/// let mut msg = pool.acquire();
+/// msg.with_header_mut(|header| header.set_field(35, "B"))?;
+/// msg.set_field(148, "Hello")?;
+/// send_to_target_by_ref_mut(&mut msg, session_id)?;
+/// // Message goes back to the pool here.
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Default)]
+pub struct MessagePool {
+    free: Mutex<Vec<Message>>,
+}
+
+impl MessagePool {
+    /// Create new empty pool, allocating messages on demand.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create new pool holding `capacity` pre-allocated messages.
+    ///
+    /// # Panic
+    ///
+    /// When memory allocation fail in C++ library.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            free: Mutex::new((0..capacity).map(|_| Message::new()).collect()),
+        }
+    }
+
+    /// Take an empty message from the pool, or allocate a new one when pool is empty.
+    ///
+    /// # Panic
+    ///
+    /// When memory allocation fail in C++ library.
+    pub fn acquire(&self) -> PooledMessage<'_> {
+        let msg = self.lock().pop().unwrap_or_default();
+        PooledMessage {
+            pool: self,
+            msg: ManuallyDrop::new(msg),
+        }
+    }
+
+    /// Number of messages ready to be acquired without allocation.
+    pub fn available(&self) -> usize {
+        self.lock().len()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Vec<Message>> {
+        self.free.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn release(&self, mut msg: Message) {
+        // Message which cannot be cleared is freed, so no stale field can leak to next user.
+        if msg.clear().is_ok() {
+            self.lock().push(msg);
+        }
+    }
+}
+
+impl fmt::Debug for MessagePool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MessagePool")
+            .field("available", &self.available())
+            .finish()
+    }
+}
+
+/// Message borrowed from a [`MessagePool`], cleared and returned to it on drop.
+pub struct PooledMessage<'a> {
+    pool: &'a MessagePool,
+    msg: ManuallyDrop<Message>,
+}
+
+impl PooledMessage<'_> {
+    /// Detach message from its pool, e.g. to pass it to a consuming send function.
+    pub fn into_inner(self) -> Message {
+        let mut this = ManuallyDrop::new(self);
+        // SAFETY: `this` is never used again nor dropped.
+        unsafe { ManuallyDrop::take(&mut this.msg) }
+    }
+}
+
+impl Deref for PooledMessage<'_> {
+    type Target = Message;
+
+    fn deref(&self) -> &Self::Target {
+        &self.msg
+    }
+}
+
+impl DerefMut for PooledMessage<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.msg
+    }
+}
+
+impl fmt::Debug for PooledMessage<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("PooledMessage").field(&*self.msg).finish()
+    }
+}
+
+impl Drop for PooledMessage<'_> {
+    fn drop(&mut self) {
+        // SAFETY: message is taken once, and never accessed after.
+        let msg = unsafe { ManuallyDrop::take(&mut self.msg) };
+        self.pool.release(msg);
+    }
+}
@@ -0,0 +1,191 @@
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{Arc, PoisonError, RwLock},
+};
+
+use crate::{
+    application::forward_callbacks, ApplicationCallback, FieldMap, Message, MsgFromAppError,
+    SessionId,
+};
+
+const MSG_TYPE: i32 = 35;
+
+type Handler = Arc<dyn Fn(Message, &SessionId) -> Result<(), MsgFromAppError> + Send + Sync>;
+
+/// Dispatch received application messages to handlers registered per MsgType(35).
+///
+/// This is the equivalent of quickfix `MessageCracker`. Messages without any matching handler go
+/// to the fallback handler, or are rejected with [`MsgFromAppError::UnsupportedMessageType`] when
+/// there is none.
+///
+/// Handlers can be registered at any time, even once sessions are running.
+///
+/// ```rust
+/// # use quickfix::*;
+/// let router = MessageRouter::new();
+/// router.on("8", |_msg, session| {
+///     println!("execution report for {session:?}");
+///     Ok(())
+/// });
+/// router.fallback(|_msg, _session| Ok(()));
+///
+/// let application = RoutedApplication::new(NullCallbacks, router);
+/// # struct NullCallbacks;
+/// # impl ApplicationCallback for NullCallbacks {}
+/// # let _ = application;
+/// ```
+#[derive(Default)]
+pub struct MessageRouter {
+    handlers: RwLock<HashMap<String, Handler>>,
+    fallback: RwLock<Option<Handler>>,
+}
+
+impl MessageRouter {
+    /// Create a router without any handler.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register handler of messages of type `msg_type`, replacing previous one if any.
+    pub fn on<F>(&self, msg_type: &str, handler: F)
+    where
+        F: Fn(Message, &SessionId) -> Result<(), MsgFromAppError> + Send + Sync + 'static,
+    {
+        self.handlers
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(msg_type.to_string(), Arc::new(handler));
+    }
+
+    /// Register handler of messages not matching any other handler.
+    pub fn fallback<F>(&self, handler: F)
+    where
+        F: Fn(Message, &SessionId) -> Result<(), MsgFromAppError> + Send + Sync + 'static,
+    {
+        *self
+            .fallback
+            .write()
+            .unwrap_or_else(PoisonError::into_inner) = Some(Arc::new(handler));
+    }
+
+    /// Unregister handler of messages of type `msg_type`.
+    ///
+    /// Return `true` if there was one.
+    pub fn remove(&self, msg_type: &str) -> bool {
+        self.handlers
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(msg_type)
+            .is_some()
+    }
+
+    /// Dispatch message to its handler.
+    pub fn route(&self, msg: Message, session: &SessionId) -> Result<(), MsgFromAppError> {
+        let msg_type = msg.with_header(|header| header.get_field(MSG_TYPE));
+
+        // Release locks before running handler, so it can register other handlers.
+        let handler = msg_type
+            .and_then(|msg_type| {
+                self.handlers
+                    .read()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .get(&msg_type)
+                    .cloned()
+            })
+            .or_else(|| {
+                self.fallback
+                    .read()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .clone()
+            });
+
+        match handler {
+            Some(handler) => handler(msg, session),
+            None => Err(MsgFromAppError::UnsupportedMessageType),
+        }
+    }
+}
+
+impl fmt::Debug for MessageRouter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut msg_types: Vec<_> = self
+            .handlers
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .keys()
+            .cloned()
+            .collect();
+        msg_types.sort();
+
+        f.debug_struct("MessageRouter")
+            .field("msg_types", &msg_types)
+            .field(
+                "has_fallback",
+                &self
+                    .fallback
+                    .read()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .is_some(),
+            )
+            .finish()
+    }
+}
+
+impl ApplicationCallback for MessageRouter {
+    fn on_msg_from_app(&self, msg: Message, session: &SessionId) -> Result<(), MsgFromAppError> {
+        self.route(msg, session)
+    }
+}
+
+/// Application callbacks sending received application messages to a [`MessageRouter`].
+///
+/// Every other callback is forwarded as is to the inner callbacks.
+#[derive(Debug)]
+pub struct RoutedApplication<C> {
+    inner: C,
+    router: MessageRouter,
+}
+
+impl<C: ApplicationCallback> RoutedApplication<C> {
+    /// Wrap `inner` callbacks.
+    pub fn new(inner: C, router: MessageRouter) -> Self {
+        Self { inner, router }
+    }
+
+    /// Read router, to register more handlers.
+    pub fn router(&self) -> &MessageRouter {
+        &self.router
+    }
+
+    /// Read inner callbacks.
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+}
+
+impl<C: ApplicationCallback> ApplicationCallback for RoutedApplication<C> {
+    fn on_msg_from_app(&self, msg: Message, session: &SessionId) -> Result<(), MsgFromAppError> {
+        self.router.route(msg, session)
+    }
+
+    forward_callbacks!(inner =>
+        on_create,
+        on_logon,
+        on_logon_with_context,
+        on_logout,
+        on_logout_with_context,
+        on_msg_to_admin,
+        on_msg_to_app,
+        on_msg_from_admin,
+        on_msg_from_admin_with_kind,
+        on_resend_begin,
+        on_resend_complete,
+        on_connect_failed,
+        on_connect,
+        on_disconnect,
+        on_remove,
+        on_raw_incoming,
+        on_raw_outgoing,
+    );
+}
@@ -0,0 +1,114 @@
+use std::collections::BTreeMap;
+
+use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{
+    message_tree::{FieldNode, MessageTree},
+    FieldMap, Group, Message, QuickFixError,
+};
+
+/// Serialized field value: raw text, or entries of a repeating group.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+enum FieldData {
+    Value(String),
+    /// Every entry lists its fields in order, so delimiter and fields order are kept.
+    Group(Vec<Vec<(i32, FieldData)>>),
+}
+
+/// Serialized message, with fields of every section by tag.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct MessageData {
+    header: BTreeMap<i32, FieldData>,
+    body: BTreeMap<i32, FieldData>,
+    trailer: BTreeMap<i32, FieldData>,
+}
+
+impl MessageData {
+    fn from_tree(tree: &MessageTree) -> Self {
+        Self {
+            header: tree.header.iter().map(field_data).collect(),
+            body: tree
+                .body
+                .iter()
+                .chain(&tree.unmatched)
+                .map(field_data)
+                .collect(),
+            trailer: tree.trailer.iter().map(field_data).collect(),
+        }
+    }
+
+    fn into_message(self) -> Result<Message, QuickFixError> {
+        let mut msg = Message::new();
+        msg.with_header_mut(|header| write_fields(header, self.header))?;
+        write_fields(&mut msg, self.body)?;
+        msg.with_trailer_mut(|trailer| write_fields(trailer, self.trailer))?;
+        Ok(msg)
+    }
+}
+
+fn field_data(node: &FieldNode) -> (i32, FieldData) {
+    match node {
+        FieldNode::Field { tag, value } => (*tag, FieldData::Value(value.to_string())),
+        FieldNode::Group { tag, entries, .. } => (
+            *tag,
+            FieldData::Group(
+                entries
+                    .iter()
+                    .map(|entry| entry.iter().map(field_data).collect())
+                    .collect(),
+            ),
+        ),
+    }
+}
+
+fn write_fields<M, I>(map: &mut M, fields: I) -> Result<(), QuickFixError>
+where
+    M: FieldMap,
+    I: IntoIterator<Item = (i32, FieldData)>,
+{
+    for (tag, data) in fields {
+        match data {
+            FieldData::Value(value) => map.set_field(tag, value)?,
+            FieldData::Group(entries) if entries.is_empty() => map.set_field(tag, 0)?,
+            FieldData::Group(entries) => {
+                for entry in entries {
+                    map.add_group(&build_group(tag, entry)?)?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn build_group(tag: i32, entry: Vec<(i32, FieldData)>) -> Result<Group, QuickFixError> {
+    let orders: Vec<_> = entry.iter().map(|(tag, _)| *tag).collect();
+    let delim = *orders
+        .first()
+        .ok_or_else(|| QuickFixError::invalid_argument(format!("Empty entry in group {tag}")))?;
+
+    let mut group = Group::try_with_orders(tag, delim, &orders)?;
+    write_fields(&mut group, entry)?;
+    Ok(group)
+}
+
+/// Serialize message as `header`, `body` and `trailer` maps of tag to raw value.
+///
+/// Repeating groups are arrays of entries, every entry being an array of `[tag, value]` so
+/// fields order is kept.
+impl Serialize for Message {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let text = self.to_fix_string_lossy().map_err(ser::Error::custom)?;
+        MessageData::from_tree(&MessageTree::read(self, &text)).serialize(serializer)
+    }
+}
+
+/// Rebuild message serialized with [`Serialize`], whatever its FIX version.
+impl<'de> Deserialize<'de> for Message {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        MessageData::deserialize(deserializer)?
+            .into_message()
+            .map_err(de::Error::custom)
+    }
+}
@@ -1,9 +1,30 @@
+use std::{
+    ffi::CString,
+    fs::{self, OpenOptions},
+    io::Read,
+    path::Path,
+    process,
+};
+
 use quickfix_ffi::{
-    FixFileMessageStoreFactory_new, FixMemoryMessageStoreFactory_new,
-    FixMessageStoreFactory_delete, FixMessageStoreFactory_t, FixNullMessageStoreFactory_new,
+    FixFileMessageStoreFactory_new, FixFileMessageStoreFactory_newWithPath,
+    FixMemoryMessageStoreFactory_new, FixMessageStoreFactory_delete,
+    FixMessageStoreFactory_getSequenceNumbers, FixMessageStoreFactory_importStore,
+    FixMessageStoreFactory_resetStore, FixMessageStoreFactory_setSequenceNumbers,
+    FixMessageStoreFactory_t, FixNullMessageStoreFactory_new,
+};
+
+use crate::{
+    store_snapshot::{check_importable, StoreSnapshot},
+    utils::ffi_code_to_result,
+    QuickFixError, SessionId, SessionSettings,
 };
 
-use crate::{QuickFixError, SessionSettings};
+#[cfg(feature = "gzip")]
+pub mod compressed;
+
+mod composite;
+mod custom;
 
 #[cfg(feature = "build-with-mysql")]
 pub mod mysql;
@@ -11,16 +32,98 @@ pub mod mysql;
 #[cfg(feature = "build-with-postgres")]
 pub mod postgres;
 
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
+
+pub use composite::CompositeMessageStoreFactory;
+pub use custom::{CustomMessageStoreFactory, MessageStoreCallback};
+
 ///  Object can be converted as a foreign object representing a `MessageStore`.
 pub trait FfiMessageStoreFactory {
     /// Get a representation of the message store as a FFI pointer.
     fn as_ffi_ptr(&self) -> FixMessageStoreFactory_t;
+
+    /// Reset store of a session without running it: both sequence numbers go back to 1 and stored
+    /// messages are dropped.
+    ///
+    /// Store is opened from this factory just for the reset, so session must not be running in any
+    /// handler, from this process or another one.
+    fn reset_store(&self, session_id: &SessionId) -> Result<(), QuickFixError> {
+        ffi_code_to_result(unsafe {
+            FixMessageStoreFactory_resetStore(self.as_ffi_ptr(), session_id.0)
+        })
+    }
+
+    /// Read next sender and target sequence numbers of a session without running it.
+    ///
+    /// Same restrictions as [`Self::reset_store`].
+    fn sequence_numbers(&self, session_id: &SessionId) -> Result<(u32, u32), QuickFixError> {
+        let mut next_sender = 0;
+        let mut next_target = 0;
+        ffi_code_to_result(unsafe {
+            FixMessageStoreFactory_getSequenceNumbers(
+                self.as_ffi_ptr(),
+                session_id.0,
+                &mut next_sender,
+                &mut next_target,
+            )
+        })?;
+        Ok((next_sender, next_target))
+    }
+
+    /// Replace store content of a session with a snapshot written by
+    /// [`Session::export_store`](crate::Session::export_store), without running it.
+    ///
+    /// Store must still be at sequence number 1 on both sides, unless `force` is set. Only useful
+    /// with persistent stores: memory stores opened here are dropped right away, use
+    /// [`Session::import_store`](crate::Session::import_store) instead. Same restrictions as
+    /// [`Self::reset_store`].
+    fn import_store(
+        &self,
+        session_id: &SessionId,
+        reader: &mut impl Read,
+        force: bool,
+    ) -> Result<(), QuickFixError>
+    where
+        Self: Sized,
+    {
+        let (next_sender, next_target) = self.sequence_numbers(session_id)?;
+        check_importable(next_sender, next_target, force)?;
+        let snapshot = StoreSnapshot::read(reader)?;
+        ffi_code_to_result(snapshot.with_ffi(|ffi_snapshot| unsafe {
+            FixMessageStoreFactory_importStore(self.as_ffi_ptr(), session_id.0, ffi_snapshot)
+        }))
+    }
+
+    /// Change next sender and target sequence numbers of a session without running it.
+    ///
+    /// Stored messages are kept. Same restrictions as [`Self::reset_store`].
+    fn set_sequence_numbers(
+        &self,
+        session_id: &SessionId,
+        next_sender: u32,
+        next_target: u32,
+    ) -> Result<(), QuickFixError> {
+        ffi_code_to_result(unsafe {
+            FixMessageStoreFactory_setSequenceNumbers(
+                self.as_ffi_ptr(),
+                session_id.0,
+                next_sender,
+                next_target,
+            )
+        })
+    }
 }
 
 /// File based implementation of `MessageStore`.
 #[derive(Debug)]
 pub struct FileMessageStoreFactory(FixMessageStoreFactory_t);
 
+// SAFETY: store factories are already used from every thread of multi threaded handlers,
+// C++ implementations do not rely on the thread they have been created on.
+unsafe impl Send for FileMessageStoreFactory {}
+unsafe impl Sync for FileMessageStoreFactory {}
+
 impl FileMessageStoreFactory {
     /// Try to create new struct from settings.
     pub fn try_new(settings: &SessionSettings) -> Result<Self, QuickFixError> {
@@ -28,6 +131,39 @@ impl FileMessageStoreFactory {
             .map(Self)
             .ok_or_else(QuickFixError::from_last_error)
     }
+
+    /// Try to create new struct storing every session in `path`, whatever `FileStorePath` is.
+    ///
+    /// Directory is created if missing.
+    pub fn try_with_path<P: AsRef<Path>>(path: P) -> Result<Self, QuickFixError> {
+        let path = path.as_ref();
+        let io_error = |err: std::io::Error| {
+            QuickFixError::IOException(format!(
+                "File store path {} is not writable: {err}",
+                path.display()
+            ))
+        };
+
+        // Check it now, quickfix only reports it once sessions are created.
+        fs::create_dir_all(path).map_err(io_error)?;
+        let probe = path.join(format!(".quickfix-write-check-{}", process::id()));
+        OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(&probe)
+            .map_err(io_error)?;
+        let _ = fs::remove_file(&probe);
+
+        let safe_path = path
+            .to_str()
+            .ok_or_else(|| QuickFixError::invalid_argument("Cannot convert path to C path"))?;
+        let ffi_path = CString::new(safe_path)?;
+
+        unsafe { FixFileMessageStoreFactory_newWithPath(ffi_path.as_ptr()) }
+            .map(Self)
+            .ok_or_else(QuickFixError::from_last_error)
+    }
 }
 
 impl FfiMessageStoreFactory for FileMessageStoreFactory {
@@ -46,6 +182,9 @@ impl Drop for FileMessageStoreFactory {
 #[derive(Debug)]
 pub struct MemoryMessageStoreFactory(FixMessageStoreFactory_t);
 
+unsafe impl Send for MemoryMessageStoreFactory {}
+unsafe impl Sync for MemoryMessageStoreFactory {}
+
 impl MemoryMessageStoreFactory {
     /// Create new struct.
     pub fn new() -> Self {
@@ -76,9 +215,16 @@ impl Drop for MemoryMessageStoreFactory {
 /// Null implementation of MessageStore.
 ///
 /// Will not actually store messages. Useful for admin-only or market data-only applications.
+///
+/// Sequence numbers are only kept in memory, and resend requests cannot be honored: counterparty
+/// gets a SequenceReset-GapFill(35=4, 123=Y) over the requested range instead of replayed
+/// messages. Pair it with `ResetOnLogon=Y` so sessions start from 1 on every logon.
 #[derive(Debug)]
 pub struct NullMessageStoreFactory(FixMessageStoreFactory_t);
 
+unsafe impl Send for NullMessageStoreFactory {}
+unsafe impl Sync for NullMessageStoreFactory {}
+
 impl NullMessageStoreFactory {
     /// Create new struct.
     pub fn new() -> Self {
@@ -0,0 +1,121 @@
+use std::{
+    ffi::{self, CStr},
+    fmt,
+    marker::PhantomData,
+    panic::{catch_unwind, AssertUnwindSafe},
+};
+
+use quickfix_ffi::{
+    FixCompositeMessageStoreFactory_new, FixMessageStoreFactory_delete, FixMessageStoreFactory_t,
+    FixSessionID_t,
+};
+
+use crate::{panic_policy::handle_store_panic, FfiMessageStoreFactory, QuickFixError, SessionId};
+
+/// Message store factory writing to two other factories, e.g. to migrate sessions from one
+/// storage to another.
+///
+/// Every change (stored messages, sequence numbers, reset) is written to primary store first, then
+/// to secondary store. Everything is read from primary store only. After each change, both stores
+/// must report the same sequence numbers: any secondary failure or mismatch is a divergence, see
+/// [`Self::try_new`] and [`Self::try_with_divergence_handler`].
+///
+/// Both factories must outlive this one.
+pub struct CompositeMessageStoreFactory<'a>(FixMessageStoreFactory_t, PhantomData<&'a ()>);
+
+// SAFETY: composite store only forwards calls to the given factories, which must be `Sync` too.
+unsafe impl Send for CompositeMessageStoreFactory<'_> {}
+unsafe impl Sync for CompositeMessageStoreFactory<'_> {}
+
+impl<'a> CompositeMessageStoreFactory<'a> {
+    /// Try to create new struct from `primary` and `secondary` factories.
+    ///
+    /// Divergence is reported as an I/O error to the FIX engine, which disconnects the session.
+    pub fn try_new<P, S>(primary: &'a P, secondary: &'a S) -> Result<Self, QuickFixError>
+    where
+        P: FfiMessageStoreFactory + Sync,
+        S: FfiMessageStoreFactory + Sync,
+    {
+        Self::build(primary, secondary, std::ptr::null(), None)
+    }
+
+    /// Same as [`Self::try_new`], calling `on_divergence` with session and reason instead.
+    ///
+    /// Session keeps running on primary store: secondary one should be resynchronized, for example
+    /// with [`FfiMessageStoreFactory::import_store`], before being used on its own.
+    pub fn try_with_divergence_handler<P, S, F>(
+        primary: &'a P,
+        secondary: &'a S,
+        on_divergence: &'a F,
+    ) -> Result<Self, QuickFixError>
+    where
+        P: FfiMessageStoreFactory + Sync,
+        S: FfiMessageStoreFactory + Sync,
+        F: Fn(&SessionId, &str) + Sync,
+    {
+        Self::build(
+            primary,
+            secondary,
+            on_divergence as *const F as *const ffi::c_void,
+            Some(Self::on_divergence::<F>),
+        )
+    }
+
+    fn build<P, S>(
+        primary: &'a P,
+        secondary: &'a S,
+        data: *const ffi::c_void,
+        on_divergence: Option<
+            extern "C" fn(*const ffi::c_void, FixSessionID_t, *const ffi::c_char),
+        >,
+    ) -> Result<Self, QuickFixError>
+    where
+        P: FfiMessageStoreFactory,
+        S: FfiMessageStoreFactory,
+    {
+        unsafe {
+            FixCompositeMessageStoreFactory_new(
+                primary.as_ffi_ptr(),
+                secondary.as_ffi_ptr(),
+                data,
+                on_divergence,
+            )
+        }
+        .map(|inner| Self(inner, PhantomData))
+        .ok_or_else(QuickFixError::from_last_error)
+    }
+
+    extern "C" fn on_divergence<F: Fn(&SessionId, &str)>(
+        data: *const ffi::c_void,
+        session: FixSessionID_t,
+        reason: *const ffi::c_char,
+    ) {
+        let session_id = SessionId::borrow_ffi(session);
+        let reason = unsafe { CStr::from_ptr(reason) }.to_string_lossy();
+
+        if let Err(payload) = catch_unwind(AssertUnwindSafe(|| {
+            let on_divergence = unsafe { &*(data as *const F) };
+            on_divergence(&session_id, &reason);
+        })) {
+            handle_store_panic("on_divergence", &session_id, payload);
+        }
+    }
+}
+
+impl FfiMessageStoreFactory for CompositeMessageStoreFactory<'_> {
+    fn as_ffi_ptr(&self) -> FixMessageStoreFactory_t {
+        self.0
+    }
+}
+
+impl fmt::Debug for CompositeMessageStoreFactory<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("CompositeMessageStoreFactory").finish()
+    }
+}
+
+impl Drop for CompositeMessageStoreFactory<'_> {
+    fn drop(&mut self) {
+        unsafe { FixMessageStoreFactory_delete(self.0) }
+    }
+}
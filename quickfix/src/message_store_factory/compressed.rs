@@ -0,0 +1,518 @@
+use std::{
+    collections::{hash_map::Entry, BTreeMap, HashMap},
+    fmt,
+    fs::{self, File, OpenOptions},
+    io::{self, BufReader, Read, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+    sync::{Mutex, PoisonError},
+    time::SystemTime,
+};
+
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
+use quickfix_ffi::FixMessageStoreFactory_t;
+
+use crate::{
+    utils::{from_millis, to_millis},
+    CustomMessageStoreFactory, FfiMessageStoreFactory, MessageStoreCallback, QuickFixError,
+    SessionId,
+};
+
+/// Number of messages compressed together by default.
+const DEFAULT_BLOCK_LEN: usize = 64;
+
+/// Size of an `.index` file record.
+const INDEX_RECORD_LEN: usize = 24;
+
+fn invalid_data(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+/// Where a stored message can be read from.
+#[derive(Debug)]
+enum Location {
+    /// Message is part of the compressed block starting at `offset` in the `.blocks` file.
+    Block {
+        offset: u64,
+        len: u32,
+        start: u32,
+        msg_len: u32,
+    },
+    /// Message is not compressed yet, it only lives in the `.pending` file.
+    Pending(String),
+}
+
+/// Files of a single session, named after the session id:
+/// - `.seqnums`: sequence numbers and creation time, rewritten on every change,
+/// - `.blocks`: gzip compressed blocks of messages, appended one after the other,
+/// - `.index`: location of every compressed message,
+/// - `.pending`: messages not compressed yet, until there are enough of them to fill a block.
+#[derive(Debug)]
+struct SessionFiles {
+    dir: PathBuf,
+    name: String,
+    block_len: usize,
+    next_sender: u32,
+    next_target: u32,
+    creation_time: SystemTime,
+    messages: BTreeMap<u32, Location>,
+    /// Sequence numbers of pending messages, in storage order.
+    pending: Vec<u32>,
+}
+
+impl SessionFiles {
+    fn open(dir: &Path, session_id: &SessionId, block_len: usize) -> io::Result<Self> {
+        let name: String = session_id
+            .to_repr()
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || c == '.' || c == '_' {
+                    c
+                } else {
+                    '-'
+                }
+            })
+            .collect();
+
+        let mut files = Self {
+            dir: dir.to_path_buf(),
+            name,
+            block_len,
+            next_sender: 1,
+            next_target: 1,
+            creation_time: SystemTime::now(),
+            messages: BTreeMap::new(),
+            pending: Vec::new(),
+        };
+        files.load()?;
+        Ok(files)
+    }
+
+    fn path(&self, extension: &str) -> PathBuf {
+        self.dir.join(format!("{}.{extension}", self.name))
+    }
+
+    fn append(&self, extension: &str) -> io::Result<File> {
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(self.path(extension))
+    }
+
+    fn load(&mut self) -> io::Result<()> {
+        self.messages.clear();
+        self.pending.clear();
+
+        match fs::read_to_string(self.path("seqnums")) {
+            Ok(content) => {
+                let values: Vec<i64> = content
+                    .split_whitespace()
+                    .map(str::parse)
+                    .collect::<Result<_, _>>()
+                    .map_err(|_err| invalid_data("Invalid seqnums file"))?;
+                let [next_sender, next_target, creation_time] = values[..] else {
+                    return Err(invalid_data("Invalid seqnums file"));
+                };
+                self.next_sender = next_sender as u32;
+                self.next_target = next_target as u32;
+                self.creation_time = from_millis(creation_time);
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                self.next_sender = 1;
+                self.next_target = 1;
+                self.creation_time = SystemTime::now();
+                self.save_seq_nums()?;
+            }
+            Err(err) => return Err(err),
+        }
+
+        if let Some(index) = read_if_exists(&self.path("index"))? {
+            // Trailing partial record is a write interrupted by a crash: message is still pending.
+            for record in index.chunks_exact(INDEX_RECORD_LEN) {
+                let field = |at: usize| u32::from_le_bytes(record[at..at + 4].try_into().unwrap());
+                let seq = field(0);
+                let offset = u64::from_le_bytes(record[4..12].try_into().unwrap());
+                self.messages.insert(
+                    seq,
+                    Location::Block {
+                        offset,
+                        len: field(12),
+                        start: field(16),
+                        msg_len: field(20),
+                    },
+                );
+            }
+        }
+
+        // Pending messages are newer than compressed ones: they win on duplicate sequence numbers.
+        if let Some(pending) = read_if_exists(&self.path("pending"))? {
+            let mut reader = pending.as_slice();
+            while let Some((seq, msg)) = read_pending(&mut reader) {
+                self.messages.insert(seq, Location::Pending(msg));
+                self.pending.push(seq);
+            }
+        }
+        Ok(())
+    }
+
+    fn save_seq_nums(&self) -> io::Result<()> {
+        fs::write(
+            self.path("seqnums"),
+            format!(
+                "{} {} {}",
+                self.next_sender,
+                self.next_target,
+                to_millis(self.creation_time)
+            ),
+        )
+    }
+
+    fn set(&mut self, seq: u32, msg: &str) -> io::Result<()> {
+        let msg_len =
+            u32::try_from(msg.len()).map_err(|_err| invalid_data("Message is too large"))?;
+        let mut record = Vec::with_capacity(msg.len() + 8);
+        record.extend_from_slice(&seq.to_le_bytes());
+        record.extend_from_slice(&msg_len.to_le_bytes());
+        record.extend_from_slice(msg.as_bytes());
+        self.append("pending")?.write_all(&record)?;
+
+        self.messages
+            .insert(seq, Location::Pending(msg.to_string()));
+        self.pending.push(seq);
+        if self.pending.len() >= self.block_len {
+            self.compress_pending()?;
+        }
+        Ok(())
+    }
+
+    /// Move every pending message into a new compressed block.
+    fn compress_pending(&mut self) -> io::Result<()> {
+        let mut pending = std::mem::take(&mut self.pending);
+        pending.sort_unstable();
+        pending.dedup();
+
+        let mut block = Vec::new();
+        let mut entries = Vec::new();
+        for seq in pending {
+            if let Some(Location::Pending(msg)) = self.messages.get(&seq) {
+                entries.push((seq, block.len() as u32, msg.len() as u32));
+                block.extend_from_slice(msg.as_bytes());
+            }
+        }
+        if entries.is_empty() {
+            return Ok(());
+        }
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&block)?;
+        let compressed = encoder.finish()?;
+        let len =
+            u32::try_from(compressed.len()).map_err(|_err| invalid_data("Block is too large"))?;
+
+        // Block is written first: a crash before index is updated only leaves unused bytes.
+        let mut blocks = self.append("blocks")?;
+        let offset = blocks.seek(SeekFrom::End(0))?;
+        blocks.write_all(&compressed)?;
+
+        let mut index = Vec::with_capacity(entries.len() * INDEX_RECORD_LEN);
+        for (seq, start, msg_len) in &entries {
+            index.extend_from_slice(&seq.to_le_bytes());
+            index.extend_from_slice(&offset.to_le_bytes());
+            index.extend_from_slice(&len.to_le_bytes());
+            index.extend_from_slice(&start.to_le_bytes());
+            index.extend_from_slice(&msg_len.to_le_bytes());
+        }
+        self.append("index")?.write_all(&index)?;
+        File::create(self.path("pending"))?;
+
+        for (seq, start, msg_len) in entries {
+            self.messages.insert(
+                seq,
+                Location::Block {
+                    offset,
+                    len,
+                    start,
+                    msg_len,
+                },
+            );
+        }
+        Ok(())
+    }
+
+    fn get(&self, begin: u32, end: u32) -> io::Result<Vec<String>> {
+        if begin > end {
+            return Ok(Vec::new());
+        }
+
+        let mut blocks = None;
+        // Messages of a block are contiguous: keep last decompressed one around.
+        let mut last_block: Option<(u64, Vec<u8>)> = None;
+        let mut output = Vec::new();
+
+        for location in self
+            .messages
+            .range(begin..=end)
+            .map(|(_, location)| location)
+        {
+            match location {
+                Location::Pending(msg) => output.push(msg.clone()),
+                Location::Block {
+                    offset,
+                    len,
+                    start,
+                    msg_len,
+                } => {
+                    if last_block.as_ref().map(|(at, _)| at) != Some(offset) {
+                        if blocks.is_none() {
+                            blocks = Some(File::open(self.path("blocks"))?);
+                        }
+                        let file = blocks.as_mut().unwrap();
+                        file.seek(SeekFrom::Start(*offset))?;
+                        let mut decoder = GzDecoder::new(BufReader::new(file.take(*len as u64)));
+                        let mut block = Vec::new();
+                        decoder.read_to_end(&mut block)?;
+                        last_block = Some((*offset, block));
+                    }
+
+                    let block = &last_block.as_ref().unwrap().1;
+                    let bytes = block
+                        .get(*start as usize..(*start + *msg_len) as usize)
+                        .ok_or_else(|| invalid_data("Index does not match compressed block"))?;
+                    let msg = String::from_utf8(bytes.to_vec())
+                        .map_err(|_err| invalid_data("Stored message is not valid UTF-8"))?;
+                    output.push(msg);
+                }
+            }
+        }
+        Ok(output)
+    }
+
+    fn reset(&mut self, now: SystemTime) -> io::Result<()> {
+        for extension in ["blocks", "index", "pending"] {
+            match fs::remove_file(self.path(extension)) {
+                Err(err) if err.kind() != io::ErrorKind::NotFound => return Err(err),
+                _ => {}
+            }
+        }
+        self.messages.clear();
+        self.pending.clear();
+        self.next_sender = 1;
+        self.next_target = 1;
+        self.creation_time = now;
+        self.save_seq_nums()
+    }
+}
+
+fn read_if_exists(path: &Path) -> io::Result<Option<Vec<u8>>> {
+    match fs::read(path) {
+        Ok(content) => Ok(Some(content)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+/// Read next `.pending` record, stopping on a trailing partial record left by a crash.
+fn read_pending(reader: &mut &[u8]) -> Option<(u32, String)> {
+    let mut header = [0; 8];
+    reader.read_exact(&mut header).ok()?;
+    let seq = u32::from_le_bytes(header[..4].try_into().unwrap());
+    let len = u32::from_le_bytes(header[4..].try_into().unwrap()) as usize;
+    let msg = reader.get(..len)?;
+    *reader = &reader[len..];
+    String::from_utf8(msg.to_vec()).ok().map(|msg| (seq, msg))
+}
+
+/// Message store keeping outgoing messages in compressed files, one set of files per session.
+///
+/// Messages are gzip compressed by blocks of `block_len` messages. Until a block is full, its
+/// messages are kept uncompressed in a side file, so nothing is lost on process restart. An index
+/// keeps the location of every message, so resend requests only decompress the blocks they need
+/// and return messages exactly as they have been stored.
+///
+/// Store directory must not be shared with another process.
+pub struct CompressedFileStore {
+    dir: PathBuf,
+    block_len: usize,
+    sessions: Mutex<HashMap<String, SessionFiles>>,
+}
+
+impl CompressedFileStore {
+    /// Try to open store in directory `path`, creating it if missing.
+    pub fn try_new<P: AsRef<Path>>(path: P) -> Result<Self, QuickFixError> {
+        Self::try_with_block_len(path, DEFAULT_BLOCK_LEN)
+    }
+
+    /// Same as [`Self::try_new`], compressing messages by blocks of `block_len` messages.
+    ///
+    /// Larger blocks compress better but make resend of a single message slower.
+    pub fn try_with_block_len<P: AsRef<Path>>(
+        path: P,
+        block_len: usize,
+    ) -> Result<Self, QuickFixError> {
+        if block_len == 0 {
+            return Err(QuickFixError::invalid_argument("Block len cannot be 0"));
+        }
+
+        let dir = path.as_ref().to_path_buf();
+        fs::create_dir_all(&dir).map_err(|err| {
+            QuickFixError::IOException(format!(
+                "Cannot create compressed store {}: {err}",
+                dir.display()
+            ))
+        })?;
+
+        Ok(Self {
+            dir,
+            block_len,
+            sessions: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Run `f` on session files, opening them if needed.
+    fn with<T>(
+        &self,
+        session_id: &SessionId,
+        f: impl FnOnce(&mut SessionFiles) -> io::Result<T>,
+    ) -> Result<T, QuickFixError> {
+        let mut sessions = self.sessions.lock().unwrap_or_else(PoisonError::into_inner);
+        let result = match sessions.entry(session_id.to_repr()) {
+            Entry::Occupied(entry) => f(entry.into_mut()),
+            Entry::Vacant(entry) => SessionFiles::open(&self.dir, session_id, self.block_len)
+                .and_then(|files| f(entry.insert(files))),
+        };
+        result.map_err(|err| {
+            QuickFixError::IOException(format!("Compressed message store failure: {err}"))
+        })
+    }
+
+    fn update_seq_nums(
+        &self,
+        session_id: &SessionId,
+        f: impl FnOnce(&mut SessionFiles),
+    ) -> Result<(), QuickFixError> {
+        self.with(session_id, |files| {
+            f(files);
+            files.save_seq_nums()
+        })
+    }
+}
+
+impl MessageStoreCallback for CompressedFileStore {
+    fn on_create(&self, session_id: &SessionId) -> Result<(), QuickFixError> {
+        self.with(session_id, |_| Ok(()))
+    }
+
+    fn get(
+        &self,
+        session_id: &SessionId,
+        begin: u32,
+        end: u32,
+    ) -> Result<Vec<String>, QuickFixError> {
+        self.with(session_id, |files| files.get(begin, end))
+    }
+
+    fn set(&self, session_id: &SessionId, seq: u32, msg: &str) -> Result<bool, QuickFixError> {
+        self.with(session_id, |files| files.set(seq, msg))?;
+        Ok(true)
+    }
+
+    fn next_sender_msg_seq_num(&self, session_id: &SessionId) -> Result<u32, QuickFixError> {
+        self.with(session_id, |files| Ok(files.next_sender))
+    }
+
+    fn next_target_msg_seq_num(&self, session_id: &SessionId) -> Result<u32, QuickFixError> {
+        self.with(session_id, |files| Ok(files.next_target))
+    }
+
+    fn set_next_sender_msg_seq_num(
+        &self,
+        session_id: &SessionId,
+        value: u32,
+    ) -> Result<(), QuickFixError> {
+        self.update_seq_nums(session_id, |files| files.next_sender = value)
+    }
+
+    fn set_next_target_msg_seq_num(
+        &self,
+        session_id: &SessionId,
+        value: u32,
+    ) -> Result<(), QuickFixError> {
+        self.update_seq_nums(session_id, |files| files.next_target = value)
+    }
+
+    fn incr_next_sender_msg_seq_num(&self, session_id: &SessionId) -> Result<(), QuickFixError> {
+        self.update_seq_nums(session_id, |files| files.next_sender += 1)
+    }
+
+    fn incr_next_target_msg_seq_num(&self, session_id: &SessionId) -> Result<(), QuickFixError> {
+        self.update_seq_nums(session_id, |files| files.next_target += 1)
+    }
+
+    fn creation_time(&self, session_id: &SessionId) -> Result<SystemTime, QuickFixError> {
+        self.with(session_id, |files| Ok(files.creation_time))
+    }
+
+    fn reset(&self, session_id: &SessionId, now: SystemTime) -> Result<(), QuickFixError> {
+        self.with(session_id, |files| files.reset(now))
+    }
+
+    fn refresh(&self, session_id: &SessionId) -> Result<(), QuickFixError> {
+        self.with(session_id, SessionFiles::load)
+    }
+}
+
+impl fmt::Debug for CompressedFileStore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CompressedFileStore")
+            .field("dir", &self.dir)
+            .field("block_len", &self.block_len)
+            .finish()
+    }
+}
+
+/// Compressed file based implementation of `MessageStore`, see [`CompressedFileStore`].
+pub struct CompressedFileMessageStoreFactory {
+    // Declared first: C++ factory must be deleted before the store it points to.
+    factory: CustomMessageStoreFactory<'static, CompressedFileStore>,
+    _store: Box<CompressedFileStore>,
+}
+
+impl CompressedFileMessageStoreFactory {
+    /// Try to open store in directory `path`, creating it if missing.
+    pub fn try_new<P: AsRef<Path>>(path: P) -> Result<Self, QuickFixError> {
+        Self::from_store(CompressedFileStore::try_new(path)?)
+    }
+
+    /// Same as [`Self::try_new`], see [`CompressedFileStore::try_with_block_len`].
+    pub fn try_with_block_len<P: AsRef<Path>>(
+        path: P,
+        block_len: usize,
+    ) -> Result<Self, QuickFixError> {
+        Self::from_store(CompressedFileStore::try_with_block_len(path, block_len)?)
+    }
+
+    fn from_store(store: CompressedFileStore) -> Result<Self, QuickFixError> {
+        let store = Box::new(store);
+        // SAFETY: store is boxed, so it does not move, and outlives factory (see field order).
+        let callbacks = unsafe { &*(store.as_ref() as *const CompressedFileStore) };
+        let factory = CustomMessageStoreFactory::try_new(callbacks)?;
+
+        Ok(Self {
+            factory,
+            _store: store,
+        })
+    }
+}
+
+impl FfiMessageStoreFactory for CompressedFileMessageStoreFactory {
+    fn as_ffi_ptr(&self) -> FixMessageStoreFactory_t {
+        self.factory.as_ffi_ptr()
+    }
+}
+
+impl fmt::Debug for CompressedFileMessageStoreFactory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("CompressedFileMessageStoreFactory")
+            .field(&self._store)
+            .finish()
+    }
+}
@@ -0,0 +1,367 @@
+use std::{
+    ffi, fmt,
+    marker::PhantomData,
+    panic::{catch_unwind, AssertUnwindSafe},
+    slice, str,
+    time::SystemTime,
+};
+
+use quickfix_ffi::{
+    FixCustomMessageStoreFactory_new, FixCustomMessageStore_setError, FixMessageStoreCallbacks_t,
+    FixMessageStoreFactory_delete, FixMessageStoreFactory_t, FixSessionID_t,
+};
+
+use crate::{
+    panic_policy::handle_store_panic,
+    utils::{from_millis, to_millis},
+    FfiMessageStoreFactory, QuickFixError, SessionId,
+};
+
+/// Message store implemented in rust, given to the FIX engine with [`CustomMessageStoreFactory`].
+///
+/// A single implementation serves every session of the handlers it is given to, so every method
+/// receives the session it applies to. Sessions never seen before start with both sequence
+/// numbers set to 1.
+///
+/// Callbacks run from quickfix threads, with session lock held: use interior mutability to update
+/// state and avoid blocking for long.
+///
+/// Errors are reported to the FIX engine as I/O errors, with their message: a failure while
+/// creating a session fails the connection handler creation, other ones disconnect the session.
+/// A panicking callback is reported the same way.
+#[allow(unused_variables)]
+pub trait MessageStoreCallback: Sync {
+    /// Called when a session using this store is created, before any other callback.
+    fn on_create(&self, session_id: &SessionId) -> Result<(), QuickFixError> {
+        Ok(())
+    }
+
+    /// Get stored messages from `begin` to `end` (inclusive), in sequence order.
+    ///
+    /// Messages never stored are skipped: quickfix gap fills them on resend.
+    fn get(
+        &self,
+        session_id: &SessionId,
+        begin: u32,
+        end: u32,
+    ) -> Result<Vec<String>, QuickFixError>;
+
+    /// Store outgoing message `seq`, return `false` if it has not been stored.
+    fn set(&self, session_id: &SessionId, seq: u32, msg: &str) -> Result<bool, QuickFixError>;
+
+    /// Sequence number of the next message to send.
+    fn next_sender_msg_seq_num(&self, session_id: &SessionId) -> Result<u32, QuickFixError>;
+
+    /// Sequence number of the next message expected from counterparty.
+    fn next_target_msg_seq_num(&self, session_id: &SessionId) -> Result<u32, QuickFixError>;
+
+    /// Update sequence number of the next message to send.
+    fn set_next_sender_msg_seq_num(
+        &self,
+        session_id: &SessionId,
+        value: u32,
+    ) -> Result<(), QuickFixError>;
+
+    /// Update sequence number of the next message expected from counterparty.
+    fn set_next_target_msg_seq_num(
+        &self,
+        session_id: &SessionId,
+        value: u32,
+    ) -> Result<(), QuickFixError>;
+
+    /// Increment sequence number of the next message to send.
+    fn incr_next_sender_msg_seq_num(&self, session_id: &SessionId) -> Result<(), QuickFixError> {
+        let value = self.next_sender_msg_seq_num(session_id)?;
+        self.set_next_sender_msg_seq_num(session_id, value + 1)
+    }
+
+    /// Increment sequence number of the next message expected from counterparty.
+    fn incr_next_target_msg_seq_num(&self, session_id: &SessionId) -> Result<(), QuickFixError> {
+        let value = self.next_target_msg_seq_num(session_id)?;
+        self.set_next_target_msg_seq_num(session_id, value + 1)
+    }
+
+    /// Time the session store has been created, or last reset.
+    fn creation_time(&self, session_id: &SessionId) -> Result<SystemTime, QuickFixError>;
+
+    /// Drop every stored message, set both sequence numbers back to 1 and creation time to `now`.
+    fn reset(&self, session_id: &SessionId, now: SystemTime) -> Result<(), QuickFixError>;
+
+    /// Reload session state from storage, e.g. when it is shared with another process.
+    fn refresh(&self, session_id: &SessionId) -> Result<(), QuickFixError> {
+        Ok(())
+    }
+}
+
+/// Message store factory backed by a [`MessageStoreCallback`].
+///
+/// Can be used everywhere a [`MemoryMessageStoreFactory`](crate::MemoryMessageStoreFactory) is.
+pub struct CustomMessageStoreFactory<'a, C: MessageStoreCallback>(
+    FixMessageStoreFactory_t,
+    PhantomData<&'a C>,
+);
+
+// SAFETY: C++ factory only keeps a pointer to the callbacks, which are `Sync` as they are called
+// from quickfix threads anyway.
+unsafe impl<C: MessageStoreCallback> Send for CustomMessageStoreFactory<'_, C> {}
+unsafe impl<C: MessageStoreCallback> Sync for CustomMessageStoreFactory<'_, C> {}
+
+const CALLBACK_OK: i8 = 0;
+const CALLBACK_FAILED: i8 = -1;
+
+impl<'a, C> CustomMessageStoreFactory<'a, C>
+where
+    C: MessageStoreCallback + 'a,
+{
+    /// Try create new struct from store callbacks.
+    pub fn try_new(callbacks: &'a C) -> Result<Self, QuickFixError> {
+        match unsafe {
+            FixCustomMessageStoreFactory_new(
+                callbacks as *const C as *const ffi::c_void,
+                &Self::CALLBACKS,
+            )
+        } {
+            Some(fix_store_factory) => Ok(Self(fix_store_factory, PhantomData)),
+            None => Err(QuickFixError::from_last_error()),
+        }
+    }
+
+    const CALLBACKS: FixMessageStoreCallbacks_t = FixMessageStoreCallbacks_t {
+        onCreate: Self::on_create,
+        set: Self::set,
+        get: Self::get,
+        getNextSenderMsgSeqNum: Self::get_next_sender_msg_seq_num,
+        getNextTargetMsgSeqNum: Self::get_next_target_msg_seq_num,
+        setNextSenderMsgSeqNum: Self::set_next_sender_msg_seq_num,
+        setNextTargetMsgSeqNum: Self::set_next_target_msg_seq_num,
+        incrNextSenderMsgSeqNum: Self::incr_next_sender_msg_seq_num,
+        incrNextTargetMsgSeqNum: Self::incr_next_target_msg_seq_num,
+        getCreationTime: Self::get_creation_time,
+        reset: Self::reset,
+        refresh: Self::refresh,
+    };
+
+    fn call<T>(
+        callback: &str,
+        data: *const ffi::c_void,
+        session: FixSessionID_t,
+        failed: T,
+        f: impl FnOnce(&C, &SessionId) -> Result<T, QuickFixError>,
+    ) -> T {
+        let session_id = SessionId::borrow_ffi(session);
+
+        match catch_unwind(AssertUnwindSafe(|| {
+            let this = unsafe { &*(data as *const C) };
+            f(this, &session_id)
+        })) {
+            Ok(Ok(output)) => output,
+            Ok(Err(err)) => {
+                // Message is read back by the FIX engine to build its exception.
+                let message = ffi::CString::new(err.to_string().replace('\0', " "))
+                    .expect("NUL bytes have been replaced");
+                unsafe { FixCustomMessageStore_setError(message.as_ptr()) };
+                failed
+            }
+            Err(payload) => {
+                handle_store_panic(callback, &session_id, payload);
+                failed
+            }
+        }
+    }
+
+    extern "C" fn on_create(data: *const ffi::c_void, session: FixSessionID_t) -> i8 {
+        Self::call(
+            "on_create",
+            data,
+            session,
+            CALLBACK_FAILED,
+            |this, session_id| {
+                this.on_create(session_id)?;
+                Ok(CALLBACK_OK)
+            },
+        )
+    }
+
+    extern "C" fn set(
+        data: *const ffi::c_void,
+        session: FixSessionID_t,
+        seq_num: u32,
+        msg: *const ffi::c_char,
+        len: u64,
+    ) -> i8 {
+        let raw = if msg.is_null() {
+            &[]
+        } else {
+            unsafe { slice::from_raw_parts(msg.cast::<u8>(), len as usize) }
+        };
+        let Ok(msg) = str::from_utf8(raw) else {
+            return CALLBACK_FAILED;
+        };
+
+        Self::call("set", data, session, CALLBACK_FAILED, |this, session_id| {
+            Ok(i8::from(this.set(session_id, seq_num, msg)?))
+        })
+    }
+
+    extern "C" fn get(
+        data: *const ffi::c_void,
+        session: FixSessionID_t,
+        begin: u32,
+        end: u32,
+        output: *mut ffi::c_void,
+        push: extern "C" fn(*mut ffi::c_void, *const ffi::c_char, u64),
+    ) -> i8 {
+        Self::call("get", data, session, CALLBACK_FAILED, |this, session_id| {
+            for msg in this.get(session_id, begin, end)? {
+                push(output, msg.as_ptr().cast(), msg.len() as u64);
+            }
+            Ok(CALLBACK_OK)
+        })
+    }
+
+    extern "C" fn get_next_sender_msg_seq_num(
+        data: *const ffi::c_void,
+        session: FixSessionID_t,
+    ) -> i64 {
+        Self::call(
+            "next_sender_msg_seq_num",
+            data,
+            session,
+            i64::from(CALLBACK_FAILED),
+            |this, session_id| Ok(i64::from(this.next_sender_msg_seq_num(session_id)?)),
+        )
+    }
+
+    extern "C" fn get_next_target_msg_seq_num(
+        data: *const ffi::c_void,
+        session: FixSessionID_t,
+    ) -> i64 {
+        Self::call(
+            "next_target_msg_seq_num",
+            data,
+            session,
+            i64::from(CALLBACK_FAILED),
+            |this, session_id| Ok(i64::from(this.next_target_msg_seq_num(session_id)?)),
+        )
+    }
+
+    extern "C" fn set_next_sender_msg_seq_num(
+        data: *const ffi::c_void,
+        session: FixSessionID_t,
+        value: u32,
+    ) -> i8 {
+        Self::call(
+            "set_next_sender_msg_seq_num",
+            data,
+            session,
+            CALLBACK_FAILED,
+            |this, session_id| {
+                this.set_next_sender_msg_seq_num(session_id, value)?;
+                Ok(CALLBACK_OK)
+            },
+        )
+    }
+
+    extern "C" fn set_next_target_msg_seq_num(
+        data: *const ffi::c_void,
+        session: FixSessionID_t,
+        value: u32,
+    ) -> i8 {
+        Self::call(
+            "set_next_target_msg_seq_num",
+            data,
+            session,
+            CALLBACK_FAILED,
+            |this, session_id| {
+                this.set_next_target_msg_seq_num(session_id, value)?;
+                Ok(CALLBACK_OK)
+            },
+        )
+    }
+
+    extern "C" fn incr_next_sender_msg_seq_num(
+        data: *const ffi::c_void,
+        session: FixSessionID_t,
+    ) -> i8 {
+        Self::call(
+            "incr_next_sender_msg_seq_num",
+            data,
+            session,
+            CALLBACK_FAILED,
+            |this, session_id| {
+                this.incr_next_sender_msg_seq_num(session_id)?;
+                Ok(CALLBACK_OK)
+            },
+        )
+    }
+
+    extern "C" fn incr_next_target_msg_seq_num(
+        data: *const ffi::c_void,
+        session: FixSessionID_t,
+    ) -> i8 {
+        Self::call(
+            "incr_next_target_msg_seq_num",
+            data,
+            session,
+            CALLBACK_FAILED,
+            |this, session_id| {
+                this.incr_next_target_msg_seq_num(session_id)?;
+                Ok(CALLBACK_OK)
+            },
+        )
+    }
+
+    extern "C" fn get_creation_time(data: *const ffi::c_void, session: FixSessionID_t) -> i64 {
+        Self::call(
+            "creation_time",
+            data,
+            session,
+            i64::from(CALLBACK_FAILED),
+            |this, session_id| Ok(to_millis(this.creation_time(session_id)?)),
+        )
+    }
+
+    extern "C" fn reset(data: *const ffi::c_void, session: FixSessionID_t, now: i64) -> i8 {
+        Self::call(
+            "reset",
+            data,
+            session,
+            CALLBACK_FAILED,
+            |this, session_id| {
+                this.reset(session_id, from_millis(now))?;
+                Ok(CALLBACK_OK)
+            },
+        )
+    }
+
+    extern "C" fn refresh(data: *const ffi::c_void, session: FixSessionID_t) -> i8 {
+        Self::call(
+            "refresh",
+            data,
+            session,
+            CALLBACK_FAILED,
+            |this, session_id| {
+                this.refresh(session_id)?;
+                Ok(CALLBACK_OK)
+            },
+        )
+    }
+}
+
+impl<C: MessageStoreCallback> FfiMessageStoreFactory for CustomMessageStoreFactory<'_, C> {
+    fn as_ffi_ptr(&self) -> FixMessageStoreFactory_t {
+        self.0
+    }
+}
+
+impl<C: MessageStoreCallback> fmt::Debug for CustomMessageStoreFactory<'_, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("CustomMessageStoreFactory").finish()
+    }
+}
+
+impl<C: MessageStoreCallback> Drop for CustomMessageStoreFactory<'_, C> {
+    fn drop(&mut self) {
+        unsafe { FixMessageStoreFactory_delete(self.0) }
+    }
+}
@@ -8,8 +8,15 @@ use crate::{FfiMessageStoreFactory, QuickFixError, SessionSettings};
 #[derive(Debug)]
 pub struct PostgresMessageStoreFactory(FixMessageStoreFactory_t);
 
+unsafe impl Send for PostgresMessageStoreFactory {}
+unsafe impl Sync for PostgresMessageStoreFactory {}
+
 impl PostgresMessageStoreFactory {
     /// Try to create new struct from settings.
+    ///
+    /// Session stores connect using `PostgreSQLStore*` settings keys once handler starts: connection
+    /// failures are reported by `start()` as [`QuickFixError::ConfigError`], naming the session and
+    /// the database settings in use.
     pub fn try_new(settings: &SessionSettings) -> Result<Self, QuickFixError> {
         unsafe { FixPostgresMessageStoreFactory_new(settings.0) }
             .map(Self)
@@ -0,0 +1,234 @@
+use std::{
+    fmt,
+    path::Path,
+    sync::{Mutex, PoisonError},
+    time::SystemTime,
+};
+
+use quickfix_ffi::FixMessageStoreFactory_t;
+use rusqlite::{params, Connection, OptionalExtension, Params};
+
+use crate::{
+    utils::{from_millis, to_millis},
+    CustomMessageStoreFactory, FfiMessageStoreFactory, MessageStoreCallback, QuickFixError,
+    SessionId,
+};
+
+const SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS sessions (
+        session_id TEXT PRIMARY KEY NOT NULL,
+        creation_time INTEGER NOT NULL,
+        next_sender_msg_seq_num INTEGER NOT NULL,
+        next_target_msg_seq_num INTEGER NOT NULL
+    );
+    CREATE TABLE IF NOT EXISTS messages (
+        session_id TEXT NOT NULL,
+        msg_seq_num INTEGER NOT NULL,
+        message TEXT NOT NULL,
+        PRIMARY KEY (session_id, msg_seq_num)
+    );
+";
+
+/// SQLite database holding every session of a store factory.
+struct SqliteStore {
+    connection: Mutex<Connection>,
+}
+
+impl SqliteStore {
+    fn open(path: &Path) -> rusqlite::Result<Self> {
+        let connection = Connection::open(path)?;
+        connection.execute_batch(SCHEMA)?;
+        Ok(Self {
+            connection: Mutex::new(connection),
+        })
+    }
+
+    /// Run a statement, database failures are reported as I/O errors.
+    fn with<T>(
+        &self,
+        f: impl FnOnce(&mut Connection) -> rusqlite::Result<T>,
+    ) -> Result<T, QuickFixError> {
+        let mut connection = self
+            .connection
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        f(&mut connection).map_err(|err| {
+            QuickFixError::IOException(format!("SQLite message store failure: {err}"))
+        })
+    }
+
+    fn get_seq_num(&self, session_id: &SessionId, column: &str) -> Result<u32, QuickFixError> {
+        let key = session_id.to_repr();
+        self.with(|connection| {
+            connection
+                .prepare_cached(&format!(
+                    "SELECT {column} FROM sessions WHERE session_id = ?1"
+                ))?
+                .query_row(params![key], |row| row.get(0))
+                .optional()
+                .map(|value| value.unwrap_or(1))
+        })
+    }
+
+    fn execute(&self, sql: &str, params: impl Params) -> Result<(), QuickFixError> {
+        self.with(|connection| connection.prepare_cached(sql)?.execute(params))
+            .map(|_| ())
+    }
+}
+
+impl MessageStoreCallback for SqliteStore {
+    fn on_create(&self, session_id: &SessionId) -> Result<(), QuickFixError> {
+        self.execute(
+            "INSERT OR IGNORE INTO sessions VALUES (?1, ?2, 1, 1)",
+            params![session_id.to_repr(), to_millis(SystemTime::now())],
+        )
+    }
+
+    fn get(
+        &self,
+        session_id: &SessionId,
+        begin: u32,
+        end: u32,
+    ) -> Result<Vec<String>, QuickFixError> {
+        let key = session_id.to_repr();
+        self.with(|connection| {
+            let mut statement = connection.prepare_cached(
+                "SELECT message FROM messages
+                 WHERE session_id = ?1 AND msg_seq_num BETWEEN ?2 AND ?3
+                 ORDER BY msg_seq_num",
+            )?;
+            let messages: rusqlite::Result<Vec<String>> = statement
+                .query_map(params![key, begin, end], |row| row.get(0))?
+                .collect();
+            messages
+        })
+    }
+
+    fn set(&self, session_id: &SessionId, seq: u32, msg: &str) -> Result<bool, QuickFixError> {
+        let key = session_id.to_repr();
+        let changed = self.with(|connection| {
+            connection
+                .prepare_cached("INSERT OR REPLACE INTO messages VALUES (?1, ?2, ?3)")?
+                .execute(params![key, seq, msg])
+        })?;
+        Ok(changed == 1)
+    }
+
+    fn next_sender_msg_seq_num(&self, session_id: &SessionId) -> Result<u32, QuickFixError> {
+        self.get_seq_num(session_id, "next_sender_msg_seq_num")
+    }
+
+    fn next_target_msg_seq_num(&self, session_id: &SessionId) -> Result<u32, QuickFixError> {
+        self.get_seq_num(session_id, "next_target_msg_seq_num")
+    }
+
+    fn set_next_sender_msg_seq_num(
+        &self,
+        session_id: &SessionId,
+        value: u32,
+    ) -> Result<(), QuickFixError> {
+        self.execute(
+            "UPDATE sessions SET next_sender_msg_seq_num = ?2 WHERE session_id = ?1",
+            params![session_id.to_repr(), value],
+        )
+    }
+
+    fn set_next_target_msg_seq_num(
+        &self,
+        session_id: &SessionId,
+        value: u32,
+    ) -> Result<(), QuickFixError> {
+        self.execute(
+            "UPDATE sessions SET next_target_msg_seq_num = ?2 WHERE session_id = ?1",
+            params![session_id.to_repr(), value],
+        )
+    }
+
+    fn incr_next_sender_msg_seq_num(&self, session_id: &SessionId) -> Result<(), QuickFixError> {
+        self.execute(
+            "UPDATE sessions SET next_sender_msg_seq_num = next_sender_msg_seq_num + 1
+             WHERE session_id = ?1",
+            params![session_id.to_repr()],
+        )
+    }
+
+    fn incr_next_target_msg_seq_num(&self, session_id: &SessionId) -> Result<(), QuickFixError> {
+        self.execute(
+            "UPDATE sessions SET next_target_msg_seq_num = next_target_msg_seq_num + 1
+             WHERE session_id = ?1",
+            params![session_id.to_repr()],
+        )
+    }
+
+    fn creation_time(&self, session_id: &SessionId) -> Result<SystemTime, QuickFixError> {
+        let key = session_id.to_repr();
+        let millis = self.with(|connection| {
+            connection
+                .query_row(
+                    "SELECT creation_time FROM sessions WHERE session_id = ?1",
+                    params![key],
+                    |row| row.get(0),
+                )
+                .optional()
+        })?;
+        Ok(millis.map_or_else(SystemTime::now, from_millis))
+    }
+
+    fn reset(&self, session_id: &SessionId, now: SystemTime) -> Result<(), QuickFixError> {
+        let key = session_id.to_repr();
+        self.with(|connection| {
+            let transaction = connection.transaction()?;
+            transaction.execute("DELETE FROM messages WHERE session_id = ?1", params![key])?;
+            transaction.execute(
+                "INSERT OR REPLACE INTO sessions VALUES (?1, ?2, 1, 1)",
+                params![key, to_millis(now)],
+            )?;
+            transaction.commit()
+        })
+    }
+}
+
+/// SQLite based implementation of `MessageStore`.
+///
+/// Every session of the handlers using this factory is stored in a single database file, keyed by
+/// session id. Sequence numbers and sent messages are written as soon as quickfix updates them, so
+/// they survive process restarts.
+pub struct SqliteMessageStoreFactory {
+    // Declared first: C++ factory must be deleted before the store it points to.
+    factory: CustomMessageStoreFactory<'static, SqliteStore>,
+    _store: Box<SqliteStore>,
+}
+
+impl SqliteMessageStoreFactory {
+    /// Try to open database at `path`, creating it and its tables if missing.
+    pub fn try_new<P: AsRef<Path>>(path: P) -> Result<Self, QuickFixError> {
+        let path = path.as_ref();
+        let store = SqliteStore::open(path).map(Box::new).map_err(|err| {
+            QuickFixError::IOException(format!(
+                "Cannot open SQLite store {}: {err}",
+                path.display()
+            ))
+        })?;
+
+        // SAFETY: store is boxed, so it does not move, and outlives factory (see field order).
+        let callbacks = unsafe { &*(store.as_ref() as *const SqliteStore) };
+        let factory = CustomMessageStoreFactory::try_new(callbacks)?;
+
+        Ok(Self {
+            factory,
+            _store: store,
+        })
+    }
+}
+
+impl FfiMessageStoreFactory for SqliteMessageStoreFactory {
+    fn as_ffi_ptr(&self) -> FixMessageStoreFactory_t {
+        self.factory.as_ffi_ptr()
+    }
+}
+
+impl fmt::Debug for SqliteMessageStoreFactory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SqliteMessageStoreFactory").finish()
+    }
+}
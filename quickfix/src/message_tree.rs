@@ -0,0 +1,104 @@
+use crate::{FieldMap, Message};
+
+/// Field of a message section, or a repeating group with all its entries.
+#[derive(Debug, PartialEq)]
+pub(crate) enum FieldNode<'a> {
+    Field {
+        tag: i32,
+        value: &'a str,
+    },
+    Group {
+        tag: i32,
+        count: &'a str,
+        entries: Vec<Vec<FieldNode<'a>>>,
+    },
+}
+
+/// Message fields in wire order, split by section with repeating groups structure.
+///
+/// C++ field maps cannot be iterated, so structure is recovered from message FIX text: fields
+/// are assigned to the first field map holding them with this value, and groups are found by
+/// looking for entries behind every count field.
+#[derive(Debug, Default)]
+pub(crate) struct MessageTree<'a> {
+    pub header: Vec<FieldNode<'a>>,
+    pub body: Vec<FieldNode<'a>>,
+    pub trailer: Vec<FieldNode<'a>>,
+    /// Fields not matched by any field map, which should not happen.
+    pub unmatched: Vec<FieldNode<'a>>,
+}
+
+impl<'a> MessageTree<'a> {
+    /// Read structure of `msg`, where `text` is its FIX text.
+    pub fn read(msg: &Message, text: &'a str) -> Self {
+        let fields: Vec<(i32, &str)> = text
+            .split('\u{1}')
+            .filter(|field| !field.is_empty())
+            .map(|field| {
+                let (tag, value) = field.split_once('=').unwrap_or((field, ""));
+                (tag.parse().unwrap_or(0), value)
+            })
+            .collect();
+
+        let mut reader = TreeReader {
+            fields: &fields,
+            pos: 0,
+        };
+        let header = msg.with_header(|header| reader.read_map(header, None));
+        let body = reader.read_map(msg, None);
+        let trailer = msg.with_trailer(|trailer| reader.read_map(trailer, None));
+        let unmatched = fields[reader.pos..]
+            .iter()
+            .map(|&(tag, value)| FieldNode::Field { tag, value })
+            .collect();
+
+        Self {
+            header,
+            body,
+            trailer,
+            unmatched,
+        }
+    }
+}
+
+struct TreeReader<'f, 'a> {
+    fields: &'f [(i32, &'a str)],
+    pos: usize,
+}
+
+impl<'a> TreeReader<'_, 'a> {
+    /// Read fields belonging to `map`, until next group entry starting with `delimiter` if any.
+    fn read_map<M: FieldMap>(&mut self, map: &M, delimiter: Option<i32>) -> Vec<FieldNode<'a>> {
+        let start = self.pos;
+        let mut output = Vec::new();
+        while let Some(&(tag, value)) = self.fields.get(self.pos) {
+            if (self.pos > start && Some(tag) == delimiter) || !map.is_field_equal(tag, value) {
+                break;
+            }
+            self.pos += 1;
+
+            let mut entries = Vec::new();
+            let count = value.parse().unwrap_or(0);
+            for index in 1..=count {
+                let Some(group) = map.clone_group(index, tag) else {
+                    break;
+                };
+                if self.fields.get(self.pos).map(|x| x.0) != Some(group.delim()) {
+                    break;
+                }
+                entries.push(self.read_map(&group, Some(group.delim())));
+            }
+
+            output.push(if entries.is_empty() {
+                FieldNode::Field { tag, value }
+            } else {
+                FieldNode::Group {
+                    tag,
+                    count: value,
+                    entries,
+                }
+            });
+        }
+        output
+    }
+}
@@ -0,0 +1,217 @@
+//! `MsgType` (tag 35) value of every standard FIX message.
+//!
+//! ```
+//! use quickfix::{msg_types, tags, Message};
+//!
+//! # fn main() -> Result<(), quickfix::QuickFixError> {
+//! let mut msg = Message::new();
+//! msg.with_header_mut(|h| h.set_field(tags::MSG_TYPE, msg_types::NEW_ORDER_SINGLE))?;
+//! # Ok(())
+//! # }
+//! ```
+
+// Generated by `quickfix-msg-gen/examples/core-constants.rs` from the FIX 5.0 / FIXT 1.1 dictionary.
+// Do not edit by hand.
+
+/// `Heartbeat` message.
+pub const HEARTBEAT: &str = "0";
+/// `TestRequest` message.
+pub const TEST_REQUEST: &str = "1";
+/// `ResendRequest` message.
+pub const RESEND_REQUEST: &str = "2";
+/// `Reject` message.
+pub const REJECT: &str = "3";
+/// `SequenceReset` message.
+pub const SEQUENCE_RESET: &str = "4";
+/// `Logout` message.
+pub const LOGOUT: &str = "5";
+/// `IOI` message.
+pub const IOI: &str = "6";
+/// `Advertisement` message.
+pub const ADVERTISEMENT: &str = "7";
+/// `ExecutionReport` message.
+pub const EXECUTION_REPORT: &str = "8";
+/// `OrderCancelReject` message.
+pub const ORDER_CANCEL_REJECT: &str = "9";
+/// `Logon` message.
+pub const LOGON: &str = "A";
+/// `DerivativeSecurityList` message.
+pub const DERIVATIVE_SECURITY_LIST: &str = "AA";
+/// `NewOrderMultileg` message.
+pub const NEW_ORDER_MULTILEG: &str = "AB";
+/// `MultilegOrderCancelReplace` message.
+pub const MULTILEG_ORDER_CANCEL_REPLACE: &str = "AC";
+/// `TradeCaptureReportRequest` message.
+pub const TRADE_CAPTURE_REPORT_REQUEST: &str = "AD";
+/// `TradeCaptureReport` message.
+pub const TRADE_CAPTURE_REPORT: &str = "AE";
+/// `OrderMassStatusRequest` message.
+pub const ORDER_MASS_STATUS_REQUEST: &str = "AF";
+/// `QuoteRequestReject` message.
+pub const QUOTE_REQUEST_REJECT: &str = "AG";
+/// `RFQRequest` message.
+pub const RFQ_REQUEST: &str = "AH";
+/// `QuoteStatusReport` message.
+pub const QUOTE_STATUS_REPORT: &str = "AI";
+/// `QuoteResponse` message.
+pub const QUOTE_RESPONSE: &str = "AJ";
+/// `Confirmation` message.
+pub const CONFIRMATION: &str = "AK";
+/// `PositionMaintenanceRequest` message.
+pub const POSITION_MAINTENANCE_REQUEST: &str = "AL";
+/// `PositionMaintenanceReport` message.
+pub const POSITION_MAINTENANCE_REPORT: &str = "AM";
+/// `RequestForPositions` message.
+pub const REQUEST_FOR_POSITIONS: &str = "AN";
+/// `RequestForPositionsAck` message.
+pub const REQUEST_FOR_POSITIONS_ACK: &str = "AO";
+/// `PositionReport` message.
+pub const POSITION_REPORT: &str = "AP";
+/// `TradeCaptureReportRequestAck` message.
+pub const TRADE_CAPTURE_REPORT_REQUEST_ACK: &str = "AQ";
+/// `TradeCaptureReportAck` message.
+pub const TRADE_CAPTURE_REPORT_ACK: &str = "AR";
+/// `AllocationReport` message.
+pub const ALLOCATION_REPORT: &str = "AS";
+/// `AllocationReportAck` message.
+pub const ALLOCATION_REPORT_ACK: &str = "AT";
+/// `ConfirmationAck` message.
+pub const CONFIRMATION_ACK: &str = "AU";
+/// `SettlementInstructionRequest` message.
+pub const SETTLEMENT_INSTRUCTION_REQUEST: &str = "AV";
+/// `AssignmentReport` message.
+pub const ASSIGNMENT_REPORT: &str = "AW";
+/// `CollateralRequest` message.
+pub const COLLATERAL_REQUEST: &str = "AX";
+/// `CollateralAssignment` message.
+pub const COLLATERAL_ASSIGNMENT: &str = "AY";
+/// `CollateralResponse` message.
+pub const COLLATERAL_RESPONSE: &str = "AZ";
+/// `News` message.
+pub const NEWS: &str = "B";
+/// `CollateralReport` message.
+pub const COLLATERAL_REPORT: &str = "BA";
+/// `CollateralInquiry` message.
+pub const COLLATERAL_INQUIRY: &str = "BB";
+/// `NetworkCounterpartySystemStatusRequest` message.
+pub const NETWORK_COUNTERPARTY_SYSTEM_STATUS_REQUEST: &str = "BC";
+/// `NetworkCounterpartySystemStatusResponse` message.
+pub const NETWORK_COUNTERPARTY_SYSTEM_STATUS_RESPONSE: &str = "BD";
+/// `UserRequest` message.
+pub const USER_REQUEST: &str = "BE";
+/// `UserResponse` message.
+pub const USER_RESPONSE: &str = "BF";
+/// `CollateralInquiryAck` message.
+pub const COLLATERAL_INQUIRY_ACK: &str = "BG";
+/// `ConfirmationRequest` message.
+pub const CONFIRMATION_REQUEST: &str = "BH";
+/// `TradingSessionListRequest` message.
+pub const TRADING_SESSION_LIST_REQUEST: &str = "BI";
+/// `TradingSessionList` message.
+pub const TRADING_SESSION_LIST: &str = "BJ";
+/// `SecurityListUpdateReport` message.
+pub const SECURITY_LIST_UPDATE_REPORT: &str = "BK";
+/// `AdjustedPositionReport` message.
+pub const ADJUSTED_POSITION_REPORT: &str = "BL";
+/// `AllocationInstructionAlert` message.
+pub const ALLOCATION_INSTRUCTION_ALERT: &str = "BM";
+/// `ExecutionAcknowledgement` message.
+pub const EXECUTION_ACKNOWLEDGEMENT: &str = "BN";
+/// `ContraryIntentionReport` message.
+pub const CONTRARY_INTENTION_REPORT: &str = "BO";
+/// `SecurityDefinitionUpdateReport` message.
+pub const SECURITY_DEFINITION_UPDATE_REPORT: &str = "BP";
+/// `Email` message.
+pub const EMAIL: &str = "C";
+/// `NewOrderSingle` message.
+pub const NEW_ORDER_SINGLE: &str = "D";
+/// `NewOrderList` message.
+pub const NEW_ORDER_LIST: &str = "E";
+/// `OrderCancelRequest` message.
+pub const ORDER_CANCEL_REQUEST: &str = "F";
+/// `OrderCancelReplaceRequest` message.
+pub const ORDER_CANCEL_REPLACE_REQUEST: &str = "G";
+/// `OrderStatusRequest` message.
+pub const ORDER_STATUS_REQUEST: &str = "H";
+/// `AllocationInstruction` message.
+pub const ALLOCATION_INSTRUCTION: &str = "J";
+/// `ListCancelRequest` message.
+pub const LIST_CANCEL_REQUEST: &str = "K";
+/// `ListExecute` message.
+pub const LIST_EXECUTE: &str = "L";
+/// `ListStatusRequest` message.
+pub const LIST_STATUS_REQUEST: &str = "M";
+/// `ListStatus` message.
+pub const LIST_STATUS: &str = "N";
+/// `AllocationInstructionAck` message.
+pub const ALLOCATION_INSTRUCTION_ACK: &str = "P";
+/// `DontKnowTrade` message.
+pub const DONT_KNOW_TRADE: &str = "Q";
+/// `QuoteRequest` message.
+pub const QUOTE_REQUEST: &str = "R";
+/// `Quote` message.
+pub const QUOTE: &str = "S";
+/// `SettlementInstructions` message.
+pub const SETTLEMENT_INSTRUCTIONS: &str = "T";
+/// `MarketDataRequest` message.
+pub const MARKET_DATA_REQUEST: &str = "V";
+/// `MarketDataSnapshotFullRefresh` message.
+pub const MARKET_DATA_SNAPSHOT_FULL_REFRESH: &str = "W";
+/// `MarketDataIncrementalRefresh` message.
+pub const MARKET_DATA_INCREMENTAL_REFRESH: &str = "X";
+/// `MarketDataRequestReject` message.
+pub const MARKET_DATA_REQUEST_REJECT: &str = "Y";
+/// `QuoteCancel` message.
+pub const QUOTE_CANCEL: &str = "Z";
+/// `QuoteStatusRequest` message.
+pub const QUOTE_STATUS_REQUEST: &str = "a";
+/// `MassQuoteAcknowledgement` message.
+pub const MASS_QUOTE_ACKNOWLEDGEMENT: &str = "b";
+/// `SecurityDefinitionRequest` message.
+pub const SECURITY_DEFINITION_REQUEST: &str = "c";
+/// `SecurityDefinition` message.
+pub const SECURITY_DEFINITION: &str = "d";
+/// `SecurityStatusRequest` message.
+pub const SECURITY_STATUS_REQUEST: &str = "e";
+/// `SecurityStatus` message.
+pub const SECURITY_STATUS: &str = "f";
+/// `TradingSessionStatusRequest` message.
+pub const TRADING_SESSION_STATUS_REQUEST: &str = "g";
+/// `TradingSessionStatus` message.
+pub const TRADING_SESSION_STATUS: &str = "h";
+/// `MassQuote` message.
+pub const MASS_QUOTE: &str = "i";
+/// `BusinessMessageReject` message.
+pub const BUSINESS_MESSAGE_REJECT: &str = "j";
+/// `BidRequest` message.
+pub const BID_REQUEST: &str = "k";
+/// `BidResponse` message.
+pub const BID_RESPONSE: &str = "l";
+/// `ListStrikePrice` message.
+pub const LIST_STRIKE_PRICE: &str = "m";
+/// `XMLnonFIX` message.
+pub const XM_LNON_FIX: &str = "n";
+/// `RegistrationInstructions` message.
+pub const REGISTRATION_INSTRUCTIONS: &str = "o";
+/// `RegistrationInstructionsResponse` message.
+pub const REGISTRATION_INSTRUCTIONS_RESPONSE: &str = "p";
+/// `OrderMassCancelRequest` message.
+pub const ORDER_MASS_CANCEL_REQUEST: &str = "q";
+/// `OrderMassCancelReport` message.
+pub const ORDER_MASS_CANCEL_REPORT: &str = "r";
+/// `NewOrderCross` message.
+pub const NEW_ORDER_CROSS: &str = "s";
+/// `CrossOrderCancelReplaceRequest` message.
+pub const CROSS_ORDER_CANCEL_REPLACE_REQUEST: &str = "t";
+/// `CrossOrderCancelRequest` message.
+pub const CROSS_ORDER_CANCEL_REQUEST: &str = "u";
+/// `SecurityTypeRequest` message.
+pub const SECURITY_TYPE_REQUEST: &str = "v";
+/// `SecurityTypes` message.
+pub const SECURITY_TYPES: &str = "w";
+/// `SecurityListRequest` message.
+pub const SECURITY_LIST_REQUEST: &str = "x";
+/// `SecurityList` message.
+pub const SECURITY_LIST: &str = "y";
+/// `DerivativeSecurityListRequest` message.
+pub const DERIVATIVE_SECURITY_LIST_REQUEST: &str = "z";
@@ -0,0 +1,168 @@
+use std::{
+    fmt,
+    sync::{Arc, PoisonError, RwLock},
+};
+
+use crate::{
+    application::forward_callbacks, ApplicationCallback, Message, MsgToAppError, SessionId,
+};
+
+type Enricher = Arc<dyn Fn(&mut Message, &SessionId) + Send + Sync>;
+
+/// Which outgoing messages an enricher applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnrichScope {
+    /// Admin messages only (Logon, Heartbeat, ...), from `on_msg_to_admin`.
+    Admin,
+    /// Application messages only, from `on_msg_to_app`.
+    App,
+    /// Every outgoing message.
+    All,
+}
+
+impl EnrichScope {
+    fn matches(self, other: Self) -> bool {
+        self == Self::All || self == other
+    }
+}
+
+/// Stamp fields on every outgoing message.
+///
+/// Enrichers run in registration order, before the wrapped callbacks see the message. Filter on
+/// MsgType(35) inside the enricher to only stamp some message types.
+///
+/// Enrichers can be registered at any time, even once sessions are running.
+///
+/// ```rust
+/// # use quickfix::*;
+/// let enricher = OutgoingEnricher::new();
+/// enricher.enrich(EnrichScope::All, |msg, _session| {
+///     let _ = msg.with_header_mut(|header| header.set_field(115, "DESK-A"));
+/// });
+///
+/// let application = EnrichedApplication::new(NullCallbacks, enricher);
+/// # struct NullCallbacks;
+/// # impl ApplicationCallback for NullCallbacks {}
+/// # let _ = application;
+/// ```
+#[derive(Default)]
+pub struct OutgoingEnricher {
+    enrichers: RwLock<Vec<(EnrichScope, Enricher)>>,
+}
+
+impl OutgoingEnricher {
+    /// Create an enricher stamping nothing.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register `enricher` for outgoing messages in `scope`.
+    pub fn enrich<F>(&self, scope: EnrichScope, enricher: F)
+    where
+        F: Fn(&mut Message, &SessionId) + Send + Sync + 'static,
+    {
+        self.enrichers
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push((scope, Arc::new(enricher)));
+    }
+
+    /// Remove every registered enricher.
+    pub fn clear(&self) {
+        self.enrichers
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+    }
+
+    /// Run enrichers registered for `scope` on message.
+    pub fn apply(&self, scope: EnrichScope, msg: &mut Message, session: &SessionId) {
+        // Release lock before running enrichers, so they can register other ones.
+        let enrichers: Vec<_> = self
+            .enrichers
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .filter(|(enricher_scope, _)| enricher_scope.matches(scope))
+            .map(|(_, enricher)| enricher.clone())
+            .collect();
+
+        for enricher in enrichers {
+            enricher(msg, session);
+        }
+    }
+}
+
+impl fmt::Debug for OutgoingEnricher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let scopes: Vec<_> = self
+            .enrichers
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .map(|(scope, _)| *scope)
+            .collect();
+
+        f.debug_struct("OutgoingEnricher")
+            .field("scopes", &scopes)
+            .finish()
+    }
+}
+
+/// Application callbacks running an [`OutgoingEnricher`] on every outgoing message.
+///
+/// Stamped fields are set before inner `on_msg_to_admin` / `on_msg_to_app` run, so inner callbacks
+/// can read them, or override them for a single message. Received messages are not enriched.
+#[derive(Debug)]
+pub struct EnrichedApplication<C> {
+    inner: C,
+    enricher: OutgoingEnricher,
+}
+
+impl<C: ApplicationCallback> EnrichedApplication<C> {
+    /// Wrap `inner` callbacks, running `enricher` on every message sent by their sessions.
+    pub fn new(inner: C, enricher: OutgoingEnricher) -> Self {
+        Self { inner, enricher }
+    }
+
+    /// Read enricher: enrichers registered through it stamp the next outgoing messages.
+    pub fn enricher(&self) -> &OutgoingEnricher {
+        &self.enricher
+    }
+
+    /// Read inner callbacks.
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+}
+
+impl<C: ApplicationCallback> ApplicationCallback for EnrichedApplication<C> {
+    fn on_msg_to_admin(&self, msg: &mut Message, session: &SessionId) {
+        self.enricher.apply(EnrichScope::Admin, msg, session);
+        self.inner.on_msg_to_admin(msg, session)
+    }
+
+    fn on_msg_to_app(&self, msg: &mut Message, session: &SessionId) -> Result<(), MsgToAppError> {
+        self.enricher.apply(EnrichScope::App, msg, session);
+        self.inner.on_msg_to_app(msg, session)
+    }
+
+    forward_callbacks!(inner =>
+        on_create,
+        on_logon,
+        on_logon_with_context,
+        on_logout,
+        on_logout_with_context,
+        on_msg_from_admin,
+        on_msg_from_admin_with_kind,
+        on_msg_from_app,
+        on_resend_begin,
+        on_resend_complete,
+        on_connect_failed,
+        on_connect,
+        on_disconnect,
+        on_remove,
+        on_raw_incoming,
+        on_raw_outgoing,
+    );
+}
@@ -0,0 +1,117 @@
+use std::{
+    any::Any,
+    process,
+    sync::atomic::{AtomicU8, Ordering},
+};
+
+use crate::{Session, SessionId};
+
+/// What to do when a callback given to the FIX engine panics.
+///
+/// Panics never cross the FFI boundary: they are always caught, and logged with the `log` feature,
+/// before the policy applies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(u8)]
+pub enum PanicPolicy {
+    /// Ignore the panic: FIX engine continues as if the callback returned successfully.
+    ///
+    /// This is the default.
+    #[default]
+    Swallow,
+    /// Disconnect the session the callback was running for.
+    ///
+    /// Initiators reconnect using their `ReconnectInterval`. Behaves like `Swallow` for log
+    /// callbacks and callbacks not bound to a session.
+    Disconnect,
+    /// Abort the process.
+    Abort,
+}
+
+static PANIC_POLICY: AtomicU8 = AtomicU8::new(PanicPolicy::Swallow as u8);
+
+/// Set process wide policy applied when application or log callbacks panic.
+pub fn set_panic_policy(policy: PanicPolicy) {
+    PANIC_POLICY.store(policy as u8, Ordering::Relaxed);
+}
+
+/// Get process wide policy applied when application or log callbacks panic.
+pub fn panic_policy() -> PanicPolicy {
+    match PANIC_POLICY.load(Ordering::Relaxed) {
+        x if x == PanicPolicy::Disconnect as u8 => PanicPolicy::Disconnect,
+        x if x == PanicPolicy::Abort as u8 => PanicPolicy::Abort,
+        _ => PanicPolicy::Swallow,
+    }
+}
+
+#[cfg(feature = "log")]
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(msg) = payload.downcast_ref::<&str>() {
+        msg
+    } else if let Some(msg) = payload.downcast_ref::<String>() {
+        msg
+    } else {
+        "Box<dyn Any>"
+    }
+}
+
+/// Log caught panic when `log` feature is enabled.
+///
+/// Nothing is written otherwise: process panic hook (by default printing to stderr) has already
+/// run when the panic is caught, use `std::panic::set_hook` to report it elsewhere.
+#[cfg_attr(not(feature = "log"), allow(unused_variables))]
+fn report_panic(callback: &str, session_id: Option<&SessionId>, payload: &(dyn Any + Send)) {
+    #[cfg(feature = "log")]
+    {
+        let session = session_id.map(SessionId::to_repr).unwrap_or_default();
+        let msg = panic_message(payload);
+        log::error!(target: "quickfix", "Callback {callback} panicked [session={session}]: {msg}");
+    }
+}
+
+/// Report panic caught in an application callback and apply policy.
+pub(crate) fn handle_application_panic(
+    callback: &str,
+    session_id: &SessionId,
+    payload: Box<dyn Any + Send>,
+) {
+    report_panic(callback, Some(session_id), payload.as_ref());
+
+    match panic_policy() {
+        PanicPolicy::Swallow => {}
+        PanicPolicy::Disconnect => {
+            // Session may not be registered yet (`on_create`) or already be gone.
+            if let Ok(session) = unsafe { Session::lookup(session_id) } {
+                let _ = session.disconnect();
+            }
+        }
+        PanicPolicy::Abort => process::abort(),
+    }
+}
+
+/// Report panic caught in a log callback and apply policy.
+pub(crate) fn handle_log_panic(
+    callback: &str,
+    session_id: Option<&SessionId>,
+    payload: Box<dyn Any + Send>,
+) {
+    report_panic(callback, session_id, payload.as_ref());
+
+    if panic_policy() == PanicPolicy::Abort {
+        process::abort();
+    }
+}
+
+/// Report panic caught in a message store callback and apply policy.
+///
+/// FIX engine then sees the store operation as failed.
+pub(crate) fn handle_store_panic(
+    callback: &str,
+    session_id: &SessionId,
+    payload: Box<dyn Any + Send>,
+) {
+    report_panic(callback, Some(session_id), payload.as_ref());
+
+    if panic_policy() == PanicPolicy::Abort {
+        process::abort();
+    }
+}
@@ -0,0 +1,134 @@
+use std::collections::HashMap;
+
+use crate::{tags, DataDictionary, FixFieldType, QuickFixError};
+
+/// Limits checked on raw messages before they are parsed.
+///
+/// Messages coming from untrusted counterparties can be of any size: parsing entry points like
+/// [`crate::Message::try_from_text`] reject them with [`QuickFixError::MessageTooLarge`],
+/// [`QuickFixError::FieldTooLarge`] or [`QuickFixError::TooManyGroups`] before quickfix allocates
+/// anything for them.
+///
+/// Limits only apply to messages parsed by this crate API. Session traffic is read and parsed by
+/// the FIX engine itself, without these checks: messages given to [`crate::ApplicationCallback`]
+/// callbacks are not limited.
+///
+/// Default limits reject input previously accepted: use [`ParseLimits::unlimited`] to parse
+/// larger trusted messages as before.
+///
+/// ```rust
+/// # use quickfix::*;
+/// let limits = ParseLimits {
+///     max_field_len: 4,
+///     ..ParseLimits::default()
+/// };
+///
+/// assert!(Message::try_from_text_with_limits("58=hello\x01", &limits).is_err());
+/// assert!(Message::try_from_text_with_limits("58=hey\x01", &limits).is_ok());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParseLimits {
+    /// Maximum message length in bytes, also checked against BodyLength(9) value.
+    pub max_message_len: usize,
+    /// Maximum length of a single field value in bytes.
+    pub max_field_len: usize,
+    /// Maximum number of entries declared by a repeating group count field.
+    ///
+    /// Count fields are only known from a data dictionary, so this limit is not checked when
+    /// parsing without one (and groups are not parsed either).
+    pub max_groups: usize,
+}
+
+impl ParseLimits {
+    /// No limit at all. Only use it with trusted input.
+    pub fn unlimited() -> Self {
+        Self {
+            max_message_len: usize::MAX,
+            max_field_len: usize::MAX,
+            max_groups: usize::MAX,
+        }
+    }
+
+    /// Check `raw` message, knowing group count fields from `dictionary` if any.
+    pub(crate) fn check(
+        &self,
+        raw: &[u8],
+        dictionary: Option<&DataDictionary>,
+    ) -> Result<(), QuickFixError> {
+        if raw.len() > self.max_message_len {
+            return Err(QuickFixError::MessageTooLarge {
+                len: raw.len(),
+                max: self.max_message_len,
+            });
+        }
+
+        let mut is_count_field = HashMap::new();
+        for field in raw.split(|x| *x == b'\x01') {
+            let (tag, value) = match field.iter().position(|x| *x == b'=') {
+                Some(index) => (&field[..index], &field[index + 1..]),
+                None => (field, &[][..]),
+            };
+            let tag = std::str::from_utf8(tag)
+                .ok()
+                .and_then(|tag| tag.parse::<i32>().ok());
+
+            if value.len() > self.max_field_len {
+                return Err(QuickFixError::FieldTooLarge {
+                    tag,
+                    len: value.len(),
+                    max: self.max_field_len,
+                });
+            }
+
+            // Invalid tags and values are left to quickfix to report.
+            let (Some(tag), Some(number)) = (tag, parse_saturating(value)) else {
+                continue;
+            };
+            if tag == tags::BODY_LENGTH && number > self.max_message_len {
+                return Err(QuickFixError::MessageTooLarge {
+                    len: number,
+                    max: self.max_message_len,
+                });
+            }
+            if let Some(dictionary) = dictionary {
+                let is_count = *is_count_field.entry(tag).or_insert_with(|| {
+                    dictionary.field_type(tag) == Some(FixFieldType::NumberInGroup)
+                });
+                if is_count && number > self.max_groups {
+                    return Err(QuickFixError::TooManyGroups {
+                        tag,
+                        count: number,
+                        max: self.max_groups,
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Limits used by parsing functions without explicit limits: 1 MiB messages, 64 KiB fields and
+/// 10 000 entries per repeating group.
+///
+/// Parsing functions had no limit before they were introduced, messages over them now fail.
+impl Default for ParseLimits {
+    fn default() -> Self {
+        Self {
+            max_message_len: 1024 * 1024,
+            max_field_len: 64 * 1024,
+            max_groups: 10_000,
+        }
+    }
+}
+
+/// Parse unsigned decimal `value`, saturating instead of overflowing.
+fn parse_saturating(value: &[u8]) -> Option<usize> {
+    if value.is_empty() || !value.iter().all(u8::is_ascii_digit) {
+        return None;
+    }
+    Some(value.iter().fold(0_usize, |number, digit| {
+        number
+            .saturating_mul(10)
+            .saturating_add(usize::from(digit - b'0'))
+    }))
+}
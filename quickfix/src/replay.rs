@@ -0,0 +1,349 @@
+//! Replay of recorded message logs through a live session.
+//!
+//! Read a QuickFIX message log with [`MessageLogReader`], then send its application messages
+//! again with [`Replayer`]:
+//!
+//! ```no_run
+//! # use quickfix::{replay::*, *};
+//! # fn main() -> Result<(), QuickFixError> {
+//! # let session_id = SessionId::try_new("FIX.4.4", "SENDER", "RECEIVER", "")?;
+//! let reader = MessageLogReader::open("FIX.4.4-SENDER-RECEIVER.messages.current.log")?;
+//! let report = Replayer::new(reader)
+//!     .speed(Speed::Scaled(10.0))
+//!     .filter(|msg| {
+//!         msg.with_header(|h| h.get_field(tags::SENDER_COMP_ID)).as_deref() == Some("SENDER")
+//!     })
+//!     .run(&session_id)?;
+//!
+//! println!("{} messages replayed", report.sent);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    fmt,
+    fs::File,
+    io::{BufRead, BufReader},
+    path::Path,
+    thread,
+    time::{Instant, SystemTime},
+};
+
+use crate::{
+    log_factory::timestamp::parse_utc_timestamp, send_to_target_by_ref_mut, tags,
+    utils::ADMIN_MSG_TYPES, DataDictionary, FieldMap, Message, QuickFixError, SessionId,
+};
+
+/// Separator between timestamp and message in log lines.
+const TIMESTAMP_SEPARATOR: &[u8] = b" : ";
+
+/// Entry of a message log: time it has been logged at and message itself.
+pub type LogEntry = (SystemTime, Message);
+
+/// Log line that cannot be read as a message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MalformedLine {
+    /// Line number, starting at 1.
+    pub line: usize,
+    /// Why line has been rejected.
+    pub reason: String,
+}
+
+impl fmt::Display for MalformedLine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.reason)
+    }
+}
+
+impl std::error::Error for MalformedLine {}
+
+impl From<MalformedLine> for QuickFixError {
+    fn from(err: MalformedLine) -> Self {
+        Self::MessageParseError(err.to_string())
+    }
+}
+
+/// Reader of QuickFIX message logs, as written by `FileLog` or [`FileLogger`](crate::FileLogger).
+///
+/// Every line is a UTC timestamp, ` : ` and a raw FIX message:
+/// `20231114-22:13:20.123 : 8=FIX.4.4|9=...|35=D|...|10=...|`, with SOH separators.
+///
+/// Empty lines are ignored. Lines without timestamp, with an invalid message or without
+/// MsgType(35) are yielded as [`MalformedLine`]. Read errors too, and then end iteration.
+///
+/// Without data dictionary, repeating groups are not parsed: use [`Self::data_dictionary`]
+/// to replay messages containing some.
+pub struct MessageLogReader<'a, R = BufReader<File>> {
+    input: R,
+    dictionary: Option<&'a DataDictionary>,
+    line: usize,
+    done: bool,
+}
+
+impl MessageLogReader<'_> {
+    /// Try to open message log at `path`.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, QuickFixError> {
+        let path = path.as_ref();
+        let file = File::open(path).map_err(|err| {
+            QuickFixError::IOException(format!("Cannot open message log {}: {err}", path.display()))
+        })?;
+        Ok(Self::from_reader(BufReader::new(file)))
+    }
+}
+
+impl<'a, R: BufRead> MessageLogReader<'a, R> {
+    /// Read message log from `input`.
+    pub fn from_reader(input: R) -> Self {
+        Self {
+            input,
+            dictionary: None,
+            line: 0,
+            done: false,
+        }
+    }
+
+    /// Parse messages with `dictionary`, so repeating groups are kept.
+    pub fn data_dictionary(mut self, dictionary: &'a DataDictionary) -> Self {
+        self.dictionary = Some(dictionary);
+        self
+    }
+
+    fn parse(&self, line: &[u8]) -> Result<LogEntry, String> {
+        let index = line
+            .windows(TIMESTAMP_SEPARATOR.len())
+            .position(|window| window == TIMESTAMP_SEPARATOR)
+            .ok_or("missing timestamp")?;
+        let (timestamp, raw) = (&line[..index], &line[index + TIMESTAMP_SEPARATOR.len()..]);
+        let time = std::str::from_utf8(timestamp)
+            .ok()
+            .and_then(parse_utc_timestamp)
+            .ok_or_else(|| format!("invalid timestamp `{}`", String::from_utf8_lossy(timestamp)))?;
+
+        let msg = match self.dictionary {
+            Some(dictionary) => {
+                let text = std::str::from_utf8(raw).map_err(|err| err.to_string())?;
+                dictionary.try_build_message(text)
+            }
+            None => Message::try_from_bytes(raw),
+        }
+        .map_err(|err| err.to_string())?;
+
+        if msg.with_header(|h| h.get_field(tags::MSG_TYPE)).is_none() {
+            return Err("missing MsgType(35)".to_string());
+        }
+        Ok((time, msg))
+    }
+}
+
+impl<R: BufRead> Iterator for MessageLogReader<'_, R> {
+    type Item = Result<LogEntry, MalformedLine>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut buf = Vec::new();
+        while !self.done {
+            buf.clear();
+            self.line += 1;
+            match self.input.read_until(b'\n', &mut buf) {
+                Ok(0) => self.done = true,
+                Ok(_) => {
+                    let line = buf.strip_suffix(b"\n").unwrap_or(&buf);
+                    let line = line.strip_suffix(b"\r").unwrap_or(line);
+                    if line.is_empty() {
+                        continue;
+                    }
+                    return Some(self.parse(line).map_err(|reason| MalformedLine {
+                        line: self.line,
+                        reason,
+                    }));
+                }
+                Err(err) => {
+                    self.done = true;
+                    return Some(Err(MalformedLine {
+                        line: self.line,
+                        reason: format!("cannot read: {err}"),
+                    }));
+                }
+            }
+        }
+        None
+    }
+}
+
+impl<R> fmt::Debug for MessageLogReader<'_, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MessageLogReader")
+            .field("line", &self.line)
+            .field("with_dictionary", &self.dictionary.is_some())
+            .finish()
+    }
+}
+
+/// Pace of a [`Replayer`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Speed {
+    /// Send messages back to back.
+    AsFastAsPossible,
+    /// Wait between messages as long as logged timestamps tell.
+    Realtime,
+    /// Divide logged delays by given factor: `Scaled(2.0)` replays twice as fast as realtime.
+    Scaled(f64),
+}
+
+/// What [`Replayer`] does with a [`MalformedLine`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OnMalformed {
+    /// Report it in [`ReplayReport::malformed`] and continue.
+    #[default]
+    Skip,
+    /// Stop replay with a [`QuickFixError::MessageParseError`].
+    Fail,
+}
+
+/// What has been done by [`Replayer::run`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReplayReport {
+    /// Application messages sent.
+    pub sent: usize,
+    /// Admin messages skipped.
+    pub skipped_admin: usize,
+    /// Application messages dropped by [`Replayer::filter`].
+    pub filtered: usize,
+    /// Malformed lines skipped.
+    pub malformed: Vec<MalformedLine>,
+}
+
+type FilterFn<'a> = Box<dyn FnMut(&Message) -> bool + 'a>;
+type RewriteFn<'a> = Box<dyn FnMut(&mut Message) -> Result<(), QuickFixError> + 'a>;
+
+/// Send again application messages of a message log.
+///
+/// Admin messages (logon, heartbeats, resend requests ...) are skipped: the target session
+/// produces its own. Header is filled by the session like for any other sent message, so
+/// logged BeginString, CompIDs, MsgSeqNum and SendingTime are replaced.
+pub struct Replayer<'a, I> {
+    entries: I,
+    speed: Speed,
+    on_malformed: OnMalformed,
+    filter: Option<FilterFn<'a>>,
+    rewrite: Option<RewriteFn<'a>>,
+}
+
+impl<'a, I> Replayer<'a, I>
+where
+    I: Iterator<Item = Result<LogEntry, MalformedLine>>,
+{
+    /// Replay `entries`, as fast as possible and skipping malformed lines by default.
+    pub fn new(entries: impl IntoIterator<IntoIter = I>) -> Self {
+        Self {
+            entries: entries.into_iter(),
+            speed: Speed::AsFastAsPossible,
+            on_malformed: OnMalformed::default(),
+            filter: None,
+            rewrite: None,
+        }
+    }
+
+    /// Set replay pace.
+    pub fn speed(mut self, speed: Speed) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Set what to do with malformed lines.
+    pub fn on_malformed(mut self, on_malformed: OnMalformed) -> Self {
+        self.on_malformed = on_malformed;
+        self
+    }
+
+    /// Only replay application messages for which `filter` returns `true`.
+    ///
+    /// Message logs contain both directions: use it to keep only one side of the traffic.
+    pub fn filter(mut self, filter: impl FnMut(&Message) -> bool + 'a) -> Self {
+        self.filter = Some(Box::new(filter));
+        self
+    }
+
+    /// Modify every application message right before it is sent, e.g. to rewrite
+    /// OnBehalfOfCompID(115) or ClOrdID(11) values. An error stops the replay.
+    pub fn rewrite(
+        mut self,
+        rewrite: impl FnMut(&mut Message) -> Result<(), QuickFixError> + 'a,
+    ) -> Self {
+        self.rewrite = Some(Box::new(rewrite));
+        self
+    }
+
+    /// Send messages to `session_id`, returning once every entry has been handled.
+    ///
+    /// Session is not required to be logged on: messages are then queued like with any send
+    /// function. Send errors stop the replay.
+    pub fn run(mut self, session_id: &SessionId) -> Result<ReplayReport, QuickFixError> {
+        let factor = match self.speed {
+            Speed::AsFastAsPossible => None,
+            Speed::Realtime => Some(1.0),
+            Speed::Scaled(factor) if factor.is_finite() && factor > 0.0 => Some(factor),
+            Speed::Scaled(factor) => {
+                return Err(QuickFixError::invalid_argument(format!(
+                    "Replay speed factor must be positive, got {factor}"
+                )))
+            }
+        };
+
+        let mut report = ReplayReport::default();
+        let mut origin: Option<(SystemTime, Instant)> = None;
+
+        for entry in self.entries.by_ref() {
+            let (time, mut msg) = match entry {
+                Ok(entry) => entry,
+                Err(malformed) => match self.on_malformed {
+                    OnMalformed::Skip => {
+                        report.malformed.push(malformed);
+                        continue;
+                    }
+                    OnMalformed::Fail => return Err(malformed.into()),
+                },
+            };
+
+            let msg_type = msg.with_header(|h| h.get_field(tags::MSG_TYPE));
+            if msg_type.is_some_and(|msg_type| ADMIN_MSG_TYPES.contains(&msg_type.as_str())) {
+                report.skipped_admin += 1;
+                continue;
+            }
+            if let Some(filter) = &mut self.filter {
+                if !filter(&msg) {
+                    report.filtered += 1;
+                    continue;
+                }
+            }
+            if let Some(rewrite) = &mut self.rewrite {
+                rewrite(&mut msg)?;
+            }
+
+            if let Some(factor) = factor {
+                let (origin_time, origin_instant) = *origin.get_or_insert((time, Instant::now()));
+                // Logs going back in time are sent right away.
+                let delay = time.duration_since(origin_time).unwrap_or_default();
+                let deadline = origin_instant + delay.div_f64(factor);
+                if let Some(wait) = deadline.checked_duration_since(Instant::now()) {
+                    thread::sleep(wait);
+                }
+            }
+
+            send_to_target_by_ref_mut(&mut msg, session_id)?;
+            report.sent += 1;
+        }
+
+        Ok(report)
+    }
+}
+
+impl<I> fmt::Debug for Replayer<'_, I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Replayer")
+            .field("speed", &self.speed)
+            .field("on_malformed", &self.on_malformed)
+            .field("filter", &self.filter.is_some())
+            .field("rewrite", &self.rewrite.is_some())
+            .finish()
+    }
+}
@@ -1,28 +1,340 @@
-use std::{fmt, marker::PhantomData};
+use std::{
+    ffi::{self, CStr, CString},
+    fmt,
+    io::{Read, Write},
+    marker::PhantomData,
+    net::SocketAddr,
+    slice,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
 
 use quickfix_ffi::{
-    FixSession_isLoggedOn, FixSession_logon, FixSession_logout, FixSession_lookup,
-    FixSession_reset, FixSession_send, FixSession_sendToTarget, FixSession_setNextSenderMsgSeqNum,
-    FixSession_setNextTargetMsgSeqNum, FixSession_t,
+    FixMessage_t, FixSessionStats_t, FixSession_disconnect, FixSession_getHeartBtInt,
+    FixSession_getMillisSinceLastReceived, FixSession_getOutboundQueueLen, FixSession_getSessionID,
+    FixSession_getSettings, FixSession_getStats, FixSession_getStoreCreationTime,
+    FixSession_getStoreMetrics, FixSession_getStoredMessages, FixSession_getTransportStats,
+    FixSession_importStore, FixSession_isEnabled, FixSession_isLoggedOn,
+    FixSession_isResendRequested, FixSession_isSessionTime, FixSession_isSessionTimeAt,
+    FixSession_logon, FixSession_logout, FixSession_logoutWithReason, FixSession_lookup,
+    FixSession_next, FixSession_readPeerAddress, FixSession_readSocketOptions, FixSession_refresh,
+    FixSession_requestResend, FixSession_reset, FixSession_send, FixSession_sendAllToTarget,
+    FixSession_sendGapFill, FixSession_sendResolved, FixSession_sendTestRequest,
+    FixSession_sendToTarget, FixSession_sendToTargetWithCompIds, FixSession_setHeartBtInt,
+    FixSession_setNextSenderMsgSeqNum, FixSession_setNextTargetMsgSeqNum,
+    FixSession_setResetOnDisconnect, FixSession_setResetOnLogon, FixSession_setResetOnLogout,
+    FixSession_setSocketOptions, FixSession_t, FixSession_tick, FixSession_trySendToTarget,
+    FixStoreMetrics_t, FixTransportStats_t,
 };
 
 use crate::{
+    store_snapshot::{check_importable, parse_seq_num, StoreSnapshot},
     utils::{ffi_code_to_bool, ffi_code_to_result},
-    Message, QuickFixError, SessionId,
+    Dictionary, Message, QuickFixError, SessionId, SocketOptions,
 };
 
 /// Send message to target design in session ID.
+///
+/// Returns [`QuickFixError::SessionNotFound`] when no session matches `session_id`, its message
+/// containing the looked up BeginString, SenderCompID and TargetCompID (e.g.
+/// `Session Not Found: FIX.4.4:ME->THEM`).
+///
+/// When session exists but is not logged on, message is stored and `Ok(())` is returned:
+/// it is delivered as a PossDup resend once session logs on again. Use [`try_send_to_target`]
+/// to get [`QuickFixError::SessionNotLoggedOn`] instead.
+///
+/// # Performances
+///
+/// Session is looked up in the global quickfix registry, which is guarded by a single mutex, on
+/// first send only: it is then cached in `session_id` and looked up again only once an acceptor /
+/// initiator has been stopped. Reuse the same `SessionId` to benefit from it.
+///
+/// Residual locking happens in C++: every send locks the mutex of its session, so concurrent
+/// sends to the same session are serialized, and the application `on_msg_to_app` callback runs
+/// in the sending thread while this lock is held. Sending to different sessions from different
+/// threads does not contend.
+///
+/// # Thread safety
+///
+/// Every send function can be called concurrently, from any thread, to the same session or to
+/// different ones: C++ registry and sessions are guarded by their own mutexes, errors are reported
+/// per thread, and session cached in `session_id` is read without lock. Application callbacks
+/// are run in sending threads, which is why handlers require them to be `Sync`.
 pub fn send_to_target(msg: Message, session_id: &SessionId) -> Result<(), QuickFixError> {
     // NOTE: Message may be changed by real library. Just consume it so nothing will leak to rust code.
-    ffi_code_to_result(unsafe { FixSession_sendToTarget(msg.0, session_id.0) })
+    timed_send(session_id, || send_cached(msg.0, session_id))
+        .map_err(|err| err.with_session(session_id))
 }
 
 /// Send message to target design in session ID without consuming the message.
+///
+/// Errors, not logged on behavior and performances are the same as [`send_to_target`].
 pub fn send_to_target_by_ref_mut(
     msg: &mut Message,
     session_id: &SessionId,
 ) -> Result<(), QuickFixError> {
-    ffi_code_to_result(unsafe { FixSession_sendToTarget(msg.0, session_id.0) })
+    timed_send(session_id, || send_cached(msg.0, session_id))
+        .map_err(|err| err.with_session(session_id))
+}
+
+/// Run send function, recording its duration and outcome when `metrics` feature is enabled.
+#[cfg_attr(not(feature = "metrics"), allow(unused_variables))]
+fn timed_send<T>(
+    session_id: &SessionId,
+    send: impl FnOnce() -> Result<T, QuickFixError>,
+) -> Result<T, QuickFixError> {
+    #[cfg(feature = "metrics")]
+    let start = std::time::Instant::now();
+    let result = send();
+    #[cfg(feature = "metrics")]
+    crate::engine_metrics::record_send(session_id, start.elapsed(), result.is_err());
+    result
+}
+
+/// Convert `time` to milliseconds since epoch, as expected by C++ timestamps.
+fn epoch_millis(time: SystemTime) -> Result<i64, QuickFixError> {
+    time.duration_since(UNIX_EPOCH)
+        .ok()
+        .and_then(|elapsed| i64::try_from(elapsed.as_millis()).ok())
+        .ok_or_else(|| QuickFixError::invalid_argument("Time must be after unix epoch"))
+}
+
+/// Send using session cached in `session_id`, resolving it again when missing or invalidated.
+///
+/// Cached session is safe to use even if its handler is dropped concurrently, see
+/// [`ResolvedSession`].
+fn send_cached(msg: FixMessage_t, session_id: &SessionId) -> Result<(), QuickFixError> {
+    if let Some(resolved) = session_id.1.get() {
+        match ffi_code_to_result(unsafe {
+            FixSession_sendResolved(resolved.inner, resolved.generation, msg)
+        }) {
+            // Only returned when cached session has been invalidated, message is not sent then.
+            Err(QuickFixError::SessionNotFound(_)) => {}
+            result => return result,
+        }
+    }
+
+    // Slow path is the plain lookup, so errors are the same as without cache.
+    ffi_code_to_result(unsafe { FixSession_sendToTarget(msg, session_id.0) })?;
+    if let Ok(resolved) = session_id.resolve() {
+        session_id.1.store(resolved);
+    }
+    Ok(())
+}
+
+/// Send message to target design in session ID only if session is currently logged on.
+///
+/// Unlike [`send_to_target`], message is dropped instead of being queued for delivery on
+/// next logon, and [`QuickFixError::SessionNotLoggedOn`] is returned.
+pub fn try_send_to_target(msg: Message, session_id: &SessionId) -> Result<(), QuickFixError> {
+    timed_send(session_id, || {
+        ffi_code_to_result(unsafe { FixSession_trySendToTarget(msg.0, session_id.0) })
+    })
+    .map_err(|err| err.with_session(session_id))
+}
+
+/// Snapshot of the key state of a session.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SessionStatus {
+    /// Session is logged on.
+    pub logged_on: bool,
+    /// Next sequence number that will be sent.
+    pub expected_sender_num: i32,
+    /// Next sequence number expected from counterparty.
+    pub expected_target_num: i32,
+    /// Heartbeat interval of the session.
+    pub heartbeat_interval: Duration,
+    /// Current time is inside session time window.
+    pub is_session_time: bool,
+    /// Time elapsed since last message has been received from counterparty.
+    pub since_last_received: Duration,
+}
+
+/// Messages counters of a session.
+///
+/// Counters survive reconnections and are cleared when session is reset.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SessionStats {
+    /// Number of application messages sent.
+    pub sent_app: u64,
+    /// Number of admin messages sent (logon, heartbeat, resend request ...).
+    pub sent_admin: u64,
+    /// Number of application messages received.
+    pub received_app: u64,
+    /// Number of admin messages received.
+    pub received_admin: u64,
+    /// Time of last message received from counterparty.
+    pub last_received_at: Option<SystemTime>,
+    /// Time of last message sent to counterparty.
+    pub last_sent_at: Option<SystemTime>,
+}
+
+impl From<FixSessionStats_t> for SessionStats {
+    fn from(value: FixSessionStats_t) -> Self {
+        let to_time =
+            |millis: i64| (millis > 0).then(|| UNIX_EPOCH + Duration::from_millis(millis as u64));
+
+        Self {
+            sent_app: value.sentApp,
+            sent_admin: value.sentAdmin,
+            received_app: value.receivedApp,
+            received_admin: value.receivedAdmin,
+            last_received_at: to_time(value.lastReceivedAt),
+            last_sent_at: to_time(value.lastSentAt),
+        }
+    }
+}
+
+/// Transport counters of a session.
+///
+/// Unlike [`SessionStats`], counters are never cleared: they keep growing for the whole handler
+/// lifetime, across reconnections and session resets.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TransportStats {
+    /// Number of bytes written to the socket, admin messages included.
+    pub bytes_sent: u64,
+    /// Number of bytes of the messages read from the socket, admin messages included.
+    ///
+    /// Counted on raw data, before quickfix parses it: rejected and garbled messages are counted
+    /// too. Data quickfix cannot frame as a message at all (i.e. not starting with `8=`) is not.
+    pub bytes_received: u64,
+    /// Number of established connections the session has been bound to.
+    ///
+    /// Failed connection attempts are not counted, see
+    /// [`ApplicationCallback::on_connect_failed`](crate::ApplicationCallback::on_connect_failed).
+    pub connect_count: u64,
+    /// Time the last connection of the session has been established: when initiator connect
+    /// completed, or when first message has been read from an accepted connection.
+    pub last_connect_at: Option<SystemTime>,
+}
+
+impl From<FixTransportStats_t> for TransportStats {
+    fn from(value: FixTransportStats_t) -> Self {
+        Self {
+            bytes_sent: value.bytesSent,
+            bytes_received: value.bytesReceived,
+            connect_count: value.connectCount,
+            last_connect_at: (value.lastConnectAt > 0)
+                .then(|| UNIX_EPOCH + Duration::from_millis(value.lastConnectAt as u64)),
+        }
+    }
+}
+
+/// Size of a session store.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct StoreMetrics {
+    /// Number of messages kept in store for resend.
+    pub message_count: u64,
+    /// Size of the files written by a file store, `None` for other stores.
+    pub approx_bytes: Option<u64>,
+}
+
+impl From<FixStoreMetrics_t> for StoreMetrics {
+    fn from(value: FixStoreMetrics_t) -> Self {
+        Self {
+            message_count: value.messageCount,
+            approx_bytes: value.approxBytes.try_into().ok(),
+        }
+    }
+}
+
+/// Message read back from a session store.
+#[derive(Debug)]
+pub enum StoredMessage {
+    /// Stored text parsed as a message.
+    Parsed(Message),
+    /// Stored text which cannot be parsed.
+    Raw(String),
+}
+
+extern "C" fn push_stored_message(output: *mut ffi::c_void, msg: *const ffi::c_char, len: u64) {
+    let output = unsafe { &mut *(output as *mut Vec<String>) };
+    let raw = unsafe { slice::from_raw_parts(msg.cast::<u8>(), len as usize) };
+    output.push(String::from_utf8_lossy(raw).into_owned());
+}
+
+/// Send a batch of messages to target design in session ID without consuming them.
+///
+/// Session is resolved once for the whole batch. Return the number of messages queued: either sent
+/// right away, or persisted for delivery on next logon when session is logged off.
+/// Sending stops at the first message the session refuses (e.g. `on_msg_to_app` returning
+/// [`MsgToAppError::DoNotSend`](crate::MsgToAppError::DoNotSend)).
+pub fn send_all_to_target(
+    msgs: &mut [Message],
+    session_id: &SessionId,
+) -> Result<usize, QuickFixError> {
+    let ffi_msgs: Vec<FixMessage_t> = msgs.iter().map(|msg| msg.0).collect();
+
+    timed_send(session_id, || {
+        unsafe {
+            FixSession_sendAllToTarget(ffi_msgs.as_ptr(), ffi_msgs.len() as u64, session_id.0)
+        }
+        .try_into()
+        .map_err(|_err| QuickFixError::from_last_error())
+    })
+    .map_err(|err| err.with_session(session_id))
+}
+
+/// Send message to the session matching given comp IDs.
+///
+/// BeginString(8), SenderCompID(49) and TargetCompID(56) are set on the message header
+/// before the matching session is looked up.
+pub fn send_to_target_with_comp_ids(
+    msg: Message,
+    begin_string: &str,
+    sender_comp_id: &str,
+    target_comp_id: &str,
+    session_qualifier: Option<&str>,
+) -> Result<(), QuickFixError> {
+    let session_id = SessionId::try_new(
+        begin_string,
+        sender_comp_id,
+        target_comp_id,
+        session_qualifier.unwrap_or_default(),
+    )?;
+    let ffi_begin_string = CString::new(begin_string)?;
+    let ffi_sender_comp_id = CString::new(sender_comp_id)?;
+    let ffi_target_comp_id = CString::new(target_comp_id)?;
+    let ffi_session_qualifier = CString::new(session_qualifier.unwrap_or_default())?;
+
+    timed_send(&session_id, || {
+        ffi_code_to_result(unsafe {
+            FixSession_sendToTargetWithCompIds(
+                msg.0,
+                ffi_begin_string.as_ptr(),
+                ffi_sender_comp_id.as_ptr(),
+                ffi_target_comp_id.as_ptr(),
+                ffi_session_qualifier.as_ptr(),
+            )
+        })
+    })
+    .map_err(|err| err.with_session(&session_id))
+}
+
+/// Session resolved once from its ID, to send messages without looking it up every time.
+///
+/// Handle is invalidated as soon as the acceptor / initiator owning the session is stopped or
+/// dropped, or the session is removed. Sessions of other handlers stay valid.
+/// Sending using an invalid handle returns [`QuickFixError::SessionNotFound`],
+/// [`SessionId::resolve`] must then be called again.
+///
+/// Stopping or dropping a handler, or removing a session, waits for sends in progress, so a session
+/// is never freed while a message is being sent through it.
+#[derive(Debug)]
+pub struct ResolvedSession {
+    pub(crate) inner: FixSession_t,
+    pub(crate) generation: u64,
+}
+
+// SAFETY: session pointer is only used by C++ after checking its generation, under a lock taken
+// exclusively before the session can be deleted.
+unsafe impl Send for ResolvedSession {}
+unsafe impl Sync for ResolvedSession {}
+
+impl ResolvedSession {
+    /// Send message to resolved session without consuming it.
+    pub fn send(&self, msg: &mut Message) -> Result<(), QuickFixError> {
+        ffi_code_to_result(unsafe { FixSession_sendResolved(self.inner, self.generation, msg.0) })
+    }
 }
 
 /// FIX Session.
@@ -53,50 +365,182 @@ impl Session<'static> {
 }
 
 impl Session<'_> {
+    /// Get ID of the session.
+    pub fn session_id(&self) -> Result<SessionId, QuickFixError> {
+        unsafe { FixSession_getSessionID(self.inner) }
+            .map(SessionId::from_ffi)
+            .ok_or_else(QuickFixError::from_last_error)
+    }
+
+    /// Attach ID of the session to error, see [`QuickFixError::with_session`].
+    fn with_context(&self, error: QuickFixError) -> QuickFixError {
+        match self.session_id() {
+            Ok(session_id) => error.with_session(&session_id),
+            Err(_) => error,
+        }
+    }
+
+    fn last_error(&self) -> QuickFixError {
+        // Read error before looking up session ID, which may override it.
+        let error = QuickFixError::from_last_error();
+        self.with_context(error)
+    }
+
+    fn code_to_result(&self, code: i8) -> Result<(), QuickFixError> {
+        ffi_code_to_result(code).map_err(|err| self.with_context(err))
+    }
+
+    fn code_to_bool(&self, code: i8) -> Result<bool, QuickFixError> {
+        ffi_code_to_bool(code).map_err(|err| self.with_context(err))
+    }
+
     /// Force session logout, and disable session.
     pub fn logout(&mut self) -> Result<(), QuickFixError> {
-        ffi_code_to_result(unsafe { FixSession_logout(self.inner) })
+        self.code_to_result(unsafe { FixSession_logout(self.inner) })
+    }
+
+    /// Force session logout with a reason, and disable session.
+    ///
+    /// Reason is sent to the counterparty in the Text(58) field of the Logout message.
+    pub fn logout_with_reason(&mut self, reason: &str) -> Result<(), QuickFixError> {
+        let ffi_reason = CString::new(reason)?;
+        self.code_to_result(unsafe { FixSession_logoutWithReason(self.inner, ffi_reason.as_ptr()) })
     }
 
     /// Check if session is logged on.
     pub fn is_logged_on(&mut self) -> Result<bool, QuickFixError> {
-        ffi_code_to_bool(unsafe { FixSession_isLoggedOn(self.inner) })
+        self.code_to_bool(unsafe { FixSession_isLoggedOn(self.inner) })
     }
 
     /// Send message using current session.
     pub fn send(&mut self, msg: Message) -> Result<bool, QuickFixError> {
-        ffi_code_to_bool(unsafe { FixSession_send(self.inner, msg.0) })
+        self.code_to_bool(unsafe { FixSession_send(self.inner, msg.0) })
     }
 
     /// Send message using current session.
     pub fn send_by_ref_mut(&mut self, msg: &mut Message) -> Result<bool, QuickFixError> {
-        ffi_code_to_bool(unsafe { FixSession_send(self.inner, msg.0) })
+        self.code_to_bool(unsafe { FixSession_send(self.inner, msg.0) })
     }
 
     /// Send message using current session without requiring mutable access to the session handle.
     ///
     /// The underlying QuickFIX session synchronizes concurrent sends internally.
     pub fn send_by_ref(&self, msg: &mut Message) -> Result<bool, QuickFixError> {
-        ffi_code_to_bool(unsafe { FixSession_send(self.inner, msg.0) })
+        self.code_to_bool(unsafe { FixSession_send(self.inner, msg.0) })
     }
 
     /// Reset session by sending a logout & disconnecting, but still keeping the session enabled,
     /// so that logon is retried.
+    ///
+    /// Sequence numbers are set back to 1 and the message store is reset.
     pub fn reset(&mut self) -> Result<(), QuickFixError> {
-        ffi_code_to_result(unsafe { FixSession_reset(self.inner) })
+        self.code_to_result(unsafe { FixSession_reset(self.inner) })
     }
 
     /// Enable session so that logon is sent.
     pub fn logon(&mut self) -> Result<(), QuickFixError> {
-        ffi_code_to_result(unsafe { FixSession_logon(self.inner) })
+        self.code_to_result(unsafe { FixSession_logon(self.inner) })
+    }
+
+    /// Enable or disable session, while its acceptor / initiator keeps running.
+    ///
+    /// Disabling sends a Logout and prevents the session from logging on again: initiators stop
+    /// reconnecting it and acceptors refuse its counterparty logon.
+    /// Enabling resumes normal behavior, initiators reconnect it on next `ReconnectInterval`.
+    pub fn set_enabled(&self, enabled: bool) -> Result<(), QuickFixError> {
+        self.code_to_result(unsafe {
+            if enabled {
+                FixSession_logon(self.inner)
+            } else {
+                FixSession_logout(self.inner)
+            }
+        })
+    }
+
+    /// Check if session is enabled, see `set_enabled`.
+    pub fn is_enabled(&self) -> Result<bool, QuickFixError> {
+        self.code_to_bool(unsafe { FixSession_isEnabled(self.inner) })
+    }
+
+    /// Drop the underlying connection without sending a Logout message.
+    ///
+    /// Session stays enabled, so initiators will reconnect using their configured
+    /// `ReconnectInterval` and sequence numbers are recovered from the message store.
+    pub fn disconnect(&self) -> Result<(), QuickFixError> {
+        self.code_to_result(unsafe { FixSession_disconnect(self.inner) })
+    }
+
+    /// Send a ResendRequest(2) for messages from `begin` to `end` (inclusive).
+    ///
+    /// Use `0` as `end` to request every message after `begin`.
+    /// Replayed messages are then processed as usual by the session.
+    pub fn request_resend(&self, begin: u32, end: u32) -> Result<(), QuickFixError> {
+        let to_seq_num = |value: u32| {
+            i32::try_from(value)
+                .map_err(|_err| QuickFixError::invalid_argument("Sequence number is too large"))
+        };
+        self.code_to_result(unsafe {
+            FixSession_requestResend(self.inner, to_seq_num(begin)?, to_seq_num(end)?)
+        })
+    }
+
+    /// Send a SequenceReset(4) with GapFillFlag(123) and NewSeqNo(36), so counterparty skips
+    /// every sequence number up to `new_seq_no`.
+    ///
+    /// Message uses the next sender sequence number (no PossDupFlag since it is not a replay),
+    /// then the next sender sequence number is moved to `new_seq_no`.
+    pub fn send_gap_fill(&self, new_seq_no: u32) -> Result<(), QuickFixError> {
+        let new_seq_no = i32::try_from(new_seq_no)
+            .map_err(|_err| QuickFixError::invalid_argument("Sequence number is too large"))?;
+        if new_seq_no <= self.get_expected_sender_num() {
+            return Err(QuickFixError::invalid_argument(
+                "NewSeqNo must be greater than next sender sequence number",
+            ));
+        }
+        self.code_to_result(unsafe { FixSession_sendGapFill(self.inner, new_seq_no) })
+    }
+
+    /// Process a raw FIX message as if it was received from the counterparty.
+    ///
+    /// Message goes through the whole session state machine (validation, sequence number
+    /// checks, application callbacks, ...) without using any socket.
+    pub fn process(&self, raw: &str) -> Result<(), QuickFixError> {
+        let ffi_raw = CString::new(raw)?;
+        self.code_to_result(unsafe { FixSession_next(self.inner, ffi_raw.as_ptr()) })
+    }
+
+    /// Run session timer checks as if current time was `now`.
+    ///
+    /// Engine threads run them with the wall clock every second: heartbeats, test requests,
+    /// session time window, logon / logout timeouts. Give it a `Clock` time to drive them from
+    /// tests without waiting, see `ClockedApplication`. Times from the last messages sent /
+    /// received are still taken from the wall clock.
+    pub fn tick(&self, now: SystemTime) -> Result<(), QuickFixError> {
+        self.code_to_result(unsafe { FixSession_tick(self.inner, epoch_millis(now)?) })
+    }
+
+    /// Reload session state (sequence numbers, ...) from the message store.
+    pub fn refresh(&self) -> Result<(), QuickFixError> {
+        self.code_to_result(unsafe { FixSession_refresh(self.inner) })
+    }
+
+    /// Send a TestRequest(1) message with the given TestReqID(112).
+    ///
+    /// Counterparty is expected to answer with a Heartbeat(0) carrying the same TestReqID.
+    /// Return `false` when message has not been sent right away, like [`Self::send`].
+    pub fn send_test_request(&self, test_req_id: &str) -> Result<bool, QuickFixError> {
+        let ffi_test_req_id = CString::new(test_req_id)?;
+        self.code_to_bool(unsafe {
+            FixSession_sendTestRequest(self.inner, ffi_test_req_id.as_ptr())
+        })
     }
     /// Set next sender message sequence number.
     pub fn set_next_sender_msg_seq_num(&mut self, num: i32) -> Result<(), QuickFixError> {
-        ffi_code_to_result(unsafe { FixSession_setNextSenderMsgSeqNum(self.inner, num) })
+        self.code_to_result(unsafe { FixSession_setNextSenderMsgSeqNum(self.inner, num) })
     }
     /// Set next target message sequence number.
     pub fn set_next_target_msg_seq_num(&mut self, num: i32) -> Result<(), QuickFixError> {
-        ffi_code_to_result(unsafe { FixSession_setNextTargetMsgSeqNum(self.inner, num) })
+        self.code_to_result(unsafe { FixSession_setNextTargetMsgSeqNum(self.inner, num) })
     }
     /// Get expected target message sequence number.
     pub fn get_expected_target_num(&self) -> i32 {
@@ -106,6 +550,280 @@ impl Session<'_> {
     pub fn get_expected_sender_num(&self) -> i32 {
         unsafe { quickfix_ffi::FixSession_getExpectedSenderNum(self.inner) }
     }
+
+    /// Check if a ResendRequest(2) has been sent and the gap is not filled yet.
+    ///
+    /// While this is `true`, `get_expected_target_num` lags behind what the counterparty
+    /// has already sent.
+    pub fn has_pending_resend(&self) -> Result<bool, QuickFixError> {
+        self.code_to_bool(unsafe { FixSession_isResendRequested(self.inner) })
+    }
+
+    /// Get number of application messages persisted by the session but not written to the socket
+    /// yet.
+    ///
+    /// This is 0 while logged on, and grows with messages sent while the session is logged off,
+    /// which are stored (when `PersistMessages` is enabled). They are no longer counted once
+    /// written, when counterparty gets them resent after next logon, or once they will never be:
+    /// skipped by a gap fill, or dropped by a sequence reset.
+    pub fn outbound_queue_len(&self) -> Result<usize, QuickFixError> {
+        unsafe { FixSession_getOutboundQueueLen(self.inner) }
+            .try_into()
+            .map_err(|_err| self.last_error())
+    }
+
+    /// Get messages counters of the session.
+    pub fn stats(&self) -> Result<SessionStats, QuickFixError> {
+        let mut stats = FixSessionStats_t::default();
+        self.code_to_result(unsafe { FixSession_getStats(self.inner, &mut stats) })?;
+        Ok(stats.into())
+    }
+
+    /// Get transport counters of the session.
+    pub fn transport_stats(&self) -> Result<TransportStats, QuickFixError> {
+        let mut stats = FixTransportStats_t::default();
+        self.code_to_result(unsafe { FixSession_getTransportStats(self.inner, &mut stats) })?;
+        Ok(stats.into())
+    }
+
+    /// Get settings of the session, merged with the default ones.
+    ///
+    /// This is a copy of the settings the session has been created with, custom keys included.
+    /// It can be read from any callback, including `on_create`.
+    pub fn settings(&self) -> Result<Dictionary, QuickFixError> {
+        unsafe { FixSession_getSettings(self.inner) }
+            .map(Dictionary)
+            .ok_or_else(|| self.last_error())
+    }
+
+    /// Get heartbeat interval of the session in seconds.
+    pub fn heartbeat_interval(&self) -> Result<u32, QuickFixError> {
+        unsafe { FixSession_getHeartBtInt(self.inner) }
+            .try_into()
+            .map_err(|_err| self.last_error())
+    }
+
+    /// Change heartbeat interval of the session in seconds.
+    ///
+    /// Local heartbeat / test request timers use the new value immediately. Counterparty
+    /// only gets it in the HeartBtInt(108) field of the next Logon sent by an initiator.
+    /// On acceptor side, value is replaced by the one of the next Logon received.
+    pub fn set_heartbeat_interval(&self, secs: u32) -> Result<(), QuickFixError> {
+        let value = secs
+            .try_into()
+            .map_err(|_err| QuickFixError::invalid_argument("Heartbeat interval is too large"))?;
+        self.code_to_result(unsafe { FixSession_setHeartBtInt(self.inner, value) })
+    }
+
+    /// Change if sequence numbers are reset when a Logon is sent or received.
+    ///
+    /// Same as `ResetOnLogon` setting. Next Logon sent by an initiator carries
+    /// ResetSeqNumFlag(141)=Y.
+    pub fn set_reset_on_logon(&self, value: bool) -> Result<(), QuickFixError> {
+        self.code_to_result(unsafe { FixSession_setResetOnLogon(self.inner, value as i8) })
+    }
+
+    /// Change if sequence numbers are reset on logout.
+    ///
+    /// Same as `ResetOnLogout` setting.
+    pub fn set_reset_on_logout(&self, value: bool) -> Result<(), QuickFixError> {
+        self.code_to_result(unsafe { FixSession_setResetOnLogout(self.inner, value as i8) })
+    }
+
+    /// Change if sequence numbers are reset on abnormal disconnection.
+    ///
+    /// Same as `ResetOnDisconnect` setting.
+    pub fn set_reset_on_disconnect(&self, value: bool) -> Result<(), QuickFixError> {
+        self.code_to_result(unsafe { FixSession_setResetOnDisconnect(self.inner, value as i8) })
+    }
+
+    /// Get address of the counterparty, `None` when session is not connected, or connected over a
+    /// unix domain socket.
+    ///
+    /// A connection is visible once its first message is exchanged: from the Logon sent by
+    /// initiators, or the Logon received by acceptors (`on_msg_from_admin` included).
+    pub fn peer_address(&self) -> Result<Option<SocketAddr>, QuickFixError> {
+        // Large enough for any "[IPv6]:port" address.
+        let mut buffer = [0_u8; 64];
+        let connected = self.code_to_bool(unsafe {
+            FixSession_readPeerAddress(self.inner, buffer.as_mut_ptr().cast(), buffer.len() as u64)
+        })?;
+        if !connected {
+            return Ok(None);
+        }
+
+        CStr::from_bytes_until_nul(&buffer)
+            .ok()
+            .and_then(|text| text.to_str().ok())
+            .and_then(|text| text.parse().ok())
+            .map(Some)
+            .ok_or_else(|| QuickFixError::invalid_argument("Invalid peer address"))
+    }
+
+    /// Read messages sent from `begin` to `end` (inclusive) back from the session store.
+    ///
+    /// Messages are returned in sequence order. Messages never stored (e.g. admin ones, or gaps of
+    /// a null store) are skipped.
+    pub fn stored_messages(
+        &self,
+        begin: u32,
+        end: u32,
+    ) -> Result<Vec<StoredMessage>, QuickFixError> {
+        Ok(self
+            .stored_messages_raw(begin, end)?
+            .into_iter()
+            .map(|text| match Message::try_from_text(&text) {
+                Ok(msg) => StoredMessage::Parsed(msg),
+                Err(_) => StoredMessage::Raw(text),
+            })
+            .collect())
+    }
+
+    /// Same as [`Self::stored_messages`], without parsing stored text.
+    ///
+    /// Invalid UTF-8 sequences of stored text are replaced with `U+FFFD`.
+    pub fn stored_messages_raw(&self, begin: u32, end: u32) -> Result<Vec<String>, QuickFixError> {
+        let mut output = Vec::<String>::new();
+        self.code_to_result(unsafe {
+            FixSession_getStoredMessages(
+                self.inner,
+                begin,
+                end,
+                &mut output as *mut Vec<String> as *mut ffi::c_void,
+                push_stored_message,
+            )
+        })?;
+        Ok(output)
+    }
+
+    /// Change socket options of the session.
+    ///
+    /// Options are applied right away when session is connected, and remembered for its next
+    /// connections, until session is removed from its acceptor / initiator. Options not supported
+    /// on the current platform are reported as errors.
+    pub fn set_socket_options(&self, options: SocketOptions) -> Result<(), QuickFixError> {
+        let ffi_options = options.to_ffi()?;
+        self.code_to_result(unsafe { FixSession_setSocketOptions(self.inner, &ffi_options) })
+    }
+
+    /// Read options of the socket the session is connected with, `None` when session is not
+    /// connected.
+    ///
+    /// Options not supported on the current platform are `None`.
+    pub fn socket_options(&self) -> Result<Option<SocketOptions>, QuickFixError> {
+        let mut ffi_options = SocketOptions::default().to_ffi()?;
+        let connected = self
+            .code_to_bool(unsafe { FixSession_readSocketOptions(self.inner, &mut ffi_options) })?;
+        Ok(connected.then(|| ffi_options.into()))
+    }
+
+    /// Check if current time is inside the session StartTime / EndTime window.
+    pub fn is_session_time(&self) -> Result<bool, QuickFixError> {
+        self.code_to_bool(unsafe { FixSession_isSessionTime(self.inner) })
+    }
+
+    /// Same as `is_session_time`, at `now` instead of current time.
+    pub fn is_session_time_at(&self, now: SystemTime) -> Result<bool, QuickFixError> {
+        self.code_to_bool(unsafe { FixSession_isSessionTimeAt(self.inner, epoch_millis(now)?) })
+    }
+
+    /// Get time the session store has been created, or last reset.
+    ///
+    /// Persistent stores keep it across handler restarts, with the precision of their backend
+    /// (e.g. second for file stores).
+    pub fn store_creation_time(&self) -> Result<SystemTime, QuickFixError> {
+        let millis: u64 = unsafe { FixSession_getStoreCreationTime(self.inner) }
+            .try_into()
+            .map_err(|_err| self.last_error())?;
+        Ok(UNIX_EPOCH + Duration::from_millis(millis))
+    }
+
+    /// Write session store content to `writer`: sequence numbers, creation time and every stored
+    /// message.
+    ///
+    /// Output can be loaded with [`Self::import_store`] or
+    /// [`FfiMessageStoreFactory::import_store`](crate::FfiMessageStoreFactory::import_store), e.g.
+    /// to move a session to another host. Format is little endian:
+    /// - magic `QFXSTOR1` (8 bytes),
+    /// - next sender and next target sequence numbers (`u32` each),
+    /// - store creation time in milliseconds since UNIX epoch (`i64`),
+    /// - number of stored messages (`u64`),
+    /// - for every stored message, in sequence order: sequence number (`u32`), text length
+    ///   (`u32`) and text bytes.
+    pub fn export_store(&self, writer: &mut impl Write) -> Result<(), QuickFixError> {
+        let next_sender = self.get_expected_sender_num() as u32;
+        let messages = if next_sender > 1 {
+            self.stored_messages_raw(1, next_sender - 1)?
+        } else {
+            Vec::new()
+        };
+
+        let messages = messages
+            .into_iter()
+            .map(|text| {
+                parse_seq_num(&text)
+                    .map(|seq_num| (seq_num, text))
+                    .ok_or_else(|| {
+                        QuickFixError::invalid_argument("Stored message without MsgSeqNum(34)")
+                    })
+            })
+            .collect::<Result<_, _>>()?;
+
+        StoreSnapshot {
+            next_sender,
+            next_target: self.get_expected_target_num() as u32,
+            creation_time: self.store_creation_time()?,
+            messages,
+        }
+        .write(writer)
+    }
+
+    /// Replace session store content with a snapshot written by [`Self::export_store`].
+    ///
+    /// Store must still be at sequence number 1 on both sides, unless `force` is set. Session
+    /// should not be logged on while importing.
+    pub fn import_store(
+        &mut self,
+        reader: &mut impl Read,
+        force: bool,
+    ) -> Result<(), QuickFixError> {
+        check_importable(
+            self.get_expected_sender_num() as u32,
+            self.get_expected_target_num() as u32,
+            force,
+        )?;
+        let snapshot = StoreSnapshot::read(reader)?;
+        self.code_to_result(
+            snapshot.with_ffi(|ffi_snapshot| unsafe {
+                FixSession_importStore(self.inner, ffi_snapshot)
+            }),
+        )
+    }
+
+    /// Get size of the session store.
+    ///
+    /// Every stored message is read to be counted, so avoid calling it too often on large stores.
+    pub fn store_metrics(&self) -> Result<StoreMetrics, QuickFixError> {
+        let mut metrics = FixStoreMetrics_t::default();
+        self.code_to_result(unsafe { FixSession_getStoreMetrics(self.inner, &mut metrics) })?;
+        Ok(metrics.into())
+    }
+
+    /// Get a snapshot of the session state.
+    pub fn status(&self) -> Result<SessionStatus, QuickFixError> {
+        let since_last_received: u64 = unsafe { FixSession_getMillisSinceLastReceived(self.inner) }
+            .try_into()
+            .map_err(|_err| self.last_error())?;
+
+        Ok(SessionStatus {
+            logged_on: self.code_to_bool(unsafe { FixSession_isLoggedOn(self.inner) })?,
+            expected_sender_num: self.get_expected_sender_num(),
+            expected_target_num: self.get_expected_target_num(),
+            heartbeat_interval: Duration::from_secs(self.heartbeat_interval()?.into()),
+            is_session_time: self.is_session_time()?,
+            since_last_received: Duration::from_millis(since_last_received),
+        })
+    }
 }
 
 unsafe impl Send for Session<'static> {}
@@ -0,0 +1,241 @@
+use std::{
+    collections::HashMap,
+    fmt,
+    net::SocketAddr,
+    sync::{Arc, PoisonError, RwLock},
+};
+
+use crate::{
+    AdminMsgKind, Application, ApplicationCallback, ConnectError, DisconnectReason, LogonContext,
+    LogoutContext, Message, MsgFromAdminError, MsgFromAppError, MsgToAppError, QuickFixError,
+    SessionId,
+};
+
+/// Callbacks of a single session, built by a [`SessionApplications`] factory.
+pub type SessionCallbacks = Arc<dyn ApplicationCallback + Send + Sync>;
+
+type Factory = Box<dyn Fn(&SessionId) -> Box<dyn ApplicationCallback + Send + Sync> + Send + Sync>;
+
+/// Application callbacks dispatching events to one callback instance per session.
+///
+/// Factory is called from `on_create`, and the callbacks it returns handle every following event
+/// of this session only. They are dropped when session is removed from its handler, or with the
+/// application itself once handler is shut down. Events of a removed session are ignored.
+///
+/// ```rust
+/// # use quickfix::*;
+/// struct Counterparty {
+///     name: String,
+/// }
+///
+/// impl ApplicationCallback for Counterparty {}
+///
+/// let application = Application::try_from_factory(|session_id: &SessionId| {
+///     Box::new(Counterparty {
+///         name: session_id.get_target_comp_id().unwrap_or_default(),
+///     })
+/// })?;
+/// # let _ = application;
+/// # Ok::<(), QuickFixError>(())
+/// ```
+pub struct SessionApplications {
+    factory: Factory,
+    sessions: RwLock<HashMap<String, SessionCallbacks>>,
+}
+
+impl SessionApplications {
+    /// Create dispatcher building callbacks of each session with `factory`.
+    pub fn new<F>(factory: F) -> Self
+    where
+        F: Fn(&SessionId) -> Box<dyn ApplicationCallback + Send + Sync> + Send + Sync + 'static,
+    {
+        Self {
+            factory: Box::new(factory),
+            sessions: RwLock::default(),
+        }
+    }
+
+    /// Get callbacks of a session, if it has been created and not removed yet.
+    pub fn get(&self, session_id: &SessionId) -> Option<SessionCallbacks> {
+        self.sessions
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&session_id.to_repr())
+            .cloned()
+    }
+
+    /// Number of sessions with callbacks.
+    pub fn len(&self) -> usize {
+        self.sessions
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .len()
+    }
+
+    /// Check if there is no session with callbacks.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn with<R>(
+        &self,
+        session_id: &SessionId,
+        skipped: R,
+        f: impl FnOnce(&dyn ApplicationCallback) -> R,
+    ) -> R {
+        // Release lock before running callbacks, so they can reach other sessions.
+        match self.get(session_id) {
+            Some(callbacks) => f(callbacks.as_ref()),
+            None => skipped,
+        }
+    }
+}
+
+impl fmt::Debug for SessionApplications {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut sessions: Vec<_> = self
+            .sessions
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .keys()
+            .cloned()
+            .collect();
+        sessions.sort();
+
+        f.debug_struct("SessionApplications")
+            .field("sessions", &sessions)
+            .finish()
+    }
+}
+
+impl ApplicationCallback for SessionApplications {
+    fn on_create(&self, session: &SessionId) {
+        let callbacks: SessionCallbacks = (self.factory)(session).into();
+        self.sessions
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(session.to_repr(), callbacks.clone());
+        callbacks.on_create(session);
+    }
+
+    fn on_logon(&self, session: &SessionId) {
+        self.with(session, (), |callbacks| callbacks.on_logon(session))
+    }
+
+    fn on_logon_with_context(&self, session: &SessionId, context: LogonContext) {
+        self.with(session, (), |callbacks| {
+            callbacks.on_logon_with_context(session, context)
+        })
+    }
+
+    fn on_logout(&self, session: &SessionId) {
+        self.with(session, (), |callbacks| callbacks.on_logout(session))
+    }
+
+    fn on_logout_with_context(&self, session: &SessionId, context: LogoutContext) {
+        self.with(session, (), |callbacks| {
+            callbacks.on_logout_with_context(session, context)
+        })
+    }
+
+    fn on_msg_to_admin(&self, msg: &mut Message, session: &SessionId) {
+        self.with(session, (), |callbacks| {
+            callbacks.on_msg_to_admin(msg, session)
+        })
+    }
+
+    fn on_msg_to_app(&self, msg: &mut Message, session: &SessionId) -> Result<(), MsgToAppError> {
+        self.with(session, Ok(()), |callbacks| {
+            callbacks.on_msg_to_app(msg, session)
+        })
+    }
+
+    fn on_msg_from_admin(
+        &self,
+        msg: Message,
+        session: &SessionId,
+    ) -> Result<(), MsgFromAdminError> {
+        self.with(session, Ok(()), |callbacks| {
+            callbacks.on_msg_from_admin(msg, session)
+        })
+    }
+
+    fn on_msg_from_admin_with_kind(
+        &self,
+        kind: AdminMsgKind,
+        msg: Message,
+        session: &SessionId,
+    ) -> Result<(), MsgFromAdminError> {
+        self.with(session, Ok(()), |callbacks| {
+            callbacks.on_msg_from_admin_with_kind(kind, msg, session)
+        })
+    }
+
+    fn on_msg_from_app(&self, msg: Message, session: &SessionId) -> Result<(), MsgFromAppError> {
+        self.with(session, Ok(()), |callbacks| {
+            callbacks.on_msg_from_app(msg, session)
+        })
+    }
+
+    fn on_resend_begin(&self, session: &SessionId, begin: u32, end: u32) {
+        self.with(session, (), |callbacks| {
+            callbacks.on_resend_begin(session, begin, end)
+        })
+    }
+
+    fn on_resend_complete(&self, session: &SessionId) {
+        self.with(session, (), |callbacks| {
+            callbacks.on_resend_complete(session)
+        })
+    }
+
+    fn on_connect_failed(&self, session: &SessionId, attempt: u32, error: ConnectError) {
+        self.with(session, (), |callbacks| {
+            callbacks.on_connect_failed(session, attempt, error)
+        })
+    }
+
+    fn on_connect(&self, session: &SessionId, peer: Option<SocketAddr>) {
+        self.with(session, (), |callbacks| callbacks.on_connect(session, peer))
+    }
+
+    fn on_disconnect(&self, session: &SessionId, reason: DisconnectReason) {
+        self.with(session, (), |callbacks| {
+            callbacks.on_disconnect(session, reason)
+        })
+    }
+
+    fn on_raw_incoming(&self, session: &SessionId, raw: &[u8]) {
+        self.with(session, (), |callbacks| {
+            callbacks.on_raw_incoming(session, raw)
+        })
+    }
+
+    fn on_raw_outgoing(&self, session: &SessionId, raw: &[u8]) {
+        self.with(session, (), |callbacks| {
+            callbacks.on_raw_outgoing(session, raw)
+        })
+    }
+
+    fn on_remove(&self, session: &SessionId) {
+        let callbacks = self
+            .sessions
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(&session.to_repr());
+        if let Some(callbacks) = callbacks {
+            callbacks.on_remove(session);
+        }
+    }
+}
+
+impl Application<'static, SessionApplications> {
+    /// Create application building dedicated callbacks for every session, see
+    /// [`SessionApplications`].
+    pub fn try_from_factory<F>(factory: F) -> Result<Self, QuickFixError>
+    where
+        F: Fn(&SessionId) -> Box<dyn ApplicationCallback + Send + Sync> + Send + Sync + 'static,
+    {
+        Self::try_new_shared(Arc::new(SessionApplications::new(factory)))
+    }
+}
@@ -1,20 +1,38 @@
-use std::{ffi::CString, fmt};
+use std::{
+    ffi::CString,
+    fmt,
+    mem::ManuallyDrop,
+    sync::{PoisonError, RwLock},
+};
 
 use quickfix_ffi::{
     FixSessionID_copy, FixSessionID_delete, FixSessionID_getBeginString,
     FixSessionID_getSenderCompID, FixSessionID_getSessionQualifier, FixSessionID_getTargetCompID,
     FixSessionID_isFIXT, FixSessionID_new, FixSessionID_t, FixSessionID_toString,
+    FixSession_resolve, FixSession_t,
 };
 
-use crate::{utils::read_checked_cstr, QuickFixError};
+use crate::{utils::read_checked_cstr, QuickFixError, ResolvedSession};
 
 /// Unique session id consists of BeginString, SenderCompID and TargetCompID.
-pub struct SessionId(pub(crate) FixSessionID_t);
+pub struct SessionId(pub(crate) FixSessionID_t, pub(crate) ResolvedCache);
 
 unsafe impl Send for SessionId {}
 unsafe impl Sync for SessionId {}
 
 impl SessionId {
+    /// Take ownership of a session ID allocated by C++.
+    pub(crate) fn from_ffi(inner: FixSessionID_t) -> Self {
+        Self(inner, ResolvedCache::new(true))
+    }
+
+    /// Wrap a session ID lent by C++, which must not be deleted.
+    ///
+    /// Resolved session is not cached, since wrapper is never dropped.
+    pub(crate) fn borrow_ffi(inner: FixSessionID_t) -> ManuallyDrop<Self> {
+        ManuallyDrop::new(Self(inner, ResolvedCache::new(false)))
+    }
+
     /// Try create new struct from all its inner components.
     ///
     /// # Panic
@@ -40,7 +58,7 @@ impl SessionId {
                 ffi_session_qualifier.as_ptr(),
             )
         }
-        .map(Self)
+        .map(Self::from_ffi)
         .expect("Fail to allocate SessionId"))
     }
 
@@ -76,11 +94,20 @@ impl SessionId {
             .map(read_checked_cstr)
             .unwrap_or_default()
     }
+
+    /// Resolve matching session once, so it can be used to send many messages.
+    pub fn resolve(&self) -> Result<ResolvedSession, QuickFixError> {
+        let mut generation = 0;
+        match unsafe { FixSession_resolve(self.0, &mut generation) } {
+            Some(inner) => Ok(ResolvedSession { inner, generation }),
+            None => Err(QuickFixError::from_last_error()),
+        }
+    }
 }
 
 impl Clone for SessionId {
     fn clone(&self) -> Self {
-        Self(unsafe { FixSessionID_copy(self.0) }.expect("Fail to copy SessionID"))
+        Self::from_ffi(unsafe { FixSessionID_copy(self.0) }.expect("Fail to copy SessionID"))
     }
 }
 
@@ -90,6 +117,39 @@ impl fmt::Debug for SessionId {
     }
 }
 
+/// Session resolved by previous sends with a session ID, see [`crate::send_to_target`].
+///
+/// Only the session pointer and its generation are kept, and overwritten in place when resolved
+/// again, so nothing accumulates however often handlers are stopped.
+pub(crate) struct ResolvedCache {
+    enabled: bool,
+    current: RwLock<Option<(FixSession_t, u64)>>,
+}
+
+impl ResolvedCache {
+    fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            current: RwLock::new(None),
+        }
+    }
+
+    /// Get last resolved session, which may have been invalidated since.
+    pub(crate) fn get(&self) -> Option<ResolvedSession> {
+        let current = *self.current.read().unwrap_or_else(PoisonError::into_inner);
+        current.map(|(inner, generation)| ResolvedSession { inner, generation })
+    }
+
+    /// Replace last resolved session.
+    pub(crate) fn store(&self, session: ResolvedSession) {
+        if !self.enabled {
+            return;
+        }
+        *self.current.write().unwrap_or_else(PoisonError::into_inner) =
+            Some((session.inner, session.generation));
+    }
+}
+
 impl Drop for SessionId {
     fn drop(&mut self) {
         unsafe { FixSessionID_delete(self.0) }
@@ -1,11 +1,16 @@
-use std::{ffi::CString, fmt, mem::ManuallyDrop, path::Path};
+use std::{env, ffi::CString, fmt, fs, mem::ManuallyDrop, ops::RangeInclusive, path::Path};
 
 use quickfix_ffi::{
-    FixSessionSettings_delete, FixSessionSettings_fromPath, FixSessionSettings_getGlobalRef,
-    FixSessionSettings_getSessionRef, FixSessionSettings_new, FixSessionSettings_setGlobal,
-    FixSessionSettings_setSession, FixSessionSettings_t,
+    FixSessionSettings_copy, FixSessionSettings_delete, FixSessionSettings_fromPath,
+    FixSessionSettings_fromString, FixSessionSettings_getGlobalRef,
+    FixSessionSettings_getSessionCount, FixSessionSettings_getSessionIdAt,
+    FixSessionSettings_getSessionRef, FixSessionSettings_getStringLen, FixSessionSettings_new,
+    FixSessionSettings_readString, FixSessionSettings_setGlobal, FixSessionSettings_setSession,
+    FixSessionSettings_t,
 };
 
+use thiserror::Error;
+
 use crate::{utils::ffi_code_to_result, Dictionary, QuickFixError, SessionId};
 
 /// Container for setting dictionaries mapped to sessions.
@@ -33,6 +38,81 @@ impl SessionSettings {
             .ok_or_else(QuickFixError::from_last_error)
     }
 
+    /// Try to load struct data from INI formatted text.
+    pub fn try_from_str(text: &str) -> Result<Self, QuickFixError> {
+        let ffi_text = CString::new(text)?;
+
+        unsafe { FixSessionSettings_fromString(ffi_text.as_ptr()) }
+            .map(Self)
+            .ok_or_else(QuickFixError::from_last_error)
+    }
+
+    /// Same as [`SessionSettings::try_from_path`] but expand `${VAR}` and `${VAR:-default}`
+    /// patterns from process environment first.
+    pub fn try_from_path_with_env<P: AsRef<Path>>(path: P) -> Result<Self, QuickFixError> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path).map_err(|err| {
+            QuickFixError::ConfigError(format!(
+                "Configuration failed: File {} cannot be read: {err}",
+                path.display()
+            ))
+        })?;
+        Self::try_from_str_with_env(&text)
+    }
+
+    /// Same as [`SessionSettings::try_from_str`] but expand `${VAR}` and `${VAR:-default}`
+    /// patterns from process environment first.
+    ///
+    /// Unset variables without default are reported as [`QuickFixError::ConfigError`].
+    pub fn try_from_str_with_env(text: &str) -> Result<Self, QuickFixError> {
+        Self::try_from_str(&expand_env(text, |name| env::var(name).ok())?)
+    }
+
+    /// Render settings using quickfix INI format, with `[DEFAULT]` and `[SESSION]` sections.
+    ///
+    /// Session keys having the same value as the `[DEFAULT]` section are omitted.
+    pub fn to_string_ini(&self) -> Result<String, QuickFixError> {
+        unsafe {
+            // Prepare output buffer
+            let buffer_len = FixSessionSettings_getStringLen(self.0)
+                .try_into()
+                .map_err(|_err| QuickFixError::from_last_error())?;
+
+            // Allocate buffer on rust side
+            let mut buffer = vec![0_u8; buffer_len as usize];
+            assert_eq!(buffer.len(), buffer_len as usize);
+
+            // Read text
+            ffi_code_to_result(FixSessionSettings_readString(
+                self.0,
+                buffer.as_mut_ptr().cast(),
+                buffer_len,
+            ))?;
+
+            // Convert to String
+            let text = CString::from_vec_with_nul(buffer).unwrap_or_default();
+            Ok(text.to_string_lossy().to_string())
+        }
+    }
+
+    /// Write settings to path using quickfix INI format.
+    pub fn write_to_path<P: AsRef<Path>>(&self, path: P) -> Result<(), QuickFixError> {
+        fs::write(path, self.to_string_ini()?)
+            .map_err(|err| QuickFixError::IOException(err.to_string()))
+    }
+
+    /// List IDs of every session defined in settings, sorted.
+    pub fn session_ids(&self) -> Vec<SessionId> {
+        let session_count = unsafe { FixSessionSettings_getSessionCount(self.0) }
+            .try_into()
+            .unwrap_or(0);
+
+        (0..session_count)
+            .filter_map(|index| unsafe { FixSessionSettings_getSessionIdAt(self.0, index) })
+            .map(SessionId::from_ffi)
+            .collect()
+    }
+
     /// Borrow inner dictionary for session or global configuration.
     pub fn with_dictionary<T, F>(&self, session_id: Option<&SessionId>, f: F) -> Option<T>
     where
@@ -55,7 +135,82 @@ impl SessionSettings {
         }
     }
 
+    /// Check every session for missing or invalid keys.
+    ///
+    /// All problems found are reported, not only the first one.
+    pub fn validate(&self) -> Result<(), Vec<SettingsError>> {
+        let mut errors = Vec::new();
+        for session_id in self.session_ids() {
+            match self.get(&session_id) {
+                Ok(dict) => validate_session(&session_id, &dict, &mut errors),
+                Err(err) => errors.push(SettingsError::new(&session_id, "", err.to_string())),
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Replace the `[DEFAULT]` section and apply it to every session.
+    ///
+    /// Session values inherited from previous defaults are replaced by the new ones, while
+    /// values set explicitly on sessions are kept. A session value equal to the previous
+    /// default value is considered as inherited.
+    pub fn set_default(&mut self, value: Dictionary) -> Result<(), QuickFixError> {
+        let previous_defaults = self
+            .default_dictionary()?
+            .iter()
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Keep only what sessions define by themselves.
+        let mut sessions = Vec::new();
+        for session_id in self.session_ids() {
+            let mut own_values = Dictionary::new();
+            for entry in self.get(&session_id)?.iter() {
+                let (key, value) = entry?;
+                if !previous_defaults.contains(&(key.clone(), value.clone())) {
+                    own_values.set(&key, value)?;
+                }
+            }
+            sessions.push((session_id, own_values));
+        }
+
+        // Replacing sessions dictionaries merges them again with new defaults.
+        self.set(None, value)?;
+        for (session_id, own_values) in sessions {
+            self.set(Some(&session_id), own_values)?;
+        }
+        Ok(())
+    }
+
+    /// Get a copy of session dictionary, including values inherited from `[DEFAULT]` section.
+    ///
+    /// Returned dictionary can be modified and put back using [`SessionSettings::set`].
+    pub fn get(&self, session_id: &SessionId) -> Result<Dictionary, QuickFixError> {
+        self.session_dictionary(session_id)
+    }
+
+    /// Get a copy of the `[DEFAULT]` section.
+    pub fn default_dictionary(&self) -> Result<Dictionary, QuickFixError> {
+        self.copy_dictionary(None)
+    }
+
+    /// Get a copy of the effective session configuration, ie: session values overlaid on defaults.
+    pub fn session_dictionary(&self, session_id: &SessionId) -> Result<Dictionary, QuickFixError> {
+        self.copy_dictionary(Some(session_id))
+    }
+
+    fn copy_dictionary(&self, session_id: Option<&SessionId>) -> Result<Dictionary, QuickFixError> {
+        self.with_dictionary(session_id, Dictionary::clone)
+            .ok_or_else(QuickFixError::from_last_error)
+    }
+
     /// Set dictionary parameter for session or global configuration.
+    ///
+    /// Setting an already defined session replaces its dictionary.
     pub fn set(
         &mut self,
         session_id: Option<&SessionId>,
@@ -70,6 +225,281 @@ impl SessionSettings {
     }
 }
 
+/// Problem found by [`SessionSettings::validate`].
+#[derive(Debug, Clone, Error)]
+#[error("{session}: {key}: {message}", session = .session_id.to_repr())]
+pub struct SettingsError {
+    /// Session having the problem.
+    pub session_id: SessionId,
+    /// Setting name.
+    pub key: String,
+    /// Problem description.
+    pub message: String,
+}
+
+impl SettingsError {
+    fn new(session_id: &SessionId, key: &str, message: impl Into<String>) -> Self {
+        Self {
+            session_id: session_id.clone(),
+            key: key.to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+const PORT_RANGE: RangeInclusive<i32> = 1..=65535;
+
+fn validate_session(session_id: &SessionId, dict: &Dictionary, errors: &mut Vec<SettingsError>) {
+    let require = |key: &str, errors: &mut Vec<SettingsError>| -> bool {
+        let found = dict.contains(key).unwrap_or(false);
+        if !found {
+            errors.push(SettingsError::new(session_id, key, "missing key"));
+        }
+        found
+    };
+
+    for key in ["BeginString", "SenderCompID", "TargetCompID"] {
+        require(key, errors);
+    }
+
+    let mut connection_keys: Vec<(&str, RangeInclusive<i32>)> = Vec::new();
+    if require("ConnectionType", errors) {
+        match dict.get::<String>("ConnectionType").as_deref() {
+            Ok("acceptor") => connection_keys.push(("SocketAcceptPort", PORT_RANGE)),
+            Ok("initiator") => {
+                require("SocketConnectHost", errors);
+                connection_keys.push(("SocketConnectPort", PORT_RANGE));
+                connection_keys.push(("HeartBtInt", 1..=i32::MAX));
+            }
+            Ok(value) => errors.push(SettingsError::new(
+                session_id,
+                "ConnectionType",
+                format!("invalid value: {value}"),
+            )),
+            Err(err) => errors.push(SettingsError::new(
+                session_id,
+                "ConnectionType",
+                err.to_string(),
+            )),
+        }
+    }
+
+    for (key, range) in connection_keys {
+        match dict.get_int(key) {
+            Ok(value) if range.contains(&value) => {}
+            Ok(value) => errors.push(SettingsError::new(
+                session_id,
+                key,
+                format!("value out of range: {value}"),
+            )),
+            Err(QuickFixError::ConfigError(_)) => {
+                errors.push(SettingsError::new(session_id, key, "missing key"))
+            }
+            Err(err) => errors.push(SettingsError::new(session_id, key, err.to_string())),
+        }
+    }
+
+    for key in ["StartTime", "EndTime"] {
+        match dict.get::<String>(key) {
+            Ok(value) if is_valid_time(&value) => {}
+            Ok(value) => errors.push(SettingsError::new(
+                session_id,
+                key,
+                format!("invalid time: {value}"),
+            )),
+            Err(_) => errors.push(SettingsError::new(session_id, key, "missing key")),
+        }
+    }
+
+    for key in ["StartDay", "EndDay"] {
+        if dict.contains(key).unwrap_or(false) {
+            if let Err(err) = dict.get_day(key) {
+                errors.push(SettingsError::new(session_id, key, err.to_string()));
+            }
+        }
+    }
+}
+
+/// Check text is formatted like `HH:MM:SS` with optional fractional seconds.
+fn is_valid_time(value: &str) -> bool {
+    let parts: Vec<_> = value.split(':').collect();
+    let [hours, minutes, seconds] = parts.as_slice() else {
+        return false;
+    };
+    let (seconds, fraction) = seconds.split_once('.').unwrap_or((seconds, "0"));
+
+    let parse = |text: &str, max: u32| {
+        text.len() == 2 && text.parse::<u32>().is_ok_and(|value| value <= max)
+    };
+    parse(hours, 23)
+        && parse(minutes, 59)
+        && parse(seconds, 60)
+        && !fraction.is_empty()
+        && fraction.chars().all(|c| c.is_ascii_digit())
+}
+
+/// Substitute environment variables line by line, so errors can name section and key.
+fn expand_env<F>(text: &str, lookup: F) -> Result<String, QuickFixError>
+where
+    F: Fn(&str) -> Option<String>,
+{
+    let mut output = String::with_capacity(text.len());
+    let mut section = String::new();
+    let mut section_index = 0;
+
+    for line in text.split_inclusive('\n') {
+        let trimmed = line.trim();
+        if trimmed.starts_with('#') {
+            output.push_str(line);
+            continue;
+        }
+        if trimmed.starts_with('[') {
+            section = trimmed.to_string();
+            section_index += 1;
+            output.push_str(line);
+            continue;
+        }
+
+        let key = trimmed
+            .split_once('=')
+            .map_or("", |(key, _value)| key.trim());
+        let expanded = expand_line(line, &lookup).map_err(|reason| {
+            QuickFixError::ConfigError(format!(
+                "Configuration failed: {reason} (section {section} #{section_index}, key {key})"
+            ))
+        })?;
+        output.push_str(&expanded);
+    }
+
+    Ok(output)
+}
+
+fn expand_line<F>(line: &str, lookup: &F) -> Result<String, String>
+where
+    F: Fn(&str) -> Option<String>,
+{
+    let mut output = String::with_capacity(line.len());
+    let mut rest = line;
+
+    while let Some(start) = rest.find("${") {
+        output.push_str(&rest[..start]);
+        let pattern = &rest[start + 2..];
+        let end = pattern
+            .find('}')
+            .ok_or_else(|| format!("unterminated variable: {}", line.trim()))?;
+
+        let (name, default) = match pattern[..end].split_once(":-") {
+            Some((name, default)) => (name, Some(default)),
+            None => (&pattern[..end], None),
+        };
+        match (lookup(name), default) {
+            (Some(value), _) if !value.is_empty() => output.push_str(&value),
+            (_, Some(default)) => output.push_str(default),
+            (Some(value), None) => output.push_str(&value),
+            (None, None) => return Err(format!("environment variable {name} is not set")),
+        }
+
+        rest = &pattern[end + 1..];
+    }
+    output.push_str(rest);
+
+    Ok(output)
+}
+
+/// Build [`SessionSettings`] programmatically, with validation of required keys.
+///
+/// Errors raised while filling dictionaries are reported by [`SessionSettingsBuilder::build`].
+#[derive(Debug, Default)]
+pub struct SessionSettingsBuilder {
+    defaults: Option<Dictionary>,
+    sessions: Vec<(SessionId, Dictionary)>,
+    error: Option<QuickFixError>,
+}
+
+impl SessionSettingsBuilder {
+    /// Create new empty builder.
+    pub fn new() -> Self {
+        <Self as Default>::default()
+    }
+
+    /// Set dictionary used as `[DEFAULT]` section.
+    pub fn default(mut self, value: Dictionary) -> Self {
+        self.defaults = Some(value);
+        self
+    }
+
+    /// Add a `[SESSION]` section, which is filled by `f`.
+    pub fn session<F>(mut self, session_id: SessionId, f: F) -> Self
+    where
+        F: FnOnce(&mut Dictionary) -> Result<(), QuickFixError>,
+    {
+        let mut dict = Dictionary::new();
+        match f(&mut dict) {
+            Ok(()) => self.sessions.push((session_id, dict)),
+            Err(err) => {
+                self.error.get_or_insert(err);
+            }
+        }
+        self
+    }
+
+    /// Validate every session and build settings.
+    pub fn build(self) -> Result<SessionSettings, QuickFixError> {
+        if let Some(err) = self.error {
+            return Err(err);
+        }
+        for (session_id, dict) in &self.sessions {
+            self.validate(session_id, dict)?;
+        }
+
+        let mut settings = SessionSettings::new();
+        if let Some(defaults) = self.defaults {
+            settings.set(None, defaults)?;
+        }
+        for (session_id, dict) in self.sessions {
+            settings.set(Some(&session_id), dict)?;
+        }
+        Ok(settings)
+    }
+
+    fn validate(&self, session_id: &SessionId, dict: &Dictionary) -> Result<(), QuickFixError> {
+        let connection_type = self.require(session_id, dict, "ConnectionType")?;
+        let connection_keys: &[&str] = match connection_type.as_str() {
+            "acceptor" => &["SocketAcceptPort"],
+            "initiator" => &["SocketConnectHost", "SocketConnectPort", "HeartBtInt"],
+            value => {
+                return Err(QuickFixError::ConfigError(format!(
+                    "{}: invalid value for key ConnectionType: {value}",
+                    session_id.to_repr()
+                )))
+            }
+        };
+
+        for key in ["StartTime", "EndTime"].iter().chain(connection_keys) {
+            self.require(session_id, dict, key)?;
+        }
+        Ok(())
+    }
+
+    fn require(
+        &self,
+        session_id: &SessionId,
+        dict: &Dictionary,
+        key: &str,
+    ) -> Result<String, QuickFixError> {
+        if dict.contains(key)? {
+            return dict.get(key);
+        }
+        match &self.defaults {
+            Some(defaults) if defaults.contains(key)? => defaults.get(key),
+            _ => Err(QuickFixError::ConfigError(format!(
+                "{}: missing key {key}",
+                session_id.to_repr()
+            ))),
+        }
+    }
+}
+
 impl fmt::Debug for SessionSettings {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_tuple("SessionSettings").finish()
@@ -84,6 +514,12 @@ impl Default for SessionSettings {
     }
 }
 
+impl Clone for SessionSettings {
+    fn clone(&self) -> Self {
+        Self(unsafe { FixSessionSettings_copy(self.0) }.expect("Fail to clone SessionSettings"))
+    }
+}
+
 impl Drop for SessionSettings {
     fn drop(&mut self) {
         unsafe { FixSessionSettings_delete(self.0) }
@@ -0,0 +1,112 @@
+use toml::{Table, Value};
+
+use crate::{Dictionary, QuickFixError, SessionId, SessionSettings};
+
+impl SessionSettings {
+    /// Try to load struct data from TOML formatted text.
+    ///
+    /// Expected schema is a `[default]` table and a `[[session]]` array of tables.
+    /// Keys map one-to-one to quickfix setting names, unknown keys are kept as is:
+    ///
+    /// ```toml
+    /// [default]
+    /// ConnectionType = "initiator"
+    /// HeartBtInt = 30
+    ///
+    /// [[session]]
+    /// BeginString = "FIX.4.4"
+    /// SenderCompID = "ME"
+    /// TargetCompID = "THEM"
+    /// SocketConnectHost = "127.0.0.1"
+    /// SocketConnectPort = 5001
+    /// StartTime = "00:00:00"
+    /// EndTime = "23:59:59"
+    /// ```
+    ///
+    /// Strings, integers, floats, booleans (as `Y` / `N`) and times are accepted as values.
+    pub fn try_from_toml(text: &str) -> Result<Self, QuickFixError> {
+        let mut root: Table = text
+            .parse()
+            .map_err(|err| QuickFixError::ConfigError(format!("Invalid TOML: {err}")))?;
+
+        let default_table = match root.remove("default") {
+            Some(Value::Table(table)) => table,
+            Some(_) => return Err(invalid_schema("`default` must be a table")),
+            None => Table::new(),
+        };
+        let sessions = match root.remove("session") {
+            Some(Value::Array(sessions)) => sessions,
+            Some(_) => return Err(invalid_schema("`session` must be an array of tables")),
+            None => Vec::new(),
+        };
+        if let Some(key) = root.keys().next() {
+            return Err(invalid_schema(&format!("unknown section `{key}`")));
+        }
+
+        // Set defaults first, so they are merged into each session.
+        let mut settings = SessionSettings::new();
+        settings.set(None, table_to_dictionary("default", &default_table)?)?;
+
+        let defaults = table_to_dictionary("default", &default_table)?;
+
+        for (index, session) in sessions.iter().enumerate() {
+            let Value::Table(table) = session else {
+                return Err(invalid_schema("`session` must be an array of tables"));
+            };
+
+            let name = format!("session #{index}");
+            let dict = table_to_dictionary(&name, table)?;
+            let session_id = session_id(&name, &dict, &defaults)?;
+            settings.set(Some(&session_id), dict)?;
+        }
+        Ok(settings)
+    }
+}
+
+fn invalid_schema(reason: &str) -> QuickFixError {
+    QuickFixError::ConfigError(format!("Invalid TOML settings: {reason}"))
+}
+
+fn table_to_dictionary(name: &str, table: &Table) -> Result<Dictionary, QuickFixError> {
+    let mut dict = Dictionary::new();
+    for (key, value) in table {
+        let text = match value {
+            Value::String(value) => value.clone(),
+            Value::Integer(value) => value.to_string(),
+            Value::Float(value) => value.to_string(),
+            Value::Boolean(true) => "Y".to_string(),
+            Value::Boolean(false) => "N".to_string(),
+            Value::Datetime(value) => value.to_string(),
+            Value::Array(_) | Value::Table(_) => {
+                return Err(invalid_schema(&format!(
+                    "{name}: unsupported value type for key {key}"
+                )))
+            }
+        };
+        dict.set(key, text)?;
+    }
+    Ok(dict)
+}
+
+fn session_id(
+    name: &str,
+    dict: &Dictionary,
+    defaults: &Dictionary,
+) -> Result<SessionId, QuickFixError> {
+    let get = |key: &str| -> Result<String, QuickFixError> {
+        if dict.contains(key)? {
+            dict.get(key)
+        } else if defaults.contains(key)? {
+            defaults.get(key)
+        } else {
+            Err(invalid_schema(&format!("{name}: missing key {key}")))
+        }
+    };
+
+    SessionId::try_new(
+        &get("BeginString")?,
+        &get("SenderCompID")?,
+        &get("TargetCompID")?,
+        &get("SessionQualifier").unwrap_or_default(),
+    )
+}
@@ -0,0 +1,81 @@
+use std::time::Duration;
+
+use quickfix_ffi::FixSocketOptions_t;
+
+use crate::QuickFixError;
+
+/// Options of the socket a session is connected with.
+///
+/// `None` fields are left unchanged by `Session::set_socket_options`.
+/// Keepalive durations have a one second resolution.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SocketOptions {
+    /// Disable Nagle algorithm (`TCP_NODELAY`).
+    pub nodelay: Option<bool>,
+    /// Send keepalive probes on idle connection (`SO_KEEPALIVE`).
+    pub keepalive: Option<bool>,
+    /// Idle time before first keepalive probe (`TCP_KEEPIDLE`).
+    pub keepalive_idle: Option<Duration>,
+    /// Time between keepalive probes (`TCP_KEEPINTVL`).
+    pub keepalive_interval: Option<Duration>,
+    /// Number of unanswered probes before connection is dropped (`TCP_KEEPCNT`).
+    pub keepalive_count: Option<u32>,
+    /// Socket send buffer size in bytes (`SO_SNDBUF`).
+    ///
+    /// Linux reports twice the requested size when reading it back.
+    pub send_buffer_size: Option<u32>,
+    /// Socket receive buffer size in bytes (`SO_RCVBUF`).
+    ///
+    /// Linux reports twice the requested size when reading it back.
+    pub receive_buffer_size: Option<u32>,
+}
+
+impl SocketOptions {
+    pub(crate) fn to_ffi(self) -> Result<FixSocketOptions_t, QuickFixError> {
+        fn flag(value: Option<bool>) -> i8 {
+            value.map_or(-1, i8::from)
+        }
+
+        fn int<T: TryInto<i32>>(value: Option<T>, name: &str) -> Result<i32, QuickFixError> {
+            value.map_or(Ok(-1), |value| {
+                value
+                    .try_into()
+                    .map_err(|_err| QuickFixError::invalid_argument(format!("{name} is too large")))
+            })
+        }
+
+        Ok(FixSocketOptions_t {
+            nodelay: flag(self.nodelay),
+            keepalive: flag(self.keepalive),
+            keepaliveIdle: int(
+                self.keepalive_idle.map(|value| value.as_secs()),
+                "keepalive_idle",
+            )?,
+            keepaliveInterval: int(
+                self.keepalive_interval.map(|value| value.as_secs()),
+                "keepalive_interval",
+            )?,
+            keepaliveCount: int(self.keepalive_count, "keepalive_count")?,
+            sendBufferSize: int(self.send_buffer_size, "send_buffer_size")?,
+            receiveBufferSize: int(self.receive_buffer_size, "receive_buffer_size")?,
+        })
+    }
+}
+
+impl From<FixSocketOptions_t> for SocketOptions {
+    fn from(value: FixSocketOptions_t) -> Self {
+        let flag = |value: i8| (value >= 0).then_some(value != 0);
+        let int = |value: i32| u32::try_from(value).ok();
+
+        Self {
+            nodelay: flag(value.nodelay),
+            keepalive: flag(value.keepalive),
+            keepalive_idle: int(value.keepaliveIdle).map(|secs| Duration::from_secs(secs.into())),
+            keepalive_interval: int(value.keepaliveInterval)
+                .map(|secs| Duration::from_secs(secs.into())),
+            keepalive_count: int(value.keepaliveCount),
+            send_buffer_size: int(value.sendBufferSize),
+            receive_buffer_size: int(value.receiveBufferSize),
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use std::{
+    ffi::CString,
+    sync::{Arc, Mutex, PoisonError},
+};
+
+use quickfix_ffi::{
+    FixAcceptor_stop, FixAcceptor_stopForce, FixAcceptor_stopWithReason, FixAcceptor_t,
+    FixInitiator_stop, FixInitiator_stopForce, FixInitiator_stopWithReason, FixInitiator_t,
+};
+
+use crate::{utils::ffi_code_to_result, QuickFixError};
+
+#[derive(Debug)]
+pub(crate) enum StopTarget {
+    Acceptor(FixAcceptor_t),
+    Initiator(FixInitiator_t),
+}
+
+// SAFETY: C++ handlers lock their own mutex on stop, so they can be stopped from any thread.
+unsafe impl Send for StopTarget {}
+unsafe impl Sync for StopTarget {}
+
+/// Handle to stop an `Acceptor` / `Initiator` from any thread.
+///
+/// This is mainly useful when the handler is running `ConnectionHandler::block` on another thread.
+/// Handle can outlive its handler: stopping it does nothing once the handler has been dropped.
+#[derive(Debug, Clone)]
+pub struct StopHandle(Arc<Mutex<Option<StopTarget>>>);
+
+impl StopHandle {
+    pub(crate) fn new(target: StopTarget) -> Self {
+        Self(Arc::new(Mutex::new(Some(target))))
+    }
+
+    /// Stop handler.
+    pub fn stop(&self) -> Result<(), QuickFixError> {
+        let target = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        match *target {
+            Some(StopTarget::Acceptor(inner)) => {
+                ffi_code_to_result(unsafe { FixAcceptor_stop(inner) })
+            }
+            Some(StopTarget::Initiator(inner)) => {
+                ffi_code_to_result(unsafe { FixInitiator_stop(inner) })
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Stop handler without waiting for sessions to logout.
+    ///
+    /// See `ConnectionHandler::stop_force`.
+    pub fn stop_force(&self) -> Result<(), QuickFixError> {
+        let target = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        match *target {
+            Some(StopTarget::Acceptor(inner)) => {
+                ffi_code_to_result(unsafe { FixAcceptor_stopForce(inner) })
+            }
+            Some(StopTarget::Initiator(inner)) => {
+                ffi_code_to_result(unsafe { FixInitiator_stopForce(inner) })
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Logout every session with the given reason, then stop handler.
+    pub fn stop_with_reason(&self, reason: &str) -> Result<(), QuickFixError> {
+        let ffi_reason = CString::new(reason)?;
+        let target = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        match *target {
+            Some(StopTarget::Acceptor(inner)) => ffi_code_to_result(unsafe {
+                FixAcceptor_stopWithReason(inner, ffi_reason.as_ptr())
+            }),
+            Some(StopTarget::Initiator(inner)) => ffi_code_to_result(unsafe {
+                FixInitiator_stopWithReason(inner, ffi_reason.as_ptr())
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Detach handle from its handler and run `release` while no other thread can use it.
+    pub(crate) fn detach<F: FnOnce()>(&self, release: F) {
+        let mut target = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        target.take();
+        release();
+    }
+}
@@ -0,0 +1,134 @@
+use std::{
+    io::{self, Read, Write},
+    time::SystemTime,
+};
+
+use quickfix_ffi::{FixStoreRecord_t, FixStoreSnapshot_t};
+
+use crate::{
+    utils::{from_millis, raw_field, to_millis},
+    QuickFixError,
+};
+
+const MAGIC: &[u8; 8] = b"QFXSTOR1";
+
+/// Content of a session store, see `Session::export_store` for its binary format.
+#[derive(Debug)]
+pub(crate) struct StoreSnapshot {
+    pub(crate) next_sender: u32,
+    pub(crate) next_target: u32,
+    pub(crate) creation_time: SystemTime,
+    pub(crate) messages: Vec<(u32, String)>,
+}
+
+fn io_error(err: io::Error) -> QuickFixError {
+    QuickFixError::IOException(format!("Cannot transfer store snapshot: {err}"))
+}
+
+fn read_array<const N: usize>(reader: &mut impl Read) -> Result<[u8; N], QuickFixError> {
+    let mut buffer = [0; N];
+    reader.read_exact(&mut buffer).map_err(io_error)?;
+    Ok(buffer)
+}
+
+/// Extract MsgSeqNum(34) from a raw FIX message.
+pub(crate) fn parse_seq_num(text: &str) -> Option<u32> {
+    raw_field(text, 34)?.parse().ok()
+}
+
+/// Check a snapshot can be imported into a store currently at given sequence numbers.
+pub(crate) fn check_importable(
+    next_sender: u32,
+    next_target: u32,
+    force: bool,
+) -> Result<(), QuickFixError> {
+    if force || (next_sender <= 1 && next_target <= 1) {
+        Ok(())
+    } else {
+        Err(QuickFixError::invalid_argument(format!(
+            "Store is not empty (next sender {next_sender}, next target {next_target}), \
+             use force to overwrite it"
+        )))
+    }
+}
+
+impl StoreSnapshot {
+    pub(crate) fn write(&self, writer: &mut impl Write) -> Result<(), QuickFixError> {
+        let creation_time = to_millis(self.creation_time);
+
+        writer.write_all(MAGIC).map_err(io_error)?;
+        writer
+            .write_all(&self.next_sender.to_le_bytes())
+            .map_err(io_error)?;
+        writer
+            .write_all(&self.next_target.to_le_bytes())
+            .map_err(io_error)?;
+        writer
+            .write_all(&creation_time.to_le_bytes())
+            .map_err(io_error)?;
+        writer
+            .write_all(&(self.messages.len() as u64).to_le_bytes())
+            .map_err(io_error)?;
+
+        for (seq_num, text) in &self.messages {
+            let len = u32::try_from(text.len())
+                .map_err(|_err| QuickFixError::invalid_argument("Stored message is too large"))?;
+            writer.write_all(&seq_num.to_le_bytes()).map_err(io_error)?;
+            writer.write_all(&len.to_le_bytes()).map_err(io_error)?;
+            writer.write_all(text.as_bytes()).map_err(io_error)?;
+        }
+        writer.flush().map_err(io_error)
+    }
+
+    pub(crate) fn read(reader: &mut impl Read) -> Result<Self, QuickFixError> {
+        if &read_array::<8>(reader)? != MAGIC {
+            return Err(QuickFixError::invalid_argument(
+                "Not a store snapshot (invalid magic)",
+            ));
+        }
+        let next_sender = u32::from_le_bytes(read_array(reader)?);
+        let next_target = u32::from_le_bytes(read_array(reader)?);
+        let creation_time = i64::from_le_bytes(read_array(reader)?);
+        let count = u64::from_le_bytes(read_array(reader)?);
+
+        let mut messages = Vec::new();
+        for _ in 0..count {
+            let seq_num = u32::from_le_bytes(read_array(reader)?);
+            let len = u32::from_le_bytes(read_array(reader)?);
+            let mut text = vec![0; len as usize];
+            reader.read_exact(&mut text).map_err(io_error)?;
+            let text = String::from_utf8(text).map_err(|_err| {
+                QuickFixError::invalid_argument("Stored message is not valid UTF-8")
+            })?;
+            messages.push((seq_num, text));
+        }
+
+        Ok(Self {
+            next_sender,
+            next_target,
+            creation_time: from_millis(creation_time),
+            messages,
+        })
+    }
+
+    /// Run `f` with FFI view of the snapshot, borrowing its messages.
+    pub(crate) fn with_ffi<T>(&self, f: impl FnOnce(&FixStoreSnapshot_t) -> T) -> T {
+        let records: Vec<_> = self
+            .messages
+            .iter()
+            .map(|(seq_num, text)| FixStoreRecord_t {
+                seqNum: *seq_num,
+                msg: text.as_ptr().cast(),
+                len: text.len() as u64,
+            })
+            .collect();
+
+        f(&FixStoreSnapshot_t {
+            nextSender: self.next_sender,
+            nextTarget: self.next_target,
+            creationTime: to_millis(self.creation_time),
+            records: records.as_ptr(),
+            recordsLen: records.len() as u64,
+        })
+    }
+}
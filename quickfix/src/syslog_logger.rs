@@ -0,0 +1,288 @@
+use std::{
+    fmt,
+    os::unix::net::UnixDatagram,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use crate::{utils::raw_field, EventSeverity, LogCallback, SessionId};
+
+/// Default socket of the local syslog daemon.
+const SYSLOG_SOCKET: &str = "/dev/log";
+/// Default socket of journald native protocol.
+const JOURNALD_SOCKET: &str = "/run/systemd/journal/socket";
+
+/// Structured data ID of FIX fields, using the enterprise number reserved for documentation.
+const SD_ID: &str = "fix@32473";
+
+const SEVERITY_ERROR: u8 = 3;
+const SEVERITY_WARNING: u8 = 4;
+const SEVERITY_INFO: u8 = 6;
+const SEVERITY_DEBUG: u8 = 7;
+
+/// Syslog facility of [`SyslogLogger`] records.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyslogFacility {
+    /// User-level messages.
+    User,
+    /// Local use 0.
+    Local0,
+    /// Local use 1.
+    Local1,
+    /// Local use 2.
+    Local2,
+    /// Local use 3.
+    Local3,
+    /// Local use 4.
+    Local4,
+    /// Local use 5.
+    Local5,
+    /// Local use 6.
+    Local6,
+    /// Local use 7.
+    Local7,
+}
+
+impl SyslogFacility {
+    fn code(self) -> u8 {
+        match self {
+            Self::User => 1,
+            Self::Local0 => 16,
+            Self::Local1 => 17,
+            Self::Local2 => 18,
+            Self::Local3 => 19,
+            Self::Local4 => 20,
+            Self::Local5 => 21,
+            Self::Local6 => 22,
+            Self::Local7 => 23,
+        }
+    }
+}
+
+/// Wire format of [`SyslogLogger`] records.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyslogFormat {
+    /// RFC 5424 message, FIX fields being sent as structured data.
+    Rfc5424,
+    /// journald native protocol, FIX fields being sent as journal fields.
+    Journald,
+}
+
+/// FIX fields attached to a record.
+struct RecordFields<'a> {
+    session_id: Option<String>,
+    direction: &'a str,
+    msg_type: Option<&'a str>,
+    seq_num: Option<&'a str>,
+}
+
+/// Escape RFC 5424 structured data parameter value.
+fn push_param_value(buffer: &mut String, value: &str) {
+    for c in value.chars() {
+        if matches!(c, '"' | '\\' | ']') {
+            buffer.push('\\');
+        }
+        buffer.push(c);
+    }
+}
+
+/// Append journald native protocol field, using binary encoding when value spans multiple lines.
+fn push_journal_field(buffer: &mut Vec<u8>, name: &str, value: &str) {
+    buffer.extend_from_slice(name.as_bytes());
+    if value.contains('\n') {
+        buffer.push(b'\n');
+        buffer.extend_from_slice(&(value.len() as u64).to_le_bytes());
+    } else {
+        buffer.push(b'=');
+    }
+    buffer.extend_from_slice(value.as_bytes());
+    buffer.push(b'\n');
+}
+
+/// Send FIX logs to local syslog daemon or journald.
+///
+/// Events are logged at `LOG_INFO`, `LOG_WARNING` or `LOG_ERR` severity depending on their
+/// [`EventSeverity`], incoming and outgoing messages at `LOG_DEBUG`.
+/// Every record carries session ID, direction (`event` / `incoming` / `outgoing`) and, for
+/// messages, MsgType(35) and MsgSeqNum(34) as structured data or journal fields (`FIX_SESSION_ID`,
+/// `FIX_DIRECTION`, `FIX_MSG_TYPE`, `FIX_SEQ_NUM`).
+///
+/// Records are sent on a non blocking datagram socket, so a missing or overloaded daemon never
+/// stalls the FIX engine: such records are dropped and counted, see [`Self::dropped_count`].
+pub struct SyslogLogger {
+    ident: String,
+    facility: SyslogFacility,
+    format: SyslogFormat,
+    path: PathBuf,
+    socket: Option<UnixDatagram>,
+    dropped: AtomicU64,
+}
+
+impl SyslogLogger {
+    /// Create new struct sending RFC 5424 records to `/dev/log`, with given `ident` as APP-NAME.
+    pub fn new(ident: impl Into<String>, facility: SyslogFacility) -> Self {
+        Self::with_format(ident.into(), facility, SyslogFormat::Rfc5424, SYSLOG_SOCKET)
+    }
+
+    /// Create new struct sending records to journald native socket, with given `ident` as
+    /// `SYSLOG_IDENTIFIER`.
+    pub fn journald(ident: impl Into<String>, facility: SyslogFacility) -> Self {
+        Self::with_format(
+            ident.into(),
+            facility,
+            SyslogFormat::Journald,
+            JOURNALD_SOCKET,
+        )
+    }
+
+    fn with_format(
+        ident: String,
+        facility: SyslogFacility,
+        format: SyslogFormat,
+        path: &str,
+    ) -> Self {
+        let socket = UnixDatagram::unbound()
+            .and_then(|socket| socket.set_nonblocking(true).map(|()| socket))
+            .ok();
+        Self {
+            ident,
+            facility,
+            format,
+            path: PathBuf::from(path),
+            socket,
+            dropped: AtomicU64::new(0),
+        }
+    }
+
+    /// Send records to socket at `path` instead of the default one.
+    pub fn with_socket_path(mut self, path: impl AsRef<Path>) -> Self {
+        self.path = path.as_ref().to_path_buf();
+        self
+    }
+
+    /// Number of records which could not be sent so far.
+    pub fn dropped_count(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+
+    fn log(&self, severity: u8, fields: RecordFields, msg: &str) {
+        let msg = msg.replace('\x01', "|");
+        let sent = self.socket.as_ref().is_some_and(|socket| {
+            let payload = match self.format {
+                SyslogFormat::Rfc5424 => self.format_rfc5424(severity, &fields, &msg).into_bytes(),
+                SyslogFormat::Journald => self.format_journald(severity, &fields, &msg),
+            };
+            socket.send_to(&payload, &self.path).is_ok()
+        });
+        if !sent {
+            self.dropped.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    fn format_rfc5424(&self, severity: u8, fields: &RecordFields, msg: &str) -> String {
+        // Timestamp and hostname are left to the local daemon.
+        let mut buffer = format!(
+            "<{}>1 - - {} {} {} [{SD_ID}",
+            self.facility.code() * 8 + severity,
+            self.ident,
+            std::process::id(),
+            fields.direction,
+        );
+        let params = [
+            ("session_id", fields.session_id.as_deref()),
+            ("direction", Some(fields.direction)),
+            ("msg_type", fields.msg_type),
+            ("seq_num", fields.seq_num),
+        ];
+        for (name, value) in params {
+            let Some(value) = value else { continue };
+            buffer.push(' ');
+            buffer.push_str(name);
+            buffer.push_str("=\"");
+            push_param_value(&mut buffer, value);
+            buffer.push('"');
+        }
+        buffer.push_str("] ");
+        buffer.push_str(msg);
+        buffer
+    }
+
+    fn format_journald(&self, severity: u8, fields: &RecordFields, msg: &str) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        push_journal_field(&mut buffer, "MESSAGE", msg);
+        push_journal_field(&mut buffer, "PRIORITY", &severity.to_string());
+        push_journal_field(
+            &mut buffer,
+            "SYSLOG_FACILITY",
+            &self.facility.code().to_string(),
+        );
+        push_journal_field(&mut buffer, "SYSLOG_IDENTIFIER", &self.ident);
+        let params = [
+            ("FIX_SESSION_ID", fields.session_id.as_deref()),
+            ("FIX_DIRECTION", Some(fields.direction)),
+            ("FIX_MSG_TYPE", fields.msg_type),
+            ("FIX_SEQ_NUM", fields.seq_num),
+        ];
+        for (name, value) in params {
+            let Some(value) = value else { continue };
+            push_journal_field(&mut buffer, name, value);
+        }
+        buffer
+    }
+
+    fn on_message(&self, session_id: Option<&SessionId>, direction: &str, msg: &str) {
+        let fields = RecordFields {
+            session_id: session_id.map(SessionId::to_repr),
+            direction,
+            msg_type: raw_field(msg, 35),
+            seq_num: raw_field(msg, 34),
+        };
+        self.log(SEVERITY_DEBUG, fields, msg);
+    }
+}
+
+impl LogCallback for SyslogLogger {
+    fn on_incoming(&self, session_id: Option<&SessionId>, msg: &str) {
+        self.on_message(session_id, "incoming", msg);
+    }
+
+    fn on_outgoing(&self, session_id: Option<&SessionId>, msg: &str) {
+        self.on_message(session_id, "outgoing", msg);
+    }
+
+    fn on_event(&self, session_id: Option<&SessionId>, msg: &str) {
+        self.on_event_with_severity(session_id, msg, EventSeverity::infer(msg));
+    }
+
+    fn on_event_with_severity(
+        &self,
+        session_id: Option<&SessionId>,
+        msg: &str,
+        severity: EventSeverity,
+    ) {
+        let fields = RecordFields {
+            session_id: session_id.map(SessionId::to_repr),
+            direction: "event",
+            msg_type: None,
+            seq_num: None,
+        };
+        let severity = match severity {
+            EventSeverity::Info => SEVERITY_INFO,
+            EventSeverity::Warning => SEVERITY_WARNING,
+            EventSeverity::Error => SEVERITY_ERROR,
+        };
+        self.log(severity, fields, msg);
+    }
+}
+
+impl fmt::Debug for SyslogLogger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SyslogLogger")
+            .field("ident", &self.ident)
+            .field("facility", &self.facility)
+            .field("format", &self.format)
+            .field("path", &self.path)
+            .field("dropped", &self.dropped_count())
+            .finish()
+    }
+}
@@ -0,0 +1,2218 @@
+//! Field tag number of every standard FIX field.
+//!
+//! Constants are named after FIX field name, so they can be used without any generated message crate:
+//!
+//! ```
+//! use quickfix::{tags, Message};
+//!
+//! # fn main() -> Result<(), quickfix::QuickFixError> {
+//! let mut msg = Message::new();
+//! msg.set_field(tags::CL_ORD_ID, "ORDER-1")?;
+//! assert_eq!(msg.get_field(tags::CL_ORD_ID).as_deref(), Some("ORDER-1"));
+//! # Ok(())
+//! # }
+//! ```
+
+// Generated by `quickfix-msg-gen/examples/core-constants.rs` from the FIX 5.0 / FIXT 1.1 dictionary.
+// Do not edit by hand.
+
+/// `Account` field.
+pub const ACCOUNT: i32 = 1;
+/// `AdvId` field.
+pub const ADV_ID: i32 = 2;
+/// `AdvRefID` field.
+pub const ADV_REF_ID: i32 = 3;
+/// `AdvSide` field.
+pub const ADV_SIDE: i32 = 4;
+/// `AdvTransType` field.
+pub const ADV_TRANS_TYPE: i32 = 5;
+/// `AvgPx` field.
+pub const AVG_PX: i32 = 6;
+/// `BeginSeqNo` field.
+pub const BEGIN_SEQ_NO: i32 = 7;
+/// `BeginString` field.
+pub const BEGIN_STRING: i32 = 8;
+/// `BodyLength` field.
+pub const BODY_LENGTH: i32 = 9;
+/// `CheckSum` field.
+pub const CHECK_SUM: i32 = 10;
+/// `ClOrdID` field.
+pub const CL_ORD_ID: i32 = 11;
+/// `Commission` field.
+pub const COMMISSION: i32 = 12;
+/// `CommType` field.
+pub const COMM_TYPE: i32 = 13;
+/// `CumQty` field.
+pub const CUM_QTY: i32 = 14;
+/// `Currency` field.
+pub const CURRENCY: i32 = 15;
+/// `EndSeqNo` field.
+pub const END_SEQ_NO: i32 = 16;
+/// `ExecID` field.
+pub const EXEC_ID: i32 = 17;
+/// `ExecInst` field.
+pub const EXEC_INST: i32 = 18;
+/// `ExecRefID` field.
+pub const EXEC_REF_ID: i32 = 19;
+/// `HandlInst` field.
+pub const HANDL_INST: i32 = 21;
+/// `SecurityIDSource` field.
+pub const SECURITY_ID_SOURCE: i32 = 22;
+/// `IOIID` field.
+pub const IOIID: i32 = 23;
+/// `IOIQltyInd` field.
+pub const IOI_QLTY_IND: i32 = 25;
+/// `IOIRefID` field.
+pub const IOI_REF_ID: i32 = 26;
+/// `IOIQty` field.
+pub const IOI_QTY: i32 = 27;
+/// `IOITransType` field.
+pub const IOI_TRANS_TYPE: i32 = 28;
+/// `LastCapacity` field.
+pub const LAST_CAPACITY: i32 = 29;
+/// `LastMkt` field.
+pub const LAST_MKT: i32 = 30;
+/// `LastPx` field.
+pub const LAST_PX: i32 = 31;
+/// `LastQty` field.
+pub const LAST_QTY: i32 = 32;
+/// `NoLinesOfText` field.
+pub const NO_LINES_OF_TEXT: i32 = 33;
+/// `MsgSeqNum` field.
+pub const MSG_SEQ_NUM: i32 = 34;
+/// `MsgType` field.
+pub const MSG_TYPE: i32 = 35;
+/// `NewSeqNo` field.
+pub const NEW_SEQ_NO: i32 = 36;
+/// `OrderID` field.
+pub const ORDER_ID: i32 = 37;
+/// `OrderQty` field.
+pub const ORDER_QTY: i32 = 38;
+/// `OrdStatus` field.
+pub const ORD_STATUS: i32 = 39;
+/// `OrdType` field.
+pub const ORD_TYPE: i32 = 40;
+/// `OrigClOrdID` field.
+pub const ORIG_CL_ORD_ID: i32 = 41;
+/// `OrigTime` field.
+pub const ORIG_TIME: i32 = 42;
+/// `PossDupFlag` field.
+pub const POSS_DUP_FLAG: i32 = 43;
+/// `Price` field.
+pub const PRICE: i32 = 44;
+/// `RefSeqNum` field.
+pub const REF_SEQ_NUM: i32 = 45;
+/// `SecurityID` field.
+pub const SECURITY_ID: i32 = 48;
+/// `SenderCompID` field.
+pub const SENDER_COMP_ID: i32 = 49;
+/// `SenderSubID` field.
+pub const SENDER_SUB_ID: i32 = 50;
+/// `SendingTime` field.
+pub const SENDING_TIME: i32 = 52;
+/// `Quantity` field.
+pub const QUANTITY: i32 = 53;
+/// `Side` field.
+pub const SIDE: i32 = 54;
+/// `Symbol` field.
+pub const SYMBOL: i32 = 55;
+/// `TargetCompID` field.
+pub const TARGET_COMP_ID: i32 = 56;
+/// `TargetSubID` field.
+pub const TARGET_SUB_ID: i32 = 57;
+/// `Text` field.
+pub const TEXT: i32 = 58;
+/// `TimeInForce` field.
+pub const TIME_IN_FORCE: i32 = 59;
+/// `TransactTime` field.
+pub const TRANSACT_TIME: i32 = 60;
+/// `Urgency` field.
+pub const URGENCY: i32 = 61;
+/// `ValidUntilTime` field.
+pub const VALID_UNTIL_TIME: i32 = 62;
+/// `SettlType` field.
+pub const SETTL_TYPE: i32 = 63;
+/// `SettlDate` field.
+pub const SETTL_DATE: i32 = 64;
+/// `SymbolSfx` field.
+pub const SYMBOL_SFX: i32 = 65;
+/// `ListID` field.
+pub const LIST_ID: i32 = 66;
+/// `ListSeqNo` field.
+pub const LIST_SEQ_NO: i32 = 67;
+/// `TotNoOrders` field.
+pub const TOT_NO_ORDERS: i32 = 68;
+/// `ListExecInst` field.
+pub const LIST_EXEC_INST: i32 = 69;
+/// `AllocID` field.
+pub const ALLOC_ID: i32 = 70;
+/// `AllocTransType` field.
+pub const ALLOC_TRANS_TYPE: i32 = 71;
+/// `RefAllocID` field.
+pub const REF_ALLOC_ID: i32 = 72;
+/// `NoOrders` field.
+pub const NO_ORDERS: i32 = 73;
+/// `AvgPxPrecision` field.
+pub const AVG_PX_PRECISION: i32 = 74;
+/// `TradeDate` field.
+pub const TRADE_DATE: i32 = 75;
+/// `PositionEffect` field.
+pub const POSITION_EFFECT: i32 = 77;
+/// `NoAllocs` field.
+pub const NO_ALLOCS: i32 = 78;
+/// `AllocAccount` field.
+pub const ALLOC_ACCOUNT: i32 = 79;
+/// `AllocQty` field.
+pub const ALLOC_QTY: i32 = 80;
+/// `ProcessCode` field.
+pub const PROCESS_CODE: i32 = 81;
+/// `NoRpts` field.
+pub const NO_RPTS: i32 = 82;
+/// `RptSeq` field.
+pub const RPT_SEQ: i32 = 83;
+/// `CxlQty` field.
+pub const CXL_QTY: i32 = 84;
+/// `NoDlvyInst` field.
+pub const NO_DLVY_INST: i32 = 85;
+/// `AllocStatus` field.
+pub const ALLOC_STATUS: i32 = 87;
+/// `AllocRejCode` field.
+pub const ALLOC_REJ_CODE: i32 = 88;
+/// `Signature` field.
+pub const SIGNATURE: i32 = 89;
+/// `SecureDataLen` field.
+pub const SECURE_DATA_LEN: i32 = 90;
+/// `SecureData` field.
+pub const SECURE_DATA: i32 = 91;
+/// `SignatureLength` field.
+pub const SIGNATURE_LENGTH: i32 = 93;
+/// `EmailType` field.
+pub const EMAIL_TYPE: i32 = 94;
+/// `RawDataLength` field.
+pub const RAW_DATA_LENGTH: i32 = 95;
+/// `RawData` field.
+pub const RAW_DATA: i32 = 96;
+/// `PossResend` field.
+pub const POSS_RESEND: i32 = 97;
+/// `EncryptMethod` field.
+pub const ENCRYPT_METHOD: i32 = 98;
+/// `StopPx` field.
+pub const STOP_PX: i32 = 99;
+/// `ExDestination` field.
+pub const EX_DESTINATION: i32 = 100;
+/// `CxlRejReason` field.
+pub const CXL_REJ_REASON: i32 = 102;
+/// `OrdRejReason` field.
+pub const ORD_REJ_REASON: i32 = 103;
+/// `IOIQualifier` field.
+pub const IOI_QUALIFIER: i32 = 104;
+/// `Issuer` field.
+pub const ISSUER: i32 = 106;
+/// `SecurityDesc` field.
+pub const SECURITY_DESC: i32 = 107;
+/// `HeartBtInt` field.
+pub const HEART_BT_INT: i32 = 108;
+/// `MinQty` field.
+pub const MIN_QTY: i32 = 110;
+/// `MaxFloor` field.
+pub const MAX_FLOOR: i32 = 111;
+/// `TestReqID` field.
+pub const TEST_REQ_ID: i32 = 112;
+/// `ReportToExch` field.
+pub const REPORT_TO_EXCH: i32 = 113;
+/// `LocateReqd` field.
+pub const LOCATE_REQD: i32 = 114;
+/// `OnBehalfOfCompID` field.
+pub const ON_BEHALF_OF_COMP_ID: i32 = 115;
+/// `OnBehalfOfSubID` field.
+pub const ON_BEHALF_OF_SUB_ID: i32 = 116;
+/// `QuoteID` field.
+pub const QUOTE_ID: i32 = 117;
+/// `NetMoney` field.
+pub const NET_MONEY: i32 = 118;
+/// `SettlCurrAmt` field.
+pub const SETTL_CURR_AMT: i32 = 119;
+/// `SettlCurrency` field.
+pub const SETTL_CURRENCY: i32 = 120;
+/// `ForexReq` field.
+pub const FOREX_REQ: i32 = 121;
+/// `OrigSendingTime` field.
+pub const ORIG_SENDING_TIME: i32 = 122;
+/// `GapFillFlag` field.
+pub const GAP_FILL_FLAG: i32 = 123;
+/// `NoExecs` field.
+pub const NO_EXECS: i32 = 124;
+/// `ExpireTime` field.
+pub const EXPIRE_TIME: i32 = 126;
+/// `DKReason` field.
+pub const DK_REASON: i32 = 127;
+/// `DeliverToCompID` field.
+pub const DELIVER_TO_COMP_ID: i32 = 128;
+/// `DeliverToSubID` field.
+pub const DELIVER_TO_SUB_ID: i32 = 129;
+/// `IOINaturalFlag` field.
+pub const IOI_NATURAL_FLAG: i32 = 130;
+/// `QuoteReqID` field.
+pub const QUOTE_REQ_ID: i32 = 131;
+/// `BidPx` field.
+pub const BID_PX: i32 = 132;
+/// `OfferPx` field.
+pub const OFFER_PX: i32 = 133;
+/// `BidSize` field.
+pub const BID_SIZE: i32 = 134;
+/// `OfferSize` field.
+pub const OFFER_SIZE: i32 = 135;
+/// `NoMiscFees` field.
+pub const NO_MISC_FEES: i32 = 136;
+/// `MiscFeeAmt` field.
+pub const MISC_FEE_AMT: i32 = 137;
+/// `MiscFeeCurr` field.
+pub const MISC_FEE_CURR: i32 = 138;
+/// `MiscFeeType` field.
+pub const MISC_FEE_TYPE: i32 = 139;
+/// `PrevClosePx` field.
+pub const PREV_CLOSE_PX: i32 = 140;
+/// `ResetSeqNumFlag` field.
+pub const RESET_SEQ_NUM_FLAG: i32 = 141;
+/// `SenderLocationID` field.
+pub const SENDER_LOCATION_ID: i32 = 142;
+/// `TargetLocationID` field.
+pub const TARGET_LOCATION_ID: i32 = 143;
+/// `OnBehalfOfLocationID` field.
+pub const ON_BEHALF_OF_LOCATION_ID: i32 = 144;
+/// `DeliverToLocationID` field.
+pub const DELIVER_TO_LOCATION_ID: i32 = 145;
+/// `NoRelatedSym` field.
+pub const NO_RELATED_SYM: i32 = 146;
+/// `Subject` field.
+pub const SUBJECT: i32 = 147;
+/// `Headline` field.
+pub const HEADLINE: i32 = 148;
+/// `URLLink` field.
+pub const URL_LINK: i32 = 149;
+/// `ExecType` field.
+pub const EXEC_TYPE: i32 = 150;
+/// `LeavesQty` field.
+pub const LEAVES_QTY: i32 = 151;
+/// `CashOrderQty` field.
+pub const CASH_ORDER_QTY: i32 = 152;
+/// `AllocAvgPx` field.
+pub const ALLOC_AVG_PX: i32 = 153;
+/// `AllocNetMoney` field.
+pub const ALLOC_NET_MONEY: i32 = 154;
+/// `SettlCurrFxRate` field.
+pub const SETTL_CURR_FX_RATE: i32 = 155;
+/// `SettlCurrFxRateCalc` field.
+pub const SETTL_CURR_FX_RATE_CALC: i32 = 156;
+/// `NumDaysInterest` field.
+pub const NUM_DAYS_INTEREST: i32 = 157;
+/// `AccruedInterestRate` field.
+pub const ACCRUED_INTEREST_RATE: i32 = 158;
+/// `AccruedInterestAmt` field.
+pub const ACCRUED_INTEREST_AMT: i32 = 159;
+/// `SettlInstMode` field.
+pub const SETTL_INST_MODE: i32 = 160;
+/// `AllocText` field.
+pub const ALLOC_TEXT: i32 = 161;
+/// `SettlInstID` field.
+pub const SETTL_INST_ID: i32 = 162;
+/// `SettlInstTransType` field.
+pub const SETTL_INST_TRANS_TYPE: i32 = 163;
+/// `EmailThreadID` field.
+pub const EMAIL_THREAD_ID: i32 = 164;
+/// `SettlInstSource` field.
+pub const SETTL_INST_SOURCE: i32 = 165;
+/// `SecurityType` field.
+pub const SECURITY_TYPE: i32 = 167;
+/// `EffectiveTime` field.
+pub const EFFECTIVE_TIME: i32 = 168;
+/// `StandInstDbType` field.
+pub const STAND_INST_DB_TYPE: i32 = 169;
+/// `StandInstDbName` field.
+pub const STAND_INST_DB_NAME: i32 = 170;
+/// `StandInstDbID` field.
+pub const STAND_INST_DB_ID: i32 = 171;
+/// `SettlDeliveryType` field.
+pub const SETTL_DELIVERY_TYPE: i32 = 172;
+/// `BidSpotRate` field.
+pub const BID_SPOT_RATE: i32 = 188;
+/// `BidForwardPoints` field.
+pub const BID_FORWARD_POINTS: i32 = 189;
+/// `OfferSpotRate` field.
+pub const OFFER_SPOT_RATE: i32 = 190;
+/// `OfferForwardPoints` field.
+pub const OFFER_FORWARD_POINTS: i32 = 191;
+/// `OrderQty2` field.
+pub const ORDER_QTY_2: i32 = 192;
+/// `SettlDate2` field.
+pub const SETTL_DATE_2: i32 = 193;
+/// `LastSpotRate` field.
+pub const LAST_SPOT_RATE: i32 = 194;
+/// `LastForwardPoints` field.
+pub const LAST_FORWARD_POINTS: i32 = 195;
+/// `AllocLinkID` field.
+pub const ALLOC_LINK_ID: i32 = 196;
+/// `AllocLinkType` field.
+pub const ALLOC_LINK_TYPE: i32 = 197;
+/// `SecondaryOrderID` field.
+pub const SECONDARY_ORDER_ID: i32 = 198;
+/// `NoIOIQualifiers` field.
+pub const NO_IOI_QUALIFIERS: i32 = 199;
+/// `MaturityMonthYear` field.
+pub const MATURITY_MONTH_YEAR: i32 = 200;
+/// `PutOrCall` field.
+pub const PUT_OR_CALL: i32 = 201;
+/// `StrikePrice` field.
+pub const STRIKE_PRICE: i32 = 202;
+/// `CoveredOrUncovered` field.
+pub const COVERED_OR_UNCOVERED: i32 = 203;
+/// `OptAttribute` field.
+pub const OPT_ATTRIBUTE: i32 = 206;
+/// `SecurityExchange` field.
+pub const SECURITY_EXCHANGE: i32 = 207;
+/// `NotifyBrokerOfCredit` field.
+pub const NOTIFY_BROKER_OF_CREDIT: i32 = 208;
+/// `AllocHandlInst` field.
+pub const ALLOC_HANDL_INST: i32 = 209;
+/// `MaxShow` field.
+pub const MAX_SHOW: i32 = 210;
+/// `PegOffsetValue` field.
+pub const PEG_OFFSET_VALUE: i32 = 211;
+/// `XmlDataLen` field.
+pub const XML_DATA_LEN: i32 = 212;
+/// `XmlData` field.
+pub const XML_DATA: i32 = 213;
+/// `SettlInstRefID` field.
+pub const SETTL_INST_REF_ID: i32 = 214;
+/// `NoRoutingIDs` field.
+pub const NO_ROUTING_I_DS: i32 = 215;
+/// `RoutingType` field.
+pub const ROUTING_TYPE: i32 = 216;
+/// `RoutingID` field.
+pub const ROUTING_ID: i32 = 217;
+/// `Spread` field.
+pub const SPREAD: i32 = 218;
+/// `BenchmarkCurveCurrency` field.
+pub const BENCHMARK_CURVE_CURRENCY: i32 = 220;
+/// `BenchmarkCurveName` field.
+pub const BENCHMARK_CURVE_NAME: i32 = 221;
+/// `BenchmarkCurvePoint` field.
+pub const BENCHMARK_CURVE_POINT: i32 = 222;
+/// `CouponRate` field.
+pub const COUPON_RATE: i32 = 223;
+/// `CouponPaymentDate` field.
+pub const COUPON_PAYMENT_DATE: i32 = 224;
+/// `IssueDate` field.
+pub const ISSUE_DATE: i32 = 225;
+/// `RepurchaseTerm` field.
+pub const REPURCHASE_TERM: i32 = 226;
+/// `RepurchaseRate` field.
+pub const REPURCHASE_RATE: i32 = 227;
+/// `Factor` field.
+pub const FACTOR: i32 = 228;
+/// `TradeOriginationDate` field.
+pub const TRADE_ORIGINATION_DATE: i32 = 229;
+/// `ExDate` field.
+pub const EX_DATE: i32 = 230;
+/// `ContractMultiplier` field.
+pub const CONTRACT_MULTIPLIER: i32 = 231;
+/// `NoStipulations` field.
+pub const NO_STIPULATIONS: i32 = 232;
+/// `StipulationType` field.
+pub const STIPULATION_TYPE: i32 = 233;
+/// `StipulationValue` field.
+pub const STIPULATION_VALUE: i32 = 234;
+/// `YieldType` field.
+pub const YIELD_TYPE: i32 = 235;
+/// `Yield` field.
+pub const YIELD: i32 = 236;
+/// `TotalTakedown` field.
+pub const TOTAL_TAKEDOWN: i32 = 237;
+/// `Concession` field.
+pub const CONCESSION: i32 = 238;
+/// `RepoCollateralSecurityType` field.
+pub const REPO_COLLATERAL_SECURITY_TYPE: i32 = 239;
+/// `RedemptionDate` field.
+pub const REDEMPTION_DATE: i32 = 240;
+/// `UnderlyingCouponPaymentDate` field.
+pub const UNDERLYING_COUPON_PAYMENT_DATE: i32 = 241;
+/// `UnderlyingIssueDate` field.
+pub const UNDERLYING_ISSUE_DATE: i32 = 242;
+/// `UnderlyingRepoCollateralSecurityType` field.
+pub const UNDERLYING_REPO_COLLATERAL_SECURITY_TYPE: i32 = 243;
+/// `UnderlyingRepurchaseTerm` field.
+pub const UNDERLYING_REPURCHASE_TERM: i32 = 244;
+/// `UnderlyingRepurchaseRate` field.
+pub const UNDERLYING_REPURCHASE_RATE: i32 = 245;
+/// `UnderlyingFactor` field.
+pub const UNDERLYING_FACTOR: i32 = 246;
+/// `UnderlyingRedemptionDate` field.
+pub const UNDERLYING_REDEMPTION_DATE: i32 = 247;
+/// `LegCouponPaymentDate` field.
+pub const LEG_COUPON_PAYMENT_DATE: i32 = 248;
+/// `LegIssueDate` field.
+pub const LEG_ISSUE_DATE: i32 = 249;
+/// `LegRepoCollateralSecurityType` field.
+pub const LEG_REPO_COLLATERAL_SECURITY_TYPE: i32 = 250;
+/// `LegRepurchaseTerm` field.
+pub const LEG_REPURCHASE_TERM: i32 = 251;
+/// `LegRepurchaseRate` field.
+pub const LEG_REPURCHASE_RATE: i32 = 252;
+/// `LegFactor` field.
+pub const LEG_FACTOR: i32 = 253;
+/// `LegRedemptionDate` field.
+pub const LEG_REDEMPTION_DATE: i32 = 254;
+/// `CreditRating` field.
+pub const CREDIT_RATING: i32 = 255;
+/// `UnderlyingCreditRating` field.
+pub const UNDERLYING_CREDIT_RATING: i32 = 256;
+/// `LegCreditRating` field.
+pub const LEG_CREDIT_RATING: i32 = 257;
+/// `TradedFlatSwitch` field.
+pub const TRADED_FLAT_SWITCH: i32 = 258;
+/// `BasisFeatureDate` field.
+pub const BASIS_FEATURE_DATE: i32 = 259;
+/// `BasisFeaturePrice` field.
+pub const BASIS_FEATURE_PRICE: i32 = 260;
+/// `MDReqID` field.
+pub const MD_REQ_ID: i32 = 262;
+/// `SubscriptionRequestType` field.
+pub const SUBSCRIPTION_REQUEST_TYPE: i32 = 263;
+/// `MarketDepth` field.
+pub const MARKET_DEPTH: i32 = 264;
+/// `MDUpdateType` field.
+pub const MD_UPDATE_TYPE: i32 = 265;
+/// `AggregatedBook` field.
+pub const AGGREGATED_BOOK: i32 = 266;
+/// `NoMDEntryTypes` field.
+pub const NO_MD_ENTRY_TYPES: i32 = 267;
+/// `NoMDEntries` field.
+pub const NO_MD_ENTRIES: i32 = 268;
+/// `MDEntryType` field.
+pub const MD_ENTRY_TYPE: i32 = 269;
+/// `MDEntryPx` field.
+pub const MD_ENTRY_PX: i32 = 270;
+/// `MDEntrySize` field.
+pub const MD_ENTRY_SIZE: i32 = 271;
+/// `MDEntryDate` field.
+pub const MD_ENTRY_DATE: i32 = 272;
+/// `MDEntryTime` field.
+pub const MD_ENTRY_TIME: i32 = 273;
+/// `TickDirection` field.
+pub const TICK_DIRECTION: i32 = 274;
+/// `MDMkt` field.
+pub const MD_MKT: i32 = 275;
+/// `QuoteCondition` field.
+pub const QUOTE_CONDITION: i32 = 276;
+/// `TradeCondition` field.
+pub const TRADE_CONDITION: i32 = 277;
+/// `MDEntryID` field.
+pub const MD_ENTRY_ID: i32 = 278;
+/// `MDUpdateAction` field.
+pub const MD_UPDATE_ACTION: i32 = 279;
+/// `MDEntryRefID` field.
+pub const MD_ENTRY_REF_ID: i32 = 280;
+/// `MDReqRejReason` field.
+pub const MD_REQ_REJ_REASON: i32 = 281;
+/// `MDEntryOriginator` field.
+pub const MD_ENTRY_ORIGINATOR: i32 = 282;
+/// `LocationID` field.
+pub const LOCATION_ID: i32 = 283;
+/// `DeskID` field.
+pub const DESK_ID: i32 = 284;
+/// `DeleteReason` field.
+pub const DELETE_REASON: i32 = 285;
+/// `OpenCloseSettlFlag` field.
+pub const OPEN_CLOSE_SETTL_FLAG: i32 = 286;
+/// `SellerDays` field.
+pub const SELLER_DAYS: i32 = 287;
+/// `MDEntryBuyer` field.
+pub const MD_ENTRY_BUYER: i32 = 288;
+/// `MDEntrySeller` field.
+pub const MD_ENTRY_SELLER: i32 = 289;
+/// `MDEntryPositionNo` field.
+pub const MD_ENTRY_POSITION_NO: i32 = 290;
+/// `FinancialStatus` field.
+pub const FINANCIAL_STATUS: i32 = 291;
+/// `CorporateAction` field.
+pub const CORPORATE_ACTION: i32 = 292;
+/// `DefBidSize` field.
+pub const DEF_BID_SIZE: i32 = 293;
+/// `DefOfferSize` field.
+pub const DEF_OFFER_SIZE: i32 = 294;
+/// `NoQuoteEntries` field.
+pub const NO_QUOTE_ENTRIES: i32 = 295;
+/// `NoQuoteSets` field.
+pub const NO_QUOTE_SETS: i32 = 296;
+/// `QuoteStatus` field.
+pub const QUOTE_STATUS: i32 = 297;
+/// `QuoteCancelType` field.
+pub const QUOTE_CANCEL_TYPE: i32 = 298;
+/// `QuoteEntryID` field.
+pub const QUOTE_ENTRY_ID: i32 = 299;
+/// `QuoteRejectReason` field.
+pub const QUOTE_REJECT_REASON: i32 = 300;
+/// `QuoteResponseLevel` field.
+pub const QUOTE_RESPONSE_LEVEL: i32 = 301;
+/// `QuoteSetID` field.
+pub const QUOTE_SET_ID: i32 = 302;
+/// `QuoteRequestType` field.
+pub const QUOTE_REQUEST_TYPE: i32 = 303;
+/// `TotNoQuoteEntries` field.
+pub const TOT_NO_QUOTE_ENTRIES: i32 = 304;
+/// `UnderlyingSecurityIDSource` field.
+pub const UNDERLYING_SECURITY_ID_SOURCE: i32 = 305;
+/// `UnderlyingIssuer` field.
+pub const UNDERLYING_ISSUER: i32 = 306;
+/// `UnderlyingSecurityDesc` field.
+pub const UNDERLYING_SECURITY_DESC: i32 = 307;
+/// `UnderlyingSecurityExchange` field.
+pub const UNDERLYING_SECURITY_EXCHANGE: i32 = 308;
+/// `UnderlyingSecurityID` field.
+pub const UNDERLYING_SECURITY_ID: i32 = 309;
+/// `UnderlyingSecurityType` field.
+pub const UNDERLYING_SECURITY_TYPE: i32 = 310;
+/// `UnderlyingSymbol` field.
+pub const UNDERLYING_SYMBOL: i32 = 311;
+/// `UnderlyingSymbolSfx` field.
+pub const UNDERLYING_SYMBOL_SFX: i32 = 312;
+/// `UnderlyingMaturityMonthYear` field.
+pub const UNDERLYING_MATURITY_MONTH_YEAR: i32 = 313;
+/// `UnderlyingPutOrCall` field.
+pub const UNDERLYING_PUT_OR_CALL: i32 = 315;
+/// `UnderlyingStrikePrice` field.
+pub const UNDERLYING_STRIKE_PRICE: i32 = 316;
+/// `UnderlyingOptAttribute` field.
+pub const UNDERLYING_OPT_ATTRIBUTE: i32 = 317;
+/// `UnderlyingCurrency` field.
+pub const UNDERLYING_CURRENCY: i32 = 318;
+/// `SecurityReqID` field.
+pub const SECURITY_REQ_ID: i32 = 320;
+/// `SecurityRequestType` field.
+pub const SECURITY_REQUEST_TYPE: i32 = 321;
+/// `SecurityResponseID` field.
+pub const SECURITY_RESPONSE_ID: i32 = 322;
+/// `SecurityResponseType` field.
+pub const SECURITY_RESPONSE_TYPE: i32 = 323;
+/// `SecurityStatusReqID` field.
+pub const SECURITY_STATUS_REQ_ID: i32 = 324;
+/// `UnsolicitedIndicator` field.
+pub const UNSOLICITED_INDICATOR: i32 = 325;
+/// `SecurityTradingStatus` field.
+pub const SECURITY_TRADING_STATUS: i32 = 326;
+/// `HaltReasonChar` field.
+pub const HALT_REASON_CHAR: i32 = 327;
+/// `InViewOfCommon` field.
+pub const IN_VIEW_OF_COMMON: i32 = 328;
+/// `DueToRelated` field.
+pub const DUE_TO_RELATED: i32 = 329;
+/// `BuyVolume` field.
+pub const BUY_VOLUME: i32 = 330;
+/// `SellVolume` field.
+pub const SELL_VOLUME: i32 = 331;
+/// `HighPx` field.
+pub const HIGH_PX: i32 = 332;
+/// `LowPx` field.
+pub const LOW_PX: i32 = 333;
+/// `Adjustment` field.
+pub const ADJUSTMENT: i32 = 334;
+/// `TradSesReqID` field.
+pub const TRAD_SES_REQ_ID: i32 = 335;
+/// `TradingSessionID` field.
+pub const TRADING_SESSION_ID: i32 = 336;
+/// `ContraTrader` field.
+pub const CONTRA_TRADER: i32 = 337;
+/// `TradSesMethod` field.
+pub const TRAD_SES_METHOD: i32 = 338;
+/// `TradSesMode` field.
+pub const TRAD_SES_MODE: i32 = 339;
+/// `TradSesStatus` field.
+pub const TRAD_SES_STATUS: i32 = 340;
+/// `TradSesStartTime` field.
+pub const TRAD_SES_START_TIME: i32 = 341;
+/// `TradSesOpenTime` field.
+pub const TRAD_SES_OPEN_TIME: i32 = 342;
+/// `TradSesPreCloseTime` field.
+pub const TRAD_SES_PRE_CLOSE_TIME: i32 = 343;
+/// `TradSesCloseTime` field.
+pub const TRAD_SES_CLOSE_TIME: i32 = 344;
+/// `TradSesEndTime` field.
+pub const TRAD_SES_END_TIME: i32 = 345;
+/// `NumberOfOrders` field.
+pub const NUMBER_OF_ORDERS: i32 = 346;
+/// `MessageEncoding` field.
+pub const MESSAGE_ENCODING: i32 = 347;
+/// `EncodedIssuerLen` field.
+pub const ENCODED_ISSUER_LEN: i32 = 348;
+/// `EncodedIssuer` field.
+pub const ENCODED_ISSUER: i32 = 349;
+/// `EncodedSecurityDescLen` field.
+pub const ENCODED_SECURITY_DESC_LEN: i32 = 350;
+/// `EncodedSecurityDesc` field.
+pub const ENCODED_SECURITY_DESC: i32 = 351;
+/// `EncodedListExecInstLen` field.
+pub const ENCODED_LIST_EXEC_INST_LEN: i32 = 352;
+/// `EncodedListExecInst` field.
+pub const ENCODED_LIST_EXEC_INST: i32 = 353;
+/// `EncodedTextLen` field.
+pub const ENCODED_TEXT_LEN: i32 = 354;
+/// `EncodedText` field.
+pub const ENCODED_TEXT: i32 = 355;
+/// `EncodedSubjectLen` field.
+pub const ENCODED_SUBJECT_LEN: i32 = 356;
+/// `EncodedSubject` field.
+pub const ENCODED_SUBJECT: i32 = 357;
+/// `EncodedHeadlineLen` field.
+pub const ENCODED_HEADLINE_LEN: i32 = 358;
+/// `EncodedHeadline` field.
+pub const ENCODED_HEADLINE: i32 = 359;
+/// `EncodedAllocTextLen` field.
+pub const ENCODED_ALLOC_TEXT_LEN: i32 = 360;
+/// `EncodedAllocText` field.
+pub const ENCODED_ALLOC_TEXT: i32 = 361;
+/// `EncodedUnderlyingIssuerLen` field.
+pub const ENCODED_UNDERLYING_ISSUER_LEN: i32 = 362;
+/// `EncodedUnderlyingIssuer` field.
+pub const ENCODED_UNDERLYING_ISSUER: i32 = 363;
+/// `EncodedUnderlyingSecurityDescLen` field.
+pub const ENCODED_UNDERLYING_SECURITY_DESC_LEN: i32 = 364;
+/// `EncodedUnderlyingSecurityDesc` field.
+pub const ENCODED_UNDERLYING_SECURITY_DESC: i32 = 365;
+/// `AllocPrice` field.
+pub const ALLOC_PRICE: i32 = 366;
+/// `QuoteSetValidUntilTime` field.
+pub const QUOTE_SET_VALID_UNTIL_TIME: i32 = 367;
+/// `QuoteEntryRejectReason` field.
+pub const QUOTE_ENTRY_REJECT_REASON: i32 = 368;
+/// `LastMsgSeqNumProcessed` field.
+pub const LAST_MSG_SEQ_NUM_PROCESSED: i32 = 369;
+/// `RefTagID` field.
+pub const REF_TAG_ID: i32 = 371;
+/// `RefMsgType` field.
+pub const REF_MSG_TYPE: i32 = 372;
+/// `SessionRejectReason` field.
+pub const SESSION_REJECT_REASON: i32 = 373;
+/// `BidRequestTransType` field.
+pub const BID_REQUEST_TRANS_TYPE: i32 = 374;
+/// `ContraBroker` field.
+pub const CONTRA_BROKER: i32 = 375;
+/// `ComplianceID` field.
+pub const COMPLIANCE_ID: i32 = 376;
+/// `SolicitedFlag` field.
+pub const SOLICITED_FLAG: i32 = 377;
+/// `ExecRestatementReason` field.
+pub const EXEC_RESTATEMENT_REASON: i32 = 378;
+/// `BusinessRejectRefID` field.
+pub const BUSINESS_REJECT_REF_ID: i32 = 379;
+/// `BusinessRejectReason` field.
+pub const BUSINESS_REJECT_REASON: i32 = 380;
+/// `GrossTradeAmt` field.
+pub const GROSS_TRADE_AMT: i32 = 381;
+/// `NoContraBrokers` field.
+pub const NO_CONTRA_BROKERS: i32 = 382;
+/// `MaxMessageSize` field.
+pub const MAX_MESSAGE_SIZE: i32 = 383;
+/// `NoMsgTypes` field.
+pub const NO_MSG_TYPES: i32 = 384;
+/// `MsgDirection` field.
+pub const MSG_DIRECTION: i32 = 385;
+/// `NoTradingSessions` field.
+pub const NO_TRADING_SESSIONS: i32 = 386;
+/// `TotalVolumeTraded` field.
+pub const TOTAL_VOLUME_TRADED: i32 = 387;
+/// `DiscretionInst` field.
+pub const DISCRETION_INST: i32 = 388;
+/// `DiscretionOffsetValue` field.
+pub const DISCRETION_OFFSET_VALUE: i32 = 389;
+/// `BidID` field.
+pub const BID_ID: i32 = 390;
+/// `ClientBidID` field.
+pub const CLIENT_BID_ID: i32 = 391;
+/// `ListName` field.
+pub const LIST_NAME: i32 = 392;
+/// `TotNoRelatedSym` field.
+pub const TOT_NO_RELATED_SYM: i32 = 393;
+/// `BidType` field.
+pub const BID_TYPE: i32 = 394;
+/// `NumTickets` field.
+pub const NUM_TICKETS: i32 = 395;
+/// `SideValue1` field.
+pub const SIDE_VALUE_1: i32 = 396;
+/// `SideValue2` field.
+pub const SIDE_VALUE_2: i32 = 397;
+/// `NoBidDescriptors` field.
+pub const NO_BID_DESCRIPTORS: i32 = 398;
+/// `BidDescriptorType` field.
+pub const BID_DESCRIPTOR_TYPE: i32 = 399;
+/// `BidDescriptor` field.
+pub const BID_DESCRIPTOR: i32 = 400;
+/// `SideValueInd` field.
+pub const SIDE_VALUE_IND: i32 = 401;
+/// `LiquidityPctLow` field.
+pub const LIQUIDITY_PCT_LOW: i32 = 402;
+/// `LiquidityPctHigh` field.
+pub const LIQUIDITY_PCT_HIGH: i32 = 403;
+/// `LiquidityValue` field.
+pub const LIQUIDITY_VALUE: i32 = 404;
+/// `EFPTrackingError` field.
+pub const EFP_TRACKING_ERROR: i32 = 405;
+/// `FairValue` field.
+pub const FAIR_VALUE: i32 = 406;
+/// `OutsideIndexPct` field.
+pub const OUTSIDE_INDEX_PCT: i32 = 407;
+/// `ValueOfFutures` field.
+pub const VALUE_OF_FUTURES: i32 = 408;
+/// `LiquidityIndType` field.
+pub const LIQUIDITY_IND_TYPE: i32 = 409;
+/// `WtAverageLiquidity` field.
+pub const WT_AVERAGE_LIQUIDITY: i32 = 410;
+/// `ExchangeForPhysical` field.
+pub const EXCHANGE_FOR_PHYSICAL: i32 = 411;
+/// `OutMainCntryUIndex` field.
+pub const OUT_MAIN_CNTRY_U_INDEX: i32 = 412;
+/// `CrossPercent` field.
+pub const CROSS_PERCENT: i32 = 413;
+/// `ProgRptReqs` field.
+pub const PROG_RPT_REQS: i32 = 414;
+/// `ProgPeriodInterval` field.
+pub const PROG_PERIOD_INTERVAL: i32 = 415;
+/// `IncTaxInd` field.
+pub const INC_TAX_IND: i32 = 416;
+/// `NumBidders` field.
+pub const NUM_BIDDERS: i32 = 417;
+/// `BidTradeType` field.
+pub const BID_TRADE_TYPE: i32 = 418;
+/// `BasisPxType` field.
+pub const BASIS_PX_TYPE: i32 = 419;
+/// `NoBidComponents` field.
+pub const NO_BID_COMPONENTS: i32 = 420;
+/// `Country` field.
+pub const COUNTRY: i32 = 421;
+/// `TotNoStrikes` field.
+pub const TOT_NO_STRIKES: i32 = 422;
+/// `PriceType` field.
+pub const PRICE_TYPE: i32 = 423;
+/// `DayOrderQty` field.
+pub const DAY_ORDER_QTY: i32 = 424;
+/// `DayCumQty` field.
+pub const DAY_CUM_QTY: i32 = 425;
+/// `DayAvgPx` field.
+pub const DAY_AVG_PX: i32 = 426;
+/// `GTBookingInst` field.
+pub const GT_BOOKING_INST: i32 = 427;
+/// `NoStrikes` field.
+pub const NO_STRIKES: i32 = 428;
+/// `ListStatusType` field.
+pub const LIST_STATUS_TYPE: i32 = 429;
+/// `NetGrossInd` field.
+pub const NET_GROSS_IND: i32 = 430;
+/// `ListOrderStatus` field.
+pub const LIST_ORDER_STATUS: i32 = 431;
+/// `ExpireDate` field.
+pub const EXPIRE_DATE: i32 = 432;
+/// `ListExecInstType` field.
+pub const LIST_EXEC_INST_TYPE: i32 = 433;
+/// `CxlRejResponseTo` field.
+pub const CXL_REJ_RESPONSE_TO: i32 = 434;
+/// `UnderlyingCouponRate` field.
+pub const UNDERLYING_COUPON_RATE: i32 = 435;
+/// `UnderlyingContractMultiplier` field.
+pub const UNDERLYING_CONTRACT_MULTIPLIER: i32 = 436;
+/// `ContraTradeQty` field.
+pub const CONTRA_TRADE_QTY: i32 = 437;
+/// `ContraTradeTime` field.
+pub const CONTRA_TRADE_TIME: i32 = 438;
+/// `LiquidityNumSecurities` field.
+pub const LIQUIDITY_NUM_SECURITIES: i32 = 441;
+/// `MultiLegReportingType` field.
+pub const MULTI_LEG_REPORTING_TYPE: i32 = 442;
+/// `StrikeTime` field.
+pub const STRIKE_TIME: i32 = 443;
+/// `ListStatusText` field.
+pub const LIST_STATUS_TEXT: i32 = 444;
+/// `EncodedListStatusTextLen` field.
+pub const ENCODED_LIST_STATUS_TEXT_LEN: i32 = 445;
+/// `EncodedListStatusText` field.
+pub const ENCODED_LIST_STATUS_TEXT: i32 = 446;
+/// `PartyIDSource` field.
+pub const PARTY_ID_SOURCE: i32 = 447;
+/// `PartyID` field.
+pub const PARTY_ID: i32 = 448;
+/// `NetChgPrevDay` field.
+pub const NET_CHG_PREV_DAY: i32 = 451;
+/// `PartyRole` field.
+pub const PARTY_ROLE: i32 = 452;
+/// `NoPartyIDs` field.
+pub const NO_PARTY_I_DS: i32 = 453;
+/// `NoSecurityAltID` field.
+pub const NO_SECURITY_ALT_ID: i32 = 454;
+/// `SecurityAltID` field.
+pub const SECURITY_ALT_ID: i32 = 455;
+/// `SecurityAltIDSource` field.
+pub const SECURITY_ALT_ID_SOURCE: i32 = 456;
+/// `NoUnderlyingSecurityAltID` field.
+pub const NO_UNDERLYING_SECURITY_ALT_ID: i32 = 457;
+/// `UnderlyingSecurityAltID` field.
+pub const UNDERLYING_SECURITY_ALT_ID: i32 = 458;
+/// `UnderlyingSecurityAltIDSource` field.
+pub const UNDERLYING_SECURITY_ALT_ID_SOURCE: i32 = 459;
+/// `Product` field.
+pub const PRODUCT: i32 = 460;
+/// `CFICode` field.
+pub const CFI_CODE: i32 = 461;
+/// `UnderlyingProduct` field.
+pub const UNDERLYING_PRODUCT: i32 = 462;
+/// `UnderlyingCFICode` field.
+pub const UNDERLYING_CFI_CODE: i32 = 463;
+/// `TestMessageIndicator` field.
+pub const TEST_MESSAGE_INDICATOR: i32 = 464;
+/// `BookingRefID` field.
+pub const BOOKING_REF_ID: i32 = 466;
+/// `IndividualAllocID` field.
+pub const INDIVIDUAL_ALLOC_ID: i32 = 467;
+/// `RoundingDirection` field.
+pub const ROUNDING_DIRECTION: i32 = 468;
+/// `RoundingModulus` field.
+pub const ROUNDING_MODULUS: i32 = 469;
+/// `CountryOfIssue` field.
+pub const COUNTRY_OF_ISSUE: i32 = 470;
+/// `StateOrProvinceOfIssue` field.
+pub const STATE_OR_PROVINCE_OF_ISSUE: i32 = 471;
+/// `LocaleOfIssue` field.
+pub const LOCALE_OF_ISSUE: i32 = 472;
+/// `NoRegistDtls` field.
+pub const NO_REGIST_DTLS: i32 = 473;
+/// `MailingDtls` field.
+pub const MAILING_DTLS: i32 = 474;
+/// `InvestorCountryOfResidence` field.
+pub const INVESTOR_COUNTRY_OF_RESIDENCE: i32 = 475;
+/// `PaymentRef` field.
+pub const PAYMENT_REF: i32 = 476;
+/// `DistribPaymentMethod` field.
+pub const DISTRIB_PAYMENT_METHOD: i32 = 477;
+/// `CashDistribCurr` field.
+pub const CASH_DISTRIB_CURR: i32 = 478;
+/// `CommCurrency` field.
+pub const COMM_CURRENCY: i32 = 479;
+/// `CancellationRights` field.
+pub const CANCELLATION_RIGHTS: i32 = 480;
+/// `MoneyLaunderingStatus` field.
+pub const MONEY_LAUNDERING_STATUS: i32 = 481;
+/// `MailingInst` field.
+pub const MAILING_INST: i32 = 482;
+/// `TransBkdTime` field.
+pub const TRANS_BKD_TIME: i32 = 483;
+/// `ExecPriceType` field.
+pub const EXEC_PRICE_TYPE: i32 = 484;
+/// `ExecPriceAdjustment` field.
+pub const EXEC_PRICE_ADJUSTMENT: i32 = 485;
+/// `DateOfBirth` field.
+pub const DATE_OF_BIRTH: i32 = 486;
+/// `TradeReportTransType` field.
+pub const TRADE_REPORT_TRANS_TYPE: i32 = 487;
+/// `CardHolderName` field.
+pub const CARD_HOLDER_NAME: i32 = 488;
+/// `CardNumber` field.
+pub const CARD_NUMBER: i32 = 489;
+/// `CardExpDate` field.
+pub const CARD_EXP_DATE: i32 = 490;
+/// `CardIssNum` field.
+pub const CARD_ISS_NUM: i32 = 491;
+/// `PaymentMethod` field.
+pub const PAYMENT_METHOD: i32 = 492;
+/// `RegistAcctType` field.
+pub const REGIST_ACCT_TYPE: i32 = 493;
+/// `Designation` field.
+pub const DESIGNATION: i32 = 494;
+/// `TaxAdvantageType` field.
+pub const TAX_ADVANTAGE_TYPE: i32 = 495;
+/// `RegistRejReasonText` field.
+pub const REGIST_REJ_REASON_TEXT: i32 = 496;
+/// `FundRenewWaiv` field.
+pub const FUND_RENEW_WAIV: i32 = 497;
+/// `CashDistribAgentName` field.
+pub const CASH_DISTRIB_AGENT_NAME: i32 = 498;
+/// `CashDistribAgentCode` field.
+pub const CASH_DISTRIB_AGENT_CODE: i32 = 499;
+/// `CashDistribAgentAcctNumber` field.
+pub const CASH_DISTRIB_AGENT_ACCT_NUMBER: i32 = 500;
+/// `CashDistribPayRef` field.
+pub const CASH_DISTRIB_PAY_REF: i32 = 501;
+/// `CashDistribAgentAcctName` field.
+pub const CASH_DISTRIB_AGENT_ACCT_NAME: i32 = 502;
+/// `CardStartDate` field.
+pub const CARD_START_DATE: i32 = 503;
+/// `PaymentDate` field.
+pub const PAYMENT_DATE: i32 = 504;
+/// `PaymentRemitterID` field.
+pub const PAYMENT_REMITTER_ID: i32 = 505;
+/// `RegistStatus` field.
+pub const REGIST_STATUS: i32 = 506;
+/// `RegistRejReasonCode` field.
+pub const REGIST_REJ_REASON_CODE: i32 = 507;
+/// `RegistRefID` field.
+pub const REGIST_REF_ID: i32 = 508;
+/// `RegistDtls` field.
+pub const REGIST_DTLS: i32 = 509;
+/// `NoDistribInsts` field.
+pub const NO_DISTRIB_INSTS: i32 = 510;
+/// `RegistEmail` field.
+pub const REGIST_EMAIL: i32 = 511;
+/// `DistribPercentage` field.
+pub const DISTRIB_PERCENTAGE: i32 = 512;
+/// `RegistID` field.
+pub const REGIST_ID: i32 = 513;
+/// `RegistTransType` field.
+pub const REGIST_TRANS_TYPE: i32 = 514;
+/// `ExecValuationPoint` field.
+pub const EXEC_VALUATION_POINT: i32 = 515;
+/// `OrderPercent` field.
+pub const ORDER_PERCENT: i32 = 516;
+/// `OwnershipType` field.
+pub const OWNERSHIP_TYPE: i32 = 517;
+/// `NoContAmts` field.
+pub const NO_CONT_AMTS: i32 = 518;
+/// `ContAmtType` field.
+pub const CONT_AMT_TYPE: i32 = 519;
+/// `ContAmtValue` field.
+pub const CONT_AMT_VALUE: i32 = 520;
+/// `ContAmtCurr` field.
+pub const CONT_AMT_CURR: i32 = 521;
+/// `OwnerType` field.
+pub const OWNER_TYPE: i32 = 522;
+/// `PartySubID` field.
+pub const PARTY_SUB_ID: i32 = 523;
+/// `NestedPartyID` field.
+pub const NESTED_PARTY_ID: i32 = 524;
+/// `NestedPartyIDSource` field.
+pub const NESTED_PARTY_ID_SOURCE: i32 = 525;
+/// `SecondaryClOrdID` field.
+pub const SECONDARY_CL_ORD_ID: i32 = 526;
+/// `SecondaryExecID` field.
+pub const SECONDARY_EXEC_ID: i32 = 527;
+/// `OrderCapacity` field.
+pub const ORDER_CAPACITY: i32 = 528;
+/// `OrderRestrictions` field.
+pub const ORDER_RESTRICTIONS: i32 = 529;
+/// `MassCancelRequestType` field.
+pub const MASS_CANCEL_REQUEST_TYPE: i32 = 530;
+/// `MassCancelResponse` field.
+pub const MASS_CANCEL_RESPONSE: i32 = 531;
+/// `MassCancelRejectReason` field.
+pub const MASS_CANCEL_REJECT_REASON: i32 = 532;
+/// `TotalAffectedOrders` field.
+pub const TOTAL_AFFECTED_ORDERS: i32 = 533;
+/// `NoAffectedOrders` field.
+pub const NO_AFFECTED_ORDERS: i32 = 534;
+/// `AffectedOrderID` field.
+pub const AFFECTED_ORDER_ID: i32 = 535;
+/// `AffectedSecondaryOrderID` field.
+pub const AFFECTED_SECONDARY_ORDER_ID: i32 = 536;
+/// `QuoteType` field.
+pub const QUOTE_TYPE: i32 = 537;
+/// `NestedPartyRole` field.
+pub const NESTED_PARTY_ROLE: i32 = 538;
+/// `NoNestedPartyIDs` field.
+pub const NO_NESTED_PARTY_I_DS: i32 = 539;
+/// `TotalAccruedInterestAmt` field.
+pub const TOTAL_ACCRUED_INTEREST_AMT: i32 = 540;
+/// `MaturityDate` field.
+pub const MATURITY_DATE: i32 = 541;
+/// `UnderlyingMaturityDate` field.
+pub const UNDERLYING_MATURITY_DATE: i32 = 542;
+/// `InstrRegistry` field.
+pub const INSTR_REGISTRY: i32 = 543;
+/// `CashMargin` field.
+pub const CASH_MARGIN: i32 = 544;
+/// `NestedPartySubID` field.
+pub const NESTED_PARTY_SUB_ID: i32 = 545;
+/// `Scope` field.
+pub const SCOPE: i32 = 546;
+/// `MDImplicitDelete` field.
+pub const MD_IMPLICIT_DELETE: i32 = 547;
+/// `CrossID` field.
+pub const CROSS_ID: i32 = 548;
+/// `CrossType` field.
+pub const CROSS_TYPE: i32 = 549;
+/// `CrossPrioritization` field.
+pub const CROSS_PRIORITIZATION: i32 = 550;
+/// `OrigCrossID` field.
+pub const ORIG_CROSS_ID: i32 = 551;
+/// `NoSides` field.
+pub const NO_SIDES: i32 = 552;
+/// `Username` field.
+pub const USERNAME: i32 = 553;
+/// `Password` field.
+pub const PASSWORD: i32 = 554;
+/// `NoLegs` field.
+pub const NO_LEGS: i32 = 555;
+/// `LegCurrency` field.
+pub const LEG_CURRENCY: i32 = 556;
+/// `TotNoSecurityTypes` field.
+pub const TOT_NO_SECURITY_TYPES: i32 = 557;
+/// `NoSecurityTypes` field.
+pub const NO_SECURITY_TYPES: i32 = 558;
+/// `SecurityListRequestType` field.
+pub const SECURITY_LIST_REQUEST_TYPE: i32 = 559;
+/// `SecurityRequestResult` field.
+pub const SECURITY_REQUEST_RESULT: i32 = 560;
+/// `RoundLot` field.
+pub const ROUND_LOT: i32 = 561;
+/// `MinTradeVol` field.
+pub const MIN_TRADE_VOL: i32 = 562;
+/// `MultiLegRptTypeReq` field.
+pub const MULTI_LEG_RPT_TYPE_REQ: i32 = 563;
+/// `LegPositionEffect` field.
+pub const LEG_POSITION_EFFECT: i32 = 564;
+/// `LegCoveredOrUncovered` field.
+pub const LEG_COVERED_OR_UNCOVERED: i32 = 565;
+/// `LegPrice` field.
+pub const LEG_PRICE: i32 = 566;
+/// `TradSesStatusRejReason` field.
+pub const TRAD_SES_STATUS_REJ_REASON: i32 = 567;
+/// `TradeRequestID` field.
+pub const TRADE_REQUEST_ID: i32 = 568;
+/// `TradeRequestType` field.
+pub const TRADE_REQUEST_TYPE: i32 = 569;
+/// `PreviouslyReported` field.
+pub const PREVIOUSLY_REPORTED: i32 = 570;
+/// `TradeReportID` field.
+pub const TRADE_REPORT_ID: i32 = 571;
+/// `TradeReportRefID` field.
+pub const TRADE_REPORT_REF_ID: i32 = 572;
+/// `MatchStatus` field.
+pub const MATCH_STATUS: i32 = 573;
+/// `MatchType` field.
+pub const MATCH_TYPE: i32 = 574;
+/// `OddLot` field.
+pub const ODD_LOT: i32 = 575;
+/// `NoClearingInstructions` field.
+pub const NO_CLEARING_INSTRUCTIONS: i32 = 576;
+/// `ClearingInstruction` field.
+pub const CLEARING_INSTRUCTION: i32 = 577;
+/// `TradeInputSource` field.
+pub const TRADE_INPUT_SOURCE: i32 = 578;
+/// `TradeInputDevice` field.
+pub const TRADE_INPUT_DEVICE: i32 = 579;
+/// `NoDates` field.
+pub const NO_DATES: i32 = 580;
+/// `AccountType` field.
+pub const ACCOUNT_TYPE: i32 = 581;
+/// `CustOrderCapacity` field.
+pub const CUST_ORDER_CAPACITY: i32 = 582;
+/// `ClOrdLinkID` field.
+pub const CL_ORD_LINK_ID: i32 = 583;
+/// `MassStatusReqID` field.
+pub const MASS_STATUS_REQ_ID: i32 = 584;
+/// `MassStatusReqType` field.
+pub const MASS_STATUS_REQ_TYPE: i32 = 585;
+/// `OrigOrdModTime` field.
+pub const ORIG_ORD_MOD_TIME: i32 = 586;
+/// `LegSettlType` field.
+pub const LEG_SETTL_TYPE: i32 = 587;
+/// `LegSettlDate` field.
+pub const LEG_SETTL_DATE: i32 = 588;
+/// `DayBookingInst` field.
+pub const DAY_BOOKING_INST: i32 = 589;
+/// `BookingUnit` field.
+pub const BOOKING_UNIT: i32 = 590;
+/// `PreallocMethod` field.
+pub const PREALLOC_METHOD: i32 = 591;
+/// `UnderlyingCountryOfIssue` field.
+pub const UNDERLYING_COUNTRY_OF_ISSUE: i32 = 592;
+/// `UnderlyingStateOrProvinceOfIssue` field.
+pub const UNDERLYING_STATE_OR_PROVINCE_OF_ISSUE: i32 = 593;
+/// `UnderlyingLocaleOfIssue` field.
+pub const UNDERLYING_LOCALE_OF_ISSUE: i32 = 594;
+/// `UnderlyingInstrRegistry` field.
+pub const UNDERLYING_INSTR_REGISTRY: i32 = 595;
+/// `LegCountryOfIssue` field.
+pub const LEG_COUNTRY_OF_ISSUE: i32 = 596;
+/// `LegStateOrProvinceOfIssue` field.
+pub const LEG_STATE_OR_PROVINCE_OF_ISSUE: i32 = 597;
+/// `LegLocaleOfIssue` field.
+pub const LEG_LOCALE_OF_ISSUE: i32 = 598;
+/// `LegInstrRegistry` field.
+pub const LEG_INSTR_REGISTRY: i32 = 599;
+/// `LegSymbol` field.
+pub const LEG_SYMBOL: i32 = 600;
+/// `LegSymbolSfx` field.
+pub const LEG_SYMBOL_SFX: i32 = 601;
+/// `LegSecurityID` field.
+pub const LEG_SECURITY_ID: i32 = 602;
+/// `LegSecurityIDSource` field.
+pub const LEG_SECURITY_ID_SOURCE: i32 = 603;
+/// `NoLegSecurityAltID` field.
+pub const NO_LEG_SECURITY_ALT_ID: i32 = 604;
+/// `LegSecurityAltID` field.
+pub const LEG_SECURITY_ALT_ID: i32 = 605;
+/// `LegSecurityAltIDSource` field.
+pub const LEG_SECURITY_ALT_ID_SOURCE: i32 = 606;
+/// `LegProduct` field.
+pub const LEG_PRODUCT: i32 = 607;
+/// `LegCFICode` field.
+pub const LEG_CFI_CODE: i32 = 608;
+/// `LegSecurityType` field.
+pub const LEG_SECURITY_TYPE: i32 = 609;
+/// `LegMaturityMonthYear` field.
+pub const LEG_MATURITY_MONTH_YEAR: i32 = 610;
+/// `LegMaturityDate` field.
+pub const LEG_MATURITY_DATE: i32 = 611;
+/// `LegStrikePrice` field.
+pub const LEG_STRIKE_PRICE: i32 = 612;
+/// `LegOptAttribute` field.
+pub const LEG_OPT_ATTRIBUTE: i32 = 613;
+/// `LegContractMultiplier` field.
+pub const LEG_CONTRACT_MULTIPLIER: i32 = 614;
+/// `LegCouponRate` field.
+pub const LEG_COUPON_RATE: i32 = 615;
+/// `LegSecurityExchange` field.
+pub const LEG_SECURITY_EXCHANGE: i32 = 616;
+/// `LegIssuer` field.
+pub const LEG_ISSUER: i32 = 617;
+/// `EncodedLegIssuerLen` field.
+pub const ENCODED_LEG_ISSUER_LEN: i32 = 618;
+/// `EncodedLegIssuer` field.
+pub const ENCODED_LEG_ISSUER: i32 = 619;
+/// `LegSecurityDesc` field.
+pub const LEG_SECURITY_DESC: i32 = 620;
+/// `EncodedLegSecurityDescLen` field.
+pub const ENCODED_LEG_SECURITY_DESC_LEN: i32 = 621;
+/// `EncodedLegSecurityDesc` field.
+pub const ENCODED_LEG_SECURITY_DESC: i32 = 622;
+/// `LegRatioQty` field.
+pub const LEG_RATIO_QTY: i32 = 623;
+/// `LegSide` field.
+pub const LEG_SIDE: i32 = 624;
+/// `TradingSessionSubID` field.
+pub const TRADING_SESSION_SUB_ID: i32 = 625;
+/// `AllocType` field.
+pub const ALLOC_TYPE: i32 = 626;
+/// `NoHops` field.
+pub const NO_HOPS: i32 = 627;
+/// `HopCompID` field.
+pub const HOP_COMP_ID: i32 = 628;
+/// `HopSendingTime` field.
+pub const HOP_SENDING_TIME: i32 = 629;
+/// `HopRefID` field.
+pub const HOP_REF_ID: i32 = 630;
+/// `MidPx` field.
+pub const MID_PX: i32 = 631;
+/// `BidYield` field.
+pub const BID_YIELD: i32 = 632;
+/// `MidYield` field.
+pub const MID_YIELD: i32 = 633;
+/// `OfferYield` field.
+pub const OFFER_YIELD: i32 = 634;
+/// `ClearingFeeIndicator` field.
+pub const CLEARING_FEE_INDICATOR: i32 = 635;
+/// `WorkingIndicator` field.
+pub const WORKING_INDICATOR: i32 = 636;
+/// `LegLastPx` field.
+pub const LEG_LAST_PX: i32 = 637;
+/// `PriorityIndicator` field.
+pub const PRIORITY_INDICATOR: i32 = 638;
+/// `PriceImprovement` field.
+pub const PRICE_IMPROVEMENT: i32 = 639;
+/// `Price2` field.
+pub const PRICE_2: i32 = 640;
+/// `LastForwardPoints2` field.
+pub const LAST_FORWARD_POINTS_2: i32 = 641;
+/// `BidForwardPoints2` field.
+pub const BID_FORWARD_POINTS_2: i32 = 642;
+/// `OfferForwardPoints2` field.
+pub const OFFER_FORWARD_POINTS_2: i32 = 643;
+/// `RFQReqID` field.
+pub const RFQ_REQ_ID: i32 = 644;
+/// `MktBidPx` field.
+pub const MKT_BID_PX: i32 = 645;
+/// `MktOfferPx` field.
+pub const MKT_OFFER_PX: i32 = 646;
+/// `MinBidSize` field.
+pub const MIN_BID_SIZE: i32 = 647;
+/// `MinOfferSize` field.
+pub const MIN_OFFER_SIZE: i32 = 648;
+/// `QuoteStatusReqID` field.
+pub const QUOTE_STATUS_REQ_ID: i32 = 649;
+/// `LegalConfirm` field.
+pub const LEGAL_CONFIRM: i32 = 650;
+/// `UnderlyingLastPx` field.
+pub const UNDERLYING_LAST_PX: i32 = 651;
+/// `UnderlyingLastQty` field.
+pub const UNDERLYING_LAST_QTY: i32 = 652;
+/// `LegRefID` field.
+pub const LEG_REF_ID: i32 = 654;
+/// `ContraLegRefID` field.
+pub const CONTRA_LEG_REF_ID: i32 = 655;
+/// `SettlCurrBidFxRate` field.
+pub const SETTL_CURR_BID_FX_RATE: i32 = 656;
+/// `SettlCurrOfferFxRate` field.
+pub const SETTL_CURR_OFFER_FX_RATE: i32 = 657;
+/// `QuoteRequestRejectReason` field.
+pub const QUOTE_REQUEST_REJECT_REASON: i32 = 658;
+/// `SideComplianceID` field.
+pub const SIDE_COMPLIANCE_ID: i32 = 659;
+/// `AcctIDSource` field.
+pub const ACCT_ID_SOURCE: i32 = 660;
+/// `AllocAcctIDSource` field.
+pub const ALLOC_ACCT_ID_SOURCE: i32 = 661;
+/// `BenchmarkPrice` field.
+pub const BENCHMARK_PRICE: i32 = 662;
+/// `BenchmarkPriceType` field.
+pub const BENCHMARK_PRICE_TYPE: i32 = 663;
+/// `ConfirmID` field.
+pub const CONFIRM_ID: i32 = 664;
+/// `ConfirmStatus` field.
+pub const CONFIRM_STATUS: i32 = 665;
+/// `ConfirmTransType` field.
+pub const CONFIRM_TRANS_TYPE: i32 = 666;
+/// `ContractSettlMonth` field.
+pub const CONTRACT_SETTL_MONTH: i32 = 667;
+/// `DeliveryForm` field.
+pub const DELIVERY_FORM: i32 = 668;
+/// `LastParPx` field.
+pub const LAST_PAR_PX: i32 = 669;
+/// `NoLegAllocs` field.
+pub const NO_LEG_ALLOCS: i32 = 670;
+/// `LegAllocAccount` field.
+pub const LEG_ALLOC_ACCOUNT: i32 = 671;
+/// `LegIndividualAllocID` field.
+pub const LEG_INDIVIDUAL_ALLOC_ID: i32 = 672;
+/// `LegAllocQty` field.
+pub const LEG_ALLOC_QTY: i32 = 673;
+/// `LegAllocAcctIDSource` field.
+pub const LEG_ALLOC_ACCT_ID_SOURCE: i32 = 674;
+/// `LegSettlCurrency` field.
+pub const LEG_SETTL_CURRENCY: i32 = 675;
+/// `LegBenchmarkCurveCurrency` field.
+pub const LEG_BENCHMARK_CURVE_CURRENCY: i32 = 676;
+/// `LegBenchmarkCurveName` field.
+pub const LEG_BENCHMARK_CURVE_NAME: i32 = 677;
+/// `LegBenchmarkCurvePoint` field.
+pub const LEG_BENCHMARK_CURVE_POINT: i32 = 678;
+/// `LegBenchmarkPrice` field.
+pub const LEG_BENCHMARK_PRICE: i32 = 679;
+/// `LegBenchmarkPriceType` field.
+pub const LEG_BENCHMARK_PRICE_TYPE: i32 = 680;
+/// `LegBidPx` field.
+pub const LEG_BID_PX: i32 = 681;
+/// `LegIOIQty` field.
+pub const LEG_IOI_QTY: i32 = 682;
+/// `NoLegStipulations` field.
+pub const NO_LEG_STIPULATIONS: i32 = 683;
+/// `LegOfferPx` field.
+pub const LEG_OFFER_PX: i32 = 684;
+/// `LegOrderQty` field.
+pub const LEG_ORDER_QTY: i32 = 685;
+/// `LegPriceType` field.
+pub const LEG_PRICE_TYPE: i32 = 686;
+/// `LegQty` field.
+pub const LEG_QTY: i32 = 687;
+/// `LegStipulationType` field.
+pub const LEG_STIPULATION_TYPE: i32 = 688;
+/// `LegStipulationValue` field.
+pub const LEG_STIPULATION_VALUE: i32 = 689;
+/// `LegSwapType` field.
+pub const LEG_SWAP_TYPE: i32 = 690;
+/// `Pool` field.
+pub const POOL: i32 = 691;
+/// `QuotePriceType` field.
+pub const QUOTE_PRICE_TYPE: i32 = 692;
+/// `QuoteRespID` field.
+pub const QUOTE_RESP_ID: i32 = 693;
+/// `QuoteRespType` field.
+pub const QUOTE_RESP_TYPE: i32 = 694;
+/// `QuoteQualifier` field.
+pub const QUOTE_QUALIFIER: i32 = 695;
+/// `YieldRedemptionDate` field.
+pub const YIELD_REDEMPTION_DATE: i32 = 696;
+/// `YieldRedemptionPrice` field.
+pub const YIELD_REDEMPTION_PRICE: i32 = 697;
+/// `YieldRedemptionPriceType` field.
+pub const YIELD_REDEMPTION_PRICE_TYPE: i32 = 698;
+/// `BenchmarkSecurityID` field.
+pub const BENCHMARK_SECURITY_ID: i32 = 699;
+/// `ReversalIndicator` field.
+pub const REVERSAL_INDICATOR: i32 = 700;
+/// `YieldCalcDate` field.
+pub const YIELD_CALC_DATE: i32 = 701;
+/// `NoPositions` field.
+pub const NO_POSITIONS: i32 = 702;
+/// `PosType` field.
+pub const POS_TYPE: i32 = 703;
+/// `LongQty` field.
+pub const LONG_QTY: i32 = 704;
+/// `ShortQty` field.
+pub const SHORT_QTY: i32 = 705;
+/// `PosQtyStatus` field.
+pub const POS_QTY_STATUS: i32 = 706;
+/// `PosAmtType` field.
+pub const POS_AMT_TYPE: i32 = 707;
+/// `PosAmt` field.
+pub const POS_AMT: i32 = 708;
+/// `PosTransType` field.
+pub const POS_TRANS_TYPE: i32 = 709;
+/// `PosReqID` field.
+pub const POS_REQ_ID: i32 = 710;
+/// `NoUnderlyings` field.
+pub const NO_UNDERLYINGS: i32 = 711;
+/// `PosMaintAction` field.
+pub const POS_MAINT_ACTION: i32 = 712;
+/// `OrigPosReqRefID` field.
+pub const ORIG_POS_REQ_REF_ID: i32 = 713;
+/// `PosMaintRptRefID` field.
+pub const POS_MAINT_RPT_REF_ID: i32 = 714;
+/// `ClearingBusinessDate` field.
+pub const CLEARING_BUSINESS_DATE: i32 = 715;
+/// `SettlSessID` field.
+pub const SETTL_SESS_ID: i32 = 716;
+/// `SettlSessSubID` field.
+pub const SETTL_SESS_SUB_ID: i32 = 717;
+/// `AdjustmentType` field.
+pub const ADJUSTMENT_TYPE: i32 = 718;
+/// `ContraryInstructionIndicator` field.
+pub const CONTRARY_INSTRUCTION_INDICATOR: i32 = 719;
+/// `PriorSpreadIndicator` field.
+pub const PRIOR_SPREAD_INDICATOR: i32 = 720;
+/// `PosMaintRptID` field.
+pub const POS_MAINT_RPT_ID: i32 = 721;
+/// `PosMaintStatus` field.
+pub const POS_MAINT_STATUS: i32 = 722;
+/// `PosMaintResult` field.
+pub const POS_MAINT_RESULT: i32 = 723;
+/// `PosReqType` field.
+pub const POS_REQ_TYPE: i32 = 724;
+/// `ResponseTransportType` field.
+pub const RESPONSE_TRANSPORT_TYPE: i32 = 725;
+/// `ResponseDestination` field.
+pub const RESPONSE_DESTINATION: i32 = 726;
+/// `TotalNumPosReports` field.
+pub const TOTAL_NUM_POS_REPORTS: i32 = 727;
+/// `PosReqResult` field.
+pub const POS_REQ_RESULT: i32 = 728;
+/// `PosReqStatus` field.
+pub const POS_REQ_STATUS: i32 = 729;
+/// `SettlPrice` field.
+pub const SETTL_PRICE: i32 = 730;
+/// `SettlPriceType` field.
+pub const SETTL_PRICE_TYPE: i32 = 731;
+/// `UnderlyingSettlPrice` field.
+pub const UNDERLYING_SETTL_PRICE: i32 = 732;
+/// `UnderlyingSettlPriceType` field.
+pub const UNDERLYING_SETTL_PRICE_TYPE: i32 = 733;
+/// `PriorSettlPrice` field.
+pub const PRIOR_SETTL_PRICE: i32 = 734;
+/// `NoQuoteQualifiers` field.
+pub const NO_QUOTE_QUALIFIERS: i32 = 735;
+/// `AllocSettlCurrency` field.
+pub const ALLOC_SETTL_CURRENCY: i32 = 736;
+/// `AllocSettlCurrAmt` field.
+pub const ALLOC_SETTL_CURR_AMT: i32 = 737;
+/// `InterestAtMaturity` field.
+pub const INTEREST_AT_MATURITY: i32 = 738;
+/// `LegDatedDate` field.
+pub const LEG_DATED_DATE: i32 = 739;
+/// `LegPool` field.
+pub const LEG_POOL: i32 = 740;
+/// `AllocInterestAtMaturity` field.
+pub const ALLOC_INTEREST_AT_MATURITY: i32 = 741;
+/// `AllocAccruedInterestAmt` field.
+pub const ALLOC_ACCRUED_INTEREST_AMT: i32 = 742;
+/// `DeliveryDate` field.
+pub const DELIVERY_DATE: i32 = 743;
+/// `AssignmentMethod` field.
+pub const ASSIGNMENT_METHOD: i32 = 744;
+/// `AssignmentUnit` field.
+pub const ASSIGNMENT_UNIT: i32 = 745;
+/// `OpenInterest` field.
+pub const OPEN_INTEREST: i32 = 746;
+/// `ExerciseMethod` field.
+pub const EXERCISE_METHOD: i32 = 747;
+/// `TotNumTradeReports` field.
+pub const TOT_NUM_TRADE_REPORTS: i32 = 748;
+/// `TradeRequestResult` field.
+pub const TRADE_REQUEST_RESULT: i32 = 749;
+/// `TradeRequestStatus` field.
+pub const TRADE_REQUEST_STATUS: i32 = 750;
+/// `TradeReportRejectReason` field.
+pub const TRADE_REPORT_REJECT_REASON: i32 = 751;
+/// `SideMultiLegReportingType` field.
+pub const SIDE_MULTI_LEG_REPORTING_TYPE: i32 = 752;
+/// `NoPosAmt` field.
+pub const NO_POS_AMT: i32 = 753;
+/// `AutoAcceptIndicator` field.
+pub const AUTO_ACCEPT_INDICATOR: i32 = 754;
+/// `AllocReportID` field.
+pub const ALLOC_REPORT_ID: i32 = 755;
+/// `NoNested2PartyIDs` field.
+pub const NO_NESTED_2_PARTY_I_DS: i32 = 756;
+/// `Nested2PartyID` field.
+pub const NESTED_2_PARTY_ID: i32 = 757;
+/// `Nested2PartyIDSource` field.
+pub const NESTED_2_PARTY_ID_SOURCE: i32 = 758;
+/// `Nested2PartyRole` field.
+pub const NESTED_2_PARTY_ROLE: i32 = 759;
+/// `Nested2PartySubID` field.
+pub const NESTED_2_PARTY_SUB_ID: i32 = 760;
+/// `BenchmarkSecurityIDSource` field.
+pub const BENCHMARK_SECURITY_ID_SOURCE: i32 = 761;
+/// `SecuritySubType` field.
+pub const SECURITY_SUB_TYPE: i32 = 762;
+/// `UnderlyingSecuritySubType` field.
+pub const UNDERLYING_SECURITY_SUB_TYPE: i32 = 763;
+/// `LegSecuritySubType` field.
+pub const LEG_SECURITY_SUB_TYPE: i32 = 764;
+/// `AllowableOneSidednessPct` field.
+pub const ALLOWABLE_ONE_SIDEDNESS_PCT: i32 = 765;
+/// `AllowableOneSidednessValue` field.
+pub const ALLOWABLE_ONE_SIDEDNESS_VALUE: i32 = 766;
+/// `AllowableOneSidednessCurr` field.
+pub const ALLOWABLE_ONE_SIDEDNESS_CURR: i32 = 767;
+/// `NoTrdRegTimestamps` field.
+pub const NO_TRD_REG_TIMESTAMPS: i32 = 768;
+/// `TrdRegTimestamp` field.
+pub const TRD_REG_TIMESTAMP: i32 = 769;
+/// `TrdRegTimestampType` field.
+pub const TRD_REG_TIMESTAMP_TYPE: i32 = 770;
+/// `TrdRegTimestampOrigin` field.
+pub const TRD_REG_TIMESTAMP_ORIGIN: i32 = 771;
+/// `ConfirmRefID` field.
+pub const CONFIRM_REF_ID: i32 = 772;
+/// `ConfirmType` field.
+pub const CONFIRM_TYPE: i32 = 773;
+/// `ConfirmRejReason` field.
+pub const CONFIRM_REJ_REASON: i32 = 774;
+/// `BookingType` field.
+pub const BOOKING_TYPE: i32 = 775;
+/// `IndividualAllocRejCode` field.
+pub const INDIVIDUAL_ALLOC_REJ_CODE: i32 = 776;
+/// `SettlInstMsgID` field.
+pub const SETTL_INST_MSG_ID: i32 = 777;
+/// `NoSettlInst` field.
+pub const NO_SETTL_INST: i32 = 778;
+/// `LastUpdateTime` field.
+pub const LAST_UPDATE_TIME: i32 = 779;
+/// `AllocSettlInstType` field.
+pub const ALLOC_SETTL_INST_TYPE: i32 = 780;
+/// `NoSettlPartyIDs` field.
+pub const NO_SETTL_PARTY_I_DS: i32 = 781;
+/// `SettlPartyID` field.
+pub const SETTL_PARTY_ID: i32 = 782;
+/// `SettlPartyIDSource` field.
+pub const SETTL_PARTY_ID_SOURCE: i32 = 783;
+/// `SettlPartyRole` field.
+pub const SETTL_PARTY_ROLE: i32 = 784;
+/// `SettlPartySubID` field.
+pub const SETTL_PARTY_SUB_ID: i32 = 785;
+/// `SettlPartySubIDType` field.
+pub const SETTL_PARTY_SUB_ID_TYPE: i32 = 786;
+/// `DlvyInstType` field.
+pub const DLVY_INST_TYPE: i32 = 787;
+/// `TerminationType` field.
+pub const TERMINATION_TYPE: i32 = 788;
+/// `NextExpectedMsgSeqNum` field.
+pub const NEXT_EXPECTED_MSG_SEQ_NUM: i32 = 789;
+/// `OrdStatusReqID` field.
+pub const ORD_STATUS_REQ_ID: i32 = 790;
+/// `SettlInstReqID` field.
+pub const SETTL_INST_REQ_ID: i32 = 791;
+/// `SettlInstReqRejCode` field.
+pub const SETTL_INST_REQ_REJ_CODE: i32 = 792;
+/// `SecondaryAllocID` field.
+pub const SECONDARY_ALLOC_ID: i32 = 793;
+/// `AllocReportType` field.
+pub const ALLOC_REPORT_TYPE: i32 = 794;
+/// `AllocReportRefID` field.
+pub const ALLOC_REPORT_REF_ID: i32 = 795;
+/// `AllocCancReplaceReason` field.
+pub const ALLOC_CANC_REPLACE_REASON: i32 = 796;
+/// `CopyMsgIndicator` field.
+pub const COPY_MSG_INDICATOR: i32 = 797;
+/// `AllocAccountType` field.
+pub const ALLOC_ACCOUNT_TYPE: i32 = 798;
+/// `OrderAvgPx` field.
+pub const ORDER_AVG_PX: i32 = 799;
+/// `OrderBookingQty` field.
+pub const ORDER_BOOKING_QTY: i32 = 800;
+/// `NoSettlPartySubIDs` field.
+pub const NO_SETTL_PARTY_SUB_I_DS: i32 = 801;
+/// `NoPartySubIDs` field.
+pub const NO_PARTY_SUB_I_DS: i32 = 802;
+/// `PartySubIDType` field.
+pub const PARTY_SUB_ID_TYPE: i32 = 803;
+/// `NoNestedPartySubIDs` field.
+pub const NO_NESTED_PARTY_SUB_I_DS: i32 = 804;
+/// `NestedPartySubIDType` field.
+pub const NESTED_PARTY_SUB_ID_TYPE: i32 = 805;
+/// `NoNested2PartySubIDs` field.
+pub const NO_NESTED_2_PARTY_SUB_I_DS: i32 = 806;
+/// `Nested2PartySubIDType` field.
+pub const NESTED_2_PARTY_SUB_ID_TYPE: i32 = 807;
+/// `AllocIntermedReqType` field.
+pub const ALLOC_INTERMED_REQ_TYPE: i32 = 808;
+/// `UnderlyingPx` field.
+pub const UNDERLYING_PX: i32 = 810;
+/// `PriceDelta` field.
+pub const PRICE_DELTA: i32 = 811;
+/// `ApplQueueMax` field.
+pub const APPL_QUEUE_MAX: i32 = 812;
+/// `ApplQueueDepth` field.
+pub const APPL_QUEUE_DEPTH: i32 = 813;
+/// `ApplQueueResolution` field.
+pub const APPL_QUEUE_RESOLUTION: i32 = 814;
+/// `ApplQueueAction` field.
+pub const APPL_QUEUE_ACTION: i32 = 815;
+/// `NoAltMDSource` field.
+pub const NO_ALT_MD_SOURCE: i32 = 816;
+/// `AltMDSourceID` field.
+pub const ALT_MD_SOURCE_ID: i32 = 817;
+/// `SecondaryTradeReportID` field.
+pub const SECONDARY_TRADE_REPORT_ID: i32 = 818;
+/// `AvgPxIndicator` field.
+pub const AVG_PX_INDICATOR: i32 = 819;
+/// `TradeLinkID` field.
+pub const TRADE_LINK_ID: i32 = 820;
+/// `OrderInputDevice` field.
+pub const ORDER_INPUT_DEVICE: i32 = 821;
+/// `UnderlyingTradingSessionID` field.
+pub const UNDERLYING_TRADING_SESSION_ID: i32 = 822;
+/// `UnderlyingTradingSessionSubID` field.
+pub const UNDERLYING_TRADING_SESSION_SUB_ID: i32 = 823;
+/// `TradeLegRefID` field.
+pub const TRADE_LEG_REF_ID: i32 = 824;
+/// `ExchangeRule` field.
+pub const EXCHANGE_RULE: i32 = 825;
+/// `TradeAllocIndicator` field.
+pub const TRADE_ALLOC_INDICATOR: i32 = 826;
+/// `ExpirationCycle` field.
+pub const EXPIRATION_CYCLE: i32 = 827;
+/// `TrdType` field.
+pub const TRD_TYPE: i32 = 828;
+/// `TrdSubType` field.
+pub const TRD_SUB_TYPE: i32 = 829;
+/// `TransferReason` field.
+pub const TRANSFER_REASON: i32 = 830;
+/// `TotNumAssignmentReports` field.
+pub const TOT_NUM_ASSIGNMENT_REPORTS: i32 = 832;
+/// `AsgnRptID` field.
+pub const ASGN_RPT_ID: i32 = 833;
+/// `ThresholdAmount` field.
+pub const THRESHOLD_AMOUNT: i32 = 834;
+/// `PegMoveType` field.
+pub const PEG_MOVE_TYPE: i32 = 835;
+/// `PegOffsetType` field.
+pub const PEG_OFFSET_TYPE: i32 = 836;
+/// `PegLimitType` field.
+pub const PEG_LIMIT_TYPE: i32 = 837;
+/// `PegRoundDirection` field.
+pub const PEG_ROUND_DIRECTION: i32 = 838;
+/// `PeggedPrice` field.
+pub const PEGGED_PRICE: i32 = 839;
+/// `PegScope` field.
+pub const PEG_SCOPE: i32 = 840;
+/// `DiscretionMoveType` field.
+pub const DISCRETION_MOVE_TYPE: i32 = 841;
+/// `DiscretionOffsetType` field.
+pub const DISCRETION_OFFSET_TYPE: i32 = 842;
+/// `DiscretionLimitType` field.
+pub const DISCRETION_LIMIT_TYPE: i32 = 843;
+/// `DiscretionRoundDirection` field.
+pub const DISCRETION_ROUND_DIRECTION: i32 = 844;
+/// `DiscretionPrice` field.
+pub const DISCRETION_PRICE: i32 = 845;
+/// `DiscretionScope` field.
+pub const DISCRETION_SCOPE: i32 = 846;
+/// `TargetStrategy` field.
+pub const TARGET_STRATEGY: i32 = 847;
+/// `TargetStrategyParameters` field.
+pub const TARGET_STRATEGY_PARAMETERS: i32 = 848;
+/// `ParticipationRate` field.
+pub const PARTICIPATION_RATE: i32 = 849;
+/// `TargetStrategyPerformance` field.
+pub const TARGET_STRATEGY_PERFORMANCE: i32 = 850;
+/// `LastLiquidityInd` field.
+pub const LAST_LIQUIDITY_IND: i32 = 851;
+/// `PublishTrdIndicator` field.
+pub const PUBLISH_TRD_INDICATOR: i32 = 852;
+/// `ShortSaleReason` field.
+pub const SHORT_SALE_REASON: i32 = 853;
+/// `QtyType` field.
+pub const QTY_TYPE: i32 = 854;
+/// `SecondaryTrdType` field.
+pub const SECONDARY_TRD_TYPE: i32 = 855;
+/// `TradeReportType` field.
+pub const TRADE_REPORT_TYPE: i32 = 856;
+/// `AllocNoOrdersType` field.
+pub const ALLOC_NO_ORDERS_TYPE: i32 = 857;
+/// `SharedCommission` field.
+pub const SHARED_COMMISSION: i32 = 858;
+/// `ConfirmReqID` field.
+pub const CONFIRM_REQ_ID: i32 = 859;
+/// `AvgParPx` field.
+pub const AVG_PAR_PX: i32 = 860;
+/// `ReportedPx` field.
+pub const REPORTED_PX: i32 = 861;
+/// `NoCapacities` field.
+pub const NO_CAPACITIES: i32 = 862;
+/// `OrderCapacityQty` field.
+pub const ORDER_CAPACITY_QTY: i32 = 863;
+/// `NoEvents` field.
+pub const NO_EVENTS: i32 = 864;
+/// `EventType` field.
+pub const EVENT_TYPE: i32 = 865;
+/// `EventDate` field.
+pub const EVENT_DATE: i32 = 866;
+/// `EventPx` field.
+pub const EVENT_PX: i32 = 867;
+/// `EventText` field.
+pub const EVENT_TEXT: i32 = 868;
+/// `PctAtRisk` field.
+pub const PCT_AT_RISK: i32 = 869;
+/// `NoInstrAttrib` field.
+pub const NO_INSTR_ATTRIB: i32 = 870;
+/// `InstrAttribType` field.
+pub const INSTR_ATTRIB_TYPE: i32 = 871;
+/// `InstrAttribValue` field.
+pub const INSTR_ATTRIB_VALUE: i32 = 872;
+/// `DatedDate` field.
+pub const DATED_DATE: i32 = 873;
+/// `InterestAccrualDate` field.
+pub const INTEREST_ACCRUAL_DATE: i32 = 874;
+/// `CPProgram` field.
+pub const CP_PROGRAM: i32 = 875;
+/// `CPRegType` field.
+pub const CP_REG_TYPE: i32 = 876;
+/// `UnderlyingCPProgram` field.
+pub const UNDERLYING_CP_PROGRAM: i32 = 877;
+/// `UnderlyingCPRegType` field.
+pub const UNDERLYING_CP_REG_TYPE: i32 = 878;
+/// `UnderlyingQty` field.
+pub const UNDERLYING_QTY: i32 = 879;
+/// `TrdMatchID` field.
+pub const TRD_MATCH_ID: i32 = 880;
+/// `SecondaryTradeReportRefID` field.
+pub const SECONDARY_TRADE_REPORT_REF_ID: i32 = 881;
+/// `UnderlyingDirtyPrice` field.
+pub const UNDERLYING_DIRTY_PRICE: i32 = 882;
+/// `UnderlyingEndPrice` field.
+pub const UNDERLYING_END_PRICE: i32 = 883;
+/// `UnderlyingStartValue` field.
+pub const UNDERLYING_START_VALUE: i32 = 884;
+/// `UnderlyingCurrentValue` field.
+pub const UNDERLYING_CURRENT_VALUE: i32 = 885;
+/// `UnderlyingEndValue` field.
+pub const UNDERLYING_END_VALUE: i32 = 886;
+/// `NoUnderlyingStips` field.
+pub const NO_UNDERLYING_STIPS: i32 = 887;
+/// `UnderlyingStipType` field.
+pub const UNDERLYING_STIP_TYPE: i32 = 888;
+/// `UnderlyingStipValue` field.
+pub const UNDERLYING_STIP_VALUE: i32 = 889;
+/// `MaturityNetMoney` field.
+pub const MATURITY_NET_MONEY: i32 = 890;
+/// `MiscFeeBasis` field.
+pub const MISC_FEE_BASIS: i32 = 891;
+/// `TotNoAllocs` field.
+pub const TOT_NO_ALLOCS: i32 = 892;
+/// `LastFragment` field.
+pub const LAST_FRAGMENT: i32 = 893;
+/// `CollReqID` field.
+pub const COLL_REQ_ID: i32 = 894;
+/// `CollAsgnReason` field.
+pub const COLL_ASGN_REASON: i32 = 895;
+/// `CollInquiryQualifier` field.
+pub const COLL_INQUIRY_QUALIFIER: i32 = 896;
+/// `NoTrades` field.
+pub const NO_TRADES: i32 = 897;
+/// `MarginRatio` field.
+pub const MARGIN_RATIO: i32 = 898;
+/// `MarginExcess` field.
+pub const MARGIN_EXCESS: i32 = 899;
+/// `TotalNetValue` field.
+pub const TOTAL_NET_VALUE: i32 = 900;
+/// `CashOutstanding` field.
+pub const CASH_OUTSTANDING: i32 = 901;
+/// `CollAsgnID` field.
+pub const COLL_ASGN_ID: i32 = 902;
+/// `CollAsgnTransType` field.
+pub const COLL_ASGN_TRANS_TYPE: i32 = 903;
+/// `CollRespID` field.
+pub const COLL_RESP_ID: i32 = 904;
+/// `CollAsgnRespType` field.
+pub const COLL_ASGN_RESP_TYPE: i32 = 905;
+/// `CollAsgnRejectReason` field.
+pub const COLL_ASGN_REJECT_REASON: i32 = 906;
+/// `CollAsgnRefID` field.
+pub const COLL_ASGN_REF_ID: i32 = 907;
+/// `CollRptID` field.
+pub const COLL_RPT_ID: i32 = 908;
+/// `CollInquiryID` field.
+pub const COLL_INQUIRY_ID: i32 = 909;
+/// `CollStatus` field.
+pub const COLL_STATUS: i32 = 910;
+/// `TotNumReports` field.
+pub const TOT_NUM_REPORTS: i32 = 911;
+/// `LastRptRequested` field.
+pub const LAST_RPT_REQUESTED: i32 = 912;
+/// `AgreementDesc` field.
+pub const AGREEMENT_DESC: i32 = 913;
+/// `AgreementID` field.
+pub const AGREEMENT_ID: i32 = 914;
+/// `AgreementDate` field.
+pub const AGREEMENT_DATE: i32 = 915;
+/// `StartDate` field.
+pub const START_DATE: i32 = 916;
+/// `EndDate` field.
+pub const END_DATE: i32 = 917;
+/// `AgreementCurrency` field.
+pub const AGREEMENT_CURRENCY: i32 = 918;
+/// `DeliveryType` field.
+pub const DELIVERY_TYPE: i32 = 919;
+/// `EndAccruedInterestAmt` field.
+pub const END_ACCRUED_INTEREST_AMT: i32 = 920;
+/// `StartCash` field.
+pub const START_CASH: i32 = 921;
+/// `EndCash` field.
+pub const END_CASH: i32 = 922;
+/// `UserRequestID` field.
+pub const USER_REQUEST_ID: i32 = 923;
+/// `UserRequestType` field.
+pub const USER_REQUEST_TYPE: i32 = 924;
+/// `NewPassword` field.
+pub const NEW_PASSWORD: i32 = 925;
+/// `UserStatus` field.
+pub const USER_STATUS: i32 = 926;
+/// `UserStatusText` field.
+pub const USER_STATUS_TEXT: i32 = 927;
+/// `StatusValue` field.
+pub const STATUS_VALUE: i32 = 928;
+/// `StatusText` field.
+pub const STATUS_TEXT: i32 = 929;
+/// `RefCompID` field.
+pub const REF_COMP_ID: i32 = 930;
+/// `RefSubID` field.
+pub const REF_SUB_ID: i32 = 931;
+/// `NetworkResponseID` field.
+pub const NETWORK_RESPONSE_ID: i32 = 932;
+/// `NetworkRequestID` field.
+pub const NETWORK_REQUEST_ID: i32 = 933;
+/// `LastNetworkResponseID` field.
+pub const LAST_NETWORK_RESPONSE_ID: i32 = 934;
+/// `NetworkRequestType` field.
+pub const NETWORK_REQUEST_TYPE: i32 = 935;
+/// `NoCompIDs` field.
+pub const NO_COMP_I_DS: i32 = 936;
+/// `NetworkStatusResponseType` field.
+pub const NETWORK_STATUS_RESPONSE_TYPE: i32 = 937;
+/// `NoCollInquiryQualifier` field.
+pub const NO_COLL_INQUIRY_QUALIFIER: i32 = 938;
+/// `TrdRptStatus` field.
+pub const TRD_RPT_STATUS: i32 = 939;
+/// `AffirmStatus` field.
+pub const AFFIRM_STATUS: i32 = 940;
+/// `UnderlyingStrikeCurrency` field.
+pub const UNDERLYING_STRIKE_CURRENCY: i32 = 941;
+/// `LegStrikeCurrency` field.
+pub const LEG_STRIKE_CURRENCY: i32 = 942;
+/// `TimeBracket` field.
+pub const TIME_BRACKET: i32 = 943;
+/// `CollAction` field.
+pub const COLL_ACTION: i32 = 944;
+/// `CollInquiryStatus` field.
+pub const COLL_INQUIRY_STATUS: i32 = 945;
+/// `CollInquiryResult` field.
+pub const COLL_INQUIRY_RESULT: i32 = 946;
+/// `StrikeCurrency` field.
+pub const STRIKE_CURRENCY: i32 = 947;
+/// `NoNested3PartyIDs` field.
+pub const NO_NESTED_3_PARTY_I_DS: i32 = 948;
+/// `Nested3PartyID` field.
+pub const NESTED_3_PARTY_ID: i32 = 949;
+/// `Nested3PartyIDSource` field.
+pub const NESTED_3_PARTY_ID_SOURCE: i32 = 950;
+/// `Nested3PartyRole` field.
+pub const NESTED_3_PARTY_ROLE: i32 = 951;
+/// `NoNested3PartySubIDs` field.
+pub const NO_NESTED_3_PARTY_SUB_I_DS: i32 = 952;
+/// `Nested3PartySubID` field.
+pub const NESTED_3_PARTY_SUB_ID: i32 = 953;
+/// `Nested3PartySubIDType` field.
+pub const NESTED_3_PARTY_SUB_ID_TYPE: i32 = 954;
+/// `LegContractSettlMonth` field.
+pub const LEG_CONTRACT_SETTL_MONTH: i32 = 955;
+/// `LegInterestAccrualDate` field.
+pub const LEG_INTEREST_ACCRUAL_DATE: i32 = 956;
+/// `NoStrategyParameters` field.
+pub const NO_STRATEGY_PARAMETERS: i32 = 957;
+/// `StrategyParameterName` field.
+pub const STRATEGY_PARAMETER_NAME: i32 = 958;
+/// `StrategyParameterType` field.
+pub const STRATEGY_PARAMETER_TYPE: i32 = 959;
+/// `StrategyParameterValue` field.
+pub const STRATEGY_PARAMETER_VALUE: i32 = 960;
+/// `HostCrossID` field.
+pub const HOST_CROSS_ID: i32 = 961;
+/// `SideTimeInForce` field.
+pub const SIDE_TIME_IN_FORCE: i32 = 962;
+/// `MDReportID` field.
+pub const MD_REPORT_ID: i32 = 963;
+/// `SecurityReportID` field.
+pub const SECURITY_REPORT_ID: i32 = 964;
+/// `SecurityStatus` field.
+pub const SECURITY_STATUS: i32 = 965;
+/// `SettleOnOpenFlag` field.
+pub const SETTLE_ON_OPEN_FLAG: i32 = 966;
+/// `StrikeMultiplier` field.
+pub const STRIKE_MULTIPLIER: i32 = 967;
+/// `StrikeValue` field.
+pub const STRIKE_VALUE: i32 = 968;
+/// `MinPriceIncrement` field.
+pub const MIN_PRICE_INCREMENT: i32 = 969;
+/// `PositionLimit` field.
+pub const POSITION_LIMIT: i32 = 970;
+/// `NTPositionLimit` field.
+pub const NT_POSITION_LIMIT: i32 = 971;
+/// `UnderlyingAllocationPercent` field.
+pub const UNDERLYING_ALLOCATION_PERCENT: i32 = 972;
+/// `UnderlyingCashAmount` field.
+pub const UNDERLYING_CASH_AMOUNT: i32 = 973;
+/// `UnderlyingCashType` field.
+pub const UNDERLYING_CASH_TYPE: i32 = 974;
+/// `UnderlyingSettlementType` field.
+pub const UNDERLYING_SETTLEMENT_TYPE: i32 = 975;
+/// `QuantityDate` field.
+pub const QUANTITY_DATE: i32 = 976;
+/// `ContIntRptID` field.
+pub const CONT_INT_RPT_ID: i32 = 977;
+/// `LateIndicator` field.
+pub const LATE_INDICATOR: i32 = 978;
+/// `InputSource` field.
+pub const INPUT_SOURCE: i32 = 979;
+/// `SecurityUpdateAction` field.
+pub const SECURITY_UPDATE_ACTION: i32 = 980;
+/// `NoExpiration` field.
+pub const NO_EXPIRATION: i32 = 981;
+/// `ExpType` field.
+pub const EXP_TYPE: i32 = 982;
+/// `ExpQty` field.
+pub const EXP_QTY: i32 = 983;
+/// `NoUnderlyingAmounts` field.
+pub const NO_UNDERLYING_AMOUNTS: i32 = 984;
+/// `UnderlyingPayAmount` field.
+pub const UNDERLYING_PAY_AMOUNT: i32 = 985;
+/// `UnderlyingCollectAmount` field.
+pub const UNDERLYING_COLLECT_AMOUNT: i32 = 986;
+/// `UnderlyingSettlementDate` field.
+pub const UNDERLYING_SETTLEMENT_DATE: i32 = 987;
+/// `UnderlyingSettlementStatus` field.
+pub const UNDERLYING_SETTLEMENT_STATUS: i32 = 988;
+/// `SecondaryIndividualAllocID` field.
+pub const SECONDARY_INDIVIDUAL_ALLOC_ID: i32 = 989;
+/// `LegReportID` field.
+pub const LEG_REPORT_ID: i32 = 990;
+/// `RndPx` field.
+pub const RND_PX: i32 = 991;
+/// `IndividualAllocType` field.
+pub const INDIVIDUAL_ALLOC_TYPE: i32 = 992;
+/// `AllocCustomerCapacity` field.
+pub const ALLOC_CUSTOMER_CAPACITY: i32 = 993;
+/// `TierCode` field.
+pub const TIER_CODE: i32 = 994;
+/// `UnitofMeasure` field.
+pub const UNITOF_MEASURE: i32 = 996;
+/// `TimeUnit` field.
+pub const TIME_UNIT: i32 = 997;
+/// `UnderlyingUnitofMeasure` field.
+pub const UNDERLYING_UNITOF_MEASURE: i32 = 998;
+/// `LegUnitofMeasure` field.
+pub const LEG_UNITOF_MEASURE: i32 = 999;
+/// `UnderlyingTimeUnit` field.
+pub const UNDERLYING_TIME_UNIT: i32 = 1000;
+/// `LegTimeUnit` field.
+pub const LEG_TIME_UNIT: i32 = 1001;
+/// `AllocMethod` field.
+pub const ALLOC_METHOD: i32 = 1002;
+/// `TradeID` field.
+pub const TRADE_ID: i32 = 1003;
+/// `SideTradeReportID` field.
+pub const SIDE_TRADE_REPORT_ID: i32 = 1005;
+/// `SideFillStationCd` field.
+pub const SIDE_FILL_STATION_CD: i32 = 1006;
+/// `SideReasonCd` field.
+pub const SIDE_REASON_CD: i32 = 1007;
+/// `SideTrdSubTyp` field.
+pub const SIDE_TRD_SUB_TYP: i32 = 1008;
+/// `SideQty` field.
+pub const SIDE_QTY: i32 = 1009;
+/// `MessageEventSource` field.
+pub const MESSAGE_EVENT_SOURCE: i32 = 1011;
+/// `SideTrdRegTimestamp` field.
+pub const SIDE_TRD_REG_TIMESTAMP: i32 = 1012;
+/// `SideTrdRegTimestampType` field.
+pub const SIDE_TRD_REG_TIMESTAMP_TYPE: i32 = 1013;
+/// `SideTrdRegTimestampSrc` field.
+pub const SIDE_TRD_REG_TIMESTAMP_SRC: i32 = 1014;
+/// `AsOfIndicator` field.
+pub const AS_OF_INDICATOR: i32 = 1015;
+/// `NoSideTrdRegTS` field.
+pub const NO_SIDE_TRD_REG_TS: i32 = 1016;
+/// `LegOptionRatio` field.
+pub const LEG_OPTION_RATIO: i32 = 1017;
+/// `NoInstrumentParties` field.
+pub const NO_INSTRUMENT_PARTIES: i32 = 1018;
+/// `InstrumentPartyID` field.
+pub const INSTRUMENT_PARTY_ID: i32 = 1019;
+/// `TradeVolume` field.
+pub const TRADE_VOLUME: i32 = 1020;
+/// `MDBookType` field.
+pub const MD_BOOK_TYPE: i32 = 1021;
+/// `MDFeedType` field.
+pub const MD_FEED_TYPE: i32 = 1022;
+/// `MDPriceLevel` field.
+pub const MD_PRICE_LEVEL: i32 = 1023;
+/// `MDOriginType` field.
+pub const MD_ORIGIN_TYPE: i32 = 1024;
+/// `FirstPx` field.
+pub const FIRST_PX: i32 = 1025;
+/// `MDEntrySpotRate` field.
+pub const MD_ENTRY_SPOT_RATE: i32 = 1026;
+/// `MDEntryForwardPoints` field.
+pub const MD_ENTRY_FORWARD_POINTS: i32 = 1027;
+/// `ManualOrderIndicator` field.
+pub const MANUAL_ORDER_INDICATOR: i32 = 1028;
+/// `CustDirectedOrder` field.
+pub const CUST_DIRECTED_ORDER: i32 = 1029;
+/// `ReceivedDeptID` field.
+pub const RECEIVED_DEPT_ID: i32 = 1030;
+/// `CustOrderHandlingInst` field.
+pub const CUST_ORDER_HANDLING_INST: i32 = 1031;
+/// `OrderHandlingInstSource` field.
+pub const ORDER_HANDLING_INST_SOURCE: i32 = 1032;
+/// `DeskType` field.
+pub const DESK_TYPE: i32 = 1033;
+/// `DeskTypeSource` field.
+pub const DESK_TYPE_SOURCE: i32 = 1034;
+/// `DeskOrderHandlingInst` field.
+pub const DESK_ORDER_HANDLING_INST: i32 = 1035;
+/// `ExecAckStatus` field.
+pub const EXEC_ACK_STATUS: i32 = 1036;
+/// `UnderlyingDeliveryAmount` field.
+pub const UNDERLYING_DELIVERY_AMOUNT: i32 = 1037;
+/// `UnderlyingCapValue` field.
+pub const UNDERLYING_CAP_VALUE: i32 = 1038;
+/// `UnderlyingSettlMethod` field.
+pub const UNDERLYING_SETTL_METHOD: i32 = 1039;
+/// `SecondaryTradeID` field.
+pub const SECONDARY_TRADE_ID: i32 = 1040;
+/// `FirmTradeID` field.
+pub const FIRM_TRADE_ID: i32 = 1041;
+/// `SecondaryFirmTradeID` field.
+pub const SECONDARY_FIRM_TRADE_ID: i32 = 1042;
+/// `CollApplType` field.
+pub const COLL_APPL_TYPE: i32 = 1043;
+/// `UnderlyingAdjustedQuantity` field.
+pub const UNDERLYING_ADJUSTED_QUANTITY: i32 = 1044;
+/// `UnderlyingFXRate` field.
+pub const UNDERLYING_FX_RATE: i32 = 1045;
+/// `UnderlyingFXRateCalc` field.
+pub const UNDERLYING_FX_RATE_CALC: i32 = 1046;
+/// `AllocPositionEffect` field.
+pub const ALLOC_POSITION_EFFECT: i32 = 1047;
+/// `DealingCapacity` field.
+pub const DEALING_CAPACITY: i32 = 1048;
+/// `InstrmtAssignmentMethod` field.
+pub const INSTRMT_ASSIGNMENT_METHOD: i32 = 1049;
+/// `InstrumentPartyIDSource` field.
+pub const INSTRUMENT_PARTY_ID_SOURCE: i32 = 1050;
+/// `InstrumentPartyRole` field.
+pub const INSTRUMENT_PARTY_ROLE: i32 = 1051;
+/// `NoInstrumentPartySubIDs` field.
+pub const NO_INSTRUMENT_PARTY_SUB_I_DS: i32 = 1052;
+/// `InstrumentPartySubID` field.
+pub const INSTRUMENT_PARTY_SUB_ID: i32 = 1053;
+/// `InstrumentPartySubIDType` field.
+pub const INSTRUMENT_PARTY_SUB_ID_TYPE: i32 = 1054;
+/// `PositionCurrency` field.
+pub const POSITION_CURRENCY: i32 = 1055;
+/// `CalculatedCcyLastQty` field.
+pub const CALCULATED_CCY_LAST_QTY: i32 = 1056;
+/// `AggressorIndicator` field.
+pub const AGGRESSOR_INDICATOR: i32 = 1057;
+/// `NoUndlyInstrumentParties` field.
+pub const NO_UNDLY_INSTRUMENT_PARTIES: i32 = 1058;
+/// `UndlyInstrumentPartyID` field.
+pub const UNDLY_INSTRUMENT_PARTY_ID: i32 = 1059;
+/// `UndlyInstrumentPartyIDSource` field.
+pub const UNDLY_INSTRUMENT_PARTY_ID_SOURCE: i32 = 1060;
+/// `UndlyInstrumentPartyRole` field.
+pub const UNDLY_INSTRUMENT_PARTY_ROLE: i32 = 1061;
+/// `NoUndlyInstrumentPartySubIDs` field.
+pub const NO_UNDLY_INSTRUMENT_PARTY_SUB_I_DS: i32 = 1062;
+/// `UndlyInstrumentPartySubID` field.
+pub const UNDLY_INSTRUMENT_PARTY_SUB_ID: i32 = 1063;
+/// `UndlyInstrumentPartySubIDType` field.
+pub const UNDLY_INSTRUMENT_PARTY_SUB_ID_TYPE: i32 = 1064;
+/// `BidSwapPoints` field.
+pub const BID_SWAP_POINTS: i32 = 1065;
+/// `OfferSwapPoints` field.
+pub const OFFER_SWAP_POINTS: i32 = 1066;
+/// `LegBidForwardPoints` field.
+pub const LEG_BID_FORWARD_POINTS: i32 = 1067;
+/// `LegOfferForwardPoints` field.
+pub const LEG_OFFER_FORWARD_POINTS: i32 = 1068;
+/// `SwapPoints` field.
+pub const SWAP_POINTS: i32 = 1069;
+/// `MDQuoteType` field.
+pub const MD_QUOTE_TYPE: i32 = 1070;
+/// `LastSwapPoints` field.
+pub const LAST_SWAP_POINTS: i32 = 1071;
+/// `SideGrossTradeAmt` field.
+pub const SIDE_GROSS_TRADE_AMT: i32 = 1072;
+/// `LegLastForwardPoints` field.
+pub const LEG_LAST_FORWARD_POINTS: i32 = 1073;
+/// `LegCalculatedCcyLastQty` field.
+pub const LEG_CALCULATED_CCY_LAST_QTY: i32 = 1074;
+/// `LegGrossTradeAmt` field.
+pub const LEG_GROSS_TRADE_AMT: i32 = 1075;
+/// `MaturityTime` field.
+pub const MATURITY_TIME: i32 = 1079;
+/// `RefOrderID` field.
+pub const REF_ORDER_ID: i32 = 1080;
+/// `RefOrderIDSource` field.
+pub const REF_ORDER_ID_SOURCE: i32 = 1081;
+/// `SecondaryDisplayQty` field.
+pub const SECONDARY_DISPLAY_QTY: i32 = 1082;
+/// `DisplayWhen` field.
+pub const DISPLAY_WHEN: i32 = 1083;
+/// `DisplayMethod` field.
+pub const DISPLAY_METHOD: i32 = 1084;
+/// `DisplayLowQty` field.
+pub const DISPLAY_LOW_QTY: i32 = 1085;
+/// `DisplayHighQty` field.
+pub const DISPLAY_HIGH_QTY: i32 = 1086;
+/// `DisplayMinIncr` field.
+pub const DISPLAY_MIN_INCR: i32 = 1087;
+/// `RefreshQty` field.
+pub const REFRESH_QTY: i32 = 1088;
+/// `MatchIncrement` field.
+pub const MATCH_INCREMENT: i32 = 1089;
+/// `MaxPriceLevels` field.
+pub const MAX_PRICE_LEVELS: i32 = 1090;
+/// `PreTradeAnonymity` field.
+pub const PRE_TRADE_ANONYMITY: i32 = 1091;
+/// `PriceProtectionScope` field.
+pub const PRICE_PROTECTION_SCOPE: i32 = 1092;
+/// `LotType` field.
+pub const LOT_TYPE: i32 = 1093;
+/// `PegPriceType` field.
+pub const PEG_PRICE_TYPE: i32 = 1094;
+/// `PeggedRefPrice` field.
+pub const PEGGED_REF_PRICE: i32 = 1095;
+/// `PegSecurityIDSource` field.
+pub const PEG_SECURITY_ID_SOURCE: i32 = 1096;
+/// `PegSecurityID` field.
+pub const PEG_SECURITY_ID: i32 = 1097;
+/// `PegSymbol` field.
+pub const PEG_SYMBOL: i32 = 1098;
+/// `PegSecurityDesc` field.
+pub const PEG_SECURITY_DESC: i32 = 1099;
+/// `TriggerType` field.
+pub const TRIGGER_TYPE: i32 = 1100;
+/// `TriggerAction` field.
+pub const TRIGGER_ACTION: i32 = 1101;
+/// `TriggerPrice` field.
+pub const TRIGGER_PRICE: i32 = 1102;
+/// `TriggerSymbol` field.
+pub const TRIGGER_SYMBOL: i32 = 1103;
+/// `TriggerSecurityID` field.
+pub const TRIGGER_SECURITY_ID: i32 = 1104;
+/// `TriggerSecurityIDSource` field.
+pub const TRIGGER_SECURITY_ID_SOURCE: i32 = 1105;
+/// `TriggerSecurityDesc` field.
+pub const TRIGGER_SECURITY_DESC: i32 = 1106;
+/// `TriggerPriceType` field.
+pub const TRIGGER_PRICE_TYPE: i32 = 1107;
+/// `TriggerPriceTypeScope` field.
+pub const TRIGGER_PRICE_TYPE_SCOPE: i32 = 1108;
+/// `TriggerPriceDirection` field.
+pub const TRIGGER_PRICE_DIRECTION: i32 = 1109;
+/// `TriggerNewPrice` field.
+pub const TRIGGER_NEW_PRICE: i32 = 1110;
+/// `TriggerOrderType` field.
+pub const TRIGGER_ORDER_TYPE: i32 = 1111;
+/// `TriggerNewQty` field.
+pub const TRIGGER_NEW_QTY: i32 = 1112;
+/// `TriggerTradingSessionID` field.
+pub const TRIGGER_TRADING_SESSION_ID: i32 = 1113;
+/// `TriggerTradingSessionSubID` field.
+pub const TRIGGER_TRADING_SESSION_SUB_ID: i32 = 1114;
+/// `OrderCategory` field.
+pub const ORDER_CATEGORY: i32 = 1115;
+/// `NoRootPartyIDs` field.
+pub const NO_ROOT_PARTY_I_DS: i32 = 1116;
+/// `RootPartyID` field.
+pub const ROOT_PARTY_ID: i32 = 1117;
+/// `RootPartyIDSource` field.
+pub const ROOT_PARTY_ID_SOURCE: i32 = 1118;
+/// `RootPartyRole` field.
+pub const ROOT_PARTY_ROLE: i32 = 1119;
+/// `NoRootPartySubIDs` field.
+pub const NO_ROOT_PARTY_SUB_I_DS: i32 = 1120;
+/// `RootPartySubID` field.
+pub const ROOT_PARTY_SUB_ID: i32 = 1121;
+/// `RootPartySubIDType` field.
+pub const ROOT_PARTY_SUB_ID_TYPE: i32 = 1122;
+/// `TradeHandlingInstr` field.
+pub const TRADE_HANDLING_INSTR: i32 = 1123;
+/// `OrigTradeHandlingInstr` field.
+pub const ORIG_TRADE_HANDLING_INSTR: i32 = 1124;
+/// `OrigTradeDate` field.
+pub const ORIG_TRADE_DATE: i32 = 1125;
+/// `OrigTradeID` field.
+pub const ORIG_TRADE_ID: i32 = 1126;
+/// `OrigSecondaryTradeID` field.
+pub const ORIG_SECONDARY_TRADE_ID: i32 = 1127;
+/// `ApplVerID` field.
+pub const APPL_VER_ID: i32 = 1128;
+/// `CstmApplVerID` field.
+pub const CSTM_APPL_VER_ID: i32 = 1129;
+/// `RefApplVerID` field.
+pub const REF_APPL_VER_ID: i32 = 1130;
+/// `RefCstmApplVerID` field.
+pub const REF_CSTM_APPL_VER_ID: i32 = 1131;
+/// `TZTransactTime` field.
+pub const TZ_TRANSACT_TIME: i32 = 1132;
+/// `ExDestinationIDSource` field.
+pub const EX_DESTINATION_ID_SOURCE: i32 = 1133;
+/// `ReportedPxDiff` field.
+pub const REPORTED_PX_DIFF: i32 = 1134;
+/// `RptSys` field.
+pub const RPT_SYS: i32 = 1135;
+/// `AllocClearingFeeIndicator` field.
+pub const ALLOC_CLEARING_FEE_INDICATOR: i32 = 1136;
+/// `DefaultApplVerID` field.
+pub const DEFAULT_APPL_VER_ID: i32 = 1137;
+/// `DisplayQty` field.
+pub const DISPLAY_QTY: i32 = 1138;
+/// `ExchangeSpecialInstructions` field.
+pub const EXCHANGE_SPECIAL_INSTRUCTIONS: i32 = 1139;
+/// `ApplExtID` field.
+pub const APPL_EXT_ID: i32 = 1156;
+/// `EncryptedPasswordMethod` field.
+pub const ENCRYPTED_PASSWORD_METHOD: i32 = 1400;
+/// `EncryptedPasswordLen` field.
+pub const ENCRYPTED_PASSWORD_LEN: i32 = 1401;
+/// `EncryptedPassword` field.
+pub const ENCRYPTED_PASSWORD: i32 = 1402;
+/// `EncryptedNewPasswordLen` field.
+pub const ENCRYPTED_NEW_PASSWORD_LEN: i32 = 1403;
+/// `EncryptedNewPassword` field.
+pub const ENCRYPTED_NEW_PASSWORD: i32 = 1404;
+/// `RefApplExtID` field.
+pub const REF_APPL_EXT_ID: i32 = 1406;
+/// `DefaultApplExtID` field.
+pub const DEFAULT_APPL_EXT_ID: i32 = 1407;
+/// `DefaultCstmApplVerID` field.
+pub const DEFAULT_CSTM_APPL_VER_ID: i32 = 1408;
+/// `SessionStatus` field.
+pub const SESSION_STATUS: i32 = 1409;
@@ -0,0 +1,614 @@
+//! Helpers to test FIX applications against an in-process counterparty.
+//!
+//! [`SessionPair`] runs an initiator and an acceptor connected to each other on localhost, with
+//! in memory message stores. Acceptor listens on a port picked by the OS and initiator is then
+//! configured with [`listen_port`], so concurrent tests never race for a port.
+//!
+//! ```rust,no_run
+//! # use std::time::Duration;
+//! # use quickfix::{testing::*, *};
+//! # fn run() -> Result<(), QuickFixError> {
+//! let pair = SessionPair::start(FixVersion::Fix44, FixSocketServerKind::SingleThreaded)?;
+//! pair.wait_logged_on(Duration::from_secs(10))?;
+//!
+//! let mut news = Message::new();
+//! news.with_header_mut(|h| h.set_field(tags::MSG_TYPE, msg_types::NEWS))?;
+//! news.set_field(tags::HEADLINE, "Hello")?;
+//! send_to_target(news, pair.initiator_session_id())?;
+//!
+//! let received = pair.acceptor_app().wait_app_messages(1, Duration::from_secs(10));
+//! assert_eq!(received[0].get_field(tags::HEADLINE).as_deref(), Some("Hello"));
+//!
+//! pair.shutdown(Duration::from_secs(5))?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Sessions are `SENDER -> RECEIVER` on initiator side, and `RECEIVER -> SENDER` on acceptor
+//! side. They do not use any data dictionary unless one is set with
+//! [`SessionPairBuilder::data_dictionary`].
+
+use std::{
+    any::Any,
+    fmt,
+    net::SocketAddr,
+    sync::{Arc, Condvar, Mutex, MutexGuard},
+    thread,
+    time::{Duration, Instant},
+};
+
+use quickfix_ffi::FixLogFactory_t;
+
+use crate::{
+    dictionary_item::{
+        ConnectionType, DataDictionary, DefaultApplVerID, DictionaryItem, EndTime, HeartBtInt,
+        ReconnectInterval, SocketAcceptPort, SocketConnectHost, SocketConnectPort, StartTime,
+        UseDataDictionary,
+    },
+    Acceptor, Application, ApplicationCallback, ConnectionHandler, Dictionary, FfiLogFactory,
+    FfiMessageStoreFactory, FixSocketServerKind, Initiator, LogFactory, MemoryMessageStoreFactory,
+    Message, MsgFromAdminError, MsgFromAppError, NullLogger, QuickFixError, SessionId,
+    SessionSettings, SessionSettingsBuilder, ShutdownReport,
+};
+
+/// Comp ID of initiator side.
+pub const SENDER_COMP_ID: &str = "SENDER";
+/// Comp ID of acceptor side.
+pub const RECEIVER_COMP_ID: &str = "RECEIVER";
+
+/// FIX version of test sessions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FixVersion {
+    /// FIX 4.0.
+    Fix40,
+    /// FIX 4.1.
+    Fix41,
+    /// FIX 4.2.
+    Fix42,
+    /// FIX 4.3.
+    Fix43,
+    /// FIX 4.4.
+    Fix44,
+    /// FIX 5.0 over FIXT 1.1.
+    Fix50,
+    /// FIX 5.0 SP1 over FIXT 1.1.
+    Fix50Sp1,
+    /// FIX 5.0 SP2 over FIXT 1.1.
+    Fix50Sp2,
+}
+
+impl FixVersion {
+    /// Get session BeginString.
+    pub const fn begin_string(self) -> &'static str {
+        match self {
+            Self::Fix40 => "FIX.4.0",
+            Self::Fix41 => "FIX.4.1",
+            Self::Fix42 => "FIX.4.2",
+            Self::Fix43 => "FIX.4.3",
+            Self::Fix44 => "FIX.4.4",
+            Self::Fix50 | Self::Fix50Sp1 | Self::Fix50Sp2 => "FIXT.1.1",
+        }
+    }
+
+    /// Get `DefaultApplVerID` of FIXT sessions.
+    pub const fn default_appl_ver_id(self) -> Option<&'static str> {
+        match self {
+            Self::Fix50 => Some("7"),
+            Self::Fix50Sp1 => Some("8"),
+            Self::Fix50Sp2 => Some("9"),
+            _ => None,
+        }
+    }
+}
+
+/// Side of a [`SessionPair`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PairSide {
+    /// Initiator side, connecting to acceptor.
+    Initiator,
+    /// Acceptor side.
+    Acceptor,
+}
+
+impl PairSide {
+    /// Get handler connection type.
+    pub fn connection_type(self) -> ConnectionType {
+        match self {
+            Self::Initiator => ConnectionType::Initiator,
+            Self::Acceptor => ConnectionType::Acceptor,
+        }
+    }
+
+    /// Get ID of the session of this side.
+    pub fn session_id(self, version: FixVersion) -> Result<SessionId, QuickFixError> {
+        let (sender, target) = match self {
+            Self::Initiator => (SENDER_COMP_ID, RECEIVER_COMP_ID),
+            Self::Acceptor => (RECEIVER_COMP_ID, SENDER_COMP_ID),
+        };
+        SessionId::try_new(version.begin_string(), sender, target, "")
+    }
+}
+
+/// Get port of a running acceptor.
+///
+/// Configure acceptor with `SocketAcceptPort=0` to let the OS pick a free port, start it, and
+/// use this port for initiators: unlike probing for a free port first, nothing can grab it in
+/// between.
+pub fn listen_port<A, S>(acceptor: &Acceptor<'_, A, S>) -> Result<u16, QuickFixError>
+where
+    A: ApplicationCallback,
+    S: FfiMessageStoreFactory,
+{
+    acceptor
+        .listen_endpoints()?
+        .first()
+        .map(SocketAddr::port)
+        .ok_or_else(|| QuickFixError::invalid_argument("Acceptor is not listening"))
+}
+
+/// Application callbacks doing nothing.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NullApplication;
+
+impl ApplicationCallback for NullApplication {}
+
+#[derive(Debug, Default)]
+struct Recorded {
+    logged_on: bool,
+    app_messages: Vec<Message>,
+    admin_messages: Vec<Message>,
+}
+
+/// Application callbacks keeping every received message.
+#[derive(Debug, Default)]
+pub struct RecordingApplication {
+    state: Mutex<Recorded>,
+    changed: Condvar,
+}
+
+impl RecordingApplication {
+    /// Create new empty recorder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn recorded(&self) -> MutexGuard<'_, Recorded> {
+        // Recorded data stays consistent even if a callback panicked.
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Check if session is logged on, according to logon / logout callbacks.
+    pub fn is_logged_on(&self) -> bool {
+        self.recorded().logged_on
+    }
+
+    /// Get received application messages, in reception order.
+    pub fn app_messages(&self) -> Vec<Message> {
+        self.recorded().app_messages.clone()
+    }
+
+    /// Get received admin messages, in reception order.
+    pub fn admin_messages(&self) -> Vec<Message> {
+        self.recorded().admin_messages.clone()
+    }
+
+    /// Wait until at least `count` application messages have been received, then get them.
+    ///
+    /// Messages received so far are returned once `timeout` elapsed, so check their count.
+    pub fn wait_app_messages(&self, count: usize, timeout: Duration) -> Vec<Message> {
+        let recorded = self.recorded();
+        let (recorded, _timeout) = self
+            .changed
+            .wait_timeout_while(recorded, timeout, |recorded| {
+                recorded.app_messages.len() < count
+            })
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        recorded.app_messages.clone()
+    }
+
+    /// Forget every received message.
+    pub fn clear(&self) {
+        let mut recorded = self.recorded();
+        recorded.app_messages.clear();
+        recorded.admin_messages.clear();
+    }
+
+    fn update<F: FnOnce(&mut Recorded)>(&self, f: F) {
+        f(&mut self.recorded());
+        self.changed.notify_all();
+    }
+}
+
+impl ApplicationCallback for RecordingApplication {
+    fn on_logon(&self, _session: &SessionId) {
+        self.update(|recorded| recorded.logged_on = true);
+    }
+
+    fn on_logout(&self, _session: &SessionId) {
+        self.update(|recorded| recorded.logged_on = false);
+    }
+
+    fn on_msg_from_admin(
+        &self,
+        msg: Message,
+        _session: &SessionId,
+    ) -> Result<(), MsgFromAdminError> {
+        self.update(|recorded| recorded.admin_messages.push(msg));
+        Ok(())
+    }
+
+    fn on_msg_from_app(&self, msg: Message, _session: &SessionId) -> Result<(), MsgFromAppError> {
+        self.update(|recorded| recorded.app_messages.push(msg));
+        Ok(())
+    }
+}
+
+/// Build settings of test sessions, see [`SessionPair`].
+#[derive(Clone)]
+pub struct TestSettings<'a> {
+    version: FixVersion,
+    data_dictionary: Option<&'a str>,
+    default_items: Vec<&'a dyn DictionaryItem>,
+}
+
+impl<'a> TestSettings<'a> {
+    /// Create new settings for `version`.
+    pub fn new(version: FixVersion) -> Self {
+        Self {
+            version,
+            data_dictionary: None,
+            default_items: Vec::new(),
+        }
+    }
+
+    /// Validate FIX 4.x messages with data dictionary at `path`.
+    ///
+    /// FIXT sessions can set `TransportDataDictionary` / `AppDataDictionary` with
+    /// [`Self::default_item`] instead.
+    pub fn data_dictionary(mut self, path: &'a str) -> Self {
+        self.data_dictionary = Some(path);
+        self
+    }
+
+    /// Apply `item` on DEFAULT section.
+    ///
+    /// Items are applied after built-in defaults, so they override them.
+    pub fn default_item(mut self, item: &'a dyn DictionaryItem) -> Self {
+        self.default_items.push(item);
+        self
+    }
+
+    /// Same as [`Self::default_item`], for a list of items.
+    pub fn default_items(mut self, items: &[&'a dyn DictionaryItem]) -> Self {
+        self.default_items.extend_from_slice(items);
+        self
+    }
+
+    /// Build settings of `side`, connecting to / listening on `port`.
+    ///
+    /// Acceptors can use port 0, see [`listen_port`].
+    pub fn build(&self, side: PairSide, port: u16) -> Result<SessionSettings, QuickFixError> {
+        let mut defaults =
+            Dictionary::try_from_items(&[&side.connection_type(), &ReconnectInterval(60)])?;
+        if self.data_dictionary.is_none() {
+            UseDataDictionary(false).apply_param(&mut defaults)?;
+        }
+        for item in &self.default_items {
+            item.apply_param(&mut defaults)?;
+        }
+
+        SessionSettingsBuilder::new()
+            .default(defaults)
+            .session(side.session_id(self.version)?, |dict| {
+                apply_session_items(dict, port)?;
+                if let Some(path) = self.data_dictionary {
+                    DataDictionary(path).apply_param(dict)?;
+                }
+                if let Some(appl_ver_id) = self.version.default_appl_ver_id() {
+                    DefaultApplVerID(appl_ver_id).apply_param(dict)?;
+                }
+                Ok(())
+            })
+            .build()
+    }
+}
+
+impl fmt::Debug for TestSettings<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TestSettings")
+            .field("version", &self.version)
+            .field("data_dictionary", &self.data_dictionary)
+            .field("default_items", &self.default_items.len())
+            .finish()
+    }
+}
+
+/// Apply items of a localhost test session to `dict`: open all day, 20 seconds heartbeats, and
+/// sockets of both sides on `port`.
+///
+/// Use it to fill sessions of [`SessionSettingsBuilder`] when [`TestSettings`] does not fit.
+pub fn apply_session_items(dict: &mut Dictionary, port: u16) -> Result<(), QuickFixError> {
+    let items: [&dyn DictionaryItem; 6] = [
+        &StartTime("00:00:00"),
+        &EndTime("23:59:59"),
+        &HeartBtInt(20),
+        &SocketAcceptPort(port),
+        &SocketConnectPort(port),
+        &SocketConnectHost("127.0.0.1"),
+    ];
+    items.iter().try_for_each(|item| item.apply_param(dict))
+}
+
+/// Build and start a [`SessionPair`].
+pub struct SessionPairBuilder<'a> {
+    settings: TestSettings<'a>,
+    server_kind: FixSocketServerKind,
+    log_factory: Option<(FixLogFactory_t, Arc<dyn Any + Send + Sync>)>,
+}
+
+impl<'a> SessionPairBuilder<'a> {
+    /// Set server kind of both handlers.
+    pub fn server_kind(mut self, server_kind: FixSocketServerKind) -> Self {
+        self.server_kind = server_kind;
+        self
+    }
+
+    /// Same as [`TestSettings::data_dictionary`], for both sides.
+    pub fn data_dictionary(mut self, path: &'a str) -> Self {
+        self.settings = self.settings.data_dictionary(path);
+        self
+    }
+
+    /// Same as [`TestSettings::default_item`], for both sides.
+    pub fn default_item(mut self, item: &'a dyn DictionaryItem) -> Self {
+        self.settings = self.settings.default_item(item);
+        self
+    }
+
+    /// Same as [`TestSettings::default_items`], for both sides.
+    pub fn default_items(mut self, items: &[&'a dyn DictionaryItem]) -> Self {
+        self.settings = self.settings.default_items(items);
+        self
+    }
+
+    /// Set log factory of both handlers.
+    ///
+    /// Pairs log nothing by default: use `LogFactory::try_new(&StdLogger::Stdout)?` to see
+    /// messages and events of a failing test.
+    pub fn log<L>(mut self, log_factory: L) -> Self
+    where
+        L: FfiLogFactory + Send + Sync + 'static,
+    {
+        let log_factory = Arc::new(log_factory);
+        self.log_factory = Some((
+            log_factory.as_ffi_ptr(),
+            log_factory as Arc<dyn Any + Send + Sync>,
+        ));
+        self
+    }
+
+    /// Start pair with a [`RecordingApplication`] on both sides.
+    pub fn start(self) -> Result<SessionPair<'static>, QuickFixError> {
+        let initiator_app = Application::try_new_shared(Arc::new(RecordingApplication::new()))?;
+        let acceptor_app = Application::try_new_shared(Arc::new(RecordingApplication::new()))?;
+        SessionPair::start_inner(self, initiator_app, acceptor_app)
+    }
+
+    /// Start pair with borrowed application callbacks.
+    pub fn start_with<'c, I, A>(
+        self,
+        initiator_app: &'c I,
+        acceptor_app: &'c A,
+    ) -> Result<SessionPair<'c, I, A>, QuickFixError>
+    where
+        I: ApplicationCallback,
+        A: ApplicationCallback,
+    {
+        let initiator_app = Application::try_new(initiator_app)?;
+        let acceptor_app = Application::try_new(acceptor_app)?;
+        SessionPair::start_inner(self, initiator_app, acceptor_app)
+    }
+}
+
+impl fmt::Debug for SessionPairBuilder<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SessionPairBuilder")
+            .field("settings", &self.settings)
+            .field("server_kind", &self.server_kind)
+            .field("log_factory", &self.log_factory.is_some())
+            .finish()
+    }
+}
+
+/// Started initiator and acceptor, connected to each other on localhost.
+///
+/// Handlers are stopped on drop, or with [`Self::shutdown`] to wait for logout completion.
+pub struct SessionPair<'a, I = RecordingApplication, A = RecordingApplication>
+where
+    I: ApplicationCallback,
+    A: ApplicationCallback,
+{
+    version: FixVersion,
+    initiator_session_id: SessionId,
+    acceptor_session_id: SessionId,
+    // Handlers are declared first, so they are dropped before components they use.
+    initiator: Initiator<'a, I, MemoryMessageStoreFactory>,
+    acceptor: Acceptor<'a, A, MemoryMessageStoreFactory>,
+    initiator_app: Application<'a, I>,
+    acceptor_app: Application<'a, A>,
+    _initiator_store: MemoryMessageStoreFactory,
+    _acceptor_store: MemoryMessageStoreFactory,
+}
+
+impl SessionPair<'static> {
+    /// Start a pair of `version` sessions, with a [`RecordingApplication`] on both sides.
+    ///
+    /// Use [`Self::builder`] for more options.
+    pub fn start(
+        version: FixVersion,
+        server_kind: FixSocketServerKind,
+    ) -> Result<Self, QuickFixError> {
+        Self::builder(version).server_kind(server_kind).start()
+    }
+
+    /// Start building a pair of `version` sessions.
+    pub fn builder<'a>(version: FixVersion) -> SessionPairBuilder<'a> {
+        SessionPairBuilder {
+            settings: TestSettings::new(version),
+            server_kind: FixSocketServerKind::default(),
+            log_factory: None,
+        }
+    }
+}
+
+impl<'a, I, A> SessionPair<'a, I, A>
+where
+    I: ApplicationCallback,
+    A: ApplicationCallback,
+{
+    fn start_inner(
+        builder: SessionPairBuilder<'_>,
+        initiator_app: Application<'a, I>,
+        acceptor_app: Application<'a, A>,
+    ) -> Result<Self, QuickFixError> {
+        let SessionPairBuilder {
+            settings,
+            server_kind,
+            log_factory,
+        } = builder;
+        let initiator_store = MemoryMessageStoreFactory::new();
+        let acceptor_store = MemoryMessageStoreFactory::new();
+        let (log_factory_ptr, log_factory) = match log_factory {
+            Some(log_factory) => log_factory,
+            None => {
+                let log_factory = Arc::new(LogFactory::try_new(&NullLogger)?);
+                (log_factory.as_ffi_ptr(), log_factory as Arc<_>)
+            }
+        };
+
+        // Applications and stores are moved in the pair next to handlers, which only keep their
+        // C++ pointers: they are never dropped first.
+        let mut acceptor = Acceptor::try_new_unbound(
+            &settings.build(PairSide::Acceptor, 0)?,
+            &acceptor_app,
+            &acceptor_store,
+            log_factory_ptr,
+            server_kind,
+            vec![log_factory.clone()],
+        )?;
+        acceptor.start()?;
+
+        let mut initiator = Initiator::try_new_unbound(
+            &settings.build(PairSide::Initiator, listen_port(&acceptor)?)?,
+            &initiator_app,
+            &initiator_store,
+            log_factory_ptr,
+            server_kind,
+            vec![log_factory],
+        )?;
+        initiator.start()?;
+
+        Ok(Self {
+            version: settings.version,
+            initiator_session_id: PairSide::Initiator.session_id(settings.version)?,
+            acceptor_session_id: PairSide::Acceptor.session_id(settings.version)?,
+            initiator,
+            acceptor,
+            initiator_app,
+            acceptor_app,
+            _initiator_store: initiator_store,
+            _acceptor_store: acceptor_store,
+        })
+    }
+
+    /// Get sessions FIX version.
+    pub fn version(&self) -> FixVersion {
+        self.version
+    }
+
+    /// Get initiator handler.
+    pub fn initiator(&self) -> &Initiator<'a, I, MemoryMessageStoreFactory> {
+        &self.initiator
+    }
+
+    /// Get acceptor handler.
+    pub fn acceptor(&self) -> &Acceptor<'a, A, MemoryMessageStoreFactory> {
+        &self.acceptor
+    }
+
+    /// Get both handlers, to mutate them.
+    pub fn handlers_mut(
+        &mut self,
+    ) -> (
+        &mut Initiator<'a, I, MemoryMessageStoreFactory>,
+        &mut Acceptor<'a, A, MemoryMessageStoreFactory>,
+    ) {
+        (&mut self.initiator, &mut self.acceptor)
+    }
+
+    /// Get initiator session ID (`SENDER -> RECEIVER`).
+    pub fn initiator_session_id(&self) -> &SessionId {
+        &self.initiator_session_id
+    }
+
+    /// Get acceptor session ID (`RECEIVER -> SENDER`).
+    pub fn acceptor_session_id(&self) -> &SessionId {
+        &self.acceptor_session_id
+    }
+
+    /// Get initiator application callbacks.
+    pub fn initiator_app(&self) -> &I {
+        // SAFETY: callbacks are borrowed for `'a`, or owned by application.
+        unsafe { &*self.initiator_app.callbacks_ptr() }
+    }
+
+    /// Get acceptor application callbacks.
+    pub fn acceptor_app(&self) -> &A {
+        // SAFETY: same as `initiator_app`.
+        unsafe { &*self.acceptor_app.callbacks_ptr() }
+    }
+
+    /// Wait until both sides are logged on.
+    ///
+    /// Fails with [`QuickFixError::SessionNotLoggedOn`] when they are still not after `timeout`.
+    pub fn wait_logged_on(&self, timeout: Duration) -> Result<(), QuickFixError> {
+        let deadline = Instant::now() + timeout;
+        while !self.initiator.is_logged_on()? || !self.acceptor.is_logged_on()? {
+            if Instant::now() >= deadline {
+                return Err(QuickFixError::SessionNotLoggedOn(format!(
+                    "Session pair not logged on after {timeout:?}"
+                )));
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+        Ok(())
+    }
+
+    /// Logout and stop initiator then acceptor, see [`ConnectionHandler::shutdown`].
+    ///
+    /// Return initiator and acceptor shutdown reports.
+    pub fn shutdown(
+        mut self,
+        timeout: Duration,
+    ) -> Result<(ShutdownReport, ShutdownReport), QuickFixError> {
+        let initiator_report = self.initiator.shutdown(timeout)?;
+        let acceptor_report = self.acceptor.shutdown(timeout)?;
+        Ok((initiator_report, acceptor_report))
+    }
+}
+
+impl<I, A> fmt::Debug for SessionPair<'_, I, A>
+where
+    I: ApplicationCallback,
+    A: ApplicationCallback,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SessionPair")
+            .field("version", &self.version)
+            .field("initiator_session_id", &self.initiator_session_id)
+            .field("acceptor_session_id", &self.acceptor_session_id)
+            .finish()
+    }
+}
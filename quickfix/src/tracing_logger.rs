@@ -0,0 +1,92 @@
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{Mutex, PoisonError},
+};
+
+use tracing::{Level, Span};
+
+use crate::{utils::raw_field, EventSeverity, LogCallback, SessionId};
+
+/// Emit FIX logs as `tracing` events.
+///
+/// Every session gets its own `fix_session` span, created on first use with a `session_id` field,
+/// so all events of a session can be correlated: logon, heartbeats, application messages...
+///
+/// Messages are emitted with `direction` (`incoming` / `outgoing`), `msg_type` and `seq_num`
+/// fields: heartbeats at trace level, other messages at debug level. Other FIX events are emitted
+/// at info, warn or error level depending on their [`EventSeverity`].
+#[derive(Default)]
+pub struct TracingLogger {
+    spans: Mutex<HashMap<String, Span>>,
+}
+
+impl TracingLogger {
+    /// Create new struct.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn span(&self, session_id: Option<&SessionId>) -> Span {
+        let Some(session_id) = session_id else {
+            return Span::current();
+        };
+        let session_id = session_id.to_repr();
+        self.spans
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entry(session_id)
+            .or_insert_with_key(|session_id| {
+                tracing::info_span!(parent: None, "fix_session", session_id = %session_id)
+            })
+            .clone()
+    }
+
+    fn message(&self, direction: &str, session_id: Option<&SessionId>, msg: &str) {
+        let msg_type = raw_field(msg, 35).unwrap_or_default();
+        let seq_num = raw_field(msg, 34).and_then(|value| value.parse::<u32>().ok());
+        let text = msg.replace('\x01', "|");
+
+        self.span(session_id).in_scope(|| {
+            if msg_type == "0" {
+                tracing::event!(Level::TRACE, direction, msg_type, seq_num, "{text}");
+            } else {
+                tracing::event!(Level::DEBUG, direction, msg_type, seq_num, "{text}");
+            }
+        });
+    }
+}
+
+impl LogCallback for TracingLogger {
+    fn on_incoming(&self, session_id: Option<&SessionId>, msg: &str) {
+        self.message("incoming", session_id, msg);
+    }
+
+    fn on_outgoing(&self, session_id: Option<&SessionId>, msg: &str) {
+        self.message("outgoing", session_id, msg);
+    }
+
+    fn on_event(&self, session_id: Option<&SessionId>, msg: &str) {
+        self.on_event_with_severity(session_id, msg, EventSeverity::infer(msg));
+    }
+
+    fn on_event_with_severity(
+        &self,
+        session_id: Option<&SessionId>,
+        msg: &str,
+        severity: EventSeverity,
+    ) {
+        let text = msg.replace('\x01', "|");
+        self.span(session_id).in_scope(|| match severity {
+            EventSeverity::Info => tracing::info!("{text}"),
+            EventSeverity::Warning => tracing::warn!("{text}"),
+            EventSeverity::Error => tracing::error!("{text}"),
+        });
+    }
+}
+
+impl fmt::Debug for TracingLogger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("TracingLogger").finish()
+    }
+}
@@ -1,19 +1,23 @@
 use std::fmt;
 
 use quickfix_ffi::{
-    FixTrailer_addGroup, FixTrailer_copy, FixTrailer_copyGroup, FixTrailer_delete,
-    FixTrailer_getField, FixTrailer_isFieldEqual, FixTrailer_new, FixTrailer_removeField,
-    FixTrailer_setField, FixTrailer_t,
+    FixTrailer_addGroup, FixTrailer_addGroups, FixTrailer_copy, FixTrailer_copyGroup,
+    FixTrailer_delete, FixTrailer_getFieldValue, FixTrailer_getFields, FixTrailer_isFieldEqual,
+    FixTrailer_new, FixTrailer_removeField, FixTrailer_setFieldRaw, FixTrailer_t,
 };
 
 use crate::{
-    utils::{ffi_code_to_result, read_checked_cstr},
-    FieldMap, Group, IntoFixValue, QuickFixError,
+    utils::{append_field_value, ffi_code_to_result, with_flat_group_rows},
+    FieldBatch, FieldMap, Group, IntoFixValue, QuickFixError,
 };
 
 /// Trailer part of a FIX message.
 pub struct Trailer(pub(crate) FixTrailer_t);
 
+// SAFETY: same as `Message`, trailer is either owned or only lent through `Message::with_trailer`.
+// Not `Sync` either, as the lent trailer holds CheckSum(10) rewritten by message serialization.
+unsafe impl Send for Trailer {}
+
 impl Trailer {
     /// Create new empty struct.
     pub fn new() -> Self {
@@ -23,7 +27,36 @@ impl Trailer {
 
 impl FieldMap for Trailer {
     fn get_field(&self, tag: i32) -> Option<String> {
-        unsafe { FixTrailer_getField(self.0, tag) }.map(read_checked_cstr)
+        self.try_get_field(tag).ok().flatten()
+    }
+
+    fn get_field_bytes(&self, tag: i32) -> Option<Vec<u8>> {
+        // Read with length, so values holding NUL bytes are not truncated.
+        let mut buf = Vec::new();
+        self.get_field_bytes_into(tag, &mut buf)
+            .ok()
+            .map(|_len| buf)
+    }
+
+    fn get_field_bytes_into(&self, tag: i32, buf: &mut Vec<u8>) -> Result<usize, QuickFixError> {
+        let mut value_len = 0;
+        unsafe {
+            let value = FixTrailer_getFieldValue(self.0, tag, &mut value_len);
+            append_field_value(tag, value, value_len, buf)
+        }
+    }
+
+    fn get_fields_into(&self, tags: &[i32], batch: &mut FieldBatch) -> Result<(), QuickFixError> {
+        batch.fill_from_ffi(tags, |buffer, buffer_len, lengths| unsafe {
+            FixTrailer_getFields(
+                self.0,
+                tags.as_ptr(),
+                tags.len() as u64,
+                buffer,
+                buffer_len,
+                lengths,
+            )
+        })
     }
 
     fn is_field_equal(&self, tag: i32, value: &str) -> bool {
@@ -34,7 +67,13 @@ impl FieldMap for Trailer {
 
     fn set_field<V: IntoFixValue>(&mut self, tag: i32, value: V) -> Result<(), QuickFixError> {
         let fix_value = value.into_fix_value()?;
-        ffi_code_to_result(unsafe { FixTrailer_setField(self.0, tag, fix_value.as_ptr()) })
+        self.set_field_raw(tag, fix_value.as_bytes())
+    }
+
+    fn set_field_raw(&mut self, tag: i32, value: &[u8]) -> Result<(), QuickFixError> {
+        ffi_code_to_result(unsafe {
+            FixTrailer_setFieldRaw(self.0, tag, value.as_ptr().cast(), value.len() as u64)
+        })
     }
 
     fn remove_field(&mut self, tag: i32) -> Result<(), QuickFixError> {
@@ -46,6 +85,21 @@ impl FieldMap for Trailer {
         Ok(())
     }
 
+    fn add_groups(
+        &mut self,
+        template: &Group,
+        rows: &[&[(i32, &str)]],
+    ) -> Result<(), QuickFixError> {
+        ffi_code_to_result(with_flat_group_rows(
+            rows,
+            |tags, values, value_lens, row_lens, rows_len| unsafe {
+                FixTrailer_addGroups(
+                    self.0, template.0, tags, values, value_lens, row_lens, rows_len,
+                )
+            },
+        ))
+    }
+
     fn clone_group(&self, index: i32, tag: i32) -> Option<Group> {
         unsafe { FixTrailer_copyGroup(self.0, index, tag) }.map(Group)
     }
@@ -1,21 +1,99 @@
 use std::{
     ffi::{self, CStr},
     ptr::NonNull,
+    time::{Duration, SystemTime, UNIX_EPOCH},
 };
 
 use crate::QuickFixError;
 
+/// Admin MsgType(35) values, as defined by FIX.
+pub const ADMIN_MSG_TYPES: [&str; 7] = ["0", "1", "2", "3", "4", "5", "A"];
+
 #[inline(always)]
 pub fn read_checked_cstr(val: NonNull<ffi::c_char>) -> String {
     let cstr = unsafe { CStr::from_ptr(val.as_ptr()) };
     cstr.to_string_lossy().to_string()
 }
 
+/// Append field value borrowed from quickfix to `buf`, returning number of bytes appended.
+///
+/// # Safety
+///
+/// `value` must point to `value_len` readable bytes when not null.
+#[inline(always)]
+pub unsafe fn append_field_value(
+    tag: i32,
+    value: Option<NonNull<ffi::c_char>>,
+    value_len: u64,
+    buf: &mut Vec<u8>,
+) -> Result<usize, QuickFixError> {
+    let value = value.ok_or_else(|| QuickFixError::field_not_found(tag))?;
+    let value = std::slice::from_raw_parts(value.as_ptr().cast::<u8>(), value_len as usize);
+    buf.extend_from_slice(value);
+    Ok(value.len())
+}
+
+/// Flatten group rows into the arrays expected by `Fix*_addGroups`, and call `f` with them.
+pub fn with_flat_group_rows<T, F>(rows: &[&[(i32, &str)]], f: F) -> T
+where
+    F: FnOnce(*const i32, *const *const ffi::c_char, *const u64, *const u64, u64) -> T,
+{
+    let field_count = rows.iter().map(|row| row.len()).sum();
+    let mut tags = Vec::with_capacity(field_count);
+    let mut values = Vec::with_capacity(field_count);
+    let mut value_lens = Vec::with_capacity(field_count);
+    let mut row_lens = Vec::with_capacity(rows.len());
+
+    for row in rows {
+        for (tag, value) in row.iter() {
+            tags.push(*tag);
+            values.push(value.as_ptr().cast::<ffi::c_char>());
+            value_lens.push(value.len() as u64);
+        }
+        row_lens.push(row.len() as u64);
+    }
+
+    f(
+        tags.as_ptr(),
+        values.as_ptr(),
+        value_lens.as_ptr(),
+        row_lens.as_ptr(),
+        rows.len() as u64,
+    )
+}
+
+/// Convert bytes read from quickfix into a `String`, keeping them in error when not UTF-8.
+pub fn utf8_to_string(tag: Option<i32>, bytes: Vec<u8>) -> Result<String, QuickFixError> {
+    String::from_utf8(bytes).map_err(|err| QuickFixError::InvalidUtf8 {
+        tag,
+        bytes: err.into_bytes(),
+    })
+}
+
+/// Borrow text given by quickfix with its length.
+///
+/// # Safety
+///
+/// `ptr` must point to `len` readable bytes, living for `'a`.
 #[inline(always)]
-pub unsafe fn from_ffi_str<'a>(ptr: *const ffi::c_char) -> &'a str {
-    assert!(!ptr.is_null(), "null ptr given from `c_str()`");
-    let cstr = CStr::from_ptr(ptr);
-    cstr.to_str().unwrap_or("invalid `c_str()` received")
+pub unsafe fn from_ffi_str<'a>(ptr: *const ffi::c_char, len: u64) -> &'a str {
+    assert!(!ptr.is_null(), "null ptr given from `std::string::data()`");
+    let bytes = std::slice::from_raw_parts(ptr.cast::<u8>(), len as usize);
+    std::str::from_utf8(bytes).unwrap_or("invalid `std::string` received")
+}
+
+/// Borrow field value given by quickfix as `&str`, `None` when field is missing or not UTF-8.
+///
+/// # Safety
+///
+/// `value` must point to `value_len` readable bytes, living for `'a`, when not null.
+#[inline(always)]
+pub unsafe fn field_value_str<'a>(
+    value: Option<NonNull<ffi::c_char>>,
+    value_len: u64,
+) -> Option<&'a str> {
+    let value = std::slice::from_raw_parts(value?.as_ptr().cast::<u8>(), value_len as usize);
+    std::str::from_utf8(value).ok()
 }
 
 #[inline(always)]
@@ -34,3 +112,29 @@ pub fn ffi_code_to_bool(code: i8) -> Result<bool, QuickFixError> {
         _ => Err(QuickFixError::from_last_error()),
     }
 }
+
+/// Find value of `tag` in a raw FIX message, except for the very first field.
+pub fn raw_field(text: &str, tag: u32) -> Option<&str> {
+    let prefix = format!("\x01{tag}=");
+    let start = text.find(&prefix)? + prefix.len();
+    let end = text[start..]
+        .find('\x01')
+        .map_or(text.len(), |end| end + start);
+    Some(&text[start..end])
+}
+
+/// Convert duration to milliseconds, saturating at `i64::MAX`.
+pub fn duration_to_millis(value: Duration) -> i64 {
+    i64::try_from(value.as_millis()).unwrap_or(i64::MAX)
+}
+
+/// Convert `time` to milliseconds since epoch, as exchanged with C++ (0 before epoch).
+pub fn to_millis(time: SystemTime) -> i64 {
+    time.duration_since(UNIX_EPOCH)
+        .map_or(0, duration_to_millis)
+}
+
+/// Convert milliseconds since epoch back to time, negative values giving epoch.
+pub fn from_millis(millis: i64) -> SystemTime {
+    UNIX_EPOCH + Duration::from_millis(millis.max(0) as u64)
+}
@@ -0,0 +1,103 @@
+use std::{
+    hint::black_box,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use quickfix::{FieldMap, Group, Message, QuickFixError};
+
+static GLOBAL_LOCK: Mutex<u8> = Mutex::new(0);
+
+const WARMUP_ITERS: usize = 20;
+const BENCH_ITERS: usize = 500;
+
+/// Number of NoMDEntries instances of the market data snapshot.
+const ENTRIES: usize = 200;
+
+#[derive(Debug)]
+struct BenchResult {
+    iterations: usize,
+    add_group: Duration,
+    add_groups: Duration,
+}
+
+fn add_one_by_one(template: &Group, rows: &[&[(i32, &str)]]) -> Result<Message, QuickFixError> {
+    let mut msg = Message::new();
+    for row in rows {
+        let mut group = template.clone();
+        for (tag, value) in row.iter() {
+            group.set_field(*tag, *value)?;
+        }
+        msg.add_group(&group)?;
+    }
+    Ok(msg)
+}
+
+fn add_bulk(template: &Group, rows: &[&[(i32, &str)]]) -> Result<Message, QuickFixError> {
+    let mut msg = Message::new();
+    msg.add_groups(template, rows)?;
+    Ok(msg)
+}
+
+fn run_bench(iterations: usize) -> Result<BenchResult, QuickFixError> {
+    let _lock = GLOBAL_LOCK.lock().expect("GLOBAL_LOCK poisoned");
+
+    let prices: Vec<_> = (0..ENTRIES).map(|i| format!("1.{i:04}")).collect();
+    let sizes: Vec<_> = (0..ENTRIES).map(|i| (i * 100).to_string()).collect();
+    let rows: Vec<[(i32, &str); 2]> = (0..ENTRIES)
+        .map(|i| [(270, prices[i].as_str()), (271, sizes[i].as_str())])
+        .collect();
+    let rows: Vec<&[(i32, &str)]> = rows.iter().map(|row| &row[..]).collect();
+
+    let mut template = Group::try_with_orders(268, 269, &[269, 270, 271])?;
+    template.set_field(269, "0")?;
+
+    for _ in 0..WARMUP_ITERS {
+        black_box(add_one_by_one(&template, &rows)?);
+        black_box(add_bulk(&template, &rows)?);
+    }
+
+    let t0 = Instant::now();
+    for _ in 0..iterations {
+        black_box(add_one_by_one(&template, &rows)?);
+    }
+    let add_group = t0.elapsed();
+
+    let t1 = Instant::now();
+    for _ in 0..iterations {
+        black_box(add_bulk(&template, &rows)?);
+    }
+    let add_groups = t1.elapsed();
+
+    Ok(BenchResult {
+        iterations,
+        add_group,
+        add_groups,
+    })
+}
+
+#[test]
+#[ignore = "Manual benchmark; run with -- --ignored --nocapture"]
+fn bench_add_group_vs_add_groups() -> Result<(), QuickFixError> {
+    let result = run_bench(BENCH_ITERS)?;
+
+    let single_ns_per_op = result.add_group.as_nanos() as f64 / result.iterations as f64;
+    let bulk_ns_per_op = result.add_groups.as_nanos() as f64 / result.iterations as f64;
+    let delta_pct = ((result.add_group.as_nanos() as f64 - result.add_groups.as_nanos() as f64)
+        / result.add_group.as_nanos() as f64)
+        * 100.0;
+
+    println!("=== repeating group building benchmark ===");
+    println!("iterations: {} ({ENTRIES} entries each)", result.iterations);
+    println!(
+        "1) clone + set_field + add_group per entry: {:?} ({:.0} ns/op)",
+        result.add_group, single_ns_per_op
+    );
+    println!(
+        "2) add_groups (single FFI call):            {:?} ({:.0} ns/op)",
+        result.add_groups, bulk_ns_per_op
+    );
+    println!("delta vs #1: {:.2}%", delta_pct);
+
+    Ok(())
+}
@@ -0,0 +1,114 @@
+#![cfg(feature = "tokio")]
+
+use std::time::Duration;
+
+use quickfix::*;
+use tokio::time::timeout;
+use tokio_stream::StreamExt;
+use utils::*;
+
+mod utils;
+
+#[tokio::test]
+async fn test_async_application_stream() -> Result<(), QuickFixError> {
+    let receiver = AsyncApplication::new(64, OverflowPolicy::Block);
+    let mut events = receiver.events().expect("events already taken");
+    assert!(receiver.events().is_none());
+
+    let app_sender = Application::try_new(&NullFixApplication)?;
+    let app_receiver = Application::try_new(&receiver)?;
+    let store_factory_sender = MemoryMessageStoreFactory::new();
+    let store_factory_receiver = MemoryMessageStoreFactory::new();
+
+    let settings_receiver = build_settings(ServerType::Receiver, 0)?;
+    let mut acceptor = Acceptor::try_new(
+        &settings_receiver,
+        &app_receiver,
+        &store_factory_receiver,
+        FixSocketServerKind::SingleThreaded,
+    )?;
+    acceptor.start()?;
+
+    let settings_sender = build_settings(ServerType::Sender, listen_port(&acceptor))?;
+    let mut initiator = Initiator::try_new(
+        &settings_sender,
+        &app_sender,
+        &store_factory_sender,
+        FixSocketServerKind::SingleThreaded,
+    )?;
+    initiator.start()?;
+
+    let mut saw_created = false;
+    let mut saw_logon = false;
+    let news = timeout(Duration::from_secs(10), async {
+        while let Some(event) = events.next().await {
+            match event {
+                FixEvent::Created(session) => {
+                    assert_eq!(
+                        session.to_repr(),
+                        ServerType::Receiver.session_id().to_repr()
+                    );
+                    saw_created = true;
+                }
+                FixEvent::Logon(_) => {
+                    saw_logon = true;
+                    send_to_target(
+                        build_news("Hello", &[]).unwrap(),
+                        &ServerType::Sender.session_id(),
+                    )
+                    .unwrap();
+                }
+                FixEvent::FromApp(session, msg) => return Some((session, msg)),
+                _ => {}
+            }
+        }
+        None
+    })
+    .await
+    .expect("no news received in time")
+    .expect("stream closed");
+
+    assert!(saw_created);
+    assert!(saw_logon);
+    assert_eq!(
+        news.0.to_repr(),
+        ServerType::Receiver.session_id().to_repr()
+    );
+    assert_eq!(news.1.get_field(tags::HEADLINE).as_deref(), Some("Hello"));
+    assert_eq!(receiver.dropped_count(), 0);
+
+    initiator.stop()?;
+    acceptor.stop()?;
+    Ok(())
+}
+
+#[test]
+fn test_async_application_overflow() -> Result<(), QuickFixError> {
+    let session_id = ServerType::Receiver.session_id();
+
+    let dropping = AsyncApplication::new(1, OverflowPolicy::Drop);
+    dropping.on_logon(&session_id);
+    dropping.on_logout(&session_id);
+    assert_eq!(dropping.dropped_count(), 1);
+
+    let erroring = AsyncApplication::new(1, OverflowPolicy::Error);
+    let mut events = erroring.events().unwrap();
+    erroring.on_logon(&session_id);
+    erroring.on_logout(&session_id);
+    erroring.on_logout(&session_id);
+    assert_eq!(erroring.dropped_count(), 2);
+
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+    runtime.block_on(async {
+        assert!(matches!(events.next().await, Some(FixEvent::Logon(_))));
+        // Room is back: consumer is told about lost events.
+        erroring.on_create(&session_id);
+        assert!(matches!(
+            events.next().await,
+            Some(FixEvent::Overflow { dropped: 2 })
+        ));
+    });
+    Ok(())
+}
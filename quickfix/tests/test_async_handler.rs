@@ -0,0 +1,101 @@
+#![cfg(feature = "tokio")]
+
+use std::{sync::Arc, time::Duration};
+
+use quickfix::{
+    async_handler::{AsyncAcceptor, AsyncInitiator},
+    *,
+};
+use tokio::time::timeout;
+use tokio_stream::StreamExt;
+use utils::*;
+
+mod utils;
+
+#[tokio::test]
+async fn test_async_handlers() -> Result<(), QuickFixError> {
+    let receiver = Arc::new(AsyncApplication::new(64, OverflowPolicy::Block));
+    let mut events = receiver.events().expect("events already taken");
+
+    let acceptor = AsyncAcceptor::start(
+        &build_settings(ServerType::Receiver, 0)?,
+        receiver.clone(),
+        Arc::new(MemoryMessageStoreFactory::new()),
+        FixSocketServerKind::SingleThreaded,
+    )
+    .await?;
+    assert_eq!(acceptor.logged_on_count(), 0);
+
+    let initiator = AsyncInitiator::start(
+        &build_settings(ServerType::Sender, listen_port(acceptor.handler()))?,
+        Arc::new(NullFixApplication),
+        Arc::new(MemoryMessageStoreFactory::new()),
+        FixSocketServerKind::SingleThreaded,
+    )
+    .await?;
+
+    initiator.wait_logged_on(Duration::from_secs(10)).await?;
+    acceptor.wait_logged_on(Duration::from_secs(10)).await?;
+    assert_eq!(initiator.logged_on_count(), 1);
+    assert!(initiator.handler().is_logged_on()?);
+
+    initiator
+        .send(build_news("Hello", &[])?, &ServerType::Sender.session_id())
+        .await?;
+    let (session_id, news) = timeout(Duration::from_secs(10), async {
+        while let Some(event) = events.next().await {
+            if let FixEvent::FromApp(session_id, msg) = event {
+                return Some((session_id, msg));
+            }
+        }
+        None
+    })
+    .await
+    .expect("no news received in time")
+    .expect("stream closed");
+    assert_eq!(
+        session_id.to_repr(),
+        ServerType::Receiver.session_id().to_repr()
+    );
+    assert_eq!(news.get_field(tags::HEADLINE).as_deref(), Some("Hello"));
+
+    let report = initiator.shutdown(Duration::from_secs(5)).await?;
+    assert_eq!(report.clean.len(), 1);
+    assert!(report.forced.is_empty());
+
+    acceptor.shutdown(Duration::from_secs(5)).await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_async_handler_logon_timeout() -> Result<(), QuickFixError> {
+    // Nobody is listening on this port.
+    let acceptor = AsyncAcceptor::start(
+        &build_settings(ServerType::Receiver, 0)?,
+        Arc::new(NullFixApplication),
+        Arc::new(MemoryMessageStoreFactory::new()),
+        FixSocketServerKind::SingleThreaded,
+    )
+    .await?;
+    let port = listen_port(acceptor.handler());
+    acceptor.shutdown(Duration::from_secs(1)).await?;
+
+    let initiator = AsyncInitiator::start(
+        &build_settings(ServerType::Sender, port)?,
+        Arc::new(NullFixApplication),
+        Arc::new(MemoryMessageStoreFactory::new()),
+        FixSocketServerKind::SingleThreaded,
+    )
+    .await?;
+
+    let err = initiator
+        .wait_logged_on(Duration::from_millis(200))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, QuickFixError::SessionNotLoggedOn(_)), "{err}");
+    assert_eq!(initiator.logged_on_count(), 0);
+
+    let report = initiator.shutdown(Duration::from_secs(1)).await?;
+    assert!(report.clean.is_empty());
+    Ok(())
+}
@@ -0,0 +1,123 @@
+use std::{
+    sync::Mutex,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use quickfix::{dictionary_item::*, *};
+use utils::*;
+
+mod utils;
+
+/// 2023-11-14 22:13:20.123 UTC.
+fn frozen_time() -> SystemTime {
+    UNIX_EPOCH + Duration::from_millis(1_700_000_000_123)
+}
+
+#[derive(Default)]
+struct RawRecorder(Mutex<Vec<String>>);
+
+impl RawRecorder {
+    fn sent(&self, msg_type: &str) -> Vec<String> {
+        let pattern = format!("\x0135={msg_type}\x01");
+        self.0
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|raw| raw.contains(&pattern))
+            .cloned()
+            .collect()
+    }
+}
+
+impl ApplicationCallback for RawRecorder {
+    fn on_raw_outgoing(&self, _session: &SessionId, raw: &[u8]) {
+        self.0
+            .lock()
+            .unwrap()
+            .push(String::from_utf8_lossy(raw).into_owned());
+    }
+}
+
+#[test]
+fn test_manual_clock() {
+    let clock = ManualClock::new(frozen_time());
+    let other = clock.clone();
+    assert_eq!(clock.now(), frozen_time());
+
+    other.advance(Duration::from_secs(30));
+    assert_eq!(clock.now(), frozen_time() + Duration::from_secs(30));
+
+    clock.set(UNIX_EPOCH);
+    assert_eq!(other.now(), UNIX_EPOCH);
+    assert!(SystemClock.now() > frozen_time());
+}
+
+#[test]
+fn test_clocked_application() -> Result<(), QuickFixError> {
+    let clock = ManualClock::new(frozen_time());
+    let app = ClockedApplication::new(NullFixApplication, clock.clone());
+    let session_id = ServerType::Sender.session_id();
+    let sending_time = |msg: &Message| msg.with_header(|h| h.get_field(tags::SENDING_TIME));
+
+    let mut msg = build_news("Hello", &[])?;
+    app.on_msg_to_app(&mut msg, &session_id).unwrap();
+    assert_eq!(sending_time(&msg).as_deref(), Some("20231114-22:13:20.123"));
+
+    // Leap day.
+    clock.set(UNIX_EPOCH + Duration::from_secs(1_709_164_800));
+    app.on_msg_to_admin(&mut msg, &session_id);
+    assert_eq!(sending_time(&msg).as_deref(), Some("20240229-00:00:00.000"));
+    Ok(())
+}
+
+#[test]
+fn test_frozen_sending_time() -> Result<(), QuickFixError> {
+    let sender = ClockedApplication::new(RawRecorder::default(), ManualClock::new(frozen_time()));
+    let receiver = FixRecorder::new(ServerType::Receiver.session_id());
+
+    // Receiver would reject messages this far from its wall clock otherwise.
+    with_session_pair(&sender, &receiver, &[&CheckLatency(false)], |_, _| {
+        send_to_target(build_news("Hello", &[])?, &ServerType::Sender.session_id())?;
+        wait_until(Duration::from_secs(10), || {
+            receiver.user_msg_count().recv == 1
+        });
+        Ok(())
+    })?;
+
+    let news = sender.inner().sent(msg_types::NEWS);
+    assert_eq!(news.len(), 1);
+    assert!(
+        news[0].contains("\x0152=20231114-22:13:20.123\x01"),
+        "{}",
+        news[0]
+    );
+    assert!(sender.inner().sent(msg_types::LOGON)[0].contains("\x0152=20231114-22:13:20.123\x01"));
+    Ok(())
+}
+
+#[test]
+fn test_heartbeat_from_manual_clock() -> Result<(), QuickFixError> {
+    let clock = ManualClock::new(SystemTime::now());
+    let sender = ClockedApplication::new(RawRecorder::default(), clock.clone());
+    let receiver = RawRecorder::default();
+
+    with_session_pair(&sender, &receiver, &[&CheckLatency(false)], |_, _| {
+        let session = unsafe { Session::lookup(&ServerType::Sender.session_id()) }?;
+        assert!(session.is_session_time_at(clock.now())?);
+
+        // Nothing to do yet.
+        session.tick(clock.now())?;
+        assert!(sender.inner().sent(msg_types::HEARTBEAT).is_empty());
+
+        // Past heartbeat interval (20s), but before any test request.
+        clock.advance(Duration::from_secs(21));
+        session.tick(clock.now())?;
+        wait_until(Duration::from_secs(5), || {
+            !sender.inner().sent(msg_types::HEARTBEAT).is_empty()
+        });
+        Ok(())
+    })?;
+
+    assert!(sender.inner().sent(msg_types::TEST_REQUEST).is_empty());
+    Ok(())
+}
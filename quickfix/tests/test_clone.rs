@@ -35,3 +35,43 @@ fn test_message() {
 fn test_trailer() {
     check(Trailer::new());
 }
+
+#[test]
+fn test_dictionary() {
+    check(Dictionary::new());
+
+    let mut dict1 = Dictionary::new();
+    dict1.set("foo", "original").unwrap();
+
+    let mut dict2 = dict1.clone();
+    dict2.set("foo", "updated").unwrap();
+    dict2.set("bar", 42).unwrap();
+
+    assert_eq!(dict1.get("foo"), Ok("original".to_string()));
+    assert_eq!(dict1.contains("bar"), Ok(false));
+    drop(dict1);
+    assert_eq!(dict2.get("foo"), Ok("updated".to_string()));
+}
+
+#[test]
+fn test_session_settings() {
+    check(SessionSettings::new());
+
+    let session_id = SessionId::try_new("FIX.4.1", "TW", "ARCA1", "").unwrap();
+    let settings1 = SessionSettings::try_from_path("../configs/settings.ini").unwrap();
+
+    let mut settings2 = settings1.clone();
+    let mut dict = settings2.get(&session_id).unwrap();
+    dict.set("SocketAcceptPort", 5000).unwrap();
+    settings2.set(Some(&session_id), dict).unwrap();
+
+    assert_eq!(
+        settings1.get(&session_id).unwrap().get("SocketAcceptPort"),
+        Ok(4000)
+    );
+    drop(settings1);
+    assert_eq!(
+        settings2.get(&session_id).unwrap().get("SocketAcceptPort"),
+        Ok(5000)
+    );
+}
@@ -26,3 +26,136 @@ fn test_build_message() {
     let msg = dd.try_build_message("8=FIX.4.1\u{1}9=65\u{1}35=A\u{1}34=1\u{1}49=SERVER1\u{1}52=20231115-14:02:24\u{1}56=CLIENT1\u{1}98=0\u{1}108=20\u{1}10=035\u{1}").unwrap();
     assert_eq!(msg.to_fix_string().as_deref(), Ok("8=FIX.4.1\u{1}9=65\u{1}35=A\u{1}34=1\u{1}49=SERVER1\u{1}52=20231115-14:02:24\u{1}56=CLIENT1\u{1}98=0\u{1}108=20\u{1}10=035\u{1}"))
 }
+
+#[test]
+fn test_field_metadata() {
+    let dd = DataDictionary::try_from_path("../quickfix-ffi/libquickfix/spec/FIX44.xml").unwrap();
+
+    assert_eq!(dd.field_name(54).as_deref(), Some("Side"));
+    assert_eq!(dd.field_name(11).as_deref(), Some("ClOrdID"));
+    assert_eq!(dd.field_name(99999), None);
+
+    assert_eq!(dd.field_tag("Side"), Some(54));
+    assert_eq!(dd.field_tag("NoPartyIDs"), Some(453));
+    assert_eq!(dd.field_tag("NotAField"), None);
+    assert_eq!(dd.field_tag("in\0valid"), None);
+
+    assert_eq!(dd.field_type(54), Some(FixFieldType::Char));
+    assert_eq!(dd.field_type(38), Some(FixFieldType::Quantity));
+    assert_eq!(dd.field_type(44), Some(FixFieldType::Price));
+    assert_eq!(dd.field_type(52), Some(FixFieldType::UtcTimeStamp));
+    assert_eq!(dd.field_type(453), Some(FixFieldType::NumberInGroup));
+    assert_eq!(dd.field_type(99999), None);
+
+    assert_eq!(dd.value_name(54, "1").as_deref(), Some("BUY"));
+    assert_eq!(dd.value_name(54, "2").as_deref(), Some("SELL"));
+    assert_eq!(dd.value_name(54, "invalid"), None);
+    assert_eq!(dd.value_name(11, "1"), None);
+}
+
+fn build_new_order_single(body: &str) -> Message {
+    let text = format!(
+        "8=FIX.4.4\u{1}9=0\u{1}35=D\u{1}34=1\u{1}49=ME\u{1}52=20240101-10:00:00\u{1}56=THEM\u{1}\
+         {body}10=000\u{1}"
+    );
+    Message::try_from_text(&text).unwrap()
+}
+
+#[test]
+fn test_validate() {
+    let dd = DataDictionary::try_from_path("../quickfix-ffi/libquickfix/spec/FIX44.xml").unwrap();
+
+    // Valid message.
+    let msg = build_new_order_single(
+        "11=ID1\u{1}55=EUR/USD\u{1}54=1\u{1}60=20240101-10:00:00\u{1}38=100\u{1}40=1\u{1}",
+    );
+    assert_eq!(dd.validate(&msg), Ok(()));
+
+    // Missing ClOrdID.
+    let msg = build_new_order_single(
+        "55=EUR/USD\u{1}54=1\u{1}60=20240101-10:00:00\u{1}38=100\u{1}40=1\u{1}",
+    );
+    assert_eq!(
+        dd.validate(&msg),
+        Err(ValidationError::RequiredTagMissing { tag: 11 })
+    );
+
+    // HeartBtInt is not part of NewOrderSingle.
+    let msg = build_new_order_single(
+        "11=ID1\u{1}55=EUR/USD\u{1}54=1\u{1}60=20240101-10:00:00\u{1}38=100\u{1}40=1\u{1}108=30\u{1}",
+    );
+    assert_eq!(
+        dd.validate(&msg),
+        Err(ValidationError::TagNotDefinedForMessage { tag: 108 })
+    );
+
+    // Unknown Side value.
+    let msg = build_new_order_single(
+        "11=ID1\u{1}55=EUR/USD\u{1}54=Z\u{1}60=20240101-10:00:00\u{1}38=100\u{1}40=1\u{1}",
+    );
+    assert_eq!(
+        dd.validate(&msg),
+        Err(ValidationError::IncorrectTagValue { tag: 54 })
+    );
+
+    // OrderQty is not a number.
+    let msg = build_new_order_single(
+        "11=ID1\u{1}55=EUR/USD\u{1}54=1\u{1}60=20240101-10:00:00\u{1}38=abc\u{1}40=1\u{1}",
+    );
+    assert_eq!(
+        dd.validate(&msg),
+        Err(ValidationError::IncorrectDataFormat { tag: 38 })
+    );
+
+    // Wrong FIX version.
+    let msg = Message::try_from_text(
+        "8=FIX.4.2\u{1}9=0\u{1}35=0\u{1}34=1\u{1}49=ME\u{1}52=20240101-10:00:00\u{1}56=THEM\u{1}10=000\u{1}",
+    )
+    .unwrap();
+    assert_eq!(dd.validate(&msg), Err(ValidationError::UnsupportedVersion));
+}
+
+#[test]
+fn test_message_fields_and_groups() {
+    let dd = DataDictionary::try_from_path("../quickfix-ffi/libquickfix/spec/FIX44.xml").unwrap();
+
+    let fields = dd.message_fields("D");
+    let find_field = |tag| fields.iter().find(|field| field.tag == tag).cloned();
+    assert_eq!(
+        find_field(11),
+        Some(FieldSpec {
+            tag: 11,
+            name: "ClOrdID".to_string(),
+            field_type: FixFieldType::String,
+            required: true,
+        })
+    );
+    assert_eq!(find_field(54).map(|field| field.required), Some(true));
+    assert_eq!(find_field(58).map(|field| field.required), Some(false));
+    assert_eq!(
+        find_field(453).map(|field| field.field_type),
+        Some(FixFieldType::NumberInGroup)
+    );
+    assert_eq!(find_field(108), None);
+
+    // Parties group.
+    let groups = dd.message_groups("D");
+    let parties = groups
+        .iter()
+        .find(|group| group.count_field.tag == 453)
+        .expect("Missing Parties group");
+    assert_eq!(parties.count_field.name, "NoPartyIDs");
+    assert_eq!(parties.delimiter, 448);
+    let party_tags: Vec<_> = parties.fields.iter().map(|field| field.tag).collect();
+    assert!(party_tags.contains(&448));
+    assert!(party_tags.contains(&447));
+    assert!(party_tags.contains(&452));
+    assert!(parties
+        .groups
+        .iter()
+        .any(|group| group.count_field.name == "NoPartySubIDs"));
+
+    // Unknown message type.
+    assert!(dd.message_fields("unknown").is_empty());
+    assert!(dd.message_groups("unknown").is_empty());
+}
@@ -4,10 +4,17 @@ use quickfix::*;
 fn test_application() {
     let obj = MsgToAppError::DoNotSend;
     assert_eq!(format!("{obj:?}"), "DoNotSend");
-    let obj = MsgFromAdminError::RejectLogon;
-    assert_eq!(format!("{obj:?}"), "RejectLogon");
+    let obj = MsgFromAdminError::RejectLogon {
+        reason: "Bad password".to_string(),
+    };
+    assert_eq!(
+        format!("{obj:?}"),
+        r#"RejectLogon { reason: "Bad password" }"#
+    );
     let obj = MsgFromAppError::UnsupportedMessageType;
     assert_eq!(format!("{obj:?}"), "UnsupportedMessageType");
+    let obj = MsgFromAppError::FieldNotFound { tag: 55 };
+    assert_eq!(format!("{obj:?}"), "FieldNotFound { tag: 55 }");
 }
 
 #[test]
@@ -44,6 +51,9 @@ fn test_group() {
 fn test_log_factory() {
     let obj = LogFactory::try_new(&NullLogger).unwrap();
     assert_eq!(format!("{obj:?}"), "LogFactory");
+
+    let obj = PerSessionLogFactory::try_new(|_| Box::new(NullLogger)).unwrap();
+    assert_eq!(format!("{obj:?}"), "PerSessionLogFactory");
 }
 
 #[test]
@@ -60,6 +70,8 @@ fn test_logger() {
     {
         let obj = RustLogger;
         assert_eq!(format!("{obj:?}"), "RustLogger");
+        let obj = LogCrateLogger;
+        assert_eq!(format!("{obj:?}"), "LogCrateLogger");
     }
 }
 
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use quickfix::*;
 
 #[test]
@@ -161,3 +163,184 @@ fn test_contains() {
     dict.set("foo", "bar".to_string()).unwrap();
     assert!(dict.contains("foo").unwrap());
 }
+
+#[test]
+fn test_typed_getters() {
+    let mut dict = Dictionary::with_name("HELLO").unwrap();
+    dict.set("int", "42").unwrap();
+    dict.set("double", "-51.23").unwrap();
+    dict.set("bool", "Y").unwrap();
+    dict.set("StartDay", "Monday").unwrap();
+    dict.set("malformed", "maybe").unwrap();
+    dict.set("short", "M").unwrap();
+
+    assert_eq!(dict.get_int("int"), Ok(42));
+    assert_eq!(dict.get_double("double"), Ok(-51.23));
+    assert_eq!(dict.get_bool("bool"), Ok(true));
+    assert_eq!(dict.get_day("StartDay"), Ok(DayOfWeek::Monday));
+
+    // Key exists with invalid value.
+    assert!(matches!(
+        dict.get_int("malformed"),
+        Err(QuickFixError::FieldConvertError { .. })
+    ));
+    assert!(matches!(
+        dict.get_double("malformed"),
+        Err(QuickFixError::FieldConvertError { .. })
+    ));
+    assert!(matches!(
+        dict.get_bool("malformed"),
+        Err(QuickFixError::FieldConvertError { .. })
+    ));
+    assert!(matches!(
+        dict.get_day("malformed"),
+        Err(QuickFixError::FieldConvertError { .. })
+    ));
+    assert!(matches!(
+        dict.get_day("short"),
+        Err(QuickFixError::FieldConvertError { .. })
+    ));
+
+    // Missing key.
+    assert_eq!(
+        dict.get_int("invalid"),
+        Err(QuickFixError::ConfigError(
+            "invalid not defined".to_string()
+        ))
+    );
+    assert!(matches!(
+        dict.get_double("invalid"),
+        Err(QuickFixError::ConfigError(_))
+    ));
+    assert!(matches!(
+        dict.get_bool("invalid"),
+        Err(QuickFixError::ConfigError(_))
+    ));
+    assert!(matches!(
+        dict.get_day("invalid"),
+        Err(QuickFixError::ConfigError(_))
+    ));
+}
+
+#[test]
+fn test_iter() {
+    let mut dict = Dictionary::new();
+    assert_eq!(dict.iter().count(), 0);
+
+    dict.set("STRING", "hello".to_string()).unwrap();
+    dict.set("INT", 42).unwrap();
+    dict.set("BOOL", true).unwrap();
+    dict.set("DAY", DayOfWeek::Friday).unwrap();
+    dict.set("CUSTOMKEY", "custom value").unwrap();
+
+    let content: HashMap<_, _> = dict.iter().collect::<Result<_, _>>().unwrap();
+    assert_eq!(
+        content,
+        HashMap::from([
+            ("STRING".to_string(), "hello".to_string()),
+            ("INT".to_string(), "42".to_string()),
+            ("BOOL".to_string(), "Y".to_string()),
+            ("DAY".to_string(), dict.get::<String>("DAY").unwrap()),
+            ("CUSTOMKEY".to_string(), "custom value".to_string()),
+        ])
+    );
+
+    // Iterator reads a copy, made before following changes.
+    let mut iter = dict.iter();
+    dict.set("LATER", "not visible").unwrap();
+    drop(dict);
+    assert_eq!(iter.by_ref().count(), 5);
+    assert!(iter.next().is_none());
+}
+
+fn build_dict(values: &[(&str, &str)]) -> Dictionary {
+    let mut dict = Dictionary::new();
+    for (key, value) in values {
+        dict.set(key, *value).unwrap();
+    }
+    dict
+}
+
+#[test]
+fn test_merge_missing_from() {
+    let defaults = build_dict(&[("A", "default a"), ("B", "default b")]);
+
+    // Overlapping keys.
+    let mut dict = build_dict(&[("A", "session a"), ("C", "session c")]);
+    dict.merge_missing_from(&defaults).unwrap();
+    assert_eq!(
+        dict.iter().collect::<Result<HashMap<_, _>, _>>().unwrap(),
+        HashMap::from([
+            ("A".to_string(), "session a".to_string()),
+            ("B".to_string(), "default b".to_string()),
+            ("C".to_string(), "session c".to_string()),
+        ])
+    );
+
+    // Other direction.
+    let mut dict2 = build_dict(&[("A", "session a"), ("C", "session c")]);
+    let mut defaults2 = build_dict(&[("A", "default a"), ("B", "default b")]);
+    defaults2.merge_missing_from(&dict2).unwrap();
+    assert_eq!(
+        defaults2
+            .iter()
+            .collect::<Result<HashMap<_, _>, _>>()
+            .unwrap(),
+        HashMap::from([
+            ("A".to_string(), "default a".to_string()),
+            ("B".to_string(), "default b".to_string()),
+            ("C".to_string(), "session c".to_string()),
+        ])
+    );
+
+    // Disjoint keys.
+    dict2
+        .merge_missing_from(&build_dict(&[("D", "d")]))
+        .unwrap();
+    assert_eq!(dict2.iter().count(), 3);
+    assert_eq!(dict2.get("D"), Ok("d".to_string()));
+}
+
+#[test]
+fn test_merge_overwrite() {
+    let overrides = build_dict(&[("A", "override a"), ("B", "override b")]);
+
+    // Overlapping keys.
+    let mut dict = build_dict(&[("A", "session a"), ("C", "session c")]);
+    dict.merge_overwrite(&overrides).unwrap();
+    assert_eq!(
+        dict.iter().collect::<Result<HashMap<_, _>, _>>().unwrap(),
+        HashMap::from([
+            ("A".to_string(), "override a".to_string()),
+            ("B".to_string(), "override b".to_string()),
+            ("C".to_string(), "session c".to_string()),
+        ])
+    );
+
+    // Other direction.
+    let dict2 = build_dict(&[("A", "session a"), ("C", "session c")]);
+    let mut overrides2 = build_dict(&[("A", "override a"), ("B", "override b")]);
+    overrides2.merge_overwrite(&dict2).unwrap();
+    assert_eq!(
+        overrides2
+            .iter()
+            .collect::<Result<HashMap<_, _>, _>>()
+            .unwrap(),
+        HashMap::from([
+            ("A".to_string(), "session a".to_string()),
+            ("B".to_string(), "override b".to_string()),
+            ("C".to_string(), "session c".to_string()),
+        ])
+    );
+
+    // Disjoint keys.
+    let mut dict3 = build_dict(&[("E", "e")]);
+    dict3.merge_overwrite(&build_dict(&[("D", "d")])).unwrap();
+    assert_eq!(
+        dict3.iter().collect::<Result<HashMap<_, _>, _>>().unwrap(),
+        HashMap::from([
+            ("D".to_string(), "d".to_string()),
+            ("E".to_string(), "e".to_string()),
+        ])
+    );
+}
@@ -10,6 +10,8 @@ fn test_build() {
         &SocketConnectHost("10.8.0.3"),
         &SocketConnectSourcePort(69),
         &SocketConnectSourceHost("10.8.0.4"),
+        &SocketAcceptPath("/tmp/accept.sock"),
+        &SocketConnectPath("/tmp/connect.sock"),
         &SocketReuseAddress(true),
         &SocketNodelay(false),
         &SocketSendBufferSize(4096),
@@ -33,8 +35,21 @@ fn test_build() {
         &UseDataDictionary(true),
         &DataDictionary("foo/FIX50.xml"),
         &TransportDataDictionary("bar/FIXT11.xml"),
+        &AppDataDictionary("bar/FIX50SP2.xml"),
         &FileStorePath("my_store"),
         &FileStoreSync(false),
+        &PostgreSQLStoreDatabase("quickfix"),
+        &PostgreSQLStoreUser("fix"),
+        &PostgreSQLStorePassword("secret"),
+        &PostgreSQLStoreHost("db"),
+        &PostgreSQLStorePort(5433),
+        &PostgreSQLStoreUseConnectionPool(true),
+        &MySQLStoreDatabase("quickfix"),
+        &MySQLStoreUser("fix"),
+        &MySQLStorePassword("secret"),
+        &MySQLStoreHost("db"),
+        &MySQLStorePort(3307),
+        &MySQLStoreUseConnectionPool(true),
         &CheckCompID(true),
         &CheckLatency(false),
         &MaxLatency(-20),
@@ -79,6 +94,14 @@ fn test_build() {
         dict.get::<String>("SocketConnectSourceHost").as_deref(),
         Ok("10.8.0.4")
     );
+    assert_eq!(
+        dict.get::<String>("SocketAcceptPath").as_deref(),
+        Ok("/tmp/accept.sock")
+    );
+    assert_eq!(
+        dict.get::<String>("SocketConnectPath").as_deref(),
+        Ok("/tmp/connect.sock")
+    );
     assert_eq!(dict.get("SocketReuseAddress"), Ok(true));
     assert_eq!(dict.get("SocketNodelay"), Ok(false));
     assert_eq!(dict.get("SocketSendBufferSize"), Ok(4096));
@@ -113,11 +136,45 @@ fn test_build() {
         dict.get::<String>("TransportDataDictionary").as_deref(),
         Ok("bar/FIXT11.xml")
     );
+    assert_eq!(
+        dict.get::<String>("AppDataDictionary").as_deref(),
+        Ok("bar/FIX50SP2.xml")
+    );
     assert_eq!(
         dict.get::<String>("FileStorePath").as_deref(),
         Ok("my_store")
     );
     assert_eq!(dict.get("FileStoreSync"), Ok(false));
+    assert_eq!(
+        dict.get::<String>("PostgreSQLStoreDatabase").as_deref(),
+        Ok("quickfix")
+    );
+    assert_eq!(
+        dict.get::<String>("PostgreSQLStoreUser").as_deref(),
+        Ok("fix")
+    );
+    assert_eq!(
+        dict.get::<String>("PostgreSQLStorePassword").as_deref(),
+        Ok("secret")
+    );
+    assert_eq!(
+        dict.get::<String>("PostgreSQLStoreHost").as_deref(),
+        Ok("db")
+    );
+    assert_eq!(dict.get("PostgreSQLStorePort"), Ok(5433));
+    assert_eq!(dict.get("PostgreSQLStoreUseConnectionPool"), Ok(true));
+    assert_eq!(
+        dict.get::<String>("MySQLStoreDatabase").as_deref(),
+        Ok("quickfix")
+    );
+    assert_eq!(dict.get::<String>("MySQLStoreUser").as_deref(), Ok("fix"));
+    assert_eq!(
+        dict.get::<String>("MySQLStorePassword").as_deref(),
+        Ok("secret")
+    );
+    assert_eq!(dict.get::<String>("MySQLStoreHost").as_deref(), Ok("db"));
+    assert_eq!(dict.get("MySQLStorePort"), Ok(3307));
+    assert_eq!(dict.get("MySQLStoreUseConnectionPool"), Ok(true));
     assert_eq!(dict.get::<String>("foo").as_deref(), Ok("bar"));
 
     assert_eq!(dict.get("CheckCompID"), Ok(true));
@@ -141,3 +198,43 @@ fn test_build() {
     assert_eq!(dict.get("PersistMessages"), Ok(false));
     assert_eq!(dict.get("PersistIncomingMessages"), Ok(true));
 }
+
+#[test]
+#[cfg(quickfix_ssl)]
+fn test_build_ssl() {
+    let dict = Dictionary::try_from_items(&[
+        &CertificationAuthoritiesFile("certs/ca.pem"),
+        &CertificationAuthoritiesDirectory("certs/"),
+        &CertificateRevocationListFile("certs/crl.pem"),
+        &CertificateRevocationListDirectory("crl/"),
+        &SSLCipherSuite("HIGH:!aNULL"),
+        &CertificateVerifyLevel::Require,
+    ])
+    .unwrap();
+
+    assert_eq!(
+        dict.get::<String>("CertificationAuthoritiesFile")
+            .as_deref(),
+        Ok("certs/ca.pem")
+    );
+    assert_eq!(
+        dict.get::<String>("CertificationAuthoritiesDirectory")
+            .as_deref(),
+        Ok("certs/")
+    );
+    assert_eq!(
+        dict.get::<String>("CertificateRevocationListFile")
+            .as_deref(),
+        Ok("certs/crl.pem")
+    );
+    assert_eq!(
+        dict.get::<String>("CertificateRevocationListDirectory")
+            .as_deref(),
+        Ok("crl/")
+    );
+    assert_eq!(
+        dict.get::<String>("SSLCipherSuite").as_deref(),
+        Ok("HIGH:!aNULL")
+    );
+    assert_eq!(dict.get("CertificateVerifyLevel"), Ok(2));
+}
@@ -1,4 +1,7 @@
-use quickfix::*;
+use quickfix::{
+    dictionary_item::{DictionaryItem, StartTime},
+    *,
+};
 
 #[test]
 fn test_derive() {
@@ -42,3 +45,343 @@ fn test_null() {
         )
     );
 }
+
+#[test]
+fn test_classified_errors() {
+    // Missing field.
+    let msg = Message::new();
+    assert_eq!(msg.get_field(9999), None);
+    let error = QuickFixError::from_last_error();
+    assert!(
+        matches!(error, QuickFixError::FieldNotFound { tag: 9999, .. }),
+        "{error:?}"
+    );
+    assert!(error.to_string().starts_with("quickfix: Field not found"));
+
+    // Bad conversion.
+    let mut dict = Dictionary::new();
+    dict.set("Count", "twelve").unwrap();
+    match dict.get_int("Count") {
+        Err(QuickFixError::FieldConvertError { tag, value, .. }) => {
+            assert_eq!(tag, None);
+            assert_eq!(value, "twelve");
+        }
+        other => panic!("Unexpected result: {other:?}"),
+    }
+
+    // Unknown session.
+    let session_id = SessionId::try_new("FIX.4.4", "NOBODY", "NOWHERE", "").unwrap();
+    assert!(matches!(
+        send_to_target(Message::new(), &session_id),
+        Err(QuickFixError::SessionNotFound(_))
+    ));
+
+    // Bad settings.
+    assert!(matches!(
+        SessionSettingsBuilder::new()
+            .session(session_id, |dict| StartTime("00:00:00").apply_param(dict))
+            .build(),
+        Err(QuickFixError::ConfigError(_))
+    ));
+}
+
+#[test]
+fn test_field_error_context() {
+    let mut msg = Message::new();
+    msg.set_field(38, "12").unwrap();
+    msg.set_field(44, "1O.5").unwrap();
+    assert_eq!(msg.get_field_as::<u32>(38), Ok(12));
+
+    // Missing field.
+    let error = msg.get_field_as::<u32>(55).unwrap_err();
+    assert_eq!(error, QuickFixError::field_not_found(55));
+    assert_eq!(error.tag(), Some(55));
+    assert_eq!(error.value(), None);
+    assert_eq!(error.to_string(), "quickfix: Field not found (tag 55)");
+
+    // Parse failure.
+    let error = msg.get_field_as::<f64>(44).unwrap_err();
+    assert_eq!(error.tag(), Some(44));
+    assert_eq!(error.value(), Some("1O.5"));
+    assert_eq!(
+        error.to_string(),
+        "quickfix: Could not convert field (tag 44, value \"1O.5\")"
+    );
+
+    // Same from C++ getters.
+    assert_eq!(msg.get_field(9999), None);
+    assert_eq!(QuickFixError::from_last_error().tag(), Some(9999));
+
+    // Tag is not known for settings conversion.
+    let mut dict = Dictionary::new();
+    dict.set("Count", "twelve").unwrap();
+    let error = dict.get_int("Count").unwrap_err();
+    assert_eq!(error.tag(), None);
+    assert_eq!(error.value(), Some("twelve"));
+    assert!(error.to_string().ends_with("(value \"twelve\")"), "{error}");
+
+    assert_eq!(QuickFixError::invalid_argument("oops").tag(), None);
+}
+
+#[test]
+fn test_invalid_utf8() {
+    // Latin-1 "café".
+    let latin1 = b"caf\xe9".to_vec();
+    let invalid_utf8 = |tag| QuickFixError::InvalidUtf8 {
+        tag,
+        bytes: latin1.clone(),
+    };
+
+    // Bytes based setter.
+    let mut msg = Message::new();
+    msg.set_field(58, &latin1[..]).unwrap();
+    assert_eq!(msg.get_field_bytes(58), Some(latin1.clone()));
+    assert_eq!(msg.try_get_field(58), Err(invalid_utf8(Some(58))));
+    assert_eq!(msg.get_field_as::<String>(58), Err(invalid_utf8(Some(58))));
+    assert_eq!(msg.get_field(58), None);
+    assert_eq!(msg.get_field_lossy(58).as_deref(), Some("caf\u{FFFD}"));
+    assert_eq!(msg.try_get_field(59), Ok(None));
+
+    let err = msg.try_get_field(58).unwrap_err();
+    assert_eq!(err.tag(), Some(58));
+    assert_eq!(err.bytes(), Some(&latin1[..]));
+    assert_eq!(err.to_string(), "Invalid UTF-8: 4 bytes (tag 58)");
+
+    // Whole message.
+    let raw = msg.to_fix_bytes().unwrap();
+    assert!(raw.windows(latin1.len()).any(|window| window == latin1));
+    match msg.to_fix_string() {
+        Err(QuickFixError::InvalidUtf8 { tag: None, bytes }) => assert_eq!(bytes, raw),
+        other => panic!("Unexpected result: {other:?}"),
+    }
+    assert!(msg
+        .to_fix_string_lossy()
+        .unwrap()
+        .contains("58=caf\u{FFFD}\x01"));
+    assert!(matches!(
+        msg.to_fix_str(),
+        Err(QuickFixError::InvalidUtf8 { tag: None, .. })
+    ));
+
+    // Bytes based parser.
+    let parsed = Message::try_from_bytes(b"9=0\x0158=caf\xe9\x0110=000\x01").unwrap();
+    assert_eq!(parsed.try_get_field(58), Err(invalid_utf8(Some(58))));
+    assert_eq!(
+        parsed.with_header(|header| header.try_get_field(9)),
+        Ok(Some("0".to_string()))
+    );
+}
+
+#[test]
+fn test_session_context() {
+    let session_id = SessionId::try_new("FIX.4.4", "SENDER", "TARGET", "").unwrap();
+
+    fn read_price(msg: &Message, session_id: &SessionId) -> Result<f64, QuickFixError> {
+        msg.get_field_as(44)
+            .map_err(|err| err.with_session(session_id))
+    }
+    fn handle(msg: &Message, session_id: &SessionId) -> Result<f64, QuickFixError> {
+        let price = read_price(msg, session_id)?;
+        Ok(price * 2.0)
+    }
+
+    // Context survives propagation.
+    let err = handle(&Message::new(), &session_id).unwrap_err();
+    assert_eq!(err.session_id(), Some("FIX.4.4:SENDER->TARGET"));
+    assert_eq!(err.tag(), Some(44));
+    assert_eq!(
+        err.to_string(),
+        "quickfix: Field not found (tag 44) [FIX.4.4:SENDER->TARGET]"
+    );
+    assert_eq!(err.without_session(), &QuickFixError::field_not_found(44));
+    assert_eq!(
+        err.into_without_session(),
+        QuickFixError::field_not_found(44)
+    );
+
+    // First session is kept.
+    let other_session_id = SessionId::try_new("FIX.4.4", "OTHER", "TARGET", "").unwrap();
+    let err = QuickFixError::invalid_argument("Bad")
+        .with_session(&session_id)
+        .with_session(&other_session_id);
+    assert_eq!(err.session_id(), Some("FIX.4.4:SENDER->TARGET"));
+    assert_eq!(
+        err.to_string(),
+        "Invalid argument: Bad [FIX.4.4:SENDER->TARGET]"
+    );
+
+    // Session not found already describes session.
+    let err = send_to_target(Message::new(), &session_id).unwrap_err();
+    assert!(matches!(err, QuickFixError::SessionNotFound(_)));
+    assert_eq!(err.session_id(), None);
+}
+
+#[test]
+fn test_error_kind() {
+    let msg = || "oops".to_string();
+    let cases = [
+        (
+            QuickFixError::InvalidFunctionReturnCode(-1, msg()),
+            ErrorKind::Internal,
+        ),
+        (
+            QuickFixError::CppException {
+                exception_type: "std::out_of_range".to_string(),
+                msg: msg(),
+            },
+            ErrorKind::Internal,
+        ),
+        (
+            QuickFixError::InvalidArgument(msg()),
+            ErrorKind::InvalidArgument,
+        ),
+        (
+            QuickFixError::DataDictionaryNotFound(msg()),
+            ErrorKind::Config,
+        ),
+        (QuickFixError::field_not_found(55), ErrorKind::Conversion),
+        (
+            QuickFixError::field_convert_error(44, "abc"),
+            ErrorKind::Conversion,
+        ),
+        (
+            QuickFixError::InvalidUtf8 {
+                tag: Some(58),
+                bytes: vec![0xe9],
+            },
+            ErrorKind::Conversion,
+        ),
+        (
+            QuickFixError::MessageParseError(msg()),
+            ErrorKind::InvalidMessage,
+        ),
+        (
+            QuickFixError::InvalidMessage(msg()),
+            ErrorKind::InvalidMessage,
+        ),
+        (QuickFixError::ConfigError(msg()), ErrorKind::Config),
+        (QuickFixError::RuntimeError(msg()), ErrorKind::Internal),
+        (
+            QuickFixError::InvalidTagNumber(msg()),
+            ErrorKind::InvalidMessage,
+        ),
+        (
+            QuickFixError::RequiredTagMissing(msg()),
+            ErrorKind::InvalidMessage,
+        ),
+        (
+            QuickFixError::TagNotDefinedForMessage(msg()),
+            ErrorKind::InvalidMessage,
+        ),
+        (QuickFixError::NoTagValue(msg()), ErrorKind::InvalidMessage),
+        (
+            QuickFixError::IncorrectTagValue {
+                tag: 54,
+                msg: msg(),
+            },
+            ErrorKind::InvalidMessage,
+        ),
+        (
+            QuickFixError::IncorrectDataFormat(msg()),
+            ErrorKind::InvalidMessage,
+        ),
+        (
+            QuickFixError::IncorrectMessageStructure(msg()),
+            ErrorKind::InvalidMessage,
+        ),
+        (
+            QuickFixError::DuplicateFieldNumber(msg()),
+            ErrorKind::InvalidMessage,
+        ),
+        (
+            QuickFixError::InvalidMessageType(msg()),
+            ErrorKind::InvalidMessage,
+        ),
+        (
+            QuickFixError::UnsupportedMessageType(msg()),
+            ErrorKind::InvalidMessage,
+        ),
+        (
+            QuickFixError::UnsupportedVersion(msg()),
+            ErrorKind::InvalidMessage,
+        ),
+        (
+            QuickFixError::TagOutOfOrder(msg()),
+            ErrorKind::InvalidMessage,
+        ),
+        (QuickFixError::RepeatedTag(msg()), ErrorKind::InvalidMessage),
+        (
+            QuickFixError::RepeatingGroupCountMismatch(msg()),
+            ErrorKind::InvalidMessage,
+        ),
+        (QuickFixError::DoNotSend(msg()), ErrorKind::Rejected),
+        (QuickFixError::RejectLogon(msg()), ErrorKind::Rejected),
+        (QuickFixError::SessionNotFound(msg()), ErrorKind::Session),
+        (QuickFixError::IOException(msg()), ErrorKind::Io),
+        (QuickFixError::SocketException(msg()), ErrorKind::Io),
+        (QuickFixError::SocketSendFailed(msg()), ErrorKind::Io),
+        (QuickFixError::SocketRecvFailed(msg()), ErrorKind::Io),
+        (QuickFixError::SocketCloseFailed(msg()), ErrorKind::Io),
+        (QuickFixError::SessionNotLoggedOn(msg()), ErrorKind::Session),
+        (
+            QuickFixError::MessageTooLarge { len: 2, max: 1 },
+            ErrorKind::InvalidMessage,
+        ),
+        (
+            QuickFixError::FieldTooLarge {
+                tag: Some(58),
+                len: 2,
+                max: 1,
+            },
+            ErrorKind::InvalidMessage,
+        ),
+        (
+            QuickFixError::TooManyGroups {
+                tag: 453,
+                count: 2,
+                max: 1,
+            },
+            ErrorKind::InvalidMessage,
+        ),
+    ];
+
+    let session_id = SessionId::try_new("FIX.4.4", "SENDER", "TARGET", "").unwrap();
+    for (err, kind) in cases {
+        let retryable = matches!(kind, ErrorKind::Session | ErrorKind::Io);
+        assert_eq!(err.kind(), kind, "{err:?}");
+        assert_eq!(err.is_retryable(), retryable, "{err:?}");
+
+        // Session context does not change classification.
+        let err = err.with_session(&session_id);
+        assert_eq!(err.kind(), kind, "{err:?}");
+        assert_eq!(err.is_retryable(), retryable, "{err:?}");
+    }
+}
+
+#[test]
+fn test_exception_type() {
+    // FIX::ConfigError
+    let err = Dictionary::new().get_int("Missing").unwrap_err();
+    assert!(matches!(err, QuickFixError::ConfigError(_)));
+    assert_eq!(err.exception_type(), Some("FIX::ConfigError"));
+
+    // FIX::FieldNotFound
+    assert_eq!(Message::new().get_field(9999), None);
+    let err = QuickFixError::from_last_error();
+    assert!(matches!(err, QuickFixError::FieldNotFound { .. }));
+    assert_eq!(err.exception_type(), Some("FIX::FieldNotFound"));
+
+    // Unclassified exceptions keep their dynamic type.
+    let err = QuickFixError::CppException {
+        exception_type: "std::out_of_range".to_string(),
+        msg: "map::at".to_string(),
+    };
+    assert_eq!(err.exception_type(), Some("std::out_of_range"));
+    assert_eq!(err.to_string(), "std::out_of_range: map::at");
+
+    // Errors built in Rust.
+    assert_eq!(
+        QuickFixError::invalid_argument("Bad").exception_type(),
+        None
+    );
+}
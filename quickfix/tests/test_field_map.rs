@@ -48,6 +48,19 @@ fn check_field_map<T: FieldMap>(mut item: T) {
     assert_eq!(item.get_field(FIELD_ID).as_deref(), Some("bar"));
     assert!(item.is_field_equal(FIELD_ID, "bar"));
 
+    // Set raw values, even those a C string cannot hold
+    item.set_field_str(FIELD_ID, "baz").unwrap();
+    assert_eq!(item.get_field(FIELD_ID).as_deref(), Some("baz"));
+    item.set_field_raw(FIELD_ID, b"a\0b").unwrap();
+    assert_eq!(
+        item.get_field_bytes(FIELD_ID).as_deref(),
+        Some(&b"a\0b"[..])
+    );
+    assert!(item.is_field_equal(FIELD_ID, "a\0b"));
+    item.set_field_raw(FIELD_ID, b"").unwrap();
+    assert_eq!(item.get_field(FIELD_ID).as_deref(), Some(""));
+    item.set_field(FIELD_ID, "bar").unwrap();
+
     // Remove and check
     item.remove_field(FIELD_ID).unwrap();
     assert_eq!(item.get_field(FIELD_ID), None);
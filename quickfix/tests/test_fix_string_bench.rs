@@ -4,7 +4,7 @@ use std::{
     time::{Duration, Instant},
 };
 
-use quickfix::{FieldMap, Group, Message, QuickFixError};
+use quickfix::{DataDictionary, FieldMap, Group, Message, QuickFixError};
 
 static GLOBAL_LOCK: Mutex<u8> = Mutex::new(0);
 
@@ -14,8 +14,11 @@ const BENCH_ITERS: usize = 20_000;
 #[derive(Debug)]
 struct BenchResult {
     iterations: usize,
+    build_set_field: Duration,
+    build_set_field_raw: Duration,
     to_fix_string: Duration,
     to_fix_str: Duration,
+    to_fix_bytes_ref: Duration,
 }
 
 fn build_sample_message() -> Result<Message, QuickFixError> {
@@ -51,6 +54,38 @@ fn build_sample_message() -> Result<Message, QuickFixError> {
     Ok(msg)
 }
 
+/// Same message as [`build_sample_message`], without intermediate `CString` per field.
+fn build_sample_message_raw() -> Result<Message, QuickFixError> {
+    let mut msg = Message::new();
+
+    msg.with_header_mut(|h| -> Result<(), QuickFixError> {
+        h.set_field_str(8, "FIX.4.4")?;
+        h.set_field_str(49, "SENDER")?;
+        h.set_field_str(56, "TARGET")?;
+        Ok(())
+    })?;
+
+    msg.set_field_str(35, "D")?;
+    msg.set_field_str(11, "ORDER-12345")?;
+    msg.set_field_str(55, "AAPL")?;
+    msg.set_field_raw(54, b"1")?;
+    msg.set_field_raw(38, b"100")?;
+    msg.set_field_raw(44, b"189.42")?;
+
+    let mut party_1 = Group::try_new(453, 448)?;
+    party_1.set_field_str(448, "PARTY-1")?;
+    party_1.set_field_str(447, "D")?;
+    party_1.set_field_raw(452, b"1")?;
+    msg.add_group(&party_1)?;
+
+    let mut party_2 = party_1.clone();
+    party_2.set_field_str(448, "PARTY-2")?;
+    party_2.set_field_raw(452, b"3")?;
+    msg.add_group(&party_2)?;
+
+    Ok(msg)
+}
+
 fn run_bench(iterations: usize) -> Result<BenchResult, QuickFixError> {
     let _lock = GLOBAL_LOCK.lock().expect("GLOBAL_LOCK poisoned");
 
@@ -67,6 +102,23 @@ fn run_bench(iterations: usize) -> Result<BenchResult, QuickFixError> {
         black_box(text.len());
     }
 
+    assert_eq!(
+        build_sample_message_raw()?.to_fix_string()?,
+        msg_for_string.to_fix_string()?
+    );
+
+    let t_build = Instant::now();
+    for _ in 0..iterations {
+        black_box(build_sample_message()?);
+    }
+    let build_set_field = t_build.elapsed();
+
+    let t_build_raw = Instant::now();
+    for _ in 0..iterations {
+        black_box(build_sample_message_raw()?);
+    }
+    let build_set_field_raw = t_build_raw.elapsed();
+
     let t0 = Instant::now();
     for _ in 0..iterations {
         let text = msg_for_string.to_fix_string()?;
@@ -81,13 +133,54 @@ fn run_bench(iterations: usize) -> Result<BenchResult, QuickFixError> {
     }
     let to_fix_str = t1.elapsed();
 
+    let t2 = Instant::now();
+    for _ in 0..iterations {
+        let bytes = msg_for_str.to_fix_bytes_ref()?;
+        black_box(bytes.len());
+    }
+    let to_fix_bytes_ref = t2.elapsed();
+
     Ok(BenchResult {
         iterations,
+        build_set_field,
+        build_set_field_raw,
         to_fix_string,
         to_fix_str,
+        to_fix_bytes_ref,
     })
 }
 
+#[test]
+fn test_pretty_string() -> Result<(), QuickFixError> {
+    let msg = build_sample_message()?;
+
+    assert_eq!(
+        msg.to_pretty_string(None, '|'),
+        "8=FIX.4.4|9=124|49=SENDER|56=TARGET|\
+         11=ORDER-12345|35=D|38=100|44=189.42|54=1|55=AAPL|453=2|\
+         [448=PARTY-1|447=D|452=1]|[448=PARTY-2|447=D|452=3]|\
+         10=238"
+    );
+
+    let dict = DataDictionary::try_from_path("../quickfix-ffi/libquickfix/spec/FIX44.xml")?;
+    assert_eq!(
+        msg.to_pretty_string(Some(&dict), ' '),
+        "BeginString=FIX.4.4 BodyLength=124 SenderCompID=SENDER TargetCompID=TARGET \
+         ClOrdID=ORDER-12345 MsgType=NEW_ORDER_SINGLE OrderQty=100 Price=189.42 Side=BUY \
+         Symbol=AAPL NoPartyIDs=2 \
+         [PartyID=PARTY-1 PartyIDSource=PROPRIETARY PartyRole=EXECUTING_FIRM] \
+         [PartyID=PARTY-2 PartyIDSource=PROPRIETARY PartyRole=CLIENT_ID] \
+         CheckSum=238"
+    );
+
+    // Rendering is only for humans, wire format is left untouched.
+    assert_eq!(
+        msg.to_fix_string()?,
+        build_sample_message_raw()?.to_fix_string()?
+    );
+    Ok(())
+}
+
 #[test]
 #[ignore = "Manual benchmark; run with -- --ignored --nocapture"]
 fn bench_to_fix_string_vs_to_fix_str() -> Result<(), QuickFixError> {
@@ -112,5 +205,119 @@ fn bench_to_fix_string_vs_to_fix_str() -> Result<(), QuickFixError> {
     );
     println!("delta vs #1: {:.2}%", delta_pct);
 
+    let bytes_ref_ns_per_op = result.to_fix_bytes_ref.as_nanos() as f64 / result.iterations as f64;
+    let bytes_ref_delta_pct = ((result.to_fix_str.as_nanos() as f64
+        - result.to_fix_bytes_ref.as_nanos() as f64)
+        / result.to_fix_str.as_nanos() as f64)
+        * 100.0;
+    println!(
+        "3) to_fix_bytes_ref (borrowed, not validated): {:?} ({:.0} ns/op)",
+        result.to_fix_bytes_ref, bytes_ref_ns_per_op
+    );
+    println!("delta vs #2: {:.2}%", bytes_ref_delta_pct);
+
+    let build_ns_per_op = result.build_set_field.as_nanos() as f64 / result.iterations as f64;
+    let build_raw_ns_per_op =
+        result.build_set_field_raw.as_nanos() as f64 / result.iterations as f64;
+    let build_delta_pct = ((result.build_set_field.as_nanos() as f64
+        - result.build_set_field_raw.as_nanos() as f64)
+        / result.build_set_field.as_nanos() as f64)
+        * 100.0;
+
+    println!("=== FIX message building benchmark ===");
+    println!(
+        "4) set_field (CString per field):   {:?} ({:.0} ns/op)",
+        result.build_set_field, build_ns_per_op
+    );
+    println!(
+        "5) set_field_str / set_field_raw:   {:?} ({:.0} ns/op)",
+        result.build_set_field_raw, build_raw_ns_per_op
+    );
+    println!("delta vs #4: {:.2}%", build_delta_pct);
+
+    Ok(())
+}
+
+#[derive(Debug)]
+struct Percentiles {
+    p50: Duration,
+    p99: Duration,
+}
+
+impl Percentiles {
+    fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort_unstable();
+        let at = |pct: usize| samples[(samples.len() - 1) * pct / 100];
+        Self {
+            p50: at(50),
+            p99: at(99),
+        }
+    }
+
+    fn print(&self, label: &str) {
+        println!(
+            "{label}: p50 {:?}, p99 {:?}, p99 - p50 {:?}",
+            self.p50,
+            self.p99,
+            self.p99.saturating_sub(self.p50)
+        );
+    }
+}
+
+fn sample<F>(iterations: usize, mut f: F) -> Result<Percentiles, QuickFixError>
+where
+    F: FnMut() -> Result<(), QuickFixError>,
+{
+    let mut samples = Vec::with_capacity(iterations);
+    for _ in 0..iterations {
+        let start = Instant::now();
+        f()?;
+        samples.push(start.elapsed());
+    }
+    Ok(Percentiles::from_samples(samples))
+}
+
+#[test]
+#[ignore = "Manual benchmark; run with -- --ignored --nocapture"]
+fn bench_serialization_variance() -> Result<(), QuickFixError> {
+    let _lock = GLOBAL_LOCK.lock().expect("GLOBAL_LOCK poisoned");
+
+    let msg = build_sample_message()?;
+    let expected_len = msg.to_fix_bytes()?.len();
+
+    // Fresh buffer per call, as `to_fix_bytes` does.
+    let mut growing = || {
+        let mut buf = Vec::new();
+        msg.to_fix_bytes_into(&mut buf)?;
+        black_box(buf.len());
+        Ok(())
+    };
+    // Same buffer reused, sized once from expected message size.
+    let mut reserved_buf = Vec::with_capacity(expected_len);
+    let mut reserved = || {
+        reserved_buf.clear();
+        msg.to_fix_bytes_into(&mut reserved_buf)?;
+        black_box(reserved_buf.len());
+        Ok(())
+    };
+
+    sample(WARMUP_ITERS, || {
+        msg.to_fix_string().map(|text| drop(black_box(text)))
+    })?;
+    sample(WARMUP_ITERS, &mut growing)?;
+    sample(WARMUP_ITERS, &mut reserved)?;
+
+    let to_fix_string = sample(BENCH_ITERS, || {
+        msg.to_fix_string().map(|text| drop(black_box(text)))
+    })?;
+    let into_growing = sample(BENCH_ITERS, &mut growing)?;
+    let into_reserved = sample(BENCH_ITERS, &mut reserved)?;
+
+    println!("=== FIX serialization latency distribution ===");
+    println!("iterations: {}", BENCH_ITERS);
+    to_fix_string.print("1) to_fix_string (allocating)          ");
+    into_growing.print("2) to_fix_bytes_into (new buffer)      ");
+    into_reserved.print("3) to_fix_bytes_into (reserved buffer) ");
+
     Ok(())
 }
@@ -0,0 +1,73 @@
+use quickfix::*;
+
+fn build_execution_report() -> Result<Message, QuickFixError> {
+    let mut msg = Message::new();
+    msg.with_header_mut(|h| -> Result<(), QuickFixError> {
+        h.set_field(tags::BEGIN_STRING, "FIX.4.4")?;
+        h.set_field(tags::MSG_TYPE, msg_types::EXECUTION_REPORT)?;
+        h.set_field(tags::SENDER_COMP_ID, "BROKER")?;
+        h.set_field(tags::TARGET_COMP_ID, "CLIENT")?;
+        Ok(())
+    })?;
+
+    msg.set_field(tags::ORDER_ID, "ORDER-1")?;
+    msg.set_field(tags::EXEC_ID, "EXEC-1")?;
+    msg.set_field(tags::EXEC_TYPE, "F")?;
+    msg.set_field(tags::ORD_STATUS, "2")?;
+    msg.set_field(tags::SIDE, "1")?;
+    msg.set_field(tags::SYMBOL, "AAPL")?;
+    msg.set_field(tags::LEAVES_QTY, 0)?;
+    msg.set_field(tags::CUM_QTY, 100)?;
+    msg.set_field(tags::AVG_PX, "150.25")?;
+    msg.set_field(tags::TEXT, "Fill & done")?;
+    msg.set_field(5001, "custom")?;
+
+    for (party_id, party_role) in [("PARTY-1", 1), ("PARTY-2", 3)] {
+        let mut party = Group::try_new(tags::NO_PARTY_I_DS, tags::PARTY_ID)?;
+        party.set_field(tags::PARTY_ID, party_id)?;
+        party.set_field(tags::PARTY_ID_SOURCE, "D")?;
+        party.set_field(tags::PARTY_ROLE, party_role)?;
+        msg.add_group(&party)?;
+    }
+    Ok(msg)
+}
+
+fn build_dictionary() -> DataDictionary {
+    DataDictionary::try_from_path("../quickfix-ffi/libquickfix/spec/FIX44.xml").unwrap()
+}
+
+#[test]
+fn test_execution_report() -> Result<(), QuickFixError> {
+    let msg = build_execution_report()?;
+
+    assert_eq!(
+        msg.to_fixml(&build_dictionary())?,
+        "<ExecutionReport \
+         AvgPx=\"150.25\" CumQty=\"100\" ExecID=\"EXEC-1\" OrderID=\"ORDER-1\" OrdStatus=\"2\" \
+         Side=\"1\" Symbol=\"AAPL\" Text=\"Fill &amp; done\" ExecType=\"F\" LeavesQty=\"0\" \
+         Tag5001=\"custom\">\
+         <Header SenderCompID=\"BROKER\" TargetCompID=\"CLIENT\"/>\
+         <PartyIDs PartyID=\"PARTY-1\" PartyIDSource=\"D\" PartyRole=\"1\"/>\
+         <PartyIDs PartyID=\"PARTY-2\" PartyIDSource=\"D\" PartyRole=\"3\"/>\
+         </ExecutionReport>"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_invalid_msg_type() -> Result<(), QuickFixError> {
+    let dict = build_dictionary();
+
+    let mut msg = Message::new();
+    assert!(matches!(
+        msg.to_fixml(&dict),
+        Err(QuickFixError::InvalidArgument(_))
+    ));
+
+    msg.with_header_mut(|h| h.set_field(tags::MSG_TYPE, "ZZ"))?;
+    assert!(matches!(
+        msg.to_fixml(&dict),
+        Err(QuickFixError::InvalidArgument(_))
+    ));
+    Ok(())
+}
@@ -0,0 +1,104 @@
+use std::{
+    hint::black_box,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use quickfix::{FieldMap, Message, QuickFixError};
+
+static GLOBAL_LOCK: Mutex<u8> = Mutex::new(0);
+
+const WARMUP_ITERS: usize = 2_000;
+const BENCH_ITERS: usize = 200_000;
+
+const TAG: i32 = 11;
+
+#[derive(Debug)]
+struct BenchResult {
+    iterations: usize,
+    get_field: Duration,
+    get_field_str: Duration,
+    get_field_into: Duration,
+}
+
+fn run_bench(iterations: usize) -> Result<BenchResult, QuickFixError> {
+    let _lock = GLOBAL_LOCK.lock().expect("GLOBAL_LOCK poisoned");
+
+    let mut msg = Message::new();
+    msg.with_header_mut(|h| h.set_field(35, "D"))?;
+    msg.set_field(TAG, "ORDER-1234567890-ABCDEF")?;
+    msg.set_field(55, "AAPL")?;
+
+    let mut buf = String::with_capacity(64);
+
+    for _ in 0..WARMUP_ITERS {
+        black_box(msg.get_field(TAG));
+        black_box(msg.get_field_str(TAG));
+        buf.clear();
+        black_box(msg.get_field_into(TAG, &mut buf)?);
+    }
+
+    let t0 = Instant::now();
+    for _ in 0..iterations {
+        let value = msg.get_field(TAG);
+        black_box(value);
+    }
+    let get_field = t0.elapsed();
+
+    let t1 = Instant::now();
+    for _ in 0..iterations {
+        let value = msg.get_field_str(TAG);
+        black_box(value);
+    }
+    let get_field_str = t1.elapsed();
+
+    let t2 = Instant::now();
+    for _ in 0..iterations {
+        buf.clear();
+        msg.get_field_into(TAG, &mut buf)?;
+        black_box(buf.as_str());
+    }
+    let get_field_into = t2.elapsed();
+
+    Ok(BenchResult {
+        iterations,
+        get_field,
+        get_field_str,
+        get_field_into,
+    })
+}
+
+#[test]
+#[ignore = "Manual benchmark; run with -- --ignored --nocapture"]
+fn bench_get_field_vs_get_field_into() -> Result<(), QuickFixError> {
+    let result = run_bench(BENCH_ITERS)?;
+
+    let per_op_ns = |duration: Duration| duration.as_nanos() as f64 / result.iterations as f64;
+    let delta_pct = |duration: Duration| {
+        ((result.get_field.as_nanos() as f64 - duration.as_nanos() as f64)
+            / result.get_field.as_nanos() as f64)
+            * 100.0
+    };
+
+    println!("=== single field read benchmark ===");
+    println!("iterations: {}", result.iterations);
+    println!(
+        "1) get_field (allocating String):    {:?} ({:.0} ns/op)",
+        result.get_field,
+        per_op_ns(result.get_field)
+    );
+    println!(
+        "2) get_field_str (borrowed &str):    {:?} ({:.0} ns/op)",
+        result.get_field_str,
+        per_op_ns(result.get_field_str)
+    );
+    println!("delta vs #1: {:.2}%", delta_pct(result.get_field_str));
+    println!(
+        "3) get_field_into (reused String):   {:?} ({:.0} ns/op)",
+        result.get_field_into,
+        per_op_ns(result.get_field_into)
+    );
+    println!("delta vs #1: {:.2}%", delta_pct(result.get_field_into));
+
+    Ok(())
+}
@@ -0,0 +1,141 @@
+use std::{
+    hint::black_box,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use quickfix::{msg_types, tags, FieldBatch, FieldMap, Message, QuickFixError};
+
+static GLOBAL_LOCK: Mutex<u8> = Mutex::new(0);
+
+const WARMUP_ITERS: usize = 2_000;
+const BENCH_ITERS: usize = 20_000;
+
+/// Tags usually read when handling an ExecutionReport.
+const TAGS: [i32; 10] = [
+    tags::ORDER_ID,
+    tags::CL_ORD_ID,
+    tags::EXEC_ID,
+    tags::EXEC_TYPE,
+    tags::ORD_STATUS,
+    tags::SYMBOL,
+    tags::SIDE,
+    tags::ORDER_QTY,
+    tags::CUM_QTY,
+    tags::AVG_PX,
+];
+
+#[derive(Debug)]
+struct BenchResult {
+    iterations: usize,
+    get_field: Duration,
+    get_fields: Duration,
+    get_fields_into: Duration,
+}
+
+fn build_execution_report() -> Result<Message, QuickFixError> {
+    let mut msg = Message::new();
+    msg.with_header_mut(|h| h.set_field(tags::MSG_TYPE, msg_types::EXECUTION_REPORT))?;
+
+    msg.set_field(tags::ORDER_ID, "ORDER-ID-98765")?;
+    msg.set_field(tags::CL_ORD_ID, "ORDER-12345")?;
+    msg.set_field(tags::EXEC_ID, "EXEC-55555")?;
+    msg.set_field(tags::EXEC_TYPE, "F")?;
+    msg.set_field(tags::ORD_STATUS, "1")?;
+    msg.set_field(tags::SYMBOL, "AAPL")?;
+    msg.set_field(tags::SIDE, 1)?;
+    msg.set_field(tags::ORDER_QTY, 100)?;
+    msg.set_field(tags::CUM_QTY, 40)?;
+    msg.set_field(tags::AVG_PX, 189.42)?;
+
+    Ok(msg)
+}
+
+fn run_bench(iterations: usize) -> Result<BenchResult, QuickFixError> {
+    let _lock = GLOBAL_LOCK.lock().expect("GLOBAL_LOCK poisoned");
+
+    let msg = build_execution_report()?;
+    let mut batch = FieldBatch::new();
+
+    for _ in 0..WARMUP_ITERS {
+        for tag in TAGS {
+            black_box(msg.get_field(tag));
+        }
+        black_box(msg.get_fields(&TAGS)?);
+        msg.get_fields_into(&TAGS, &mut batch)?;
+        black_box(batch.get(0));
+    }
+
+    let t0 = Instant::now();
+    for _ in 0..iterations {
+        for tag in TAGS {
+            black_box(msg.get_field(tag));
+        }
+    }
+    let get_field = t0.elapsed();
+
+    let t1 = Instant::now();
+    for _ in 0..iterations {
+        black_box(msg.get_fields(&TAGS)?);
+    }
+    let get_fields = t1.elapsed();
+
+    let t2 = Instant::now();
+    for _ in 0..iterations {
+        msg.get_fields_into(&TAGS, &mut batch)?;
+        for value in batch.iter() {
+            black_box(value);
+        }
+    }
+    let get_fields_into = t2.elapsed();
+
+    Ok(BenchResult {
+        iterations,
+        get_field,
+        get_fields,
+        get_fields_into,
+    })
+}
+
+#[test]
+#[ignore = "Manual benchmark; run with -- --ignored --nocapture"]
+fn bench_get_field_vs_get_fields() -> Result<(), QuickFixError> {
+    let result = run_bench(BENCH_ITERS)?;
+
+    let get_field_ns_per_op = result.get_field.as_nanos() as f64 / result.iterations as f64;
+    let get_fields_ns_per_op = result.get_fields.as_nanos() as f64 / result.iterations as f64;
+    let get_fields_into_ns_per_op =
+        result.get_fields_into.as_nanos() as f64 / result.iterations as f64;
+    let delta_pct = ((result.get_field.as_nanos() as f64 - result.get_fields.as_nanos() as f64)
+        / result.get_field.as_nanos() as f64)
+        * 100.0;
+    let into_delta_pct = ((result.get_field.as_nanos() as f64
+        - result.get_fields_into.as_nanos() as f64)
+        / result.get_field.as_nanos() as f64)
+        * 100.0;
+
+    println!("=== batched field read benchmark ===");
+    println!(
+        "iterations: {} ({} tags each)",
+        result.iterations,
+        TAGS.len()
+    );
+    println!(
+        "1) get_field x {} (one FFI call per tag): {:?} ({:.0} ns/op)",
+        TAGS.len(),
+        result.get_field,
+        get_field_ns_per_op
+    );
+    println!(
+        "2) get_fields (single FFI call):          {:?} ({:.0} ns/op)",
+        result.get_fields, get_fields_ns_per_op
+    );
+    println!("delta vs #1: {:.2}%", delta_pct);
+    println!(
+        "3) get_fields_into (reused batch):        {:?} ({:.0} ns/op)",
+        result.get_fields_into, get_fields_into_ns_per_op
+    );
+    println!("delta vs #1: {:.2}%", into_delta_pct);
+
+    Ok(())
+}
@@ -0,0 +1,122 @@
+#![cfg(feature = "json")]
+
+use quickfix::*;
+use serde_json::{json, Value};
+
+fn build_order() -> Result<Message, QuickFixError> {
+    let mut msg = Message::new();
+    msg.with_header_mut(|h| -> Result<(), QuickFixError> {
+        h.set_field(8, "FIX.4.4")?;
+        h.set_field(35, "D")?;
+        h.set_field(49, "SENDER")?;
+        h.set_field(56, "TARGET")?;
+        Ok(())
+    })?;
+
+    msg.set_field(11, "ORDER-1")?;
+    msg.set_field(54, 1)?;
+    msg.set_field(55, "AAPL")?;
+    msg.set_field(9999, "custom")?;
+
+    let mut party = Group::try_new(453, 448)?;
+    party.set_field(448, "PARTY-1")?;
+    party.set_field(447, "D")?;
+    party.set_field(452, 1)?;
+    msg.add_group(&party)?;
+    Ok(msg)
+}
+
+fn parse_error(text: &str) -> String {
+    match Message::try_from_json(text) {
+        Err(QuickFixError::MessageParseError(msg)) => msg,
+        other => panic!("unexpected result: {:?}", other.map(|msg| msg.to_json())),
+    }
+}
+
+#[test]
+fn test_to_json() -> Result<(), Box<dyn std::error::Error>> {
+    let msg = build_order()?;
+    let text = msg.to_json()?;
+
+    // Entries keep wire order, so delimiter comes first.
+    assert!(
+        text.contains(r#""groups":{"453":[{"448":"PARTY-1","447":"D","452":"1"}]}"#),
+        "{text}"
+    );
+
+    let value: Value = serde_json::from_str(&text)?;
+    assert_eq!(value["header"]["8"], "FIX.4.4");
+    assert_eq!(value["header"]["35"], "D");
+    assert!(value["header"]["9"].is_string());
+    assert_eq!(
+        value["body"],
+        json!({ "11": "ORDER-1", "54": "1", "55": "AAPL", "9999": "custom" })
+    );
+    assert!(value["trailer"]["10"].is_string());
+    Ok(())
+}
+
+#[test]
+fn test_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+    let msg = build_order()?;
+    let output = Message::try_from_json(&msg.to_json()?)?;
+
+    assert_eq!(output.get_field(11).as_deref(), Some("ORDER-1"));
+    assert_eq!(output.get_field(9999).as_deref(), Some("custom"));
+    assert_eq!(output.to_fix_string()?, msg.to_fix_string()?);
+    Ok(())
+}
+
+#[test]
+fn test_nested_groups() -> Result<(), Box<dyn std::error::Error>> {
+    let input = json!({
+        "header": { "8": "FIX.4.4", "35": "D" },
+        "body": { "11": "ORDER-1", "54": 1 },
+        "groups": {
+            "453": [
+                {
+                    "448": "PARTY-1",
+                    "452": "1",
+                    "447": "D",
+                    "802": [{ "523": "DESK-1", "803": "4" }, { "523": "DESK-2", "803": "4" }],
+                },
+                { "448": "PARTY-2", "452": "3", "447": "D" },
+            ],
+        },
+    });
+    let msg = Message::try_from_json(&input.to_string())?;
+
+    assert_eq!(msg.get_field(54).as_deref(), Some("1"));
+    assert!(msg.to_fix_string()?.contains(
+        "453=2\u{1}\
+         448=PARTY-1\u{1}452=1\u{1}447=D\u{1}802=2\u{1}\
+         523=DESK-1\u{1}803=4\u{1}523=DESK-2\u{1}803=4\u{1}\
+         448=PARTY-2\u{1}452=3\u{1}447=D\u{1}"
+    ));
+
+    let output: Value = serde_json::from_str(&msg.to_json()?)?;
+    assert_eq!(output["body"], json!({ "11": "ORDER-1", "54": "1" }));
+    assert_eq!(output["groups"], input["groups"]);
+    assert_eq!(
+        Message::try_from_json(&msg.to_json()?)?.to_fix_string()?,
+        msg.to_fix_string()?
+    );
+    Ok(())
+}
+
+#[test]
+fn test_invalid_json() {
+    assert!(parse_error(r#"{"body": "#).starts_with("Invalid JSON message: EOF"));
+    assert!(parse_error(r#"{"body": {"ClOrdID": "ORDER-1"}}"#)
+        .contains("invalid tag `ClOrdID`, expected a field number"));
+    assert!(parse_error(r#"{"body": {"-11": "ORDER-1"}}"#).contains("invalid tag `-11`"));
+    assert!(parse_error(r#"{"body": {"11": "A", "11": "B"}}"#).contains("duplicate tag 11"));
+    assert!(parse_error(r#"{"orders": {}}"#).contains("unknown field `orders`"));
+    assert!(parse_error(r#"{"body": {"453": [{"448": "P"}]}}"#)
+        .contains("body field 453 is a group, it must be in `groups`"));
+    assert!(parse_error(r#"{"groups": {"453": "1"}}"#)
+        .contains("group 453 must be an array of entries"));
+    assert!(parse_error(r#"{"groups": {"453": [{}]}}"#).contains("empty entry in group 453"));
+    assert!(parse_error(r#"{"groups": {"453": [{"448": {}}]}}"#)
+        .contains("expected a field value or an array of group entries"));
+}
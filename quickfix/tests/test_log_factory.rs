@@ -1,10 +1,12 @@
+#[cfg(any(feature = "log", feature = "tracing"))]
+use std::sync::Mutex;
+use std::time::Duration;
+
 use quickfix::*;
 use utils::*;
 
 mod utils;
 
-const TEST_PORT: u16 = 8001;
-
 #[test]
 fn test_log_factory() {
     let _log_factory = LogFactory::try_new(&StdLogger::Stdout).unwrap();
@@ -22,13 +24,10 @@ fn test_extra_log_factory() {
 #[test]
 fn test_null_logger() {
     checker::run(
-        TEST_PORT,
         // Sender
         NullFixApplication,
-        MemoryMessageStoreFactory::new(),
         // Receiver
         NullFixApplication,
-        MemoryMessageStoreFactory::new(),
     )
     .unwrap();
 }
@@ -36,13 +35,10 @@ fn test_null_logger() {
 #[test]
 fn test_stdout_logger() {
     checker::run(
-        TEST_PORT,
         // Sender
         NullFixApplication,
-        MemoryMessageStoreFactory::new(),
         // Receiver
         NullFixApplication,
-        MemoryMessageStoreFactory::new(),
     )
     .unwrap();
 }
@@ -50,13 +46,10 @@ fn test_stdout_logger() {
 #[test]
 fn test_stderr_logger() {
     checker::run(
-        TEST_PORT,
         // Sender
         NullFixApplication,
-        MemoryMessageStoreFactory::new(),
         // Receiver
         NullFixApplication,
-        MemoryMessageStoreFactory::new(),
     )
     .unwrap();
 }
@@ -65,13 +58,1035 @@ fn test_stderr_logger() {
 #[cfg(feature = "log")]
 fn test_rust_logger() {
     checker::run(
-        TEST_PORT,
         // Sender
         NullFixApplication,
-        MemoryMessageStoreFactory::new(),
         // Receiver
         NullFixApplication,
-        MemoryMessageStoreFactory::new(),
     )
     .unwrap();
 }
+
+/// Keep every `quickfix` log record, as (target, level, message).
+#[cfg(feature = "log")]
+struct CaptureLogger(Mutex<Vec<(String, log::Level, String)>>);
+
+#[cfg(feature = "log")]
+impl log::Log for CaptureLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.target().starts_with("quickfix::")
+    }
+
+    fn log(&self, record: &log::Record) {
+        if self.enabled(record.metadata()) {
+            self.0.lock().unwrap().push((
+                record.target().to_string(),
+                record.level(),
+                record.args().to_string(),
+            ));
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+#[test]
+#[cfg(feature = "log")]
+fn test_log_crate_logger() -> Result<(), QuickFixError> {
+    static CAPTURE: CaptureLogger = CaptureLogger(Mutex::new(Vec::new()));
+    log::set_logger(&CAPTURE).unwrap();
+    log::set_max_level(log::LevelFilter::Trace);
+
+    let app = Application::try_new(&NullFixApplication)?;
+    let store_factory = MemoryMessageStoreFactory::new();
+    let mut acceptor = Acceptor::try_new(
+        &build_settings(ServerType::Receiver, 0)?,
+        &app,
+        &store_factory,
+        FixSocketServerKind::default(),
+    )?;
+    acceptor.start()?;
+    let mut initiator =
+        Initiator::builder(&build_settings(ServerType::Sender, listen_port(&acceptor))?)
+            .application(Application::try_new_shared(std::sync::Arc::new(
+                NullFixApplication,
+            ))?)
+            .store(MemoryMessageStoreFactory::new())
+            .log(LogFactory::try_new(&LogCrateLogger)?)
+            .build()?;
+    initiator.start()?;
+    wait_until(Duration::from_secs(10), || {
+        initiator.is_logged_on().unwrap_or(false) && acceptor.is_logged_on().unwrap_or(false)
+    });
+    initiator.stop()?;
+    acceptor.stop()?;
+
+    let records = CAPTURE.0.lock().unwrap();
+    let session = ServerType::Sender.session_id().to_repr();
+    let find = |target: &str, level: log::Level, needle: &str| {
+        records.iter().any(|(record_target, record_level, msg)| {
+            record_target == target
+                && *record_level == level
+                && msg.starts_with(&format!("{session}: "))
+                && msg.contains(needle)
+        })
+    };
+    assert!(find("quickfix::outgoing", log::Level::Debug, "|35=A|"));
+    assert!(find("quickfix::incoming", log::Level::Debug, "|35=A|"));
+    assert!(find("quickfix::outgoing", log::Level::Debug, "|35=5|"));
+    assert!(find("quickfix::event", log::Level::Info, ""));
+    assert!(!records.iter().any(|(_, _, msg)| msg.contains('\x01')));
+    Ok(())
+}
+
+/// Event seen by [`CaptureLayer`]: level, name of its span, span fields and event fields.
+#[cfg(feature = "tracing")]
+type TracingRecord = (
+    tracing::Level,
+    String,
+    std::collections::BTreeMap<String, String>,
+    std::collections::BTreeMap<String, String>,
+);
+
+#[cfg(feature = "tracing")]
+#[derive(Default)]
+struct FieldVisitor(std::collections::BTreeMap<String, String>);
+
+#[cfg(feature = "tracing")]
+impl tracing::field::Visit for FieldVisitor {
+    fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+        self.0.insert(
+            field.name().to_string(),
+            format!("{value:?}").replace('"', ""),
+        );
+    }
+}
+
+/// Keep every event with fields of the span it has been emitted in.
+#[cfg(feature = "tracing")]
+struct CaptureLayer(std::sync::Arc<Mutex<Vec<TracingRecord>>>);
+
+#[cfg(feature = "tracing")]
+impl<S> tracing_subscriber::Layer<S> for CaptureLayer
+where
+    S: tracing::Subscriber + for<'a> tracing_subscriber::registry::LookupSpan<'a>,
+{
+    fn on_new_span(
+        &self,
+        attrs: &tracing::span::Attributes<'_>,
+        id: &tracing::span::Id,
+        ctx: tracing_subscriber::layer::Context<'_, S>,
+    ) {
+        let mut fields = FieldVisitor::default();
+        attrs.record(&mut fields);
+        ctx.span(id).unwrap().extensions_mut().insert(fields);
+    }
+
+    fn on_event(&self, event: &tracing::Event<'_>, ctx: tracing_subscriber::layer::Context<'_, S>) {
+        let mut fields = FieldVisitor::default();
+        event.record(&mut fields);
+        let (span_name, span_fields) =
+            ctx.event_span(event).map_or_else(Default::default, |span| {
+                let span_fields = span
+                    .extensions()
+                    .get::<FieldVisitor>()
+                    .map(|fields| fields.0.clone())
+                    .unwrap_or_default();
+                (span.name().to_string(), span_fields)
+            });
+        self.0
+            .lock()
+            .unwrap()
+            .push((*event.metadata().level(), span_name, span_fields, fields.0));
+    }
+}
+
+#[test]
+#[cfg(feature = "tracing")]
+fn test_tracing_logger() -> Result<(), QuickFixError> {
+    use tracing_subscriber::layer::SubscriberExt;
+
+    let records = std::sync::Arc::new(Mutex::new(Vec::new()));
+    tracing::subscriber::set_global_default(
+        tracing_subscriber::registry().with(CaptureLayer(records.clone())),
+    )
+    .unwrap();
+
+    static LOGGER: std::sync::OnceLock<TracingLogger> = std::sync::OnceLock::new();
+    let logger = LOGGER.get_or_init(TracingLogger::new);
+
+    let app = Application::try_new(&NullFixApplication)?;
+    let store_factory = MemoryMessageStoreFactory::new();
+    let mut acceptor = Acceptor::try_new(
+        &build_settings(ServerType::Receiver, 0)?,
+        &app,
+        &store_factory,
+        FixSocketServerKind::default(),
+    )?;
+    acceptor.start()?;
+    let mut initiator =
+        Initiator::builder(&build_settings(ServerType::Sender, listen_port(&acceptor))?)
+            .application(Application::try_new_shared(std::sync::Arc::new(
+                NullFixApplication,
+            ))?)
+            .store(MemoryMessageStoreFactory::new())
+            .log(LogFactory::try_new(logger)?)
+            .build()?;
+    initiator.start()?;
+    wait_until(Duration::from_secs(10), || {
+        initiator.is_logged_on().unwrap_or(false) && acceptor.is_logged_on().unwrap_or(false)
+    });
+    unsafe { Session::lookup(&ServerType::Sender.session_id()) }?.send_test_request("PING")?;
+    wait_until(Duration::from_secs(5), || {
+        records
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|(_, _, _, fields)| fields.get("msg_type").map(String::as_str) == Some("0"))
+    });
+    initiator.stop()?;
+    acceptor.stop()?;
+
+    let records = records.lock().unwrap();
+    let session = ServerType::Sender.session_id().to_repr();
+    let find = |level: tracing::Level, direction: &str, msg_type: &str| {
+        records.iter().find(|(record_level, _, _, fields)| {
+            *record_level == level
+                && fields.get("direction").map(String::as_str) == Some(direction)
+                && fields.get("msg_type").map(String::as_str) == Some(msg_type)
+        })
+    };
+
+    let (_, span_name, span_fields, fields) =
+        find(tracing::Level::DEBUG, "outgoing", "A").expect("Missing outgoing logon");
+    assert_eq!(span_name, "fix_session");
+    assert_eq!(span_fields.get("session_id"), Some(&session));
+    assert_eq!(fields.get("seq_num").map(String::as_str), Some("1"));
+
+    assert!(find(tracing::Level::DEBUG, "incoming", "A").is_some());
+    assert!(find(tracing::Level::DEBUG, "outgoing", "1").is_some());
+    // Heartbeat answering the test request.
+    let (_, span_name, _, _) =
+        find(tracing::Level::TRACE, "incoming", "0").expect("Missing heartbeat");
+    assert_eq!(span_name, "fix_session");
+    assert!(find(tracing::Level::DEBUG, "incoming", "0").is_none());
+
+    // Events are in session span too.
+    assert!(records
+        .iter()
+        .any(|(level, span_name, span_fields, fields)| {
+            *level == tracing::Level::INFO
+                && span_name == "fix_session"
+                && span_fields.get("session_id") == Some(&session)
+                && !fields.contains_key("direction")
+        }));
+    Ok(())
+}
+
+#[test]
+fn test_file_logger_rotation() -> Result<(), QuickFixError> {
+    let log_path =
+        std::env::temp_dir().join(format!("quickfix-file-logger-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&log_path);
+
+    static LOGGER: std::sync::OnceLock<FileLogger> = std::sync::OnceLock::new();
+    let logger = LOGGER.get_or_init(|| {
+        FileLogger::try_new(&log_path, Rotation::MaxBytes(300)).expect("Cannot create logger")
+    });
+    assert!(format!("{logger:?}").contains("MaxBytes(300)"));
+
+    let app = Application::try_new(&NullFixApplication)?;
+    let store_factory = MemoryMessageStoreFactory::new();
+    let mut acceptor = Acceptor::try_new(
+        &build_settings(ServerType::Receiver, 0)?,
+        &app,
+        &store_factory,
+        FixSocketServerKind::default(),
+    )?;
+    acceptor.start()?;
+    let mut initiator =
+        Initiator::builder(&build_settings(ServerType::Sender, listen_port(&acceptor))?)
+            .application(Application::try_new_shared(std::sync::Arc::new(
+                NullFixApplication,
+            ))?)
+            .store(MemoryMessageStoreFactory::new())
+            .log(LogFactory::try_new(logger)?)
+            .build()?;
+    initiator.start()?;
+    wait_until(Duration::from_secs(10), || {
+        initiator.is_logged_on().unwrap_or(false) && acceptor.is_logged_on().unwrap_or(false)
+    });
+    let sender_id = ServerType::Sender.session_id();
+    for index in 0..5 {
+        send_to_target(build_news(&format!("Hello {index}"), &[])?, &sender_id)?;
+    }
+    initiator.stop()?;
+    acceptor.stop()?;
+
+    // Messages and events are split, messages rotated many times.
+    let prefix = log_path.join("FIX.4.4-SENDER-RECEIVER");
+    let file = |suffix: &str| std::path::PathBuf::from(format!("{}.{suffix}", prefix.display()));
+    assert!(file("event.current.log").exists());
+    assert!(file("messages.current.log").exists());
+    let rotated = (1..)
+        .map(|index| file(&format!("messages.{index}.log")))
+        .take_while(|path| path.exists())
+        .collect::<Vec<_>>();
+    assert!(rotated.len() >= 5, "{rotated:?}");
+
+    // Rotated files put back in order hold every sent message, none split or lost.
+    let mut content = String::new();
+    for path in rotated.iter().chain([&file("messages.current.log")]) {
+        let text = std::fs::read_to_string(path).unwrap();
+        assert!(text.len() <= 300 || text.lines().count() == 1);
+        content.push_str(&text);
+    }
+    let sent_seq_nums: Vec<u32> = content
+        .lines()
+        .filter(|line| line.contains("\x0149=SENDER\x01"))
+        .map(|line| {
+            assert!(line.ends_with('\x01'), "{line}");
+            let start = line.find("\x0134=").unwrap() + 4;
+            line[start..].split('\x01').next().unwrap().parse().unwrap()
+        })
+        .collect();
+    let expected: Vec<u32> = (1..=sent_seq_nums.len() as u32).collect();
+    assert_eq!(sent_seq_nums, expected);
+    assert!(sent_seq_nums.len() >= 6);
+
+    let _ = std::fs::remove_dir_all(&log_path);
+    Ok(())
+}
+
+/// Keep every log reaching it, as (callback, session, message).
+#[derive(Default)]
+struct RecordingLogger(std::sync::Mutex<Vec<(&'static str, Option<String>, String)>>);
+
+impl RecordingLogger {
+    fn push(&self, callback: &'static str, session_id: Option<&SessionId>, msg: &str) {
+        self.0.lock().unwrap().push((
+            callback,
+            session_id.map(SessionId::to_repr),
+            msg.to_string(),
+        ));
+    }
+}
+
+impl LogCallback for RecordingLogger {
+    fn on_incoming(&self, session_id: Option<&SessionId>, msg: &str) {
+        self.push("incoming", session_id, msg);
+    }
+
+    fn on_outgoing(&self, session_id: Option<&SessionId>, msg: &str) {
+        self.push("outgoing", session_id, msg);
+    }
+
+    fn on_event(&self, session_id: Option<&SessionId>, msg: &str) {
+        self.push("event", session_id, msg);
+    }
+}
+
+fn raw_message(msg_type: &str, seq_num: u32) -> String {
+    format!("8=FIX.4.4\x019=20\x0135={msg_type}\x0134={seq_num}\x0149=SENDER\x0156=RECEIVER\x0110=000\x01")
+}
+
+#[test]
+fn test_filtered_logger_no_admin() {
+    let session_id = ServerType::Sender.session_id();
+    let logger = FilteredLogger::new(RecordingLogger::default(), LogFilter::no_admin());
+
+    for (seq_num, msg_type) in (1..).zip(["A", "0", "1", "D", "2", "4", "8", "3", "B", "5"]) {
+        logger.on_outgoing(Some(&session_id), &raw_message(msg_type, seq_num));
+        logger.on_incoming(Some(&session_id), &raw_message(msg_type, seq_num));
+    }
+    logger.on_event(Some(&session_id), "Initiated connection");
+    // Tag 35 only matches as a whole tag.
+    logger.on_incoming(None, "8=FIX.4.4\x019=20\x01135=0\x0135=8\x0110=000\x01");
+
+    let records = logger.inner().0.lock().unwrap();
+    let kept: Vec<_> = records
+        .iter()
+        .map(|(callback, _, msg)| (*callback, msg.clone()))
+        .collect();
+    assert_eq!(
+        kept,
+        vec![
+            ("outgoing", raw_message("D", 4)),
+            ("incoming", raw_message("D", 4)),
+            ("outgoing", raw_message("8", 7)),
+            ("incoming", raw_message("8", 7)),
+            ("outgoing", raw_message("B", 9)),
+            ("incoming", raw_message("B", 9)),
+            ("event", "Initiated connection".to_string()),
+            (
+                "incoming",
+                "8=FIX.4.4\x019=20\x01135=0\x0135=8\x0110=000\x01".to_string()
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_filtered_logger_direction_and_session() {
+    let sender_id = ServerType::Sender.session_id();
+    let receiver_id = ServerType::Receiver.session_id();
+    let filter = LogFilter::new()
+        .exclude_direction(LogDirection::Incoming)
+        .exclude_session(&receiver_id);
+    let logger = FilteredLogger::new(RecordingLogger::default(), filter);
+
+    logger.on_incoming(Some(&sender_id), &raw_message("D", 1));
+    logger.on_outgoing(Some(&sender_id), &raw_message("D", 2));
+    logger.on_outgoing(Some(&receiver_id), &raw_message("8", 1));
+    logger.on_event(Some(&receiver_id), "Dropped event");
+    logger.on_event(None, "Global event");
+
+    let records = logger.inner().0.lock().unwrap();
+    assert_eq!(
+        *records,
+        vec![
+            ("outgoing", Some(sender_id.to_repr()), raw_message("D", 2)),
+            ("event", None, "Global event".to_string()),
+        ]
+    );
+    assert!(logger.filter().accepts_event(Some(&sender_id)));
+}
+
+/// Keep every structured message reaching it, as (session, direction, msg type, seq num, raw).
+#[derive(Default)]
+#[allow(clippy::type_complexity)]
+struct StructuredRecorder(
+    std::sync::Mutex<
+        Vec<(
+            Option<String>,
+            LogDirection,
+            Option<String>,
+            Option<u32>,
+            String,
+        )>,
+    >,
+);
+
+impl StructuredLogCallback for StructuredRecorder {
+    fn on_message(&self, record: MessageLogRecord<'_>) {
+        assert!(record.timestamp <= std::time::SystemTime::now());
+        self.0.lock().unwrap().push((
+            record.session_id.map(SessionId::to_repr),
+            record.direction,
+            record.msg_type.map(str::to_string),
+            record.seq_num,
+            record.raw.to_string(),
+        ));
+    }
+}
+
+#[test]
+fn test_structured_logger() {
+    let session_id = ServerType::Sender.session_id();
+    let logger = StructuredLogger(StructuredRecorder::default());
+    let logon = "8=FIX.4.4\x019=70\x0135=A\x0134=1\x0149=SENDER\x0152=20240101-10:00:00.000\x0156=RECEIVER\x0198=0\x01108=30\x0110=123\x01";
+    let news = "8=FIX.4.4\x019=60\x0135=B\x0134=27\x0149=RECEIVER\x0152=20240101-10:00:01.000\x0156=SENDER\x01148=Hello\x0110=045\x01";
+
+    logger.on_outgoing(Some(&session_id), logon);
+    logger.on_incoming(Some(&session_id), logon);
+    logger.on_outgoing(Some(&session_id), news);
+    logger.on_incoming(None, news);
+    logger.on_incoming(None, "garbage");
+
+    let session = Some(session_id.to_repr());
+    let records = logger.0 .0.lock().unwrap();
+    assert_eq!(
+        *records,
+        vec![
+            (
+                session.clone(),
+                LogDirection::Outgoing,
+                Some("A".to_string()),
+                Some(1),
+                logon.to_string()
+            ),
+            (
+                session.clone(),
+                LogDirection::Incoming,
+                Some("A".to_string()),
+                Some(1),
+                logon.to_string()
+            ),
+            (
+                session,
+                LogDirection::Outgoing,
+                Some("B".to_string()),
+                Some(27),
+                news.to_string()
+            ),
+            (
+                None,
+                LogDirection::Incoming,
+                Some("B".to_string()),
+                Some(27),
+                news.to_string()
+            ),
+            (
+                None,
+                LogDirection::Incoming,
+                None,
+                None,
+                "garbage".to_string()
+            ),
+        ]
+    );
+}
+
+/// Forward to a shared recorder, panicking on events containing "Panic".
+struct SharedRecorder(std::sync::Arc<RecordingLogger>);
+
+impl LogCallback for SharedRecorder {
+    fn on_incoming(&self, session_id: Option<&SessionId>, msg: &str) {
+        self.0.on_incoming(session_id, msg);
+    }
+
+    fn on_outgoing(&self, session_id: Option<&SessionId>, msg: &str) {
+        self.0.on_outgoing(session_id, msg);
+    }
+
+    fn on_event(&self, session_id: Option<&SessionId>, msg: &str) {
+        assert!(!msg.contains("Panic"), "Deliberate panic from logger");
+        self.0.on_event(session_id, msg);
+    }
+}
+
+#[test]
+fn test_composite_logger() {
+    let session_id = ServerType::Sender.session_id();
+    let first = std::sync::Arc::new(RecordingLogger::default());
+    let second = std::sync::Arc::new(RecordingLogger::default());
+    let logger = CompositeLogger::new(vec![
+        Box::new(SharedRecorder(first.clone())),
+        Box::new(NullLogger),
+        Box::new(FilteredLogger::new(
+            SharedRecorder(second.clone()),
+            LogFilter::new(),
+        )),
+    ]);
+    assert_eq!(format!("{logger:?}"), "CompositeLogger { children: 3 }");
+
+    logger.on_event(Some(&session_id), "Connecting");
+    logger.on_outgoing(Some(&session_id), &raw_message("A", 1));
+    logger.on_incoming(Some(&session_id), &raw_message("A", 1));
+    // Panicking child is skipped, others still get every log.
+    logger.on_event(None, "Panic");
+    logger.on_outgoing(Some(&session_id), &raw_message("D", 2));
+
+    let first = first.0.lock().unwrap();
+    assert_eq!(first.len(), 4);
+    assert_eq!(*first, *second.0.lock().unwrap());
+    assert_eq!(
+        first[3],
+        ("outgoing", Some(session_id.to_repr()), raw_message("D", 2))
+    );
+}
+
+/// Record events once `gate` is free, telling `entered` it is waiting for it.
+struct GatedRecorder {
+    gate: std::sync::Arc<std::sync::Mutex<()>>,
+    entered: std::sync::Mutex<std::sync::mpsc::Sender<()>>,
+    events: std::sync::Mutex<Vec<String>>,
+}
+
+impl LogCallback for GatedRecorder {
+    fn on_event(&self, _session_id: Option<&SessionId>, msg: &str) {
+        let _ = self.entered.lock().unwrap().send(());
+        let _gate = self.gate.lock().unwrap();
+        self.events.lock().unwrap().push(msg.to_string());
+    }
+}
+
+/// Log 5 events while wrapped logger is stuck on the first one, return events it finally got.
+fn run_async_overflow(policy: LogOverflowPolicy) -> (Vec<String>, u64) {
+    let gate = std::sync::Arc::new(std::sync::Mutex::new(()));
+    let (entered_sender, entered) = std::sync::mpsc::channel();
+    let inner = GatedRecorder {
+        gate: gate.clone(),
+        entered: std::sync::Mutex::new(entered_sender),
+        events: std::sync::Mutex::default(),
+    };
+    let logger = AsyncLogger::new(inner, 2, policy);
+
+    let closed_gate = gate.lock().unwrap();
+    logger.on_event(None, "event 0");
+    entered.recv().unwrap();
+    for index in 1..5 {
+        logger.on_event(None, &format!("event {index}"));
+    }
+    drop(closed_gate);
+
+    logger.flush();
+    let events = logger.inner().events.lock().unwrap().clone();
+    (events, logger.dropped_count())
+}
+
+#[test]
+fn test_async_logger_overflow() {
+    assert_eq!(
+        run_async_overflow(LogOverflowPolicy::DropNewest),
+        (
+            vec!["event 0".into(), "event 1".into(), "event 2".into()],
+            2
+        )
+    );
+    assert_eq!(
+        run_async_overflow(LogOverflowPolicy::DropOldest),
+        (
+            vec!["event 0".into(), "event 3".into(), "event 4".into()],
+            2
+        )
+    );
+}
+
+#[test]
+fn test_async_logger_block_and_drop() {
+    let session_id = ServerType::Sender.session_id();
+    let recorder = std::sync::Arc::new(RecordingLogger::default());
+    let logger = AsyncLogger::new(
+        SharedRecorder(recorder.clone()),
+        2,
+        LogOverflowPolicy::Block,
+    );
+    assert!(format!("{logger:?}").contains("Block"));
+
+    for seq_num in 1..=100 {
+        logger.on_outgoing(Some(&session_id), &raw_message("D", seq_num));
+    }
+    logger.flush();
+    assert_eq!(recorder.0.lock().unwrap().len(), 100);
+
+    // Records still queued when logger is dropped are written too.
+    for seq_num in 101..=200 {
+        logger.on_incoming(None, &raw_message("8", seq_num));
+    }
+    assert_eq!(logger.dropped_count(), 0);
+    drop(logger);
+
+    let records = recorder.0.lock().unwrap();
+    assert_eq!(records.len(), 200);
+    for (seq_num, (callback, session, msg)) in (1..).zip(records.iter()) {
+        let (expected_callback, expected_session, msg_type) = if seq_num <= 100 {
+            ("outgoing", Some(session_id.to_repr()), "D")
+        } else {
+            ("incoming", None, "8")
+        };
+        assert_eq!(*callback, expected_callback);
+        assert_eq!(*session, expected_session);
+        assert_eq!(*msg, raw_message(msg_type, seq_num));
+    }
+}
+
+#[test]
+fn test_sampled_logger_rate_limit() {
+    let sender_id = ServerType::Sender.session_id();
+    let receiver_id = ServerType::Receiver.session_id();
+    let recorder = std::sync::Arc::new(RecordingLogger::default());
+    let logger = SampledLogger::new(
+        SharedRecorder(recorder.clone()),
+        SamplingPolicy::new().max_per_second(10),
+    );
+
+    for seq_num in 1..=1000 {
+        logger.on_incoming(Some(&sender_id), &raw_message("8", seq_num));
+    }
+    // Limits are per session, events and logouts always pass.
+    logger.on_incoming(Some(&receiver_id), &raw_message("8", 1));
+    logger.on_event(Some(&sender_id), "Disconnecting");
+    logger.on_incoming(Some(&sender_id), &raw_message("5", 1001));
+    drop(logger);
+
+    let records = recorder.0.lock().unwrap();
+    let sender = Some(sender_id.to_repr());
+    let sender_news = records
+        .iter()
+        .filter(|(callback, session, msg)| {
+            *callback == "incoming" && *session == sender && msg.contains("\x0135=8\x01")
+        })
+        .count();
+    // Burst size, plus what has been refilled while feeding it.
+    assert!((10..=12).contains(&sender_news), "{sender_news}");
+    assert!(records.contains(&("incoming", Some(receiver_id.to_repr()), raw_message("8", 1))));
+    assert!(records.contains(&("event", sender.clone(), "Disconnecting".to_string())));
+    assert!(records.contains(&("incoming", sender.clone(), raw_message("5", 1001))));
+
+    let summaries: Vec<_> = records
+        .iter()
+        .filter(|(callback, _, msg)| *callback == "event" && msg.starts_with("Log sampling"))
+        .collect();
+    assert_eq!(summaries.len(), 1);
+    assert_eq!(
+        *summaries[0],
+        (
+            "event",
+            sender,
+            format!("Log sampling dropped {} messages", 1000 - sender_news)
+        )
+    );
+    assert_eq!(records.len(), sender_news + 4);
+}
+
+#[test]
+fn test_sampled_logger_one_in() {
+    let session_id = ServerType::Sender.session_id();
+    let recorder = std::sync::Arc::new(RecordingLogger::default());
+    let logger = SampledLogger::new(
+        SharedRecorder(recorder.clone()),
+        SamplingPolicy::new().one_in(100),
+    );
+    assert!(format!("{logger:?}").contains("one_in: Some(100)"));
+
+    for seq_num in 1..=1000 {
+        logger.on_outgoing(Some(&session_id), &raw_message("D", seq_num));
+    }
+    logger.on_outgoing(Some(&session_id), &raw_message("3", 1001));
+
+    // Summary comes once a second.
+    std::thread::sleep(Duration::from_millis(1100));
+    logger.on_event(Some(&session_id), "Tick");
+
+    let records = recorder.0.lock().unwrap();
+    let kept: Vec<_> = records.iter().map(|(_, _, msg)| msg.clone()).collect();
+    let mut expected: Vec<_> = (0..10)
+        .map(|index| raw_message("D", index * 100 + 1))
+        .collect();
+    expected.push(raw_message("3", 1001));
+    expected.push("Tick".to_string());
+    expected.push("Log sampling dropped 990 messages".to_string());
+    assert_eq!(kept, expected);
+}
+
+#[test]
+#[cfg(all(feature = "syslog", unix))]
+fn test_syslog_logger() {
+    use std::os::unix::net::UnixDatagram;
+
+    let socket_path =
+        std::env::temp_dir().join(format!("quickfix-syslog-{}.sock", std::process::id()));
+    let _ = std::fs::remove_file(&socket_path);
+    let daemon = UnixDatagram::bind(&socket_path).unwrap();
+    let session_id = ServerType::Sender.session_id();
+    let mut buffer = [0; 4096];
+    let mut recv = || {
+        let len = daemon.recv(&mut buffer).unwrap();
+        String::from_utf8_lossy(&buffer[..len]).into_owned()
+    };
+
+    let logger = SyslogLogger::new("fix-gw", SyslogFacility::Local3).with_socket_path(&socket_path);
+    logger.on_event(Some(&session_id), "Logon \"ok\"");
+    logger.on_incoming(Some(&session_id), &raw_message("D", 12));
+
+    let pid = std::process::id();
+    let session_repr = session_id.to_repr();
+    assert_eq!(
+        recv(),
+        format!(
+            "<158>1 - - fix-gw {pid} event [fix@32473 session_id=\"{session_repr}\" \
+             direction=\"event\"] Logon \"ok\""
+        )
+    );
+    assert_eq!(
+        recv(),
+        format!(
+            "<159>1 - - fix-gw {pid} incoming [fix@32473 session_id=\"{session_repr}\" \
+             direction=\"incoming\" msg_type=\"D\" seq_num=\"12\"] {}",
+            raw_message("D", 12).replace('\x01', "|")
+        )
+    );
+
+    let logger =
+        SyslogLogger::journald("fix-gw", SyslogFacility::User).with_socket_path(&socket_path);
+    logger.on_outgoing(None, &raw_message("0", 3));
+    assert_eq!(
+        recv(),
+        format!(
+            "MESSAGE={}\nPRIORITY=7\nSYSLOG_FACILITY=1\nSYSLOG_IDENTIFIER=fix-gw\n\
+             FIX_DIRECTION=outgoing\nFIX_MSG_TYPE=0\nFIX_SEQ_NUM=3\n",
+            raw_message("0", 3).replace('\x01', "|")
+        )
+    );
+    assert_eq!(logger.dropped_count(), 0);
+
+    // Daemon going away must not block nor fail callers.
+    drop(daemon);
+    std::fs::remove_file(&socket_path).unwrap();
+    for _ in 0..3 {
+        logger.on_event(Some(&session_id), "Disconnected");
+    }
+    assert_eq!(logger.dropped_count(), 3);
+    assert!(format!("{logger:?}").contains("dropped: 3"));
+}
+
+/// Writer keeping everything written to it.
+#[derive(Clone, Default)]
+struct CaptureWriter(std::sync::Arc<std::sync::Mutex<Vec<u8>>>);
+
+impl std::io::Write for CaptureWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl CaptureWriter {
+    fn lines(&self) -> Vec<String> {
+        String::from_utf8(self.0.lock().unwrap().clone())
+            .unwrap()
+            .lines()
+            .map(str::to_string)
+            .collect()
+    }
+}
+
+#[test]
+fn test_screen_logger() {
+    let session_id = ServerType::Sender.session_id();
+    let session_repr = session_id.to_repr();
+    let capture = CaptureWriter::default();
+    let logger = ScreenLogger::with_writer(capture.clone())
+        .delimiter('|')
+        .timestamp_format(ScreenLogger::RFC3339)
+        .outgoing(false);
+
+    logger.on_incoming(Some(&session_id), &raw_message("D", 7));
+    logger.on_outgoing(Some(&session_id), &raw_message("8", 7));
+    logger.on_event(None, "Started");
+
+    let lines = capture.lines();
+    assert_eq!(lines.len(), 2);
+    for (line, expected) in lines.iter().zip([
+        format!(
+            " incoming {session_repr}: {}",
+            raw_message("D", 7).replace('\x01', "|")
+        ),
+        " event -: Started".to_string(),
+    ]) {
+        // e.g. 2024-03-05T14:07:09.123Z
+        let (timestamp, rest) = line.split_at(24);
+        assert_eq!(rest, expected);
+        let bytes = timestamp.as_bytes();
+        for (index, byte) in bytes.iter().enumerate() {
+            match index {
+                4 | 7 => assert_eq!(*byte, b'-', "{timestamp}"),
+                10 => assert_eq!(*byte, b'T', "{timestamp}"),
+                13 | 16 => assert_eq!(*byte, b':', "{timestamp}"),
+                19 => assert_eq!(*byte, b'.', "{timestamp}"),
+                23 => assert_eq!(*byte, b'Z', "{timestamp}"),
+                _ => assert!(byte.is_ascii_digit(), "{timestamp}"),
+            }
+        }
+    }
+}
+
+#[test]
+fn test_screen_logger_options() {
+    let capture = CaptureWriter::default();
+    let logger = ScreenLogger::with_writer(capture.clone())
+        .timestamp_format("[%s%%]")
+        .color(true)
+        .events(false);
+    assert!(format!("{logger:?}").contains("output: \"writer\""));
+
+    logger.on_event(None, "Hidden");
+    logger.on_outgoing(None, "35=0\x01");
+    let lines = capture.lines();
+    assert_eq!(lines.len(), 1);
+    // SOH is kept without delimiter.
+    assert!(lines[0].starts_with("\x1b[36m["), "{:?}", lines[0]);
+    assert!(
+        lines[0].ends_with("%] outgoing -: 35=0\x01\x1b[0m"),
+        "{:?}",
+        lines[0]
+    );
+
+    let capture = CaptureWriter::default();
+    let logger = ScreenLogger::with_writer(capture.clone())
+        .no_timestamp()
+        .incoming(false);
+    logger.on_incoming(None, "35=0\x01");
+    logger.on_event(None, "Shown");
+    assert_eq!(capture.lines(), ["event -: Shown"]);
+}
+
+#[test]
+fn test_per_session_log_factory() -> Result<(), QuickFixError> {
+    let sender_log = std::sync::Arc::new(RecordingLogger::default());
+    let receiver_log = std::sync::Arc::new(RecordingLogger::default());
+    let created = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+    let make_factory = || {
+        let sender_log = sender_log.clone();
+        let receiver_log = receiver_log.clone();
+        let created = created.clone();
+        PerSessionLogFactory::try_new(move |session_id| {
+            created.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            let recorder = if session_id.to_repr() == ServerType::Sender.session_id().to_repr() {
+                sender_log.clone()
+            } else {
+                receiver_log.clone()
+            };
+            Box::new(SharedRecorder(recorder)) as Box<dyn LogCallback + Send + Sync>
+        })
+    };
+
+    let mut acceptor = Acceptor::builder(&build_settings(ServerType::Receiver, 0)?)
+        .application(Application::try_new_shared(std::sync::Arc::new(
+            NullFixApplication,
+        ))?)
+        .log(make_factory()?)
+        .build()?;
+    acceptor.start()?;
+    let mut initiator =
+        Initiator::builder(&build_settings(ServerType::Sender, listen_port(&acceptor))?)
+            .application(Application::try_new_shared(std::sync::Arc::new(
+                NullFixApplication,
+            ))?)
+            .log(make_factory()?)
+            .build()?;
+    initiator.start()?;
+    wait_until(Duration::from_secs(10), || {
+        initiator.is_logged_on().unwrap_or(false) && acceptor.is_logged_on().unwrap_or(false)
+    });
+    initiator.stop()?;
+    acceptor.stop()?;
+    drop(initiator);
+    drop(acceptor);
+
+    assert_eq!(created.load(std::sync::atomic::Ordering::SeqCst), 2);
+    // Loggers are dropped with their session.
+    assert_eq!(std::sync::Arc::strong_count(&sender_log), 1);
+    assert_eq!(std::sync::Arc::strong_count(&receiver_log), 1);
+
+    for (recorder, server_type) in [
+        (&sender_log, ServerType::Sender),
+        (&receiver_log, ServerType::Receiver),
+    ] {
+        let records = recorder.0.lock().unwrap();
+        let session = Some(server_type.session_id().to_repr());
+        assert!(records
+            .iter()
+            .all(|(_, record_session, _)| *record_session == session));
+        for callback in ["incoming", "outgoing", "event"] {
+            assert!(
+                records
+                    .iter()
+                    .any(|(record_callback, _, _)| *record_callback == callback),
+                "Missing {callback} records for {session:?}"
+            );
+        }
+        assert!(records
+            .iter()
+            .any(|(callback, _, msg)| *callback == "outgoing" && msg.contains("\x0135=A\x01")));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_event_severity_infer() {
+    for (msg, severity) in [
+        ("Created session", EventSeverity::Info),
+        ("Received logon", EventSeverity::Info),
+        ("Disconnecting", EventSeverity::Warning),
+        ("Received logout request", EventSeverity::Warning),
+        ("Sent test request TEST", EventSeverity::Warning),
+        (
+            "Logon contains ResetSeqNumFlag=Y, resetting sequence numbers to 1",
+            EventSeverity::Warning,
+        ),
+        (
+            "Socket Error: Connection reset by peer.",
+            EventSeverity::Error,
+        ),
+        (
+            "Message 3 Rejected: Invalid tag number:9999",
+            EventSeverity::Error,
+        ),
+        (
+            "MsgSeqNum too low, expecting 5 but received 2",
+            EventSeverity::Error,
+        ),
+    ] {
+        assert_eq!(EventSeverity::infer(msg), severity, "{msg}");
+    }
+    assert!(EventSeverity::Info < EventSeverity::Warning);
+    assert!(EventSeverity::Warning < EventSeverity::Error);
+}
+
+/// Logger keeping events with their severity, ignoring messages.
+#[derive(Default)]
+struct SeverityRecorder(std::sync::Mutex<Vec<(Option<String>, String, EventSeverity)>>);
+
+impl LogCallback for SeverityRecorder {
+    fn on_event_with_severity(
+        &self,
+        session_id: Option<&SessionId>,
+        msg: &str,
+        severity: EventSeverity,
+    ) {
+        self.0.lock().unwrap().push((
+            session_id.map(SessionId::to_repr),
+            msg.to_string(),
+            severity,
+        ));
+    }
+}
+
+#[test]
+fn test_forced_disconnect_event_severity() -> Result<(), QuickFixError> {
+    static LOGGER: std::sync::OnceLock<SeverityRecorder> = std::sync::OnceLock::new();
+    let logger = LOGGER.get_or_init(SeverityRecorder::default);
+
+    let mut acceptor = Acceptor::builder(&build_settings(ServerType::Receiver, 0)?)
+        .application(Application::try_new_shared(std::sync::Arc::new(
+            NullFixApplication,
+        ))?)
+        .log(LogFactory::try_new(logger)?)
+        .build()?;
+    acceptor.start()?;
+    let mut initiator =
+        Initiator::builder(&build_settings(ServerType::Sender, listen_port(&acceptor))?)
+            .application(Application::try_new_shared(std::sync::Arc::new(
+                NullFixApplication,
+            ))?)
+            .log(LogFactory::try_new(logger)?)
+            .build()?;
+    initiator.start()?;
+    wait_until(Duration::from_secs(10), || {
+        initiator.is_logged_on().unwrap_or(false) && acceptor.is_logged_on().unwrap_or(false)
+    });
+
+    // Logon events are informational.
+    assert!(logger
+        .0
+        .lock()
+        .unwrap()
+        .iter()
+        .all(|(_, _, severity)| *severity == EventSeverity::Info));
+
+    unsafe { Session::lookup(&ServerType::Receiver.session_id()) }?.disconnect()?;
+    wait_until(Duration::from_secs(5), || {
+        logger
+            .0
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|(_, _, severity)| *severity >= EventSeverity::Warning)
+    });
+    initiator.stop()?;
+    acceptor.stop()?;
+
+    let records = logger.0.lock().unwrap();
+    assert!(
+        records
+            .iter()
+            .any(|(session, _, severity)| session.is_some() && *severity >= EventSeverity::Warning),
+        "{records:?}"
+    );
+    Ok(())
+}
@@ -0,0 +1,103 @@
+use std::{
+    hint::black_box,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use quickfix::{FieldMap, Group, Message, MessagePool, QuickFixError};
+
+static GLOBAL_LOCK: Mutex<u8> = Mutex::new(0);
+
+const WARMUP_ITERS: usize = 2_000;
+const BENCH_ITERS: usize = 20_000;
+
+#[derive(Debug)]
+struct BenchResult {
+    iterations: usize,
+    clone: Duration,
+    copy_from_pooled: Duration,
+}
+
+fn build_template() -> Result<Message, QuickFixError> {
+    let mut msg = Message::new();
+    msg.with_header_mut(|h| -> Result<(), QuickFixError> {
+        h.set_field(8, "FIX.4.4")?;
+        h.set_field(35, "D")?;
+        h.set_field(49, "SENDER")?;
+        h.set_field(56, "TARGET")?;
+        Ok(())
+    })?;
+
+    msg.set_field(11, "ORDER-12345")?;
+    msg.set_field(55, "AAPL")?;
+    msg.set_field(54, 1)?;
+    msg.set_field(38, 100)?;
+    msg.set_field(44, 189.42)?;
+
+    let mut party = Group::try_new(453, 448)?;
+    party.set_field(448, "PARTY-1")?;
+    party.set_field(447, "D")?;
+    party.set_field(452, 1)?;
+    msg.add_group(&party)?;
+
+    Ok(msg)
+}
+
+fn run_bench(iterations: usize) -> Result<BenchResult, QuickFixError> {
+    let _lock = GLOBAL_LOCK.lock().expect("GLOBAL_LOCK poisoned");
+
+    let template = build_template()?;
+    let pool = MessagePool::with_capacity(1);
+
+    for _ in 0..WARMUP_ITERS {
+        black_box(template.clone());
+        pool.acquire().copy_from(&template)?;
+    }
+
+    let t0 = Instant::now();
+    for _ in 0..iterations {
+        let msg = template.clone();
+        black_box(&msg);
+    }
+    let clone = t0.elapsed();
+
+    let t1 = Instant::now();
+    for _ in 0..iterations {
+        let mut msg = pool.acquire();
+        msg.copy_from(&template)?;
+        black_box(&msg);
+    }
+    let copy_from_pooled = t1.elapsed();
+
+    Ok(BenchResult {
+        iterations,
+        clone,
+        copy_from_pooled,
+    })
+}
+
+#[test]
+#[ignore = "Manual benchmark; run with -- --ignored --nocapture"]
+fn bench_clone_vs_copy_from() -> Result<(), QuickFixError> {
+    let result = run_bench(BENCH_ITERS)?;
+
+    let clone_ns_per_op = result.clone.as_nanos() as f64 / result.iterations as f64;
+    let copy_from_ns_per_op = result.copy_from_pooled.as_nanos() as f64 / result.iterations as f64;
+    let delta_pct = ((result.clone.as_nanos() as f64 - result.copy_from_pooled.as_nanos() as f64)
+        / result.clone.as_nanos() as f64)
+        * 100.0;
+
+    println!("=== message copy benchmark ===");
+    println!("iterations: {}", result.iterations);
+    println!(
+        "1) clone (new C++ object):            {:?} ({:.0} ns/op)",
+        result.clone, clone_ns_per_op
+    );
+    println!(
+        "2) copy_from into pooled message:     {:?} ({:.0} ns/op)",
+        result.copy_from_pooled, copy_from_ns_per_op
+    );
+    println!("delta vs #1: {:.2}%", delta_pct);
+
+    Ok(())
+}
@@ -199,53 +199,60 @@ fn test_build_custom_with_recursive_groups() -> Result<(), QuickFixError> {
 #[test]
 fn test_read_group_clone() {
     let msg = build_news("Great news", &["Some new library", "are available", "soon"]).unwrap();
-    assert_eq!(msg.get_field(MSG_HEADLINE).unwrap(), "Great news");
+    assert_eq!(msg.get_field(tags::HEADLINE).unwrap(), "Great news");
 
     // Read before
-    assert!(msg.clone_group(0, MSG_NO_LINES_OF_TEXT).is_none());
+    assert!(msg.clone_group(0, tags::NO_LINES_OF_TEXT).is_none());
 
     // Read configured lines
-    let group = msg.clone_group(1, MSG_NO_LINES_OF_TEXT).unwrap();
-    assert_eq!(group.get_field(MSG_TEXT).unwrap(), "Some new library");
+    let group = msg.clone_group(1, tags::NO_LINES_OF_TEXT).unwrap();
+    assert_eq!(group.get_field(tags::TEXT).unwrap(), "Some new library");
 
-    let group = msg.clone_group(2, MSG_NO_LINES_OF_TEXT).unwrap();
-    assert_eq!(group.get_field(MSG_TEXT).unwrap(), "are available");
+    let group = msg.clone_group(2, tags::NO_LINES_OF_TEXT).unwrap();
+    assert_eq!(group.get_field(tags::TEXT).unwrap(), "are available");
 
-    let group = msg.clone_group(3, MSG_NO_LINES_OF_TEXT).unwrap();
-    assert_eq!(group.get_field(MSG_TEXT).unwrap(), "soon");
+    let group = msg.clone_group(3, tags::NO_LINES_OF_TEXT).unwrap();
+    assert_eq!(group.get_field(tags::TEXT).unwrap(), "soon");
 
     // Read after
-    assert!(msg.clone_group(4, MSG_NO_LINES_OF_TEXT).is_none());
+    assert!(msg.clone_group(4, tags::NO_LINES_OF_TEXT).is_none());
 }
 
 #[test]
 fn test_read_group_ref() {
     let msg = build_news("Great news", &["Some new library", "are available", "soon"]).unwrap();
-    assert_eq!(msg.get_field(MSG_HEADLINE).unwrap(), "Great news");
+    assert_eq!(msg.get_field(tags::HEADLINE).unwrap(), "Great news");
 
     fn read_text(group: &Group) -> String {
-        group.get_field(MSG_TEXT).unwrap()
+        group.get_field(tags::TEXT).unwrap()
     }
 
     // Read before
-    assert!(msg.with_group(0, MSG_NO_LINES_OF_TEXT, read_text).is_none());
+    assert!(msg
+        .with_group(0, tags::NO_LINES_OF_TEXT, read_text)
+        .is_none());
 
     // Read configured lines
     assert_eq!(
-        msg.with_group(1, MSG_NO_LINES_OF_TEXT, read_text).unwrap(),
+        msg.with_group(1, tags::NO_LINES_OF_TEXT, read_text)
+            .unwrap(),
         "Some new library"
     );
     assert_eq!(
-        msg.with_group(2, MSG_NO_LINES_OF_TEXT, read_text).unwrap(),
+        msg.with_group(2, tags::NO_LINES_OF_TEXT, read_text)
+            .unwrap(),
         "are available"
     );
     assert_eq!(
-        msg.with_group(3, MSG_NO_LINES_OF_TEXT, read_text).unwrap(),
+        msg.with_group(3, tags::NO_LINES_OF_TEXT, read_text)
+            .unwrap(),
         "soon"
     );
 
     // Read after
-    assert!(msg.with_group(4, MSG_NO_LINES_OF_TEXT, read_text).is_none());
+    assert!(msg
+        .with_group(4, tags::NO_LINES_OF_TEXT, read_text)
+        .is_none());
 }
 
 #[test]
@@ -265,15 +272,15 @@ fn test_modify_group() {
 
     // Update valid group
     assert!(msg
-        .with_group_mut(2, MSG_NO_LINES_OF_TEXT, |g| g
-            .set_field(MSG_TEXT, "will be available")
+        .with_group_mut(2, tags::NO_LINES_OF_TEXT, |g| g
+            .set_field(tags::TEXT, "will be available")
             .unwrap())
         .is_some());
 
     // Update invalid group
     assert!(msg
-        .with_group_mut(58, MSG_NO_LINES_OF_TEXT, |g| g
-            .set_field(MSG_TEXT, "whatever")
+        .with_group_mut(58, tags::NO_LINES_OF_TEXT, |g| g
+            .set_field(tags::TEXT, "whatever")
             .unwrap())
         .is_none());
 
@@ -287,3 +294,69 @@ fn test_modify_group() {
          10=127\u{1}"
     );
 }
+
+fn build_snapshot_header(msg: &mut Message) -> Result<(), QuickFixError> {
+    msg.with_header_mut(|h| {
+        h.set_field(tags::BEGIN_STRING, "FIX.4.4")?;
+        h.set_field(tags::MSG_TYPE, "W")?;
+        h.set_field(tags::SENDER_COMP_ID, "SENDER")?;
+        h.set_field(tags::TARGET_COMP_ID, "TARGET")?;
+        h.set_field(tags::MSG_SEQ_NUM, 1)?;
+        h.set_field(tags::SENDING_TIME, "20240101-00:00:00.000")
+    })?;
+    msg.set_field(tags::SYMBOL, "EUR/USD")
+}
+
+#[test]
+fn test_add_groups() -> Result<(), QuickFixError> {
+    const ROWS: usize = 200;
+
+    let prices: Vec<_> = (0..ROWS).map(|i| format!("1.{i:04}")).collect();
+    let sizes: Vec<_> = (0..ROWS).map(|i| (i * 100).to_string()).collect();
+    let rows: Vec<[(i32, &str); 2]> = (0..ROWS)
+        .map(|i| [(270, prices[i].as_str()), (271, sizes[i].as_str())])
+        .collect();
+    let rows: Vec<&[(i32, &str)]> = rows.iter().map(|row| &row[..]).collect();
+
+    let mut template = Group::try_with_orders(268, 269, &[269, 270, 271])?;
+    template.set_field(269, "0")?;
+
+    // Bulk add.
+    let mut bulk = Message::new();
+    build_snapshot_header(&mut bulk)?;
+    bulk.add_groups(&template, &rows)?;
+    assert_eq!(bulk.get_field(268).as_deref(), Some("200"));
+
+    // Same message than adding groups one by one.
+    let mut single = Message::new();
+    build_snapshot_header(&mut single)?;
+    for row in &rows {
+        let mut group = template.clone();
+        for (tag, value) in row.iter() {
+            group.set_field(*tag, *value)?;
+        }
+        single.add_group(&group)?;
+    }
+    let text = bulk.to_fix_string()?;
+    assert_eq!(text, single.to_fix_string()?);
+
+    // Re-parse and count entries.
+    let dd = DataDictionary::try_from_path("../quickfix-ffi/libquickfix/spec/FIX44.xml")?;
+    let parsed = dd.try_build_message(&text)?;
+    assert_eq!(parsed.get_field(268).as_deref(), Some("200"));
+    for (index, row) in rows.iter().enumerate() {
+        let group = parsed.clone_group(index as i32 + 1, 268).unwrap();
+        assert_eq!(group.get_field(269).as_deref(), Some("0"));
+        assert_eq!(group.get_field(270).as_deref(), Some(row[0].1));
+        assert_eq!(group.get_field(271).as_deref(), Some(row[1].1));
+    }
+    assert!(parsed.clone_group(201, 268).is_none());
+
+    // Rows are appended after existing groups.
+    bulk.add_groups(&template, &[&[(270, "2.0")]])?;
+    assert_eq!(bulk.get_field(268).as_deref(), Some("201"));
+    bulk.add_groups(&template, &[])?;
+    assert_eq!(bulk.get_field(268).as_deref(), Some("201"));
+
+    Ok(())
+}
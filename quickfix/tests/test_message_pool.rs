@@ -0,0 +1,138 @@
+use quickfix::*;
+use utils::*;
+
+mod utils;
+
+fn fill(msg: &mut Message) -> Result<(), QuickFixError> {
+    msg.with_header_mut(|h| h.set_field(tags::MSG_TYPE, "B"))?;
+    msg.set_field(tags::HEADLINE, "Hello")?;
+    msg.set_field(tags::NO_LINES_OF_TEXT, 1)?;
+    let mut group = Group::try_new(tags::NO_LINES_OF_TEXT, tags::TEXT)?;
+    group.set_field(tags::TEXT, "line")?;
+    msg.add_group(&group)?;
+    msg.with_trailer_mut(|t| t.set_field(93, "4"))
+}
+
+fn assert_empty(msg: &Message) {
+    assert_eq!(msg.get_field(tags::HEADLINE), None);
+    assert_eq!(msg.get_field(tags::NO_LINES_OF_TEXT), None);
+    assert!(msg.clone_group(1, tags::NO_LINES_OF_TEXT).is_none());
+    msg.with_header(|h| {
+        assert_eq!(h.get_field(tags::MSG_TYPE), None);
+        assert_eq!(h.get_field(tags::MSG_SEQ_NUM), None);
+        assert_eq!(h.get_field(tags::SENDING_TIME), None);
+    });
+    msg.with_trailer(|t| assert_eq!(t.get_field(93), None));
+}
+
+#[test]
+fn test_clear() -> Result<(), QuickFixError> {
+    let mut msg = Message::new();
+    fill(&mut msg)?;
+    msg.clear()?;
+    assert_empty(&msg);
+    assert_eq!(msg.to_fix_string()?, Message::new().to_fix_string()?);
+
+    // Message is still usable once cleared.
+    msg.set_field(tags::HEADLINE, "Again")?;
+    assert_eq!(msg.get_field(tags::HEADLINE).as_deref(), Some("Again"));
+    Ok(())
+}
+
+#[test]
+fn test_recycled_message_is_empty() -> Result<(), QuickFixError> {
+    let pool = MessagePool::with_capacity(2);
+    assert_eq!(pool.available(), 2);
+
+    {
+        let mut msg = pool.acquire();
+        assert_eq!(pool.available(), 1);
+        fill(&mut msg)?;
+    }
+    assert_eq!(pool.available(), 2);
+
+    for _ in 0..3 {
+        let msg = pool.acquire();
+        assert_empty(&msg);
+    }
+
+    // Pool grows on demand.
+    let msgs: Vec<_> = (0..4).map(|_| pool.acquire()).collect();
+    assert_eq!(pool.available(), 0);
+    drop(msgs);
+    assert_eq!(pool.available(), 4);
+
+    // Detached message does not come back.
+    let mut msg = pool.acquire().into_inner();
+    fill(&mut msg)?;
+    drop(msg);
+    assert_eq!(pool.available(), 3);
+    Ok(())
+}
+
+#[test]
+fn test_pooled_send_by_ref_mut() -> Result<(), QuickFixError> {
+    let pool = MessagePool::new();
+
+    with_session_pair(&NullFixApplication, &NullFixApplication, &[], |_, _| {
+        for _ in 0..3 {
+            let mut msg = pool.acquire();
+            assert_empty(&msg);
+            fill(&mut msg)?;
+            send_to_target_by_ref_mut(&mut msg, &ServerType::Sender.session_id())?;
+
+            // Send stamped session header fields on the message.
+            msg.with_header(|h| assert!(h.get_field(tags::MSG_SEQ_NUM).is_some()));
+        }
+        Ok(())
+    })?;
+
+    assert_eq!(pool.available(), 1);
+    Ok(())
+}
+
+#[test]
+fn test_copy_from() -> Result<(), QuickFixError> {
+    let mut template = Message::new();
+    fill(&mut template)?;
+
+    // Destination already holds other fields, which must not survive the copy.
+    let mut msg = Message::new();
+    msg.set_field(tags::SYMBOL, "AAPL")?;
+    msg.with_header_mut(|h| h.set_field(tags::MSG_SEQ_NUM, 42))?;
+
+    msg.copy_from(&template)?;
+    assert_eq!(msg.to_fix_string()?, template.to_fix_string()?);
+    assert_eq!(msg.get_field(tags::SYMBOL), None);
+    assert_eq!(
+        msg.clone_group(1, tags::NO_LINES_OF_TEXT)
+            .and_then(|group| group.get_field(tags::TEXT))
+            .as_deref(),
+        Some("line")
+    );
+
+    // Copy is independent from its source.
+    msg.set_field(tags::HEADLINE, "Changed")?;
+    assert_eq!(template.get_field(tags::HEADLINE).as_deref(), Some("Hello"));
+
+    // `clone_from` goes through the same path.
+    msg.clone_from(&template);
+    assert_eq!(msg.to_fix_string()?, template.to_fix_string()?);
+    Ok(())
+}
+
+#[test]
+fn test_copy_into_pooled_message() -> Result<(), QuickFixError> {
+    let pool = MessagePool::with_capacity(1);
+    let mut template = Message::new();
+    fill(&mut template)?;
+    let expected = template.to_fix_string()?;
+
+    for _ in 0..3 {
+        let mut msg = pool.acquire();
+        msg.copy_from(&template)?;
+        assert_eq!(msg.to_fix_string()?, expected);
+    }
+    assert_eq!(pool.available(), 1);
+    Ok(())
+}
@@ -0,0 +1,123 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+
+use quickfix::*;
+use utils::*;
+
+mod utils;
+
+fn build_message(msg_type: &str) -> Result<Message, QuickFixError> {
+    let mut msg = Message::new();
+    msg.with_header_mut(|h| h.set_field(tags::MSG_TYPE, msg_type))?;
+    Ok(msg)
+}
+
+#[test]
+fn test_route_by_msg_type() -> Result<(), QuickFixError> {
+    let routed = Arc::new(Mutex::new(Vec::new()));
+    let session_id = ServerType::Receiver.session_id();
+
+    let router = MessageRouter::new();
+    for (msg_type, name) in [("8", "execution_report"), ("B", "news")] {
+        let routed = routed.clone();
+        router.on(msg_type, move |_msg, _session| {
+            routed.lock().unwrap().push(name);
+            Ok(())
+        });
+    }
+
+    assert!(router.route(build_message("8")?, &session_id).is_ok());
+    assert!(router.route(build_news("Hello", &[])?, &session_id).is_ok());
+    assert_eq!(*routed.lock().unwrap(), vec!["execution_report", "news"]);
+
+    // Unknown type without fallback.
+    assert!(matches!(
+        router.route(build_message("D")?, &session_id),
+        Err(MsgFromAppError::UnsupportedMessageType)
+    ));
+
+    // Unknown type with fallback.
+    let fallback_routed = routed.clone();
+    router.fallback(move |msg, _session| {
+        let msg_type = msg.with_header(|h| h.get_field(tags::MSG_TYPE)).unwrap();
+        assert_eq!(msg_type, "D");
+        fallback_routed.lock().unwrap().push("fallback");
+        Ok(())
+    });
+    assert!(router.route(build_message("D")?, &session_id).is_ok());
+    assert_eq!(
+        *routed.lock().unwrap(),
+        vec!["execution_report", "news", "fallback"]
+    );
+
+    // Removed handler goes to fallback.
+    assert!(router.remove("8"));
+    assert!(!router.remove("8"));
+    assert!(router.route(build_message("8")?, &session_id).is_ok());
+    assert_eq!(routed.lock().unwrap().last(), Some(&"fallback"));
+
+    Ok(())
+}
+
+#[test]
+fn test_route_handler_error() -> Result<(), QuickFixError> {
+    let router = MessageRouter::new();
+    router.on("B", |_msg, _session| {
+        Err(MsgFromAppError::IncorrectTagValue {
+            tag: tags::HEADLINE,
+        })
+    });
+
+    assert!(matches!(
+        router.route(
+            build_news("Hello", &[])?,
+            &ServerType::Receiver.session_id()
+        ),
+        Err(MsgFromAppError::IncorrectTagValue {
+            tag: tags::HEADLINE
+        })
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_routed_application() -> Result<(), QuickFixError> {
+    let news_count = Arc::new(AtomicUsize::new(0));
+    let receiver = RoutedApplication::new(LogonCounter::default(), MessageRouter::new());
+
+    with_session_pair(&NullFixApplication, &receiver, &[], |_, _| {
+        // Other callbacks still reach inner application.
+        wait_until(Duration::from_secs(5), || {
+            receiver.inner().logon_count.load(Ordering::Relaxed) == 1
+        });
+
+        // Register handler once session is running.
+        let handler_news_count = news_count.clone();
+        receiver.router().on("B", move |_msg, _session| {
+            handler_news_count.fetch_add(1, Ordering::Relaxed);
+            Ok(())
+        });
+
+        send_to_target(build_news("Hello", &[])?, &ServerType::Sender.session_id())?;
+        wait_until(Duration::from_secs(5), || {
+            news_count.load(Ordering::Relaxed) == 1
+        });
+        Ok(())
+    })
+}
+
+#[derive(Debug, Default)]
+struct LogonCounter {
+    logon_count: AtomicUsize,
+}
+
+impl ApplicationCallback for LogonCounter {
+    fn on_logon(&self, _session: &SessionId) {
+        self.logon_count.fetch_add(1, Ordering::Relaxed);
+    }
+}
@@ -1,4 +1,13 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Mutex,
+    time::{Duration, SystemTime},
+};
+
 use quickfix::*;
+use utils::*;
+
+mod utils;
 
 #[test]
 fn test_file() {
@@ -34,3 +43,1235 @@ fn test_null() {
     let message_store_factory = NullMessageStoreFactory::new();
     let _ptr = message_store_factory.as_ffi_ptr();
 }
+
+/// Record NewSeqNo(36) of every SequenceReset-GapFill received, along with received news.
+#[derive(Debug, Default)]
+struct GapFillRecorder {
+    gap_fills: Mutex<Vec<String>>,
+    news: NewsRecorder,
+}
+
+impl ApplicationCallback for GapFillRecorder {
+    fn on_msg_from_admin(
+        &self,
+        msg: Message,
+        _session: &SessionId,
+    ) -> Result<(), MsgFromAdminError> {
+        let msg_type = msg.with_header(|header| header.get_field(tags::MSG_TYPE));
+        if msg_type.as_deref() == Some("4")
+            && msg.get_field(tags::GAP_FILL_FLAG).as_deref() == Some("Y")
+        {
+            let new_seq_no = msg.get_field(tags::NEW_SEQ_NO).unwrap_or_default();
+            self.gap_fills.lock().unwrap().push(new_seq_no);
+        }
+        Ok(())
+    }
+
+    fn on_msg_from_app(&self, msg: Message, session: &SessionId) -> Result<(), MsgFromAppError> {
+        self.news.on_msg_from_app(msg, session)
+    }
+}
+
+#[test]
+fn test_null_resend_sends_gap_fill() -> Result<(), QuickFixError> {
+    let receiver = GapFillRecorder::default();
+
+    let app_sender = Application::try_new(&NullFixApplication)?;
+    let app_receiver = Application::try_new(&receiver)?;
+    let store_factory_sender = NullMessageStoreFactory::new();
+    let store_factory_receiver = MemoryMessageStoreFactory::new();
+
+    let mut acceptor = Acceptor::try_new(
+        &build_settings(ServerType::Receiver, 0)?,
+        &app_receiver,
+        &store_factory_receiver,
+        FixSocketServerKind::default(),
+    )?;
+    acceptor.start()?;
+    let mut initiator = Initiator::try_new(
+        &build_settings(ServerType::Sender, listen_port(&acceptor))?,
+        &app_sender,
+        &store_factory_sender,
+        FixSocketServerKind::default(),
+    )?;
+    initiator.start()?;
+    wait_until(Duration::from_secs(10), || {
+        initiator.is_logged_on().unwrap_or(false) && acceptor.is_logged_on().unwrap_or(false)
+    });
+
+    let sender_id = ServerType::Sender.session_id();
+    let mut receiver_session = unsafe { Session::lookup(&ServerType::Receiver.session_id()) }?;
+    let first_seq_num = receiver_session.get_expected_target_num();
+    for index in 0..3 {
+        send_to_target(build_news(&format!("Hello {index}"), &[])?, &sender_id)?;
+    }
+    wait_until(Duration::from_secs(5), || {
+        receiver.news.news.lock().unwrap().len() == 3
+    });
+
+    // Nothing to replay: the whole range is gap filled.
+    receiver_session.set_next_target_msg_seq_num(first_seq_num)?;
+    receiver_session.request_resend(first_seq_num as u32, 0)?;
+    wait_until(Duration::from_secs(5), || {
+        !receiver.gap_fills.lock().unwrap().is_empty()
+    });
+
+    assert_eq!(
+        *receiver.gap_fills.lock().unwrap(),
+        vec![(first_seq_num + 3).to_string()]
+    );
+    wait_until(Duration::from_secs(5), || {
+        receiver_session.get_expected_target_num() == first_seq_num + 3
+    });
+    assert_eq!(
+        receiver_session.get_expected_target_num(),
+        first_seq_num + 3
+    );
+    assert_eq!(receiver.news.news.lock().unwrap().len(), 3);
+
+    initiator.stop()?;
+    acceptor.stop()?;
+    Ok(())
+}
+
+/// State of a single session inside [`RustStore`].
+#[derive(Debug)]
+struct StoredSession {
+    messages: BTreeMap<u32, String>,
+    next_sender: u32,
+    next_target: u32,
+    created_at: SystemTime,
+}
+
+impl StoredSession {
+    fn new(now: SystemTime) -> Self {
+        Self {
+            messages: BTreeMap::new(),
+            next_sender: 1,
+            next_target: 1,
+            created_at: now,
+        }
+    }
+}
+
+/// In memory message store implemented in rust, recording resend reads.
+#[derive(Debug, Default)]
+struct RustStore {
+    sessions: Mutex<HashMap<String, StoredSession>>,
+    reads: Mutex<Vec<(u32, u32)>>,
+}
+
+impl RustStore {
+    fn with<T>(&self, session_id: &SessionId, f: impl FnOnce(&mut StoredSession) -> T) -> T {
+        let mut sessions = self.sessions.lock().unwrap();
+        let session = sessions
+            .entry(session_id.to_repr())
+            .or_insert_with(|| StoredSession::new(SystemTime::now()));
+        f(session)
+    }
+}
+
+impl MessageStoreCallback for RustStore {
+    fn get(
+        &self,
+        session_id: &SessionId,
+        begin: u32,
+        end: u32,
+    ) -> Result<Vec<String>, QuickFixError> {
+        self.reads.lock().unwrap().push((begin, end));
+        Ok(self.with(session_id, |session| {
+            session
+                .messages
+                .range(begin..=end)
+                .map(|(_, msg)| msg.clone())
+                .collect()
+        }))
+    }
+
+    fn set(&self, session_id: &SessionId, seq: u32, msg: &str) -> Result<bool, QuickFixError> {
+        self.with(session_id, |session| {
+            session.messages.insert(seq, msg.to_string());
+        });
+        Ok(true)
+    }
+
+    fn next_sender_msg_seq_num(&self, session_id: &SessionId) -> Result<u32, QuickFixError> {
+        Ok(self.with(session_id, |session| session.next_sender))
+    }
+
+    fn next_target_msg_seq_num(&self, session_id: &SessionId) -> Result<u32, QuickFixError> {
+        Ok(self.with(session_id, |session| session.next_target))
+    }
+
+    fn set_next_sender_msg_seq_num(
+        &self,
+        session_id: &SessionId,
+        value: u32,
+    ) -> Result<(), QuickFixError> {
+        self.with(session_id, |session| session.next_sender = value);
+        Ok(())
+    }
+
+    fn set_next_target_msg_seq_num(
+        &self,
+        session_id: &SessionId,
+        value: u32,
+    ) -> Result<(), QuickFixError> {
+        self.with(session_id, |session| session.next_target = value);
+        Ok(())
+    }
+
+    fn creation_time(&self, session_id: &SessionId) -> Result<SystemTime, QuickFixError> {
+        Ok(self.with(session_id, |session| session.created_at))
+    }
+
+    fn reset(&self, session_id: &SessionId, now: SystemTime) -> Result<(), QuickFixError> {
+        self.with(session_id, |session| *session = StoredSession::new(now));
+        Ok(())
+    }
+}
+
+/// Store failing to create any session.
+struct UnavailableStore;
+
+impl MessageStoreCallback for UnavailableStore {
+    fn on_create(&self, _session_id: &SessionId) -> Result<(), QuickFixError> {
+        Err(QuickFixError::IOException("store unavailable".to_string()))
+    }
+
+    fn get(&self, _: &SessionId, _: u32, _: u32) -> Result<Vec<String>, QuickFixError> {
+        unreachable!()
+    }
+
+    fn set(&self, _: &SessionId, _: u32, _: &str) -> Result<bool, QuickFixError> {
+        unreachable!()
+    }
+
+    fn next_sender_msg_seq_num(&self, _: &SessionId) -> Result<u32, QuickFixError> {
+        unreachable!()
+    }
+
+    fn next_target_msg_seq_num(&self, _: &SessionId) -> Result<u32, QuickFixError> {
+        unreachable!()
+    }
+
+    fn set_next_sender_msg_seq_num(&self, _: &SessionId, _: u32) -> Result<(), QuickFixError> {
+        unreachable!()
+    }
+
+    fn set_next_target_msg_seq_num(&self, _: &SessionId, _: u32) -> Result<(), QuickFixError> {
+        unreachable!()
+    }
+
+    fn creation_time(&self, _: &SessionId) -> Result<SystemTime, QuickFixError> {
+        unreachable!()
+    }
+
+    fn reset(&self, _: &SessionId, _: SystemTime) -> Result<(), QuickFixError> {
+        unreachable!()
+    }
+}
+
+/// Record headline of every received News, with its PossDupFlag.
+#[derive(Debug, Default)]
+struct NewsRecorder {
+    news: Mutex<Vec<(String, bool)>>,
+}
+
+impl ApplicationCallback for NewsRecorder {
+    fn on_msg_from_app(&self, msg: Message, _session: &SessionId) -> Result<(), MsgFromAppError> {
+        let headline = msg.get_field(tags::HEADLINE).unwrap_or_default();
+        let poss_dup = msg
+            .with_header(|header| header.get_field(tags::POSS_DUP_FLAG))
+            .is_some_and(|value| value == "Y");
+        self.news.lock().unwrap().push((headline, poss_dup));
+        Ok(())
+    }
+}
+
+#[test]
+fn test_custom() -> Result<(), QuickFixError> {
+    let store = RustStore::default();
+    let receiver = NewsRecorder::default();
+
+    let app_sender = Application::try_new(&NullFixApplication)?;
+    let app_receiver = Application::try_new(&receiver)?;
+    let store_factory_sender = CustomMessageStoreFactory::try_new(&store)?;
+    let store_factory_receiver = MemoryMessageStoreFactory::new();
+
+    let mut acceptor = Acceptor::try_new(
+        &build_settings(ServerType::Receiver, 0)?,
+        &app_receiver,
+        &store_factory_receiver,
+        FixSocketServerKind::default(),
+    )?;
+    acceptor.start()?;
+    let mut initiator = Initiator::try_new(
+        &build_settings(ServerType::Sender, listen_port(&acceptor))?,
+        &app_sender,
+        &store_factory_sender,
+        FixSocketServerKind::default(),
+    )?;
+    initiator.start()?;
+    wait_until(Duration::from_secs(10), || {
+        initiator.is_logged_on().unwrap_or(false) && acceptor.is_logged_on().unwrap_or(false)
+    });
+
+    let sender_id = ServerType::Sender.session_id();
+    let mut receiver_session = unsafe { Session::lookup(&ServerType::Receiver.session_id()) }?;
+    let first_seq_num = receiver_session.get_expected_target_num();
+    for index in 0..3 {
+        send_to_target(build_news(&format!("Hello {index}"), &[])?, &sender_id)?;
+    }
+    wait_until(Duration::from_secs(5), || {
+        receiver.news.lock().unwrap().len() == 3
+    });
+
+    // Sequence numbers come from the rust store.
+    let sender_session = unsafe { Session::lookup(&sender_id) }?;
+    assert_eq!(
+        sender_session.get_expected_sender_num() as u32,
+        store.next_sender_msg_seq_num(&sender_id)?
+    );
+    assert_eq!(
+        store.next_target_msg_seq_num(&sender_id)?,
+        sender_session.get_expected_target_num() as u32
+    );
+
+    // Replayed messages are read back from it.
+    receiver_session.set_next_target_msg_seq_num(first_seq_num)?;
+    receiver_session.request_resend(first_seq_num as u32, 0)?;
+    wait_until(Duration::from_secs(5), || {
+        receiver.news.lock().unwrap().len() == 6
+    });
+
+    assert!(store
+        .reads
+        .lock()
+        .unwrap()
+        .iter()
+        .any(|(begin, _)| *begin == first_seq_num as u32));
+    let news = receiver.news.lock().unwrap();
+    for index in 0..3 {
+        assert_eq!(news[index], (format!("Hello {index}"), false));
+        assert_eq!(news[index + 3], (format!("Hello {index}"), true));
+    }
+    drop(news);
+
+    initiator.stop()?;
+    acceptor.stop()?;
+    Ok(())
+}
+
+#[test]
+fn test_custom_error() -> Result<(), QuickFixError> {
+    let app = Application::try_new(&NullFixApplication)?;
+    let store_factory = CustomMessageStoreFactory::try_new(&UnavailableStore)?;
+
+    // Store error is reported with its message, instead of aborting the process.
+    let err = Initiator::try_new(
+        &build_settings(ServerType::Sender, 1)?,
+        &app,
+        &store_factory,
+        FixSocketServerKind::default(),
+    )
+    .and_then(|mut initiator| initiator.start())
+    .unwrap_err();
+    assert!(err.to_string().contains("store unavailable"), "{err}");
+    Ok(())
+}
+
+/// Log on with `callbacks` and stores built by `make_store`, send `count` news and return sender
+/// next sequence numbers.
+fn run_with_stores<A, F, M>(
+    callbacks: &A,
+    make_store: M,
+    count: usize,
+) -> Result<(i32, i32), QuickFixError>
+where
+    A: ApplicationCallback,
+    F: FfiMessageStoreFactory,
+    M: Fn(ServerType) -> Result<F, QuickFixError>,
+{
+    let app = Application::try_new(callbacks)?;
+    let store_factory_sender = make_store(ServerType::Sender)?;
+    let store_factory_receiver = make_store(ServerType::Receiver)?;
+
+    let mut acceptor = Acceptor::try_new(
+        &build_settings(ServerType::Receiver, 0)?,
+        &app,
+        &store_factory_receiver,
+        FixSocketServerKind::default(),
+    )?;
+    acceptor.start()?;
+    let mut initiator = Initiator::try_new(
+        &build_settings(ServerType::Sender, listen_port(&acceptor))?,
+        &app,
+        &store_factory_sender,
+        FixSocketServerKind::default(),
+    )?;
+    initiator.start()?;
+    wait_until(Duration::from_secs(10), || {
+        initiator.is_logged_on().unwrap_or(false) && acceptor.is_logged_on().unwrap_or(false)
+    });
+
+    let sender_session = unsafe { Session::lookup(&ServerType::Sender.session_id()) }?;
+    let receiver_session = unsafe { Session::lookup(&ServerType::Receiver.session_id()) }?;
+    for index in 0..count {
+        send_to_target(
+            build_news(&format!("Hello {index}"), &[])?,
+            &ServerType::Sender.session_id(),
+        )?;
+    }
+    let expected = sender_session.get_expected_sender_num();
+    wait_until(Duration::from_secs(5), || {
+        receiver_session.get_expected_target_num() == expected
+    });
+
+    initiator.stop()?;
+    acceptor.stop()?;
+    Ok((
+        sender_session.get_expected_sender_num(),
+        sender_session.get_expected_target_num(),
+    ))
+}
+
+fn file_store_path(store_path: &std::path::Path, server_type: &ServerType) -> std::path::PathBuf {
+    store_path.join(match server_type {
+        ServerType::Sender => "sender",
+        ServerType::Receiver => "receiver",
+    })
+}
+
+/// Same as [`run_with_stores`], with file stores in `store_path`.
+fn run_with_file_stores(
+    store_path: &std::path::Path,
+    count: usize,
+) -> Result<(i32, i32), QuickFixError> {
+    run_with_stores(
+        &NullFixApplication,
+        |server_type| {
+            FileMessageStoreFactory::try_with_path(file_store_path(store_path, &server_type))
+        },
+        count,
+    )
+}
+
+#[test]
+fn test_file_with_path() -> Result<(), QuickFixError> {
+    let store_path = std::env::temp_dir().join(format!("quickfix-store-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&store_path);
+
+    let (sender_num, target_num) = run_with_file_stores(&store_path, 3)?;
+    assert!(store_path
+        .join("sender/FIX.4.4-SENDER-RECEIVER.seqnums")
+        .exists());
+
+    // Restarted handlers continue from stored sequence numbers, Logout included.
+    let (restarted_sender_num, restarted_target_num) = run_with_file_stores(&store_path, 1)?;
+    assert_eq!(restarted_sender_num, sender_num + 3);
+    assert!(restarted_target_num > target_num);
+
+    let _ = std::fs::remove_dir_all(&store_path);
+    Ok(())
+}
+
+/// Record MsgSeqNum(34) of every Logon sent by sender session.
+#[derive(Debug, Default)]
+struct LogonRecorder {
+    seq_nums: Mutex<Vec<String>>,
+}
+
+impl ApplicationCallback for LogonRecorder {
+    fn on_msg_to_admin(&self, msg: &mut Message, session: &SessionId) {
+        if session.to_repr() != ServerType::Sender.session_id().to_repr() {
+            return;
+        }
+        if msg
+            .with_header(|header| header.get_field(tags::MSG_TYPE))
+            .as_deref()
+            == Some("A")
+        {
+            let seq_num = msg.with_header(|header| header.get_field(tags::MSG_SEQ_NUM));
+            self.seq_nums
+                .lock()
+                .unwrap()
+                .push(seq_num.unwrap_or_default());
+        }
+    }
+}
+
+#[test]
+fn test_file_reset_store() -> Result<(), QuickFixError> {
+    let store_path =
+        std::env::temp_dir().join(format!("quickfix-store-reset-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&store_path);
+
+    let (sender_num, _) = run_with_file_stores(&store_path, 3)?;
+    assert!(sender_num > 1);
+
+    // Handlers are down: reset both sides from their factories only.
+    for server_type in [ServerType::Sender, ServerType::Receiver] {
+        let store_factory =
+            FileMessageStoreFactory::try_with_path(file_store_path(&store_path, &server_type))?;
+        store_factory.reset_store(&server_type.session_id())?;
+    }
+
+    let recorder = LogonRecorder::default();
+    run_with_stores(
+        &recorder,
+        |server_type| {
+            FileMessageStoreFactory::try_with_path(file_store_path(&store_path, &server_type))
+        },
+        0,
+    )?;
+    assert_eq!(*recorder.seq_nums.lock().unwrap(), vec!["1".to_string()]);
+
+    let _ = std::fs::remove_dir_all(&store_path);
+    Ok(())
+}
+
+#[test]
+fn test_file_set_sequence_numbers() -> Result<(), QuickFixError> {
+    let store_path =
+        std::env::temp_dir().join(format!("quickfix-store-seqnums-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&store_path);
+
+    // Stores never used before start from 1.
+    let sender_store =
+        FileMessageStoreFactory::try_with_path(file_store_path(&store_path, &ServerType::Sender))?;
+    let receiver_store = FileMessageStoreFactory::try_with_path(file_store_path(
+        &store_path,
+        &ServerType::Receiver,
+    ))?;
+    let sender_id = ServerType::Sender.session_id();
+    let receiver_id = ServerType::Receiver.session_id();
+    assert_eq!(sender_store.sequence_numbers(&sender_id)?, (1, 1));
+
+    sender_store.set_sequence_numbers(&sender_id, 42, 7)?;
+    receiver_store.set_sequence_numbers(&receiver_id, 7, 42)?;
+    assert_eq!(sender_store.sequence_numbers(&sender_id)?, (42, 7));
+    drop((sender_store, receiver_store));
+
+    let recorder = LogonRecorder::default();
+    let (sender_num, target_num) = run_with_stores(
+        &recorder,
+        |server_type| {
+            FileMessageStoreFactory::try_with_path(file_store_path(&store_path, &server_type))
+        },
+        0,
+    )?;
+    assert_eq!(*recorder.seq_nums.lock().unwrap(), vec!["42".to_string()]);
+    assert!(sender_num > 42);
+    assert!(target_num > 7);
+
+    let _ = std::fs::remove_dir_all(&store_path);
+    Ok(())
+}
+
+/// Export sender session store of an initiator that is never started.
+fn export_sender_store<F: FfiMessageStoreFactory>(
+    store_factory: &F,
+) -> Result<Vec<u8>, QuickFixError> {
+    let app = Application::try_new(&NullFixApplication)?;
+    let _initiator = Initiator::try_new(
+        &build_settings(ServerType::Sender, 0)?,
+        &app,
+        store_factory,
+        FixSocketServerKind::default(),
+    )?;
+    let session = unsafe { Session::lookup(&ServerType::Sender.session_id()) }?;
+    let mut snapshot = Vec::new();
+    session.export_store(&mut snapshot)?;
+    Ok(snapshot)
+}
+
+#[test]
+fn test_file_export_import_store() -> Result<(), QuickFixError> {
+    let store_path =
+        std::env::temp_dir().join(format!("quickfix-store-export-{}", std::process::id()));
+    let copy_path = store_path.join("copy");
+    let _ = std::fs::remove_dir_all(&store_path);
+
+    let (sender_num, target_num) = run_with_file_stores(&store_path, 3)?;
+    let snapshot = export_sender_store(&FileMessageStoreFactory::try_with_path(file_store_path(
+        &store_path,
+        &ServerType::Sender,
+    ))?)?;
+
+    // Copy sender store somewhere else, empty stores only unless forced.
+    let sender_id = ServerType::Sender.session_id();
+    let copy_store = FileMessageStoreFactory::try_with_path(&copy_path)?;
+    copy_store.import_store(&sender_id, &mut snapshot.as_slice(), false)?;
+    assert_eq!(
+        copy_store.sequence_numbers(&sender_id)?,
+        (sender_num as u32, target_num as u32)
+    );
+    assert!(copy_store
+        .import_store(&sender_id, &mut snapshot.as_slice(), false)
+        .is_err());
+    copy_store.import_store(&sender_id, &mut snapshot.as_slice(), true)?;
+    assert!(copy_store
+        .import_store(&sender_id, &mut &snapshot[..10], true)
+        .is_err());
+
+    // Copy is a drop-in replacement for the original store.
+    let recorder = LogonRecorder::default();
+    run_with_stores(
+        &recorder,
+        |server_type| {
+            FileMessageStoreFactory::try_with_path(match server_type {
+                ServerType::Sender => copy_path.clone(),
+                ServerType::Receiver => file_store_path(&store_path, &server_type),
+            })
+        },
+        0,
+    )?;
+    assert_eq!(
+        *recorder.seq_nums.lock().unwrap(),
+        vec![sender_num.to_string()]
+    );
+
+    let _ = std::fs::remove_dir_all(&store_path);
+    Ok(())
+}
+
+#[test]
+fn test_memory_import_store() -> Result<(), QuickFixError> {
+    let store_path =
+        std::env::temp_dir().join(format!("quickfix-store-import-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&store_path);
+
+    let (sender_num, target_num) = run_with_file_stores(&store_path, 3)?;
+    let file_store =
+        FileMessageStoreFactory::try_with_path(file_store_path(&store_path, &ServerType::Sender))?;
+    let snapshot = export_sender_store(&file_store)?;
+
+    // Memory stores only live with their session: import into the live one.
+    let app = Application::try_new(&NullFixApplication)?;
+    let memory_store = MemoryMessageStoreFactory::new();
+    let _initiator = Initiator::try_new(
+        &build_settings(ServerType::Sender, 0)?,
+        &app,
+        &memory_store,
+        FixSocketServerKind::default(),
+    )?;
+    let mut session = unsafe { Session::lookup(&ServerType::Sender.session_id()) }?;
+    session.import_store(&mut snapshot.as_slice(), false)?;
+
+    assert_eq!(session.get_expected_sender_num(), sender_num);
+    assert_eq!(session.get_expected_target_num(), target_num);
+    let news: Vec<_> = session
+        .stored_messages_raw(1, sender_num as u32 - 1)?
+        .into_iter()
+        .filter(|text| text.contains("\x0135=B\x01"))
+        .collect();
+    assert_eq!(news.len(), 3);
+    assert!(session
+        .import_store(&mut snapshot.as_slice(), false)
+        .is_err());
+
+    let _ = std::fs::remove_dir_all(&store_path);
+    Ok(())
+}
+
+#[test]
+fn test_composite_memory_file() -> Result<(), QuickFixError> {
+    let store_path =
+        std::env::temp_dir().join(format!("quickfix-store-composite-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&store_path);
+
+    let divergences = Mutex::new(Vec::new());
+    let on_divergence = |session_id: &SessionId, reason: &str| {
+        divergences
+            .lock()
+            .unwrap()
+            .push(format!("{}: {reason}", session_id.to_repr()));
+    };
+
+    let app = Application::try_new(&NullFixApplication)?;
+    let memory_store = MemoryMessageStoreFactory::new();
+    let file_store =
+        FileMessageStoreFactory::try_with_path(file_store_path(&store_path, &ServerType::Sender))?;
+    let store_factory_sender = CompositeMessageStoreFactory::try_with_divergence_handler(
+        &memory_store,
+        &file_store,
+        &on_divergence,
+    )?;
+    let store_factory_receiver = MemoryMessageStoreFactory::new();
+
+    let mut acceptor = Acceptor::try_new(
+        &build_settings(ServerType::Receiver, 0)?,
+        &app,
+        &store_factory_receiver,
+        FixSocketServerKind::default(),
+    )?;
+    acceptor.start()?;
+    let mut initiator = Initiator::try_new(
+        &build_settings(ServerType::Sender, listen_port(&acceptor))?,
+        &app,
+        &store_factory_sender,
+        FixSocketServerKind::default(),
+    )?;
+    initiator.start()?;
+    wait_until(Duration::from_secs(10), || {
+        initiator.is_logged_on().unwrap_or(false) && acceptor.is_logged_on().unwrap_or(false)
+    });
+
+    let sender_id = ServerType::Sender.session_id();
+    let sender_session = unsafe { Session::lookup(&sender_id) }?;
+    for index in 0..3 {
+        send_to_target(build_news(&format!("Hello {index}"), &[])?, &sender_id)?;
+    }
+    initiator.stop()?;
+    acceptor.stop()?;
+
+    let expected = (
+        sender_session.get_expected_sender_num() as u32,
+        sender_session.get_expected_target_num() as u32,
+    );
+    assert!(expected.0 > 4);
+    drop(initiator);
+
+    // File store has been written as much as the memory one.
+    assert_eq!(file_store.sequence_numbers(&sender_id)?, expected);
+    assert!(divergences.lock().unwrap().is_empty());
+
+    // Store behind the primary one is reported as soon as session is created.
+    file_store.set_sequence_numbers(&sender_id, 1, 1)?;
+    let store_path_primary = store_path.join("primary");
+    let primary_store = FileMessageStoreFactory::try_with_path(&store_path_primary)?;
+    primary_store.set_sequence_numbers(&sender_id, 5, 5)?;
+    let store_factory = CompositeMessageStoreFactory::try_with_divergence_handler(
+        &primary_store,
+        &file_store,
+        &on_divergence,
+    )?;
+    let _initiator = Initiator::try_new(
+        &build_settings(ServerType::Sender, 0)?,
+        &app,
+        &store_factory,
+        FixSocketServerKind::default(),
+    )?;
+    let divergences = divergences.lock().unwrap();
+    assert_eq!(divergences.len(), 1);
+    assert!(divergences[0].contains("SENDER"));
+    assert!(divergences[0].contains("1/1 instead of 5/5"));
+    drop(divergences);
+
+    let _ = std::fs::remove_dir_all(&store_path);
+    Ok(())
+}
+
+#[test]
+fn test_composite_divergence_error() -> Result<(), QuickFixError> {
+    let store_path = std::env::temp_dir().join(format!(
+        "quickfix-store-composite-error-{}",
+        std::process::id()
+    ));
+    let _ = std::fs::remove_dir_all(&store_path);
+
+    let sender_id = ServerType::Sender.session_id();
+    let primary_store = FileMessageStoreFactory::try_with_path(store_path.join("primary"))?;
+    let secondary_store = FileMessageStoreFactory::try_with_path(store_path.join("secondary"))?;
+    primary_store.set_sequence_numbers(&sender_id, 5, 5)?;
+
+    // Without handler, divergence is an error.
+    let store_factory = CompositeMessageStoreFactory::try_new(&primary_store, &secondary_store)?;
+    let err = store_factory.sequence_numbers(&sender_id).unwrap_err();
+    assert!(err.to_string().contains("diverged"), "{err}");
+
+    secondary_store.set_sequence_numbers(&sender_id, 5, 5)?;
+    assert_eq!(store_factory.sequence_numbers(&sender_id)?, (5, 5));
+    store_factory.set_sequence_numbers(&sender_id, 8, 9)?;
+    assert_eq!(secondary_store.sequence_numbers(&sender_id)?, (8, 9));
+
+    let _ = std::fs::remove_dir_all(&store_path);
+    Ok(())
+}
+
+#[test]
+fn test_store_metrics() -> Result<(), QuickFixError> {
+    let store_path =
+        std::env::temp_dir().join(format!("quickfix-store-metrics-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&store_path);
+
+    let app = Application::try_new(&NullFixApplication)?;
+    let store_factory_sender =
+        FileMessageStoreFactory::try_with_path(file_store_path(&store_path, &ServerType::Sender))?;
+    let store_factory_receiver = MemoryMessageStoreFactory::new();
+
+    let mut acceptor = Acceptor::try_new(
+        &build_settings(ServerType::Receiver, 0)?,
+        &app,
+        &store_factory_receiver,
+        FixSocketServerKind::default(),
+    )?;
+    acceptor.start()?;
+    let mut initiator = Initiator::try_new(
+        &build_settings(ServerType::Sender, listen_port(&acceptor))?,
+        &app,
+        &store_factory_sender,
+        FixSocketServerKind::default(),
+    )?;
+    initiator.start()?;
+    wait_until(Duration::from_secs(10), || {
+        initiator.is_logged_on().unwrap_or(false) && acceptor.is_logged_on().unwrap_or(false)
+    });
+
+    let sender_id = ServerType::Sender.session_id();
+    let sender_session = unsafe { Session::lookup(&sender_id) }?;
+    let receiver_session = unsafe { Session::lookup(&ServerType::Receiver.session_id()) }?;
+    let before = sender_session.store_metrics()?;
+    for index in 0..5 {
+        send_to_target(build_news(&format!("Hello {index}"), &[])?, &sender_id)?;
+    }
+
+    let metrics = sender_session.store_metrics()?;
+    assert_eq!(metrics.message_count, before.message_count + 5);
+    assert!(metrics.approx_bytes.unwrap() > before.approx_bytes.unwrap());
+
+    // Memory store does not write anything to disk.
+    let metrics = receiver_session.store_metrics()?;
+    assert_eq!(metrics.approx_bytes, None);
+
+    initiator.stop()?;
+    acceptor.stop()?;
+    let _ = std::fs::remove_dir_all(&store_path);
+    Ok(())
+}
+
+/// Record store creation time of sender session on every logon.
+#[derive(Debug, Default)]
+struct CreationTimeRecorder {
+    times: Mutex<Vec<SystemTime>>,
+}
+
+impl ApplicationCallback for CreationTimeRecorder {
+    fn on_logon(&self, session: &SessionId) {
+        if session.to_repr() != ServerType::Sender.session_id().to_repr() {
+            return;
+        }
+        let time = unsafe { Session::lookup(session) }
+            .and_then(|session| session.store_creation_time())
+            .unwrap();
+        self.times.lock().unwrap().push(time);
+    }
+}
+
+#[test]
+fn test_file_store_creation_time() -> Result<(), QuickFixError> {
+    let store_path =
+        std::env::temp_dir().join(format!("quickfix-store-created-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&store_path);
+
+    let before = SystemTime::now() - Duration::from_secs(1);
+    let recorder = CreationTimeRecorder::default();
+    let run = || {
+        run_with_stores(
+            &recorder,
+            |server_type| {
+                FileMessageStoreFactory::try_with_path(file_store_path(&store_path, &server_type))
+            },
+            1,
+        )
+    };
+    run()?;
+    // File stores only keep seconds: make sure a new store would get another time.
+    std::thread::sleep(Duration::from_millis(1100));
+    run()?;
+
+    let times = recorder.times.lock().unwrap();
+    assert_eq!(times.len(), 2);
+    assert!(times[0] >= before && times[0] <= SystemTime::now());
+    assert_eq!(times[1], times[0]);
+    drop(times);
+
+    let _ = std::fs::remove_dir_all(&store_path);
+    Ok(())
+}
+
+#[test]
+fn test_file_with_path_not_writable() {
+    let file_path =
+        std::env::temp_dir().join(format!("quickfix-store-file-{}", std::process::id()));
+    std::fs::write(&file_path, "not a directory").unwrap();
+
+    assert!(matches!(
+        FileMessageStoreFactory::try_with_path(file_path.join("store")),
+        Err(QuickFixError::IOException(_))
+    ));
+
+    let _ = std::fs::remove_file(&file_path);
+}
+
+#[cfg(any(feature = "build-with-mysql", feature = "build-with-postgres"))]
+fn env_or(name: &str, default: &str) -> String {
+    std::env::var(name).unwrap_or_else(|_| default.to_string())
+}
+
+#[cfg(feature = "build-with-postgres")]
+fn build_postgres_store(
+    server_type: ServerType,
+    port: u16,
+) -> Result<PostgresMessageStoreFactory, QuickFixError> {
+    use quickfix::dictionary_item::*;
+
+    let database = env_or("QUICKFIX_POSTGRES_DATABASE", "quickfix");
+    let user = env_or("QUICKFIX_POSTGRES_USER", "postgres");
+    let password = env_or("QUICKFIX_POSTGRES_PASSWORD", "postgres");
+    let host = env_or("QUICKFIX_POSTGRES_HOST", "127.0.0.1");
+
+    let settings = build_settings_with_defaults(
+        server_type,
+        0,
+        &[
+            &PostgreSQLStoreDatabase(&database),
+            &PostgreSQLStoreUser(&user),
+            &PostgreSQLStorePassword(&password),
+            &PostgreSQLStoreHost(&host),
+            &PostgreSQLStorePort(port),
+        ],
+    )?;
+    PostgresMessageStoreFactory::try_new(&settings)
+}
+
+#[test]
+#[cfg(feature = "build-with-postgres")]
+fn test_postgres_unreachable() -> Result<(), QuickFixError> {
+    let app = Application::try_new(&NullFixApplication)?;
+    // Nothing listens on port 1: connection is refused when session is created.
+    let store_factory = build_postgres_store(ServerType::Receiver, 1)?;
+    let mut acceptor = Acceptor::try_new(
+        &build_settings(ServerType::Receiver, 0)?,
+        &app,
+        &store_factory,
+        FixSocketServerKind::default(),
+    )?;
+
+    match acceptor.start() {
+        Err(QuickFixError::ConfigError(msg)) => {
+            assert!(msg.contains("FIX.4.4:RECEIVER->SENDER"), "{msg}");
+            assert!(msg.contains("PostgreSQLStorePort=1"), "{msg}");
+        }
+        other => panic!("unexpected start result: {other:?}"),
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "build-with-postgres")]
+#[ignore = "requires PostgreSQL with quickfix tables, e.g. `docker run -e POSTGRES_PASSWORD=postgres -e POSTGRES_DB=quickfix -p 5432:5432 postgres` then apply `libquickfix/src/sql/postgresql` scripts"]
+fn test_postgres_with_restart() -> Result<(), QuickFixError> {
+    let port = env_or("QUICKFIX_POSTGRES_PORT", "5432").parse().unwrap();
+
+    let (sender_num, target_num) = run_with_stores(
+        &NullFixApplication,
+        |server_type| build_postgres_store(server_type, port),
+        3,
+    )?;
+
+    // Restarted handlers continue from stored sequence numbers, Logout included.
+    let (restarted_sender_num, restarted_target_num) = run_with_stores(
+        &NullFixApplication,
+        |server_type| build_postgres_store(server_type, port),
+        1,
+    )?;
+    assert_eq!(restarted_sender_num, sender_num + 3);
+    assert!(restarted_target_num > target_num);
+    Ok(())
+}
+
+#[cfg(feature = "build-with-mysql")]
+fn build_mysql_store(
+    server_type: ServerType,
+    port: u16,
+) -> Result<MySqlMessageStoreFactory, QuickFixError> {
+    use quickfix::dictionary_item::*;
+
+    let database = env_or("QUICKFIX_MYSQL_DATABASE", "quickfix");
+    let user = env_or("QUICKFIX_MYSQL_USER", "root");
+    let password = env_or("QUICKFIX_MYSQL_PASSWORD", "mysql");
+    let host = env_or("QUICKFIX_MYSQL_HOST", "127.0.0.1");
+
+    let settings = build_settings_with_defaults(
+        server_type,
+        0,
+        &[
+            &MySQLStoreDatabase(&database),
+            &MySQLStoreUser(&user),
+            &MySQLStorePassword(&password),
+            &MySQLStoreHost(&host),
+            &MySQLStorePort(port),
+        ],
+    )?;
+    MySqlMessageStoreFactory::try_new(&settings)
+}
+
+#[test]
+#[cfg(feature = "build-with-mysql")]
+fn test_mysql_unreachable() -> Result<(), QuickFixError> {
+    let app = Application::try_new(&NullFixApplication)?;
+    // Nothing listens on port 1: connection is refused when session is created.
+    let store_factory = build_mysql_store(ServerType::Receiver, 1)?;
+    let mut acceptor = Acceptor::try_new(
+        &build_settings(ServerType::Receiver, 0)?,
+        &app,
+        &store_factory,
+        FixSocketServerKind::default(),
+    )?;
+
+    match acceptor.start() {
+        Err(QuickFixError::ConfigError(msg)) => {
+            assert!(msg.contains("FIX.4.4:RECEIVER->SENDER"), "{msg}");
+            assert!(msg.contains("MySQLStorePort=1"), "{msg}");
+        }
+        other => panic!("unexpected start result: {other:?}"),
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "build-with-mysql")]
+#[ignore = "requires MySQL with quickfix tables, e.g. `docker run -e MYSQL_ROOT_PASSWORD=mysql -e MYSQL_DATABASE=quickfix -p 3306:3306 mysql` then apply `libquickfix/src/sql/mysql` scripts"]
+fn test_mysql_with_restart() -> Result<(), QuickFixError> {
+    let port = env_or("QUICKFIX_MYSQL_PORT", "3306").parse().unwrap();
+
+    let (sender_num, target_num) = run_with_stores(
+        &NullFixApplication,
+        |server_type| build_mysql_store(server_type, port),
+        3,
+    )?;
+
+    // Restarted handlers continue from stored sequence numbers, Logout included.
+    let (restarted_sender_num, restarted_target_num) = run_with_stores(
+        &NullFixApplication,
+        |server_type| build_mysql_store(server_type, port),
+        1,
+    )?;
+    assert_eq!(restarted_sender_num, sender_num + 3);
+    assert!(restarted_target_num > target_num);
+    Ok(())
+}
+
+/// Log on with stores built by `make_store`, send `count` news and wait for receiver to catch up.
+///
+/// Once handlers are stopped, `after_stop` runs with receiver session and its first expected
+/// sequence number.
+#[cfg(any(feature = "sqlite", feature = "gzip"))]
+fn run_with_news_receiver<F, M>(
+    make_store: M,
+    receiver: &NewsRecorder,
+    count: usize,
+    after_stop: impl FnOnce(&mut Session, i32) -> Result<(), QuickFixError>,
+) -> Result<(), QuickFixError>
+where
+    F: FfiMessageStoreFactory,
+    M: Fn(ServerType) -> Result<F, QuickFixError>,
+{
+    let app_sender = Application::try_new(&NullFixApplication)?;
+    let app_receiver = Application::try_new(receiver)?;
+    let store_factory_sender = make_store(ServerType::Sender)?;
+    let store_factory_receiver = make_store(ServerType::Receiver)?;
+
+    let mut acceptor = Acceptor::try_new(
+        &build_settings(ServerType::Receiver, 0)?,
+        &app_receiver,
+        &store_factory_receiver,
+        FixSocketServerKind::default(),
+    )?;
+    acceptor.start()?;
+    let mut initiator = Initiator::try_new(
+        &build_settings(ServerType::Sender, listen_port(&acceptor))?,
+        &app_sender,
+        &store_factory_sender,
+        FixSocketServerKind::default(),
+    )?;
+    initiator.start()?;
+    wait_until(Duration::from_secs(10), || {
+        initiator.is_logged_on().unwrap_or(false) && acceptor.is_logged_on().unwrap_or(false)
+    });
+
+    let sender_id = ServerType::Sender.session_id();
+    let sender_session = unsafe { Session::lookup(&sender_id) }?;
+    let mut receiver_session = unsafe { Session::lookup(&ServerType::Receiver.session_id()) }?;
+    let first_seq_num = receiver_session.get_expected_target_num();
+    for index in 0..count {
+        send_to_target(build_news(&format!("Hello {index}"), &[])?, &sender_id)?;
+    }
+    let expected = sender_session.get_expected_sender_num();
+    wait_until(Duration::from_secs(5), || {
+        receiver_session.get_expected_target_num() == expected
+    });
+
+    initiator.stop()?;
+    acceptor.stop()?;
+    after_stop(&mut receiver_session, first_seq_num)
+}
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn test_sqlite_resend_after_restart() -> Result<(), QuickFixError> {
+    let store_path = std::env::temp_dir().join(format!("quickfix-sqlite-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&store_path);
+    std::fs::create_dir_all(&store_path).unwrap();
+    let make_store = |server_type: ServerType| {
+        SqliteMessageStoreFactory::try_new(store_path.join(match server_type {
+            ServerType::Sender => "sender.db",
+            ServerType::Receiver => "receiver.db",
+        }))
+    };
+
+    // Receiver "misses" every news: rewind its store once handlers are down.
+    let receiver = NewsRecorder::default();
+    run_with_news_receiver(
+        make_store,
+        &receiver,
+        3,
+        |receiver_session, first_seq_num| {
+            receiver_session.set_next_target_msg_seq_num(first_seq_num)
+        },
+    )?;
+    assert_eq!(receiver.news.lock().unwrap().len(), 3);
+
+    // Recreated handlers detect the gap on Logon, sender replays news from its database.
+    let receiver = NewsRecorder::default();
+    run_with_news_receiver(make_store, &receiver, 0, |_, _| Ok(()))?;
+    let news = receiver.news.lock().unwrap();
+    assert_eq!(news.len(), 3);
+    for (index, entry) in news.iter().enumerate() {
+        assert_eq!(*entry, (format!("Hello {index}"), true));
+    }
+    drop(news);
+
+    let _ = std::fs::remove_dir_all(&store_path);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn test_sqlite_not_writable() {
+    let file_path =
+        std::env::temp_dir().join(format!("quickfix-sqlite-file-{}", std::process::id()));
+    std::fs::write(&file_path, "not a directory").unwrap();
+
+    assert!(matches!(
+        SqliteMessageStoreFactory::try_new(file_path.join("store.db")),
+        Err(QuickFixError::IOException(_))
+    ));
+
+    let _ = std::fs::remove_file(&file_path);
+}
+
+/// Build a FIX message of about `len` bytes with sequence number `seq`.
+#[cfg(feature = "gzip")]
+fn build_raw_message(seq: u32, len: usize) -> String {
+    let headline = format!("Headline {seq} ").repeat(len / 16 + 1);
+    format!("8=FIX.4.4\x019=0\x0135=B\x0134={seq}\x01148={headline}\x0110=000\x01")
+}
+
+#[test]
+#[cfg(feature = "gzip")]
+fn test_compressed_round_trip() -> Result<(), QuickFixError> {
+    let store_path =
+        std::env::temp_dir().join(format!("quickfix-compressed-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&store_path);
+    let session_id = ServerType::Sender.session_id();
+
+    // Uncompressed reference, with various message sizes.
+    let expected: Vec<String> = (1..=10_000)
+        .map(|seq| build_raw_message(seq, (seq as usize * 7919) % 500))
+        .collect();
+
+    let store = CompressedFileStore::try_new(&store_path)?;
+    store.on_create(&session_id)?;
+    for (seq, msg) in (1..).zip(&expected) {
+        assert!(store.set(&session_id, seq, msg)?);
+    }
+    store.set_next_sender_msg_seq_num(&session_id, 10_001)?;
+
+    let raw_len: usize = expected.iter().map(String::len).sum();
+    let compressed_len = std::fs::read_dir(&store_path)
+        .unwrap()
+        .map(|entry| entry.unwrap().metadata().unwrap().len())
+        .sum::<u64>();
+    assert!((compressed_len as usize) < raw_len / 2);
+
+    // Check both the store that wrote messages and one reading them back from disk.
+    let reopened = CompressedFileStore::try_new(&store_path)?;
+    for store in [&store, &reopened] {
+        assert_eq!(store.next_sender_msg_seq_num(&session_id)?, 10_001);
+        assert_eq!(store.get(&session_id, 1, 10_000)?, expected);
+
+        // Pseudo random ranges, crossing block boundaries.
+        let mut seed = 42_u64;
+        for _ in 0..100 {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            let begin = (seed >> 33) as u32 % 10_000 + 1;
+            let end = (begin + (seed >> 20) as u32 % 200).min(10_000);
+            assert_eq!(
+                store.get(&session_id, begin, end)?,
+                expected[begin as usize - 1..end as usize]
+            );
+        }
+        assert!(store.get(&session_id, 10_001, 10_010)?.is_empty());
+    }
+    drop((store, reopened));
+
+    // Reset drops every message.
+    let store = CompressedFileStore::try_new(&store_path)?;
+    store.reset(&session_id, SystemTime::now())?;
+    assert!(store.get(&session_id, 1, 10_000)?.is_empty());
+    assert_eq!(store.next_sender_msg_seq_num(&session_id)?, 1);
+
+    let _ = std::fs::remove_dir_all(&store_path);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "gzip")]
+fn test_compressed_resend_after_restart() -> Result<(), QuickFixError> {
+    let store_path =
+        std::env::temp_dir().join(format!("quickfix-compressed-resend-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&store_path);
+    // Small blocks, so replayed news are both compressed and pending.
+    let make_store = |server_type: ServerType| {
+        CompressedFileMessageStoreFactory::try_with_block_len(
+            file_store_path(&store_path, &server_type),
+            4,
+        )
+    };
+
+    let receiver = NewsRecorder::default();
+    run_with_news_receiver(
+        make_store,
+        &receiver,
+        10,
+        |receiver_session, first_seq_num| {
+            receiver_session.set_next_target_msg_seq_num(first_seq_num)
+        },
+    )?;
+    assert_eq!(receiver.news.lock().unwrap().len(), 10);
+
+    let receiver = NewsRecorder::default();
+    run_with_news_receiver(make_store, &receiver, 0, |_, _| Ok(()))?;
+    let news = receiver.news.lock().unwrap();
+    assert_eq!(news.len(), 10);
+    for (index, entry) in news.iter().enumerate() {
+        assert_eq!(*entry, (format!("Hello {index}"), true));
+    }
+    drop(news);
+
+    let _ = std::fs::remove_dir_all(&store_path);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "gzip")]
+fn test_compressed_invalid_block_len() {
+    let store_path =
+        std::env::temp_dir().join(format!("quickfix-compressed-zero-{}", std::process::id()));
+    assert!(CompressedFileMessageStoreFactory::try_with_block_len(&store_path, 0).is_err());
+}
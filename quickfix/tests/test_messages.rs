@@ -13,12 +13,10 @@ fn test_read_empy_message() {
 
 #[test]
 fn test_from_text() {
-    // Check with invalid C string
+    // Check with embedded NUL, kept in value
     {
-        assert_eq!(
-            Message::try_from_text("\050=18").unwrap_err(),
-            QuickFixError::invalid_argument("nul byte found in provided data at position: 0")
-        );
+        let msg = Message::try_from_text("58=a\0b\u{1}").unwrap();
+        assert_eq!(msg.get_field_str(58), Some("a\0b"));
     }
     // Check compute len + checksum
     {
@@ -191,3 +189,266 @@ fn test_copy_trailer() {
     );
     assert_eq!(cpy.get_field(5000).as_deref(), Some("hello"));
 }
+
+#[test]
+fn test_get_fields() -> Result<(), QuickFixError> {
+    let mut msg = Message::new();
+    msg.set_field(11, "ORDER-1")?;
+    msg.set_field(55, "AAPL")?;
+    msg.set_field(58, "")?;
+    msg.with_header_mut(|h| h.set_field(35, "8"))?;
+
+    // Missing tags do not fail the batch.
+    assert_eq!(
+        msg.get_fields(&[55, 42, 11, 58])?,
+        vec![
+            Some("AAPL".to_string()),
+            None,
+            Some("ORDER-1".to_string()),
+            Some(String::new()),
+        ]
+    );
+    assert_eq!(msg.get_fields(&[])?, Vec::<Option<String>>::new());
+    assert_eq!(
+        msg.with_header(|h| h.get_fields(&[35, 55]))?,
+        vec![Some("8".to_string()), None]
+    );
+
+    // Batch is reused, growing when needed.
+    let mut batch = FieldBatch::new();
+    msg.get_fields_into(&[55], &mut batch)?;
+    assert_eq!(batch.iter().collect::<Vec<_>>(), vec![Some("AAPL")]);
+
+    msg.get_fields_into(&[11, 42, 55], &mut batch)?;
+    assert_eq!(batch.len(), 3);
+    assert_eq!(batch.tags(), &[11, 42, 55]);
+    assert_eq!(batch.get(0), Some("ORDER-1"));
+    assert_eq!(batch.get(1), None);
+    assert_eq!(batch.get(3), None);
+    assert_eq!(batch.get_by_tag(55), Some("AAPL"));
+    assert_eq!(batch.get_by_tag(58), None);
+
+    // Non UTF-8 value fails with its tag.
+    msg.set_field(58, &b"caf\xe9"[..])?;
+    let err = msg.get_fields(&[11, 58]).unwrap_err();
+    assert_eq!(err.tag(), Some(58));
+    assert_eq!(err.bytes(), Some(&b"caf\xe9"[..]));
+    assert!(msg.get_fields_into(&[58], &mut batch).is_err());
+    assert!(batch.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn test_get_field_into() -> Result<(), QuickFixError> {
+    let mut msg = Message::new();
+    msg.set_field(55, "AAPL")?;
+    msg.set_field(58, "")?;
+
+    // Values are appended.
+    let mut buf = String::from("symbol=");
+    assert_eq!(msg.get_field_into(55, &mut buf)?, 4);
+    assert_eq!(buf, "symbol=AAPL");
+
+    buf.clear();
+    assert_eq!(msg.get_field_into(58, &mut buf)?, 0);
+    assert_eq!(buf, "");
+
+    // Missing field leaves buffer unchanged.
+    buf.push_str("keep");
+    let err = msg.get_field_into(42, &mut buf).unwrap_err();
+    assert!(matches!(err, QuickFixError::FieldNotFound { tag: 42, .. }));
+    assert_eq!(buf, "keep");
+
+    // Buffer is a copy, not affected by later mutations.
+    buf.clear();
+    msg.get_field_into(55, &mut buf)?;
+    msg.set_field(55, "MSFT")?;
+    msg.remove_field(58)?;
+    assert_eq!(buf, "AAPL");
+
+    // Non UTF-8 value is only readable as bytes.
+    msg.set_field(58, &b"caf\xe9"[..])?;
+    let err = msg.get_field_into(58, &mut buf).unwrap_err();
+    assert_eq!(err.bytes(), Some(&b"caf\xe9"[..]));
+    assert_eq!(buf, "AAPL");
+
+    let mut bytes = Vec::new();
+    assert_eq!(msg.get_field_bytes_into(58, &mut bytes)?, 4);
+    assert_eq!(bytes, b"caf\xe9");
+
+    msg.with_header_mut(|h| h.set_field(35, "D"))?;
+    bytes.clear();
+    msg.with_header(|h| h.get_field_bytes_into(35, &mut bytes))?;
+    assert_eq!(bytes, b"D");
+
+    Ok(())
+}
+
+#[test]
+fn test_get_field_into_panicking_override() {
+    /// Field map appending invalid UTF-8 before panicking.
+    struct PanickingMap;
+
+    impl FieldMap for PanickingMap {
+        fn get_field(&self, _tag: i32) -> Option<String> {
+            None
+        }
+
+        fn get_field_bytes_into(
+            &self,
+            _tag: i32,
+            buf: &mut Vec<u8>,
+        ) -> Result<usize, QuickFixError> {
+            buf.extend_from_slice(b"caf\xe9");
+            panic!("reader failed");
+        }
+
+        fn set_field<V: IntoFixValue>(
+            &mut self,
+            _tag: i32,
+            _value: V,
+        ) -> Result<(), QuickFixError> {
+            unimplemented!()
+        }
+
+        fn remove_field(&mut self, _tag: i32) -> Result<(), QuickFixError> {
+            unimplemented!()
+        }
+
+        fn add_group(&mut self, _group: &Group) -> Result<(), QuickFixError> {
+            unimplemented!()
+        }
+
+        fn clone_group(&self, _index: i32, _tag: i32) -> Option<Group> {
+            None
+        }
+    }
+
+    let mut buf = String::from("keep");
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        PanickingMap.get_field_into(58, &mut buf)
+    }));
+    assert!(result.is_err());
+    assert_eq!(buf, "keep");
+}
+
+#[test]
+fn test_to_fix_bytes_ref() -> Result<(), QuickFixError> {
+    let mut msg = Message::new();
+    msg.set_field(58, "hello")?;
+    let expected = msg.to_fix_string()?;
+    assert_eq!(msg.to_fix_bytes_ref()?, expected.as_bytes());
+    assert_eq!(msg.to_fix_str()?, expected);
+
+    // Bytes based setter introducing non UTF-8 data: only validated path fails.
+    msg.set_field_raw(58, b"caf\xe9")?;
+    let raw = msg.to_fix_bytes()?;
+    assert_eq!(msg.to_fix_bytes_ref()?, raw);
+    assert!(raw.windows(6).any(|window| window == b"58=caf"));
+    assert_eq!(
+        msg.to_fix_str(),
+        Err(QuickFixError::InvalidUtf8 {
+            tag: None,
+            bytes: raw.clone(),
+        })
+    );
+
+    // Back to valid data once field is fixed.
+    msg.set_field_str(58, "café")?;
+    assert!(msg.to_fix_str()?.contains("58=café\u{1}"));
+    Ok(())
+}
+
+#[test]
+fn test_header_accessors() -> Result<(), QuickFixError> {
+    let mut msg = Message::new();
+    msg.header_mut().set_field(49, "SENDER")?;
+    msg.header_mut().set_field(56, "TARGET")?;
+    msg.set_field(58, "Hello")?;
+
+    // Guard is a temporary, so body can be changed right after.
+    msg.header_mut().remove_field(56)?;
+    msg.set_field(58, "Hello again")?;
+
+    // Reading both header and body at the same time is fine.
+    let header = msg.header();
+    let sender = header.get_field_str(49);
+    assert_eq!(sender, Some("SENDER"));
+    assert_eq!(header.get_field(56), None);
+    assert_eq!(msg.get_field_str(58), Some("Hello again"));
+
+    // Same result as closure based access.
+    assert_eq!(
+        msg.with_header(|h| h.get_field(49)),
+        msg.header().get_field(49)
+    );
+
+    // Mutable guard can be passed to generic code.
+    fn stamp(map: &mut impl FieldMap) -> Result<(), QuickFixError> {
+        map.set_field(52, "20240101-00:00:00")
+    }
+    {
+        let mut header = msg.header_mut();
+        stamp(&mut header)?;
+        assert_eq!(header.get_field(52).as_deref(), Some("20240101-00:00:00"));
+    }
+
+    assert_eq!(
+        msg.to_fix_string()?,
+        "9=46\u{1}49=SENDER\u{1}52=20240101-00:00:00\u{1}58=Hello again\u{1}10=252\u{1}"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_to_fix_bytes_into() -> Result<(), QuickFixError> {
+    let mut msg = Message::new();
+    msg.set_field(58, "hello")?;
+    let expected = msg.to_fix_bytes()?;
+
+    // Empty buffer is grown.
+    let mut buf = Vec::new();
+    assert_eq!(msg.to_fix_bytes_into(&mut buf)?, expected.len());
+    assert_eq!(buf, expected);
+
+    // Bytes are appended, and a large enough buffer is not reallocated.
+    let mut buf = Vec::with_capacity(1 + 2 * expected.len());
+    buf.push(b'>');
+    let capacity = buf.capacity();
+    msg.to_fix_bytes_into(&mut buf)?;
+    msg.to_fix_bytes_into(&mut buf)?;
+    assert_eq!(buf.capacity(), capacity);
+    assert_eq!(buf[0], b'>');
+    assert_eq!(&buf[1..=expected.len()], expected);
+    assert_eq!(&buf[1 + expected.len()..], expected);
+
+    // Non UTF-8 data is kept as is.
+    msg.set_field_raw(58, b"caf\xe9")?;
+    buf.clear();
+    msg.to_fix_bytes_into(&mut buf)?;
+    assert_eq!(buf, msg.to_fix_bytes()?);
+    Ok(())
+}
+
+#[test]
+fn test_embedded_nul() -> Result<(), QuickFixError> {
+    let mut msg = Message::new();
+    msg.set_field_raw(58, b"a\0b")?;
+    msg.header_mut().set_field_raw(49, b"c\0d")?;
+
+    // Values cross FFI with their length, so nothing is truncated.
+    assert_eq!(msg.get_field_str(58), Some("a\0b"));
+    assert_eq!(msg.header().get_field_str(49), Some("c\0d"));
+
+    let raw = msg.to_fix_bytes()?;
+    assert!(raw.windows(7).any(|window| window == b"58=a\0b\x01"));
+    assert!(raw.windows(7).any(|window| window == b"49=c\0d\x01"));
+    assert_eq!(msg.to_fix_bytes_ref()?, raw);
+
+    // Raw bytes are parsed with their length too.
+    let parsed = Message::try_from_bytes(&raw)?;
+    assert_eq!(parsed.get_field_str(58), Some("a\0b"));
+    assert_eq!(parsed.header().get_field_str(49), Some("c\0d"));
+    Ok(())
+}
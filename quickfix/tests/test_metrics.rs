@@ -0,0 +1,130 @@
+#![cfg(feature = "metrics")]
+
+use std::time::Duration;
+
+use metrics_util::debugging::{DebugValue, DebuggingRecorder, Snapshotter};
+use quickfix::{engine_metrics::*, *};
+use utils::*;
+
+mod utils;
+
+/// Metric name, labels and value.
+type MetricEntry = (String, Vec<(String, String)>, DebugValue);
+
+struct Metrics(Vec<MetricEntry>);
+
+impl Metrics {
+    fn take(snapshotter: &Snapshotter) -> Self {
+        Self(
+            snapshotter
+                .snapshot()
+                .into_vec()
+                .into_iter()
+                .map(|(key, _unit, _description, value)| {
+                    let key = key.key();
+                    let labels = key
+                        .labels()
+                        .map(|label| (label.key().to_string(), label.value().to_string()))
+                        .collect();
+                    (key.name().to_string(), labels, value)
+                })
+                .collect(),
+        )
+    }
+
+    fn get(&self, name: &str, labels: &[(&str, &str)]) -> Option<&DebugValue> {
+        self.0
+            .iter()
+            .find(|(other_name, other_labels, _)| {
+                other_name == name
+                    && other_labels.len() == labels.len()
+                    && labels
+                        .iter()
+                        .all(|(k, v)| other_labels.iter().any(|(ok, ov)| ok == k && ov == v))
+            })
+            .map(|(_, _, value)| value)
+    }
+
+    fn counter(&self, name: &str, labels: &[(&str, &str)]) -> u64 {
+        match self.get(name, labels) {
+            Some(DebugValue::Counter(value)) => *value,
+            None => 0,
+            Some(value) => panic!("{name} is not a counter: {value:?}"),
+        }
+    }
+}
+
+#[test]
+fn test_metrics() -> Result<(), QuickFixError> {
+    let recorder = DebuggingRecorder::new();
+    let snapshotter = recorder.snapshotter();
+    metrics::set_global_recorder(recorder).expect("recorder already installed");
+
+    let sender = FixRecorder::new(ServerType::Sender.session_id());
+    let receiver = FixRecorder::new(ServerType::Receiver.session_id());
+
+    with_session_pair(&sender, &receiver, &[], |_, _| {
+        let session_id = ServerType::Sender.session_id();
+        for headline in ["one", "two", "three"] {
+            send_to_target(build_news(headline, &[])?, &session_id)?;
+        }
+        wait_until(Duration::from_secs(10), || {
+            receiver.user_msg_count().recv == 3
+        });
+        Ok(())
+    })?;
+
+    let metrics = Metrics::take(&snapshotter);
+    let sender_repr = ServerType::Sender.session_id().to_repr();
+    let receiver_repr = ServerType::Receiver.session_id().to_repr();
+    let sender_label = ("session", sender_repr.as_str());
+    let receiver_label = ("session", receiver_repr.as_str());
+
+    // Messages are counted on the wire, by type.
+    let messages = |session, direction, msg_type| {
+        metrics.counter(
+            MESSAGES,
+            &[session, ("direction", direction), ("msg_type", msg_type)],
+        )
+    };
+    assert_eq!(messages(sender_label, "out", msg_types::NEWS), 3);
+    assert_eq!(messages(receiver_label, "in", msg_types::NEWS), 3);
+    assert_eq!(messages(receiver_label, "out", msg_types::NEWS), 0);
+    assert_eq!(messages(sender_label, "out", msg_types::LOGON), 1);
+    assert_eq!(messages(receiver_label, "in", msg_types::LOGON), 1);
+    assert_eq!(messages(receiver_label, "out", msg_types::LOGOUT), 1);
+    assert_eq!(messages(sender_label, "in", "other"), 0);
+    assert!(
+        metrics.counter(TRANSPORT_BYTES, &[sender_label, ("direction", "out")]) > 0,
+        "no bytes sent"
+    );
+
+    // Send path.
+    match metrics.get(SEND_DURATION, &[sender_label]) {
+        Some(DebugValue::Histogram(values)) => assert_eq!(values.len(), 3),
+        value => panic!("unexpected send duration: {value:?}"),
+    }
+    assert_eq!(metrics.counter(SEND_ERRORS, &[sender_label]), 0);
+    match metrics.get(OUTBOUND_QUEUE_LEN, &[sender_label]) {
+        Some(DebugValue::Gauge(value)) => assert_eq!(value.into_inner(), 0.0),
+        value => panic!("unexpected outbound queue len: {value:?}"),
+    }
+
+    // Session lifecycle.
+    for label in [sender_label, receiver_label] {
+        assert_eq!(metrics.counter(LOGONS, &[label]), 1);
+        assert_eq!(metrics.counter(LOGOUTS, &[label]), 1);
+        assert_eq!(metrics.counter(CONNECT_FAILURES, &[label]), 0);
+        assert_eq!(metrics.counter(RESEND_REQUESTS, &[label]), 0);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_known_msg_types() {
+    assert!(KNOWN_MSG_TYPES.contains(&msg_types::NEW_ORDER_SINGLE));
+    assert!(KNOWN_MSG_TYPES.contains(&msg_types::HEARTBEAT));
+    assert!(!KNOWN_MSG_TYPES
+        .iter()
+        .any(|msg_type| msg_type.starts_with('U')));
+}
@@ -0,0 +1,91 @@
+//! Every C++ exception must come back as an `Err`, never abort the process.
+
+use quickfix::{dictionary_item::*, *};
+use utils::*;
+
+mod utils;
+
+#[test]
+fn test_bad_settings() {
+    assert!(SessionSettings::try_from_path("/does/not/exist.ini").is_err());
+
+    // Session without connection type.
+    let settings = SessionSettingsBuilder::new()
+        .session(ServerType::Receiver.session_id(), |dict| {
+            StartTime("00:00:00").apply_param(dict)?;
+            EndTime("23:59:59").apply_param(dict)
+        })
+        .build()
+        .unwrap();
+    let app = Application::try_new(&NullFixApplication).unwrap();
+    let store = MemoryMessageStoreFactory::new();
+    assert!(Acceptor::try_new(&settings, &app, &store, FixSocketServerKind::default()).is_err());
+    assert!(Initiator::try_new(&settings, &app, &store, FixSocketServerKind::default()).is_err());
+}
+
+#[test]
+fn test_bad_field_conversion() {
+    let mut dict = Dictionary::new();
+    dict.set("Count", "twelve").unwrap();
+    assert!(dict.get_int("Count").is_err());
+    assert!(dict.get_int("Missing").is_err());
+
+    assert!(Message::try_from_text("garbage").is_err());
+    assert!(Message::new().get_field_as::<i32>(38).is_err());
+}
+
+#[test]
+fn test_send_to_missing_session() {
+    let session_id = SessionId::try_new("FIX.4.4", "NOBODY", "NOWHERE", "").unwrap();
+    assert!(send_to_target(Message::new(), &session_id).is_err());
+    assert!(send_to_target_by_ref_mut(&mut Message::new(), &session_id).is_err());
+    assert!(try_send_to_target(Message::new(), &session_id).is_err());
+    assert!(send_all_to_target(&mut [Message::new()], &session_id).is_err());
+    assert!(session_id.resolve().is_err());
+}
+
+#[test]
+fn test_store_not_writable() {
+    let file_path =
+        std::env::temp_dir().join(format!("quickfix-no-abort-file-{}", std::process::id()));
+    std::fs::write(&file_path, "not a directory").unwrap();
+    let store_path = file_path.join("store");
+
+    let settings = build_settings_with_defaults(
+        ServerType::Receiver,
+        0,
+        &[&FileStorePath(store_path.to_str().unwrap())],
+    )
+    .unwrap();
+    let app = Application::try_new(&NullFixApplication).unwrap();
+    let store = FileMessageStoreFactory::try_new(&settings).unwrap();
+    assert!(Acceptor::try_new(&settings, &app, &store, FixSocketServerKind::default()).is_err());
+
+    let _ = std::fs::remove_file(&file_path);
+}
+
+#[test]
+fn test_start_on_used_port() -> Result<(), QuickFixError> {
+    let app = Application::try_new(&NullFixApplication)?;
+    let store = MemoryMessageStoreFactory::new();
+
+    let mut acceptor = Acceptor::try_new(
+        &build_settings(ServerType::Receiver, 0)?,
+        &app,
+        &store,
+        FixSocketServerKind::default(),
+    )?;
+    acceptor.start()?;
+
+    // Same session and port: either session or socket creation fails.
+    let other = Acceptor::try_new(
+        &build_settings(ServerType::Receiver, listen_port(&acceptor))?,
+        &app,
+        &store,
+        FixSocketServerKind::default(),
+    );
+    assert!(other.map_or(true, |mut other| other.start().is_err()));
+
+    acceptor.stop()?;
+    Ok(())
+}
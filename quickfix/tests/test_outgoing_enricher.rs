@@ -0,0 +1,126 @@
+use std::{sync::Mutex, time::Duration};
+
+use quickfix::{dictionary_item::*, *};
+use utils::*;
+
+mod utils;
+
+const MSG_DESK_CODE: i32 = 20001;
+
+/// Stamped fields of a received message, as (MsgType, OnBehalfOfCompID, desk code).
+type Stamp = (String, Option<String>, Option<String>);
+
+/// Record stamped fields of received Logon(A) and News(B).
+#[derive(Debug, Default)]
+struct StampRecorder {
+    stamps: Mutex<Vec<Stamp>>,
+}
+
+impl StampRecorder {
+    fn record(&self, msg: &Message) {
+        let (msg_type, on_behalf_of) = msg.with_header(|header| {
+            (
+                header.get_field(tags::MSG_TYPE).unwrap_or_default(),
+                header.get_field(tags::ON_BEHALF_OF_COMP_ID),
+            )
+        });
+        let desk_code = msg.get_field(MSG_DESK_CODE);
+        self.stamps
+            .lock()
+            .unwrap()
+            .push((msg_type, on_behalf_of, desk_code));
+    }
+
+    fn stamps_of(&self, msg_type: &str) -> Vec<(Option<String>, Option<String>)> {
+        self.stamps
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(stamp_msg_type, _, _)| stamp_msg_type == msg_type)
+            .map(|(_, on_behalf_of, desk_code)| (on_behalf_of.clone(), desk_code.clone()))
+            .collect()
+    }
+}
+
+impl ApplicationCallback for StampRecorder {
+    fn on_msg_from_admin(
+        &self,
+        msg: Message,
+        _session: &SessionId,
+    ) -> Result<(), MsgFromAdminError> {
+        self.record(&msg);
+        Ok(())
+    }
+
+    fn on_msg_from_app(&self, msg: Message, _session: &SessionId) -> Result<(), MsgFromAppError> {
+        self.record(&msg);
+        Ok(())
+    }
+}
+
+/// Check enrichers ran before user callbacks.
+#[derive(Debug, Default)]
+struct DeskCodeChecker {
+    seen: Mutex<Vec<Option<String>>>,
+}
+
+impl ApplicationCallback for DeskCodeChecker {
+    fn on_msg_to_app(&self, msg: &mut Message, _session: &SessionId) -> Result<(), MsgToAppError> {
+        self.seen.lock().unwrap().push(msg.get_field(MSG_DESK_CODE));
+        Ok(())
+    }
+}
+
+#[test]
+fn test_enrich_admin_and_app() -> Result<(), QuickFixError> {
+    let enricher = OutgoingEnricher::new();
+    enricher.enrich(EnrichScope::All, |msg, _session| {
+        msg.with_header_mut(|header| header.set_field(tags::ON_BEHALF_OF_COMP_ID, "DESK-A"))
+            .unwrap();
+    });
+    enricher.enrich(EnrichScope::App, |msg, _session| {
+        msg.set_field(MSG_DESK_CODE, "EQ-42").unwrap();
+    });
+
+    let sender = EnrichedApplication::new(DeskCodeChecker::default(), enricher);
+    let receiver = StampRecorder::default();
+
+    with_session_pair(
+        &sender,
+        &receiver,
+        &[&ValidateUserDefinedFields(false)],
+        |_, _| {
+            send_to_target(build_news("Hello", &[])?, &ServerType::Sender.session_id())?;
+            wait_until(Duration::from_secs(5), || {
+                !receiver.stamps_of("B").is_empty()
+            });
+
+            assert_eq!(
+                receiver.stamps_of("A"),
+                vec![(Some("DESK-A".to_string()), None)]
+            );
+            assert_eq!(
+                receiver.stamps_of("B"),
+                vec![(Some("DESK-A".to_string()), Some("EQ-42".to_string()))]
+            );
+            assert_eq!(
+                *sender.inner().seen.lock().unwrap(),
+                vec![Some("EQ-42".to_string())]
+            );
+            Ok(())
+        },
+    )
+}
+
+#[test]
+fn test_enricher_debug() {
+    let enricher = OutgoingEnricher::new();
+    enricher.enrich(EnrichScope::Admin, |_msg, _session| {});
+    assert_eq!(
+        format!("{enricher:?}"),
+        "OutgoingEnricher { scopes: [Admin] }"
+    );
+
+    enricher.clear();
+    assert_eq!(format!("{enricher:?}"), "OutgoingEnricher { scopes: [] }");
+}
@@ -0,0 +1,79 @@
+use std::{
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+use quickfix::{dictionary_item::*, *};
+use utils::*;
+
+mod utils;
+
+/// Panic on News(B) with a "Panic" headline, count every other one.
+#[derive(Debug, Default)]
+struct PanickingReceiver {
+    news_count: AtomicUsize,
+    logout_count: AtomicUsize,
+}
+
+impl ApplicationCallback for PanickingReceiver {
+    fn on_logout(&self, _session: &SessionId) {
+        self.logout_count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn on_msg_from_app(&self, msg: Message, _session: &SessionId) -> Result<(), MsgFromAppError> {
+        if msg.is_field_equal(tags::HEADLINE, "Panic") {
+            panic!("Deliberate panic from callback");
+        }
+        self.news_count.fetch_add(1, Ordering::Relaxed);
+        Ok(())
+    }
+}
+
+// Policy is process wide: every scenario runs in the same test.
+#[test]
+fn test_callback_panic_policy() -> Result<(), QuickFixError> {
+    assert_eq!(panic_policy(), PanicPolicy::Swallow);
+
+    // Swallow: session keeps working.
+    let receiver = PanickingReceiver::default();
+    with_session_pair(&NullFixApplication, &receiver, &[], |_, acceptor| {
+        send_to_target(build_news("Panic", &[])?, &ServerType::Sender.session_id())?;
+        send_to_target(build_news("Hello", &[])?, &ServerType::Sender.session_id())?;
+        wait_until(Duration::from_secs(5), || {
+            receiver.news_count.load(Ordering::Relaxed) == 1
+        });
+        assert!(acceptor.is_logged_on()?);
+        assert_eq!(receiver.logout_count.load(Ordering::Relaxed), 0);
+        Ok(())
+    })?;
+
+    // Disconnect: session is dropped, then reconnects and messages flow again.
+    set_panic_policy(PanicPolicy::Disconnect);
+    assert_eq!(panic_policy(), PanicPolicy::Disconnect);
+
+    let receiver = PanickingReceiver::default();
+    let result = with_session_pair(
+        &NullFixApplication,
+        &receiver,
+        &[&ReconnectInterval(1)],
+        |initiator, acceptor| {
+            send_to_target(build_news("Panic", &[])?, &ServerType::Sender.session_id())?;
+            wait_until(Duration::from_secs(5), || {
+                receiver.logout_count.load(Ordering::Relaxed) == 1
+            });
+
+            wait_until(Duration::from_secs(10), || {
+                initiator.is_logged_on().unwrap_or(false)
+                    && acceptor.is_logged_on().unwrap_or(false)
+            });
+            send_to_target(build_news("Hello", &[])?, &ServerType::Sender.session_id())?;
+            wait_until(Duration::from_secs(5), || {
+                receiver.news_count.load(Ordering::Relaxed) == 1
+            });
+            Ok(())
+        },
+    );
+
+    set_panic_policy(PanicPolicy::Swallow);
+    result
+}
@@ -0,0 +1,206 @@
+use quickfix::*;
+use utils::FIX44_SPEC;
+
+mod utils;
+
+/// Build a FIX 4.4 message with valid BodyLength(9) and CheckSum(10) from `body` fields.
+fn build_raw(msg_type: &str, body: &str) -> String {
+    let body = format!(
+        "35={msg_type}\x0149=SENDER\x0156=TARGET\x0134=1\x0152=20231114-22:13:20.123\x01{body}"
+    );
+    let head = format!("8=FIX.4.4\x019={}\x01{body}", body.len());
+    let checksum = head.bytes().map(u32::from).sum::<u32>() % 256;
+    format!("{head}10={checksum:03}\x01")
+}
+
+fn new_order_with_parties(count: usize, parties: &[&str]) -> String {
+    let parties: String = parties
+        .iter()
+        .map(|party| format!("448={party}\x01447=D\x01452=1\x01"))
+        .collect();
+    build_raw(
+        "D",
+        &format!(
+            "11=ORDER-1\x01453={count}\x01{parties}55=EURUSD\x0154=1\x0160=20231114-22:13:20.123\x0138=100\x0140=1\x01"
+        ),
+    )
+}
+
+#[test]
+fn test_default_limits() {
+    assert_eq!(
+        ParseLimits::default(),
+        ParseLimits {
+            max_message_len: 1024 * 1024,
+            max_field_len: 64 * 1024,
+            max_groups: 10_000,
+        }
+    );
+
+    let msg = Message::try_from_text(&build_raw("B", "148=hello\x01")).unwrap();
+    assert_eq!(msg.get_field(tags::HEADLINE).as_deref(), Some("hello"));
+}
+
+#[test]
+fn test_message_too_large() {
+    // Multi-megabyte single field.
+    let text = build_raw("B", &format!("148={}\x01", "x".repeat(2 * 1024 * 1024)));
+    let err = Message::try_from_text(&text).unwrap_err();
+    assert_eq!(
+        err,
+        QuickFixError::MessageTooLarge {
+            len: text.len(),
+            max: 1024 * 1024
+        }
+    );
+    assert_eq!(err.kind(), ErrorKind::InvalidMessage);
+
+    // Still accepted when asked so.
+    let msg = Message::try_from_text_with_limits(&text, &ParseLimits::unlimited()).unwrap();
+    assert_eq!(
+        msg.get_field(tags::HEADLINE).map(|x| x.len()),
+        Some(2 * 1024 * 1024)
+    );
+}
+
+#[test]
+fn test_absurd_body_length() {
+    let limits = ParseLimits {
+        max_message_len: 1000,
+        ..ParseLimits::default()
+    };
+    assert_eq!(
+        Message::try_from_text_with_limits("8=FIX.4.4\x019=1001\x0135=0\x0110=000\x01", &limits)
+            .unwrap_err(),
+        QuickFixError::MessageTooLarge {
+            len: 1001,
+            max: 1000
+        }
+    );
+
+    // Overflowing values saturate instead of wrapping to small ones.
+    assert_eq!(
+        Message::try_from_text("8=FIX.4.4\x019=184467440737095516170\x0135=0\x0110=000\x01")
+            .unwrap_err(),
+        QuickFixError::MessageTooLarge {
+            len: usize::MAX,
+            max: 1024 * 1024
+        }
+    );
+}
+
+#[test]
+fn test_field_too_large() {
+    let limits = ParseLimits {
+        max_field_len: 4,
+        ..ParseLimits::default()
+    };
+    let err = Message::try_from_bytes_with_limits(b"58=hello\x01", &limits).unwrap_err();
+    assert_eq!(
+        err,
+        QuickFixError::FieldTooLarge {
+            tag: Some(58),
+            len: 5,
+            max: 4
+        }
+    );
+    assert_eq!(err.tag(), Some(58));
+    assert_eq!(err.to_string(), "Field too large: 5 bytes (tag 58) (max 4)");
+    assert!(Message::try_from_bytes_with_limits(b"58=hell\x01", &limits).is_ok());
+
+    // Invalid tags are reported too.
+    assert_eq!(
+        Message::try_from_bytes_with_limits(b"5x=hello\x01", &limits).unwrap_err(),
+        QuickFixError::FieldTooLarge {
+            tag: None,
+            len: 5,
+            max: 4
+        }
+    );
+}
+
+#[test]
+fn test_too_many_groups() {
+    let dictionary = DataDictionary::try_from_path(FIX44_SPEC).unwrap();
+    let limits = ParseLimits {
+        max_groups: 2,
+        ..ParseLimits::default()
+    };
+
+    let text = new_order_with_parties(2, &["A", "B"]);
+    let msg = dictionary
+        .try_build_message_with_limits(&text, &limits)
+        .unwrap();
+    assert!(msg.clone_group(2, tags::NO_PARTY_I_DS).is_some());
+
+    let text = new_order_with_parties(3, &["A", "B", "C"]);
+    let err = dictionary
+        .try_build_message_with_limits(&text, &limits)
+        .unwrap_err();
+    assert_eq!(
+        err,
+        QuickFixError::TooManyGroups {
+            tag: tags::NO_PARTY_I_DS,
+            count: 3,
+            max: 2
+        }
+    );
+    assert_eq!(err.tag(), Some(tags::NO_PARTY_I_DS));
+
+    // Huge count with a single entry is rejected before quickfix tries reading entries.
+    let text = new_order_with_parties(1_000_000_000, &["A"]);
+    assert!(matches!(
+        dictionary.try_build_message(&text),
+        Err(QuickFixError::TooManyGroups {
+            count: 1_000_000_000,
+            ..
+        })
+    ));
+
+    // Count fields are not known without dictionary.
+    assert!(Message::try_from_text_with_limits(&text, &limits).is_ok());
+}
+
+/// Inputs found while fuzzing: they must fail or parse, without crashing.
+#[test]
+fn test_adversarial_input() {
+    let dictionary = DataDictionary::try_from_path(FIX44_SPEC).unwrap();
+    let valid = new_order_with_parties(2, &["A", "B"]);
+    let mut inputs: Vec<Vec<u8>> = vec![
+        b"".to_vec(),
+        b"\x01\x01\x01".to_vec(),
+        b"=".to_vec(),
+        b"=value\x01".to_vec(),
+        b"tag\x01".to_vec(),
+        b"8=FIX.4.4\x019=5\x0135=".to_vec(),
+        b"8=FIX.4.4\x019=-1\x0135=0\x0110=000\x01".to_vec(),
+        b"8=FIX.4.4\x019=5\x0135=0\x0135=0\x0135=D\x0110=000\x01".to_vec(),
+        b"99999999999999999999=1\x01".to_vec(),
+        b"-1=1\x010=0\x01".to_vec(),
+        b"8=FIX.4.4\x019=5\x0135=0\x0110=999999999999\x01".to_vec(),
+        b"\xff\xfe\x80=\xc3\x28\x01".to_vec(),
+        new_order_with_parties(3, &["A"]).into_bytes(),
+        new_order_with_parties(0, &["A", "B"]).into_bytes(),
+        new_order_with_parties(2, &[]).into_bytes(),
+    ];
+    // Every truncation of a valid message.
+    inputs.extend((0..valid.len()).map(|len| valid.as_bytes()[..len].to_vec()));
+
+    for input in &inputs {
+        if let Ok(msg) = Message::try_from_bytes(input) {
+            let _ = msg.to_pretty_string(None, '|');
+            let _ = msg.to_fix_string();
+        }
+        if let Ok(text) = std::str::from_utf8(input) {
+            if let Ok(msg) = dictionary.try_build_message(text) {
+                let _ = msg.to_pretty_string(Some(&dictionary), '|');
+                let _ = dictionary.validate(&msg);
+            }
+        }
+    }
+
+    // Group counts not matching entries are rejected by quickfix.
+    if let Ok(msg) = dictionary.try_build_message(&new_order_with_parties(3, &["A"])) {
+        assert!(dictionary.validate(&msg).is_err());
+    }
+}
@@ -0,0 +1,172 @@
+use std::{
+    io::Cursor,
+    time::{Duration, Instant, UNIX_EPOCH},
+};
+
+use quickfix::{replay::*, testing::*, *};
+
+const FIXTURE: &str = "tests/fixtures/messages.log";
+
+fn sent_by_sender(msg: &Message) -> bool {
+    msg.with_header(|h| h.get_field(tags::SENDER_COMP_ID))
+        .as_deref()
+        == Some("SENDER")
+}
+
+fn start_pair() -> Result<SessionPair<'static>, QuickFixError> {
+    let pair = SessionPair::start(FixVersion::Fix44, FixSocketServerKind::SingleThreaded)?;
+    pair.wait_logged_on(Duration::from_secs(10))?;
+    Ok(pair)
+}
+
+#[test]
+fn test_read_message_log() -> Result<(), QuickFixError> {
+    let entries: Vec<_> = MessageLogReader::open(FIXTURE)?.collect();
+    assert_eq!(entries.len(), 8);
+
+    let (time, msg) = entries[0].as_ref().unwrap();
+    assert_eq!(*time, UNIX_EPOCH + Duration::from_millis(1_700_000_000_123));
+    assert_eq!(
+        msg.with_header(|h| h.get_field(tags::MSG_TYPE)).as_deref(),
+        Some(msg_types::LOGON)
+    );
+
+    let (_, msg) = entries[2].as_ref().unwrap();
+    assert_eq!(msg.get_field(tags::HEADLINE).as_deref(), Some("first"));
+
+    assert_eq!(
+        entries[4].as_ref().unwrap_err(),
+        &MalformedLine {
+            line: 5,
+            reason: "missing timestamp".to_string()
+        }
+    );
+
+    // Empty line 8 is ignored.
+    let (time, _) = entries[7].as_ref().unwrap();
+    assert_eq!(*time, UNIX_EPOCH + Duration::from_millis(1_700_000_030_400));
+    Ok(())
+}
+
+#[test]
+fn test_read_malformed_lines() {
+    let log = "20231114-22:13:20 : 8=FIX.4.4\x019=5\x0135=0\x0110=000\x01\n\
+               20231114-25:13:20.123 : 8=FIX.4.4\x019=5\x0135=0\x0110=000\x01\n\
+               20231114-22:13:20.123 : 8=FIX.4.4\x019=5\x0158=no type\x0110=000\x01\n";
+    let entries: Vec<_> = MessageLogReader::from_reader(Cursor::new(log)).collect();
+    assert_eq!(entries.len(), 3);
+
+    let (time, _) = entries[0].as_ref().unwrap();
+    assert_eq!(*time, UNIX_EPOCH + Duration::from_secs(1_700_000_000));
+    assert_eq!(
+        entries[1].as_ref().unwrap_err().to_string(),
+        "line 2: invalid timestamp `20231114-25:13:20.123`"
+    );
+    assert_eq!(
+        entries[2].as_ref().unwrap_err().to_string(),
+        "line 3: missing MsgType(35)"
+    );
+}
+
+#[test]
+fn test_replay() -> Result<(), QuickFixError> {
+    let pair = start_pair()?;
+
+    let report = Replayer::new(MessageLogReader::open(FIXTURE)?)
+        .filter(sent_by_sender)
+        .rewrite(|msg| {
+            if let Some(cl_ord_id) = msg.get_field(tags::CL_ORD_ID) {
+                msg.set_field(tags::CL_ORD_ID, format!("REPLAY-{cl_ord_id}"))?;
+            }
+            Ok(())
+        })
+        .run(pair.initiator_session_id())?;
+
+    assert_eq!(
+        report,
+        ReplayReport {
+            sent: 3,
+            skipped_admin: 3,
+            filtered: 1,
+            malformed: vec![MalformedLine {
+                line: 5,
+                reason: "missing timestamp".to_string()
+            }],
+        }
+    );
+
+    let received = pair
+        .acceptor_app()
+        .wait_app_messages(3, Duration::from_secs(10));
+    let summary: Vec<_> = received
+        .iter()
+        .map(|msg| {
+            (
+                msg.with_header(|h| h.get_field(tags::MSG_TYPE)),
+                msg.get_field(tags::HEADLINE)
+                    .or_else(|| msg.get_field(tags::CL_ORD_ID)),
+            )
+        })
+        .collect();
+    let expected = [
+        (msg_types::NEWS, "first"),
+        (msg_types::NEW_ORDER_SINGLE, "REPLAY-ORDER-1"),
+        (msg_types::NEWS, "second"),
+    ];
+    assert_eq!(
+        summary,
+        expected.map(|(msg_type, value)| (Some(msg_type.to_string()), Some(value.to_string())))
+    );
+
+    // Header comes from replaying session, not from the log.
+    assert_eq!(
+        received[0]
+            .with_header(|h| h.get_field(tags::TARGET_COMP_ID))
+            .as_deref(),
+        Some(RECEIVER_COMP_ID)
+    );
+    Ok(())
+}
+
+#[test]
+fn test_replay_fail_on_malformed() -> Result<(), QuickFixError> {
+    let pair = start_pair()?;
+
+    let err = Replayer::new(MessageLogReader::open(FIXTURE)?)
+        .filter(sent_by_sender)
+        .on_malformed(OnMalformed::Fail)
+        .run(pair.initiator_session_id())
+        .unwrap_err();
+    assert!(
+        matches!(&err, QuickFixError::MessageParseError(reason) if reason == "line 5: missing timestamp"),
+        "unexpected error: {err:?}"
+    );
+
+    // Messages before malformed line have been sent.
+    let received = pair
+        .acceptor_app()
+        .wait_app_messages(2, Duration::from_secs(10));
+    assert_eq!(received.len(), 2);
+    Ok(())
+}
+
+#[test]
+fn test_replay_speed() -> Result<(), QuickFixError> {
+    let pair = start_pair()?;
+
+    // Sent messages span 30.2 seconds in the log.
+    let start = Instant::now();
+    let report = Replayer::new(MessageLogReader::open(FIXTURE)?)
+        .filter(sent_by_sender)
+        .speed(Speed::Scaled(200.0))
+        .run(pair.initiator_session_id())?;
+    assert_eq!(report.sent, 3);
+    assert!(start.elapsed() >= Duration::from_millis(150));
+
+    let err = Replayer::new(MessageLogReader::open(FIXTURE)?)
+        .speed(Speed::Scaled(0.0))
+        .run(pair.initiator_session_id())
+        .unwrap_err();
+    assert!(matches!(err, QuickFixError::InvalidArgument(_)));
+    Ok(())
+}
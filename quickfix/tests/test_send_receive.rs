@@ -12,11 +12,6 @@ where
     let sender = FixRecorder::new(ServerType::Sender.session_id());
     let receiver = FixRecorder::new(ServerType::Receiver.session_id());
 
-    // Init settings with same server port.
-    let communication_port = find_available_port();
-    let settings_sender = setting_builder(ServerType::Sender, communication_port)?;
-    let settings_receiver = setting_builder(ServerType::Receiver, communication_port)?;
-
     let app_sender = Application::try_new(&sender)?;
     let app_receiver = Application::try_new(&receiver)?;
 
@@ -31,13 +26,8 @@ where
     assert_eq!(receiver.admin_msg_count(), MsgCounter::default());
     assert_eq!(receiver.user_msg_count(), MsgCounter::default());
 
-    // Init socket acceptor / initiator.
-    let mut socket_sender = Initiator::try_new(
-        &settings_sender,
-        &app_sender,
-        &message_store_factory_sender,
-        server_kind,
-    )?;
+    // Init socket acceptor, on a port picked by the OS.
+    let settings_receiver = setting_builder(ServerType::Receiver, 0)?;
     let mut socket_receiver = Acceptor::try_new(
         &settings_receiver,
         &app_receiver,
@@ -46,17 +36,30 @@ where
     )?;
 
     // Check session have been configured
-    assert_eq!(sender.session_created(), 1);
     assert_eq!(receiver.session_created(), 1);
 
     // Check connection state = OFF
-    assert!(!sender.is_logged_in());
-    assert!(!socket_sender.is_logged_on().unwrap());
     assert!(!receiver.is_logged_in());
     assert!(!socket_receiver.is_logged_on().unwrap());
 
-    // Start the app
+    // Start acceptor, then init socket initiator with the same port.
     socket_receiver.start()?;
+    let settings_sender = setting_builder(ServerType::Sender, listen_port(&socket_receiver))?;
+    let mut socket_sender = Initiator::try_new(
+        &settings_sender,
+        &app_sender,
+        &message_store_factory_sender,
+        server_kind,
+    )?;
+
+    // Check session have been configured
+    assert_eq!(sender.session_created(), 1);
+
+    // Check connection state = OFF
+    assert!(!sender.is_logged_in());
+    assert!(!socket_sender.is_logged_on().unwrap());
+
+    // Start the app
     socket_sender.start()?;
 
     // Wait for login completion
@@ -141,8 +144,11 @@ where
     assert_eq!(receiver.user_msg_count(), MsgCounter { sent: 2, recv: 2 });
 
     // Stop everything
-    socket_receiver.stop()?;
-    socket_sender.stop()?;
+    let report = socket_receiver.shutdown(Duration::from_secs(5))?;
+    let clean: Vec<_> = report.clean.iter().map(SessionId::to_repr).collect();
+    assert_eq!(clean, vec![ServerType::Receiver.session_id().to_repr()]);
+    assert!(report.forced.is_empty());
+    socket_sender.shutdown(Duration::from_secs(5))?;
 
     // Check connection state = OFF
     assert!(!sender.is_logged_in());
@@ -0,0 +1,205 @@
+use std::{
+    cell::Cell,
+    collections::HashMap,
+    marker::PhantomData,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc, Mutex,
+    },
+    thread,
+    time::Duration,
+};
+
+use quickfix::*;
+use static_assertions::{assert_impl_all, assert_not_impl_any};
+use utils::*;
+
+mod utils;
+
+struct Callbacks;
+
+impl ApplicationCallback for Callbacks {}
+
+struct NotSyncCallbacks(PhantomData<Cell<u8>>);
+
+impl ApplicationCallback for NotSyncCallbacks {}
+
+assert_impl_all!(Message: Send);
+assert_impl_all!(Group: Send, Sync);
+assert_impl_all!(Trailer: Send);
+assert_impl_all!(SessionId: Send, Sync);
+assert_impl_all!(Dictionary: Send, Sync);
+assert_impl_all!(SessionSettings: Send, Sync);
+assert_impl_all!(DataDictionary: Send, Sync);
+
+assert_impl_all!(MemoryMessageStoreFactory: Send, Sync);
+assert_impl_all!(FileMessageStoreFactory: Send, Sync);
+assert_impl_all!(NullMessageStoreFactory: Send, Sync);
+assert_impl_all!(LogFactory<'static, StdLogger>: Send, Sync);
+assert_impl_all!(PerSessionLogFactory: Send, Sync);
+assert_impl_all!(Application<'static, Callbacks>: Send, Sync);
+
+assert_impl_all!(Acceptor<'static, Callbacks, MemoryMessageStoreFactory>: Send, Sync);
+assert_impl_all!(Initiator<'static, Callbacks, MemoryMessageStoreFactory>: Send, Sync);
+assert_impl_all!(StopHandle: Send, Sync);
+assert_impl_all!(Session<'static>: Send, Sync);
+assert_impl_all!(ResolvedSession: Send, Sync);
+
+// Callbacks are called concurrently by sending threads, so they must be `Sync`.
+assert_not_impl_any!(Application<'static, NotSyncCallbacks>: Send, Sync);
+assert_not_impl_any!(Acceptor<'static, NotSyncCallbacks, MemoryMessageStoreFactory>: Send, Sync);
+
+// Headers returned by `Message::get_header` point into their message.
+assert_not_impl_any!(Header: Send, Sync);
+
+// Serializing a shared message rewrites its BodyLength(9) and CheckSum(10).
+assert_not_impl_any!(Message: Sync);
+assert_not_impl_any!(Trailer: Sync);
+
+#[test]
+fn test_message_over_channel() -> Result<(), QuickFixError> {
+    let (tx, rx) = mpsc::channel();
+
+    thread::spawn(move || {
+        let mut msg = Message::new();
+        msg.set_field(58, "Hello")?;
+        tx.send(msg).expect("receiver is alive");
+        Ok::<_, QuickFixError>(())
+    })
+    .join()
+    .expect("sender thread panicked")?;
+
+    let msg = rx.recv().expect("message has been sent");
+    assert_eq!(msg.get_field(58).as_deref(), Some("Hello"));
+    Ok(())
+}
+
+/// Record headlines received on every session, and count messages with a bad checksum.
+#[derive(Debug, Default)]
+struct StressReceiver {
+    headlines: Mutex<HashMap<String, Vec<String>>>,
+    bad_checksums: AtomicUsize,
+}
+
+impl ApplicationCallback for StressReceiver {
+    fn on_msg_from_app(&self, msg: Message, session: &SessionId) -> Result<(), MsgFromAppError> {
+        let headline = msg.get_field(tags::HEADLINE).unwrap_or_default();
+        self.headlines
+            .lock()
+            .unwrap()
+            .entry(session.to_repr())
+            .or_default()
+            .push(headline);
+        Ok(())
+    }
+
+    fn on_raw_incoming(&self, _session: &SessionId, raw: &[u8]) {
+        if !has_valid_checksum(raw) {
+            self.bad_checksums.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
+
+/// Check CheckSum(10) is the sum of every byte before it, modulo 256.
+fn has_valid_checksum(raw: &[u8]) -> bool {
+    let Some(start) = raw.windows(4).rposition(|window| window == b"\x0110=") else {
+        return false;
+    };
+    let expected: u32 = raw[..=start]
+        .iter()
+        .map(|byte| u32::from(*byte))
+        .sum::<u32>()
+        % 256;
+    std::str::from_utf8(&raw[start + 4..])
+        .ok()
+        .and_then(|value| value.trim_end_matches('\x01').parse::<u32>().ok())
+        == Some(expected)
+}
+
+#[test]
+fn test_concurrent_send_to_distinct_sessions() -> Result<(), QuickFixError> {
+    const SESSIONS: usize = 2;
+    const THREADS: usize = 4;
+    const MSG_PER_THREAD: usize = 5_000;
+
+    let receiver = StressReceiver::default();
+    let app_sender = Application::try_new(&NullFixApplication)?;
+    let app_receiver = Application::try_new(&receiver)?;
+    let store_sender = MemoryMessageStoreFactory::new();
+    let store_receiver = MemoryMessageStoreFactory::new();
+
+    let settings_receiver = build_multi_settings(ServerType::Receiver, 0, SESSIONS)?;
+    let mut acceptor = Acceptor::try_new(
+        &settings_receiver,
+        &app_receiver,
+        &store_receiver,
+        FixSocketServerKind::MultiThreaded,
+    )?;
+    acceptor.start()?;
+
+    let settings_sender =
+        build_multi_settings(ServerType::Sender, listen_port(&acceptor), SESSIONS)?;
+    let mut initiator = Initiator::try_new(
+        &settings_sender,
+        &app_sender,
+        &store_sender,
+        FixSocketServerKind::MultiThreaded,
+    )?;
+    initiator.start()?;
+
+    wait_until(Duration::from_secs(10), || {
+        (0..SESSIONS).all(|index| {
+            initiator
+                .is_session_logged_on(&ServerType::Sender.indexed_session_id(index))
+                .unwrap_or(false)
+        })
+    });
+
+    // Threads are spread over sessions, so each session is also sent to concurrently.
+    thread::scope(|scope| {
+        let handles: Vec<_> = (0..THREADS)
+            .map(|thread_index| {
+                scope.spawn(move || {
+                    let session_id = ServerType::Sender.indexed_session_id(thread_index % SESSIONS);
+                    (0..MSG_PER_THREAD).try_for_each(|msg_index| {
+                        let mut msg = build_news(&format!("{thread_index}-{msg_index}"), &[])?;
+                        send_to_target_by_ref_mut(&mut msg, &session_id)
+                    })
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .try_for_each(|handle| handle.join().expect("Sender thread panicked"))
+    })?;
+
+    wait_until(Duration::from_secs(60), || {
+        let headlines = receiver.headlines.lock().unwrap();
+        headlines.values().map(Vec::len).sum::<usize>() == THREADS * MSG_PER_THREAD
+    });
+
+    initiator.stop()?;
+    acceptor.stop()?;
+
+    assert_eq!(receiver.bad_checksums.load(Ordering::Relaxed), 0);
+
+    // Every message has been received exactly once, on the session it was sent to.
+    let mut headlines = std::mem::take(&mut *receiver.headlines.lock().unwrap());
+    for index in 0..SESSIONS {
+        let session_id = ServerType::Receiver.indexed_session_id(index);
+        let mut received = headlines.remove(&session_id.to_repr()).unwrap_or_default();
+        received.sort_unstable();
+
+        let mut expected: Vec<_> = (0..THREADS)
+            .filter(|thread_index| thread_index % SESSIONS == index)
+            .flat_map(|thread_index| {
+                (0..MSG_PER_THREAD).map(move |msg_index| format!("{thread_index}-{msg_index}"))
+            })
+            .collect();
+        expected.sort_unstable();
+
+        assert_eq!(received, expected, "{}", session_id.to_repr());
+    }
+    assert!(headlines.is_empty());
+    Ok(())
+}
@@ -5,25 +5,33 @@ use std::{
 };
 
 use quickfix::{
-    send_to_target, send_to_target_by_ref_mut, Acceptor, Application, ConnectionHandler, FieldMap,
-    FixSocketServerKind, Initiator, MemoryMessageStoreFactory, QuickFixError,
+    send_all_to_target, send_to_target, send_to_target_by_ref_mut, tags, Acceptor, Application,
+    ConnectionHandler, FieldMap, FixSocketServerKind, Initiator, MemoryMessageStoreFactory,
+    MessagePool, QuickFixError, SessionContainer, SessionId,
 };
 use utils::{
-    build_news, build_settings, find_available_port, NullFixApplication, ServerType, MSG_HEADLINE,
+    build_multi_settings, build_news, listen_port, session_pair_builder, NullFixApplication,
+    ServerType,
 };
 
 mod utils;
 
+// Benchmarks reuse the same session IDs and ports, and must not skew each other timings.
+// Sending from several threads does not require any lock, see `bench_multi_threaded_send_to_target`.
 static GLOBAL_LOCK: Mutex<u8> = Mutex::new(0);
 
 const WARMUP_ITERS: usize = 200;
 const BENCH_ITERS: usize = 2_000;
+const BENCH_THREADS: usize = 4;
 
 #[derive(Debug)]
 struct BenchResult {
     iterations: usize,
     string_then_send: Duration,
     str_then_send_by_ref_mut: Duration,
+    str_then_send_all: Duration,
+    str_then_send_resolved: Duration,
+    str_then_send_pooled: Duration,
 }
 
 fn run_bench(
@@ -32,45 +40,20 @@ fn run_bench(
 ) -> Result<BenchResult, QuickFixError> {
     let _lock = GLOBAL_LOCK.lock().expect("GLOBAL_LOCK poisoned");
 
-    let communication_port = find_available_port();
-    let settings_sender = build_settings(ServerType::Sender, communication_port)?;
-    let settings_receiver = build_settings(ServerType::Receiver, communication_port)?;
-
-    let app_sender = Application::try_new(&NullFixApplication)?;
-    let app_receiver = Application::try_new(&NullFixApplication)?;
-
-    let message_store_factory_sender = MemoryMessageStoreFactory::new();
-    let message_store_factory_receiver = MemoryMessageStoreFactory::new();
-
-    let mut socket_sender = Initiator::try_new(
-        &settings_sender,
-        &app_sender,
-        &message_store_factory_sender,
-        server_kind,
-    )?;
-    let mut socket_receiver = Acceptor::try_new(
-        &settings_receiver,
-        &app_receiver,
-        &message_store_factory_receiver,
-        server_kind,
-    )?;
-
-    socket_receiver.start()?;
-    socket_sender.start()?;
-
-    while !socket_sender.is_logged_on()? || !socket_receiver.is_logged_on()? {
-        thread::sleep(Duration::from_millis(10));
-    }
+    let pair = session_pair_builder()
+        .server_kind(server_kind)
+        .start_with(&NullFixApplication, &NullFixApplication)?;
+    pair.wait_logged_on(Duration::from_secs(30))?;
 
     for _ in 0..WARMUP_ITERS {
         let msg = build_news("warmup", &[])?;
-        let _headline = msg.get_field(MSG_HEADLINE);
+        let _headline = msg.get_field(tags::HEADLINE);
         send_to_target(msg, &ServerType::Sender.session_id())?;
     }
 
     for _ in 0..WARMUP_ITERS {
         let mut msg = build_news("warmup", &[])?;
-        let _headline = msg.get_field_str(MSG_HEADLINE);
+        let _headline = msg.get_field_str(tags::HEADLINE);
         send_to_target_by_ref_mut(&mut msg, &ServerType::Sender.session_id())?;
         drop(msg);
     }
@@ -80,7 +63,7 @@ fn run_bench(
     let t0 = Instant::now();
     for _ in 0..iterations {
         let msg = build_news("string_send", &[])?;
-        let _headline = msg.get_field(MSG_HEADLINE);
+        let _headline = msg.get_field(tags::HEADLINE);
         send_to_target(msg, &ServerType::Sender.session_id())?;
     }
     let string_then_send = t0.elapsed();
@@ -90,7 +73,7 @@ fn run_bench(
     let t1 = Instant::now();
     for _ in 0..iterations {
         let mut msg = build_news("str_send_ref_mut", &[])?;
-        let _headline = msg.get_field_str(MSG_HEADLINE);
+        let _headline = msg.get_field_str(tags::HEADLINE);
         send_to_target_by_ref_mut(&mut msg, &ServerType::Sender.session_id())?;
         drop(msg);
     }
@@ -98,13 +81,57 @@ fn run_bench(
 
     thread::sleep(Duration::from_millis(100));
 
-    socket_receiver.stop()?;
-    socket_sender.stop()?;
+    let t2 = Instant::now();
+    let mut msgs = (0..iterations)
+        .map(|_| {
+            let msg = build_news("str_send_all", &[])?;
+            let _headline = msg.get_field_str(tags::HEADLINE);
+            Ok(msg)
+        })
+        .collect::<Result<Vec<_>, QuickFixError>>()?;
+    let sent = send_all_to_target(&mut msgs, &ServerType::Sender.session_id())?;
+    drop(msgs);
+    let str_then_send_all = t2.elapsed();
+    assert_eq!(sent, iterations);
+
+    thread::sleep(Duration::from_millis(100));
+
+    let t3 = Instant::now();
+    let session = ServerType::Sender.session_id().resolve()?;
+    for _ in 0..iterations {
+        let mut msg = build_news("str_send_resolved", &[])?;
+        let _headline = msg.get_field_str(tags::HEADLINE);
+        session.send(&mut msg)?;
+        drop(msg);
+    }
+    let str_then_send_resolved = t3.elapsed();
+
+    thread::sleep(Duration::from_millis(100));
+
+    let pool = MessagePool::with_capacity(1);
+    let t4 = Instant::now();
+    for _ in 0..iterations {
+        let mut msg = pool.acquire();
+        msg.with_header_mut(|h| h.set_field(tags::MSG_TYPE, "B"))?;
+        msg.set_field(tags::HEADLINE, "str_send_pooled")?;
+        msg.set_field(tags::NO_LINES_OF_TEXT, 0)?;
+        let _headline = msg.get_field_str(tags::HEADLINE);
+        send_to_target_by_ref_mut(&mut msg, &ServerType::Sender.session_id())?;
+    }
+    let str_then_send_pooled = t4.elapsed();
+    assert_eq!(pool.available(), 1);
+
+    thread::sleep(Duration::from_millis(100));
+
+    pair.shutdown(Duration::from_secs(5))?;
 
     Ok(BenchResult {
         iterations,
         string_then_send,
         str_then_send_by_ref_mut,
+        str_then_send_all,
+        str_then_send_resolved,
+        str_then_send_pooled,
     })
 }
 
@@ -116,10 +143,26 @@ fn bench_get_field_then_send_paths() -> Result<(), QuickFixError> {
     let string_per_op_ns = result.string_then_send.as_nanos() as f64 / result.iterations as f64;
     let str_per_op_ns =
         result.str_then_send_by_ref_mut.as_nanos() as f64 / result.iterations as f64;
+    let send_all_per_op_ns = result.str_then_send_all.as_nanos() as f64 / result.iterations as f64;
+    let resolved_per_op_ns =
+        result.str_then_send_resolved.as_nanos() as f64 / result.iterations as f64;
+    let pooled_per_op_ns = result.str_then_send_pooled.as_nanos() as f64 / result.iterations as f64;
     let delta_pct = ((result.string_then_send.as_nanos() as f64
         - result.str_then_send_by_ref_mut.as_nanos() as f64)
         / result.string_then_send.as_nanos() as f64)
         * 100.0;
+    let send_all_delta_pct = ((result.string_then_send.as_nanos() as f64
+        - result.str_then_send_all.as_nanos() as f64)
+        / result.string_then_send.as_nanos() as f64)
+        * 100.0;
+    let resolved_delta_pct = ((result.string_then_send.as_nanos() as f64
+        - result.str_then_send_resolved.as_nanos() as f64)
+        / result.string_then_send.as_nanos() as f64)
+        * 100.0;
+    let pooled_delta_pct = ((result.string_then_send.as_nanos() as f64
+        - result.str_then_send_pooled.as_nanos() as f64)
+        / result.string_then_send.as_nanos() as f64)
+        * 100.0;
 
     println!("=== send_to_target benchmark ===");
     println!("iterations: {}", result.iterations);
@@ -132,6 +175,156 @@ fn bench_get_field_then_send_paths() -> Result<(), QuickFixError> {
         result.str_then_send_by_ref_mut, str_per_op_ns
     );
     println!("delta vs #1: {:.2}%", delta_pct);
+    println!(
+        "3) get_field_str(&str) + send_all_to_target(batch) + drop: {:?} ({:.0} ns/op)",
+        result.str_then_send_all, send_all_per_op_ns
+    );
+    println!("delta vs #1: {:.2}%", send_all_delta_pct);
+    println!(
+        "4) get_field_str(&str) + ResolvedSession::send + drop: {:?} ({:.0} ns/op)",
+        result.str_then_send_resolved, resolved_per_op_ns
+    );
+    println!("delta vs #1: {:.2}%", resolved_delta_pct);
+    println!(
+        "5) MessagePool::acquire + send_to_target_by_ref_mut + recycle: {:?} ({:.0} ns/op)",
+        result.str_then_send_pooled, pooled_per_op_ns
+    );
+    println!("delta vs #1: {:.2}%", pooled_delta_pct);
+
+    Ok(())
+}
+
+#[derive(Debug)]
+struct MultiThreadedBenchResult {
+    threads: usize,
+    iterations: usize,
+    lookup_then_send: Duration,
+    cached_then_send: Duration,
+}
+
+/// Run `send` from one thread per session and measure time until every thread is done.
+fn run_threads<F>(threads: usize, iterations: usize, send: F) -> Result<Duration, QuickFixError>
+where
+    F: Fn(&SessionId) -> Result<(), QuickFixError> + Sync,
+{
+    let start = Instant::now();
+    thread::scope(|scope| {
+        let handles: Vec<_> = (0..threads)
+            .map(|index| {
+                let send = &send;
+                scope.spawn(move || {
+                    let session_id = ServerType::Sender.indexed_session_id(index);
+                    (0..iterations).try_for_each(|_| send(&session_id))
+                })
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .try_for_each(|handle| handle.join().expect("Sender thread panicked"))
+    })?;
+    Ok(start.elapsed())
+}
+
+fn run_multi_threaded_bench(
+    threads: usize,
+    iterations: usize,
+) -> Result<MultiThreadedBenchResult, QuickFixError> {
+    let _lock = GLOBAL_LOCK.lock().expect("GLOBAL_LOCK poisoned");
+    let server_kind = FixSocketServerKind::MultiThreaded;
+
+    let app_sender = Application::try_new(&NullFixApplication)?;
+    let app_receiver = Application::try_new(&NullFixApplication)?;
+
+    let message_store_factory_sender = MemoryMessageStoreFactory::new();
+    let message_store_factory_receiver = MemoryMessageStoreFactory::new();
+
+    let settings_receiver = build_multi_settings(ServerType::Receiver, 0, threads)?;
+    let mut socket_receiver = Acceptor::try_new(
+        &settings_receiver,
+        &app_receiver,
+        &message_store_factory_receiver,
+        server_kind,
+    )?;
+    socket_receiver.start()?;
+
+    let settings_sender =
+        build_multi_settings(ServerType::Sender, listen_port(&socket_receiver), threads)?;
+    let mut socket_sender = Initiator::try_new(
+        &settings_sender,
+        &app_sender,
+        &message_store_factory_sender,
+        server_kind,
+    )?;
+    socket_sender.start()?;
+
+    // Every session must be logged on, otherwise messages are only stored.
+    for index in 0..threads {
+        let session_id = ServerType::Sender.indexed_session_id(index);
+        while !socket_sender.is_session_logged_on(&session_id)? {
+            thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    // Fresh clone has an empty cache, so every send goes through the session registry.
+    let lookup_send = |session_id: &SessionId| {
+        let mut msg = build_news("mt_lookup_send", &[])?;
+        send_to_target_by_ref_mut(&mut msg, &session_id.clone())
+    };
+    let cached_send = |session_id: &SessionId| {
+        let mut msg = build_news("mt_cached_send", &[])?;
+        send_to_target_by_ref_mut(&mut msg, session_id)
+    };
+
+    run_threads(threads, WARMUP_ITERS, lookup_send)?;
+    run_threads(threads, WARMUP_ITERS, cached_send)?;
+
+    thread::sleep(Duration::from_millis(100));
+    let lookup_then_send = run_threads(threads, iterations, lookup_send)?;
+
+    thread::sleep(Duration::from_millis(100));
+    let cached_then_send = run_threads(threads, iterations, cached_send)?;
+
+    thread::sleep(Duration::from_millis(100));
+
+    socket_receiver.stop()?;
+    socket_sender.stop()?;
+
+    Ok(MultiThreadedBenchResult {
+        threads,
+        iterations,
+        lookup_then_send,
+        cached_then_send,
+    })
+}
+
+#[test]
+#[ignore = "Manual benchmark; run with -- --ignored --nocapture"]
+fn bench_multi_threaded_send_to_target() -> Result<(), QuickFixError> {
+    let result = run_multi_threaded_bench(BENCH_THREADS, BENCH_ITERS)?;
+
+    let total_ops = (result.threads * result.iterations) as f64;
+    let lookup_per_op_ns = result.lookup_then_send.as_nanos() as f64 / total_ops;
+    let cached_per_op_ns = result.cached_then_send.as_nanos() as f64 / total_ops;
+    let cached_delta_pct = ((result.lookup_then_send.as_nanos() as f64
+        - result.cached_then_send.as_nanos() as f64)
+        / result.lookup_then_send.as_nanos() as f64)
+        * 100.0;
+
+    println!("=== multi-threaded send_to_target benchmark ===");
+    println!(
+        "threads: {} (one session each), iterations per thread: {}",
+        result.threads, result.iterations
+    );
+    println!(
+        "1) send_to_target_by_ref_mut with registry lookup: {:?} ({:.0} ns/op)",
+        result.lookup_then_send, lookup_per_op_ns
+    );
+    println!(
+        "2) send_to_target_by_ref_mut with cached session: {:?} ({:.0} ns/op)",
+        result.cached_then_send, cached_per_op_ns
+    );
+    println!("delta vs #1: {:.2}%", cached_delta_pct);
 
     Ok(())
 }
@@ -0,0 +1,158 @@
+#![cfg(feature = "serde")]
+
+use quickfix::*;
+use serde_json::json;
+
+fn build_order() -> Result<Message, QuickFixError> {
+    let mut msg = Message::new();
+    msg.with_header_mut(|h| -> Result<(), QuickFixError> {
+        h.set_field(8, "FIX.4.4")?;
+        h.set_field(35, "D")?;
+        h.set_field(49, "SENDER")?;
+        h.set_field(56, "TARGET")?;
+        Ok(())
+    })?;
+
+    msg.set_field(11, "ORDER-1")?;
+    msg.set_field(54, 1)?;
+    msg.set_field(55, "AAPL")?;
+
+    let mut party = Group::try_new(453, 448)?;
+    party.set_field(448, "PARTY-1")?;
+    party.set_field(447, "D")?;
+    party.set_field(452, 1)?;
+    msg.add_group(&party)?;
+
+    party.set_field(448, "PARTY-2")?;
+    party.set_field(452, 3)?;
+    msg.add_group(&party)?;
+
+    msg.with_trailer_mut(|t| t.set_field(93, 4))?;
+    Ok(msg)
+}
+
+fn round_trip(msg: &Message) -> Result<Message, serde_json::Error> {
+    serde_json::from_value(serde_json::to_value(msg)?)
+}
+
+#[test]
+fn test_serialize() -> Result<(), Box<dyn std::error::Error>> {
+    let msg = build_order()?;
+    let value = serde_json::to_value(&msg)?;
+
+    assert_eq!(value["header"]["8"], "FIX.4.4");
+    assert_eq!(value["header"]["35"], "D");
+    assert_eq!(value["header"]["49"], "SENDER");
+    assert!(value["header"]["9"].is_string());
+    assert_eq!(
+        value["body"],
+        json!({
+            "11": "ORDER-1",
+            "54": "1",
+            "55": "AAPL",
+            "453": [
+                [[448, "PARTY-1"], [447, "D"], [452, "1"]],
+                [[448, "PARTY-2"], [447, "D"], [452, "3"]],
+            ],
+        })
+    );
+    assert_eq!(value["trailer"]["93"], "4");
+    assert!(value["trailer"]["10"].is_string());
+    Ok(())
+}
+
+#[test]
+fn test_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+    let msg = build_order()?;
+    assert_eq!(round_trip(&msg)?.to_fix_string()?, msg.to_fix_string()?);
+
+    let mut empty = Message::new();
+    empty.with_header_mut(|h| h.set_field(8, "FIX.4.2"))?;
+    assert_eq!(round_trip(&empty)?.to_fix_string()?, empty.to_fix_string()?);
+    Ok(())
+}
+
+#[test]
+fn test_round_trip_group_orders() -> Result<(), Box<dyn std::error::Error>> {
+    let mut msg = Message::new();
+    msg.with_header_mut(|h| h.set_field(8, "FIX.4.4"))?;
+    msg.set_field(55, "AAPL")?;
+
+    // Not tag ordered, so it must be kept by entries.
+    let mut party = Group::try_with_orders(453, 448, &[448, 452, 447])?;
+    party.set_field(448, "PARTY-1")?;
+    party.set_field(447, "D")?;
+    party.set_field(452, 1)?;
+
+    let mut sub_party = Group::try_with_orders(802, 523, &[523, 803])?;
+    sub_party.set_field(523, "DESK-1")?;
+    sub_party.set_field(803, 4)?;
+    party.add_group(&sub_party)?;
+    sub_party.set_field(523, "DESK-2")?;
+    party.add_group(&sub_party)?;
+    msg.add_group(&party)?;
+
+    let value = serde_json::to_value(&msg)?;
+    assert_eq!(
+        value["body"]["453"],
+        json!([[
+            [448, "PARTY-1"],
+            [452, "1"],
+            [447, "D"],
+            [
+                802,
+                [[[523, "DESK-1"], [803, "4"]], [[523, "DESK-2"], [803, "4"]]]
+            ],
+        ]])
+    );
+
+    let output: Message = serde_json::from_value(value)?;
+    assert!(output
+        .to_fix_string()?
+        .contains("448=PARTY-1\u{1}452=1\u{1}447=D\u{1}802=2"));
+    assert_eq!(output.to_fix_string()?, msg.to_fix_string()?);
+    Ok(())
+}
+
+#[test]
+fn test_deserialize_empty_group() -> Result<(), Box<dyn std::error::Error>> {
+    let msg: Message = serde_json::from_value(json!({
+        "header": { "8": "FIX.4.4" },
+        "body": { "55": "AAPL", "453": [] },
+        "trailer": {},
+    }))?;
+    assert_eq!(msg.get_field(453).as_deref(), Some("0"));
+    Ok(())
+}
+
+#[test]
+fn test_deserialize_invalid() {
+    let err = serde_json::from_value::<Message>(json!({
+        "header": {},
+        "body": { "453": [[]] },
+        "trailer": {},
+    }))
+    .unwrap_err();
+    assert!(
+        err.to_string().contains("Empty entry in group 453"),
+        "{err}"
+    );
+
+    // Tags are numbers.
+    serde_json::from_value::<Message>(json!({
+        "header": {},
+        "body": { "ClOrdID": "ORDER-1" },
+        "trailer": {},
+    }))
+    .unwrap_err();
+
+    // Sections are all required, and no other one is accepted.
+    serde_json::from_value::<Message>(json!({ "body": {} })).unwrap_err();
+    serde_json::from_value::<Message>(json!({
+        "header": {},
+        "body": {},
+        "trailer": {},
+        "extra": {},
+    }))
+    .unwrap_err();
+}
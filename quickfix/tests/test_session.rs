@@ -1,20 +1,233 @@
-use std::{thread, time::Duration};
+use std::{
+    sync::{
+        atomic::{AtomicBool, AtomicI32, AtomicUsize, Ordering},
+        Arc, Condvar, Mutex,
+    },
+    thread,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
 
-use quickfix::*;
+use quickfix::{dictionary_item::*, *};
 use utils::*;
 
 mod utils;
 
+/// Logon / logout events seen by a `LogonRecorder`.
+#[derive(Debug, Default, Clone, Copy)]
+struct LogonCounts {
+    logon: usize,
+    logout: usize,
+    sent_logon: usize,
+}
+
+/// Record logon / logout count and the sequence number of the last outgoing Logon message.
+#[derive(Debug, Default)]
+struct LogonRecorder {
+    counts: Mutex<LogonCounts>,
+    changed: Condvar,
+    last_sent_logon_seq_num: AtomicI32,
+}
+
+impl LogonRecorder {
+    fn counts(&self) -> LogonCounts {
+        *self.counts.lock().unwrap()
+    }
+
+    fn logon_count(&self) -> usize {
+        self.counts().logon
+    }
+
+    fn logout_count(&self) -> usize {
+        self.counts().logout
+    }
+
+    fn sent_logon_count(&self) -> usize {
+        self.counts().sent_logon
+    }
+
+    fn last_sent_logon_seq_num(&self) -> i32 {
+        self.last_sent_logon_seq_num.load(Ordering::Relaxed)
+    }
+
+    /// Wait until `predicate` holds for recorded counts, return `false` on timeout.
+    fn wait_for<F: Fn(&LogonCounts) -> bool>(&self, timeout: Duration, predicate: F) -> bool {
+        let counts = self.counts.lock().unwrap();
+        let (counts, _) = self
+            .changed
+            .wait_timeout_while(counts, timeout, |counts| !predicate(counts))
+            .unwrap();
+        predicate(&counts)
+    }
+
+    fn update<F: FnOnce(&mut LogonCounts)>(&self, update: F) {
+        update(&mut self.counts.lock().unwrap());
+        self.changed.notify_all();
+    }
+}
+
+impl ApplicationCallback for LogonRecorder {
+    fn on_logon(&self, _session: &SessionId) {
+        self.update(|counts| counts.logon += 1);
+    }
+
+    fn on_logout(&self, _session: &SessionId) {
+        self.update(|counts| counts.logout += 1);
+    }
+
+    fn on_msg_to_admin(&self, msg: &mut Message, _session: &SessionId) {
+        let is_logon = msg.with_header(|header| {
+            if !header.is_field_equal(tags::MSG_TYPE, "A") {
+                return false;
+            }
+            if let Some(seq_num) = header
+                .get_field(tags::MSG_SEQ_NUM)
+                .and_then(|x| x.parse().ok())
+            {
+                self.last_sent_logon_seq_num
+                    .store(seq_num, Ordering::Relaxed);
+            }
+            true
+        });
+        if is_logon {
+            self.update(|counts| counts.sent_logon += 1);
+        }
+    }
+}
+
+/// Record a field of the last received message of a given type.
+#[derive(Debug)]
+struct FieldRecorder {
+    msg_type: &'static str,
+    tag: i32,
+    last_value: Mutex<Option<String>>,
+}
+
+impl FieldRecorder {
+    fn new(msg_type: &'static str, tag: i32) -> Self {
+        Self {
+            msg_type,
+            tag,
+            last_value: Mutex::new(None),
+        }
+    }
+
+    fn last_value(&self) -> Option<String> {
+        self.last_value.lock().unwrap().clone()
+    }
+
+    fn record(&self, msg: &Message) {
+        if msg.with_header(|header| header.is_field_equal(tags::MSG_TYPE, self.msg_type)) {
+            *self.last_value.lock().unwrap() = msg.get_field(self.tag);
+        }
+    }
+}
+
+impl ApplicationCallback for FieldRecorder {
+    fn on_msg_from_admin(
+        &self,
+        msg: Message,
+        _session: &SessionId,
+    ) -> Result<(), MsgFromAdminError> {
+        self.record(&msg);
+        Ok(())
+    }
+
+    fn on_msg_from_app(&self, msg: Message, _session: &SessionId) -> Result<(), MsgFromAppError> {
+        self.record(&msg);
+        Ok(())
+    }
+}
+
+/// Hold incoming ResendRequest(2) messages until released.
+#[derive(Debug, Default)]
+struct ResendRequestGate {
+    resend_requested: AtomicBool,
+    released: AtomicBool,
+}
+
+impl ResendRequestGate {
+    fn resend_requested(&self) -> bool {
+        self.resend_requested.load(Ordering::Relaxed)
+    }
+
+    fn release(&self) {
+        self.released.store(true, Ordering::Relaxed);
+    }
+}
+
+impl ApplicationCallback for ResendRequestGate {
+    fn on_msg_from_admin(
+        &self,
+        msg: Message,
+        _session: &SessionId,
+    ) -> Result<(), MsgFromAdminError> {
+        if msg.with_header(|header| header.is_field_equal(tags::MSG_TYPE, "2")) {
+            self.resend_requested.store(true, Ordering::Relaxed);
+            wait_until(Duration::from_secs(10), || {
+                self.released.load(Ordering::Relaxed)
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Count received application messages flagged as possible duplicates.
+#[derive(Debug, Default)]
+struct PossDupRecorder {
+    app_msg_count: AtomicUsize,
+    poss_dup_count: AtomicUsize,
+}
+
+impl PossDupRecorder {
+    fn app_msg_count(&self) -> usize {
+        self.app_msg_count.load(Ordering::Relaxed)
+    }
+
+    fn poss_dup_count(&self) -> usize {
+        self.poss_dup_count.load(Ordering::Relaxed)
+    }
+}
+
+impl ApplicationCallback for PossDupRecorder {
+    fn on_msg_from_app(&self, msg: Message, _session: &SessionId) -> Result<(), MsgFromAppError> {
+        self.app_msg_count.fetch_add(1, Ordering::Relaxed);
+        if msg.with_header(|header| header.is_field_equal(tags::POSS_DUP_FLAG, "Y")) {
+            self.poss_dup_count.fetch_add(1, Ordering::Relaxed);
+        }
+        Ok(())
+    }
+}
+
+/// Record resend begin / complete events in order.
+#[derive(Debug, Default)]
+struct ResendRecorder {
+    events: Mutex<Vec<String>>,
+}
+
+impl ResendRecorder {
+    fn events(&self) -> Vec<String> {
+        self.events.lock().unwrap().clone()
+    }
+}
+
+impl ApplicationCallback for ResendRecorder {
+    fn on_resend_begin(&self, _session: &SessionId, begin: u32, end: u32) {
+        self.events
+            .lock()
+            .unwrap()
+            .push(format!("begin {begin} {end}"));
+    }
+
+    fn on_resend_complete(&self, _session: &SessionId) {
+        self.events.lock().unwrap().push("complete".to_string());
+    }
+}
+
 #[test]
 fn test_session_login_logout() -> Result<(), QuickFixError> {
     let sender = FixRecorder::new(ServerType::Sender.session_id());
     let receiver = FixRecorder::new(ServerType::Receiver.session_id());
 
-    // Init settings with same server port.
-    let communication_port = find_available_port();
-    let settings_sender = build_settings(ServerType::Sender, communication_port)?;
-    let settings_receiver = build_settings(ServerType::Receiver, communication_port)?;
-
     let app_sender = Application::try_new(&sender)?;
     let app_receiver = Application::try_new(&receiver)?;
 
@@ -25,26 +238,29 @@ fn test_session_login_logout() -> Result<(), QuickFixError> {
     assert_eq!(sender.session_created(), 0);
     assert_eq!(receiver.session_created(), 0);
 
-    // Init socket acceptor / initiator.
-    let mut socket_sender = Initiator::try_new(
-        &settings_sender,
-        &app_sender,
-        &message_store_factory_sender,
-        FixSocketServerKind::default(),
-    )?;
+    // Init socket acceptor on a port picked by the OS, then the initiator connecting to it.
+    let settings_receiver = build_settings(ServerType::Receiver, 0)?;
     let mut socket_receiver = Acceptor::try_new(
         &settings_receiver,
         &app_receiver,
         &message_store_factory_receiver,
         FixSocketServerKind::default(),
     )?;
+    socket_receiver.start()?;
+
+    let settings_sender = build_settings(ServerType::Sender, listen_port(&socket_receiver))?;
+    let mut socket_sender = Initiator::try_new(
+        &settings_sender,
+        &app_sender,
+        &message_store_factory_sender,
+        FixSocketServerKind::default(),
+    )?;
 
     // Check session have been configured
     assert_eq!(sender.session_created(), 1);
     assert_eq!(receiver.session_created(), 1);
 
     // Start the app
-    socket_receiver.start()?;
     socket_sender.start()?;
 
     // Wait for login completion
@@ -87,33 +303,29 @@ fn test_session_set_next_sender_target_msg_seq_num() -> Result<(), QuickFixError
     let sender = FixRecorder::new(ServerType::Sender.session_id());
     let receiver = FixRecorder::new(ServerType::Receiver.session_id());
 
-    // Init settings with same server port.
-    let communication_port = find_available_port();
-    let settings_sender = build_settings(ServerType::Sender, communication_port)?;
-    let settings_receiver = build_settings(ServerType::Receiver, communication_port)?;
-
     let app_sender = Application::try_new(&sender)?;
     let app_receiver = Application::try_new(&receiver)?;
 
     let message_store_factory_sender = MemoryMessageStoreFactory::new();
     let message_store_factory_receiver = MemoryMessageStoreFactory::new();
 
-    // Init socket acceptor / initiator.
-    let mut socket_sender = Initiator::try_new(
-        &settings_sender,
-        &app_sender,
-        &message_store_factory_sender,
-        FixSocketServerKind::default(),
-    )?;
+    // Start socket acceptor, then initiator connecting to its port.
+    let settings_receiver = build_settings(ServerType::Receiver, 0)?;
     let mut socket_receiver = Acceptor::try_new(
         &settings_receiver,
         &app_receiver,
         &message_store_factory_receiver,
         FixSocketServerKind::default(),
     )?;
-
-    // Start the app
     socket_receiver.start()?;
+
+    let settings_sender = build_settings(ServerType::Sender, listen_port(&socket_receiver))?;
+    let mut socket_sender = Initiator::try_new(
+        &settings_sender,
+        &app_sender,
+        &message_store_factory_sender,
+        FixSocketServerKind::default(),
+    )?;
     socket_sender.start()?;
 
     // Wait for login completion
@@ -137,3 +349,3084 @@ fn test_session_set_next_sender_target_msg_seq_num() -> Result<(), QuickFixError
 
     Ok(())
 }
+
+#[test]
+fn test_session_reset() -> Result<(), QuickFixError> {
+    let sender = LogonRecorder::default();
+    let receiver = LogonRecorder::default();
+
+    // Reset flags make both sides agree on sequence numbers after the session reset.
+    let reset_defaults: [&dyn DictionaryItem; 3] = [
+        &ReconnectInterval(1),
+        &ResetOnLogout(true),
+        &ResetOnDisconnect(true),
+    ];
+
+    let app_sender = Application::try_new(&sender)?;
+    let app_receiver = Application::try_new(&receiver)?;
+
+    let message_store_factory_sender = MemoryMessageStoreFactory::new();
+    let message_store_factory_receiver = MemoryMessageStoreFactory::new();
+
+    // Start socket acceptor, then initiator connecting to its port.
+    let settings_receiver = build_settings_with_defaults(ServerType::Receiver, 0, &reset_defaults)?;
+    let mut socket_receiver = Acceptor::try_new(
+        &settings_receiver,
+        &app_receiver,
+        &message_store_factory_receiver,
+        FixSocketServerKind::default(),
+    )?;
+    socket_receiver.start()?;
+
+    let settings_sender = build_settings_with_defaults(
+        ServerType::Sender,
+        listen_port(&socket_receiver),
+        &reset_defaults,
+    )?;
+    let mut socket_sender = Initiator::try_new(
+        &settings_sender,
+        &app_sender,
+        &message_store_factory_sender,
+        FixSocketServerKind::default(),
+    )?;
+    socket_sender.start()?;
+
+    // Wait for login completion
+    while sender.logon_count() < 1 || receiver.logon_count() < 1 {
+        thread::sleep(Duration::from_millis(50));
+    }
+    assert_eq!(sender.last_sent_logon_seq_num(), 1);
+
+    // Exchange a message so sequence numbers move forward.
+    let mut session = unsafe { Session::lookup(&ServerType::Sender.session_id()) }.unwrap();
+    assert!(session.send(build_news("Hello", &[])?)?);
+    thread::sleep(Duration::from_millis(50));
+    assert!(session.get_expected_sender_num() > 2);
+
+    // Reset session: logout + disconnect + sequence number reset.
+    session.reset()?;
+
+    // Wait for the initiator to log on again.
+    while sender.logon_count() < 2 || receiver.logon_count() < 2 {
+        thread::sleep(Duration::from_millis(50));
+    }
+    assert_eq!(sender.last_sent_logon_seq_num(), 1);
+    assert!(session.is_logged_on()?);
+
+    // Stop everything
+    socket_sender.stop()?;
+    socket_receiver.stop()?;
+
+    Ok(())
+}
+
+#[test]
+fn test_session_logout_with_reason() -> Result<(), QuickFixError> {
+    let receiver = FieldRecorder::new("5", tags::TEXT);
+
+    with_session_pair(&NullFixApplication, &receiver, &[], |_, _| {
+        let mut session = unsafe { Session::lookup(&ServerType::Sender.session_id()) }?;
+        session.logout_with_reason("scheduled maintenance")?;
+
+        wait_until(Duration::from_secs(5), || receiver.last_value().is_some());
+        assert_eq!(
+            receiver.last_value().as_deref(),
+            Some("scheduled maintenance")
+        );
+        Ok(())
+    })
+}
+
+#[test]
+fn test_handler_stop_with_reason() -> Result<(), QuickFixError> {
+    let receiver = FieldRecorder::new("5", tags::TEXT);
+
+    with_session_pair(&NullFixApplication, &receiver, &[], |socket_sender, _| {
+        socket_sender.stop_with_reason("end of day")?;
+        assert!(socket_sender.is_stopped()?);
+
+        wait_until(Duration::from_secs(5), || receiver.last_value().is_some());
+        assert_eq!(receiver.last_value().as_deref(), Some("end of day"));
+        Ok(())
+    })
+}
+
+#[test]
+fn test_session_disconnect() -> Result<(), QuickFixError> {
+    let sender = LogonRecorder::default();
+
+    with_session_pair(
+        &sender,
+        &NullFixApplication,
+        &[&ReconnectInterval(1)],
+        |_, _| {
+            let sender_session = unsafe { Session::lookup(&ServerType::Sender.session_id()) }?;
+            let receiver_session = unsafe { Session::lookup(&ServerType::Receiver.session_id()) }?;
+
+            // Move sequence numbers forward.
+            assert!(sender_session.send_by_ref(&mut build_news("Hello", &[])?)?);
+            wait_until(Duration::from_secs(5), || {
+                receiver_session.get_expected_target_num()
+                    == sender_session.get_expected_sender_num()
+            });
+            let next_sender_seq_num = sender_session.get_expected_sender_num();
+            assert!(next_sender_seq_num > 2);
+
+            // Drop connection from the acceptor side: no Logout is exchanged.
+            receiver_session.disconnect()?;
+            wait_until(Duration::from_secs(5), || sender.logout_count() == 1);
+
+            // Initiator reconnects and keeps its sequence numbers.
+            wait_until(Duration::from_secs(10), || sender.logon_count() == 2);
+            assert_eq!(sender.last_sent_logon_seq_num(), next_sender_seq_num);
+            wait_until(Duration::from_secs(5), || {
+                receiver_session.get_expected_target_num()
+                    == sender_session.get_expected_sender_num()
+            });
+            Ok(())
+        },
+    )
+}
+
+#[test]
+fn test_handler_is_session_logged_on() -> Result<(), QuickFixError> {
+    let settings_receiver = build_settings(ServerType::Receiver, 0)?;
+
+    let app_sender = Application::try_new(&NullFixApplication)?;
+    let app_receiver = Application::try_new(&NullFixApplication)?;
+
+    let message_store_factory_sender = MemoryMessageStoreFactory::new();
+    let message_store_factory_receiver = MemoryMessageStoreFactory::new();
+
+    let mut socket_receiver = Acceptor::try_new(
+        &settings_receiver,
+        &app_receiver,
+        &message_store_factory_receiver,
+        FixSocketServerKind::default(),
+    )?;
+    socket_receiver.start()?;
+    let communication_port = listen_port(&socket_receiver);
+
+    // Add a second session on the initiator, unknown from the acceptor, so its logon is refused.
+    let unreachable_session_id = SessionId::try_new("FIX.4.4", "SENDER", "UNREACHABLE", "")?;
+    let mut settings_sender = build_settings(ServerType::Sender, communication_port)?;
+    settings_sender.set(
+        Some(&unreachable_session_id),
+        Dictionary::try_from_items(&[
+            &StartTime("00:00:00"),
+            &EndTime("23:59:59"),
+            &HeartBtInt(20),
+            &DataDictionary("../quickfix-ffi/libquickfix/spec/FIX44.xml"),
+            &SocketConnectPort(communication_port),
+            &SocketConnectHost("127.0.0.1"),
+        ])?,
+    )?;
+
+    let mut socket_sender = Initiator::try_new(
+        &settings_sender,
+        &app_sender,
+        &message_store_factory_sender,
+        FixSocketServerKind::default(),
+    )?;
+    socket_sender.start()?;
+
+    wait_until(Duration::from_secs(5), || {
+        socket_sender
+            .is_session_logged_on(&ServerType::Sender.session_id())
+            .unwrap()
+    });
+    assert!(!socket_sender.is_session_logged_on(&unreachable_session_id)?);
+
+    // Aggregate status is true as soon as one session is logged on.
+    assert!(socket_sender.is_logged_on()?);
+
+    // Unknown session are reported as errors.
+    let unknown_session_id = SessionId::try_new("FIX.4.4", "SENDER", "UNKNOWN", "")?;
+    assert!(socket_sender
+        .is_session_logged_on(&unknown_session_id)
+        .is_err());
+
+    socket_sender.stop()?;
+    socket_receiver.stop()?;
+
+    Ok(())
+}
+
+#[test]
+fn test_session_send_test_request() -> Result<(), QuickFixError> {
+    let sender = FieldRecorder::new("0", tags::TEST_REQ_ID);
+
+    with_session_pair(&sender, &NullFixApplication, &[], |_, _| {
+        let session = unsafe { Session::lookup(&ServerType::Sender.session_id()) }?;
+        assert!(session.send_test_request("PROBE-1")?);
+
+        wait_until(Duration::from_secs(5), || sender.last_value().is_some());
+        assert_eq!(sender.last_value().as_deref(), Some("PROBE-1"));
+        Ok(())
+    })
+}
+
+#[test]
+fn test_handler_session_ids() -> Result<(), QuickFixError> {
+    let mut settings = build_settings(ServerType::Receiver, 0)?;
+
+    let other_session_id = SessionId::try_new("FIX.4.4", "RECEIVER", "OTHER", "")?;
+    settings.set(
+        Some(&other_session_id),
+        Dictionary::try_from_items(&[
+            &StartTime("00:00:00"),
+            &EndTime("23:59:59"),
+            &HeartBtInt(20),
+            &DataDictionary("../quickfix-ffi/libquickfix/spec/FIX44.xml"),
+            &SocketAcceptPort(0),
+        ])?,
+    )?;
+
+    let app = Application::try_new(&NullFixApplication)?;
+    let message_store_factory = MemoryMessageStoreFactory::new();
+    let mut socket_receiver = Acceptor::try_new(
+        &settings,
+        &app,
+        &message_store_factory,
+        FixSocketServerKind::default(),
+    )?;
+    socket_receiver.start()?;
+
+    let mut session_ids: Vec<_> = socket_receiver
+        .session_ids()?
+        .iter()
+        .map(SessionId::to_repr)
+        .collect();
+    session_ids.sort();
+    assert_eq!(
+        session_ids,
+        vec![
+            other_session_id.to_repr(),
+            ServerType::Receiver.session_id().to_repr()
+        ]
+    );
+
+    // Returned IDs can be used to address sessions.
+    for session_id in socket_receiver.session_ids()? {
+        assert!(!socket_receiver.is_session_logged_on(&session_id)?);
+        send_to_target(build_news("Hello", &[])?, &session_id)?;
+    }
+
+    socket_receiver.stop()?;
+
+    Ok(())
+}
+
+#[test]
+fn test_session_status() -> Result<(), QuickFixError> {
+    with_session_pair(
+        &NullFixApplication,
+        &NullFixApplication,
+        &[],
+        |socket_sender, _| {
+            let session = unsafe { Session::lookup(&ServerType::Sender.session_id()) }?;
+
+            let status = session.status()?;
+            assert!(status.logged_on);
+            assert!(status.is_session_time);
+            assert_eq!(status.heartbeat_interval, Duration::from_secs(20));
+            assert!(status.since_last_received < Duration::from_secs(20));
+
+            // Sequence numbers advance with sent messages.
+            send_to_target(build_news("Hello", &[])?, &ServerType::Sender.session_id())?;
+            assert_eq!(
+                session.status()?.expected_sender_num,
+                status.expected_sender_num + 1
+            );
+            assert_eq!(
+                session.status()?.expected_target_num,
+                status.expected_target_num
+            );
+
+            // Logon state flips once handler is stopped.
+            socket_sender.stop()?;
+            assert!(!session.status()?.logged_on);
+            Ok(())
+        },
+    )
+}
+
+#[test]
+fn test_send_to_target_with_comp_ids() -> Result<(), QuickFixError> {
+    let receiver = FixRecorder::new(ServerType::Receiver.session_id());
+
+    with_session_pair(&NullFixApplication, &receiver, &[], |_, _| {
+        send_to_target_with_comp_ids(
+            build_news("Hello", &[])?,
+            "FIX.4.4",
+            "SENDER",
+            "RECEIVER",
+            None,
+        )?;
+        wait_until(Duration::from_secs(5), || {
+            receiver.user_msg_count().recv == 1
+        });
+
+        // No session match those comp IDs.
+        assert!(matches!(
+            send_to_target_with_comp_ids(
+                build_news("Hello", &[])?,
+                "FIX.4.4",
+                "SENDER",
+                "UNKNOWN",
+                None,
+            ),
+            Err(QuickFixError::SessionNotFound(_))
+        ));
+        Ok(())
+    })
+}
+
+#[test]
+fn test_send_all_to_target() -> Result<(), QuickFixError> {
+    let receiver = FixRecorder::new(ServerType::Receiver.session_id());
+
+    with_session_pair(&NullFixApplication, &receiver, &[], |_, _| {
+        let mut msgs = vec![
+            build_news("Hello 1", &[])?,
+            build_news("Hello 2", &[])?,
+            build_news("Hello 3", &[])?,
+        ];
+        assert_eq!(
+            send_all_to_target(&mut msgs, &ServerType::Sender.session_id())?,
+            3
+        );
+        wait_until(Duration::from_secs(5), || {
+            receiver.user_msg_count().recv == 3
+        });
+
+        // Empty batch is a no-op.
+        assert_eq!(
+            send_all_to_target(&mut [], &ServerType::Sender.session_id())?,
+            0
+        );
+
+        // Unknown session.
+        let unknown_session_id = SessionId::try_new("FIX.4.4", "SENDER", "UNKNOWN", "")?;
+        assert!(matches!(
+            send_all_to_target(&mut msgs, &unknown_session_id),
+            Err(QuickFixError::SessionNotFound(_))
+        ));
+        Ok(())
+    })
+}
+
+#[test]
+fn test_send_all_to_target_counts_queued_and_refused() -> Result<(), QuickFixError> {
+    let sender = PossDupVeto::default();
+
+    with_session_pair(&sender, &NullFixApplication, &[], |initiator, _| {
+        let session_id = ServerType::Sender.session_id();
+
+        // Batch stops at the message refused by `on_msg_to_app`.
+        let mut refused = build_news("Refused", &[])?;
+        refused.with_header_mut(|header| header.set_field(tags::POSS_DUP_FLAG, "Y"))?;
+        let mut msgs = vec![
+            build_news("Sent", &[])?,
+            refused,
+            build_news("Skipped", &[])?,
+        ];
+        assert_eq!(send_all_to_target(&mut msgs, &session_id)?, 1);
+        assert_eq!(sender.vetoed_count.load(Ordering::Relaxed), 1);
+
+        // Messages sent while logged off are queued for next logon, and counted.
+        initiator.session(session_id.clone())?.logout()?;
+        wait_until(Duration::from_secs(5), || {
+            !initiator.is_session_logged_on(&session_id).unwrap_or(true)
+        });
+        let mut msgs = vec![build_news("Queued 1", &[])?, build_news("Queued 2", &[])?];
+        assert_eq!(send_all_to_target(&mut msgs, &session_id)?, 2);
+
+        initiator.session(session_id)?.logon()?;
+        Ok(())
+    })
+}
+
+#[test]
+fn test_session_refresh() -> Result<(), QuickFixError> {
+    let settings_receiver = build_settings(ServerType::Receiver, 0)?;
+
+    let app_sender = Application::try_new(&NullFixApplication)?;
+    let app_receiver = Application::try_new(&NullFixApplication)?;
+
+    let message_store_factory_receiver = MemoryMessageStoreFactory::new();
+    let mut socket_receiver = Acceptor::try_new(
+        &settings_receiver,
+        &app_receiver,
+        &message_store_factory_receiver,
+        FixSocketServerKind::default(),
+    )?;
+    socket_receiver.start()?;
+
+    // Port is unique while acceptor is running, use it to name the store directory too.
+    let communication_port = listen_port(&socket_receiver);
+    let store_path = std::env::temp_dir().join(format!("quickfix-refresh-{communication_port}"));
+    let _ = std::fs::remove_dir_all(&store_path);
+
+    let settings_sender = build_settings_with_defaults(
+        ServerType::Sender,
+        communication_port,
+        &[&FileStorePath(store_path.to_str().unwrap())],
+    )?;
+    let message_store_factory_sender = FileMessageStoreFactory::try_new(&settings_sender)?;
+    let mut socket_sender = Initiator::try_new(
+        &settings_sender,
+        &app_sender,
+        &message_store_factory_sender,
+        FixSocketServerKind::default(),
+    )?;
+    socket_sender.start()?;
+    while !socket_sender.is_logged_on()? || !socket_receiver.is_logged_on()? {
+        thread::sleep(Duration::from_millis(50));
+    }
+
+    // Advance sequence numbers, then stop as a failed over node would.
+    let session = unsafe { Session::lookup(&ServerType::Sender.session_id()) }?;
+    send_to_target(build_news("Hello", &[])?, &ServerType::Sender.session_id())?;
+    socket_sender.stop()?;
+    assert!(session.get_expected_sender_num() > 2);
+
+    // Rewrite sequence numbers behind the session back.
+    std::fs::write(
+        store_path.join("FIX.4.4-SENDER-RECEIVER.seqnums"),
+        "0000000042 : 0000000017",
+    )
+    .unwrap();
+
+    session.refresh()?;
+    assert_eq!(session.get_expected_sender_num(), 42);
+    assert_eq!(session.get_expected_target_num(), 17);
+
+    socket_receiver.stop()?;
+    let _ = std::fs::remove_dir_all(&store_path);
+
+    Ok(())
+}
+
+#[test]
+fn test_session_has_pending_resend() -> Result<(), QuickFixError> {
+    let sender = ResendRequestGate::default();
+
+    with_session_pair(&sender, &NullFixApplication, &[], |_, _| {
+        let mut sender_session = unsafe { Session::lookup(&ServerType::Sender.session_id()) }?;
+        let receiver_session = unsafe { Session::lookup(&ServerType::Receiver.session_id()) }?;
+        assert!(!receiver_session.has_pending_resend()?);
+
+        // Skip some sequence numbers so receiver detects a gap.
+        sender_session.set_next_sender_msg_seq_num(sender_session.get_expected_sender_num() + 5)?;
+        send_to_target(build_news("Hello", &[])?, &ServerType::Sender.session_id())?;
+
+        // Gap stays open while sender holds the ResendRequest.
+        wait_until(Duration::from_secs(5), || sender.resend_requested());
+        wait_until(Duration::from_secs(5), || {
+            receiver_session.has_pending_resend().unwrap()
+        });
+
+        // Let sender fill the gap.
+        sender.release();
+        wait_until(Duration::from_secs(5), || {
+            !receiver_session.has_pending_resend().unwrap()
+        });
+        assert_eq!(
+            receiver_session.get_expected_target_num(),
+            sender_session.get_expected_sender_num()
+        );
+        Ok(())
+    })
+}
+
+#[test]
+fn test_session_outbound_queue_len() -> Result<(), QuickFixError> {
+    with_session_pair(
+        &NullFixApplication,
+        &NullFixApplication,
+        &[&ReconnectInterval(1)],
+        |_, _| {
+            let mut session = unsafe { Session::lookup(&ServerType::Sender.session_id()) }?;
+            send_to_target(build_news("Hello", &[])?, &ServerType::Sender.session_id())?;
+            assert_eq!(session.outbound_queue_len()?, 0);
+
+            // Messages sent while logged off are queued.
+            session.logout()?;
+            wait_until(Duration::from_secs(5), || !session.is_logged_on().unwrap());
+            for _ in 0..3 {
+                send_to_target(build_news("Queued", &[])?, &ServerType::Sender.session_id())?;
+            }
+            assert_eq!(session.outbound_queue_len()?, 3);
+
+            // Queue is flushed once counterparty gets them resent, after next logon.
+            session.logon()?;
+            wait_until(Duration::from_secs(10), || {
+                session.outbound_queue_len().unwrap() == 0
+            });
+            assert!(session.is_logged_on()?);
+
+            // Messages written right away are never counted.
+            send_to_target(build_news("Live", &[])?, &ServerType::Sender.session_id())?;
+            assert_eq!(session.outbound_queue_len()?, 0);
+            Ok(())
+        },
+    )
+}
+
+#[test]
+fn test_session_process() -> Result<(), QuickFixError> {
+    let receiver = FieldRecorder::new("D", tags::CL_ORD_ID);
+
+    with_session_pair(
+        &NullFixApplication,
+        &receiver,
+        &[&CheckLatency(false)],
+        |_, _| {
+            let session = unsafe { Session::lookup(&ServerType::Receiver.session_id()) }?;
+
+            // Build a NewOrderSingle as if it was sent by the counterparty.
+            let mut order = Message::new();
+            order.with_header_mut(|header| {
+                header.set_field(tags::BEGIN_STRING, "FIX.4.4")?;
+                header.set_field(tags::MSG_TYPE, "D")?;
+                header.set_field(tags::MSG_SEQ_NUM, session.get_expected_target_num())?;
+                header.set_field(tags::SENDER_COMP_ID, "SENDER")?;
+                header.set_field(tags::SENDING_TIME, "20240101-00:00:00")?;
+                header.set_field(tags::TARGET_COMP_ID, "RECEIVER")
+            })?;
+            order.set_field(tags::CL_ORD_ID, "ORDER-1")?;
+            order.set_field(tags::SYMBOL, "EUR/USD")?;
+            order.set_field(tags::SIDE, "1")?;
+            order.set_field(tags::TRANSACT_TIME, "20240101-00:00:00")?;
+            order.set_field(tags::ORDER_QTY, 100)?;
+            order.set_field(tags::ORD_TYPE, "1")?;
+
+            session.process(&order.to_fix_string()?)?;
+            assert_eq!(receiver.last_value().as_deref(), Some("ORDER-1"));
+            Ok(())
+        },
+    )
+}
+
+#[test]
+fn test_session_heartbeat_interval() -> Result<(), QuickFixError> {
+    let receiver = FieldRecorder::new("A", tags::HEART_BT_INT);
+
+    with_session_pair(
+        &NullFixApplication,
+        &receiver,
+        &[&ReconnectInterval(1)],
+        |_, _| {
+            let session = unsafe { Session::lookup(&ServerType::Sender.session_id()) }?;
+            assert_eq!(session.heartbeat_interval()?, 20);
+            assert_eq!(receiver.last_value().as_deref(), Some("20"));
+
+            // Change is visible locally right away ...
+            session.set_heartbeat_interval(5)?;
+            assert_eq!(session.heartbeat_interval()?, 5);
+
+            // ... and sent to counterparty on next logon.
+            session.disconnect()?;
+            wait_until(Duration::from_secs(10), || {
+                receiver.last_value().as_deref() == Some("5")
+            });
+            Ok(())
+        },
+    )
+}
+
+#[test]
+fn test_session_is_session_time() -> Result<(), QuickFixError> {
+    // One minute window, 12 hours away from now (UTC).
+    let seconds_of_day = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+        % 86_400;
+    let format_time = |secs: u64| {
+        let secs = secs % 86_400;
+        format!("{:02}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60)
+    };
+    let start_time = format_time(seconds_of_day + 12 * 3600);
+    let end_time = format_time(seconds_of_day + 12 * 3600 + 60);
+
+    let closed_session_id = SessionId::try_new("FIX.4.4", "RECEIVER", "CLOSED", "")?;
+    let mut settings = build_settings(ServerType::Receiver, 0)?;
+    settings.set(
+        Some(&closed_session_id),
+        Dictionary::try_from_items(&[
+            &StartTime(&start_time),
+            &EndTime(&end_time),
+            &HeartBtInt(20),
+            &DataDictionary("../quickfix-ffi/libquickfix/spec/FIX44.xml"),
+            &SocketAcceptPort(0),
+        ])?,
+    )?;
+
+    let app = Application::try_new(&NullFixApplication)?;
+    let message_store_factory = MemoryMessageStoreFactory::new();
+    let mut socket_receiver = Acceptor::try_new(
+        &settings,
+        &app,
+        &message_store_factory,
+        FixSocketServerKind::default(),
+    )?;
+    socket_receiver.start()?;
+
+    assert!(!socket_receiver
+        .session(closed_session_id)?
+        .is_session_time()?);
+    assert!(socket_receiver
+        .session(ServerType::Receiver.session_id())?
+        .is_session_time()?);
+
+    socket_receiver.stop()?;
+
+    Ok(())
+}
+
+#[test]
+fn test_session_request_resend() -> Result<(), QuickFixError> {
+    let receiver = PossDupRecorder::default();
+
+    with_session_pair(&NullFixApplication, &receiver, &[], |_, _| {
+        let mut receiver_session = unsafe { Session::lookup(&ServerType::Receiver.session_id()) }?;
+        let first_seq_num = receiver_session.get_expected_target_num();
+
+        for index in 0..3 {
+            send_to_target(
+                build_news(&format!("Hello {index}"), &[])?,
+                &ServerType::Sender.session_id(),
+            )?;
+        }
+        wait_until(Duration::from_secs(5), || receiver.app_msg_count() == 3);
+        assert_eq!(receiver.poss_dup_count(), 0);
+
+        // Forget about received messages and ask for them again.
+        receiver_session.set_next_target_msg_seq_num(first_seq_num)?;
+        receiver_session.request_resend(first_seq_num as u32, 0)?;
+
+        wait_until(Duration::from_secs(5), || receiver.poss_dup_count() == 3);
+        assert_eq!(receiver.app_msg_count(), 6);
+        Ok(())
+    })
+}
+
+#[test]
+fn test_session_send_gap_fill() -> Result<(), QuickFixError> {
+    let receiver = FixRecorder::new(ServerType::Receiver.session_id());
+
+    with_session_pair(&NullFixApplication, &receiver, &[], |_, _| {
+        let sender_session = unsafe { Session::lookup(&ServerType::Sender.session_id()) }?;
+        let receiver_session = unsafe { Session::lookup(&ServerType::Receiver.session_id()) }?;
+
+        let new_seq_no = sender_session.get_expected_sender_num() + 10;
+        sender_session.send_gap_fill(new_seq_no as u32)?;
+        assert_eq!(sender_session.get_expected_sender_num(), new_seq_no);
+        wait_until(Duration::from_secs(5), || {
+            receiver_session.get_expected_target_num() == new_seq_no
+        });
+
+        // Session keeps working after the gap.
+        send_to_target(build_news("Hello", &[])?, &ServerType::Sender.session_id())?;
+        wait_until(Duration::from_secs(5), || {
+            receiver.user_msg_count().recv == 1
+        });
+        assert!(!receiver_session.has_pending_resend()?);
+
+        // Cannot move backward.
+        assert!(matches!(
+            sender_session
+                .send_gap_fill(1)
+                .map_err(QuickFixError::into_without_session),
+            Err(QuickFixError::InvalidArgument(_))
+        ));
+        let err = sender_session.send_gap_fill(1).unwrap_err();
+        assert_eq!(
+            err.session_id(),
+            Some(ServerType::Sender.session_id().to_repr().as_str())
+        );
+        Ok(())
+    })
+}
+
+#[test]
+fn test_try_send_to_target() -> Result<(), QuickFixError> {
+    let receiver = PossDupRecorder::default();
+
+    with_session_pair(
+        &NullFixApplication,
+        &receiver,
+        &[&ReconnectInterval(1)],
+        |_, _| {
+            let mut session = unsafe { Session::lookup(&ServerType::Sender.session_id()) }?;
+            try_send_to_target(build_news("Live", &[])?, &ServerType::Sender.session_id())?;
+            wait_until(Duration::from_secs(5), || receiver.app_msg_count() == 1);
+
+            session.logout()?;
+            wait_until(Duration::from_secs(5), || !session.is_logged_on().unwrap());
+
+            // Message is dropped while logged off ...
+            assert!(matches!(
+                try_send_to_target(
+                    build_news("Dropped", &[])?,
+                    &ServerType::Sender.session_id()
+                )
+                .map_err(QuickFixError::into_without_session),
+                Err(QuickFixError::SessionNotLoggedOn(_))
+            ));
+            assert_eq!(session.outbound_queue_len()?, 0);
+
+            // ... whereas regular send queue it for next logon.
+            send_to_target(build_news("Queued", &[])?, &ServerType::Sender.session_id())?;
+            assert_eq!(session.outbound_queue_len()?, 1);
+
+            session.logon()?;
+            wait_until(Duration::from_secs(10), || receiver.app_msg_count() == 2);
+            assert_eq!(receiver.poss_dup_count(), 1);
+            Ok(())
+        },
+    )
+}
+
+#[test]
+fn test_send_to_unknown_session() -> Result<(), QuickFixError> {
+    let unknown_session_id = SessionId::try_new("FIX.4.4", "SENDER", "UNKNOWN", "")?;
+    let assert_not_found = |result: Result<(), QuickFixError>| match result {
+        Err(QuickFixError::SessionNotFound(msg)) => {
+            assert!(msg.contains("FIX.4.4:SENDER->UNKNOWN"), "{msg}")
+        }
+        other => panic!("Unexpected result: {other:?}"),
+    };
+
+    assert_not_found(send_to_target(
+        build_news("Lost", &[])?,
+        &unknown_session_id,
+    ));
+    assert_not_found(send_to_target_by_ref_mut(
+        &mut build_news("Lost", &[])?,
+        &unknown_session_id,
+    ));
+    assert_not_found(try_send_to_target(
+        build_news("Lost", &[])?,
+        &unknown_session_id,
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_resend_callbacks() -> Result<(), QuickFixError> {
+    let receiver = ResendRecorder::default();
+
+    with_session_pair(
+        &NullFixApplication,
+        &receiver,
+        &[&ReconnectInterval(1)],
+        |_, _| {
+            let mut sender_session = unsafe { Session::lookup(&ServerType::Sender.session_id()) }?;
+            let mut receiver_session =
+                unsafe { Session::lookup(&ServerType::Receiver.session_id()) }?;
+
+            sender_session.logout()?;
+            wait_until(Duration::from_secs(5), || {
+                !sender_session.is_logged_on().unwrap() && !receiver_session.is_logged_on().unwrap()
+            });
+            let gap_begin = receiver_session.get_expected_target_num();
+
+            // Messages sent while disconnected create a gap on receiver side.
+            for _ in 0..2 {
+                send_to_target(build_news("Queued", &[])?, &ServerType::Sender.session_id())?;
+            }
+            assert!(receiver.events().is_empty());
+
+            sender_session.logon()?;
+            wait_until(Duration::from_secs(10), || receiver.events().len() == 2);
+            assert_eq!(
+                receiver.events(),
+                vec![format!("begin {gap_begin} 0"), "complete".to_string()]
+            );
+            assert!(!receiver_session.has_pending_resend()?);
+            Ok(())
+        },
+    )
+}
+
+#[test]
+fn test_resolved_session() -> Result<(), QuickFixError> {
+    let receiver = FixRecorder::new(ServerType::Receiver.session_id());
+    let mut resolved = None;
+
+    with_session_pair(&NullFixApplication, &receiver, &[], |_, _| {
+        let session = ServerType::Sender.session_id().resolve()?;
+        for _ in 0..3 {
+            session.send(&mut build_news("Hello", &[])?)?;
+        }
+        wait_until(Duration::from_secs(5), || {
+            receiver.user_msg_count().recv == 3
+        });
+
+        // Stopping handlers of other sessions does not invalidate it.
+        let other = testing::SessionPair::start(
+            testing::FixVersion::Fix42,
+            FixSocketServerKind::SingleThreaded,
+        )?;
+        other.shutdown(Duration::from_secs(5))?;
+        session.send(&mut build_news("Still valid", &[])?)?;
+        wait_until(Duration::from_secs(5), || {
+            receiver.user_msg_count().recv == 4
+        });
+        resolved = Some(session);
+
+        // Unknown session cannot be resolved.
+        assert!(matches!(
+            SessionId::try_new("FIX.4.4", "FOO", "BAR", "")?.resolve(),
+            Err(QuickFixError::SessionNotFound(_))
+        ));
+        Ok(())
+    })?;
+
+    // Connection handlers are now stopped.
+    let resolved = resolved.expect("Session has not been resolved");
+    assert!(matches!(
+        resolved.send(&mut build_news("Too late", &[])?),
+        Err(QuickFixError::SessionNotFound(_))
+    ));
+
+    Ok(())
+}
+
+/// Record headline and sequence number of every received application message.
+#[derive(Debug, Default)]
+struct NewsRecorder {
+    received: Mutex<Vec<(String, Option<String>)>>,
+}
+
+impl ApplicationCallback for NewsRecorder {
+    fn on_msg_from_app(&self, msg: Message, _session: &SessionId) -> Result<(), MsgFromAppError> {
+        let headline = msg.get_field(tags::HEADLINE).unwrap_or_default();
+        let seq_num = msg.with_header(|h| h.get_field(tags::MSG_SEQ_NUM));
+        self.received.lock().unwrap().push((headline, seq_num));
+        Ok(())
+    }
+}
+
+#[test]
+fn test_concurrent_send_to_same_session() -> Result<(), QuickFixError> {
+    const THREADS: usize = 4;
+    const MSG_PER_THREAD: usize = 100;
+
+    let receiver = NewsRecorder::default();
+    let session_id = ServerType::Sender.session_id();
+
+    with_session_pair(&NullFixApplication, &receiver, &[], |_, _| {
+        thread::scope(|scope| {
+            let handles: Vec<_> = (0..THREADS)
+                .map(|thread_index| {
+                    let session_id = &session_id;
+                    scope.spawn(move || {
+                        (0..MSG_PER_THREAD).try_for_each(|msg_index| {
+                            let headline = format!("{thread_index}-{msg_index}");
+                            let lines = ["first", headline.as_str(), "last"];
+                            send_to_target(build_news(&headline, &lines)?, session_id)
+                        })
+                    })
+                })
+                .collect();
+            handles
+                .into_iter()
+                .try_for_each(|handle| handle.join().unwrap())
+        })?;
+
+        wait_until(Duration::from_secs(10), || {
+            receiver.received.lock().unwrap().len() == THREADS * MSG_PER_THREAD
+        });
+        Ok(())
+    })?;
+
+    let received = receiver.received.into_inner().unwrap();
+
+    // Every message has been validated by receiver data dictionary, and each one has its own
+    // sequence number.
+    let mut seq_nums: Vec<i32> = received
+        .iter()
+        .map(|(_, seq_num)| seq_num.as_deref().unwrap().parse().unwrap())
+        .collect();
+    seq_nums.sort_unstable();
+    seq_nums.dedup();
+    assert_eq!(seq_nums.len(), THREADS * MSG_PER_THREAD);
+
+    // Messages of a thread are received in the order it sent them.
+    for thread_index in 0..THREADS {
+        let headlines: Vec<_> = received
+            .iter()
+            .map(|(headline, _)| headline)
+            .filter(|headline| headline.starts_with(&format!("{thread_index}-")))
+            .cloned()
+            .collect();
+        let expected: Vec<_> = (0..MSG_PER_THREAD)
+            .map(|msg_index| format!("{thread_index}-{msg_index}"))
+            .collect();
+        assert_eq!(headlines, expected);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_send_to_target_after_restart() -> Result<(), QuickFixError> {
+    // Same ID is used across both pairs, so session cached by first send becomes stale.
+    let session_id = ServerType::Sender.session_id();
+
+    for _ in 0..2 {
+        let receiver = FixRecorder::new(ServerType::Receiver.session_id());
+        with_session_pair(&NullFixApplication, &receiver, &[], |_, _| {
+            for _ in 0..3 {
+                send_to_target(build_news("Hello", &[])?, &session_id)?;
+            }
+            wait_until(Duration::from_secs(5), || {
+                receiver.user_msg_count().recv == 3
+            });
+            Ok(())
+        })?;
+    }
+
+    // No session is running anymore.
+    assert!(matches!(
+        send_to_target(build_news("Too late", &[])?, &session_id),
+        Err(QuickFixError::SessionNotFound(_))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn test_session_stats() -> Result<(), QuickFixError> {
+    with_session_pair(&NullFixApplication, &NullFixApplication, &[], |_, _| {
+        let mut sender_session = unsafe { Session::lookup(&ServerType::Sender.session_id()) }?;
+        let receiver_session = unsafe { Session::lookup(&ServerType::Receiver.session_id()) }?;
+
+        // Logon has been exchanged.
+        let stats = sender_session.stats()?;
+        assert_eq!(stats.sent_admin, 1);
+        assert_eq!(stats.received_admin, 1);
+        assert_eq!(stats.sent_app, 0);
+        assert_eq!(stats.received_app, 0);
+        assert!(stats.last_sent_at.is_some());
+        assert!(stats.last_received_at.is_some());
+
+        // Round-trip News + TestRequest / Heartbeat.
+        send_to_target(build_news("Hello", &[])?, &ServerType::Sender.session_id())?;
+        sender_session.send_test_request("Stats")?;
+        wait_until(Duration::from_secs(5), || {
+            sender_session.stats().unwrap().received_admin == 2
+        });
+
+        let stats = sender_session.stats()?;
+        assert_eq!(stats.sent_app, 1);
+        assert_eq!(stats.sent_admin, 2);
+        wait_until(Duration::from_secs(5), || {
+            receiver_session.stats().unwrap().received_app == 1
+        });
+        let stats = receiver_session.stats()?;
+        assert_eq!(stats.received_admin, 2);
+        assert_eq!(stats.sent_admin, 2);
+
+        // Counters are cleared on reset.
+        sender_session.reset()?;
+        assert_eq!(sender_session.stats()?, SessionStats::default());
+        Ok(())
+    })
+}
+
+#[test]
+fn test_session_stored_messages() -> Result<(), QuickFixError> {
+    with_session_pair(&NullFixApplication, &NullFixApplication, &[], |_, _| {
+        let sender_id = ServerType::Sender.session_id();
+        let sender_session = unsafe { Session::lookup(&sender_id) }?;
+        let first_seq_num = sender_session.get_expected_sender_num() as u32;
+
+        for index in 0..3 {
+            let mut news = build_news(&format!("Hello {index}"), &[])?;
+            news.set_field(tags::CL_ORD_ID, format!("ORDER-{index}"))?;
+            send_to_target(news, &sender_id)?;
+        }
+
+        // Logon is not stored: reading from 1 only returns the news.
+        let stored = sender_session.stored_messages(1, first_seq_num + 2)?;
+        assert_eq!(stored.len(), 3);
+        for (index, msg) in stored.iter().enumerate() {
+            let StoredMessage::Parsed(msg) = msg else {
+                panic!("cannot parse stored message: {msg:?}");
+            };
+            assert_eq!(
+                msg.get_field(tags::HEADLINE),
+                Some(format!("Hello {index}"))
+            );
+            assert_eq!(
+                msg.get_field(tags::CL_ORD_ID),
+                Some(format!("ORDER-{index}"))
+            );
+            assert_eq!(
+                msg.with_header(|header| header.get_field(tags::MSG_SEQ_NUM)),
+                Some((first_seq_num + index as u32).to_string())
+            );
+        }
+
+        let raw = sender_session.stored_messages_raw(first_seq_num + 1, first_seq_num + 1)?;
+        assert_eq!(raw.len(), 1);
+        assert!(raw[0].contains("\x01148=Hello 1\x01"));
+
+        assert!(sender_session
+            .stored_messages(first_seq_num + 3, first_seq_num + 10)?
+            .is_empty());
+        Ok(())
+    })
+}
+
+#[test]
+fn test_session_set_reset_on_logon() -> Result<(), QuickFixError> {
+    let sender = LogonRecorder::default();
+    let receiver = FieldRecorder::new("A", tags::RESET_SEQ_NUM_FLAG);
+
+    with_session_pair(&sender, &receiver, &[&ReconnectInterval(1)], |_, _| {
+        let mut session = unsafe { Session::lookup(&ServerType::Sender.session_id()) }?;
+        for _ in 0..3 {
+            send_to_target(build_news("Hello", &[])?, &ServerType::Sender.session_id())?;
+        }
+        assert_eq!(receiver.last_value(), None);
+        assert!(session.get_expected_sender_num() > 1);
+
+        // Bounce connection with reset enabled.
+        session.set_reset_on_logon(true)?;
+        session.logout()?;
+        wait_until(Duration::from_secs(5), || sender.logout_count() == 1);
+        session.logon()?;
+        wait_until(Duration::from_secs(10), || sender.logon_count() == 2);
+
+        assert_eq!(receiver.last_value().as_deref(), Some("Y"));
+        assert_eq!(sender.last_sent_logon_seq_num(), 1);
+        assert_eq!(session.get_expected_sender_num(), 2);
+        assert_eq!(session.get_expected_target_num(), 2);
+        Ok(())
+    })
+}
+
+#[test]
+fn test_session_peer_address() -> Result<(), QuickFixError> {
+    with_session_pair(
+        &NullFixApplication,
+        &NullFixApplication,
+        &[],
+        |initiator, acceptor| {
+            let initiator_peer = initiator
+                .session(ServerType::Sender.session_id())?
+                .peer_address()?
+                .expect("Initiator is not connected");
+            let acceptor_peer = acceptor
+                .session(ServerType::Receiver.session_id())?
+                .peer_address()?
+                .expect("Acceptor is not connected");
+
+            // Acceptor sees the ephemeral port used by the initiator.
+            assert_eq!(initiator_peer.ip().to_string(), "127.0.0.1");
+            assert_eq!(acceptor_peer.ip().to_string(), "127.0.0.1");
+            assert_ne!(acceptor_peer.port(), 0);
+            assert_ne!(acceptor_peer.port(), initiator_peer.port());
+
+            acceptor.stop()?;
+            assert_eq!(
+                acceptor
+                    .session(ServerType::Receiver.session_id())?
+                    .peer_address()?,
+                None
+            );
+            Ok(())
+        },
+    )
+}
+
+#[test]
+fn test_session_peer_address_while_disconnecting() -> Result<(), QuickFixError> {
+    with_session_pair(
+        &NullFixApplication,
+        &NullFixApplication,
+        &[&ReconnectInterval(1)],
+        |initiator, acceptor| {
+            let initiator_session = initiator.session(ServerType::Sender.session_id())?;
+            let done = AtomicBool::new(false);
+
+            thread::scope(|scope| {
+                // Socket is read while connections are closed under it.
+                let reader = scope.spawn(|| {
+                    let sessions = [ServerType::Sender, ServerType::Receiver].map(|server_type| {
+                        unsafe { Session::lookup(&server_type.session_id()) }.unwrap()
+                    });
+                    let mut connected_reads = 0;
+                    while !done.load(Ordering::Relaxed) {
+                        for session in &sessions {
+                            if session.peer_address().unwrap().is_some() {
+                                connected_reads += 1;
+                            }
+                            let _ = session.socket_options().unwrap();
+                        }
+                    }
+                    connected_reads
+                });
+
+                for _ in 0..3 {
+                    initiator_session.disconnect()?;
+                    wait_until(Duration::from_secs(10), || {
+                        initiator.is_logged_on().unwrap_or(false)
+                            && acceptor.is_logged_on().unwrap_or(false)
+                    });
+                }
+                done.store(true, Ordering::Relaxed);
+                assert!(reader.join().unwrap() > 0);
+                Ok(())
+            })
+        },
+    )
+}
+
+#[test]
+fn test_settings_override_connect_port() -> Result<(), QuickFixError> {
+    let settings_receiver = build_settings(ServerType::Receiver, 0)?;
+
+    let app_sender = Application::try_new(&NullFixApplication)?;
+    let app_receiver = Application::try_new(&NullFixApplication)?;
+    let store_sender = MemoryMessageStoreFactory::new();
+    let store_receiver = MemoryMessageStoreFactory::new();
+
+    let mut acceptor = Acceptor::try_new(
+        &settings_receiver,
+        &app_receiver,
+        &store_receiver,
+        FixSocketServerKind::default(),
+    )?;
+    acceptor.start()?;
+    let receiver_port = listen_port(&acceptor);
+
+    // Sender settings first point to a port nobody listens on.
+    let session_id = ServerType::Sender.session_id();
+    let mut settings_sender = build_settings(ServerType::Sender, receiver_port + 1)?;
+    let mut dict = settings_sender.get(&session_id)?;
+    SocketConnectPort(receiver_port).apply_param(&mut dict)?;
+    settings_sender.set(Some(&session_id), dict)?;
+
+    let mut initiator = Initiator::try_new(
+        &settings_sender,
+        &app_sender,
+        &store_sender,
+        FixSocketServerKind::default(),
+    )?;
+    initiator.start()?;
+    wait_until(Duration::from_secs(10), || {
+        initiator.is_logged_on().unwrap_or(false) && acceptor.is_logged_on().unwrap_or(false)
+    });
+
+    initiator.stop()?;
+    acceptor.stop()?;
+    Ok(())
+}
+
+#[test]
+fn test_initiator_reload_settings() -> Result<(), QuickFixError> {
+    let extra_defaults: &[&dyn DictionaryItem] = &[&ReconnectInterval(1)];
+    let settings_receiver = build_settings_with_defaults(ServerType::Receiver, 0, extra_defaults)?;
+
+    let app_sender = Application::try_new(&NullFixApplication)?;
+    let app_receiver = Application::try_new(&NullFixApplication)?;
+    let store_sender = MemoryMessageStoreFactory::new();
+    let store_receiver = MemoryMessageStoreFactory::new();
+
+    let mut acceptor = Acceptor::try_new(
+        &settings_receiver,
+        &app_receiver,
+        &store_receiver,
+        FixSocketServerKind::default(),
+    )?;
+    acceptor.start()?;
+    let receiver_port = listen_port(&acceptor);
+
+    // Sender starts connecting to a port nobody listens on.
+    let session_id = ServerType::Sender.session_id();
+    let settings_sender =
+        build_settings_with_defaults(ServerType::Sender, receiver_port + 1, extra_defaults)?;
+    let mut initiator = Initiator::try_new(
+        &settings_sender,
+        &app_sender,
+        &store_sender,
+        FixSocketServerKind::default(),
+    )?;
+    initiator.start()?;
+
+    // Reloading same settings does nothing.
+    let report = initiator.reload_settings(&settings_sender, false)?;
+    assert!(report.updated.is_empty());
+    assert!(report.rejected.is_empty());
+    assert!(report.removed.is_empty());
+
+    // Keys not supported live and unknown sessions are rejected.
+    let mut unsupported = build_settings_with_defaults(
+        ServerType::Sender,
+        receiver_port + 1,
+        &[&ReconnectInterval(1), &ResetOnLogon(true)],
+    )?;
+    unsupported.set(Some(&ServerType::Receiver.session_id()), {
+        let mut dict = Dictionary::new();
+        ConnectionType::Initiator.apply_param(&mut dict)?;
+        dict
+    })?;
+    let report = initiator.reload_settings(&unsupported, false)?;
+    assert!(report.updated.is_empty());
+    let rejected: Vec<_> = report
+        .rejected
+        .iter()
+        .map(|(session_id, _reason)| session_id.to_repr())
+        .collect();
+    assert_eq!(
+        rejected,
+        vec![
+            ServerType::Receiver.session_id().to_repr(),
+            session_id.to_repr(),
+        ]
+    );
+
+    // Point sender to the acceptor port.
+    let mut settings_updated =
+        build_settings_with_defaults(ServerType::Sender, receiver_port + 1, extra_defaults)?;
+    let mut dict = settings_updated.get(&session_id)?;
+    SocketConnectPort(receiver_port).apply_param(&mut dict)?;
+    settings_updated.set(Some(&session_id), dict)?;
+
+    let report = initiator.reload_settings(&settings_updated, false)?;
+    assert_eq!(report.updated.len(), 1);
+    assert_eq!(report.updated[0].to_repr(), session_id.to_repr());
+
+    wait_until(Duration::from_secs(10), || {
+        initiator.is_logged_on().unwrap_or(false) && acceptor.is_logged_on().unwrap_or(false)
+    });
+
+    // Remove session.
+    let report = initiator.reload_settings(&SessionSettings::new(), true)?;
+    assert_eq!(report.removed.len(), 1);
+    wait_until(Duration::from_secs(10), || {
+        !initiator.is_logged_on().unwrap_or(true)
+    });
+
+    initiator.stop()?;
+    acceptor.stop()?;
+    Ok(())
+}
+
+#[test]
+fn test_settings_set_default_heartbeat() -> Result<(), QuickFixError> {
+    let session_id = ServerType::Sender.session_id();
+    let settings_receiver = build_settings(ServerType::Receiver, 0)?;
+
+    let app_sender = Application::try_new(&NullFixApplication)?;
+    let app_receiver = Application::try_new(&NullFixApplication)?;
+    let store_sender = MemoryMessageStoreFactory::new();
+    let store_receiver = MemoryMessageStoreFactory::new();
+
+    let mut acceptor = Acceptor::try_new(
+        &settings_receiver,
+        &app_receiver,
+        &store_receiver,
+        FixSocketServerKind::default(),
+    )?;
+    acceptor.start()?;
+    let port = listen_port(&acceptor);
+
+    // HeartBtInt is only defined in DEFAULT section.
+    let mut settings_sender = SessionSettingsBuilder::new()
+        .default(Dictionary::try_from_items(&[
+            &ConnectionType::Initiator,
+            &ReconnectInterval(1),
+            &HeartBtInt(20),
+        ])?)
+        .session(session_id.clone(), |dict| {
+            let items: [&dyn DictionaryItem; 5] = [
+                &StartTime("00:00:00"),
+                &EndTime("23:59:59"),
+                &dictionary_item::DataDictionary(FIX44_SPEC),
+                &SocketConnectPort(port),
+                &SocketConnectHost("127.0.0.1"),
+            ];
+            items.iter().try_for_each(|item| item.apply_param(dict))
+        })
+        .build()?;
+
+    let mut defaults = settings_sender.default_dictionary()?;
+    defaults.set("HeartBtInt", 7)?;
+    settings_sender.set_default(defaults)?;
+
+    let mut initiator = Initiator::try_new(
+        &settings_sender,
+        &app_sender,
+        &store_sender,
+        FixSocketServerKind::default(),
+    )?;
+    initiator.start()?;
+    wait_until(Duration::from_secs(10), || {
+        initiator.is_logged_on().unwrap_or(false) && acceptor.is_logged_on().unwrap_or(false)
+    });
+
+    assert_eq!(initiator.session(session_id)?.heartbeat_interval()?, 7);
+    assert_eq!(
+        acceptor
+            .session(ServerType::Receiver.session_id())?
+            .heartbeat_interval()?,
+        7
+    );
+
+    initiator.stop()?;
+    acceptor.stop()?;
+    Ok(())
+}
+
+#[test]
+fn test_initiator_add_session() -> Result<(), QuickFixError> {
+    let extra_defaults: &[&dyn DictionaryItem] = &[&ReconnectInterval(1)];
+    let settings_receiver = build_settings(ServerType::Receiver, 0)?;
+
+    let app_sender = Application::try_new(&NullFixApplication)?;
+    let app_receiver = Application::try_new(&NullFixApplication)?;
+    let store_sender = MemoryMessageStoreFactory::new();
+    let store_receiver = MemoryMessageStoreFactory::new();
+
+    let mut acceptor = Acceptor::try_new(
+        &settings_receiver,
+        &app_receiver,
+        &store_receiver,
+        FixSocketServerKind::default(),
+    )?;
+    acceptor.start()?;
+
+    let settings_sender =
+        build_settings_with_defaults(ServerType::Sender, listen_port(&acceptor), extra_defaults)?;
+    let mut initiator = Initiator::try_new(
+        &settings_sender,
+        &app_sender,
+        &store_sender,
+        FixSocketServerKind::default(),
+    )?;
+    initiator.start()?;
+
+    let session_id = ServerType::Sender.session_id();
+    wait_until(Duration::from_secs(10), || {
+        initiator
+            .session(session_id.clone())
+            .and_then(|mut session| session.is_logged_on())
+            .unwrap_or(false)
+    });
+
+    // Start a backup venue, using other comp IDs.
+    let backup_session_id = SessionId::try_new("FIX.4.4", "SENDER", "BACKUP", "")?;
+    let backup_settings_receiver = SessionSettingsBuilder::new()
+        .default(Dictionary::try_from_items(&[&ConnectionType::Acceptor])?)
+        .session(
+            SessionId::try_new("FIX.4.4", "BACKUP", "SENDER", "")?,
+            |dict| fill_session(dict, 0),
+        )
+        .build()?;
+
+    let app_backup = Application::try_new(&NullFixApplication)?;
+    let store_backup = MemoryMessageStoreFactory::new();
+    let mut backup_acceptor = Acceptor::try_new(
+        &backup_settings_receiver,
+        &app_backup,
+        &store_backup,
+        FixSocketServerKind::default(),
+    )?;
+    backup_acceptor.start()?;
+    let backup_port = listen_port(&backup_acceptor);
+
+    // Add backup session to the running initiator.
+    let backup_dict = Dictionary::try_from_items(&[
+        &StartTime("00:00:00"),
+        &EndTime("23:59:59"),
+        &HeartBtInt(20),
+        &DataDictionary("../quickfix-ffi/libquickfix/spec/FIX44.xml"),
+        &SocketConnectHost("127.0.0.1"),
+        &SocketConnectPort(backup_port),
+    ])?;
+    initiator.add_session(&backup_session_id, &backup_dict)?;
+
+    // Adding it twice is an error.
+    assert!(initiator
+        .add_session(&backup_session_id, &backup_dict)
+        .is_err());
+
+    let session_reprs: Vec<_> = initiator
+        .session_ids()?
+        .iter()
+        .map(SessionId::to_repr)
+        .collect();
+    assert!(session_reprs.contains(&backup_session_id.to_repr()));
+
+    wait_until(Duration::from_secs(10), || {
+        backup_acceptor.is_logged_on().unwrap_or(false)
+    });
+    assert!(backup_acceptor.is_logged_on()?);
+    assert!(initiator.session(backup_session_id)?.is_logged_on()?);
+    assert!(initiator.session(session_id)?.is_logged_on()?);
+    assert!(acceptor.is_logged_on()?);
+
+    initiator.stop()?;
+    acceptor.stop()?;
+    backup_acceptor.stop()?;
+    Ok(())
+}
+
+#[test]
+fn test_acceptor_remove_session() -> Result<(), QuickFixError> {
+    let extra_defaults: &[&dyn DictionaryItem] = &[&ReconnectInterval(1)];
+    let settings_receiver = build_settings(ServerType::Receiver, 0)?;
+
+    let sender = LogonRecorder::default();
+    let app_sender = Application::try_new(&sender)?;
+    let app_receiver = Application::try_new(&NullFixApplication)?;
+    let store_sender = MemoryMessageStoreFactory::new();
+    let store_receiver = MemoryMessageStoreFactory::new();
+
+    let mut acceptor = Acceptor::try_new(
+        &settings_receiver,
+        &app_receiver,
+        &store_receiver,
+        FixSocketServerKind::default(),
+    )?;
+    acceptor.start()?;
+    let port = listen_port(&acceptor);
+
+    let settings_sender = build_settings_with_defaults(ServerType::Sender, port, extra_defaults)?;
+    let mut initiator = Initiator::try_new(
+        &settings_sender,
+        &app_sender,
+        &store_sender,
+        FixSocketServerKind::default(),
+    )?;
+    initiator.start()?;
+    wait_until(Duration::from_secs(10), || {
+        initiator.is_logged_on().unwrap_or(false) && acceptor.is_logged_on().unwrap_or(false)
+    });
+    assert_eq!(sender.logon_count(), 1);
+
+    // Unknown session cannot be removed.
+    let unknown_session_id = SessionId::try_new("FIX.4.4", "RECEIVER", "UNKNOWN", "")?;
+    assert!(matches!(
+        acceptor.remove_session(&unknown_session_id, false),
+        Err(QuickFixError::SessionNotFound(_))
+    ));
+
+    // Remove session with a clean logout.
+    let session_id = ServerType::Receiver.session_id();
+    acceptor.remove_session(&session_id, false)?;
+    wait_until(Duration::from_secs(5), || sender.logout_count() == 1);
+    assert_eq!(sender.logout_count(), 1);
+    assert!(acceptor.session_ids()?.is_empty());
+    assert!(matches!(
+        acceptor.session(session_id.clone()),
+        Err(QuickFixError::SessionNotFound(_))
+    ));
+
+    // Messages cannot be sent anymore.
+    assert!(matches!(
+        send_to_target(build_news("Hello", &[])?, &session_id),
+        Err(QuickFixError::SessionNotFound(_))
+    ));
+
+    // Counterparty keeps reconnecting, but logon is refused.
+    let sent_logon = sender.sent_logon_count();
+    assert!(sender.wait_for(Duration::from_secs(10), |counts| {
+        counts.sent_logon >= sent_logon + 2
+    }));
+    assert_eq!(sender.logon_count(), 1);
+    assert!(!initiator.is_logged_on()?);
+    assert!(!acceptor.is_logged_on()?);
+
+    initiator.stop()?;
+    acceptor.stop()?;
+    Ok(())
+}
+
+#[test]
+fn test_is_stopped_lifecycle() -> Result<(), QuickFixError> {
+    for server_kind in [
+        FixSocketServerKind::SingleThreaded,
+        FixSocketServerKind::MultiThreaded,
+    ] {
+        let settings_receiver = build_settings(ServerType::Receiver, 0)?;
+
+        let app_sender = Application::try_new(&NullFixApplication)?;
+        let app_receiver = Application::try_new(&NullFixApplication)?;
+        let store_sender = MemoryMessageStoreFactory::new();
+        let store_receiver = MemoryMessageStoreFactory::new();
+
+        let mut acceptor = Acceptor::try_new(
+            &settings_receiver,
+            &app_receiver,
+            &store_receiver,
+            server_kind,
+        )?;
+        assert!(
+            acceptor.is_stopped()?,
+            "{server_kind:?}: acceptor not started"
+        );
+        acceptor.start()?;
+        assert!(!acceptor.is_stopped()?, "{server_kind:?}: acceptor running");
+        let port = listen_port(&acceptor);
+
+        let settings_sender = build_settings(ServerType::Sender, port)?;
+        let mut initiator =
+            Initiator::try_new(&settings_sender, &app_sender, &store_sender, server_kind)?;
+        assert!(
+            initiator.is_stopped()?,
+            "{server_kind:?}: initiator not started"
+        );
+        initiator.start()?;
+        assert!(
+            !initiator.is_stopped()?,
+            "{server_kind:?}: initiator running"
+        );
+
+        // Still running once sessions are logged on.
+        wait_until(Duration::from_secs(10), || {
+            initiator.is_logged_on().unwrap_or(false) && acceptor.is_logged_on().unwrap_or(false)
+        });
+        assert!(
+            !initiator.is_stopped()?,
+            "{server_kind:?}: initiator logged on"
+        );
+        assert!(
+            !acceptor.is_stopped()?,
+            "{server_kind:?}: acceptor logged on"
+        );
+
+        initiator.stop()?;
+        assert!(
+            initiator.is_stopped()?,
+            "{server_kind:?}: initiator stopped"
+        );
+        acceptor.stop()?;
+        assert!(acceptor.is_stopped()?, "{server_kind:?}: acceptor stopped");
+    }
+    Ok(())
+}
+
+#[test]
+fn test_block_and_stop_from_other_thread() -> Result<(), QuickFixError> {
+    let settings_receiver = build_settings(ServerType::Receiver, 0)?;
+
+    let app_sender = Application::try_new(&NullFixApplication)?;
+    let app_receiver = Application::try_new(&NullFixApplication)?;
+    let store_sender = MemoryMessageStoreFactory::new();
+    let store_receiver = MemoryMessageStoreFactory::new();
+
+    let mut acceptor = Acceptor::try_new(
+        &settings_receiver,
+        &app_receiver,
+        &store_receiver,
+        FixSocketServerKind::default(),
+    )?;
+    acceptor.start()?;
+    let port = listen_port(&acceptor);
+
+    let settings_sender = build_settings(ServerType::Sender, port)?;
+    let mut initiator = Initiator::try_new(
+        &settings_sender,
+        &app_sender,
+        &store_sender,
+        FixSocketServerKind::default(),
+    )?;
+
+    let stop_handle = initiator.stop_handle();
+    thread::scope(|scope| {
+        let worker = scope.spawn(move || {
+            initiator.block()?;
+            assert!(initiator.is_stopped()?);
+            Ok::<_, QuickFixError>(())
+        });
+
+        wait_until(Duration::from_secs(10), || {
+            acceptor.is_logged_on().unwrap_or(false)
+        });
+        assert!(acceptor.is_logged_on()?);
+
+        stop_handle.stop()?;
+        worker.join().expect("Blocking thread panicked")
+    })?;
+
+    // Handle can outlive its handler.
+    stop_handle.stop()?;
+
+    acceptor.stop()?;
+    Ok(())
+}
+
+#[test]
+fn test_poll_without_start() -> Result<(), QuickFixError> {
+    let extra_defaults: &[&dyn DictionaryItem] = &[&ReconnectInterval(1)];
+    let settings_receiver = build_settings(ServerType::Receiver, 0)?;
+
+    let app_sender = Application::try_new(&NullFixApplication)?;
+    let app_receiver = Application::try_new(&NullFixApplication)?;
+    let store_sender = MemoryMessageStoreFactory::new();
+    let store_receiver = MemoryMessageStoreFactory::new();
+
+    let mut acceptor = Acceptor::try_new(
+        &settings_receiver,
+        &app_receiver,
+        &store_receiver,
+        FixSocketServerKind::SingleThreaded,
+    )?;
+
+    // Drive both sides from this thread only, first acceptor poll opens its socket.
+    let timeout = Some(Duration::from_millis(10));
+    assert!(acceptor.poll(timeout)?);
+
+    let settings_sender =
+        build_settings_with_defaults(ServerType::Sender, listen_port(&acceptor), extra_defaults)?;
+    let mut initiator = Initiator::try_new(
+        &settings_sender,
+        &app_sender,
+        &store_sender,
+        FixSocketServerKind::SingleThreaded,
+    )?;
+    wait_until(Duration::from_secs(10), || {
+        assert!(acceptor.poll(timeout).unwrap());
+        assert!(initiator.poll(timeout).unwrap());
+        initiator.is_logged_on().unwrap_or(false) && acceptor.is_logged_on().unwrap_or(false)
+    });
+    assert!(initiator.is_logged_on()?);
+    assert!(acceptor.is_logged_on()?);
+
+    initiator.stop()?;
+    acceptor.stop()?;
+    Ok(())
+}
+
+#[test]
+fn test_poll_multi_threaded() -> Result<(), QuickFixError> {
+    let settings = build_settings(ServerType::Receiver, 0)?;
+    let app = Application::try_new(&NullFixApplication)?;
+    let store = MemoryMessageStoreFactory::new();
+
+    let mut acceptor =
+        Acceptor::try_new(&settings, &app, &store, FixSocketServerKind::MultiThreaded)?;
+    assert_eq!(
+        acceptor.poll(None),
+        Err(QuickFixError::InvalidArgument(
+            "poll is only supported by single threaded acceptor".to_string()
+        ))
+    );
+    Ok(())
+}
+
+#[test]
+fn test_stop_force_with_unresponsive_peer() -> Result<(), QuickFixError> {
+    let settings_receiver = build_settings(ServerType::Receiver, 0)?;
+
+    let sender = LogonRecorder::default();
+    let app_sender = Application::try_new(&sender)?;
+    let app_receiver = Application::try_new(&NullFixApplication)?;
+    let store_sender = MemoryMessageStoreFactory::new();
+    let store_receiver = MemoryMessageStoreFactory::new();
+
+    let mut acceptor = Acceptor::try_new(
+        &settings_receiver,
+        &app_receiver,
+        &store_receiver,
+        FixSocketServerKind::SingleThreaded,
+    )?;
+
+    // Acceptor only runs while this thread polls it, first poll opens its socket.
+    assert!(acceptor.poll(Some(Duration::from_millis(10)))?);
+
+    let settings_sender = build_settings(ServerType::Sender, listen_port(&acceptor))?;
+    let mut initiator = Initiator::try_new(
+        &settings_sender,
+        &app_sender,
+        &store_sender,
+        FixSocketServerKind::default(),
+    )?;
+    initiator.start()?;
+    wait_until(Duration::from_secs(10), || {
+        assert!(acceptor.poll(Some(Duration::from_millis(10))).unwrap());
+        initiator.is_logged_on().unwrap_or(false) && acceptor.is_logged_on().unwrap_or(false)
+    });
+
+    // Acceptor does not answer anymore, but forced stop does not wait for it.
+    let started_at = Instant::now();
+    initiator.stop_force()?;
+    assert!(started_at.elapsed() < Duration::from_secs(2));
+    assert!(initiator.is_stopped()?);
+    assert!(!initiator.is_logged_on()?);
+
+    acceptor.stop_force()?;
+    assert!(acceptor.is_stopped()?);
+    Ok(())
+}
+
+/// Log on with `client` certificate, acceptor requiring client certificates signed by `authority`.
+#[cfg(quickfix_ssl)]
+fn ssl_logon_with_authority(
+    client: &TestCertificate,
+    authority: &TestCertificate,
+) -> Result<bool, QuickFixError> {
+    let mut settings_receiver = build_ssl_settings(ServerType::Receiver, 0)?;
+    let mut defaults = settings_receiver.default_dictionary()?;
+    CertificationAuthoritiesFile(&authority.cert_file).apply_param(&mut defaults)?;
+    CertificateVerifyLevel::Require.apply_param(&mut defaults)?;
+    settings_receiver.set(None, defaults)?;
+
+    let app_sender = Application::try_new(&NullFixApplication)?;
+    let app_receiver = Application::try_new(&NullFixApplication)?;
+    let store_sender = MemoryMessageStoreFactory::new();
+    let store_receiver = MemoryMessageStoreFactory::new();
+
+    let mut acceptor = Acceptor::try_new(
+        &settings_receiver,
+        &app_receiver,
+        &store_receiver,
+        FixSocketServerKind::SslSingleThreaded,
+    )?;
+    acceptor.start()?;
+
+    let mut settings_sender = build_ssl_settings(ServerType::Sender, listen_port(&acceptor))?;
+    let mut defaults = settings_sender.default_dictionary()?;
+    ClientCertificateFile(&client.cert_file).apply_param(&mut defaults)?;
+    ClientCertificateKeyFile(&client.key_file).apply_param(&mut defaults)?;
+    settings_sender.set(None, defaults)?;
+
+    let mut initiator = Initiator::try_new(
+        &settings_sender,
+        &app_sender,
+        &store_sender,
+        FixSocketServerKind::SslSingleThreaded,
+    )?;
+    initiator.start()?;
+
+    let mut logged_on = false;
+    for _ in 0..50 {
+        logged_on = initiator.is_logged_on()? && acceptor.is_logged_on()?;
+        if logged_on {
+            break;
+        }
+        thread::sleep(Duration::from_millis(100));
+    }
+
+    initiator.stop()?;
+    acceptor.stop()?;
+    Ok(logged_on)
+}
+
+#[test]
+#[cfg(quickfix_ssl)]
+fn test_ssl_client_certificate_verification() -> Result<(), QuickFixError> {
+    // Client certificate is self signed, so it is its own authority.
+    let client = TestCertificate::generate("verifiedClient");
+    assert!(ssl_logon_with_authority(&client, &client)?);
+    Ok(())
+}
+
+#[test]
+#[cfg(quickfix_ssl)]
+fn test_ssl_client_certificate_wrong_authority() -> Result<(), QuickFixError> {
+    // Client certificate is not signed by this authority.
+    let client = TestCertificate::generate("refusedClient");
+    let authority = TestCertificate::generate("otherAuthority");
+    assert!(!ssl_logon_with_authority(&client, &authority)?);
+    Ok(())
+}
+
+#[test]
+fn test_handler_sessions() -> Result<(), QuickFixError> {
+    let other_session_id = SessionId::try_new("FIX.4.4", "RECEIVER", "OTHER", "")?;
+
+    let mut settings = build_settings(ServerType::Receiver, 0)?;
+    settings.set(
+        Some(&other_session_id),
+        Dictionary::try_from_items(&[
+            &StartTime("00:00:00"),
+            &EndTime("23:59:59"),
+            &HeartBtInt(30),
+            &DataDictionary("../quickfix-ffi/libquickfix/spec/FIX44.xml"),
+            &SocketAcceptPort(0),
+        ])?,
+    )?;
+
+    let app = Application::try_new(&NullFixApplication)?;
+    let store = MemoryMessageStoreFactory::new();
+    let acceptor = Acceptor::try_new(&settings, &app, &store, FixSocketServerKind::default())?;
+
+    let sessions = acceptor.sessions()?;
+    assert_eq!(sessions.len(), 2);
+
+    let mut heartbeat_intervals: Vec<_> = sessions
+        .iter()
+        .map(|session| session.status().map(|status| status.heartbeat_interval))
+        .collect::<Result<_, _>>()?;
+    heartbeat_intervals.sort();
+    assert_eq!(
+        heartbeat_intervals,
+        vec![Duration::from_secs(20), Duration::from_secs(30)]
+    );
+
+    for session in &sessions {
+        let status = session.status()?;
+        assert!(!status.logged_on);
+        assert!(status.is_session_time);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_shutdown_report() -> Result<(), QuickFixError> {
+    let settings_receiver = build_settings(ServerType::Receiver, 0)?;
+
+    let app_sender = Application::try_new(&NullFixApplication)?;
+    let app_receiver = Application::try_new(&NullFixApplication)?;
+    let store_sender = MemoryMessageStoreFactory::new();
+    let store_receiver = MemoryMessageStoreFactory::new();
+
+    let mut acceptor = Acceptor::try_new(
+        &settings_receiver,
+        &app_receiver,
+        &store_receiver,
+        FixSocketServerKind::SingleThreaded,
+    )?;
+
+    // Acceptor only runs while this thread polls it, first poll opens its socket.
+    assert!(acceptor.poll(Some(Duration::from_millis(10)))?);
+
+    let settings_sender = build_settings(ServerType::Sender, listen_port(&acceptor))?;
+    let mut initiator = Initiator::try_new(
+        &settings_sender,
+        &app_sender,
+        &store_sender,
+        FixSocketServerKind::default(),
+    )?;
+    initiator.start()?;
+    wait_until(Duration::from_secs(10), || {
+        assert!(acceptor.poll(Some(Duration::from_millis(10))).unwrap());
+        initiator.is_logged_on().unwrap_or(false) && acceptor.is_logged_on().unwrap_or(false)
+    });
+
+    // Acceptor does not answer logout, so session is disconnected once timeout is reached.
+    let started_at = Instant::now();
+    let report = initiator.shutdown(Duration::from_millis(500))?;
+    assert!(started_at.elapsed() < Duration::from_secs(2));
+    assert!(report.clean.is_empty());
+    let forced: Vec<_> = report.forced.iter().map(SessionId::to_repr).collect();
+    assert_eq!(forced, vec![ServerType::Sender.session_id().to_repr()]);
+    assert!(initiator.is_stopped()?);
+    assert!(!initiator.is_logged_on()?);
+
+    // Nothing left to logout.
+    let report = initiator.shutdown(Duration::from_millis(500))?;
+    assert!(report.clean.is_empty());
+    assert!(report.forced.is_empty());
+
+    acceptor.stop_force()?;
+    Ok(())
+}
+
+#[test]
+fn test_initiator_set_reconnect_interval() -> Result<(), QuickFixError> {
+    let extra_defaults: &[&dyn DictionaryItem] = &[&ReconnectInterval(1)];
+    let settings_receiver = build_settings(ServerType::Receiver, 0)?;
+
+    let sender = LogonRecorder::default();
+    let app_sender = Application::try_new(&sender)?;
+    let app_receiver = Application::try_new(&NullFixApplication)?;
+    let store_sender = MemoryMessageStoreFactory::new();
+    let store_receiver = MemoryMessageStoreFactory::new();
+
+    let mut acceptor = Acceptor::try_new(
+        &settings_receiver,
+        &app_receiver,
+        &store_receiver,
+        FixSocketServerKind::default(),
+    )?;
+    acceptor.start()?;
+    let port = listen_port(&acceptor);
+
+    let settings_sender = build_settings_with_defaults(ServerType::Sender, port, extra_defaults)?;
+    let mut initiator = Initiator::try_new(
+        &settings_sender,
+        &app_sender,
+        &store_sender,
+        FixSocketServerKind::default(),
+    )?;
+
+    // Invalid values are rejected.
+    let session_id = ServerType::Sender.session_id();
+    assert!(initiator
+        .set_reconnect_interval(None, Duration::ZERO)
+        .is_err());
+    assert!(initiator
+        .set_reconnect_policy(
+            Some(&session_id),
+            ReconnectPolicy::Exponential {
+                initial: Duration::from_secs(2),
+                max: Duration::from_secs(1),
+                jitter: Duration::ZERO,
+            },
+        )
+        .is_err());
+    assert!(initiator
+        .set_reconnect_interval(
+            Some(&SessionId::try_new("FIX.4.4", "SENDER", "UNKNOWN", "")?),
+            Duration::from_secs(1),
+        )
+        .is_err());
+
+    initiator.start()?;
+
+    wait_until(Duration::from_secs(10), || {
+        initiator.is_session_logged_on(&session_id).unwrap_or(false)
+    });
+
+    for session in [None, Some(&session_id)] {
+        // Long interval: session stays disconnected.
+        initiator.set_reconnect_interval(session, Duration::from_secs(60))?;
+        let sent_logon = sender.sent_logon_count();
+        initiator.session(session_id.clone())?.disconnect()?;
+        assert!(!sender.wait_for(Duration::from_secs(3), |counts| {
+            counts.sent_logon > sent_logon
+        }));
+        assert!(!initiator.is_session_logged_on(&session_id)?);
+
+        // Shorter interval applies right away.
+        initiator.set_reconnect_interval(session, Duration::from_secs(1))?;
+        wait_until(Duration::from_secs(10), || {
+            initiator.is_session_logged_on(&session_id).unwrap_or(false)
+        });
+    }
+
+    // Session disabled by user while waiting for policy delay is not enabled again once it expires.
+    initiator.set_reconnect_policy(
+        Some(&session_id),
+        ReconnectPolicy::Exponential {
+            initial: Duration::from_secs(1),
+            max: Duration::from_secs(1),
+            jitter: Duration::from_millis(200),
+        },
+    )?;
+    let sent_logon = sender.sent_logon_count();
+    initiator.session(session_id.clone())?.disconnect()?;
+    initiator.session(session_id.clone())?.logout()?;
+    assert!(!sender.wait_for(Duration::from_secs(4), |counts| {
+        counts.sent_logon > sent_logon
+    }));
+    assert!(!initiator.session(session_id.clone())?.is_enabled()?);
+
+    initiator.session(session_id.clone())?.logon()?;
+    wait_until(Duration::from_secs(10), || {
+        initiator.is_session_logged_on(&session_id).unwrap_or(false)
+    });
+
+    initiator.stop()?;
+    acceptor.stop()?;
+    Ok(())
+}
+
+#[test]
+fn test_acceptor_listen_endpoints() -> Result<(), QuickFixError> {
+    // Both sessions share the same port, assigned by the OS.
+    let mut settings = build_settings(ServerType::Receiver, 0)?;
+    settings.set(
+        Some(&SessionId::try_new("FIX.4.4", "RECEIVER", "OTHER", "")?),
+        Dictionary::try_from_items(&[
+            &StartTime("00:00:00"),
+            &EndTime("23:59:59"),
+            &HeartBtInt(20),
+            &DataDictionary("../quickfix-ffi/libquickfix/spec/FIX44.xml"),
+            &SocketAcceptPort(0),
+        ])?,
+    )?;
+
+    let app = Application::try_new(&NullFixApplication)?;
+    let store = MemoryMessageStoreFactory::new();
+    let mut acceptor = Acceptor::try_new(&settings, &app, &store, FixSocketServerKind::default())?;
+    assert!(acceptor.listen_endpoints()?.is_empty());
+
+    acceptor.start()?;
+    let endpoints = acceptor.listen_endpoints()?;
+    assert_eq!(endpoints.len(), 1);
+    assert_ne!(endpoints[0].port(), 0);
+
+    acceptor.stop()?;
+    assert!(acceptor.listen_endpoints()?.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_session_set_enabled() -> Result<(), QuickFixError> {
+    // Second session, next to the SENDER / RECEIVER one.
+    let maintenance_dict = |port: u16| {
+        Dictionary::try_from_items(&[
+            &StartTime("00:00:00"),
+            &EndTime("23:59:59"),
+            &HeartBtInt(20),
+            &DataDictionary("../quickfix-ffi/libquickfix/spec/FIX44.xml"),
+            &SocketAcceptPort(port),
+            &SocketConnectHost("127.0.0.1"),
+            &SocketConnectPort(port),
+        ])
+    };
+    let maintenance_id = SessionId::try_new("FIX.4.4", "MAINTENANCE", "RECEIVER", "")?;
+    let trading_id = ServerType::Sender.session_id();
+
+    let mut settings_receiver = build_settings(ServerType::Receiver, 0)?;
+    settings_receiver.set(
+        Some(&SessionId::try_new(
+            "FIX.4.4",
+            "RECEIVER",
+            "MAINTENANCE",
+            "",
+        )?),
+        maintenance_dict(0)?,
+    )?;
+
+    let app_sender = Application::try_new(&NullFixApplication)?;
+    let app_receiver = Application::try_new(&NullFixApplication)?;
+    let store_sender = MemoryMessageStoreFactory::new();
+    let store_receiver = MemoryMessageStoreFactory::new();
+
+    let mut acceptor = Acceptor::try_new(
+        &settings_receiver,
+        &app_receiver,
+        &store_receiver,
+        FixSocketServerKind::default(),
+    )?;
+    acceptor.start()?;
+    let port = listen_port(&acceptor);
+
+    let extra_defaults: &[&dyn DictionaryItem] = &[&ReconnectInterval(1)];
+    let mut settings_sender =
+        build_settings_with_defaults(ServerType::Sender, port, extra_defaults)?;
+    settings_sender.set(Some(&maintenance_id), maintenance_dict(port)?)?;
+    let mut initiator = Initiator::try_new(
+        &settings_sender,
+        &app_sender,
+        &store_sender,
+        FixSocketServerKind::default(),
+    )?;
+    initiator.start()?;
+
+    let is_logged_on =
+        |session_id: &SessionId| initiator.is_session_logged_on(session_id).unwrap_or(false);
+    wait_until(Duration::from_secs(10), || {
+        is_logged_on(&trading_id) && is_logged_on(&maintenance_id)
+    });
+
+    // Take maintenance session offline, it does not reconnect.
+    let session = initiator.session(maintenance_id.clone())?;
+    assert!(session.is_enabled()?);
+    session.set_enabled(false)?;
+    assert!(!session.is_enabled()?);
+    wait_until(Duration::from_secs(5), || !is_logged_on(&maintenance_id));
+
+    // Other session keeps trading meanwhile.
+    for _ in 0..3 {
+        send_to_target(build_news("Hello", &[])?, &trading_id)?;
+        thread::sleep(Duration::from_secs(1));
+        assert!(!is_logged_on(&maintenance_id));
+    }
+    let receiver_session = acceptor.session(ServerType::Receiver.session_id())?;
+    wait_until(Duration::from_secs(5), || {
+        receiver_session
+            .stats()
+            .map(|stats| stats.received_app == 3)
+            .unwrap_or(false)
+    });
+    assert!(is_logged_on(&trading_id));
+
+    // Bring it back.
+    session.set_enabled(true)?;
+    assert!(session.is_enabled()?);
+    wait_until(Duration::from_secs(10), || is_logged_on(&maintenance_id));
+
+    initiator.stop()?;
+    acceptor.stop()?;
+    Ok(())
+}
+
+#[test]
+fn test_initiator_restart() -> Result<(), QuickFixError> {
+    let recorder = LogonRecorder::default();
+    let app_sender = Application::try_new(&recorder)?;
+    let app_receiver = Application::try_new(&NullFixApplication)?;
+    let store_sender = MemoryMessageStoreFactory::new();
+    let store_receiver = MemoryMessageStoreFactory::new();
+
+    let mut acceptor = Acceptor::try_new(
+        &build_settings(ServerType::Receiver, 0)?,
+        &app_receiver,
+        &store_receiver,
+        FixSocketServerKind::default(),
+    )?;
+    acceptor.start()?;
+    let port = listen_port(&acceptor);
+
+    let mut initiator = Initiator::try_new(
+        &build_settings(ServerType::Sender, port)?,
+        &app_sender,
+        &store_sender,
+        FixSocketServerKind::default(),
+    )?;
+    let sender_id = ServerType::Sender.session_id();
+    let receiver_session = acceptor.session(ServerType::Receiver.session_id())?;
+    let received_app = || {
+        receiver_session
+            .stats()
+            .map(|stats| stats.received_app)
+            .unwrap_or(0)
+    };
+
+    initiator.start()?;
+    wait_until(Duration::from_secs(10), || recorder.logon_count() == 1);
+    assert_eq!(recorder.last_sent_logon_seq_num(), 1);
+    send_to_target(build_news("Before restart", &[])?, &sender_id)?;
+    wait_until(Duration::from_secs(5), || received_app() == 1);
+
+    initiator.restart()?;
+    assert!(!initiator.is_stopped()?);
+    wait_until(Duration::from_secs(10), || recorder.logon_count() == 2);
+    assert_eq!(recorder.logout_count(), 1);
+
+    // Logon, News and Logout have been sent before restart.
+    assert_eq!(recorder.last_sent_logon_seq_num(), 4);
+    send_to_target(build_news("After restart", &[])?, &sender_id)?;
+    wait_until(Duration::from_secs(5), || received_app() == 2);
+
+    initiator.stop()?;
+    assert!(initiator.is_stopped()?);
+    acceptor.stop()?;
+    Ok(())
+}
+
+#[test]
+fn test_session_transport_stats() -> Result<(), QuickFixError> {
+    with_session_pair(
+        &NullFixApplication,
+        &NullFixApplication,
+        &[],
+        |socket_sender, socket_receiver| {
+            let sender_session = socket_sender.session(ServerType::Sender.session_id())?;
+            let receiver_session = socket_receiver.session(ServerType::Receiver.session_id())?;
+
+            // Logon has been exchanged.
+            let before = sender_session.transport_stats()?;
+            assert_eq!(before.connect_count, 1);
+            assert!(before.last_connect_at.is_some());
+            assert!(before.bytes_sent > 0);
+            assert!(before.bytes_received > 0);
+
+            let news = build_news("Hello", &[])?;
+            let news_len = news.to_fix_string()?.len() as u64;
+            let received_before = receiver_session.transport_stats()?.bytes_received;
+            send_to_target(news, &ServerType::Sender.session_id())?;
+
+            let after = sender_session.transport_stats()?;
+            assert!(after.bytes_sent >= before.bytes_sent + news_len);
+            assert_eq!(after.connect_count, 1);
+            wait_until(Duration::from_secs(5), || {
+                receiver_session
+                    .transport_stats()
+                    .map(|stats| stats.bytes_received >= received_before + news_len)
+                    .unwrap_or(false)
+            });
+            Ok(())
+        },
+    )
+}
+
+#[test]
+fn test_session_socket_options() -> Result<(), QuickFixError> {
+    with_session_pair(
+        &NullFixApplication,
+        &NullFixApplication,
+        &[],
+        |socket_sender, _| {
+            let session = socket_sender.session(ServerType::Sender.session_id())?;
+
+            // `SocketNodelay` is enabled by default.
+            let options = session.socket_options()?.expect("session is connected");
+            assert_eq!(options.nodelay, Some(true));
+
+            session.set_socket_options(SocketOptions {
+                nodelay: Some(false),
+                keepalive: Some(true),
+                ..Default::default()
+            })?;
+            let options = session.socket_options()?.expect("session is connected");
+            assert_eq!(options.nodelay, Some(false));
+            assert_eq!(options.keepalive, Some(true));
+
+            #[cfg(target_os = "linux")]
+            {
+                session.set_socket_options(SocketOptions {
+                    keepalive_idle: Some(Duration::from_secs(30)),
+                    keepalive_interval: Some(Duration::from_secs(5)),
+                    keepalive_count: Some(4),
+                    ..Default::default()
+                })?;
+                let options = session.socket_options()?.expect("session is connected");
+                assert_eq!(options.keepalive_idle, Some(Duration::from_secs(30)));
+                assert_eq!(options.keepalive_interval, Some(Duration::from_secs(5)));
+                assert_eq!(options.keepalive_count, Some(4));
+                assert_eq!(options.nodelay, Some(false));
+            }
+            Ok(())
+        },
+    )
+}
+
+#[test]
+fn test_session_socket_options_not_connected() -> Result<(), QuickFixError> {
+    let settings = build_settings(ServerType::Sender, 1)?;
+    let app = Application::try_new(&NullFixApplication)?;
+    let store = MemoryMessageStoreFactory::new();
+    let initiator = Initiator::try_new(&settings, &app, &store, FixSocketServerKind::default())?;
+
+    let session = initiator.session(ServerType::Sender.session_id())?;
+    assert_eq!(session.socket_options()?, None);
+    session.set_socket_options(SocketOptions {
+        nodelay: Some(false),
+        ..Default::default()
+    })?;
+    assert_eq!(session.socket_options()?, None);
+    Ok(())
+}
+
+#[test]
+fn test_connection_handler_builder() -> Result<(), QuickFixError> {
+    let receiver = Arc::new(Application::try_new_shared(Arc::new(NullFixApplication))?);
+
+    let settings_receiver = build_settings(ServerType::Receiver, 0)?;
+    let mut acceptor = Acceptor::builder(&settings_receiver)
+        .shared_application(receiver.clone())
+        .build()?;
+    acceptor.start()?;
+
+    let settings_sender = build_settings(ServerType::Sender, listen_port(&acceptor))?;
+    let mut initiator = Initiator::builder(&settings_sender)
+        .application(Application::try_new_shared(Arc::new(NullFixApplication))?)
+        .store(MemoryMessageStoreFactory::new())
+        .log(LogFactory::try_new(&NullLogger)?)
+        .kind(FixSocketServerKind::SingleThreaded)
+        .build()?;
+    initiator.start()?;
+
+    wait_until(Duration::from_secs(10), || {
+        initiator.is_logged_on().unwrap_or(false) && acceptor.is_logged_on().unwrap_or(false)
+    });
+    send_to_target(build_news("Hello", &[])?, &ServerType::Sender.session_id())?;
+
+    let receiver_session = acceptor.session(ServerType::Receiver.session_id())?;
+    wait_until(Duration::from_secs(5), || {
+        receiver_session
+            .stats()
+            .map(|stats| stats.received_app == 1)
+            .unwrap_or(false)
+    });
+
+    initiator.stop()?;
+    acceptor.stop()?;
+    Ok(())
+}
+
+#[test]
+fn test_connection_handler_builder_requires_application() {
+    let settings = build_settings(ServerType::Receiver, 0).unwrap();
+    let result = Acceptor::<NullFixApplication, _>::builder(&settings).build();
+    assert_eq!(
+        result.err(),
+        Some(QuickFixError::invalid_argument("Missing application"))
+    );
+}
+
+#[derive(Default)]
+struct ConnectFailureRecorder {
+    failures: Mutex<Vec<(u32, ConnectError)>>,
+}
+
+impl ApplicationCallback for ConnectFailureRecorder {
+    fn on_connect_failed(&self, _session: &SessionId, attempt: u32, error: ConnectError) {
+        self.failures.lock().unwrap().push((attempt, error));
+    }
+}
+
+#[test]
+#[cfg(unix)]
+fn test_initiator_on_connect_failed() -> Result<(), QuickFixError> {
+    let refusing_port = RefusingPort::bind();
+    let port = refusing_port.port;
+
+    let recorder = ConnectFailureRecorder::default();
+    let app = Application::try_new(&recorder)?;
+    let store_factory = MemoryMessageStoreFactory::new();
+    let settings =
+        build_settings_with_defaults(ServerType::Sender, port, &[&ReconnectInterval(1)])?;
+    let mut initiator = Initiator::try_new(
+        &settings,
+        &app,
+        &store_factory,
+        FixSocketServerKind::SingleThreaded,
+    )?;
+    initiator.start()?;
+
+    wait_until(Duration::from_secs(10), || {
+        !recorder.failures.lock().unwrap().is_empty()
+    });
+
+    // Failed attempts are not counted as connections.
+    let stats = initiator
+        .session(ServerType::Sender.session_id())?
+        .transport_stats()?;
+    assert_eq!(stats.connect_count, 0);
+    assert_eq!(stats.last_connect_at, None);
+    initiator.stop()?;
+
+    let failures = recorder.failures.lock().unwrap();
+    let (attempt, error) = &failures[0];
+    assert_eq!(*attempt, 1);
+    assert_eq!(error.kind, ConnectErrorKind::Refused, "{error:?}");
+    assert!(!error.message.is_empty());
+    Ok(())
+}
+
+#[test]
+#[cfg(unix)]
+fn test_initiator_on_connect_failed_before_stop() -> Result<(), QuickFixError> {
+    let refusing_port = RefusingPort::bind();
+
+    let recorder = ConnectFailureRecorder::default();
+    let app = Application::try_new(&recorder)?;
+    let store_factory = MemoryMessageStoreFactory::new();
+    let settings = build_settings_with_defaults(
+        ServerType::Sender,
+        refusing_port.port,
+        &[&ReconnectInterval(60)],
+    )?;
+    let mut initiator = Initiator::try_new(
+        &settings,
+        &app,
+        &store_factory,
+        FixSocketServerKind::MultiThreaded,
+    )?;
+    initiator.start()?;
+
+    // No other attempt is made before stop, which reports the first one.
+    thread::sleep(Duration::from_secs(1));
+    initiator.stop()?;
+
+    let failures = recorder.failures.lock().unwrap();
+    assert_eq!(failures.len(), 1, "{failures:?}");
+    assert_eq!(failures[0].0, 1);
+    Ok(())
+}
+
+/// Record connection callbacks in the order they are triggered.
+#[derive(Debug, Default)]
+struct ConnectionRecorder {
+    events: Mutex<Vec<String>>,
+}
+
+impl ApplicationCallback for ConnectionRecorder {
+    fn on_connect(&self, _session: &SessionId, peer: Option<std::net::SocketAddr>) {
+        let event = if peer.is_some_and(|peer| peer.ip().is_loopback()) {
+            "connect"
+        } else {
+            "connect from unexpected peer"
+        };
+        self.events.lock().unwrap().push(event.to_string());
+    }
+
+    fn on_logon(&self, _session: &SessionId) {
+        self.events.lock().unwrap().push("logon".to_string());
+    }
+
+    fn on_logout(&self, _session: &SessionId) {
+        self.events.lock().unwrap().push("logout".to_string());
+    }
+
+    fn on_disconnect(&self, _session: &SessionId, reason: DisconnectReason) {
+        self.events
+            .lock()
+            .unwrap()
+            .push(format!("disconnect {reason:?}"));
+    }
+}
+
+#[test]
+fn test_on_connect_on_disconnect() -> Result<(), QuickFixError> {
+    let sender = ConnectionRecorder::default();
+    let receiver = ConnectionRecorder::default();
+
+    with_session_pair(&sender, &receiver, &[], |_, _| Ok(()))?;
+
+    // Initiator closes the socket once its logout is acknowledged.
+    assert_eq!(
+        *sender.events.lock().unwrap(),
+        ["connect", "logon", "disconnect Local", "logout"]
+    );
+    let receiver_events = receiver.events.lock().unwrap();
+    assert_eq!(receiver_events.len(), 4, "{receiver_events:?}");
+    assert_eq!(receiver_events[..2], ["connect", "logon"]);
+    assert!(receiver_events[2].starts_with("disconnect"));
+    assert_eq!(receiver_events[3], "logout");
+    Ok(())
+}
+
+/// Refuse to resend application messages.
+#[derive(Debug, Default)]
+struct PossDupVeto {
+    vetoed_count: AtomicUsize,
+}
+
+impl ApplicationCallback for PossDupVeto {
+    fn on_msg_to_app(&self, msg: &mut Message, _session: &SessionId) -> Result<(), MsgToAppError> {
+        if msg.with_header(|header| header.is_field_equal(tags::POSS_DUP_FLAG, "Y")) {
+            self.vetoed_count.fetch_add(1, Ordering::Relaxed);
+            return Err(MsgToAppError::DoNotSend);
+        }
+        Ok(())
+    }
+}
+
+/// Record received SequenceReset(4) messages with GapFillFlag(123) set.
+#[derive(Debug, Default)]
+struct GapFillRecorder {
+    gap_fill_new_seq_nums: Mutex<Vec<i32>>,
+    poss_dup: PossDupRecorder,
+}
+
+impl ApplicationCallback for GapFillRecorder {
+    fn on_msg_from_admin(
+        &self,
+        msg: Message,
+        _session: &SessionId,
+    ) -> Result<(), MsgFromAdminError> {
+        if msg.with_header(|header| header.is_field_equal(tags::MSG_TYPE, "4"))
+            && msg.is_field_equal(tags::GAP_FILL_FLAG, "Y")
+        {
+            let new_seq_no = msg.get_field(tags::NEW_SEQ_NO).unwrap().parse().unwrap();
+            self.gap_fill_new_seq_nums.lock().unwrap().push(new_seq_no);
+        }
+        Ok(())
+    }
+
+    fn on_msg_from_app(&self, msg: Message, session: &SessionId) -> Result<(), MsgFromAppError> {
+        self.poss_dup.on_msg_from_app(msg, session)
+    }
+}
+
+#[test]
+fn test_to_app_do_not_send_on_resend() -> Result<(), QuickFixError> {
+    let sender = PossDupVeto::default();
+    let receiver = GapFillRecorder::default();
+
+    with_session_pair(&sender, &receiver, &[], |_, _| {
+        let mut receiver_session = unsafe { Session::lookup(&ServerType::Receiver.session_id()) }?;
+        let first_seq_num = receiver_session.get_expected_target_num();
+
+        send_to_target(build_news("Stale", &[])?, &ServerType::Sender.session_id())?;
+        wait_until(Duration::from_secs(5), || {
+            receiver.poss_dup.app_msg_count() == 1
+        });
+        let next_seq_num = receiver_session.get_expected_target_num();
+
+        // Ask for the message again: sender vetoes it and fills the gap instead.
+        receiver_session.set_next_target_msg_seq_num(first_seq_num)?;
+        receiver_session.request_resend(first_seq_num as u32, 0)?;
+
+        wait_until(Duration::from_secs(5), || {
+            !receiver.gap_fill_new_seq_nums.lock().unwrap().is_empty()
+        });
+        assert_eq!(sender.vetoed_count.load(Ordering::Relaxed), 1);
+        assert_eq!(receiver.poss_dup.app_msg_count(), 1);
+        assert_eq!(receiver.poss_dup.poss_dup_count(), 0);
+        assert_eq!(
+            receiver.gap_fill_new_seq_nums.lock().unwrap()[0],
+            next_seq_num
+        );
+        Ok(())
+    })
+}
+
+/// Send Username(553) / Password(554) on logon, record logout reasons.
+#[derive(Debug, Default)]
+struct LogonCredentials {
+    password: Mutex<String>,
+    logout_texts: Mutex<Vec<String>>,
+}
+
+impl ApplicationCallback for LogonCredentials {
+    fn on_msg_to_admin(&self, msg: &mut Message, _session: &SessionId) {
+        if msg.with_header(|header| header.is_field_equal(tags::MSG_TYPE, "A")) {
+            msg.set_field(tags::USERNAME, "trader").unwrap();
+            msg.set_field(tags::PASSWORD, self.password.lock().unwrap().as_str())
+                .unwrap();
+        }
+    }
+
+    fn on_msg_from_admin(
+        &self,
+        msg: Message,
+        _session: &SessionId,
+    ) -> Result<(), MsgFromAdminError> {
+        if msg.with_header(|header| header.is_field_equal(tags::MSG_TYPE, "5")) {
+            let text = msg.get_field(tags::TEXT).unwrap_or_default();
+            self.logout_texts.lock().unwrap().push(text);
+        }
+        Ok(())
+    }
+}
+
+/// Reject logon of counterparties not knowing the password.
+#[derive(Debug)]
+struct PasswordChecker(&'static str);
+
+impl ApplicationCallback for PasswordChecker {
+    fn on_msg_from_admin(
+        &self,
+        msg: Message,
+        _session: &SessionId,
+    ) -> Result<(), MsgFromAdminError> {
+        if msg.with_header(|header| header.is_field_equal(tags::MSG_TYPE, "A"))
+            && !msg.is_field_equal(tags::PASSWORD, self.0)
+        {
+            return Err(MsgFromAdminError::RejectLogon {
+                reason: "Invalid credentials".to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_from_admin_reject_logon() -> Result<(), QuickFixError> {
+    let sender = LogonCredentials::default();
+    *sender.password.lock().unwrap() = "wrong".to_string();
+    let receiver = PasswordChecker("secret");
+
+    let app_sender = Application::try_new(&sender)?;
+    let app_receiver = Application::try_new(&receiver)?;
+    let store_factory_sender = MemoryMessageStoreFactory::new();
+    let store_factory_receiver = MemoryMessageStoreFactory::new();
+
+    let settings_receiver = build_settings(ServerType::Receiver, 0)?;
+    let mut acceptor = Acceptor::try_new(
+        &settings_receiver,
+        &app_receiver,
+        &store_factory_receiver,
+        FixSocketServerKind::SingleThreaded,
+    )?;
+    acceptor.start()?;
+
+    let settings_sender = build_settings_with_defaults(
+        ServerType::Sender,
+        listen_port(&acceptor),
+        &[&ReconnectInterval(1)],
+    )?;
+    let mut initiator = Initiator::try_new(
+        &settings_sender,
+        &app_sender,
+        &store_factory_sender,
+        FixSocketServerKind::SingleThreaded,
+    )?;
+    initiator.start()?;
+
+    // Wrong password: counterparty is logged out with the reason.
+    wait_until(Duration::from_secs(10), || {
+        !sender.logout_texts.lock().unwrap().is_empty()
+    });
+    assert!(sender.logout_texts.lock().unwrap()[0].contains("Invalid credentials"));
+    assert!(!initiator.is_logged_on()?);
+    assert!(!acceptor.is_logged_on()?);
+
+    // Right password: session comes up on next reconnection.
+    *sender.password.lock().unwrap() = "secret".to_string();
+    wait_until(Duration::from_secs(10), || {
+        initiator.is_logged_on().unwrap_or(false) && acceptor.is_logged_on().unwrap_or(false)
+    });
+
+    initiator.stop()?;
+    acceptor.stop()?;
+    Ok(())
+}
+
+/// Reject every News(B) message, depending on its headline.
+#[derive(Debug)]
+struct NewsRejecter;
+
+impl ApplicationCallback for NewsRejecter {
+    fn on_msg_from_app(&self, msg: Message, _session: &SessionId) -> Result<(), MsgFromAppError> {
+        if msg.is_field_equal(tags::HEADLINE, "Unsupported") {
+            Err(MsgFromAppError::UnsupportedMessageType)
+        } else {
+            Err(MsgFromAppError::IncorrectTagValue {
+                tag: tags::HEADLINE,
+            })
+        }
+    }
+}
+
+/// Record received Reject(3) and BusinessMessageReject(j) messages.
+#[derive(Debug, Default)]
+struct RejectRecorder {
+    session_rejects: Mutex<Vec<Option<String>>>,
+    business_rejects: Mutex<Vec<Option<String>>>,
+}
+
+impl ApplicationCallback for RejectRecorder {
+    fn on_msg_from_admin(
+        &self,
+        msg: Message,
+        _session: &SessionId,
+    ) -> Result<(), MsgFromAdminError> {
+        if msg.with_header(|header| header.is_field_equal(tags::MSG_TYPE, "3")) {
+            let ref_tag_id = msg.get_field(tags::REF_TAG_ID);
+            self.session_rejects.lock().unwrap().push(ref_tag_id);
+        }
+        Ok(())
+    }
+
+    fn on_msg_from_app(&self, msg: Message, _session: &SessionId) -> Result<(), MsgFromAppError> {
+        if msg.with_header(|header| header.is_field_equal(tags::MSG_TYPE, "j")) {
+            let ref_msg_type = msg.get_field(tags::REF_MSG_TYPE);
+            self.business_rejects.lock().unwrap().push(ref_msg_type);
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_from_app_rejects() -> Result<(), QuickFixError> {
+    let sender = RejectRecorder::default();
+
+    with_session_pair(&sender, &NewsRejecter, &[], |_, _| {
+        send_to_target(
+            build_news("Unsupported", &[])?,
+            &ServerType::Sender.session_id(),
+        )?;
+        wait_until(Duration::from_secs(5), || {
+            !sender.business_rejects.lock().unwrap().is_empty()
+        });
+        assert_eq!(
+            *sender.business_rejects.lock().unwrap(),
+            vec![Some("B".to_string())]
+        );
+
+        send_to_target(build_news("Bad", &[])?, &ServerType::Sender.session_id())?;
+        wait_until(Duration::from_secs(5), || {
+            !sender.session_rejects.lock().unwrap().is_empty()
+        });
+        assert_eq!(
+            *sender.session_rejects.lock().unwrap(),
+            vec![Some(tags::HEADLINE.to_string())]
+        );
+        Ok(())
+    })
+}
+
+/// Stamp outgoing Logon(A) with a signature of its MsgSeqNum(34).
+#[derive(Debug, Default)]
+struct LogonStamper;
+
+impl ApplicationCallback for LogonStamper {
+    fn on_msg_to_admin(&self, msg: &mut Message, _session: &SessionId) {
+        if msg.with_header(|header| header.is_field_equal(tags::MSG_TYPE, "A")) {
+            let seq_num = msg.with_header(|header| header.get_field(tags::MSG_SEQ_NUM).unwrap());
+            msg.set_field(tags::TEST_MESSAGE_INDICATOR, "Y").unwrap();
+            msg.with_header_mut(|header| {
+                header.set_field(tags::SENDER_SUB_ID, format!("sig-{seq_num}"))
+            })
+            .unwrap();
+        }
+    }
+}
+
+/// Record fields stamped on received Logon(A).
+#[derive(Debug, Default)]
+struct LogonStampRecorder {
+    stamps: Mutex<Vec<(Option<String>, Option<String>)>>,
+}
+
+impl ApplicationCallback for LogonStampRecorder {
+    fn on_msg_from_admin(
+        &self,
+        msg: Message,
+        _session: &SessionId,
+    ) -> Result<(), MsgFromAdminError> {
+        if msg.with_header(|header| header.is_field_equal(tags::MSG_TYPE, "A")) {
+            let indicator = msg.get_field(tags::TEST_MESSAGE_INDICATOR);
+            let signature = msg.with_header(|header| header.get_field(tags::SENDER_SUB_ID));
+            self.stamps.lock().unwrap().push((indicator, signature));
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_to_admin_modify_logon() -> Result<(), QuickFixError> {
+    let receiver = LogonStampRecorder::default();
+
+    with_session_pair(&LogonStamper, &receiver, &[], |_, _| {
+        assert_eq!(
+            *receiver.stamps.lock().unwrap(),
+            vec![(Some("Y".to_string()), Some("sig-1".to_string()))]
+        );
+        Ok(())
+    })
+}
+
+/// Count received News(B) and answer each of them, without any lock.
+#[derive(Debug, Default)]
+struct NewsCounter {
+    news_count: usize,
+    sent_count: usize,
+}
+
+impl ApplicationCallbackMut for NewsCounter {
+    fn on_msg_to_app(
+        &mut self,
+        _msg: &mut Message,
+        _session: &SessionId,
+    ) -> Result<(), MsgToAppError> {
+        self.sent_count += 1;
+        Ok(())
+    }
+
+    fn on_msg_from_app(
+        &mut self,
+        _msg: Message,
+        _session: &SessionId,
+    ) -> Result<(), MsgFromAppError> {
+        self.news_count += 1;
+        // Re-entrant `on_msg_to_app` is skipped instead of dead locking.
+        send_to_target(
+            build_news("Ack", &[]).unwrap(),
+            &ServerType::Receiver.session_id(),
+        )
+        .unwrap();
+        Ok(())
+    }
+}
+
+#[test]
+fn test_exclusive_application() -> Result<(), QuickFixError> {
+    let sender = PossDupRecorder::default();
+    let receiver = ExclusiveApplication::new(NewsCounter::default());
+
+    with_session_pair(&sender, &receiver, &[], |_, _| {
+        for _ in 0..3 {
+            send_to_target(build_news("Hello", &[])?, &ServerType::Sender.session_id())?;
+        }
+        wait_until(Duration::from_secs(5), || sender.app_msg_count() == 3);
+
+        // Messages sent from outside callbacks go through `on_msg_to_app`.
+        send_to_target(
+            build_news("Direct", &[])?,
+            &ServerType::Receiver.session_id(),
+        )?;
+        wait_until(Duration::from_secs(5), || sender.app_msg_count() == 4);
+
+        let counter = receiver.lock();
+        assert_eq!(counter.news_count, 3);
+        assert_eq!(counter.sent_count, 1);
+        Ok(())
+    })
+}
+
+/// Record headline of received News(B).
+#[derive(Debug, Default)]
+struct HeadlineRecorder {
+    headlines: Mutex<Vec<String>>,
+}
+
+impl ApplicationCallback for HeadlineRecorder {
+    fn on_msg_from_app(&self, msg: Message, _session: &SessionId) -> Result<(), MsgFromAppError> {
+        let headline = msg.get_field(tags::HEADLINE).unwrap_or_default();
+        self.headlines.lock().unwrap().push(headline);
+        Ok(())
+    }
+}
+
+#[test]
+fn test_application_tee() -> Result<(), QuickFixError> {
+    let business = HeadlineRecorder::default();
+    let audit = HeadlineRecorder::default();
+    let receiver = ApplicationTee::new(vec![&business, &audit]);
+    assert_eq!(receiver.len(), 2);
+
+    with_session_pair(&NullFixApplication, &receiver, &[], |_, _| {
+        send_to_target(build_news("Hello", &[])?, &ServerType::Sender.session_id())?;
+        wait_until(Duration::from_secs(5), || {
+            audit.headlines.lock().unwrap().len() == 1
+        });
+        assert_eq!(*business.headlines.lock().unwrap(), vec!["Hello"]);
+        assert_eq!(*audit.headlines.lock().unwrap(), vec!["Hello"]);
+        Ok(())
+    })
+}
+
+#[test]
+fn test_application_tee_first_error_wins() -> Result<(), QuickFixError> {
+    let sender = RejectRecorder::default();
+    let audit = HeadlineRecorder::default();
+    let receiver = ApplicationTee::new(vec![&NewsRejecter, &audit]);
+
+    with_session_pair(&sender, &receiver, &[], |_, _| {
+        send_to_target(
+            build_news("Unsupported", &[])?,
+            &ServerType::Sender.session_id(),
+        )?;
+        wait_until(Duration::from_secs(5), || {
+            !sender.business_rejects.lock().unwrap().is_empty()
+        });
+        // Rejected message still reached next child.
+        assert_eq!(*audit.headlines.lock().unwrap(), vec!["Unsupported"]);
+        Ok(())
+    })
+}
+
+/// Record kind of every received admin message.
+#[derive(Debug, Default)]
+struct AdminKindRecorder {
+    kinds: Mutex<Vec<AdminMsgKind>>,
+}
+
+impl ApplicationCallback for AdminKindRecorder {
+    fn on_msg_from_admin_with_kind(
+        &self,
+        kind: AdminMsgKind,
+        _msg: Message,
+        _session: &SessionId,
+    ) -> Result<(), MsgFromAdminError> {
+        self.kinds.lock().unwrap().push(kind);
+        Ok(())
+    }
+}
+
+#[test]
+fn test_from_admin_kind() -> Result<(), QuickFixError> {
+    let sender = AdminKindRecorder::default();
+    let receiver = LogonRecorder::default();
+
+    with_session_pair(&sender, &receiver, &[], |_, _| {
+        wait_until(Duration::from_secs(5), || {
+            sender.kinds.lock().unwrap().contains(&AdminMsgKind::Logon)
+        });
+
+        // Counterparty answers TestRequest with a Heartbeat.
+        let session = unsafe { Session::lookup(&ServerType::Sender.session_id()) }?;
+        session.send_test_request("Kind")?;
+        wait_until(Duration::from_secs(5), || {
+            sender.kinds.lock().unwrap().len() == 2
+        });
+
+        assert_eq!(
+            *sender.kinds.lock().unwrap(),
+            vec![AdminMsgKind::Logon, AdminMsgKind::Heartbeat]
+        );
+        Ok(())
+    })
+}
+
+/// Record serialized application messages and raw bytes seen on the wire.
+#[derive(Debug, Default)]
+struct RawRecorder {
+    serialized: Mutex<Vec<String>>,
+    incoming: Mutex<Vec<Vec<u8>>>,
+    outgoing: Mutex<Vec<Vec<u8>>>,
+}
+
+impl ApplicationCallback for RawRecorder {
+    fn on_msg_to_app(&self, msg: &mut Message, _session: &SessionId) -> Result<(), MsgToAppError> {
+        self.serialized
+            .lock()
+            .unwrap()
+            .push(msg.to_fix_string().unwrap());
+        Ok(())
+    }
+
+    fn on_raw_incoming(&self, _session: &SessionId, raw: &[u8]) {
+        self.incoming.lock().unwrap().push(raw.to_vec());
+    }
+
+    fn on_raw_outgoing(&self, _session: &SessionId, raw: &[u8]) {
+        self.outgoing.lock().unwrap().push(raw.to_vec());
+    }
+}
+
+#[test]
+fn test_raw_messages() -> Result<(), QuickFixError> {
+    let sender = RawRecorder::default();
+    let receiver = RawRecorder::default();
+
+    with_session_pair(&sender, &receiver, &[], |_, _| {
+        send_to_target(build_news("Hello", &[])?, &ServerType::Sender.session_id())?;
+        wait_until(Duration::from_secs(5), || {
+            receiver
+                .incoming
+                .lock()
+                .unwrap()
+                .iter()
+                .any(|raw| raw.windows(5).any(|w| w == b"35=B\x01"))
+        });
+
+        let serialized = sender.serialized.lock().unwrap()[0].clone().into_bytes();
+        let is_news = |raw: &&Vec<u8>| raw.windows(5).any(|w| w == b"35=B\x01");
+
+        // Bytes written by sender are the one it serialized, and the one received by counterparty.
+        let outgoing = sender.outgoing.lock().unwrap();
+        assert_eq!(
+            outgoing.iter().filter(is_news).collect::<Vec<_>>(),
+            vec![&serialized]
+        );
+        let incoming = receiver.incoming.lock().unwrap();
+        assert_eq!(
+            incoming.iter().filter(is_news).collect::<Vec<_>>(),
+            vec![&serialized]
+        );
+
+        // Admin messages are reported too, starting with Logon.
+        assert!(outgoing[0].windows(5).any(|w| w == b"35=A\x01"));
+        assert!(incoming[0].windows(5).any(|w| w == b"35=A\x01"));
+        Ok(())
+    })
+}
+
+/// Read custom settings of sessions when they are created.
+#[derive(Debug, Default)]
+struct DeskCodeReader {
+    desk_codes: Mutex<Vec<Result<String, String>>>,
+}
+
+impl ApplicationCallback for DeskCodeReader {
+    fn on_create(&self, session_id: &SessionId) {
+        let desk_code = unsafe { Session::lookup(session_id) }
+            .and_then(|session| session.settings())
+            .and_then(|settings| settings.get::<String>("MyDeskCode"))
+            .map_err(|err| err.to_string());
+        self.desk_codes.lock().unwrap().push(desk_code);
+    }
+}
+
+#[test]
+fn test_session_settings_on_create() -> Result<(), QuickFixError> {
+    let receiver = DeskCodeReader::default();
+
+    with_session_pair(
+        &NullFixApplication,
+        &receiver,
+        &[&("MyDeskCode", "EQ-42")],
+        |_, acceptor| {
+            assert_eq!(
+                *receiver.desk_codes.lock().unwrap(),
+                vec![Ok("EQ-42".to_string())]
+            );
+
+            // Settings are merged with defaults.
+            let session = acceptor.session(ServerType::Receiver.session_id())?;
+            let settings = session.settings()?;
+            assert_eq!(settings.get::<String>("MyDeskCode")?, "EQ-42");
+            assert_eq!(settings.get::<String>("SenderCompID")?, "RECEIVER");
+            assert_eq!(settings.get::<i32>("HeartBtInt")?, 20);
+            Ok(())
+        },
+    )
+}
+
+/// Record logon and logout details.
+#[derive(Debug, Default)]
+struct LifecycleRecorder {
+    logons: Mutex<Vec<LogonContext>>,
+    logouts: Mutex<Vec<LogoutContext>>,
+}
+
+impl ApplicationCallback for LifecycleRecorder {
+    fn on_logon_with_context(&self, _session: &SessionId, context: LogonContext) {
+        self.logons.lock().unwrap().push(context);
+    }
+
+    fn on_logout_with_context(&self, _session: &SessionId, context: LogoutContext) {
+        self.logouts.lock().unwrap().push(context);
+    }
+}
+
+#[test]
+fn test_logout_context_on_stop() -> Result<(), QuickFixError> {
+    let sender = LifecycleRecorder::default();
+    let receiver = LifecycleRecorder::default();
+
+    with_session_pair(&sender, &receiver, &[&ResetOnLogon(true)], |_, _| Ok(()))?;
+
+    for recorder in [&sender, &receiver] {
+        assert_eq!(
+            *recorder.logons.lock().unwrap(),
+            vec![LogonContext {
+                sequence_reset: true
+            }]
+        );
+    }
+
+    // Initiator logs out first when stopped.
+    let sender_logouts = sender.logouts.lock().unwrap();
+    assert_eq!(sender_logouts.len(), 1);
+    assert_eq!(sender_logouts[0].kind, LogoutKind::Normal);
+    assert!(sender_logouts[0].initiated_locally);
+
+    let receiver_logouts = receiver.logouts.lock().unwrap();
+    assert_eq!(receiver_logouts.len(), 1);
+    assert_eq!(receiver_logouts[0].kind, LogoutKind::Normal);
+    assert!(!receiver_logouts[0].initiated_locally);
+    Ok(())
+}
+
+#[test]
+fn test_logout_context_on_disconnect() -> Result<(), QuickFixError> {
+    let sender = LifecycleRecorder::default();
+    let receiver = LifecycleRecorder::default();
+
+    with_session_pair(&sender, &receiver, &[], |_, _| {
+        let session = unsafe { Session::lookup(&ServerType::Sender.session_id()) }?;
+        session.disconnect()?;
+        wait_until(Duration::from_secs(5), || {
+            !sender.logouts.lock().unwrap().is_empty()
+                && !receiver.logouts.lock().unwrap().is_empty()
+        });
+
+        assert_eq!(
+            sender.logouts.lock().unwrap()[0],
+            LogoutContext {
+                initiated_locally: true,
+                reason: None,
+                kind: LogoutKind::Disconnect,
+            }
+        );
+        assert_eq!(
+            receiver.logouts.lock().unwrap()[0],
+            LogoutContext {
+                initiated_locally: false,
+                reason: None,
+                kind: LogoutKind::Disconnect,
+            }
+        );
+        Ok(())
+    })?;
+
+    assert_eq!(
+        sender.logons.lock().unwrap()[0],
+        LogonContext {
+            sequence_reset: false
+        }
+    );
+    Ok(())
+}
@@ -0,0 +1,148 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use quickfix::{dictionary_item::*, *};
+use utils::*;
+
+mod utils;
+
+/// Events seen by a callback instance, as (instance session, callback, event session).
+type EventLog = Arc<Mutex<Vec<(String, &'static str, String)>>>;
+
+/// Callbacks built for a single session.
+#[derive(Debug)]
+struct CounterpartyRecorder {
+    owner: String,
+    events: EventLog,
+    dropped: Arc<Mutex<Vec<String>>>,
+}
+
+impl CounterpartyRecorder {
+    fn record(&self, callback: &'static str, session: &SessionId) {
+        self.events
+            .lock()
+            .unwrap()
+            .push((self.owner.clone(), callback, session.to_repr()));
+    }
+}
+
+impl ApplicationCallback for CounterpartyRecorder {
+    fn on_create(&self, session: &SessionId) {
+        self.record("on_create", session);
+    }
+
+    fn on_logon(&self, session: &SessionId) {
+        self.record("on_logon", session);
+    }
+
+    fn on_msg_from_app(&self, _msg: Message, session: &SessionId) -> Result<(), MsgFromAppError> {
+        self.record("on_msg_from_app", session);
+        Ok(())
+    }
+}
+
+impl Drop for CounterpartyRecorder {
+    fn drop(&mut self) {
+        self.dropped.lock().unwrap().push(self.owner.clone());
+    }
+}
+
+#[test]
+fn test_application_per_session() -> Result<(), QuickFixError> {
+    let receiver_main = ServerType::Receiver.session_id();
+    let receiver_backup = SessionId::try_new("FIX.4.4", "RECEIVER", "BACKUP", "")?;
+    let sender_main = ServerType::Sender.session_id();
+    let sender_backup = SessionId::try_new("FIX.4.4", "BACKUP", "RECEIVER", "")?;
+
+    let events = EventLog::default();
+    let dropped = Arc::new(Mutex::new(Vec::new()));
+
+    let factory_events = events.clone();
+    let factory_dropped = dropped.clone();
+    let app_receiver = Application::try_from_factory(move |session_id: &SessionId| {
+        Box::new(CounterpartyRecorder {
+            owner: session_id.to_repr(),
+            events: factory_events.clone(),
+            dropped: factory_dropped.clone(),
+        })
+    })?;
+    let app_sender = Application::try_new(&NullFixApplication)?;
+    let store_receiver = MemoryMessageStoreFactory::new();
+    let store_sender = MemoryMessageStoreFactory::new();
+
+    let settings_receiver = SessionSettingsBuilder::new()
+        .default(Dictionary::try_from_items(&[&ConnectionType::Acceptor])?)
+        .session(receiver_main.clone(), |dict| fill_session(dict, 0))
+        .session(receiver_backup.clone(), |dict| fill_session(dict, 0))
+        .build()?;
+    let mut acceptor = Acceptor::try_new(
+        &settings_receiver,
+        &app_receiver,
+        &store_receiver,
+        FixSocketServerKind::default(),
+    )?;
+    acceptor.start()?;
+    let port = listen_port(&acceptor);
+
+    let settings_sender = SessionSettingsBuilder::new()
+        .default(Dictionary::try_from_items(&[
+            &ConnectionType::Initiator,
+            &ReconnectInterval(60),
+        ])?)
+        .session(sender_main.clone(), |dict| fill_session(dict, port))
+        .session(sender_backup.clone(), |dict| fill_session(dict, port))
+        .build()?;
+    let mut initiator = Initiator::try_new(
+        &settings_sender,
+        &app_sender,
+        &store_sender,
+        FixSocketServerKind::default(),
+    )?;
+    initiator.start()?;
+
+    let count = |callback: &str| {
+        events
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(_, event, _)| *event == callback)
+            .count()
+    };
+    wait_until(Duration::from_secs(10), || count("on_logon") == 2);
+
+    send_to_target(build_news("Main", &[])?, &sender_main)?;
+    send_to_target(build_news("Backup", &[])?, &sender_backup)?;
+    wait_until(Duration::from_secs(5), || count("on_msg_from_app") == 2);
+
+    // Every instance only saw events of its own session.
+    {
+        let events = events.lock().unwrap();
+        assert!(events.iter().all(|(owner, _, session)| owner == session));
+        for session_id in [&receiver_main, &receiver_backup] {
+            let callbacks: Vec<_> = events
+                .iter()
+                .filter(|(owner, _, _)| *owner == session_id.to_repr())
+                .map(|(_, callback, _)| *callback)
+                .collect();
+            assert_eq!(callbacks, vec!["on_create", "on_logon", "on_msg_from_app"]);
+        }
+    }
+
+    // Removed session drops its callbacks.
+    assert!(dropped.lock().unwrap().is_empty());
+    acceptor.remove_session(&receiver_backup, true)?;
+    assert_eq!(*dropped.lock().unwrap(), vec![receiver_backup.to_repr()]);
+
+    // Others are dropped with the application, once handler is shut down.
+    initiator.stop()?;
+    acceptor.stop()?;
+    drop(acceptor);
+    drop(app_receiver);
+    assert_eq!(
+        *dropped.lock().unwrap(),
+        vec![receiver_backup.to_repr(), receiver_main.to_repr()]
+    );
+    Ok(())
+}
@@ -63,3 +63,502 @@ fn test_getter_and_setter() {
         None,
     );
 }
+
+#[test]
+fn test_builder() {
+    let session_id = SessionId::try_new("FIX.4.4", "ME", "THEM", "").unwrap();
+
+    let mut defaults = Dictionary::new();
+    defaults.set("ConnectionType", "initiator").unwrap();
+    defaults.set("HeartBtInt", 30).unwrap();
+
+    let settings = SessionSettingsBuilder::new()
+        .default(defaults)
+        .session(session_id.clone(), |dict| {
+            dict.set("StartTime", "00:00:00")?;
+            dict.set("EndTime", "23:59:59")?;
+            dict.set("SocketConnectHost", "127.0.0.1")?;
+            dict.set("SocketConnectPort", 4000)
+        })
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        settings
+            .with_dictionary(Some(&session_id), |dict| dict
+                .get::<i32>("SocketConnectPort"))
+            .unwrap(),
+        Ok(4000)
+    );
+    assert_eq!(
+        settings
+            .with_dictionary(Some(&session_id), |dict| dict.get::<i32>("HeartBtInt"))
+            .unwrap(),
+        Ok(30)
+    );
+}
+
+#[test]
+fn test_builder_validation() {
+    let session_id = SessionId::try_new("FIX.4.4", "ME", "THEM", "").unwrap();
+
+    // Missing connection type.
+    assert_eq!(
+        SessionSettingsBuilder::new()
+            .session(session_id.clone(), |dict| dict.set("StartTime", "00:00:00"))
+            .build()
+            .unwrap_err(),
+        QuickFixError::ConfigError("FIX.4.4:ME->THEM: missing key ConnectionType".to_string())
+    );
+
+    // Invalid connection type.
+    assert_eq!(
+        SessionSettingsBuilder::new()
+            .session(session_id.clone(), |dict| dict
+                .set("ConnectionType", "both"))
+            .build()
+            .unwrap_err(),
+        QuickFixError::ConfigError(
+            "FIX.4.4:ME->THEM: invalid value for key ConnectionType: both".to_string()
+        )
+    );
+
+    // Missing connection specific key.
+    assert_eq!(
+        SessionSettingsBuilder::new()
+            .session(session_id.clone(), |dict| {
+                dict.set("ConnectionType", "acceptor")?;
+                dict.set("StartTime", "00:00:00")?;
+                dict.set("EndTime", "23:59:59")
+            })
+            .build()
+            .unwrap_err(),
+        QuickFixError::ConfigError("FIX.4.4:ME->THEM: missing key SocketAcceptPort".to_string())
+    );
+
+    // Error from session closure.
+    assert_eq!(
+        SessionSettingsBuilder::new()
+            .session(session_id, |dict| dict.set("Bad\0Key", "value"))
+            .build()
+            .unwrap_err(),
+        QuickFixError::invalid_argument("nul byte found in provided data at position: 3")
+    );
+}
+
+#[test]
+fn test_to_string_ini_round_trip() {
+    let text = r#"
+[DEFAULT]
+ConnectionType=acceptor
+SenderCompID=TW
+
+[SESSION]
+BeginString=FIX.4.1
+TargetCompID=ARCA1
+SocketAcceptPort=4000
+
+[SESSION]
+BeginString=FIX.4.1
+TargetCompID=ARCA2
+SocketAcceptPort=4001
+ConnectionType=initiator
+"#;
+    let session1 = SessionId::try_new("FIX.4.1", "TW", "ARCA1", "").unwrap();
+    let session2 = SessionId::try_new("FIX.4.1", "TW", "ARCA2", "").unwrap();
+
+    let check = |settings: &SessionSettings| {
+        let read = |session_id: Option<&SessionId>, key: &str| {
+            settings
+                .with_dictionary(session_id, |dict| dict.get::<String>(key).ok())
+                .flatten()
+        };
+
+        assert_eq!(read(None, "ConnectionType").as_deref(), Some("acceptor"));
+        assert_eq!(read(None, "SocketAcceptPort"), None);
+        assert_eq!(
+            read(Some(&session1), "SocketAcceptPort").as_deref(),
+            Some("4000")
+        );
+        assert_eq!(
+            read(Some(&session1), "ConnectionType").as_deref(),
+            Some("acceptor")
+        );
+        assert_eq!(
+            read(Some(&session2), "SocketAcceptPort").as_deref(),
+            Some("4001")
+        );
+        assert_eq!(
+            read(Some(&session2), "ConnectionType").as_deref(),
+            Some("initiator")
+        );
+    };
+
+    let settings = SessionSettings::try_from_str(text).unwrap();
+    check(&settings);
+
+    let rendered = settings.to_string_ini().unwrap();
+    assert!(rendered.starts_with("[DEFAULT]\n"));
+    assert_eq!(rendered.matches("[SESSION]").count(), 2);
+    check(&SessionSettings::try_from_str(&rendered).unwrap());
+
+    let path = std::env::temp_dir().join(format!("quickfix-settings-{}.ini", std::process::id()));
+    settings.write_to_path(&path).unwrap();
+    check(&SessionSettings::try_from_path(&path).unwrap());
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_from_invalid_str() {
+    assert!(matches!(
+        SessionSettings::try_from_str("[SESSION]\nBeginString=FIX.4.4\n"),
+        Err(QuickFixError::ConfigError(_))
+    ));
+    assert_eq!(
+        SessionSettings::try_from_str("invalid_\0text").unwrap_err(),
+        QuickFixError::invalid_argument("nul byte found in provided data at position: 8")
+    );
+}
+
+#[test]
+fn test_session_ids() {
+    let settings = SessionSettings::try_from_path("../configs/settings.ini").unwrap();
+    let session_ids = settings.session_ids();
+
+    assert_eq!(
+        session_ids
+            .iter()
+            .map(|session_id| (
+                session_id.get_begin_string().unwrap(),
+                session_id.get_sender_comp_id().unwrap(),
+                session_id.get_target_comp_id().unwrap(),
+                session_id.get_session_qualifier().unwrap(),
+            ))
+            .collect::<Vec<_>>(),
+        vec![
+            (
+                "FIX.4.1".to_string(),
+                "TW".to_string(),
+                "ARCA1".to_string(),
+                "".to_string()
+            ),
+            (
+                "FIX.4.1".to_string(),
+                "TW".to_string(),
+                "ARCA2".to_string(),
+                "".to_string()
+            ),
+        ]
+    );
+
+    assert!(SessionSettings::new().session_ids().is_empty());
+}
+
+#[test]
+fn test_get_and_replace_session() {
+    let session_id = SessionId::try_new("FIX.4.4", "CLIENT1", "SERVER1", "").unwrap();
+    let mut settings = SessionSettings::new();
+
+    assert!(matches!(
+        settings.get(&session_id),
+        Err(QuickFixError::ConfigError(_))
+    ));
+
+    let mut dict_global = Dictionary::new();
+    dict_global.set("ConnectionType", "initiator").unwrap();
+    dict_global.set("foo", 60).unwrap();
+    settings.set(None, dict_global).unwrap();
+
+    let mut dict_session = Dictionary::new();
+    dict_session.set("bar", "initial").unwrap();
+    settings.set(Some(&session_id), dict_session).unwrap();
+
+    // Copy contains defaults.
+    let mut dict = settings.get(&session_id).unwrap();
+    assert_eq!(dict.get("foo"), Ok("60".to_string()));
+    assert_eq!(dict.get("bar"), Ok("initial".to_string()));
+
+    // Modifying the copy does not change settings.
+    dict.set("bar", "updated").unwrap();
+    assert_eq!(
+        settings.get(&session_id).unwrap().get("bar"),
+        Ok("initial".to_string())
+    );
+
+    // Put it back.
+    settings.set(Some(&session_id), dict).unwrap();
+    assert_eq!(settings.session_ids().len(), 1);
+    let dict = settings.get(&session_id).unwrap();
+    assert_eq!(dict.get("bar"), Ok("updated".to_string()));
+    assert_eq!(dict.get("foo"), Ok("60".to_string()));
+}
+
+#[test]
+fn test_validate() {
+    // Valid settings.
+    let settings = SessionSettings::try_from_path("../configs/settings.ini").unwrap();
+    assert!(settings.validate().is_ok());
+    assert!(SessionSettings::new().validate().is_ok());
+
+    // Every problem is reported.
+    let settings = SessionSettings::try_from_str(
+        "[DEFAULT]\n\
+         StartTime=00:00:00\n\
+         \n\
+         [SESSION]\n\
+         ConnectionType=initiator\n\
+         BeginString=FIX.4.4\n\
+         SenderCompID=ME\n\
+         TargetCompID=THEM\n\
+         SocketConnectPort=99999\n\
+         HeartBtInt=often\n\
+         EndTime=25:00:00\n\
+         StartDay=Funday\n\
+         \n\
+         [SESSION]\n\
+         ConnectionType=acceptor\n\
+         BeginString=FIX.4.4\n\
+         SenderCompID=ME\n\
+         TargetCompID=OTHER\n\
+         EndTime=23:00:00\n",
+    )
+    .unwrap();
+
+    let errors = settings.validate().unwrap_err();
+    let errors: Vec<_> = errors
+        .iter()
+        .map(|err| (err.session_id.to_repr(), err.key.as_str()))
+        .collect();
+    assert_eq!(
+        errors,
+        vec![
+            ("FIX.4.4:ME->OTHER".to_string(), "SocketAcceptPort"),
+            ("FIX.4.4:ME->THEM".to_string(), "SocketConnectHost"),
+            ("FIX.4.4:ME->THEM".to_string(), "SocketConnectPort"),
+            ("FIX.4.4:ME->THEM".to_string(), "HeartBtInt"),
+            ("FIX.4.4:ME->THEM".to_string(), "EndTime"),
+            ("FIX.4.4:ME->THEM".to_string(), "StartDay"),
+        ]
+    );
+}
+
+const ENV_SETTINGS: &str = "[DEFAULT]\n\
+    ConnectionType=initiator\n\
+    # Not expanded: ${QF_TEST_NOT_IN_COMMENT}\n\
+    \n\
+    [SESSION]\n\
+    BeginString=FIX.4.4\n\
+    SenderCompID=ME\n\
+    TargetCompID=THEM\n\
+    SocketConnectHost=${QF_TEST_HOST}\n\
+    SocketConnectPort=${QF_TEST_PORT:-4000}\n\
+    Password=prefix-${QF_TEST_HOST}-${QF_TEST_SUFFIX:-}\n";
+
+#[test]
+fn test_from_str_with_env() {
+    let session_id = SessionId::try_new("FIX.4.4", "ME", "THEM", "").unwrap();
+    std::env::set_var("QF_TEST_HOST", "fix.example.com");
+
+    // Substitution and defaults.
+    let settings = SessionSettings::try_from_str_with_env(ENV_SETTINGS).unwrap();
+    let dict = settings.get(&session_id).unwrap();
+    assert_eq!(
+        dict.get("SocketConnectHost"),
+        Ok("fix.example.com".to_string())
+    );
+    assert_eq!(dict.get("SocketConnectPort"), Ok("4000".to_string()));
+    assert_eq!(
+        dict.get("Password"),
+        Ok("prefix-fix.example.com-".to_string())
+    );
+
+    // Defined variable wins over default.
+    std::env::set_var("QF_TEST_PORT", "5001");
+    let settings = SessionSettings::try_from_str_with_env(ENV_SETTINGS).unwrap();
+    let dict = settings.get(&session_id).unwrap();
+    assert_eq!(dict.get("SocketConnectPort"), Ok("5001".to_string()));
+
+    // Same from file.
+    let path = std::env::temp_dir().join("quickfix_test_from_str_with_env.ini");
+    std::fs::write(&path, ENV_SETTINGS).unwrap();
+    let settings = SessionSettings::try_from_path_with_env(&path).unwrap();
+    let dict = settings.get(&session_id).unwrap();
+    assert_eq!(dict.get("SocketConnectPort"), Ok("5001".to_string()));
+    std::fs::remove_file(&path).unwrap();
+
+    // Text without environment expansion is left untouched.
+    let settings = SessionSettings::try_from_str(ENV_SETTINGS).unwrap();
+    let dict = settings.get(&session_id).unwrap();
+    assert_eq!(
+        dict.get("SocketConnectHost"),
+        Ok("${QF_TEST_HOST}".to_string())
+    );
+}
+
+#[test]
+fn test_from_str_with_env_missing_variable() {
+    // Use a dedicated variable, so tests running in parallel do not interfere.
+    let text = ENV_SETTINGS.replace("QF_TEST_HOST", "QF_TEST_UNSET_HOST");
+    assert_eq!(
+        SessionSettings::try_from_str_with_env(&text).unwrap_err(),
+        QuickFixError::ConfigError(
+            "Configuration failed: environment variable QF_TEST_UNSET_HOST is not set \
+             (section [SESSION] #2, key SocketConnectHost)"
+                .to_string()
+        )
+    );
+    assert!(matches!(
+        SessionSettings::try_from_str_with_env("[DEFAULT]\nFoo=${BAR\n"),
+        Err(QuickFixError::ConfigError(_))
+    ));
+    assert!(matches!(
+        SessionSettings::try_from_path_with_env("invalid_file.ini"),
+        Err(QuickFixError::ConfigError(_))
+    ));
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn test_from_toml() {
+    let from_toml = SessionSettings::try_from_toml(
+        r#"
+        [default]
+        ConnectionType = "initiator"
+        SenderCompID = "ME"
+        HeartBtInt = 30
+        ResetOnLogon = true
+        UseDataDictionary = false
+        CustomCounterpartyKey = "custom"
+
+        [[session]]
+        BeginString = "FIX.4.4"
+        TargetCompID = "THEM1"
+        SocketConnectHost = "127.0.0.1"
+        SocketConnectPort = 5001
+        StartTime = 00:00:00
+        EndTime = "23:59:59"
+
+        [[session]]
+        BeginString = "FIX.4.2"
+        TargetCompID = "THEM2"
+        SocketConnectHost = "127.0.0.1"
+        SocketConnectPort = 5002
+        StartTime = "00:00:00"
+        EndTime = "23:59:59"
+        "#,
+    )
+    .unwrap();
+
+    let from_ini = SessionSettings::try_from_str(
+        "[DEFAULT]\n\
+         ConnectionType=initiator\n\
+         SenderCompID=ME\n\
+         HeartBtInt=30\n\
+         ResetOnLogon=Y\n\
+         UseDataDictionary=N\n\
+         CustomCounterpartyKey=custom\n\
+         \n\
+         [SESSION]\n\
+         BeginString=FIX.4.4\n\
+         TargetCompID=THEM1\n\
+         SocketConnectHost=127.0.0.1\n\
+         SocketConnectPort=5001\n\
+         StartTime=00:00:00\n\
+         EndTime=23:59:59\n\
+         \n\
+         [SESSION]\n\
+         BeginString=FIX.4.2\n\
+         TargetCompID=THEM2\n\
+         SocketConnectHost=127.0.0.1\n\
+         SocketConnectPort=5002\n\
+         StartTime=00:00:00\n\
+         EndTime=23:59:59\n",
+    )
+    .unwrap();
+
+    assert_eq!(from_toml.to_string_ini(), from_ini.to_string_ini());
+    assert!(from_toml.validate().is_ok());
+
+    // Both settings start the same initiator.
+    struct MyApplication;
+    impl ApplicationCallback for MyApplication {}
+
+    for settings in [&from_toml, &from_ini] {
+        let app = Application::try_new(&MyApplication).unwrap();
+        let store = MemoryMessageStoreFactory::new();
+        let mut initiator =
+            Initiator::try_new(settings, &app, &store, FixSocketServerKind::default()).unwrap();
+
+        let session_ids: Vec<_> = initiator
+            .session_ids()
+            .unwrap()
+            .iter()
+            .map(SessionId::to_repr)
+            .collect();
+        assert_eq!(session_ids, vec!["FIX.4.2:ME->THEM2", "FIX.4.4:ME->THEM1"]);
+
+        initiator.start().unwrap();
+        initiator.stop().unwrap();
+    }
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn test_from_invalid_toml() {
+    for text in [
+        "not toml",
+        "default = 42",
+        "[other]",
+        "[[session]]\nBeginString = \"FIX.4.4\"",
+        "[[session]]\nBeginString = \"FIX.4.4\"\nSenderCompID = \"ME\"\nTargetCompID = [1]",
+    ] {
+        assert!(matches!(
+            SessionSettings::try_from_toml(text),
+            Err(QuickFixError::ConfigError(_))
+        ));
+    }
+}
+
+#[test]
+fn test_default_and_session_dictionary() {
+    let settings = SessionSettings::try_from_path("../configs/settings.ini").unwrap();
+    let session_id = SessionId::try_new("FIX.4.1", "TW", "ARCA1", "").unwrap();
+
+    let defaults = settings.default_dictionary().unwrap();
+    assert_eq!(defaults.get("ReconnectInterval"), Ok(60));
+    assert_eq!(defaults.contains("TargetCompID"), Ok(false));
+
+    // Key only defined in DEFAULT section is visible from session.
+    let session = settings.session_dictionary(&session_id).unwrap();
+    assert_eq!(session.get("ReconnectInterval"), Ok(60));
+    assert_eq!(session.get("ConnectionType"), Ok("acceptor".to_string()));
+    assert_eq!(session.get("TargetCompID"), Ok("ARCA1".to_string()));
+    assert_eq!(session.get("SocketAcceptPort"), Ok(4000));
+
+    let unknown_id = SessionId::try_new("FIX.4.1", "TW", "UNKNOWN", "").unwrap();
+    assert!(matches!(
+        settings.session_dictionary(&unknown_id),
+        Err(QuickFixError::ConfigError(_))
+    ));
+}
+
+#[test]
+fn test_set_default() {
+    let session_id = SessionId::try_new("FIX.4.1", "TW", "ARCA1", "").unwrap();
+    let mut settings = SessionSettings::try_from_path("../configs/settings.ini").unwrap();
+
+    let mut defaults = settings.default_dictionary().unwrap();
+    defaults.set("ReconnectInterval", 5).unwrap();
+    defaults.set("ResetOnLogon", true).unwrap();
+    defaults.set("HeartBtInt", 45).unwrap();
+    settings.set_default(defaults).unwrap();
+
+    let dict = settings.get(&session_id).unwrap();
+    // Inherited values are updated.
+    assert_eq!(dict.get("ReconnectInterval"), Ok(5));
+    assert_eq!(dict.get("ResetOnLogon"), Ok(true));
+    // Session values are kept.
+    assert_eq!(dict.get("HeartBtInt"), Ok(20));
+    assert_eq!(dict.get("TargetCompID"), Ok("ARCA1".to_string()));
+    assert_eq!(dict.get("SenderCompID"), Ok("TW".to_string()));
+}
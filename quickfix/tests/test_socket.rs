@@ -79,4 +79,5 @@ fn check_connection_handler<C: ConnectionHandler>(mut conn: C) {
 
     // Test stop when started
     assert!(conn.stop().is_ok());
+    assert_eq!(conn.is_stopped(), Ok(true));
 }
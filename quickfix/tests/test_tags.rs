@@ -0,0 +1,33 @@
+use quickfix::*;
+
+#[test]
+fn test_tags() {
+    assert_eq!(tags::BEGIN_STRING, 8);
+    assert_eq!(tags::CL_ORD_ID, 11);
+    assert_eq!(tags::MSG_TYPE, 35);
+    assert_eq!(tags::NO_PARTY_I_DS, 453);
+    assert_eq!(tags::APPL_VER_ID, 1128);
+}
+
+#[test]
+fn test_msg_types() {
+    assert_eq!(msg_types::HEARTBEAT, "0");
+    assert_eq!(msg_types::LOGON, "A");
+    assert_eq!(msg_types::NEW_ORDER_SINGLE, "D");
+    assert_eq!(msg_types::EXECUTION_REPORT, "8");
+    assert_eq!(msg_types::TRADE_CAPTURE_REPORT_REQUEST, "AD");
+}
+
+#[test]
+fn test_build_message() -> Result<(), QuickFixError> {
+    let mut msg = Message::new();
+    msg.with_header_mut(|h| h.set_field(tags::MSG_TYPE, msg_types::NEW_ORDER_SINGLE))?;
+    msg.set_field(tags::CL_ORD_ID, "ORDER-1")?;
+    msg.set_field(tags::SYMBOL, "AAPL")?;
+
+    let txt = msg.to_fix_string()?;
+    assert!(txt.contains("\u{1}35=D\u{1}"), "{txt}");
+    assert!(txt.contains("\u{1}11=ORDER-1\u{1}"), "{txt}");
+    assert!(txt.contains("\u{1}55=AAPL\u{1}"), "{txt}");
+    Ok(())
+}
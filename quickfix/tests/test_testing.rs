@@ -0,0 +1,152 @@
+use std::{
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+use quickfix::{dictionary_item::*, testing::*, *};
+
+fn build_news(headline: &str) -> Result<Message, QuickFixError> {
+    let mut msg = Message::new();
+    msg.with_header_mut(|h| h.set_field(tags::MSG_TYPE, msg_types::NEWS))?;
+    msg.set_field(tags::HEADLINE, headline)?;
+    Ok(msg)
+}
+
+#[test]
+fn test_session_pair() -> Result<(), QuickFixError> {
+    let pair = SessionPair::start(FixVersion::Fix44, FixSocketServerKind::SingleThreaded)?;
+    pair.wait_logged_on(Duration::from_secs(10))?;
+    assert!(pair.initiator_app().is_logged_on());
+    assert!(pair.acceptor_app().is_logged_on());
+    assert_eq!(
+        pair.initiator_session_id().to_repr(),
+        "FIX.4.4:SENDER->RECEIVER"
+    );
+    assert_eq!(
+        pair.acceptor_session_id().to_repr(),
+        "FIX.4.4:RECEIVER->SENDER"
+    );
+
+    // Logon is recorded as admin message.
+    assert!(pair.acceptor_app().admin_messages().iter().any(|msg| msg
+        .with_header(|h| h.get_field(tags::MSG_TYPE))
+        .as_deref()
+        == Some(msg_types::LOGON)));
+
+    send_to_target(build_news("ping")?, pair.initiator_session_id())?;
+    send_to_target(build_news("pong")?, pair.acceptor_session_id())?;
+
+    let received = pair
+        .acceptor_app()
+        .wait_app_messages(1, Duration::from_secs(10));
+    assert_eq!(received.len(), 1);
+    assert_eq!(
+        received[0].get_field(tags::HEADLINE).as_deref(),
+        Some("ping")
+    );
+
+    let received = pair
+        .initiator_app()
+        .wait_app_messages(1, Duration::from_secs(10));
+    assert_eq!(received.len(), 1);
+    assert_eq!(
+        received[0].get_field(tags::HEADLINE).as_deref(),
+        Some("pong")
+    );
+
+    pair.acceptor_app().clear();
+    assert!(pair.acceptor_app().app_messages().is_empty());
+
+    let (initiator_report, _acceptor_report) = pair.shutdown(Duration::from_secs(5))?;
+    assert_eq!(initiator_report.clean.len(), 1);
+    assert!(initiator_report.forced.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_session_pair_fixt() -> Result<(), QuickFixError> {
+    let pair = SessionPair::start(FixVersion::Fix50Sp2, FixSocketServerKind::MultiThreaded)?;
+    pair.wait_logged_on(Duration::from_secs(10))?;
+    assert_eq!(
+        pair.initiator_session_id().to_repr(),
+        "FIXT.1.1:SENDER->RECEIVER"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_session_pair_borrowed_callbacks() -> Result<(), QuickFixError> {
+    let initiator_app = RecordingApplication::new();
+    let pair = SessionPair::builder(FixVersion::Fix42)
+        .default_item(&HeartBtInt(5))
+        .start_with(&initiator_app, &NullApplication)?;
+    pair.wait_logged_on(Duration::from_secs(10))?;
+    assert!(initiator_app.is_logged_on());
+    assert!(pair.initiator_app().is_logged_on());
+    drop(pair);
+    Ok(())
+}
+
+#[test]
+fn test_session_pair_logger() -> Result<(), QuickFixError> {
+    struct CountingLogger(AtomicUsize);
+
+    impl LogCallback for CountingLogger {
+        fn on_incoming(&self, _session_id: Option<&SessionId>, _msg: &str) {
+            self.0.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    static LOGGER: CountingLogger = CountingLogger(AtomicUsize::new(0));
+
+    let pair = SessionPair::builder(FixVersion::Fix44)
+        .log(LogFactory::try_new(&LOGGER)?)
+        .start()?;
+    pair.wait_logged_on(Duration::from_secs(10))?;
+    // Both sides received a logon.
+    assert!(LOGGER.0.load(Ordering::Relaxed) >= 2);
+    Ok(())
+}
+
+#[test]
+fn test_wait_logged_on_timeout() -> Result<(), QuickFixError> {
+    let pair = SessionPair::start(FixVersion::Fix44, FixSocketServerKind::SingleThreaded)?;
+    // Logon cannot complete that fast.
+    let err = pair.wait_logged_on(Duration::ZERO).unwrap_err();
+    assert!(matches!(err, QuickFixError::SessionNotLoggedOn(_)), "{err}");
+    Ok(())
+}
+
+#[test]
+fn test_settings() -> Result<(), QuickFixError> {
+    let settings = TestSettings::new(FixVersion::Fix50)
+        .default_item(&ReconnectInterval(1))
+        .build(PairSide::Initiator, 1234)?;
+
+    let session_id = PairSide::Initiator.session_id(FixVersion::Fix50)?;
+    let dict = settings.get(&session_id)?;
+    assert_eq!(dict.get::<String>("ConnectionType")?, "initiator");
+    assert_eq!(dict.get::<i32>("ReconnectInterval")?, 1);
+    assert!(!dict.get::<bool>("UseDataDictionary")?);
+    assert_eq!(dict.get::<String>("DefaultApplVerID")?, "7");
+    assert_eq!(dict.get::<i32>("SocketConnectPort")?, 1234);
+    assert_eq!(dict.get::<String>("SocketConnectHost")?, "127.0.0.1");
+
+    let settings = TestSettings::new(FixVersion::Fix44)
+        .data_dictionary("spec/FIX44.xml")
+        .build(PairSide::Acceptor, 0)?;
+    let dict = settings.get(&PairSide::Acceptor.session_id(FixVersion::Fix44)?)?;
+    assert_eq!(dict.get::<String>("ConnectionType")?, "acceptor");
+    assert_eq!(dict.get::<String>("DataDictionary")?, "spec/FIX44.xml");
+    assert!(dict.get::<bool>("UseDataDictionary").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_fix_version() {
+    assert_eq!(FixVersion::Fix40.begin_string(), "FIX.4.0");
+    assert_eq!(FixVersion::Fix44.begin_string(), "FIX.4.4");
+    assert_eq!(FixVersion::Fix44.default_appl_ver_id(), None);
+    assert_eq!(FixVersion::Fix50Sp2.begin_string(), "FIXT.1.1");
+    assert_eq!(FixVersion::Fix50Sp2.default_appl_ver_id(), Some("9"));
+}
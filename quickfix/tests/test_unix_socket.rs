@@ -0,0 +1,139 @@
+#![cfg(target_os = "linux")]
+
+use std::{fs, os::unix::net::UnixListener, path::PathBuf, process, time::Duration};
+
+use quickfix::{dictionary_item::*, *};
+use utils::*;
+
+mod utils;
+
+fn socket_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("quickfix-{name}-{}.sock", process::id()))
+}
+
+#[test]
+fn test_logon_and_news_over_unix_socket() -> Result<(), QuickFixError> {
+    let path = socket_path("news");
+    // Socket file left behind by a listener which is gone.
+    drop(UnixListener::bind(&path).expect("Fail to bind stale listener"));
+    assert!(path.exists());
+    let path_name = path.to_str().unwrap();
+
+    let sender = FixRecorder::new(ServerType::Sender.session_id());
+    let receiver = FixRecorder::new(ServerType::Receiver.session_id());
+    let app_sender = Application::try_new(&sender)?;
+    let app_receiver = Application::try_new(&receiver)?;
+    let message_store_factory_sender = MemoryMessageStoreFactory::new();
+    let message_store_factory_receiver = MemoryMessageStoreFactory::new();
+
+    let settings_receiver =
+        build_settings_with_defaults(ServerType::Receiver, 0, &[&SocketAcceptPath(path_name)])?;
+    let mut socket_receiver = Acceptor::try_new(
+        &settings_receiver,
+        &app_receiver,
+        &message_store_factory_receiver,
+        FixSocketServerKind::SingleThreaded,
+    )?;
+    socket_receiver.start()?;
+    // Nothing listens over TCP.
+    assert_eq!(socket_receiver.listen_endpoints()?, vec![]);
+
+    let settings_sender =
+        build_settings_with_defaults(ServerType::Sender, 0, &[&SocketConnectPath(path_name)])?;
+    let mut socket_sender = Initiator::try_new(
+        &settings_sender,
+        &app_sender,
+        &message_store_factory_sender,
+        FixSocketServerKind::SingleThreaded,
+    )?;
+    socket_sender.start()?;
+
+    wait_until(Duration::from_secs(10), || {
+        sender.is_logged_in() && receiver.is_logged_in()
+    });
+
+    send_to_target(build_news("Hello", &[])?, &ServerType::Sender.session_id())?;
+    wait_until(Duration::from_secs(5), || {
+        receiver.user_msg_count() == MsgCounter { sent: 0, recv: 1 }
+    });
+
+    let news = build_news("Anyone here", &["This news goes", "the other way"])?;
+    send_to_target(news, &ServerType::Receiver.session_id())?;
+    wait_until(Duration::from_secs(5), || {
+        sender.user_msg_count() == MsgCounter { sent: 1, recv: 1 }
+    });
+
+    socket_sender.stop()?;
+    socket_receiver.stop()?;
+
+    drop(socket_receiver);
+    assert!(!path.exists());
+    Ok(())
+}
+
+#[test]
+fn test_unix_socket_path_is_not_a_socket() -> Result<(), QuickFixError> {
+    let path = socket_path("regular-file");
+    fs::write(&path, "keep me").expect("Fail to write file");
+
+    let app = Application::try_new(&NullFixApplication)?;
+    let message_store_factory = MemoryMessageStoreFactory::new();
+    let settings = build_settings_with_defaults(
+        ServerType::Receiver,
+        0,
+        &[&SocketAcceptPath(path.to_str().unwrap())],
+    )?;
+    let mut socket_receiver = Acceptor::try_new(
+        &settings,
+        &app,
+        &message_store_factory,
+        FixSocketServerKind::SingleThreaded,
+    )?;
+
+    assert!(socket_receiver.start().is_err());
+    drop(socket_receiver);
+    assert_eq!(fs::read_to_string(&path).as_deref().ok(), Some("keep me"));
+    fs::remove_file(&path).expect("Fail to remove file");
+    Ok(())
+}
+
+#[test]
+fn test_unix_socket_requires_single_threaded_handler() -> Result<(), QuickFixError> {
+    let app = Application::try_new(&NullFixApplication)?;
+    let message_store_factory = MemoryMessageStoreFactory::new();
+
+    let settings = build_settings_with_defaults(
+        ServerType::Receiver,
+        0,
+        &[&SocketAcceptPath("/tmp/quickfix-unused.sock")],
+    )?;
+    let result = Acceptor::try_new(
+        &settings,
+        &app,
+        &message_store_factory,
+        FixSocketServerKind::MultiThreaded,
+    );
+    assert!(
+        matches!(result, Err(QuickFixError::ConfigError(_))),
+        "{:?}",
+        result.err()
+    );
+
+    let settings = build_settings_with_defaults(
+        ServerType::Sender,
+        0,
+        &[&SocketConnectPath("/tmp/quickfix-unused.sock")],
+    )?;
+    let result = Initiator::try_new(
+        &settings,
+        &app,
+        &message_store_factory,
+        FixSocketServerKind::MultiThreaded,
+    );
+    assert!(
+        matches!(result, Err(QuickFixError::ConfigError(_))),
+        "{:?}",
+        result.err()
+    );
+    Ok(())
+}
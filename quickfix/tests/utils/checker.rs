@@ -2,7 +2,7 @@ use std::{sync::Mutex, thread, time::Duration};
 
 use quickfix::*;
 
-use super::{build_news, build_settings, ServerType};
+use super::{build_news, session_pair_builder, ServerType};
 
 static GLOBAL_LOCK: Mutex<u8> = Mutex::new(0);
 
@@ -14,45 +14,17 @@ static GLOBAL_LOCK: Mutex<u8> = Mutex::new(0);
 /// WARNING: There is a lot of global variables in QuickFix C++. So this function
 ///          cannot be instantiate using multiple thread.
 ///          This is why there is a global lock in the application.
-///          It also make port re-use easier.
 pub fn run(
-    communication_port: u16,
-    sender: impl ApplicationCallback + 'static,
-    message_store_factory_sender: impl FfiMessageStoreFactory + 'static,
-    receiver: impl ApplicationCallback + 'static,
-    message_store_factory_receiver: impl FfiMessageStoreFactory + 'static,
+    sender: impl ApplicationCallback,
+    receiver: impl ApplicationCallback,
 ) -> Result<(), QuickFixError> {
     let _lock = GLOBAL_LOCK.lock().expect("GLOBAL_LOCK poisoned");
 
-    // Init settings with same server port.
-    let settings_sender = build_settings(ServerType::Sender, communication_port)?;
-    let settings_receiver = build_settings(ServerType::Receiver, communication_port)?;
-
-    let app_sender = Application::try_new(&sender)?;
-    let app_receiver = Application::try_new(&receiver)?;
-
-    // Init socket acceptor / initiator.
-    let mut socket_sender = Initiator::try_new(
-        &settings_sender,
-        &app_sender,
-        &message_store_factory_sender,
-        FixSocketServerKind::default(),
-    )?;
-    let mut socket_receiver = Acceptor::try_new(
-        &settings_receiver,
-        &app_receiver,
-        &message_store_factory_receiver,
-        FixSocketServerKind::default(),
-    )?;
-
-    // Start the app
-    socket_receiver.start()?;
-    socket_sender.start()?;
+    // Start acceptor / initiator on a port picked by the OS.
+    let pair = session_pair_builder().start_with(&sender, &receiver)?;
 
     // Wait for login completion
-    while !socket_sender.is_logged_on()? && !socket_receiver.is_logged_on()? {
-        thread::sleep(Duration::from_millis(50));
-    }
+    pair.wait_logged_on(Duration::from_secs(30))?;
 
     // Send message in both direction
     let news = build_news("Hello", &[])?;
@@ -64,8 +36,7 @@ pub fn run(
     thread::sleep(Duration::from_millis(50));
 
     // Stop everything
-    socket_receiver.stop()?;
-    socket_sender.stop()?;
+    drop(pair);
 
     Ok(())
 }
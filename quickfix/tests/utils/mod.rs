@@ -1,25 +1,107 @@
 #![allow(dead_code)]
 #![allow(unused_imports)]
 
-use std::net::TcpListener;
-
 pub mod checker;
 mod msg_const;
 mod recorder;
+mod session_pair;
 mod settings_builder;
 
-use quickfix::ApplicationCallback;
+use quickfix::{Acceptor, ApplicationCallback, FfiMessageStoreFactory};
+
+pub use quickfix::testing::NullApplication as NullFixApplication;
 
 pub use msg_const::*;
 pub use recorder::*;
+pub use session_pair::*;
 pub use settings_builder::*;
 
-pub fn find_available_port() -> u16 {
-    (8000..9000)
-        .find(|port| TcpListener::bind(("127.0.0.1", *port)).is_ok())
-        .expect("No port available in test range")
+/// Get port of a running acceptor, configured with `SocketAcceptPort=0` to let the OS pick a free port.
+pub fn listen_port<A, S>(acceptor: &Acceptor<'_, A, S>) -> u16
+where
+    A: ApplicationCallback,
+    S: FfiMessageStoreFactory,
+{
+    quickfix::testing::listen_port(acceptor).expect("Fail to get listen port")
+}
+
+/// Local TCP port bound without listening, so every connection to it is refused while it is alive.
+///
+/// Unlike releasing a port picked by the OS, nobody can start listening on it meanwhile.
+#[cfg(unix)]
+pub struct RefusingPort {
+    fd: libc::c_int,
+    pub port: u16,
+}
+
+#[cfg(unix)]
+impl RefusingPort {
+    pub fn bind() -> Self {
+        unsafe {
+            let fd = libc::socket(libc::AF_INET, libc::SOCK_STREAM, 0);
+            assert!(fd >= 0, "Fail to create socket");
+
+            let mut addr: libc::sockaddr_in = std::mem::zeroed();
+            addr.sin_family = libc::AF_INET as libc::sa_family_t;
+            addr.sin_addr.s_addr = u32::from(std::net::Ipv4Addr::LOCALHOST).to_be();
+            let mut addr_len = std::mem::size_of::<libc::sockaddr_in>() as libc::socklen_t;
+            let addr_ptr = std::ptr::addr_of_mut!(addr).cast::<libc::sockaddr>();
+            assert_eq!(libc::bind(fd, addr_ptr, addr_len), 0, "Fail to bind socket");
+            assert_eq!(
+                libc::getsockname(fd, addr_ptr, &mut addr_len),
+                0,
+                "Fail to get socket port"
+            );
+
+            Self {
+                fd,
+                port: u16::from_be(addr.sin_port),
+            }
+        }
+    }
+}
+
+#[cfg(unix)]
+impl Drop for RefusingPort {
+    fn drop(&mut self) {
+        unsafe { libc::close(self.fd) };
+    }
 }
 
-pub struct NullFixApplication;
+/// Self signed certificate and its key, generated with `openssl` command.
+#[cfg(quickfix_ssl)]
+pub struct TestCertificate {
+    pub cert_file: String,
+    pub key_file: String,
+}
 
-impl ApplicationCallback for NullFixApplication {}
+#[cfg(quickfix_ssl)]
+impl TestCertificate {
+    /// Generate certificate for common name `name`, which must be unique in test process.
+    pub fn generate(name: &str) -> Self {
+        let dir = std::env::temp_dir().join(format!("quickfix-certs-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).expect("Fail to create certificate directory");
+        let cert_file = dir.join(format!("{name}Cert.pem"));
+        let key_file = dir.join(format!("{name}Key.pem"));
+
+        let status = std::process::Command::new("openssl")
+            .args([
+                "req", "-x509", "-newkey", "rsa:2048", "-nodes", "-days", "1",
+            ])
+            .arg("-subj")
+            .arg(format!("/CN={name}"))
+            .arg("-keyout")
+            .arg(&key_file)
+            .arg("-out")
+            .arg(&cert_file)
+            .stderr(std::process::Stdio::null())
+            .status()
+            .expect("Fail to run openssl");
+        assert!(status.success(), "Fail to generate certificate {name}");
+
+        Self {
+            cert_file: cert_file.to_string_lossy().into_owned(),
+            key_file: key_file.to_string_lossy().into_owned(),
+        }
+    }
+}
@@ -2,11 +2,6 @@
 
 use quickfix::*;
 
-pub const MSG_NO_LINES_OF_TEXT: i32 = 33;
-pub const MSG_TYPE: i32 = 35;
-pub const MSG_TEXT: i32 = 58;
-pub const MSG_HEADLINE: i32 = 148;
-
 /// Create new News message.
 ///
 /// This is a super simple message to build.
@@ -14,14 +9,15 @@ pub const MSG_HEADLINE: i32 = 148;
 /// Doc: https://www.onixs.biz/fix-dictionary/4.4/msgType_B_66.html
 pub fn build_news(headline: &str, lines: &[&str]) -> Result<Message, QuickFixError> {
     let mut msg = Message::new();
-    msg.with_header_mut(|h| h.set_field(MSG_TYPE, "B"))?;
+    msg.header_mut()
+        .set_field(tags::MSG_TYPE, msg_types::NEWS)?;
 
-    msg.set_field(MSG_HEADLINE, headline)?;
-    msg.set_field(MSG_NO_LINES_OF_TEXT, lines.len())?; // Not required but always nice
+    msg.set_field(tags::HEADLINE, headline)?;
+    msg.set_field(tags::NO_LINES_OF_TEXT, lines.len())?; // Not required but always nice
 
     for line in lines {
-        let mut group = Group::try_new(MSG_NO_LINES_OF_TEXT, MSG_TEXT)?;
-        group.set_field(MSG_TEXT, *line)?;
+        let mut group = Group::try_new(tags::NO_LINES_OF_TEXT, tags::TEXT)?;
+        group.set_field(tags::TEXT, *line)?;
         msg.add_group(&group)?;
     }
 
@@ -39,7 +35,11 @@ pub fn build_list_status(
 
     for params in params_list {
         msg.add_group(&{
-            let mut group = Group::try_with_orders(73, 11, &[11, 14, 84, 6])?;
+            let mut group = Group::try_with_orders(
+                tags::NO_ORDERS,
+                tags::CL_ORD_ID,
+                &[tags::CL_ORD_ID, tags::CUM_QTY, tags::CXL_QTY, tags::AVG_PX],
+            )?;
             for (param_id, param_value) in *params {
                 group.set_field(*param_id, *param_value)?;
             }
@@ -47,7 +47,7 @@ pub fn build_list_status(
         })?;
     }
 
-    msg.set_field(66, list_id)?;
+    msg.set_field(tags::LIST_ID, list_id)?;
 
     Ok(msg)
 }
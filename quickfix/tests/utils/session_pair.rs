@@ -0,0 +1,56 @@
+use std::{thread, time::Duration};
+
+use quickfix::{dictionary_item::*, *};
+
+use super::session_pair_builder;
+
+/// Initiator used by sender side of `with_session_pair`.
+pub type PairInitiator<'a, S> = Initiator<'a, S, MemoryMessageStoreFactory>;
+
+/// Acceptor used by receiver side of `with_session_pair`.
+pub type PairAcceptor<'a, R> = Acceptor<'a, R, MemoryMessageStoreFactory>;
+
+/// Start an initiator (sender) / acceptor (receiver) pair on a port picked by the OS,
+/// wait for both sides to be logged on and then run `f`.
+///
+/// `extra_defaults` are applied to the DEFAULT section of both sides.
+/// Both handlers are stopped once `f` returns.
+pub fn with_session_pair<S, R, F>(
+    sender: &S,
+    receiver: &R,
+    extra_defaults: &[&dyn DictionaryItem],
+    f: F,
+) -> Result<(), QuickFixError>
+where
+    S: ApplicationCallback,
+    R: ApplicationCallback,
+    F: FnOnce(&mut PairInitiator<'_, S>, &mut PairAcceptor<'_, R>) -> Result<(), QuickFixError>,
+{
+    let mut pair = session_pair_builder()
+        .default_items(extra_defaults)
+        .start_with(sender, receiver)?;
+    pair.wait_logged_on(Duration::from_secs(30))?;
+
+    let (socket_sender, socket_receiver) = pair.handlers_mut();
+    let result = f(socket_sender, socket_receiver);
+
+    // Stop everything
+    pair.shutdown(Duration::from_secs(5))?;
+
+    result
+}
+
+/// Wait until `predicate` returns `true`.
+///
+/// # Panic
+///
+/// When condition is still not met after `timeout`.
+pub fn wait_until<F: FnMut() -> bool>(timeout: Duration, mut predicate: F) {
+    let step = Duration::from_millis(50);
+    let mut elapsed = Duration::ZERO;
+    while !predicate() {
+        assert!(elapsed < timeout, "Condition not met after {timeout:?}");
+        thread::sleep(step);
+        elapsed += step;
+    }
+}
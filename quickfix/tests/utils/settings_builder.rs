@@ -1,4 +1,13 @@
-use quickfix::{dictionary_item::*, *};
+use quickfix::{
+    dictionary_item::*,
+    testing::{
+        apply_session_items, FixVersion, PairSide, SessionPair, SessionPairBuilder, TestSettings,
+    },
+    *,
+};
+
+/// Data dictionary of every test session.
+pub const FIX44_SPEC: &str = "../quickfix-ffi/libquickfix/spec/FIX44.xml";
 
 pub enum ServerType {
     Receiver,
@@ -6,47 +15,85 @@ pub enum ServerType {
 }
 
 impl ServerType {
-    pub fn connection_type(&self) -> ConnectionType {
+    pub fn side(&self) -> PairSide {
         match self {
-            ServerType::Receiver => ConnectionType::Acceptor,
-            ServerType::Sender => ConnectionType::Initiator,
+            ServerType::Receiver => PairSide::Acceptor,
+            ServerType::Sender => PairSide::Initiator,
         }
     }
 
+    pub fn connection_type(&self) -> ConnectionType {
+        self.side().connection_type()
+    }
+
     pub fn session_id(&self) -> SessionId {
+        self.side()
+            .session_id(FixVersion::Fix44)
+            .expect("Fail to build session ID")
+    }
+
+    /// ID of the `index`-th session built by `build_multi_settings`, e.g. `SENDER1 -> RECEIVER`.
+    pub fn indexed_session_id(&self, index: usize) -> SessionId {
+        let sender = format!("SENDER{index}");
         match self {
-            ServerType::Receiver => SessionId::try_new("FIX.4.4", "RECEIVER", "SENDER", ""),
-            ServerType::Sender => SessionId::try_new("FIX.4.4", "SENDER", "RECEIVER", ""),
+            ServerType::Receiver => SessionId::try_new("FIX.4.4", "RECEIVER", &sender, ""),
+            ServerType::Sender => SessionId::try_new("FIX.4.4", &sender, "RECEIVER", ""),
         }
         .expect("Fail to build session ID")
     }
 }
 
+/// Start building a FIX 4.4 session pair validating messages with `FIX44_SPEC`.
+pub fn session_pair_builder<'a>() -> SessionPairBuilder<'a> {
+    SessionPair::builder(FixVersion::Fix44).data_dictionary(FIX44_SPEC)
+}
+
+/// Fill a test session validating messages with `FIX44_SPEC`, see `apply_session_items`.
+pub fn fill_session(dict: &mut Dictionary, port: u16) -> Result<(), QuickFixError> {
+    apply_session_items(dict, port)?;
+    dictionary_item::DataDictionary(FIX44_SPEC).apply_param(dict)
+}
+
 pub fn build_settings(
     server_type: ServerType,
     port: u16,
 ) -> Result<SessionSettings, QuickFixError> {
-    let mut settings = SessionSettings::new();
+    build_settings_with_defaults(server_type, port, &[])
+}
 
-    settings.set(
-        None,
-        Dictionary::try_from_items(&[&server_type.connection_type(), &ReconnectInterval(60)])?,
-    )?;
+/// Same as `build_settings` but with extra items applied on the DEFAULT section.
+///
+/// Extra items are applied last, so they override built-in defaults.
+pub fn build_settings_with_defaults(
+    server_type: ServerType,
+    port: u16,
+    extra_defaults: &[&dyn DictionaryItem],
+) -> Result<SessionSettings, QuickFixError> {
+    TestSettings::new(FixVersion::Fix44)
+        .data_dictionary(FIX44_SPEC)
+        .default_items(extra_defaults)
+        .build(server_type.side(), port)
+}
 
-    settings.set(
-        Some(&server_type.session_id()),
-        Dictionary::try_from_items(&[
-            &StartTime("00:00:00"),
-            &EndTime("23:59:59"),
-            &HeartBtInt(20),
-            &DataDictionary("../quickfix-ffi/libquickfix/spec/FIX44.xml"),
-            &SocketAcceptPort(port),
-            &SocketConnectPort(port),
-            &SocketConnectHost("127.0.0.1"),
-        ])?,
-    )?;
+/// Same as `build_settings` but with `count` sessions, see `ServerType::indexed_session_id`.
+pub fn build_multi_settings(
+    server_type: ServerType,
+    port: u16,
+    count: usize,
+) -> Result<SessionSettings, QuickFixError> {
+    let defaults =
+        Dictionary::try_from_items(&[&server_type.connection_type(), &ReconnectInterval(60)])?;
 
-    Ok(settings)
+    (0..count)
+        .fold(
+            SessionSettingsBuilder::new().default(defaults),
+            |builder, index| {
+                builder.session(server_type.indexed_session_id(index), |dict| {
+                    fill_session(dict, port)
+                })
+            },
+        )
+        .build()
 }
 
 pub fn build_ssl_settings(